@@ -9,6 +9,7 @@ use crate::{
 };
 use async_trait::async_trait;
 use futures::Future;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::{
     client::{StreamClient, TopicClient},
     clients::client::{IggyClient, IggyClientConfig},
@@ -87,6 +88,10 @@ pub trait Benchmarkable {
                         message_expiry: None,
                         max_topic_size: None,
                         replication_factor: 1,
+                        compression_algorithm: None,
+                        cleanup_policy: CleanupPolicy::Delete,
+                        max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+                        flush_policy: FlushPolicy::default(),
                     })
                     .await?;
             }
@@ -61,6 +61,8 @@ impl Consumer {
             strategy: PollingStrategy::offset(0),
             count: self.messages_per_batch,
             auto_commit: false,
+            skip_poisoned: false,
+            generation: None,
         };
 
         let mut latencies: Vec<Duration> = Vec::with_capacity(self.message_batches as usize);
@@ -4,7 +4,7 @@ use iggy::client::MessageClient;
 use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::error::Error;
 use iggy::identifier::Identifier;
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
 use integration::test_server::{login_root, ClientFactory};
 use std::str::FromStr;
 use std::sync::Arc;
@@ -62,6 +62,7 @@ impl Producer {
             stream_id: Identifier::numeric(self.stream_id)?,
             topic_id: Identifier::numeric(topic_id)?,
             partitioning: Partitioning::partition_id(partition_id),
+            ack: AckLevel::InMemory,
             messages,
         };
 
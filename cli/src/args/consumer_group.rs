@@ -1,6 +1,9 @@
 use crate::args::common::ListMode;
-use clap::{Args, Subcommand};
+use clap::{ArgGroup, Args, Subcommand};
+use iggy::consumer_groups::reset_consumer_group_offsets::OffsetsResetStrategy;
 use iggy::identifier::Identifier;
+use iggy::models::consumer_group::PartitionAssignmentStrategy;
+use iggy::utils::duration::IggyDuration;
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum ConsumerGroupAction {
@@ -14,6 +17,8 @@ pub(crate) enum ConsumerGroupAction {
     ///  iggy consumer-group create stream 2 2 test
     ///  iggy consumer-group create 2 topic 3 receiver
     ///  iggy consumer-group create stream topic 4 group
+    ///  iggy consumer-group create 1 1 5 workers --session-timeout 10s
+    ///  iggy consumer-group create 1 1 6 cache --assignment-strategy sticky
     #[clap(verbatim_doc_comment, visible_alias = "c")]
     Create(ConsumerGroupCreateArgs),
     /// Delete consumer group with given ID for given stream ID and topic ID
@@ -62,6 +67,22 @@ pub(crate) enum ConsumerGroupAction {
     ///  iggy consumer-group list production sensor -l table
     #[clap(verbatim_doc_comment, visible_alias = "l")]
     List(ConsumerGroupListArgs),
+    /// Reset offsets of consumer group with given ID for all or selected partitions
+    /// of given stream ID and topic ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// Consumer group ID can be specified as a consumer group name or ID
+    /// Offsets are the offsets of the next messages to be polled by the consumer group
+    ///
+    /// Examples:
+    ///  iggy consumer-group reset-offsets 1 1 1 --earliest
+    ///  iggy consumer-group reset-offsets stream topic group --latest --partitions 1,2
+    ///  iggy consumer-group reset-offsets 1 1 group --offset 100 --dry-run
+    ///  iggy consumer-group reset-offsets 1 topic 1 --timestamp 1700000000000000
+    ///  iggy consumer-group reset-offsets stream 1 1 --shift -10 --dry-run
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    ResetOffsets(ConsumerGroupResetOffsetsArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -80,6 +101,16 @@ pub(crate) struct ConsumerGroupCreateArgs {
     pub(crate) consumer_group_id: u32,
     /// Consumer group name to create
     pub(crate) name: String,
+    /// Session timeout of the consumer group members in human readable format like 10s or 1min
+    ///
+    /// (skipping parameter uses the default session timeout configured on the server)
+    #[arg(short, long, verbatim_doc_comment)]
+    pub(crate) session_timeout: Option<IggyDuration>,
+    /// Strategy used to assign the partitions to the members (round_robin, range or sticky)
+    ///
+    /// (sticky strategy keeps the current assignment of the members as much as possible on membership change)
+    #[arg(short, long, default_value = "round_robin", verbatim_doc_comment)]
+    pub(crate) assignment_strategy: PartitionAssignmentStrategy,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -136,3 +167,78 @@ pub(crate) struct ConsumerGroupListArgs {
     #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
     pub(crate) list_mode: ListMode,
 }
+
+#[derive(Debug, Clone, Args)]
+#[command(group = ArgGroup::new("reset_strategy").required(true))]
+pub(crate) struct ConsumerGroupResetOffsetsArgs {
+    /// Stream ID to reset consumer group offsets
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to reset consumer group offsets
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Consumer group ID to reset offsets
+    ///
+    /// Consumer group ID can be specified as a consumer group name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) consumer_group_id: Identifier,
+    /// Comma separated list of partition IDs to reset offsets
+    ///
+    /// (skipping parameter resets offsets for all partitions of the topic)
+    #[arg(short, long, value_delimiter = ',', verbatim_doc_comment)]
+    pub(crate) partitions: Vec<u32>,
+    /// Reset strategy - reset to the first message still stored in the partition
+    #[arg(short, long, default_value_t = false, group = "reset_strategy")]
+    pub(crate) earliest: bool,
+    /// Reset strategy - reset after the last message, so only new messages are polled
+    #[arg(short, long, default_value_t = false, group = "reset_strategy")]
+    pub(crate) latest: bool,
+    /// Reset strategy - reset to the message with given offset
+    #[arg(short, long, group = "reset_strategy")]
+    pub(crate) offset: Option<u64>,
+    /// Reset strategy - reset to the first message appended at or after
+    /// given timestamp in microseconds since Unix epoch
+    #[arg(short, long, group = "reset_strategy", verbatim_doc_comment)]
+    pub(crate) timestamp: Option<u64>,
+    /// Reset strategy - shift current offset by given number of messages
+    ///
+    /// (negative value rewinds the consumer group)
+    #[arg(
+        short,
+        long,
+        group = "reset_strategy",
+        allow_negative_numbers = true,
+        verbatim_doc_comment
+    )]
+    pub(crate) shift: Option<i64>,
+    /// Print offsets before and after reset without changing them
+    #[arg(short, long, default_value_t = false)]
+    pub(crate) dry_run: bool,
+}
+
+impl ConsumerGroupResetOffsetsArgs {
+    pub(crate) fn strategy(&self) -> OffsetsResetStrategy {
+        match (
+            self.earliest,
+            self.latest,
+            self.offset,
+            self.timestamp,
+            self.shift,
+        ) {
+            (true, false, None, None, None) => OffsetsResetStrategy::Earliest,
+            (false, true, None, None, None) => OffsetsResetStrategy::Latest,
+            (false, false, Some(offset), None, None) => OffsetsResetStrategy::Offset(offset),
+            (false, false, None, Some(timestamp), None) => {
+                OffsetsResetStrategy::Timestamp(timestamp)
+            }
+            (false, false, None, None, Some(shift)) => OffsetsResetStrategy::Shift(shift),
+            _ => unreachable!(
+                "Either earliest, latest, offset, timestamp or shift must be specified"
+            ),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use clap::{ArgGroup, Args, Subcommand};
 use iggy::identifier::Identifier;
+use iggy::messages::send_messages::AckLevel;
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum MessageAction {
@@ -50,6 +51,11 @@ pub(crate) struct SendMessagesArgs {
     #[clap(verbatim_doc_comment)]
     #[clap(short, long, group = "partitioning")]
     pub(crate) message_key: Option<String>,
+    /// Acknowledgement level of the sent messages (none, in_memory or persisted)
+    ///
+    /// (persisted waits until the messages are written and synced on disk by the server)
+    #[arg(short, long, default_value = "in_memory", verbatim_doc_comment)]
+    pub(crate) ack: AckLevel,
     /// Messages to be sent
     ///
     /// If no messages are provided, the command will read the messages from the
@@ -5,6 +5,7 @@ pub(crate) mod message;
 pub(crate) mod partition;
 pub(crate) mod permissions;
 pub(crate) mod personal_access_token;
+pub(crate) mod role;
 pub(crate) mod stream;
 pub(crate) mod system;
 pub(crate) mod topic;
@@ -12,9 +13,15 @@ pub(crate) mod user;
 
 use self::user::UserAction;
 use crate::args::{
-    client::ClientAction, consumer_group::ConsumerGroupAction, message::MessageAction,
-    partition::PartitionAction, personal_access_token::PersonalAccessTokenAction,
-    stream::StreamAction, system::PingArgs, topic::TopicAction,
+    client::ClientAction,
+    consumer_group::ConsumerGroupAction,
+    message::MessageAction,
+    partition::PartitionAction,
+    personal_access_token::PersonalAccessTokenAction,
+    role::RoleAction,
+    stream::StreamAction,
+    system::{AuditLogArgs, PingArgs},
+    topic::TopicAction,
 };
 use clap::{Args, Command as ClapCommand};
 use clap::{Parser, Subcommand};
@@ -113,12 +120,27 @@ pub(crate) enum Command {
     /// Collect basic Iggy server statistics like number of streams, topics, partitions, etc.
     /// Server OS name, version, etc. are also collected.
     Stats,
+    /// get iggy server audit log
+    ///
+    /// Collect the latest entries of the audit log recording the administrative
+    /// and security-relevant actions, like changes of streams, users and permissions
+    /// or failed logins. Requires the permission to manage the servers.
+    ///
+    /// Examples:
+    ///  iggy audit-log
+    ///  iggy audit-log --count 10 --user-id 2
+    ///  iggy audit-log -c 10 -u 2 -l list
+    #[clap(verbatim_doc_comment)]
+    AuditLog(AuditLogArgs),
     /// personal access token operations
     #[command(subcommand)]
     Pat(PersonalAccessTokenAction),
     /// user operations
     #[command(subcommand, visible_alias = "u")]
     User(UserAction),
+    /// role operations
+    #[command(subcommand, visible_alias = "r")]
+    Role(RoleAction),
     /// client operations
     #[command(subcommand, visible_alias = "c")]
     Client(ClientAction),
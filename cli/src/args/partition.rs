@@ -30,6 +30,29 @@ pub(crate) enum PartitionAction {
     ///  iggy partition delete 1 sensor 16
     #[clap(verbatim_doc_comment, visible_alias = "d")]
     Delete(PartitionDeleteArgs),
+    /// Get the earliest and latest offsets of each partition
+    /// for the specified topic ID and stream ID.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy partition offsets 1 1
+    ///  iggy partition offsets prod sensor
+    #[clap(verbatim_doc_comment, visible_alias = "o")]
+    Offsets(PartitionOffsetsArgs),
+    /// Get the offset of the first message appended at or after
+    /// the given timestamp in each partition for the specified
+    /// topic ID and stream ID.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy partition offsets-for-timestamp 1 1 1700000000000000
+    ///  iggy partition offsets-for-timestamp prod sensor 1700000000000000
+    #[clap(verbatim_doc_comment, visible_alias = "t")]
+    OffsetsForTimestamp(PartitionOffsetsForTimestampArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -65,3 +88,33 @@ pub(crate) struct PartitionDeleteArgs {
     #[arg(value_parser = clap::value_parser!(u32).range(1..100_001))]
     pub(crate) partitions_count: u32,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct PartitionOffsetsArgs {
+    /// Stream ID to get partition offsets
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to get partition offsets
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct PartitionOffsetsForTimestampArgs {
+    /// Stream ID to get offsets for timestamp
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to get offsets for timestamp
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Timestamp in microseconds since Unix epoch
+    pub(crate) timestamp: u64,
+}
@@ -0,0 +1,170 @@
+use crate::args::common::ListMode;
+use crate::args::permissions::global::GlobalPermissionsArg;
+use crate::args::permissions::stream::StreamPermissionsArg;
+use clap::{Args, Subcommand};
+use iggy::identifier::Identifier;
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum RoleAction {
+    /// Create role with given name and permissions
+    ///
+    /// Examples
+    ///  iggy role create readers --global-permissions r_str,r_top,p_msg
+    ///  iggy role create producers -s 1:s_msg
+    #[clap(verbatim_doc_comment, visible_alias = "c")]
+    Create(RoleCreateArgs),
+    /// Delete role with given ID
+    ///
+    /// The role ID can be specified as either a name or an ID.
+    /// The role is also unassigned from all the users having it.
+    ///
+    /// Examples:
+    ///  iggy role delete 1
+    ///  iggy role delete readers
+    #[clap(verbatim_doc_comment, visible_alias = "d")]
+    Delete(RoleDeleteArgs),
+    /// Get details of a single role with given ID
+    ///
+    /// The role ID can be specified as either a name or an ID
+    ///
+    /// Examples:
+    ///  iggy role get 1
+    ///  iggy role get readers
+    #[clap(verbatim_doc_comment, visible_alias = "g")]
+    Get(RoleGetArgs),
+    /// List all roles
+    ///
+    /// Examples:
+    ///  iggy role list
+    ///  iggy role list --list-mode table
+    ///  iggy role list -l table
+    #[clap(verbatim_doc_comment, visible_alias = "l")]
+    List(RoleListArgs),
+    /// Change name for role with given ID
+    ///
+    /// The role ID can be specified as either a name or an ID
+    ///
+    /// Examples:
+    ///  iggy role name 1 new_role_name
+    ///  iggy role name readers consumers
+    #[clap(verbatim_doc_comment, visible_alias = "n")]
+    Name(RoleNameArgs),
+    /// Set permissions for role with given ID
+    ///
+    /// The role ID can be specified as either a name or an ID. Permissions
+    /// are configured based on the options provided with this command. If no
+    /// options are set, all the permissions of the role are set to false.
+    /// The permissions are applied immediately to all the users having the role.
+    ///
+    /// Examples:
+    ///  iggy role permissions 1 -g r_str,r_top
+    ///  iggy role permissions readers -s 1:p_msg
+    #[clap(verbatim_doc_comment, visible_alias = "p")]
+    Permissions(RolePermissionsArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RoleCreateArgs {
+    /// Role name
+    ///
+    /// Unique name of the role, must be between 3 and 50 characters long.
+    #[clap(verbatim_doc_comment)]
+    pub(crate) name: String,
+    /// Set global permissions for created role
+    ///
+    /// All global permissions by default are set to false and this command line option
+    /// allows to set each permission individually. Permissions are separated
+    /// by comma and each permission is identified by the same name as in the iggy
+    /// SDK in iggy::models::permissions::GlobalPermissions struct. For each permission
+    /// there's long variant (same as in SDK) and short variant.
+    ///
+    /// Available permissions (long and short versions):  manage_servers / m_srv,
+    /// read_servers / r_srv, manage_users / m_usr, read_users / r_usr,
+    /// manage_streams / m_str, read_streams / r_str, manage_topics / m_top,
+    /// read_topics / r_top, poll_messages / p_msg, send_messages / s_msg
+    ///
+    /// Examples:
+    ///  iggy role create producers --global-permissions s_msg
+    ///  iggy role create admins -g m_srv,r_srv,m_usr,r_usr,m_str,r_str,m_top,r_top,p_msg,s_msg
+    #[clap(short, long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(GlobalPermissionsArg))]
+    pub(crate) global_permissions: Option<GlobalPermissionsArg>,
+    /// Set stream permissions for created role
+    ///
+    /// Stream permissions are defined by each stream separately, using the same
+    /// format as for the user permissions.
+    ///
+    /// Permissions format: STREAM_ID[:STREAM_PERMISSIONS][#TOPIC_ID[:TOPIC_PERMISSIONS]]
+    ///
+    /// Examples:
+    ///  iggy role create readers -s 1:read_stream,poll_messages
+    ///  iggy role create producers -s 3#1:s_msg#2:s_msg
+    #[clap(short, long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(StreamPermissionsArg))]
+    pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RoleDeleteArgs {
+    /// Role ID to delete
+    ///
+    /// The role ID can be specified as either a name or an ID
+    pub(crate) role_id: Identifier,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RoleGetArgs {
+    /// Role ID to get
+    ///
+    /// The role ID can be specified as either a name or an ID
+    pub(crate) role_id: Identifier,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RoleListArgs {
+    /// List mode (table or list)
+    #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
+    pub(crate) list_mode: ListMode,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RoleNameArgs {
+    /// Role ID to update
+    ///
+    /// The role ID can be specified as either a name or an ID
+    pub(crate) role_id: Identifier,
+    /// New name
+    ///
+    /// New and unique name of the role, must be between 3 and 50 characters long.
+    #[clap(verbatim_doc_comment)]
+    pub(crate) name: String,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RolePermissionsArgs {
+    /// Role ID to update
+    ///
+    /// The role ID can be specified as either a name or an ID
+    pub(crate) role_id: Identifier,
+    /// Set global permissions for the role
+    ///
+    /// Available permissions (long and short versions):  manage_servers / m_srv,
+    /// read_servers / r_srv, manage_users / m_usr, read_users / r_usr,
+    /// manage_streams / m_str, read_streams / r_str, manage_topics / m_top,
+    /// read_topics / r_top, poll_messages / p_msg, send_messages / s_msg
+    ///
+    /// Examples:
+    ///  iggy role permissions readers --global-permissions r_str,r_top,p_msg
+    #[clap(short, long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(GlobalPermissionsArg))]
+    pub(crate) global_permissions: Option<GlobalPermissionsArg>,
+    /// Set stream permissions for the role
+    ///
+    /// Permissions format: STREAM_ID[:STREAM_PERMISSIONS][#TOPIC_ID[:TOPIC_PERMISSIONS]]
+    ///
+    /// Examples:
+    ///  iggy role permissions readers -s 1:read_stream,poll_messages
+    #[clap(short, long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(StreamPermissionsArg))]
+    pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
+}
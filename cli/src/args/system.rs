@@ -1,3 +1,4 @@
+use crate::args::common::ListMode;
 use clap::Args;
 
 #[derive(Debug, Clone, Args)]
@@ -6,3 +7,16 @@ pub(crate) struct PingArgs {
     #[arg(short, long, default_value_t = 1)]
     pub(crate) count: u32,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct AuditLogArgs {
+    /// Maximum number of the latest entries to get
+    #[arg(short, long, default_value_t = 100)]
+    pub(crate) count: u32,
+    /// Get only the entries of the user with given ID
+    #[arg(short, long)]
+    pub(crate) user_id: Option<u32>,
+    /// List mode (table or list)
+    #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
+    pub(crate) list_mode: ListMode,
+}
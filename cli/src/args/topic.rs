@@ -1,7 +1,9 @@
 use crate::args::common::ListMode;
 use clap::{Args, Subcommand};
 use iggy::cli::utils::message_expiry::MessageExpiry;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::identifier::Identifier;
+use iggy::models::topic::{CleanupPolicy, MaxTopicSizePolicy};
 use iggy::utils::byte_size::IggyByteSize;
 use std::convert::From;
 
@@ -16,6 +18,7 @@ pub(crate) enum TopicAction {
     ///  iggy topic create 1 1 2 sensor1 15days
     ///  iggy topic create prod 2 2 sensor2
     ///  iggy topic create test 3 2 debugs 1day 1hour 1min 1sec
+    ///  iggy topic create -c lz4 prod 4 2 events
     #[clap(verbatim_doc_comment, visible_alias = "c")]
     Create(TopicCreateArgs),
     /// Delete topic with given ID in given stream ID
@@ -41,6 +44,7 @@ pub(crate) enum TopicAction {
     ///  iggy update test debugs ready 15days
     ///  iggy update 1 1 new-name
     ///  iggy update 1 2 new-name 1day 1hour 1min 1sec
+    ///  iggy update -c zstd prod sensor3 sensor3
     #[clap(verbatim_doc_comment, visible_alias = "u")]
     Update(TopicUpdateArgs),
     /// Get topic detail for given topic ID and stream ID
@@ -88,6 +92,36 @@ pub(crate) struct TopicCreateArgs {
     /// Replication factor for the topic
     #[arg(short, long, default_value = "1")]
     pub(crate) replication_factor: u8,
+    /// Compression algorithm for the topic (none, gzip, lz4, zstd or snappy)
+    ///
+    /// (skipping parameter uses the default algorithm configured on the server)
+    #[arg(short, long, verbatim_doc_comment)]
+    pub(crate) compression_algorithm: Option<CompressionAlgorithm>,
+    /// Cleanup policy for the topic (delete or compact)
+    ///
+    /// (compact keeps only the latest message for each message key in the closed segments)
+    #[arg(long, default_value = "delete", verbatim_doc_comment)]
+    pub(crate) cleanup_policy: CleanupPolicy,
+    /// Policy applied once the topic reaches max topic size (delete_oldest or reject)
+    ///
+    /// (reject fails appending the messages to the full topic instead of deleting the oldest segments)
+    #[arg(long, default_value = "delete_oldest", verbatim_doc_comment)]
+    pub(crate) max_topic_size_policy: MaxTopicSizePolicy,
+    /// Number of buffered messages after which the topic partitions are flushed to disk
+    ///
+    /// (skipping parameter uses the messages required to save configured on the server)
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) flush_messages_count: Option<u32>,
+    /// Size of buffered messages after which the topic partitions are flushed to disk
+    ///
+    /// (skipping parameter disables the size based flushing)
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) flush_size: Option<IggyByteSize>,
+    /// Max time in milliseconds the messages can stay buffered before being flushed to disk
+    ///
+    /// (skipping parameter relies only on the message saver interval configured on the server)
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) flush_max_latency: Option<u32>,
     /// Message expiry time in human readable format like 15days 2min 2s
     ///
     /// ("unlimited" or skipping parameter disables message expiry functionality in topic)
@@ -132,6 +166,42 @@ pub(crate) struct TopicUpdateArgs {
     #[arg(short, long, default_value = "1")]
     /// New replication factor for the topic
     pub(crate) replication_factor: u8,
+    /// New compression algorithm for the topic (none, gzip, lz4, zstd or snappy)
+    ///
+    /// (skipping parameter keeps the current algorithm of the topic)
+    /// Already stored messages remain compressed with the algorithm used when they were appended.
+    #[arg(short, long, verbatim_doc_comment)]
+    pub(crate) compression_algorithm: Option<CompressionAlgorithm>,
+    /// New cleanup policy for the topic (delete or compact)
+    ///
+    /// (compact keeps only the latest message for each message key in the closed segments)
+    /// (skipping parameter keeps the current cleanup policy of the topic)
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) cleanup_policy: Option<CleanupPolicy>,
+    /// New policy applied once the topic reaches max topic size (delete_oldest or reject)
+    ///
+    /// (reject fails appending the messages to the full topic instead of deleting the oldest segments)
+    /// (skipping parameter keeps the current policy of the topic)
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) max_topic_size_policy: Option<MaxTopicSizePolicy>,
+    /// New number of buffered messages after which the topic partitions are flushed to disk
+    ///
+    /// (skipping all the flush parameters keeps the current flush policy of the topic,
+    /// otherwise the skipped ones use the messages required to save configured on the server)
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) flush_messages_count: Option<u32>,
+    /// New size of buffered messages after which the topic partitions are flushed to disk
+    ///
+    /// (skipping all the flush parameters keeps the current flush policy of the topic,
+    /// otherwise the skipped ones disable the size based flushing)
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) flush_size: Option<IggyByteSize>,
+    /// New max time in milliseconds the messages can stay buffered before being flushed to disk
+    ///
+    /// (skipping all the flush parameters keeps the current flush policy of the topic,
+    /// otherwise the skipped ones rely only on the message saver interval configured on the server)
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) flush_max_latency: Option<u32>,
     /// New message expiry time in human readable format like 15days 2min 2s
     ///
     /// ("unlimited" or skipping parameter causes removal of expiry parameter in topic)
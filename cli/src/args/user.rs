@@ -83,6 +83,29 @@ pub(crate) enum UserAction {
     ///  iggy user permissions client
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Permissions(UserPermissionsArgs),
+    /// Assign roles to user with given ID
+    ///
+    /// The user ID and the role IDs can be specified as either a name or an ID.
+    /// The provided roles replace the ones assigned before, if no roles are
+    /// provided, all the roles are removed from the specified user.
+    ///
+    /// Examples:
+    ///  iggy user roles 2 readers producers
+    ///  iggy user roles testuser 1
+    ///  iggy user roles testuser
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    Roles(UserRolesArgs),
+    /// Unlock user with given ID
+    ///
+    /// Clears the lockout and the failed login attempts recorded for the user,
+    /// so that the user can log in again before the lockout expires.
+    /// The user ID can be specified as either a username or an ID.
+    ///
+    /// Examples:
+    ///  iggy user unlock 2
+    ///  iggy user unlock testuser
+    #[clap(verbatim_doc_comment, visible_alias = "u")]
+    Unlock(UserUnlockArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -286,3 +309,23 @@ pub(crate) struct UserPermissionsArgs {
     #[arg(value_parser = clap::value_parser!(StreamPermissionsArg))]
     pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct UserRolesArgs {
+    /// User ID to update
+    ///
+    /// The user ID can be specified as either a username or an ID
+    pub(crate) user_id: Identifier,
+    /// Role IDs to assign
+    ///
+    /// The role IDs can be specified as either a name or an ID
+    pub(crate) roles: Vec<Identifier>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct UserUnlockArgs {
+    /// User ID to unlock
+    ///
+    /// The user ID can be specified as either a username or an ID
+    pub(crate) user_id: Identifier,
+}
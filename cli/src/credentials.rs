@@ -22,6 +22,7 @@ struct IggyUserClient {
 enum Credentials {
     UserNameAndPassword(IggyUserClient),
     PersonalAccessToken(String),
+    ClientCertificate,
 }
 
 pub(crate) struct IggyCredentials<'a> {
@@ -95,6 +96,14 @@ impl<'a> IggyCredentials<'a> {
                 iggy_client: None,
                 login_required,
             })
+        } else if args.iggy.tcp_tls_certificate.is_some()
+            || args.iggy.quic_client_certificate.is_some()
+        {
+            Ok(Self {
+                credentials: Some(Credentials::ClientCertificate),
+                iggy_client: None,
+                login_required,
+            })
         } else {
             Err(IggyCmdError::CmdToolError(CmdToolError::MissingCredentials).into())
         }
@@ -133,6 +142,9 @@ impl<'a> IggyCredentials<'a> {
                                 format!("Problem with server login with token: {}", &token_value)
                             })?;
                     }
+                    Credentials::ClientCertificate => {
+                        // User mapped to the client certificate is logged in by the server during the TLS handshake
+                    }
                 }
             }
         }
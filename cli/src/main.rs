@@ -5,8 +5,8 @@ mod logging;
 
 use crate::args::{
     client::ClientAction, consumer_group::ConsumerGroupAction, permissions::PermissionsArgs,
-    personal_access_token::PersonalAccessTokenAction, stream::StreamAction, topic::TopicAction,
-    Command, IggyConsoleArgs,
+    personal_access_token::PersonalAccessTokenAction, role::RoleAction, stream::StreamAction,
+    topic::TopicAction, Command, IggyConsoleArgs,
 };
 use crate::credentials::IggyCredentials;
 use crate::error::IggyCmdError;
@@ -21,29 +21,43 @@ use iggy::cli::{
         create_consumer_group::CreateConsumerGroupCmd,
         delete_consumer_group::DeleteConsumerGroupCmd, get_consumer_group::GetConsumerGroupCmd,
         get_consumer_groups::GetConsumerGroupsCmd,
+        reset_consumer_group_offsets::ResetConsumerGroupOffsetsCmd,
     },
     message::{poll_messages::PollMessagesCmd, send_messages::SendMessagesCmd},
-    partitions::{create_partitions::CreatePartitionsCmd, delete_partitions::DeletePartitionsCmd},
+    partitions::{
+        create_partitions::CreatePartitionsCmd, delete_partitions::DeletePartitionsCmd,
+        get_offsets_for_timestamp::GetOffsetsForTimestampCmd,
+        get_partition_offsets::GetPartitionOffsetsCmd,
+    },
     personal_access_tokens::{
         create_personal_access_token::CreatePersonalAccessTokenCmd,
         delete_personal_access_tokens::DeletePersonalAccessTokenCmd,
         get_personal_access_tokens::GetPersonalAccessTokensCmd,
     },
+    roles::{
+        create_role::CreateRoleCmd,
+        delete_role::DeleteRoleCmd,
+        get_role::GetRoleCmd,
+        get_roles::GetRolesCmd,
+        update_role::{UpdateRoleCmd, UpdateRoleType},
+    },
     streams::{
         create_stream::CreateStreamCmd, delete_stream::DeleteStreamCmd, get_stream::GetStreamCmd,
         get_streams::GetStreamsCmd, update_stream::UpdateStreamCmd,
     },
-    system::{me::GetMeCmd, ping::PingCmd, stats::GetStatsCmd},
+    system::{audit_log::GetAuditLogCmd, me::GetMeCmd, ping::PingCmd, stats::GetStatsCmd},
     topics::{
         create_topic::CreateTopicCmd, delete_topic::DeleteTopicCmd, get_topic::GetTopicCmd,
         get_topics::GetTopicsCmd, update_topic::UpdateTopicCmd,
     },
     users::{
+        assign_roles::AssignRolesCmd,
         change_password::ChangePasswordCmd,
         create_user::CreateUserCmd,
         delete_user::DeleteUserCmd,
         get_user::GetUserCmd,
         get_users::GetUsersCmd,
+        unlock_user::UnlockUserCmd,
         update_permissions::UpdatePermissionsCmd,
         update_user::{UpdateUserCmd, UpdateUserType},
     },
@@ -52,6 +66,7 @@ use iggy::cli::{
 use iggy::cli_command::{CliCommand, PRINT_TARGET};
 use iggy::client_provider::{self, ClientProviderConfig};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::models::topic::FlushPolicy;
 use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
 use std::sync::Arc;
 use tracing::{event, Level};
@@ -80,6 +95,14 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 args.message_expiry.clone().into(),
                 args.max_topic_size,
                 args.replication_factor,
+                args.compression_algorithm,
+                args.cleanup_policy,
+                args.max_topic_size_policy,
+                FlushPolicy {
+                    messages_count: args.flush_messages_count,
+                    size: args.flush_size,
+                    max_latency: args.flush_max_latency,
+                },
             )),
             TopicAction::Delete(args) => Box::new(DeleteTopicCmd::new(
                 args.stream_id.clone(),
@@ -92,6 +115,21 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 args.message_expiry.clone().into(),
                 args.max_topic_size,
                 args.replication_factor,
+                args.compression_algorithm,
+                args.cleanup_policy,
+                args.max_topic_size_policy,
+                match (
+                    args.flush_messages_count,
+                    args.flush_size,
+                    args.flush_max_latency,
+                ) {
+                    (None, None, None) => None,
+                    (messages_count, size, max_latency) => Some(FlushPolicy {
+                        messages_count,
+                        size,
+                        max_latency,
+                    }),
+                },
             )),
             TopicAction::Get(args) => Box::new(GetTopicCmd::new(
                 args.stream_id.clone(),
@@ -113,10 +151,24 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 args.topic_id.clone(),
                 args.partitions_count,
             )),
+            PartitionAction::Offsets(args) => Box::new(GetPartitionOffsetsCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+            )),
+            PartitionAction::OffsetsForTimestamp(args) => Box::new(GetOffsetsForTimestampCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.timestamp,
+            )),
         },
         Command::Ping(args) => Box::new(PingCmd::new(args.count)),
         Command::Me => Box::new(GetMeCmd::new()),
         Command::Stats => Box::new(GetStatsCmd::new()),
+        Command::AuditLog(args) => Box::new(GetAuditLogCmd::new(
+            args.count,
+            args.user_id,
+            args.list_mode.into(),
+        )),
         Command::Pat(command) => match command {
             PersonalAccessTokenAction::Create(pat_create_args) => {
                 Box::new(CreatePersonalAccessTokenCmd::new(
@@ -174,6 +226,42 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 )
                 .into(),
             )),
+            UserAction::Roles(roles_args) => Box::new(AssignRolesCmd::new(
+                roles_args.user_id.clone(),
+                roles_args.roles.clone(),
+            )),
+            UserAction::Unlock(unlock_args) => {
+                Box::new(UnlockUserCmd::new(unlock_args.user_id.clone()))
+            }
+        },
+        Command::Role(command) => match command {
+            RoleAction::Create(create_args) => Box::new(CreateRoleCmd::new(
+                create_args.name.clone(),
+                Option::from(PermissionsArgs::new(
+                    create_args.global_permissions.clone(),
+                    create_args.stream_permissions.clone(),
+                ))
+                .unwrap_or_default(),
+            )),
+            RoleAction::Delete(delete_args) => {
+                Box::new(DeleteRoleCmd::new(delete_args.role_id.clone()))
+            }
+            RoleAction::Get(get_args) => Box::new(GetRoleCmd::new(get_args.role_id.clone())),
+            RoleAction::List(list_args) => Box::new(GetRolesCmd::new(list_args.list_mode.into())),
+            RoleAction::Name(name_args) => Box::new(UpdateRoleCmd::new(
+                name_args.role_id.clone(),
+                UpdateRoleType::Name(name_args.name.clone()),
+            )),
+            RoleAction::Permissions(permissions_args) => Box::new(UpdateRoleCmd::new(
+                permissions_args.role_id.clone(),
+                UpdateRoleType::Permissions(
+                    Option::from(PermissionsArgs::new(
+                        permissions_args.global_permissions.clone(),
+                        permissions_args.stream_permissions.clone(),
+                    ))
+                    .unwrap_or_default(),
+                ),
+            )),
         },
         Command::Client(command) => match command {
             ClientAction::Get(get_args) => Box::new(GetClientCmd::new(get_args.client_id)),
@@ -187,6 +275,10 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 create_args.topic_id.clone(),
                 create_args.consumer_group_id,
                 create_args.name.clone(),
+                create_args
+                    .session_timeout
+                    .map(|session_timeout| session_timeout.as_secs()),
+                create_args.assignment_strategy,
             )),
             ConsumerGroupAction::Delete(delete_args) => Box::new(DeleteConsumerGroupCmd::new(
                 delete_args.stream_id.clone(),
@@ -203,6 +295,16 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 list_args.topic_id.clone(),
                 list_args.list_mode.into(),
             )),
+            ConsumerGroupAction::ResetOffsets(reset_args) => {
+                Box::new(ResetConsumerGroupOffsetsCmd::new(
+                    reset_args.stream_id.clone(),
+                    reset_args.topic_id.clone(),
+                    reset_args.consumer_group_id.clone(),
+                    reset_args.partitions.clone(),
+                    reset_args.strategy(),
+                    reset_args.dry_run,
+                ))
+            }
         },
         Command::Message(command) => match command {
             MessageAction::Send(send_args) => Box::new(SendMessagesCmd::new(
@@ -210,6 +312,7 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 send_args.topic_id.clone(),
                 send_args.partition_id,
                 send_args.message_key.clone(),
+                send_args.ack,
                 send_args.messages.clone(),
             )),
             MessageAction::Poll(poll_args) => Box::new(PollMessagesCmd::new(
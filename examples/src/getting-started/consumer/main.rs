@@ -73,6 +73,8 @@ async fn consume_messages(client: &dyn Client) -> Result<(), Box<dyn Error>> {
                 strategy: PollingStrategy::offset(offset),
                 count: messages_per_batch,
                 auto_commit: false,
+                skip_poisoned: false,
+                generation: None,
             })
             .await?;
         if polled_messages.messages.is_empty() {
@@ -1,7 +1,8 @@
 use iggy::client::{Client, StreamClient, TopicClient, UserClient};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::identifier::Identifier;
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::streams::create_stream::CreateStream;
 use iggy::tcp::client::TcpClient;
 use iggy::tcp::config::TcpClientConfig;
@@ -68,6 +69,10 @@ async fn init_system(client: &IggyClient) {
             message_expiry: None,
             max_topic_size: None,
             replication_factor: 1,
+            compression_algorithm: None,
+            cleanup_policy: CleanupPolicy::Delete,
+            max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+            flush_policy: FlushPolicy::default(),
         })
         .await
     {
@@ -107,6 +112,7 @@ async fn produce_messages(client: &dyn Client) -> Result<(), Box<dyn Error>> {
                 stream_id: Identifier::numeric(STREAM_ID)?,
                 topic_id: Identifier::numeric(TOPIC_ID)?,
                 partitioning: Partitioning::partition_id(PARTITION_ID),
+                ack: AckLevel::InMemory,
                 messages,
             })
             .await?;
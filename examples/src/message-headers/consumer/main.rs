@@ -27,6 +27,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
             poll_messages: PollMessagesConfig {
                 interval: args.interval,
                 store_offset_kind: StoreOffsetKind::WhenMessagesAreProcessed,
+                ..Default::default()
             },
             ..Default::default()
         })
@@ -5,6 +5,7 @@ use iggy::error::Error;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
 use iggy::models::messages::Message;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::get_stream::GetStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -92,6 +93,10 @@ pub async fn init_by_producer(args: &Args, client: &dyn Client) -> Result<(), Er
             message_expiry: None,
             max_topic_size: None,
             replication_factor: 1,
+            compression_algorithm: None,
+            cleanup_policy: CleanupPolicy::Delete,
+            max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+            flush_policy: FlushPolicy::default(),
         })
         .await?;
     Ok(())
@@ -125,6 +130,8 @@ pub async fn consume_messages(
                 strategy: PollingStrategy::next(),
                 count: args.messages_per_batch,
                 auto_commit: true,
+                skip_poisoned: false,
+                generation: None,
             })
             .await?;
         if polled_messages.messages.is_empty() {
@@ -5,6 +5,7 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -86,6 +87,10 @@ impl IggyCmdTestCase for TestConsumerGroupCreateCmd {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                compression_algorithm: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+                flush_policy: FlushPolicy::default(),
             })
             .await;
         assert!(topic.is_ok());
@@ -221,8 +226,10 @@ Examples:
  iggy consumer-group create stream 2 2 test
  iggy consumer-group create 2 topic 3 receiver
  iggy consumer-group create stream topic 4 group
+ iggy consumer-group create 1 1 5 workers --session-timeout 10s
+ iggy consumer-group create 1 1 6 cache --assignment-strategy sticky
 
-{USAGE_PREFIX} consumer-group create <STREAM_ID> <TOPIC_ID> <CONSUMER_GROUP_ID> <NAME>
+{USAGE_PREFIX} consumer-group create [OPTIONS] <STREAM_ID> <TOPIC_ID> <CONSUMER_GROUP_ID> <NAME>
 
 Arguments:
   <STREAM_ID>
@@ -242,6 +249,18 @@ Arguments:
           Consumer group name to create
 
 Options:
+  -s, --session-timeout <SESSION_TIMEOUT>
+          Session timeout of the consumer group members in human readable format like 10s or 1min
+{CLAP_INDENT}
+          (skipping parameter uses the default session timeout configured on the server)
+
+  -a, --assignment-strategy <ASSIGNMENT_STRATEGY>
+          Strategy used to assign the partitions to the members (round_robin, range or sticky)
+{CLAP_INDENT}
+          (sticky strategy keeps the current assignment of the members as much as possible on membership change)
+{CLAP_INDENT}
+          [default: round_robin]
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -261,7 +280,7 @@ pub async fn should_short_help_match() {
             format!(
                 r#"Create consumer group with given ID and name for given stream ID and topic ID.
 
-{USAGE_PREFIX} consumer-group create <STREAM_ID> <TOPIC_ID> <CONSUMER_GROUP_ID> <NAME>
+{USAGE_PREFIX} consumer-group create [OPTIONS] <STREAM_ID> <TOPIC_ID> <CONSUMER_GROUP_ID> <NAME>
 
 Arguments:
   <STREAM_ID>          Stream ID to create consumer group
@@ -270,7 +289,12 @@ Arguments:
   <NAME>               Consumer group name to create
 
 Options:
-  -h, --help  Print help (see more with '--help')
+  -s, --session-timeout <SESSION_TIMEOUT>
+          Session timeout of the consumer group members in human readable format like 10s or 1min
+  -a, --assignment-strategy <ASSIGNMENT_STRATEGY>
+          Strategy used to assign the partitions to the members (round_robin, range or sticky) [default: round_robin]
+  -h, --help
+          Print help (see more with '--help')
 "#,
             ),
         ))
@@ -6,6 +6,8 @@ use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
+use iggy::models::consumer_group::PartitionAssignmentStrategy;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -89,6 +91,10 @@ impl IggyCmdTestCase for TestConsumerGroupListCmd {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                compression_algorithm: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+                flush_policy: FlushPolicy::default(),
             })
             .await;
         assert!(topic.is_ok());
@@ -99,6 +105,8 @@ impl IggyCmdTestCase for TestConsumerGroupListCmd {
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
                 consumer_group_id: self.consumer_group_id,
                 name: self.consumer_group_name.clone(),
+                session_timeout: None,
+                assignment_strategy: PartitionAssignmentStrategy::RoundRobin,
             })
             .await;
         assert!(consumer_group.is_ok());
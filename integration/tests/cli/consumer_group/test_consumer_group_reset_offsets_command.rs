@@ -0,0 +1,437 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestConsumerGroupId, TestHelpCmd, TestStreamId,
+    TestTopicId, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::reset_consumer_group_offsets::{
+    OffsetsResetStrategy, ResetConsumerGroupOffsets,
+};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
+use iggy::models::consumer_group::PartitionAssignmentStrategy;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::str::FromStr;
+
+struct TestConsumerGroupResetOffsetsCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    consumer_group_id: u32,
+    consumer_group_name: String,
+    messages_count: u32,
+    strategy: OffsetsResetStrategy,
+    dry_run: bool,
+    expected_offset: u64,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+    using_consumer_group_id: TestConsumerGroupId,
+}
+
+impl TestConsumerGroupResetOffsetsCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        consumer_group_id: u32,
+        consumer_group_name: String,
+        messages_count: u32,
+        strategy: OffsetsResetStrategy,
+        dry_run: bool,
+        expected_offset: u64,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+        using_consumer_group_id: TestConsumerGroupId,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            consumer_group_id,
+            consumer_group_name,
+            messages_count,
+            strategy,
+            dry_run,
+            expected_offset,
+            using_stream_id,
+            using_topic_id,
+            using_consumer_group_id,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = match self.using_stream_id {
+            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
+            TestStreamId::Named => vec![self.stream_name.clone()],
+        };
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        command.push(match self.using_consumer_group_id {
+            TestConsumerGroupId::Numeric => format!("{}", self.consumer_group_id),
+            TestConsumerGroupId::Named => self.consumer_group_name.clone(),
+        });
+
+        command.extend(match self.strategy {
+            OffsetsResetStrategy::Earliest => vec![String::from("--earliest")],
+            OffsetsResetStrategy::Latest => vec![String::from("--latest")],
+            OffsetsResetStrategy::Offset(offset) => {
+                vec![String::from("--offset"), format!("{offset}")]
+            }
+            OffsetsResetStrategy::Timestamp(timestamp) => {
+                vec![String::from("--timestamp"), format!("{timestamp}")]
+            }
+            OffsetsResetStrategy::Shift(shift) => vec![String::from("--shift"), format!("{shift}")],
+        });
+
+        command.extend(vec![String::from("--partitions"), String::from("1")]);
+
+        if self.dry_run {
+            command.push(String::from("--dry-run"));
+        }
+
+        command
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestConsumerGroupResetOffsetsCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: self.stream_id,
+                name: self.stream_name.clone(),
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: self.topic_id,
+                partitions_count: 2,
+                name: self.topic_name.clone(),
+                message_expiry: None,
+                max_topic_size: None,
+                replication_factor: 1,
+                compression_algorithm: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+                flush_policy: FlushPolicy::default(),
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let consumer_group = client
+            .create_consumer_group(&CreateConsumerGroup {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                consumer_group_id: self.consumer_group_id,
+                name: self.consumer_group_name.clone(),
+                session_timeout: None,
+                assignment_strategy: PartitionAssignmentStrategy::RoundRobin,
+            })
+            .await;
+        assert!(consumer_group.is_ok());
+
+        let messages = (0..self.messages_count)
+            .filter_map(|id| Message::from_str(&format!("message {id}")).ok())
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(&mut SendMessages {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                partitioning: Partitioning::partition_id(1),
+                ack: AckLevel::InMemory,
+                messages,
+            })
+            .await;
+        assert!(send_status.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("consumer-group")
+            .arg("reset-offsets")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let consumer_group_id = match self.using_consumer_group_id {
+            TestConsumerGroupId::Numeric => format!("{}", self.consumer_group_id),
+            TestConsumerGroupId::Named => self.consumer_group_name.clone(),
+        };
+
+        let dry_run = if self.dry_run { "dry run of " } else { "" };
+        let message = format!("Executing {dry_run}reset offsets ({}) of consumer group with ID: {} for partitions: 1 of topic with ID: {} and stream with ID: {}\n",
+            self.strategy, consumer_group_id, topic_id, stream_id);
+        let summary = match self.dry_run {
+            true => format!("Dry run, offsets of consumer group with ID: {} for topic with ID: {} and stream with ID: {} were not changed\n",
+                consumer_group_id, topic_id, stream_id),
+            false => format!("Offsets of consumer group with ID: {} reset for topic with ID: {} and stream with ID: {}\n",
+                consumer_group_id, topic_id, stream_id),
+        };
+
+        command_state
+            .success()
+            .stdout(starts_with(message))
+            .stdout(contains(format!(
+                "| 1            | 0               | {: <10} |",
+                self.expected_offset
+            )))
+            .stdout(contains(summary));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let offsets = client
+            .reset_consumer_group_offsets(&ResetConsumerGroupOffsets {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                consumer_group_id: Identifier::numeric(self.consumer_group_id).unwrap(),
+                partition_ids: vec![],
+                strategy: OffsetsResetStrategy::Earliest,
+                dry_run: true,
+            })
+            .await;
+        assert!(offsets.is_ok());
+        let offsets = offsets.unwrap();
+        assert_eq!(offsets.len(), 2);
+        assert_eq!(offsets[0].partition_id, 1);
+        if self.dry_run {
+            assert_eq!(offsets[0].previous_offset, 0);
+        } else {
+            assert_eq!(offsets[0].previous_offset, self.expected_offset);
+        }
+        assert_eq!(offsets[1].partition_id, 2);
+        assert_eq!(offsets[1].previous_offset, 0);
+
+        let topic = client
+            .delete_topic(&DeleteTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&DeleteStream {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestConsumerGroupResetOffsetsCmd::new(
+            1,
+            String::from("main"),
+            1,
+            String::from("sync"),
+            1,
+            String::from("group"),
+            10,
+            OffsetsResetStrategy::Latest,
+            false,
+            10,
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+            TestConsumerGroupId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestConsumerGroupResetOffsetsCmd::new(
+            2,
+            String::from("stream"),
+            3,
+            String::from("topic"),
+            2,
+            String::from("consumers"),
+            10,
+            OffsetsResetStrategy::Offset(4),
+            true,
+            4,
+            TestStreamId::Named,
+            TestTopicId::Named,
+            TestConsumerGroupId::Named,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestConsumerGroupResetOffsetsCmd::new(
+            4,
+            String::from("development"),
+            1,
+            String::from("probe"),
+            3,
+            String::from("workers"),
+            5,
+            OffsetsResetStrategy::Shift(3),
+            false,
+            3,
+            TestStreamId::Numeric,
+            TestTopicId::Named,
+            TestConsumerGroupId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestConsumerGroupResetOffsetsCmd::new(
+            5,
+            String::from("production"),
+            2,
+            String::from("test"),
+            4,
+            String::from("readers"),
+            5,
+            OffsetsResetStrategy::Shift(-3),
+            false,
+            0,
+            TestStreamId::Named,
+            TestTopicId::Numeric,
+            TestConsumerGroupId::Named,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-group", "reset-offsets", "--help"],
+            format!(
+                r#"Reset offsets of consumer group with given ID for all or selected partitions
+of given stream ID and topic ID
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+Consumer group ID can be specified as a consumer group name or ID
+Offsets are the offsets of the next messages to be polled by the consumer group
+
+Examples:
+ iggy consumer-group reset-offsets 1 1 1 --earliest
+ iggy consumer-group reset-offsets stream topic group --latest --partitions 1,2
+ iggy consumer-group reset-offsets 1 1 group --offset 100 --dry-run
+ iggy consumer-group reset-offsets 1 topic 1 --timestamp 1700000000000000
+ iggy consumer-group reset-offsets stream 1 1 --shift -10 --dry-run
+
+{USAGE_PREFIX} consumer-group reset-offsets [OPTIONS] <--earliest|--latest|--offset <OFFSET>|--timestamp <TIMESTAMP>|--shift <SHIFT>> <STREAM_ID> <TOPIC_ID> <CONSUMER_GROUP_ID>
+
+Arguments:
+  <STREAM_ID>
+          Stream ID to reset consumer group offsets
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID to reset consumer group offsets
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <CONSUMER_GROUP_ID>
+          Consumer group ID to reset offsets
+{CLAP_INDENT}
+          Consumer group ID can be specified as a consumer group name or ID
+
+Options:
+  -p, --partitions <PARTITIONS>
+          Comma separated list of partition IDs to reset offsets
+{CLAP_INDENT}
+          (skipping parameter resets offsets for all partitions of the topic)
+
+  -e, --earliest
+          Reset strategy - reset to the first message still stored in the partition
+
+  -l, --latest
+          Reset strategy - reset after the last message, so only new messages are polled
+
+  -o, --offset <OFFSET>
+          Reset strategy - reset to the message with given offset
+
+  -t, --timestamp <TIMESTAMP>
+          Reset strategy - reset to the first message appended at or after
+          given timestamp in microseconds since Unix epoch
+
+  -s, --shift <SHIFT>
+          Reset strategy - shift current offset by given number of messages
+{CLAP_INDENT}
+          (negative value rewinds the consumer group)
+
+  -d, --dry-run
+          Print offsets before and after reset without changing them
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-group", "reset-offsets", "-h"],
+            format!(
+                r#"Reset offsets of consumer group with given ID for all or selected partitions
+of given stream ID and topic ID
+
+{USAGE_PREFIX} consumer-group reset-offsets [OPTIONS] <--earliest|--latest|--offset <OFFSET>|--timestamp <TIMESTAMP>|--shift <SHIFT>> <STREAM_ID> <TOPIC_ID> <CONSUMER_GROUP_ID>
+
+Arguments:
+  <STREAM_ID>          Stream ID to reset consumer group offsets
+  <TOPIC_ID>           Topic ID to reset consumer group offsets
+  <CONSUMER_GROUP_ID>  Consumer group ID to reset offsets
+
+Options:
+  -p, --partitions <PARTITIONS>  Comma separated list of partition IDs to reset offsets
+  -e, --earliest                 Reset strategy - reset to the first message still stored in the partition
+  -l, --latest                   Reset strategy - reset after the last message, so only new messages are polled
+  -o, --offset <OFFSET>          Reset strategy - reset to the message with given offset
+  -t, --timestamp <TIMESTAMP>    Reset strategy - reset to the first message appended at or after
+                                 given timestamp in microseconds since Unix epoch
+  -s, --shift <SHIFT>            Reset strategy - shift current offset by given number of messages
+  -d, --dry-run                  Print offsets before and after reset without changing them
+  -h, --help                     Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
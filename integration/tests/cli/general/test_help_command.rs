@@ -21,8 +21,10 @@ Commands:
   ping            ping iggy server
   me              get current client info
   stats           get iggy server statistics
+  audit-log       get iggy server audit log
   pat             personal access token operations
   user            user operations [aliases: u]
+  role            role operations [aliases: r]
   client          client operations [aliases: c]
   consumer-group  consumer group operations [aliases: g]
   message         message operations [aliases: m]
@@ -72,6 +74,12 @@ Options:
 {CLAP_INDENT}
           [default: localhost]
 
+      --tcp-tls-certificate <TCP_TLS_CERTIFICATE>
+          The optional path to the PEM client certificate for the mutual TLS authentication over TCP
+
+      --tcp-tls-key <TCP_TLS_KEY>
+          The optional path to the PEM private key of the client certificate for TCP
+
       --quic-client-address <QUIC_CLIENT_ADDRESS>
           The optional client address for the QUIC transport
 {CLAP_INDENT}
@@ -140,6 +148,12 @@ Options:
       --quic-validate-certificate
           Flag to enable certificate validation for QUIC
 
+      --quic-client-certificate <QUIC_CLIENT_CERTIFICATE>
+          The optional path to the PEM client certificate for the mutual TLS authentication over QUIC
+
+      --quic-client-key <QUIC_CLIENT_KEY>
+          The optional path to the PEM private key of the client certificate for QUIC
+
   -q, --quiet
           Quiet mode (disabled stdout printing)
 
@@ -6,6 +6,7 @@ use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::consumer::Consumer;
 use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -143,6 +144,10 @@ impl IggyCmdTestCase for TestMessageSendCmd {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                compression_algorithm: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+                flush_policy: FlushPolicy::default(),
             })
             .await;
         assert!(topic.is_ok());
@@ -200,6 +205,8 @@ impl IggyCmdTestCase for TestMessageSendCmd {
                 strategy: PollingStrategy::offset(0),
                 count: self.messages.len() as u32,
                 auto_commit: false,
+                skip_poisoned: false,
+                generation: None,
             })
             .await;
 
@@ -386,6 +393,13 @@ Options:
 {CLAP_INDENT}
           Value of the key will be used by the server to calculate the partition ID
 
+  -a, --ack <ACK>
+          Acknowledgement level of the sent messages (none, in_memory or persisted)
+{CLAP_INDENT}
+          (persisted waits until the messages are written and synced on disk by the server)
+{CLAP_INDENT}
+          [default: in_memory]
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -415,6 +429,7 @@ Arguments:
 Options:
   -p, --partition-id <PARTITION_ID>  ID of the partition to which the message will be sent
   -m, --message-key <MESSAGE_KEY>    Messages key which will be used to partition the messages
+  -a, --ack <ACK>                    Acknowledgement level of the sent messages (none, in_memory or persisted) [default: in_memory]
   -h, --help                         Print help (see more with '--help')
 "#,
             ),
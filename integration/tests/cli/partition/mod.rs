@@ -1,3 +1,5 @@
 mod test_partition_create_command;
 mod test_partition_delete_command;
 mod test_partition_help_command;
+mod test_partition_offsets_command;
+mod test_partition_offsets_for_timestamp_command;
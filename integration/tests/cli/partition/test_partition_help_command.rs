@@ -15,11 +15,16 @@ pub async fn should_help_match() {
 {USAGE_PREFIX} partition <COMMAND>
 
 Commands:
-  create  Create partitions for the specified topic ID
-              and stream ID based on the given count. [aliases: c]
-  delete  Delete partitions for the specified topic ID
-              and stream ID based on the given count. [aliases: d]
-  help    Print this message or the help of the given subcommand(s)
+  create                 Create partitions for the specified topic ID
+                             and stream ID based on the given count. [aliases: c]
+  delete                 Delete partitions for the specified topic ID
+                             and stream ID based on the given count. [aliases: d]
+  offsets                Get the earliest and latest offsets of each partition
+                             for the specified topic ID and stream ID. [aliases: o]
+  offsets-for-timestamp  Get the offset of the first message appended at or after
+                             the given timestamp in each partition for the specified
+                             topic ID and stream ID. [aliases: t]
+  help                   Print this message or the help of the given subcommand(s)
 
 Options:
   -h, --help  Print help
@@ -0,0 +1,278 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::str::FromStr;
+
+struct TestPartitionOffsetsCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    partitions_count: u32,
+    messages_count: u32,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+}
+
+impl TestPartitionOffsetsCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        partitions_count: u32,
+        messages_count: u32,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partitions_count,
+            messages_count,
+            using_stream_id,
+            using_topic_id,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = match self.using_stream_id {
+            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
+            TestStreamId::Named => vec![self.stream_name.clone()],
+        };
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        command
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestPartitionOffsetsCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: self.stream_id,
+                name: self.stream_name.clone(),
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: self.topic_id,
+                partitions_count: self.partitions_count,
+                name: self.topic_name.clone(),
+                message_expiry: None,
+                max_topic_size: None,
+                replication_factor: 1,
+                compression_algorithm: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+                flush_policy: FlushPolicy::default(),
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let messages = (0..self.messages_count)
+            .filter_map(|id| Message::from_str(&format!("message {id}")).ok())
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(&mut SendMessages {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                partitioning: Partitioning::partition_id(1),
+                ack: AckLevel::InMemory,
+                messages,
+            })
+            .await;
+        assert!(send_status.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("partition")
+            .arg("offsets")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let message = format!(
+            "Executing get partition offsets for topic with ID: {} and stream with ID: {}\n",
+            topic_id, stream_id
+        );
+
+        let status = command_state
+            .success()
+            .stdout(starts_with(message))
+            .stdout(contains(format!(
+                "| 1            | 0               | {: <13} |",
+                self.messages_count - 1
+            )));
+        (2..=self.partitions_count).fold(status, |status, partition_id| {
+            status.stdout(contains(format!(
+                "| {: <12} | 0               | 0             |",
+                partition_id
+            )))
+        });
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic = client
+            .delete_topic(&DeleteTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&DeleteStream {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestPartitionOffsetsCmd::new(
+            1,
+            String::from("main"),
+            1,
+            String::from("sync"),
+            1,
+            10,
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestPartitionOffsetsCmd::new(
+            2,
+            String::from("stream"),
+            3,
+            String::from("topic"),
+            3,
+            5,
+            TestStreamId::Named,
+            TestTopicId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestPartitionOffsetsCmd::new(
+            4,
+            String::from("development"),
+            1,
+            String::from("probe"),
+            2,
+            1,
+            TestStreamId::Numeric,
+            TestTopicId::Named,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["partition", "offsets", "--help"],
+            format!(
+                r#"Get the earliest and latest offsets of each partition
+for the specified topic ID and stream ID.
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples
+ iggy partition offsets 1 1
+ iggy partition offsets prod sensor
+
+{USAGE_PREFIX} partition offsets <STREAM_ID> <TOPIC_ID>
+
+Arguments:
+  <STREAM_ID>
+          Stream ID to get partition offsets
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID to get partition offsets
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["partition", "offsets", "-h"],
+            format!(
+                r#"Get the earliest and latest offsets of each partition
+for the specified topic ID and stream ID.
+
+{USAGE_PREFIX} partition offsets <STREAM_ID> <TOPIC_ID>
+
+Arguments:
+  <STREAM_ID>  Stream ID to get partition offsets
+  <TOPIC_ID>   Topic ID to get partition offsets
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
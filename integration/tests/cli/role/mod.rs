@@ -0,0 +1,3 @@
+mod test_role_create_command;
+mod test_role_delete_command;
+mod test_role_help_command;
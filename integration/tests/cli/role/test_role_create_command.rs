@@ -0,0 +1,157 @@
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::identifier::Identifier;
+use iggy::models::permissions::{GlobalPermissions, Permissions, StreamPermissions};
+use iggy::roles::get_role::GetRole;
+use predicates::str::diff;
+use serial_test::parallel;
+use std::collections::HashMap;
+
+struct TestRoleCreateCmd {
+    name: String,
+    global_permissions: Option<String>,
+    stream_permissions: Vec<String>,
+    expected_permissions: Permissions,
+}
+
+impl TestRoleCreateCmd {
+    fn new(
+        name: String,
+        global_permissions: Option<String>,
+        stream_permissions: Vec<String>,
+        expected_permissions: Permissions,
+    ) -> Self {
+        Self {
+            name,
+            global_permissions,
+            stream_permissions,
+            expected_permissions,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut args = vec![self.name.clone()];
+        if let Some(global_permissions) = &self.global_permissions {
+            args.push(String::from("--global-permissions"));
+            args.push(global_permissions.clone());
+        }
+
+        for stream_permissions in &self.stream_permissions {
+            args.push(String::from("--stream-permissions"));
+            args.push(stream_permissions.clone());
+        }
+
+        args
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestRoleCreateCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("role")
+            .arg("create")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing create role with name: {}\nRole with name: {} created\n",
+            self.name, self.name
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let role = client
+            .get_role(&GetRole {
+                role_id: Identifier::named(self.name.as_str()).unwrap(),
+            })
+            .await;
+        assert!(role.is_ok());
+        let role = role.unwrap();
+        assert_eq!(role.name, self.name);
+        assert_eq!(role.permissions, self.expected_permissions);
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestRoleCreateCmd::new(
+            String::from("readers"),
+            Some(String::from("r_str,r_top,p_msg")),
+            vec![],
+            Permissions {
+                global: GlobalPermissions {
+                    read_streams: true,
+                    read_topics: true,
+                    poll_messages: true,
+                    ..Default::default()
+                },
+                streams: None,
+            },
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestRoleCreateCmd::new(
+            String::from("producers"),
+            None,
+            vec![String::from("2:s_msg")],
+            Permissions {
+                global: GlobalPermissions::default(),
+                streams: Some(HashMap::from([(
+                    2u32,
+                    StreamPermissions {
+                        send_messages: true,
+                        ..Default::default()
+                    },
+                )])),
+            },
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestRoleCreateCmd::new(
+            String::from("nobody"),
+            None,
+            vec![],
+            Permissions::default(),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["role", "create", "-h"],
+            format!(
+                r#"Create role with given name and permissions
+
+{USAGE_PREFIX} role create [OPTIONS] <NAME>
+
+Arguments:
+  <NAME>  Role name
+
+Options:
+  -g, --global-permissions <GLOBAL_PERMISSIONS>  Set global permissions for created role
+  -s, --stream-permissions <STREAM_PERMISSIONS>  Set stream permissions for created role
+  -h, --help                                     Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,157 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestRoleId, CLAP_INDENT,
+    USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::identifier::Identifier;
+use iggy::roles::create_role::CreateRole;
+use iggy::roles::get_role::GetRole;
+use iggy::roles::get_roles::GetRoles;
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestRoleDeleteCmd {
+    name: String,
+    role_id: Option<u32>,
+    using_identifier: TestRoleId,
+}
+
+impl TestRoleDeleteCmd {
+    fn new(name: String, using_identifier: TestRoleId) -> Self {
+        Self {
+            name,
+            role_id: None,
+            using_identifier,
+        }
+    }
+
+    fn to_arg(&self) -> String {
+        match self.using_identifier {
+            TestRoleId::Named => self.name.clone(),
+            TestRoleId::Numeric => format!("{}", self.role_id.unwrap()),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestRoleDeleteCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let create_role = client
+            .create_role(&CreateRole {
+                name: self.name.clone(),
+                ..Default::default()
+            })
+            .await;
+        assert!(create_role.is_ok());
+        let role = client
+            .get_role(&GetRole {
+                role_id: Identifier::named(self.name.as_str()).unwrap(),
+            })
+            .await;
+        assert!(role.is_ok());
+        self.role_id = Some(role.unwrap().id);
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("role")
+            .arg("delete")
+            .arg(self.to_arg())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let role_id = self.to_arg();
+        let message =
+            format!("Executing delete role with ID: {role_id}\nRole with ID: {role_id} deleted\n");
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let roles = client.get_roles(&GetRoles {}).await;
+        assert!(roles.is_ok());
+        assert!(roles.unwrap().is_empty());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestRoleDeleteCmd::new(
+            String::from("readers"),
+            TestRoleId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestRoleDeleteCmd::new(
+            String::from("producers"),
+            TestRoleId::Named,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["role", "delete", "--help"],
+            format!(
+                r"Delete role with given ID
+
+The role ID can be specified as either a name or an ID.
+The role is also unassigned from all the users having it.
+
+Examples:
+ iggy role delete 1
+ iggy role delete readers
+
+{USAGE_PREFIX} role delete <ROLE_ID>
+
+Arguments:
+  <ROLE_ID>
+          Role ID to delete
+{CLAP_INDENT}
+          The role ID can be specified as either a name or an ID
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+",
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["role", "delete", "-h"],
+            format!(
+                r#"Delete role with given ID
+
+{USAGE_PREFIX} role delete <ROLE_ID>
+
+Arguments:
+  <ROLE_ID>  Role ID to delete
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
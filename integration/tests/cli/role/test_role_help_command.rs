@@ -0,0 +1,32 @@
+use crate::cli::common::{help::TestHelpCmd, IggyCmdTest, USAGE_PREFIX};
+use serial_test::parallel;
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["role", "help"],
+            format!(
+                r#"role operations
+
+{USAGE_PREFIX} role <COMMAND>
+
+Commands:
+  create       Create role with given name and permissions [aliases: c]
+  delete       Delete role with given ID [aliases: d]
+  get          Get details of a single role with given ID [aliases: g]
+  list         List all roles [aliases: l]
+  name         Change name for role with given ID [aliases: n]
+  permissions  Set permissions for role with given ID [aliases: p]
+  help         Print this message or the help of the given subcommand(s)
+
+Options:
+  -h, --help  Print help
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,218 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::streams::create_stream::CreateStream;
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+enum TestAuditLogOutput {
+    Table,
+    List,
+}
+
+struct TestAuditLogCmd {
+    stream_id: u32,
+    stream_name: String,
+    user_id: Option<u32>,
+    output: TestAuditLogOutput,
+}
+
+impl TestAuditLogCmd {
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        user_id: Option<u32>,
+        output: TestAuditLogOutput,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            user_id,
+            output,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "audit-log".to_string(),
+            "--count".to_string(),
+            "1000".to_string(),
+        ];
+        if let Some(user_id) = self.user_id {
+            args.push("--user-id".to_string());
+            args.push(format!("{user_id}"));
+        }
+        args.push("--list-mode".to_string());
+        args.push(
+            match self.output {
+                TestAuditLogOutput::Table => "table",
+                TestAuditLogOutput::List => "list",
+            }
+            .to_string(),
+        );
+        args
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestAuditLogCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: self.stream_id,
+                name: self.stream_name.clone(),
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: self.stream_id,
+                name: format!("{}-duplicate", self.stream_name),
+            })
+            .await;
+        assert!(stream.is_err());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let mode = match self.output {
+            TestAuditLogOutput::Table => "table",
+            TestAuditLogOutput::List => "list",
+        };
+        let message = match self.user_id {
+            Some(user_id) => format!("Executing get 1000 latest audit log entries for user with ID: {user_id} in {mode} mode\n"),
+            None => format!("Executing get 1000 latest audit log entries in {mode} mode\n"),
+        };
+
+        let command_state = command_state.success().stdout(starts_with(message));
+        match self.output {
+            TestAuditLogOutput::Table => {
+                command_state
+                    .stdout(contains("| Command"))
+                    .stdout(contains(format!(
+                        "stream ID: {}, name: {}",
+                        self.stream_id, self.stream_name
+                    )))
+                    .stdout(contains("success"))
+                    .stdout(contains("failure"));
+            }
+            TestAuditLogOutput::List => {
+                command_state
+                    .stdout(contains("|user.login|username: iggy|success|\n"))
+                    .stdout(contains(format!(
+                        "|stream.create|stream ID: {}, name: {}|success|\n",
+                        self.stream_id, self.stream_name
+                    )))
+                    .stdout(contains(format!(
+                        "|stream.create|stream ID: {}, name: {}-duplicate|failure|",
+                        self.stream_id, self.stream_name
+                    )));
+            }
+        }
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {}
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestAuditLogCmd::new(
+            1,
+            String::from("audit"),
+            None,
+            TestAuditLogOutput::List,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestAuditLogCmd::new(
+            2,
+            String::from("compliance"),
+            Some(DEFAULT_ROOT_USER_ID),
+            TestAuditLogOutput::Table,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["audit-log", "--help"],
+            format!(
+                r#"get iggy server audit log
+
+Collect the latest entries of the audit log recording the administrative
+and security-relevant actions, like changes of streams, users and permissions
+or failed logins. Requires the permission to manage the servers.
+
+Examples:
+ iggy audit-log
+ iggy audit-log --count 10 --user-id 2
+ iggy audit-log -c 10 -u 2 -l list
+
+{USAGE_PREFIX} audit-log [OPTIONS]
+
+Options:
+  -c, --count <COUNT>
+          Maximum number of the latest entries to get
+{CLAP_INDENT}
+          [default: 100]
+
+  -u, --user-id <USER_ID>
+          Get only the entries of the user with given ID
+
+  -l, --list-mode <LIST_MODE>
+          List mode (table or list)
+{CLAP_INDENT}
+          [default: table]
+          [possible values: table, list]
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["audit-log", "-h"],
+            format!(
+                r#"get iggy server audit log
+
+{USAGE_PREFIX} audit-log [OPTIONS]
+
+Options:
+  -c, --count <COUNT>          Maximum number of the latest entries to get [default: 100]
+  -u, --user-id <USER_ID>      Get only the entries of the user with given ID
+  -l, --list-mode <LIST_MODE>  List mode (table or list) [default: table] [possible values: table, list]
+  -h, --help                   Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -6,6 +6,7 @@ use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use humantime::Duration as HumanDuration;
 use iggy::cli::utils::message_expiry::MessageExpiry;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::streams::create_stream::CreateStream;
 use iggy::topics::get_topic::GetTopic;
 use iggy::utils::byte_size::IggyByteSize;
@@ -23,6 +24,7 @@ struct TestTopicCreateCmd {
     message_expiry: Option<Vec<String>>,
     max_topic_size: Option<IggyByteSize>,
     replication_factor: u8,
+    compression_algorithm: Option<CompressionAlgorithm>,
     using_identifier: TestStreamId,
 }
 
@@ -37,6 +39,7 @@ impl TestTopicCreateCmd {
         message_expiry: Option<Vec<String>>,
         max_topic_size: Option<IggyByteSize>,
         replication_factor: u8,
+        compression_algorithm: Option<CompressionAlgorithm>,
         using_identifier: TestStreamId,
     ) -> Self {
         Self {
@@ -48,16 +51,24 @@ impl TestTopicCreateCmd {
             message_expiry,
             max_topic_size,
             replication_factor,
+            compression_algorithm,
             using_identifier,
         }
     }
 
     fn to_args(&self) -> Vec<String> {
-        let mut command = match self.using_identifier {
-            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
-            TestStreamId::Named => vec![self.stream_name.clone()],
+        let mut command = match self.compression_algorithm {
+            Some(compression_algorithm) => {
+                vec!["-c".to_string(), compression_algorithm.to_string()]
+            }
+            None => vec![],
         };
 
+        command.push(match self.using_identifier {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        });
+
         command.push(format!("{}", self.topic_id));
         command.push(format!("{}", self.partitions_count));
         command.push(self.topic_name.clone());
@@ -109,11 +120,20 @@ impl IggyCmdTestCase for TestTopicCreateCmd {
 
         let replication_factor = self.replication_factor;
 
+        let compression_algorithm = match &self.compression_algorithm {
+            Some(value) => value.to_string(),
+            None => "server default".to_string(),
+        };
+
         let message = format!(
             "Executing create topic with ID: {topic_id}, name: {topic_name}, message expiry: {message_expiry}, \
-            max topic size: {max_topic_size}, replication factor: {replication_factor} in stream with ID: {stream_id}\n\
+            max topic size: {max_topic_size}, replication factor: {replication_factor}, \
+            compression algorithm: {compression_algorithm}, cleanup policy: delete, max topic size policy: delete_oldest, \
+            flush policy: (messages count: default, size: unlimited, max latency: default) in stream with ID: {stream_id}\n\
             Topic with ID: {topic_id}, name: {topic_name}, partitions count: {partitions_count}, message expiry: {message_expiry}, \
-            max topic size: {max_topic_size}, replication factor: {replication_factor} created in stream with ID: {stream_id}\n",
+            max topic size: {max_topic_size}, replication factor: {replication_factor}, \
+            compression algorithm: {compression_algorithm}, cleanup policy: delete, max topic size policy: delete_oldest, \
+            flush policy: (messages count: default, size: unlimited, max latency: default) created in stream with ID: {stream_id}\n",
         );
 
         command_state.success().stdout(diff(message));
@@ -165,6 +185,7 @@ pub async fn should_be_successful() {
             None,
             None,
             1,
+            None,
             TestStreamId::Numeric,
         ))
         .await;
@@ -178,6 +199,7 @@ pub async fn should_be_successful() {
             None,
             None,
             1,
+            None,
             TestStreamId::Named,
         ))
         .await;
@@ -191,6 +213,7 @@ pub async fn should_be_successful() {
             Some(vec![String::from("3days"), String::from("5s")]),
             None,
             1,
+            None,
             TestStreamId::Named,
         ))
         .await;
@@ -209,6 +232,7 @@ pub async fn should_be_successful() {
             ]),
             None,
             1,
+            None,
             TestStreamId::Numeric,
         ))
         .await;
@@ -232,6 +256,7 @@ Examples
  iggy topic create 1 1 2 sensor1 15days
  iggy topic create prod 2 2 sensor2
  iggy topic create test 3 2 debugs 1day 1hour 1min 1sec
+ iggy topic create -c lz4 prod 4 2 events
 
 {USAGE_PREFIX} topic create [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT> <NAME> [MESSAGE_EXPIRY]...
 
@@ -269,6 +294,40 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+  -c, --compression-algorithm <COMPRESSION_ALGORITHM>
+          Compression algorithm for the topic (none, gzip, lz4, zstd or snappy)
+{CLAP_INDENT}
+          (skipping parameter uses the default algorithm configured on the server)
+
+      --cleanup-policy <CLEANUP_POLICY>
+          Cleanup policy for the topic (delete or compact)
+{CLAP_INDENT}
+          (compact keeps only the latest message for each message key in the closed segments)
+{CLAP_INDENT}
+          [default: delete]
+
+      --max-topic-size-policy <MAX_TOPIC_SIZE_POLICY>
+          Policy applied once the topic reaches max topic size (delete_oldest or reject)
+{CLAP_INDENT}
+          (reject fails appending the messages to the full topic instead of deleting the oldest segments)
+{CLAP_INDENT}
+          [default: delete_oldest]
+
+      --flush-messages-count <FLUSH_MESSAGES_COUNT>
+          Number of buffered messages after which the topic partitions are flushed to disk
+{CLAP_INDENT}
+          (skipping parameter uses the messages required to save configured on the server)
+
+      --flush-size <FLUSH_SIZE>
+          Size of buffered messages after which the topic partitions are flushed to disk
+{CLAP_INDENT}
+          (skipping parameter disables the size based flushing)
+
+      --flush-max-latency <FLUSH_MAX_LATENCY>
+          Max time in milliseconds the messages can stay buffered before being flushed to disk
+{CLAP_INDENT}
+          (skipping parameter relies only on the message saver interval configured on the server)
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -298,9 +357,24 @@ Arguments:
   [MESSAGE_EXPIRY]...  Message expiry time in human readable format like 15days 2min 2s
 
 Options:
-  -m, --max-topic-size <MAX_TOPIC_SIZE>          Max topic size [default: unlimited]
-  -r, --replication-factor <REPLICATION_FACTOR>  Replication factor for the topic [default: 1]
-  -h, --help                                     Print help (see more with '--help')
+  -m, --max-topic-size <MAX_TOPIC_SIZE>
+          Max topic size [default: unlimited]
+  -r, --replication-factor <REPLICATION_FACTOR>
+          Replication factor for the topic [default: 1]
+  -c, --compression-algorithm <COMPRESSION_ALGORITHM>
+          Compression algorithm for the topic (none, gzip, lz4, zstd or snappy)
+      --cleanup-policy <CLEANUP_POLICY>
+          Cleanup policy for the topic (delete or compact) [default: delete]
+      --max-topic-size-policy <MAX_TOPIC_SIZE_POLICY>
+          Policy applied once the topic reaches max topic size (delete_oldest or reject) [default: delete_oldest]
+      --flush-messages-count <FLUSH_MESSAGES_COUNT>
+          Number of buffered messages after which the topic partitions are flushed to disk
+      --flush-size <FLUSH_SIZE>
+          Size of buffered messages after which the topic partitions are flushed to disk
+      --flush-max-latency <FLUSH_MAX_LATENCY>
+          Max time in milliseconds the messages can stay buffered before being flushed to disk
+  -h, --help
+          Print help (see more with '--help')
 "#,
             ),
         ))
@@ -4,6 +4,7 @@ use crate::cli::common::{
 };
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -75,6 +76,10 @@ impl IggyCmdTestCase for TestTopicGetCmd {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                compression_algorithm: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+                flush_policy: FlushPolicy::default(),
             })
             .await;
         assert!(topic.is_ok());
@@ -115,6 +120,12 @@ impl IggyCmdTestCase for TestTopicGetCmd {
             .stdout(contains("Topic size          | 0"))
             .stdout(contains("Message expiry      | unlimited"))
             .stdout(contains("Max topic size      | unlimited"))
+            .stdout(contains("Compression         | none"))
+            .stdout(contains("Cleanup policy      | delete"))
+            .stdout(contains("Max size policy     | delete_oldest"))
+            .stdout(contains(
+                "Flush policy        | messages count: default, size: unlimited, max latency: default",
+            ))
             .stdout(contains("Topic message count | 0"))
             .stdout(contains("Partitions count    | 1"));
     }
@@ -6,6 +6,7 @@ use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use humantime::Duration as HumanDuration;
 use iggy::cli::utils::message_expiry::MessageExpiry;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -131,6 +132,10 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
                 message_expiry,
                 max_topic_size,
                 replication_factor: self.replication_factor,
+                compression_algorithm: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+                flush_policy: FlushPolicy::default(),
             })
             .await;
         assert!(topic.is_ok());
@@ -172,7 +177,9 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
 
         let expected_message = format!("Executing update topic with ID: {topic_id}, name: {new_topic_name}, \
                                 message expiry: {message_expiry}, max topic size: {max_topic_size}, \
-                                replication factor: {replication_factor}, in stream with ID: {stream_id}\n\
+                                replication factor: {replication_factor}, compression algorithm: unchanged, \
+                                cleanup policy: unchanged, max topic size policy: unchanged, \
+                                flush policy: unchanged, in stream with ID: {stream_id}\n\
                                 Topic with ID: {topic_id} updated name: {new_topic_name}, updated message expiry: {message_expiry} \
                                 in stream with ID: {stream_id}\n");
 
@@ -361,6 +368,7 @@ Examples
  iggy update test debugs ready 15days
  iggy update 1 1 new-name
  iggy update 1 2 new-name 1day 1hour 1min 1sec
+ iggy update -c zstd prod sensor3 sensor3
 
 {USAGE_PREFIX} topic update [OPTIONS] <STREAM_ID> <TOPIC_ID> <NAME> [MESSAGE_EXPIRY]...
 
@@ -397,6 +405,42 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+  -c, --compression-algorithm <COMPRESSION_ALGORITHM>
+          New compression algorithm for the topic (none, gzip, lz4, zstd or snappy)
+{CLAP_INDENT}
+          (skipping parameter keeps the current algorithm of the topic)
+          Already stored messages remain compressed with the algorithm used when they were appended.
+
+      --cleanup-policy <CLEANUP_POLICY>
+          New cleanup policy for the topic (delete or compact)
+{CLAP_INDENT}
+          (compact keeps only the latest message for each message key in the closed segments)
+          (skipping parameter keeps the current cleanup policy of the topic)
+
+      --max-topic-size-policy <MAX_TOPIC_SIZE_POLICY>
+          New policy applied once the topic reaches max topic size (delete_oldest or reject)
+{CLAP_INDENT}
+          (reject fails appending the messages to the full topic instead of deleting the oldest segments)
+          (skipping parameter keeps the current policy of the topic)
+
+      --flush-messages-count <FLUSH_MESSAGES_COUNT>
+          New number of buffered messages after which the topic partitions are flushed to disk
+{CLAP_INDENT}
+          (skipping all the flush parameters keeps the current flush policy of the topic,
+          otherwise the skipped ones use the messages required to save configured on the server)
+
+      --flush-size <FLUSH_SIZE>
+          New size of buffered messages after which the topic partitions are flushed to disk
+{CLAP_INDENT}
+          (skipping all the flush parameters keeps the current flush policy of the topic,
+          otherwise the skipped ones disable the size based flushing)
+
+      --flush-max-latency <FLUSH_MAX_LATENCY>
+          New max time in milliseconds the messages can stay buffered before being flushed to disk
+{CLAP_INDENT}
+          (skipping all the flush parameters keeps the current flush policy of the topic,
+          otherwise the skipped ones rely only on the message saver interval configured on the server)
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -425,9 +469,24 @@ Arguments:
   [MESSAGE_EXPIRY]...  New message expiry time in human readable format like 15days 2min 2s
 
 Options:
-  -m, --max-topic-size <MAX_TOPIC_SIZE>          New max topic size [default: unlimited]
-  -r, --replication-factor <REPLICATION_FACTOR>  New replication factor for the topic [default: 1]
-  -h, --help                                     Print help (see more with '--help')
+  -m, --max-topic-size <MAX_TOPIC_SIZE>
+          New max topic size [default: unlimited]
+  -r, --replication-factor <REPLICATION_FACTOR>
+          New replication factor for the topic [default: 1]
+  -c, --compression-algorithm <COMPRESSION_ALGORITHM>
+          New compression algorithm for the topic (none, gzip, lz4, zstd or snappy)
+      --cleanup-policy <CLEANUP_POLICY>
+          New cleanup policy for the topic (delete or compact)
+      --max-topic-size-policy <MAX_TOPIC_SIZE_POLICY>
+          New policy applied once the topic reaches max topic size (delete_oldest or reject)
+      --flush-messages-count <FLUSH_MESSAGES_COUNT>
+          New number of buffered messages after which the topic partitions are flushed to disk
+      --flush-size <FLUSH_SIZE>
+          New size of buffered messages after which the topic partitions are flushed to disk
+      --flush-max-latency <FLUSH_MAX_LATENCY>
+          New max time in milliseconds the messages can stay buffered before being flushed to disk
+  -h, --help
+          Print help (see more with '--help')
 "#,
             ),
         ))
@@ -8,4 +8,6 @@ mod test_user_list_command;
 mod test_user_name_command;
 mod test_user_password_command;
 mod test_user_permissions_command;
+mod test_user_roles_command;
 mod test_user_status_command;
+mod test_user_unlock_command;
@@ -0,0 +1,155 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestUserId, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::models::user_info::UserId;
+use iggy::roles::create_role::CreateRole;
+use iggy::roles::get_role::GetRole;
+use iggy::users::create_user::CreateUser;
+use iggy::users::get_user::GetUser;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestUserRolesCmd {
+    username: String,
+    roles: Vec<String>,
+    using_identifier: TestUserId,
+    user_id: Option<UserId>,
+    role_ids: Vec<u32>,
+}
+
+impl TestUserRolesCmd {
+    fn new(username: String, roles: Vec<String>, using_identifier: TestUserId) -> Self {
+        Self {
+            username,
+            roles,
+            using_identifier,
+            user_id: None,
+            role_ids: vec![],
+        }
+    }
+
+    fn get_user_id(&self) -> String {
+        match self.using_identifier {
+            TestUserId::Named => self.username.clone(),
+            TestUserId::Numeric => format!("{}", self.user_id.unwrap()),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestUserRolesCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let create_user = client
+            .create_user(&CreateUser {
+                username: self.username.clone(),
+                ..Default::default()
+            })
+            .await;
+        assert!(create_user.is_ok());
+        let user = client
+            .get_user(&GetUser {
+                user_id: Identifier::named(self.username.as_str()).unwrap(),
+            })
+            .await;
+        assert!(user.is_ok());
+        self.user_id = Some(user.unwrap().id);
+
+        for name in &self.roles {
+            let create_role = client
+                .create_role(&CreateRole {
+                    name: name.clone(),
+                    ..Default::default()
+                })
+                .await;
+            assert!(create_role.is_ok());
+            let role = client
+                .get_role(&GetRole {
+                    role_id: Identifier::named(name.as_str()).unwrap(),
+                })
+                .await;
+            assert!(role.is_ok());
+            self.role_ids.push(role.unwrap().id);
+        }
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("user")
+            .arg("roles")
+            .arg(self.get_user_id())
+            .args(self.roles.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let user_id = self.get_user_id();
+        let roles = self.roles.join(", ");
+        let message = format!(
+            "Executing assign roles: [{roles}] to user with ID: {user_id}\nRoles: [{roles}] assigned to user with ID: {user_id}\n"
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let user = client
+            .get_user(&GetUser {
+                user_id: Identifier::named(self.username.as_str()).unwrap(),
+            })
+            .await;
+        assert!(user.is_ok());
+        let user = user.unwrap();
+        assert_eq!(user.username, self.username);
+        assert_eq!(user.roles, self.role_ids);
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestUserRolesCmd::new(
+            String::from("reader"),
+            vec![String::from("readers")],
+            TestUserId::Named,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserRolesCmd::new(
+            String::from("producer"),
+            vec![String::from("producers"), String::from("auditors")],
+            TestUserId::Numeric,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "roles", "-h"],
+            format!(
+                r#"Assign roles to user with given ID
+
+{USAGE_PREFIX} user roles <USER_ID> [ROLES]...
+
+Arguments:
+  <USER_ID>   User ID to update
+  [ROLES]...  Role IDs to assign
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
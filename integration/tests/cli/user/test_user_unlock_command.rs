@@ -0,0 +1,163 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestUserId, CLAP_INDENT,
+    USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::models::user_info::UserId;
+use iggy::users::create_user::CreateUser;
+use iggy::users::get_user::GetUser;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestUserUnlockCmd {
+    username: String,
+    using_identifier: TestUserId,
+    user_id: Option<UserId>,
+}
+
+impl TestUserUnlockCmd {
+    fn new(username: String, using_identifier: TestUserId) -> Self {
+        Self {
+            username,
+            using_identifier,
+            user_id: None,
+        }
+    }
+
+    fn get_user_id(&self) -> String {
+        match self.using_identifier {
+            TestUserId::Named => self.username.clone(),
+            TestUserId::Numeric => format!("{}", self.user_id.unwrap()),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestUserUnlockCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let create_user = client
+            .create_user(&CreateUser {
+                username: self.username.clone(),
+                ..Default::default()
+            })
+            .await;
+        assert!(create_user.is_ok());
+        let user = client
+            .get_user(&GetUser {
+                user_id: Identifier::named(self.username.as_str()).unwrap(),
+            })
+            .await;
+        assert!(user.is_ok());
+        self.user_id = Some(user.unwrap().id);
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("user")
+            .arg("unlock")
+            .arg(self.get_user_id())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let user_id = self.get_user_id();
+        let message =
+            format!("Executing unlock user with ID: {user_id}\nUser with ID: {user_id} unlocked\n");
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let user = client
+            .get_user(&GetUser {
+                user_id: Identifier::named(self.username.as_str()).unwrap(),
+            })
+            .await;
+        assert!(user.is_ok());
+        let user = user.unwrap();
+        assert_eq!(user.username, self.username);
+        assert!(user.locked_until.is_none());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestUserUnlockCmd::new(
+            String::from("locked"),
+            TestUserId::Named,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserUnlockCmd::new(
+            String::from("blocked"),
+            TestUserId::Numeric,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "unlock", "--help"],
+            format!(
+                r"Unlock user with given ID
+
+Clears the lockout and the failed login attempts recorded for the user,
+so that the user can log in again before the lockout expires.
+The user ID can be specified as either a username or an ID.
+
+Examples:
+ iggy user unlock 2
+ iggy user unlock testuser
+
+{USAGE_PREFIX} user unlock <USER_ID>
+
+Arguments:
+  <USER_ID>
+          User ID to unlock
+{CLAP_INDENT}
+          The user ID can be specified as either a username or an ID
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+",
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "unlock", "-h"],
+            format!(
+                r#"Unlock user with given ID
+
+{USAGE_PREFIX} user unlock <USER_ID>
+
+Arguments:
+  <USER_ID>  User ID to unlock
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
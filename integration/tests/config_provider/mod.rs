@@ -41,6 +41,9 @@ async fn validate_custom_env_provider() {
     env::set_var("IGGY_QUIC_CERTIFICATE_SELF_SIGNED", "false");
     env::set_var("IGGY_HTTP_ENABLED", "false");
     env::set_var("IGGY_SYSTEM_PARTITION_MESSAGES_REQUIRED_TO_SAVE", "42");
+    env::set_var("IGGY_TCP_TLS_CLIENT_AUTH_ENABLED", "true");
+    env::set_var("IGGY_QUIC_CLIENT_AUTH_CA_FILE", "certs/custom_ca.pem");
+    env::set_var("IGGY_SYSTEM_REPLICATION_FOLLOWER_IDS", "[2, 3]");
 
     let config_path = get_root_path().join("../configs/server.toml");
 
@@ -58,10 +61,16 @@ async fn validate_custom_env_provider() {
     assert!(!config.quic.certificate.self_signed);
     assert!(!config.http.enabled);
     assert_eq!(config.system.partition.messages_required_to_save, 42);
+    assert!(config.tcp.tls.client_auth.enabled);
+    assert_eq!(config.quic.client_auth.ca_file, "certs/custom_ca.pem");
+    assert_eq!(config.system.replication.follower_ids, vec![2, 3]);
 
     env::remove_var("IGGY_SYSTEM_DATABASE_PATH");
     env::remove_var("IGGY_QUIC_DATAGRAM_SEND_BUFFER_SIZE");
     env::remove_var("IGGY_QUIC_CERTIFICATE_SELF_SIGNED");
     env::remove_var("IGGY_HTTP_ENABLED");
     env::remove_var("IGGY_SYSTEM_PARTITION_MESSAGES_REQUIRED_TO_SAVE");
+    env::remove_var("IGGY_TCP_TLS_CLIENT_AUTH_ENABLED");
+    env::remove_var("IGGY_QUIC_CLIENT_AUTH_CA_FILE");
+    env::remove_var("IGGY_SYSTEM_REPLICATION_FOLLOWER_IDS");
 }
@@ -13,6 +13,7 @@ use iggy::client::UserClient;
 use iggy::clients::client::IggyClient;
 use iggy::clients::client::IggyClientConfig;
 use iggy::identifier::Identifier;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::streams::create_stream::CreateStream;
 use iggy::system::ping::Ping;
 use iggy::tcp::client::TcpClient;
@@ -136,6 +137,10 @@ impl<'a> IggyExampleTest<'a> {
                     message_expiry: None,
                     max_topic_size: None,
                     replication_factor: 1,
+                    compression_algorithm: None,
+                    cleanup_policy: CleanupPolicy::Delete,
+                    max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+                    flush_policy: FlushPolicy::default(),
                 })
                 .await
                 .unwrap();
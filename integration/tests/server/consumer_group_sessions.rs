@@ -0,0 +1,115 @@
+use bytes::Bytes;
+use iggy::client::{ConsumerGroupClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig, PollMessagesConfig, StoreOffsetKind};
+use iggy::consumer::Consumer;
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::messages::Message as ReceivedMessage;
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use integration::tcp_client::TcpClientFactory;
+use integration::test_server::{login_root, ClientFactory, IpAddrKind, TestServer};
+use serial_test::parallel;
+use std::time::Duration;
+use tokio::time::timeout;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const PARTITION_ID: u32 = 1;
+const CONSUMER_GROUP_ID: u32 = 1;
+const SESSION_TIMEOUT_SECONDS: u32 = 1;
+
+#[tokio::test]
+#[parallel]
+async fn evicted_member_without_heartbeats_should_join_consumer_group_again_on_next_poll() {
+    let mut test_server = TestServer::new(None, true, None, IpAddrKind::V4);
+    test_server.start();
+    let client = create_client(&test_server).await;
+    client
+        .create_stream(&CreateStream {
+            stream_id: STREAM_ID,
+            name: "test-stream".to_string(),
+        })
+        .await
+        .unwrap();
+    client
+        .create_topic(&CreateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: TOPIC_ID,
+            partitions_count: 1,
+            name: "test-topic".to_string(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    client
+        .create_consumer_group(&CreateConsumerGroup {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: CONSUMER_GROUP_ID,
+            name: "test-consumer-group".to_string(),
+            session_timeout: Some(SESSION_TIMEOUT_SECONDS),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let mut consumer = create_client(&test_server).await;
+    consumer
+        .join_consumer_group(&JoinConsumerGroup {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    client
+        .send_messages(&mut SendMessages {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partitioning: Partitioning::partition_id(PARTITION_ID),
+            messages: vec![Message::new(None, Bytes::from("message"), None)],
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    // The member is evicted before its first poll, as the heartbeats are disabled and the polling interval exceeds the session timeout.
+    let receiver = consumer.subscribe_to_polled_messages();
+    let polling = consumer.start_polling_messages(
+        PollMessages {
+            consumer: Consumer::group(Identifier::numeric(CONSUMER_GROUP_ID).unwrap()),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: None,
+            strategy: PollingStrategy::next(),
+            count: 1,
+            ..Default::default()
+        },
+        None::<fn(ReceivedMessage)>,
+        Some(PollMessagesConfig {
+            interval: 3000,
+            store_offset_kind: StoreOffsetKind::Never,
+            heartbeat_interval: 0,
+        }),
+    );
+    let message = timeout(Duration::from_secs(10), receiver.recv_async())
+        .await
+        .unwrap()
+        .unwrap();
+    polling.abort();
+    assert_eq!(message.payload, Bytes::from("message"));
+}
+
+async fn create_client(test_server: &TestServer) -> IggyClient {
+    let client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+    };
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    login_root(&client).await;
+    client
+}
@@ -1,4 +1,7 @@
-use crate::server::scenarios::{message_headers_scenario, system_scenario, user_scenario};
+use crate::server::scenarios::{
+    message_ack_scenario, message_headers_scenario, message_rejection_scenario, role_scenario,
+    system_scenario, user_scenario,
+};
 use integration::{http_client::HttpClientFactory, test_server::TestServer};
 use serial_test::parallel;
 
@@ -22,6 +25,16 @@ async fn user_scenario_should_be_valid() {
     user_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn role_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    role_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn message_headers_scenario_should_be_valid() {
@@ -31,3 +44,23 @@ async fn message_headers_scenario_should_be_valid() {
     let client_factory = HttpClientFactory { server_addr };
     message_headers_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn message_rejection_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    message_rejection_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn message_ack_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    message_ack_scenario::run(&client_factory).await;
+}
@@ -0,0 +1,109 @@
+use iggy::client::UserClient;
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::models::user_status::UserStatus;
+use iggy::users::create_user::CreateUser;
+use iggy::users::get_user::GetUser;
+use iggy::users::login_user::LoginUser;
+use iggy::users::unlock_user::UnlockUser;
+use integration::tcp_client::TcpClientFactory;
+use integration::test_server::{login_root, ClientFactory, IpAddrKind, TestServer};
+use serial_test::parallel;
+use std::collections::HashMap;
+
+const USERNAME: &str = "locked-user";
+const PASSWORD: &str = "secret";
+const MAX_FAILED_ATTEMPTS: u32 = 3;
+const INVALID_CREDENTIALS_CODE: u32 = 42;
+const USER_LOCKED_CODE: u32 = 58;
+
+#[tokio::test]
+#[parallel]
+async fn user_should_be_locked_after_too_many_failed_logins_and_unlocked_by_admin() {
+    let mut test_server = TestServer::new(
+        Some(HashMap::from([
+            (
+                "IGGY_SYSTEM_LOGIN_ATTEMPTS_FREE_ATTEMPTS".to_string(),
+                MAX_FAILED_ATTEMPTS.to_string(),
+            ),
+            (
+                "IGGY_SYSTEM_LOGIN_ATTEMPTS_USER_MAX_FAILED_ATTEMPTS".to_string(),
+                MAX_FAILED_ATTEMPTS.to_string(),
+            ),
+        ])),
+        true,
+        None,
+        IpAddrKind::V4,
+    );
+    test_server.start();
+    let root_client = create_client(&test_server).await;
+    login_root(&root_client).await;
+    root_client
+        .create_user(&CreateUser {
+            username: USERNAME.to_string(),
+            password: PASSWORD.to_string(),
+            status: UserStatus::Active,
+            permissions: None,
+        })
+        .await
+        .unwrap();
+
+    let client = create_client(&test_server).await;
+    for _ in 0..MAX_FAILED_ATTEMPTS {
+        let error = client
+            .login_user(&LoginUser {
+                username: USERNAME.to_string(),
+                password: "invalid".to_string(),
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::InvalidResponse(INVALID_CREDENTIALS_CODE)
+        ));
+    }
+
+    let error = client
+        .login_user(&LoginUser {
+            username: USERNAME.to_string(),
+            password: PASSWORD.to_string(),
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::InvalidResponse(USER_LOCKED_CODE)));
+
+    let user_id = Identifier::named(USERNAME).unwrap();
+    let user = root_client
+        .get_user(&GetUser {
+            user_id: user_id.clone(),
+        })
+        .await
+        .unwrap();
+    assert!(user.locked_until.is_some());
+
+    root_client
+        .unlock_user(&UnlockUser {
+            user_id: user_id.clone(),
+        })
+        .await
+        .unwrap();
+    let user = root_client.get_user(&GetUser { user_id }).await.unwrap();
+    assert!(user.locked_until.is_none());
+
+    client
+        .login_user(&LoginUser {
+            username: USERNAME.to_string(),
+            password: PASSWORD.to_string(),
+        })
+        .await
+        .unwrap();
+}
+
+async fn create_client(test_server: &TestServer) -> IggyClient {
+    let client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+    };
+    let client = client_factory.create_client().await;
+    IggyClient::create(client, IggyClientConfig::default(), None, None, None)
+}
@@ -1,4 +1,8 @@
+mod consumer_group_sessions;
 mod http_server;
+mod login_attempts;
 mod quic_server;
+mod quotas;
+mod replication;
 mod scenarios;
 mod tcp_server;
@@ -1,7 +1,8 @@
 use crate::server::scenarios::{
     consumer_group_join_scenario, consumer_group_with_multiple_clients_polling_messages_scenario,
-    consumer_group_with_single_client_polling_messages_scenario, message_headers_scenario,
-    system_scenario, user_scenario,
+    consumer_group_with_single_client_polling_messages_scenario, message_ack_scenario,
+    message_headers_scenario, message_rejection_scenario, role_scenario, system_scenario,
+    user_scenario,
 };
 use integration::{quic_client::QuicClientFactory, test_server::TestServer};
 use serial_test::parallel;
@@ -26,6 +27,16 @@ async fn user_scenario_should_be_valid() {
     user_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn role_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    role_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn message_headers_scenario_should_be_valid() {
@@ -36,6 +47,16 @@ async fn message_headers_scenario_should_be_valid() {
     message_headers_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn message_rejection_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    message_rejection_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn consumer_group_join_scenario_should_be_valid() {
@@ -65,3 +86,13 @@ async fn consumer_group_with_multiple_clients_polling_messages_scenario_should_b
     let client_factory = QuicClientFactory { server_addr };
     consumer_group_with_multiple_clients_polling_messages_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn message_ack_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    message_ack_scenario::run(&client_factory).await;
+}
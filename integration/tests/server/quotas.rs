@@ -0,0 +1,88 @@
+use iggy::client::SystemClient;
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::error::Error;
+use iggy::system::get_me::GetMe;
+use iggy::system::ping::Ping;
+use integration::tcp_client::TcpClientFactory;
+use integration::test_server::{login_root, ClientFactory, IpAddrKind, TestServer};
+use serial_test::parallel;
+use std::collections::HashMap;
+use std::time::Instant;
+
+const REQUESTS_PER_SECOND: u64 = 5;
+const QUOTA_EXCEEDED_CODE: u32 = 57;
+
+#[tokio::test]
+#[parallel]
+async fn requests_exceeding_client_quota_should_be_rejected() {
+    let test_server = start_server("reject");
+    let client = create_client(&test_server).await;
+
+    let mut rejected = false;
+    for _ in 0..2 * REQUESTS_PER_SECOND {
+        if let Err(error) = client.ping(&Ping {}).await {
+            assert!(matches!(error, Error::InvalidResponse(QUOTA_EXCEEDED_CODE)));
+            rejected = true;
+            break;
+        }
+    }
+    assert!(rejected);
+
+    let other_client = create_client(&test_server).await;
+    let me = other_client.get_me(&GetMe {}).await.unwrap();
+    let quotas = me.quotas.unwrap();
+    assert_eq!(quotas.client.requests.limit, REQUESTS_PER_SECOND);
+    assert_eq!(quotas.throttled_requests, 0);
+}
+
+#[tokio::test]
+#[parallel]
+async fn requests_exceeding_client_quota_should_be_throttled() {
+    let test_server = start_server("throttle");
+    let client = create_client(&test_server).await;
+
+    let start = Instant::now();
+    for _ in 0..2 * REQUESTS_PER_SECOND {
+        client.ping(&Ping {}).await.unwrap();
+    }
+    assert!(start.elapsed().as_millis() >= 800);
+
+    let me = client.get_me(&GetMe {}).await.unwrap();
+    let quotas = me.quotas.unwrap();
+    assert!(quotas.throttled_requests > 0);
+    assert_eq!(quotas.rejected_requests, 0);
+    assert_eq!(quotas.client.requests.limit, REQUESTS_PER_SECOND);
+    assert_eq!(quotas.user.requests.limit, 0);
+}
+
+fn start_server(mode: &str) -> TestServer {
+    let mut test_server = TestServer::new(
+        Some(HashMap::from([
+            ("IGGY_SYSTEM_QUOTAS_ENABLED".to_string(), "true".to_string()),
+            ("IGGY_SYSTEM_QUOTAS_MODE".to_string(), mode.to_string()),
+            (
+                "IGGY_SYSTEM_QUOTAS_MAX_THROTTLE_DELAY".to_string(),
+                "10s".to_string(),
+            ),
+            (
+                "IGGY_SYSTEM_QUOTAS_CLIENT_REQUESTS_PER_SECOND".to_string(),
+                REQUESTS_PER_SECOND.to_string(),
+            ),
+        ])),
+        true,
+        None,
+        IpAddrKind::V4,
+    );
+    test_server.start();
+    test_server
+}
+
+async fn create_client(test_server: &TestServer) -> IggyClient {
+    let client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+    };
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    login_root(&client).await;
+    client
+}
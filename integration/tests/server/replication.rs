@@ -0,0 +1,177 @@
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
+use iggy::models::messages::PolledMessages;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::get_topic::GetTopic;
+use integration::tcp_client::TcpClientFactory;
+use integration::test_server::{login_root, ClientFactory, IpAddrKind, TestServer};
+use serial_test::parallel;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::sleep;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 10;
+
+#[tokio::test]
+#[parallel]
+async fn messages_should_be_replicated_to_follower_node() {
+    let mut leader = TestServer::new(
+        Some(HashMap::from([
+            (
+                "IGGY_SYSTEM_REPLICATION_ENABLED".to_string(),
+                "true".to_string(),
+            ),
+            (
+                "IGGY_SYSTEM_REPLICATION_ACK".to_string(),
+                "in_sync_replicas".to_string(),
+            ),
+            (
+                "IGGY_SYSTEM_REPLICATION_FOLLOWER_IDS".to_string(),
+                "[2]".to_string(),
+            ),
+        ])),
+        true,
+        None,
+        IpAddrKind::V4,
+    );
+    leader.start();
+    let leader_address = leader.get_raw_tcp_addr().unwrap();
+    let leader_client = create_client(&leader_address).await;
+    leader_client
+        .create_stream(&CreateStream {
+            stream_id: STREAM_ID,
+            name: "test-stream".to_string(),
+        })
+        .await
+        .unwrap();
+    leader_client
+        .create_topic(&CreateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: TOPIC_ID,
+            partitions_count: 1,
+            name: "test-topic".to_string(),
+            message_expiry: None,
+            max_topic_size: None,
+            replication_factor: 2,
+            compression_algorithm: None,
+            cleanup_policy: CleanupPolicy::Delete,
+            max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+            flush_policy: FlushPolicy::default(),
+        })
+        .await
+        .unwrap();
+
+    let mut follower = TestServer::new(
+        Some(HashMap::from([
+            (
+                "IGGY_SYSTEM_REPLICATION_ENABLED".to_string(),
+                "true".to_string(),
+            ),
+            (
+                "IGGY_SYSTEM_REPLICATION_ROLE".to_string(),
+                "follower".to_string(),
+            ),
+            (
+                "IGGY_SYSTEM_REPLICATION_NODE_ID".to_string(),
+                "2".to_string(),
+            ),
+            (
+                "IGGY_SYSTEM_REPLICATION_LEADER_ADDRESS".to_string(),
+                leader_address,
+            ),
+        ])),
+        true,
+        None,
+        IpAddrKind::V4,
+    );
+    follower.start();
+
+    // The messages are acknowledged only once they have been replicated by the follower.
+    leader_client
+        .send_messages(&mut create_send_messages())
+        .await
+        .unwrap();
+
+    let follower_client = create_client(&follower.get_raw_tcp_addr().unwrap()).await;
+
+    let polled_messages = poll_messages(&follower_client).await;
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    for (offset, message) in polled_messages.messages.iter().enumerate() {
+        assert_eq!(message.offset, offset as u64);
+        assert_eq!(message.payload, Bytes::from(format!("message {offset}")));
+    }
+
+    let topic = leader_client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic.partitions[0].in_sync_replicas, vec![1, 2]);
+
+    // The replicated partitions are read-only on the follower node.
+    assert!(follower_client
+        .send_messages(&mut create_send_messages())
+        .await
+        .is_err());
+}
+
+async fn create_client(server_addr: &str) -> IggyClient {
+    let client_factory = TcpClientFactory {
+        server_addr: server_addr.to_string(),
+    };
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    login_root(&client).await;
+    client
+}
+
+fn create_send_messages() -> SendMessages {
+    let messages = (0..MESSAGES_COUNT)
+        .map(|offset| Message::new(None, Bytes::from(format!("message {offset}")), None))
+        .collect();
+    SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        ack: AckLevel::InMemory,
+        messages,
+    }
+}
+
+async fn poll_messages(client: &IggyClient) -> PolledMessages {
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: MESSAGES_COUNT,
+        auto_commit: false,
+        skip_poisoned: false,
+        generation: None,
+    };
+
+    // The follower might not have created the replicated topic yet.
+    for _ in 0..50 {
+        if let Ok(polled_messages) = client.poll_messages(&poll_messages).await {
+            if polled_messages.messages.len() as u32 == MESSAGES_COUNT {
+                return polled_messages;
+            }
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    client.poll_messages(&poll_messages).await.unwrap()
+}
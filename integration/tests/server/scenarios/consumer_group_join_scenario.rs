@@ -1,11 +1,18 @@
-use iggy::client::{ConsumerGroupClient, StreamClient, SystemClient, TopicClient};
+use iggy::client::{ConsumerGroupClient, MessageClient, StreamClient, SystemClient, TopicClient};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
+use iggy::consumer_groups::get_consumer_group_assignment::GetConsumerGroupAssignment;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use iggy::error::Error;
 use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
 use iggy::models::client_info::ClientInfoDetails;
-use iggy::models::consumer_group::ConsumerGroupDetails;
+use iggy::models::consumer_group::{
+    ConsumerGroupDetails, ConsumerGroupState, PartitionAssignmentStrategy,
+};
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::streams::create_stream::CreateStream;
 use iggy::system::get_me::GetMe;
 use iggy::topics::create_topic::CreateTopic;
@@ -48,6 +55,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         message_expiry: None,
         max_topic_size: None,
         replication_factor: 1,
+        compression_algorithm: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+        flush_policy: FlushPolicy::default(),
     };
     system_client.create_topic(&create_topic).await.unwrap();
 
@@ -57,6 +68,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         consumer_group_id: CONSUMER_GROUP_ID,
         name: CONSUMER_GROUP_NAME.to_string(),
+        session_timeout: None,
+        assignment_strategy: PartitionAssignmentStrategy::RoundRobin,
     };
     system_client
         .create_consumer_group(&create_group)
@@ -121,10 +134,41 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_ne!(member1.partitions[0], member3.partitions[0]);
     assert_ne!(member2.partitions[0], member3.partitions[0]);
 
+    // 13. Validate that the consumer group is rebalancing until all the members acknowledge the current generation
+    assert_eq!(consumer_group.generation, 3);
+    assert_eq!(consumer_group.state, ConsumerGroupState::Rebalancing);
+    for client in [&client1, &client2, &client3] {
+        get_assignment_and_validate_partitions(client, &consumer_group).await;
+    }
+    let consumer_group = get_consumer_group_and_validate_members(&system_client, 3).await;
+    assert_eq!(consumer_group.generation, 3);
+    assert_eq!(consumer_group.state, ConsumerGroupState::Stable);
+
+    // 14. Validate that the member using the stale generation is fenced
+    let error = poll_messages(&client1, Some(2)).await.unwrap_err();
+    assert!(matches!(
+        error,
+        Error::InvalidResponse(code) if code == Error::ConsumerGroupGenerationMismatch(0, 0, 0).as_code()
+    ));
+    assert!(poll_messages(&client1, Some(3)).await.is_ok());
+
     cleanup(&system_client).await;
     assert_clean_system(&system_client).await;
 }
 
+async fn poll_messages(client: &IggyClient, generation: Option<u32>) -> Result<(), Error> {
+    let poll_messages = PollMessages {
+        consumer: Consumer::group(Identifier::numeric(CONSUMER_GROUP_ID).unwrap()),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: None,
+        strategy: PollingStrategy::next(),
+        generation,
+        ..Default::default()
+    };
+    client.poll_messages(&poll_messages).await.map(|_| ())
+}
+
 async fn join_consumer_group(client: &IggyClient) {
     let join_group = JoinConsumerGroup {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -170,6 +214,32 @@ async fn get_consumer_group_and_validate_members(
     consumer_group
 }
 
+async fn get_assignment_and_validate_partitions(
+    client: &IggyClient,
+    consumer_group: &ConsumerGroupDetails,
+) {
+    let client_info = client.get_me(&GetMe {}).await.unwrap();
+    let member = consumer_group
+        .members
+        .iter()
+        .find(|member| member.id == client_info.client_id)
+        .unwrap();
+    let get_assignment = GetConsumerGroupAssignment {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+    };
+    let assignment = client
+        .get_consumer_group_assignment(&get_assignment)
+        .await
+        .unwrap();
+
+    assert_eq!(assignment.consumer_group_id, CONSUMER_GROUP_ID);
+    assert_eq!(assignment.generation, consumer_group.generation);
+    assert_eq!(assignment.partitions, member.partitions);
+    assert!(assignment.pending_partitions.is_empty());
+}
+
 async fn create_client(client_factory: &dyn ClientFactory) -> IggyClient {
     let client = client_factory.create_client().await;
     IggyClient::create(client, IggyClientConfig::default(), None, None, None)
@@ -3,11 +3,13 @@ use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::consumer::Consumer;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
+use iggy::consumer_groups::get_consumer_group_assignment::GetConsumerGroupAssignment;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
-use iggy::models::consumer_group::ConsumerGroupDetails;
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
+use iggy::models::consumer_group::{ConsumerGroupDetails, PartitionAssignmentStrategy};
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::system::get_me::GetMe;
@@ -72,6 +74,10 @@ async fn init_system(
         message_expiry: None,
         max_topic_size: None,
         replication_factor: 1,
+        compression_algorithm: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+        flush_policy: FlushPolicy::default(),
     };
     system_client.create_topic(&create_topic).await.unwrap();
 
@@ -81,6 +87,8 @@ async fn init_system(
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         consumer_group_id: CONSUMER_GROUP_ID,
         name: CONSUMER_GROUP_NAME.to_string(),
+        session_timeout: None,
+        assignment_strategy: PartitionAssignmentStrategy::RoundRobin,
     };
     system_client
         .create_consumer_group(&create_group)
@@ -139,6 +147,7 @@ async fn execute_using_messages_key_key(
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::messages_key_u32(entity_id),
+            ack: AckLevel::InMemory,
             messages,
         };
         system_client
@@ -165,6 +174,8 @@ async fn poll_messages(client: &IggyClient) -> u32 {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        skip_poisoned: false,
+        generation: Some(get_generation(client).await),
     };
 
     let mut total_read_messages_count = 0;
@@ -176,6 +187,19 @@ async fn poll_messages(client: &IggyClient) -> u32 {
     total_read_messages_count
 }
 
+// The member polls with its current generation, so that it's fenced if the group is rebalanced in the meantime.
+async fn get_generation(client: &IggyClient) -> u32 {
+    client
+        .get_consumer_group_assignment(&GetConsumerGroupAssignment {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+        })
+        .await
+        .unwrap()
+        .generation
+}
+
 fn get_message_payload(entity_id: u32) -> String {
     format!("message-{}", entity_id)
 }
@@ -200,6 +224,7 @@ async fn execute_using_none_key(
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::balanced(),
+            ack: AckLevel::InMemory,
             messages,
         };
         system_client
@@ -248,6 +273,8 @@ async fn validate_message_polling(client: &IggyClient, consumer_group: &Consumer
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        skip_poisoned: false,
+        generation: Some(get_generation(client).await),
     };
 
     for i in 1..=MESSAGES_COUNT {
@@ -6,7 +6,9 @@ use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
+use iggy::models::consumer_group::PartitionAssignmentStrategy;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::system::get_me::GetMe;
@@ -59,6 +61,10 @@ async fn init_system(client: &IggyClient) {
         message_expiry: None,
         max_topic_size: None,
         replication_factor: 1,
+        compression_algorithm: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+        flush_policy: FlushPolicy::default(),
     };
     client.create_topic(&create_topic).await.unwrap();
 
@@ -68,6 +74,8 @@ async fn init_system(client: &IggyClient) {
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         consumer_group_id: CONSUMER_GROUP_ID,
         name: CONSUMER_GROUP_NAME.to_string(),
+        session_timeout: None,
+        assignment_strategy: PartitionAssignmentStrategy::RoundRobin,
     };
     client.create_consumer_group(&create_group).await.unwrap();
 
@@ -110,6 +118,7 @@ async fn execute_using_messages_key_key(client: &IggyClient) {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::messages_key_u32(entity_id),
+            ack: AckLevel::InMemory,
             messages,
         };
         client.send_messages(&mut send_messages).await.unwrap();
@@ -124,6 +133,8 @@ async fn execute_using_messages_key_key(client: &IggyClient) {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        skip_poisoned: false,
+        generation: None,
     };
 
     let mut total_read_messages_count = 0;
@@ -154,6 +165,7 @@ async fn execute_using_none_key(client: &IggyClient) {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::balanced(),
+            ack: AckLevel::InMemory,
             messages,
         };
         client.send_messages(&mut send_messages).await.unwrap();
@@ -168,6 +180,8 @@ async fn execute_using_none_key(client: &IggyClient) {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        skip_poisoned: false,
+        generation: None,
     };
 
     let mut partition_id = 1;
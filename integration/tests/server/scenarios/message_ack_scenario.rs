@@ -0,0 +1,123 @@
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
+use iggy::models::messages::PolledMessages;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::time::Duration;
+use tokio::time::sleep;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 1;
+const MESSAGES_COUNT: u32 = 10;
+const PARTITION_ID: u32 = 1;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Send the messages without waiting for any acknowledgement
+    client
+        .send_messages(&mut create_send_messages(AckLevel::None))
+        .await
+        .unwrap();
+
+    // 2. Send the messages and wait until they are persisted on disk
+    client
+        .send_messages(&mut create_send_messages(AckLevel::Persisted))
+        .await
+        .unwrap();
+
+    // 3. Poll the messages and validate that all of them were appended
+    let polled_messages = poll_messages(&client, 2 * MESSAGES_COUNT).await;
+    assert_eq!(polled_messages.messages.len() as u32, 2 * MESSAGES_COUNT);
+    for (offset, message) in polled_messages.messages.iter().enumerate() {
+        assert_eq!(message.offset, offset as u64);
+    }
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+fn create_send_messages(ack: AckLevel) -> SendMessages {
+    let messages = (0..MESSAGES_COUNT)
+        .map(|offset| Message::new(None, Bytes::from(format!("message {offset}")), None))
+        .collect();
+    SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        ack,
+        messages,
+    }
+}
+
+async fn poll_messages(client: &IggyClient, count: u32) -> PolledMessages {
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count,
+        auto_commit: false,
+        skip_poisoned: false,
+        generation: None,
+    };
+
+    // The messages sent without acknowledgement might not have been appended yet.
+    for _ in 0..50 {
+        let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+        if polled_messages.messages.len() as u32 == count {
+            return polled_messages;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    client.poll_messages(&poll_messages).await.unwrap()
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        max_topic_size: None,
+        replication_factor: 1,
+        compression_algorithm: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+        flush_policy: FlushPolicy::default(),
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
@@ -4,8 +4,9 @@ use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::consumer::Consumer;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
 use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -46,6 +47,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         partitioning: Partitioning::partition_id(PARTITION_ID),
+        ack: AckLevel::InMemory,
         messages,
     };
     client.send_messages(&mut send_messages).await.unwrap();
@@ -59,6 +61,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::offset(0),
         count: MESSAGES_COUNT,
         auto_commit: false,
+        skip_poisoned: false,
+        generation: None,
     };
 
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -111,6 +115,10 @@ async fn init_system(client: &IggyClient) {
         message_expiry: None,
         max_topic_size: None,
         replication_factor: 1,
+        compression_algorithm: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+        flush_policy: FlushPolicy::default(),
     };
     client.create_topic(&create_topic).await.unwrap();
 }
@@ -0,0 +1,190 @@
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig, PollMessagesConfig, StoreOffsetKind};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::reject_message::RejectMessage;
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
+use iggy::models::messages::{Message as ReceivedMessage, MessageState};
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::utils::crypto::Aes256GcmEncryptor;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::time::Duration;
+use tokio::time::timeout;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 1;
+const MESSAGES_COUNT: u32 = 10;
+const PARTITION_ID: u32 = 1;
+const REJECTED_OFFSET: u64 = 3;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Send the messages
+    let mut messages = Vec::new();
+    for offset in 0..MESSAGES_COUNT {
+        let id = (offset + 1) as u128;
+        let payload = Bytes::from(format!("message {}", offset));
+        messages.push(Message::new(Some(id), payload, None));
+    }
+
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        ack: AckLevel::InMemory,
+        messages,
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+
+    // 2. Reject the single message
+    let reject_message = RejectMessage {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: PARTITION_ID,
+        offset: REJECTED_OFFSET,
+        reason: "invalid payload".to_string(),
+    };
+    client.reject_message(&reject_message).await.unwrap();
+
+    // 3. Poll the messages and validate that the rejected one is poisoned
+    let mut poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: MESSAGES_COUNT,
+        auto_commit: false,
+        skip_poisoned: false,
+        generation: None,
+    };
+
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    for message in polled_messages.messages {
+        if message.offset == REJECTED_OFFSET {
+            assert_eq!(message.state, MessageState::Poisoned);
+        } else {
+            assert_eq!(message.state, MessageState::Available);
+        }
+    }
+
+    // 4. Poll the messages skipping the poisoned ones
+    poll_messages.skip_poisoned = true;
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT - 1);
+    assert!(polled_messages
+        .messages
+        .iter()
+        .all(|message| message.offset != REJECTED_OFFSET));
+
+    // 5. Rejecting the message with the offset which doesn't exist should fail
+    let reject_message = RejectMessage {
+        offset: MESSAGES_COUNT as u64,
+        ..reject_message
+    };
+    assert!(client.reject_message(&reject_message).await.is_err());
+
+    // 6. Send the message which cannot be decrypted by the consumer, followed by the encrypted one
+    let consumer = client_factory.create_client().await;
+    let mut consumer = IggyClient::create(
+        consumer,
+        IggyClientConfig::default(),
+        None,
+        None,
+        Some(Box::new(Aes256GcmEncryptor::new(&[1; 32]).unwrap())),
+    );
+    login_root(&consumer).await;
+    let undecodable_offset = MESSAGES_COUNT as u64;
+    let mut send_messages = SendMessages {
+        messages: vec![Message::new(None, Bytes::from("undecodable"), None)],
+        ..send_messages
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+    send_messages.messages = vec![Message::new(None, Bytes::from("encrypted"), None)];
+    consumer.send_messages(&mut send_messages).await.unwrap();
+
+    // 7. Poll the messages in the background and validate that the undecodable one is skipped without being rejected
+    let receiver = consumer.subscribe_to_polled_messages();
+    let polling = consumer.start_polling_messages(
+        PollMessages {
+            strategy: PollingStrategy::offset(undecodable_offset),
+            skip_poisoned: false,
+            ..poll_messages
+        },
+        None::<fn(ReceivedMessage)>,
+        Some(PollMessagesConfig {
+            interval: 10,
+            store_offset_kind: StoreOffsetKind::Never,
+            heartbeat_interval: 0,
+        }),
+    );
+    let message = timeout(Duration::from_secs(5), receiver.recv_async())
+        .await
+        .unwrap()
+        .unwrap();
+    polling.abort();
+    assert_eq!(message.offset, undecodable_offset + 1);
+    assert_eq!(message.payload, Bytes::from("encrypted"));
+
+    let polled_messages = client
+        .poll_messages(&PollMessages {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: Some(PARTITION_ID),
+            strategy: PollingStrategy::offset(undecodable_offset),
+            count: 1,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages[0].state, MessageState::Available);
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        max_topic_size: None,
+        replication_factor: 1,
+        compression_algorithm: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+        flush_policy: FlushPolicy::default(),
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
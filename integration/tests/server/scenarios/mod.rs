@@ -1,6 +1,9 @@
 pub mod consumer_group_join_scenario;
 pub mod consumer_group_with_multiple_clients_polling_messages_scenario;
 pub mod consumer_group_with_single_client_polling_messages_scenario;
+pub mod message_ack_scenario;
 pub mod message_headers_scenario;
+pub mod message_rejection_scenario;
+pub mod role_scenario;
 pub mod system_scenario;
 pub mod user_scenario;
@@ -0,0 +1,187 @@
+use iggy::client::{RoleClient, UserClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::identifier::Identifier;
+use iggy::models::permissions::{GlobalPermissions, Permissions};
+use iggy::models::user_status::UserStatus;
+use iggy::roles::create_role::CreateRole;
+use iggy::roles::delete_role::DeleteRole;
+use iggy::roles::get_role::GetRole;
+use iggy::roles::get_roles::GetRoles;
+use iggy::roles::update_role::UpdateRole;
+use iggy::users::assign_roles::AssignRoles;
+use iggy::users::create_user::CreateUser;
+use iggy::users::defaults::*;
+use iggy::users::delete_user::DeleteUser;
+use iggy::users::get_user::GetUser;
+use iggy::users::get_users::GetUsers;
+use iggy::users::login_user::LoginUser;
+use iggy::users::logout_user::LogoutUser;
+use integration::test_server::{assert_clean_system, ClientFactory};
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    let test_user = "user";
+    let test_password = "secret";
+    let test_role = "readers";
+
+    // 1. Login as root user and create a new user without any permissions
+    login_root(&client).await;
+    client
+        .create_user(&CreateUser {
+            username: test_user.to_string(),
+            password: test_password.to_string(),
+            status: UserStatus::Active,
+            permissions: None,
+        })
+        .await
+        .unwrap();
+
+    // 2. The new user should not be allowed to list the users
+    login_user(&client, test_user, test_password).await;
+    let get_users = client.get_users(&GetUsers {}).await;
+    assert!(get_users.is_err());
+
+    // 3. Trying to create a role without the appropriate permissions should fail
+    let create_role = client
+        .create_role(&CreateRole {
+            name: test_role.to_string(),
+            permissions: Permissions::default(),
+        })
+        .await;
+    assert!(create_role.is_err());
+
+    // 4. Create a new role allowing to read the users
+    login_root(&client).await;
+    client
+        .create_role(&CreateRole {
+            name: test_role.to_string(),
+            permissions: Permissions {
+                global: GlobalPermissions {
+                    read_users: true,
+                    ..Default::default()
+                },
+                streams: None,
+            },
+        })
+        .await
+        .unwrap();
+
+    // 5. Trying to create a role with the same name should fail
+    let create_duplicated_role = client
+        .create_role(&CreateRole {
+            name: test_role.to_string(),
+            permissions: Permissions::default(),
+        })
+        .await;
+    assert!(create_duplicated_role.is_err());
+
+    // 6. List all roles and get the role details
+    let roles = client.get_roles(&GetRoles {}).await.unwrap();
+    assert_eq!(roles.len(), 1);
+    let role = roles.first().unwrap();
+    assert_eq!(role.name, test_role);
+    assert!(role.created_at > 0);
+
+    let role = client
+        .get_role(&GetRole {
+            role_id: Identifier::named(test_role).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(role.name, test_role);
+    assert!(role.permissions.global.read_users);
+    assert!(!role.permissions.global.manage_users);
+
+    // 7. Assign the role to the user
+    client
+        .assign_roles(&AssignRoles {
+            user_id: Identifier::named(test_user).unwrap(),
+            roles: vec![Identifier::named(test_role).unwrap()],
+        })
+        .await
+        .unwrap();
+
+    let user = client
+        .get_user(&GetUser {
+            user_id: Identifier::named(test_user).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(user.roles, vec![role.id]);
+    assert!(user.permissions.is_none());
+
+    // 8. The user should be allowed to list the users using the role permissions
+    login_user(&client, test_user, test_password).await;
+    let users = client.get_users(&GetUsers {}).await.unwrap();
+    assert_eq!(users.len(), 2);
+
+    // 9. Updating the role permissions should be applied immediately to the user
+    login_root(&client).await;
+    client
+        .update_role(&UpdateRole {
+            role_id: Identifier::numeric(role.id).unwrap(),
+            name: None,
+            permissions: Some(Permissions::default()),
+        })
+        .await
+        .unwrap();
+
+    login_user(&client, test_user, test_password).await;
+    let get_users = client.get_users(&GetUsers {}).await;
+    assert!(get_users.is_err());
+
+    // 10. Deleting the role should unassign it from the user
+    login_root(&client).await;
+    client
+        .delete_role(&DeleteRole {
+            role_id: Identifier::named(test_role).unwrap(),
+        })
+        .await
+        .unwrap();
+
+    let roles = client.get_roles(&GetRoles {}).await.unwrap();
+    assert!(roles.is_empty());
+
+    let user = client
+        .get_user(&GetUser {
+            user_id: Identifier::named(test_user).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert!(user.roles.is_empty());
+
+    // 11. Assigning the non-existing role should fail
+    let assign_roles = client
+        .assign_roles(&AssignRoles {
+            user_id: Identifier::named(test_user).unwrap(),
+            roles: vec![Identifier::named(test_role).unwrap()],
+        })
+        .await;
+    assert!(assign_roles.is_err());
+
+    // 12. Delete the user
+    client
+        .delete_user(&DeleteUser {
+            user_id: Identifier::named(test_user).unwrap(),
+        })
+        .await
+        .unwrap();
+
+    assert_clean_system(&client).await;
+    client.logout_user(&LogoutUser {}).await.unwrap();
+}
+
+async fn login_root(client: &IggyClient) {
+    login_user(client, DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD).await;
+}
+
+async fn login_user(client: &IggyClient, username: &str, password: &str) {
+    client
+        .login_user(&LoginUser {
+            username: username.to_string(),
+            password: password.to_string(),
+        })
+        .await
+        .unwrap();
+}
@@ -4,6 +4,8 @@ use iggy::client::{
     SystemClient, TopicClient, UserClient,
 };
 use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::command::{CREATE_STREAM, DELETE_STREAM, LOGIN_USER};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
@@ -11,20 +13,29 @@ use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::get_consumer_groups::GetConsumerGroups;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use iggy::consumer_groups::reset_consumer_group_offsets::{
+    OffsetsResetStrategy, ResetConsumerGroupOffsets,
+};
 use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
+use iggy::models::audit_log::AuditOutcome;
+use iggy::models::consumer_group::PartitionAssignmentStrategy;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use iggy::partitions::get_partition_offsets::GetPartitionOffsets;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::get_stream::GetStream;
 use iggy::streams::get_streams::GetStreams;
 use iggy::streams::purge_stream::PurgeStream;
 use iggy::streams::update_stream::UpdateStream;
+use iggy::system::get_audit_log::GetAuditLog;
 use iggy::system::get_clients::GetClients;
 use iggy::system::get_me::GetMe;
 use iggy::system::get_stats::GetStats;
@@ -134,6 +145,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         message_expiry: None,
         max_topic_size: None,
         replication_factor: 1,
+        compression_algorithm: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+        flush_policy: FlushPolicy::default(),
     };
     client.create_topic(&create_topic).await.unwrap();
 
@@ -154,6 +169,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(topic.message_expiry, None);
     assert_eq!(topic.max_topic_size, None);
     assert_eq!(topic.replication_factor, 1);
+    assert_eq!(topic.compression_algorithm, CompressionAlgorithm::None);
 
     // 11. Get topic details by ID
     let topic = client
@@ -226,6 +242,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         partitioning: Partitioning::partition_id(PARTITION_ID),
+        ack: AckLevel::InMemory,
         messages,
     };
     client.send_messages(&mut send_messages).await.unwrap();
@@ -242,6 +259,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::offset(0),
         count: MESSAGES_COUNT,
         auto_commit: false,
+        skip_poisoned: false,
+        generation: None,
     };
 
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -268,6 +287,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             strategy: PollingStrategy::offset(start_offset),
             count: batch_size,
             auto_commit: false,
+            skip_poisoned: false,
+            generation: None,
         };
 
         let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -300,7 +321,57 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(topic_partition.current_offset, (MESSAGES_COUNT - 1) as u64);
     assert_eq!(topic_partition.messages_count, MESSAGES_COUNT as u64);
 
-    // 21. Ensure that messages do not exist in the second partition in the same topic
+    // 21. Get the partition offsets and the offsets for the timestamps of the polled messages
+    let partition_offsets = client
+        .get_partition_offsets(&GetPartitionOffsets {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(partition_offsets.len(), PARTITIONS_COUNT as usize);
+    for offsets in partition_offsets {
+        let latest_offset = match offsets.partition_id {
+            PARTITION_ID => (MESSAGES_COUNT - 1) as u64,
+            _ => 0,
+        };
+        assert_eq!(offsets.earliest_offset, 0);
+        assert_eq!(offsets.latest_offset, latest_offset);
+    }
+
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    let middle_timestamp = polled_messages.messages[(MESSAGES_COUNT / 2) as usize].timestamp;
+    let last_timestamp = polled_messages.messages.last().unwrap().timestamp;
+    for (timestamp, expected_offset) in [
+        (0, Some(0)),
+        (
+            middle_timestamp,
+            polled_messages
+                .messages
+                .iter()
+                .find(|message| message.timestamp >= middle_timestamp)
+                .map(|message| message.offset),
+        ),
+        (last_timestamp + 1, None),
+    ] {
+        let timestamp_offsets = client
+            .get_offsets_for_timestamp(&GetOffsetsForTimestamp {
+                stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+                topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+                timestamp,
+            })
+            .await
+            .unwrap();
+        assert_eq!(timestamp_offsets.len(), PARTITIONS_COUNT as usize);
+        for timestamp_offset in timestamp_offsets {
+            match timestamp_offset.partition_id {
+                PARTITION_ID => assert_eq!(timestamp_offset.offset, expected_offset),
+                _ => assert!(timestamp_offset.offset.is_none()),
+            }
+        }
+    }
+
+    // 22. Ensure that messages do not exist in the second partition in the same topic
     let poll_messages = PollMessages {
         consumer: Consumer {
             kind: CONSUMER_KIND,
@@ -312,11 +383,13 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::offset(0),
         count: MESSAGES_COUNT,
         auto_commit: false,
+        skip_poisoned: false,
+        generation: None,
     };
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
     assert!(polled_messages.messages.is_empty());
 
-    // 22. Get the existing customer offset and ensure it's 0
+    // 23. Get the existing customer offset and ensure it's 0
     let offset = client
         .get_consumer_offset(&GetConsumerOffset {
             consumer: Consumer {
@@ -333,7 +406,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(offset.current_offset, (MESSAGES_COUNT - 1) as u64);
     assert_eq!(offset.stored_offset, 0);
 
-    // 23. Store the consumer offset
+    // 24. Store the consumer offset
     let stored_offset = 10;
     client
         .store_consumer_offset(&StoreConsumerOffset {
@@ -345,11 +418,12 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partition_id: Some(PARTITION_ID),
             offset: stored_offset,
+            generation: None,
         })
         .await
         .unwrap();
 
-    // 24. Get the existing customer offset and ensure it's the previously stored value
+    // 25. Get the existing customer offset and ensure it's the previously stored value
     let offset = client
         .get_consumer_offset(&GetConsumerOffset {
             consumer: Consumer {
@@ -366,7 +440,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(offset.current_offset, (MESSAGES_COUNT - 1) as u64);
     assert_eq!(offset.stored_offset, stored_offset);
 
-    // 25. Poll messages from the specific partition in topic using next with auto commit
+    // 26. Poll messages from the specific partition in topic using next with auto commit
     let messages_count = 10;
     let poll_messages = PollMessages {
         consumer: Consumer {
@@ -379,6 +453,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::next(),
         count: messages_count,
         auto_commit: true,
+        skip_poisoned: false,
+        generation: None,
     };
 
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -389,7 +465,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(first_offset, stored_offset + 1);
     assert_eq!(last_offset, expected_last_offset);
 
-    // 26. Get the existing customer offset and ensure that auto commit during poll has worked
+    // 27. Get the existing customer offset and ensure that auto commit during poll has worked
     let offset = client
         .get_consumer_offset(&GetConsumerOffset {
             consumer: Consumer {
@@ -406,7 +482,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(offset.current_offset, (MESSAGES_COUNT - 1) as u64);
     assert_eq!(offset.stored_offset, expected_last_offset);
 
-    // 27. Get the consumer groups and validate that there are no groups
+    // 28. Get the consumer groups and validate that there are no groups
     let consumer_groups = client
         .get_consumer_groups(&GetConsumerGroups {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -417,18 +493,20 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert!(consumer_groups.is_empty());
 
-    // 28. Create the consumer group
+    // 29. Create the consumer group
     client
         .create_consumer_group(&CreateConsumerGroup {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             consumer_group_id: CONSUMER_GROUP_ID,
             name: CONSUMER_GROUP_NAME.to_string(),
+            session_timeout: None,
+            assignment_strategy: PartitionAssignmentStrategy::RoundRobin,
         })
         .await
         .unwrap();
 
-    // 29. Get the consumer groups and validate that there is one group
+    // 30. Get the consumer groups and validate that there is one group
     let consumer_groups = client
         .get_consumer_groups(&GetConsumerGroups {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -443,7 +521,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(consumer_group.partitions_count, PARTITIONS_COUNT);
     assert_eq!(consumer_group.members_count, 0);
 
-    // 30. Get the consumer group details
+    // 31. Get the consumer group details
     let consumer_group = client
         .get_consumer_group(&GetConsumerGroup {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -458,7 +536,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(consumer_group.members_count, 0);
     assert!(consumer_group.members.is_empty());
 
-    // 31. Join the consumer group and then leave it if the feature is available
+    // 32. Join the consumer group and then leave it if the feature is available
     let result = client
         .join_consumer_group(&JoinConsumerGroup {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -521,7 +599,115 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         Err(e) => assert_eq!(e.as_code(), Error::FeatureUnavailable.as_code()),
     }
 
-    // 32. Get the stats and validate that there is one stream
+    // 33. Reset the consumer group offsets in the dry run mode and then for real, and validate them
+    let offsets = client
+        .reset_consumer_group_offsets(&ResetConsumerGroupOffsets {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+            partition_ids: vec![],
+            strategy: OffsetsResetStrategy::Latest,
+            dry_run: true,
+        })
+        .await
+        .unwrap();
+    assert_eq!(offsets.len(), PARTITIONS_COUNT as usize);
+    for (index, offset) in offsets.iter().enumerate() {
+        let partition_id = index as u32 + 1;
+        assert_eq!(offset.partition_id, partition_id);
+        assert_eq!(offset.previous_offset, 0);
+        if partition_id == PARTITION_ID {
+            assert_eq!(offset.new_offset, MESSAGES_COUNT as u64);
+        } else {
+            assert_eq!(offset.new_offset, 0);
+        }
+    }
+
+    let offsets = client
+        .reset_consumer_group_offsets(&ResetConsumerGroupOffsets {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+            partition_ids: vec![PARTITION_ID],
+            strategy: OffsetsResetStrategy::Offset(500),
+            dry_run: false,
+        })
+        .await
+        .unwrap();
+    assert_eq!(offsets.len(), 1);
+    assert_eq!(offsets[0].partition_id, PARTITION_ID);
+    assert_eq!(offsets[0].previous_offset, 0);
+    assert_eq!(offsets[0].new_offset, 500);
+
+    let offsets = client
+        .reset_consumer_group_offsets(&ResetConsumerGroupOffsets {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+            partition_ids: vec![PARTITION_ID],
+            strategy: OffsetsResetStrategy::Shift(-100),
+            dry_run: true,
+        })
+        .await
+        .unwrap();
+    assert_eq!(offsets[0].previous_offset, 500);
+    assert_eq!(offsets[0].new_offset, 400);
+
+    let offsets = client
+        .reset_consumer_group_offsets(&ResetConsumerGroupOffsets {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+            partition_ids: vec![PARTITION_ID],
+            strategy: OffsetsResetStrategy::Shift(10_000),
+            dry_run: false,
+        })
+        .await
+        .unwrap();
+    assert_eq!(offsets[0].previous_offset, 500);
+    assert_eq!(offsets[0].new_offset, MESSAGES_COUNT as u64);
+
+    let offsets = client
+        .reset_consumer_group_offsets(&ResetConsumerGroupOffsets {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+            partition_ids: vec![PARTITION_ID],
+            strategy: OffsetsResetStrategy::Earliest,
+            dry_run: false,
+        })
+        .await
+        .unwrap();
+    assert_eq!(offsets[0].previous_offset, MESSAGES_COUNT as u64);
+    assert_eq!(offsets[0].new_offset, 0);
+
+    let offsets = client
+        .reset_consumer_group_offsets(&ResetConsumerGroupOffsets {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+            partition_ids: vec![PARTITION_ID],
+            strategy: OffsetsResetStrategy::Earliest,
+            dry_run: true,
+        })
+        .await
+        .unwrap();
+    assert_eq!(offsets[0].previous_offset, 0);
+    assert_eq!(offsets[0].new_offset, 0);
+
+    let reset_offsets = client
+        .reset_consumer_group_offsets(&ResetConsumerGroupOffsets {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+            partition_ids: vec![PARTITIONS_COUNT + 1],
+            strategy: OffsetsResetStrategy::Earliest,
+            dry_run: true,
+        })
+        .await;
+    assert!(reset_offsets.is_err());
+
+    // 34. Get the stats and validate that there is one stream
     let stats = client.get_stats(&GetStats {}).await.unwrap();
     assert!(!stats.hostname.is_empty());
     assert!(!stats.os_name.is_empty());
@@ -533,7 +719,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(stats.segments_count, PARTITIONS_COUNT);
     assert_eq!(stats.messages_count, MESSAGES_COUNT as u64);
 
-    // 33. Delete the consumer group
+    // 35. Delete the consumer group
     client
         .delete_consumer_group(&DeleteConsumerGroup {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -543,7 +729,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .await
         .unwrap();
 
-    // 34. Create new partitions and validate that the number of partitions is increased
+    // 36. Create new partitions and validate that the number of partitions is increased
     client
         .create_partitions(&CreatePartitions {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -563,7 +749,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_eq!(topic.partitions_count, 2 * PARTITIONS_COUNT);
 
-    // 35. Delete the partitions and validate that the number of partitions is decreased
+    // 37. Delete the partitions and validate that the number of partitions is decreased
     client
         .delete_partitions(&DeletePartitions {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -583,7 +769,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
 
-    // 36. Update the existing topic and ensure it's updated
+    // 38. Update the existing topic and ensure it's updated
     let updated_topic_name = format!("{}-updated", TOPIC_NAME);
     let updated_message_expiry = 1000;
     let updated_max_topic_size = IggyByteSize::from(0x1337);
@@ -597,6 +783,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             message_expiry: Some(updated_message_expiry),
             max_topic_size: Some(updated_max_topic_size),
             replication_factor: updated_replication_factor,
+            compression_algorithm: None,
+            cleanup_policy: None,
+            max_topic_size_policy: None,
+            flush_policy: None,
         })
         .await
         .unwrap();
@@ -613,8 +803,18 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(updated_topic.message_expiry, Some(updated_message_expiry));
     assert_eq!(updated_topic.max_topic_size, Some(updated_max_topic_size));
     assert_eq!(updated_topic.replication_factor, updated_replication_factor);
-
-    // 37. Purge the existing topic and ensure it has no messages
+    assert_eq!(
+        updated_topic.compression_algorithm,
+        topic.compression_algorithm
+    );
+    assert_eq!(updated_topic.cleanup_policy, topic.cleanup_policy);
+    assert_eq!(
+        updated_topic.max_topic_size_policy,
+        topic.max_topic_size_policy
+    );
+    assert_eq!(updated_topic.flush_policy, topic.flush_policy);
+
+    // 39. Purge the existing topic and ensure it has no messages
     client
         .purge_topic(&PurgeTopic {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -627,7 +827,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(polled_messages.current_offset, 0);
     assert!(polled_messages.messages.is_empty());
 
-    // 38. Update the existing stream and ensure it's updated
+    // 40. Update the existing stream and ensure it's updated
     let updated_stream_name = format!("{}-updated", STREAM_NAME);
 
     client
@@ -647,12 +847,13 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_eq!(updated_stream.name, updated_stream_name);
 
-    // 39. Purge the existing stream and ensure it has no messages
+    // 41. Purge the existing stream and ensure it has no messages
     let messages = create_messages();
     let mut send_messages = SendMessages {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         partitioning: Partitioning::partition_id(PARTITION_ID),
+        ack: AckLevel::InMemory,
         messages,
     };
     client.send_messages(&mut send_messages).await.unwrap();
@@ -668,7 +869,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(polled_messages.current_offset, 0);
     assert!(polled_messages.messages.is_empty());
 
-    // 40. Delete the existing topic and ensure it doesn't exist anymore
+    // 42. Delete the existing topic and ensure it doesn't exist anymore
     client
         .delete_topic(&DeleteTopic {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -684,7 +885,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .unwrap();
     assert!(topics.is_empty());
 
-    // 41. Delete the existing stream and ensure it doesn't exist anymore
+    // 43. Delete the existing stream and ensure it doesn't exist anymore
     client
         .delete_stream(&DeleteStream {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -694,11 +895,40 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let streams = client.get_streams(&GetStreams {}).await.unwrap();
     assert!(streams.is_empty());
 
-    // 42. Get clients and ensure that there's 0 (HTTP) or 1 (TCP, QUIC) client
+    // 44. Get clients and ensure that there's 0 (HTTP) or 1 (TCP, QUIC) client
     let clients = client.get_clients(&GetClients {}).await.unwrap();
 
     assert!(clients.len() <= 1);
 
+    // 45. Get the audit log and ensure that the actions of the root user have been recorded, including the failed ones
+    let entries = client
+        .get_audit_log(&GetAuditLog {
+            count: 1000,
+            user_id: Some(DEFAULT_ROOT_USER_ID),
+        })
+        .await
+        .unwrap();
+    assert!(entries
+        .iter()
+        .all(|entry| entry.user_id == DEFAULT_ROOT_USER_ID));
+    assert!(entries
+        .iter()
+        .any(|entry| entry.command == LOGIN_USER && entry.outcome == AuditOutcome::Success));
+    let stream_entries = entries
+        .iter()
+        .filter(|entry| entry.command == CREATE_STREAM)
+        .collect::<Vec<_>>();
+    assert_eq!(stream_entries.len(), 3);
+    assert_eq!(stream_entries[0].outcome, AuditOutcome::Success);
+    assert!(stream_entries[0].error.is_none());
+    assert!(stream_entries[1..]
+        .iter()
+        .all(|entry| entry.outcome == AuditOutcome::Failure && entry.error.is_some()));
+    let last_entry = entries.last().unwrap();
+    assert_eq!(last_entry.command, DELETE_STREAM);
+    assert_eq!(last_entry.outcome, AuditOutcome::Success);
+    assert!(!last_entry.address.is_empty());
+
     assert_clean_system(&client).await;
 }
 
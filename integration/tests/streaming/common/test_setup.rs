@@ -1,7 +1,7 @@
 use server::configs::system::SystemConfig;
+use server::streaming::metadata::database::{open_database, Database};
 use server::streaming::persistence::persister::FilePersister;
 use server::streaming::storage::SystemStorage;
-use sled::Db;
 use std::sync::Arc;
 use tokio::fs;
 use uuid::Uuid;
@@ -9,7 +9,7 @@ use uuid::Uuid;
 pub struct TestSetup {
     pub config: Arc<SystemConfig>,
     pub storage: Arc<SystemStorage>,
-    pub db: Arc<Db>,
+    pub db: Arc<dyn Database>,
 }
 
 impl TestSetup {
@@ -23,7 +23,7 @@ impl TestSetup {
         let config = Arc::new(config);
         fs::create_dir(config.get_system_path()).await.unwrap();
         let persister = FilePersister {};
-        let db = Arc::new(sled::open(config.get_database_path()).unwrap());
+        let db = open_database(config.database.backend, &config.get_database_path()).unwrap();
         let storage = Arc::new(SystemStorage::new(db.clone(), Arc::new(persister)));
         TestSetup {
             config,
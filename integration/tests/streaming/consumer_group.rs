@@ -1,5 +1,7 @@
 use crate::streaming::common::test_setup::TestSetup;
 use iggy::identifier::Identifier;
+use iggy::models::consumer_group::PartitionAssignmentStrategy;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use server::streaming::topics::topic::Topic;
 
 #[tokio::test]
@@ -10,13 +12,23 @@ async fn should_persist_consumer_group_and_then_load_it_from_disk() {
     let consumer_group_id = 1;
     let consumer_group_name = "test";
     topic
-        .create_consumer_group(consumer_group_id, consumer_group_name)
+        .create_consumer_group(
+            consumer_group_id,
+            consumer_group_name,
+            Some(10),
+            PartitionAssignmentStrategy::Sticky,
+        )
         .await
         .unwrap();
 
     let consumer_groups = storage.load_consumer_groups(&topic).await.unwrap();
     assert_eq!(consumer_groups.len(), 1);
     let consumer_group = consumer_groups.first().unwrap();
+    assert_eq!(consumer_group.session_timeout, Some(10));
+    assert_eq!(
+        consumer_group.assignment_strategy,
+        PartitionAssignmentStrategy::Sticky
+    );
 
     let consumer_group_by_id = topic
         .get_consumer_group(&Identifier::numeric(consumer_group_id).unwrap())
@@ -47,7 +59,12 @@ async fn should_delete_consumer_group_from_disk() {
     let consumer_group_id = 1;
     let consumer_group_name = "test";
     topic
-        .create_consumer_group(consumer_group_id, consumer_group_name)
+        .create_consumer_group(
+            consumer_group_id,
+            consumer_group_name,
+            None,
+            PartitionAssignmentStrategy::RoundRobin,
+        )
         .await
         .unwrap();
 
@@ -83,6 +100,10 @@ async fn init_topic(setup: &TestSetup) -> Topic {
         None,
         None,
         1,
+        None,
+        CleanupPolicy::Delete,
+        MaxTopicSizePolicy::DeleteOldest,
+        FlushPolicy::default(),
     )
     .unwrap();
     topic.persist().await.unwrap();
@@ -1,13 +1,17 @@
 use crate::streaming::common::test_setup::TestSetup;
 use bytes::Bytes;
+use iggy::messages::send_messages::{AckLevel, MESSAGE_KEY_HEADER};
 use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::models::messages::{Message, MessageState};
+use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::{checksum, timestamp::IggyTimestamp};
-use server::configs::system::{PartitionConfig, SystemConfig};
+use server::configs::system::{PartitionConfig, SegmentConfig, SystemConfig};
+use server::streaming::partitions::compaction;
 use server::streaming::partitions::partition::Partition;
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
+use tokio::sync::RwLock;
 
 #[tokio::test]
 async fn should_persist_messages_and_then_load_them_from_disk() {
@@ -112,3 +116,290 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
         assert_eq!(loaded_message.headers, appended_message.headers);
     }
 }
+
+#[tokio::test]
+async fn should_persist_messages_sent_with_persisted_ack_without_waiting_for_more_messages() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 1;
+    let partition_id = 1;
+    let messages_count = 10;
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.to_string(),
+        partition: PartitionConfig {
+            messages_required_to_save: 1000,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    let messages = (1..=messages_count)
+        .map(|id| {
+            let payload = Bytes::from(format!("message {}", id));
+            let checksum = checksum::calculate(&payload);
+            Message::create(
+                0,
+                MessageState::Available,
+                IggyTimestamp::now().to_micros(),
+                id as u128,
+                payload,
+                checksum,
+                None,
+            )
+        })
+        .collect();
+
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    partition.persist().await.unwrap();
+    partition
+        .append_messages_with_ack(messages, AckLevel::Persisted)
+        .await
+        .unwrap();
+    assert_eq!(partition.unsaved_messages_count, 0);
+
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition.partition_id,
+        false,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_partition.load().await.unwrap();
+    let loaded_messages = loaded_partition
+        .get_messages_by_offset(0, messages_count)
+        .await
+        .unwrap();
+    assert_eq!(loaded_messages.len(), messages_count as usize);
+}
+
+#[tokio::test]
+async fn should_compact_closed_segments_keeping_only_the_latest_message_for_each_key() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 1;
+    let partition_id = 1;
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.to_string(),
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        },
+        segment: SegmentConfig {
+            size: IggyByteSize::from(1),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    partition.persist().await.unwrap();
+
+    // Each message is stored in its own segment, as the segment size is exceeded by a single message.
+    let messages = [
+        (Some("a"), "a1"),
+        (Some("b"), "b1"),
+        (None, "c1"),
+        (Some("a"), "a2"),
+        (Some("b"), ""),
+        (Some("a"), "a3"),
+    ];
+    for (id, (key, payload)) in messages.iter().enumerate() {
+        let payload = Bytes::from(payload.to_string());
+        let checksum = checksum::calculate(&payload);
+        let headers = key.map(|key| {
+            HashMap::from([(
+                HeaderKey::new(MESSAGE_KEY_HEADER).unwrap(),
+                HeaderValue::from_raw(key.as_bytes()).unwrap(),
+            )])
+        });
+        let message = Message::create(
+            0,
+            MessageState::Available,
+            IggyTimestamp::now().to_micros(),
+            1 + id as u128,
+            payload,
+            checksum,
+            headers,
+        );
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+
+    assert_eq!(partition.get_messages_count(), 6);
+    let partition = RwLock::new(partition);
+    let removed_messages_count = compaction::compact_segments(&partition, 0).await.unwrap();
+    assert_eq!(removed_messages_count, 3);
+    assert_eq!(partition.read().await.get_messages_count(), 3);
+    let compacted_messages = partition
+        .read()
+        .await
+        .get_messages_by_offset(0, 10)
+        .await
+        .unwrap();
+    let offsets = compacted_messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<u64>>();
+    assert_eq!(offsets, vec![2, 4, 5]);
+    assert!(compacted_messages[1].payload.is_empty());
+    assert_eq!(compacted_messages[2].payload, Bytes::from("a3"));
+
+    // The removed offsets are skipped, so the poll returns the requested count of the retained messages.
+    let first_messages = partition.read().await.get_first_messages(2).await.unwrap();
+    let offsets = first_messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<u64>>();
+    assert_eq!(offsets, vec![2, 4]);
+
+    let tombstone_expiry = IggyTimestamp::now().to_micros() + 1;
+    let removed_messages_count = compaction::compact_segments(&partition, tombstone_expiry)
+        .await
+        .unwrap();
+    assert_eq!(removed_messages_count, 1);
+    assert_eq!(partition.read().await.get_messages_count(), 2);
+
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        false,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_partition.load().await.unwrap();
+    assert_eq!(loaded_partition.current_offset, 5);
+    assert_eq!(loaded_partition.get_messages_count(), 2);
+    let loaded_messages = loaded_partition
+        .get_messages_by_offset(0, 10)
+        .await
+        .unwrap();
+    let offsets = loaded_messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<u64>>();
+    assert_eq!(offsets, vec![2, 5]);
+    assert_eq!(loaded_messages[0].payload, Bytes::from("c1"));
+    assert_eq!(loaded_messages[1].payload, Bytes::from("a3"));
+}
+
+#[tokio::test]
+async fn should_poll_the_retained_messages_across_the_gap_left_by_compaction() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 1;
+    let partition_id = 1;
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.to_string(),
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        },
+        segment: SegmentConfig {
+            size: IggyByteSize::from(1),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    partition.persist().await.unwrap();
+
+    // Each batch is stored in its own segment, so the first segment has the gap of 3 offsets after the compaction.
+    let batches = [vec!["a", "a", "a", "a", "b"], vec!["c"]];
+    let mut id = 0;
+    for batch in batches {
+        let messages = batch
+            .into_iter()
+            .map(|key| {
+                id += 1;
+                let payload = Bytes::from(format!("{}{}", key, id));
+                let checksum = checksum::calculate(&payload);
+                let headers = HashMap::from([(
+                    HeaderKey::new(MESSAGE_KEY_HEADER).unwrap(),
+                    HeaderValue::from_raw(key.as_bytes()).unwrap(),
+                )]);
+                Message::create(
+                    0,
+                    MessageState::Available,
+                    IggyTimestamp::now().to_micros(),
+                    id,
+                    payload,
+                    checksum,
+                    Some(headers),
+                )
+            })
+            .collect();
+        partition.append_messages(messages).await.unwrap();
+    }
+
+    let partition = RwLock::new(partition);
+    let removed_messages_count = compaction::compact_segments(&partition, 0).await.unwrap();
+    assert_eq!(removed_messages_count, 3);
+
+    let partition = partition.read().await;
+    let first_messages = partition.get_first_messages(2).await.unwrap();
+    let offsets = first_messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<u64>>();
+    assert_eq!(offsets, vec![3, 4]);
+
+    let messages = partition.get_messages_by_offset(1, 1).await.unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].offset, 3);
+
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        false,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_partition.load().await.unwrap();
+    let loaded_messages = loaded_partition.get_first_messages(2).await.unwrap();
+    let offsets = loaded_messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<u64>>();
+    assert_eq!(offsets, vec![3, 4]);
+
+    // The compacted segment isn't loaded to the cache, as the cache relies on the contiguous offsets.
+    let newest_messages = loaded_partition
+        .get_newest_messages_by_size(u32::MAX)
+        .await
+        .unwrap();
+    let offsets = newest_messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<u64>>();
+    assert_eq!(offsets, vec![5]);
+}
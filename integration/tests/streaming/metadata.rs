@@ -0,0 +1,86 @@
+use crate::streaming::common::test_setup::TestSetup;
+use iggy::models::user_status::UserStatus;
+use server::configs::system::{DatabaseBackend, SystemConfig};
+use server::streaming::metadata::database::{migrate_database, open_database};
+use server::streaming::persistence::persister::FilePersister;
+use server::streaming::storage::SystemStorage;
+use server::streaming::users::user::User;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn users_should_be_saved_loaded_and_deleted_using_each_database_backend() {
+    for backend in [DatabaseBackend::Sled, DatabaseBackend::Redb] {
+        let setup = init_with_backend(backend).await;
+        let user1 = create_user(1);
+        let user2 = create_user(2);
+        setup.storage.user.save(&user1).await.unwrap();
+        setup.storage.user.save(&user2).await.unwrap();
+
+        let loaded_user = setup.storage.user.load_by_username("user2").await.unwrap();
+        assert_eq!(loaded_user.id, user2.id);
+        assert_eq!(setup.storage.user.load_all().await.unwrap().len(), 2);
+
+        setup.storage.user.delete(&user1).await.unwrap();
+        let users = setup.storage.user.load_all().await.unwrap();
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0].username, user2.username);
+        assert!(setup.storage.user.load_by_username("user1").await.is_err());
+    }
+}
+
+#[tokio::test]
+async fn metadata_should_be_migrated_from_sled_to_redb_database() {
+    let setup = init_with_backend(DatabaseBackend::Sled).await;
+    let users_count = 3;
+    for id in 1..=users_count {
+        setup.storage.user.save(&create_user(id)).await.unwrap();
+    }
+
+    let target_path = format!("{}/metadata.redb", setup.config.get_system_path());
+    let target = open_database(DatabaseBackend::Redb, &target_path).unwrap();
+    let migrated_entries_count = migrate_database(setup.db.as_ref(), target.as_ref()).unwrap();
+    // Each user is stored under its ID and the username keys.
+    assert_eq!(migrated_entries_count, 2 * users_count as u64);
+
+    let storage = SystemStorage::new(target.clone(), Arc::new(FilePersister {}));
+    let users = storage.user.load_all().await.unwrap();
+    assert_eq!(users.len(), users_count as usize);
+    let loaded_user = storage.user.load_by_username("user2").await.unwrap();
+    assert_eq!(loaded_user.id, 2);
+
+    assert!(migrate_database(setup.db.as_ref(), target.as_ref()).is_err());
+}
+
+#[tokio::test]
+async fn redb_database_should_not_collide_with_sled_database_using_same_path() {
+    let setup = init_with_backend(DatabaseBackend::Sled).await;
+    setup.storage.user.save(&create_user(1)).await.unwrap();
+    let mut config = SystemConfig::default();
+    config.path = setup.config.path.clone();
+    config.database.backend = DatabaseBackend::Redb;
+    assert_eq!(
+        config.get_database_path(),
+        format!("{}.redb", setup.config.get_database_path())
+    );
+
+    let target = open_database(DatabaseBackend::Redb, &config.get_database_path()).unwrap();
+    migrate_database(setup.db.as_ref(), target.as_ref()).unwrap();
+    let storage = SystemStorage::new(target, Arc::new(FilePersister {}));
+    assert_eq!(storage.user.load_all().await.unwrap().len(), 1);
+}
+
+async fn init_with_backend(backend: DatabaseBackend) -> TestSetup {
+    let mut config = SystemConfig::default();
+    config.database.backend = backend;
+    TestSetup::init_with_config(config).await
+}
+
+fn create_user(id: u32) -> User {
+    User::new(
+        id,
+        &format!("user{}", id),
+        "secret",
+        UserStatus::Active,
+        None,
+    )
+}
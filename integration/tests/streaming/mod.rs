@@ -6,8 +6,10 @@ mod common;
 mod consumer_group;
 mod consumer_offset;
 mod messages;
+mod metadata;
 mod partition;
 mod personal_access_token;
+mod role;
 mod segment;
 mod stream;
 mod system;
@@ -1,8 +1,27 @@
 use crate::streaming::common::test_setup::TestSetup;
 use crate::streaming::create_messages;
+use bytes::Bytes;
+use iggy::consumer_groups::reset_consumer_group_offsets::OffsetsResetStrategy;
+use iggy::error::Error;
+use iggy::messages::send_messages::MESSAGE_KEY_HEADER;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::{Message, MessageState};
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::checksum;
+use iggy::utils::timestamp::IggyTimestamp;
+use server::configs::system::{CacheConfig, PartitionConfig, SegmentConfig, SystemConfig};
+use server::streaming::partitions::compaction;
 use server::streaming::partitions::partition::Partition;
+use server::streaming::partitions::tiering::{self, PartitionRead};
+use server::streaming::persistence::persister::FilePersister;
+use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION};
+use server::streaming::storage::SystemStorage;
+use server::streaming::tiering::local::LocalTieredStorage;
+use std::collections::HashMap;
+use std::sync::Arc;
 use tokio::fs;
+use tokio::sync::RwLock;
 
 #[tokio::test]
 async fn should_persist_partition_with_segment() {
@@ -146,6 +165,347 @@ async fn should_purge_existing_partition_on_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_offload_closed_segments_to_tiered_storage_and_fetch_them_back() {
+    let setup = TestSetup::init_with_config(SystemConfig {
+        cache: CacheConfig {
+            enabled: false,
+            ..Default::default()
+        },
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        },
+        segment: SegmentConfig {
+            size: IggyByteSize::from(1),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let tiered_storage_path = format!("{}/tiered", setup.config.get_system_path());
+    let mut storage = SystemStorage::new(setup.db.clone(), Arc::new(FilePersister {}));
+    storage.tiered = Some(Arc::new(LocalTieredStorage::new(&tiered_storage_path)));
+    let storage = Arc::new(storage);
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        setup.config.clone(),
+        storage.clone(),
+        None,
+    );
+    partition.persist().await.unwrap();
+
+    // Each message is stored in its own segment, as the segment size is exceeded by a single message.
+    let messages = create_messages();
+    let messages_count = messages.len();
+    for message in messages {
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+
+    let partition = RwLock::new(partition);
+    let now = IggyTimestamp::now().to_micros();
+    let offloaded_segments_count = tiering::offload_segments(&partition, now).await.unwrap();
+    assert_eq!(offloaded_segments_count, messages_count as u32);
+    for segment in partition
+        .read()
+        .await
+        .get_segments()
+        .iter()
+        .filter(|s| s.is_closed)
+    {
+        let remote_log_path = format!(
+            "{}/{}/{}/{}/{:0>20}.{}",
+            tiered_storage_path,
+            stream_id,
+            topic_id,
+            partition_id,
+            segment.start_offset,
+            LOG_EXTENSION
+        );
+        assert!(segment.is_offloaded());
+        assert!(fs::metadata(&segment.log_path).await.is_err());
+        assert!(fs::metadata(&segment.index_path).await.is_ok());
+        assert!(fs::metadata(&remote_log_path).await.is_ok());
+    }
+
+    // The offloaded log files are fetched only once by the concurrent readers, without holding the partition lock.
+    let result = partition.read().await.get_messages_by_offset(0, 100).await;
+    assert!(matches!(result, Err(Error::RemoteSegmentNotFetched(0, _))));
+    let (loaded_messages, concurrently_loaded_messages) = tokio::join!(
+        tiering::read_partition(&partition, read_all_messages),
+        tiering::read_partition(&partition, read_all_messages)
+    );
+    assert_eq!(loaded_messages.unwrap().len(), messages_count);
+    assert_eq!(concurrently_loaded_messages.unwrap().len(), messages_count);
+    for segment in partition.read().await.get_segments() {
+        assert!(!segment.is_offloaded());
+        let download_path = format!("{}.download", segment.log_path);
+        assert!(fs::metadata(&download_path).await.is_err());
+    }
+    let offloaded_segments_count = tiering::offload_segments(&partition, now).await.unwrap();
+    assert_eq!(offloaded_segments_count, messages_count as u32);
+
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        false,
+        setup.config.clone(),
+        storage.clone(),
+        None,
+    );
+    loaded_partition.load().await.unwrap();
+    assert_eq!(loaded_partition.get_segments().len(), messages_count);
+    assert!(loaded_partition.get_segments()[0].is_offloaded());
+    let loaded_partition = RwLock::new(loaded_partition);
+    let loaded_messages = tiering::read_partition(&loaded_partition, read_all_messages)
+        .await
+        .unwrap();
+    assert_eq!(loaded_messages.len(), messages_count);
+
+    loaded_partition.into_inner().delete().await.unwrap();
+    let remote_partition_path = format!(
+        "{}/{}/{}/{}",
+        tiered_storage_path, stream_id, topic_id, partition_id
+    );
+    let mut remote_files = fs::read_dir(&remote_partition_path).await.unwrap();
+    assert!(remote_files.next_entry().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn should_not_compact_offloaded_segments_and_keep_them_readable() {
+    let setup = TestSetup::init_with_config(SystemConfig {
+        cache: CacheConfig {
+            enabled: false,
+            ..Default::default()
+        },
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        },
+        segment: SegmentConfig {
+            size: IggyByteSize::from(1),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let tiered_storage_path = format!("{}/tiered", setup.config.get_system_path());
+    let mut storage = SystemStorage::new(setup.db.clone(), Arc::new(FilePersister {}));
+    storage.tiered = Some(Arc::new(LocalTieredStorage::new(&tiered_storage_path)));
+    let storage = Arc::new(storage);
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        setup.config.clone(),
+        storage.clone(),
+        None,
+    );
+    partition.persist().await.unwrap();
+
+    // Each message with the same key is stored in its own segment, so all but the last one could be compacted.
+    let payloads = ["a1", "a2", "a3"];
+    for (id, payload) in payloads.iter().enumerate() {
+        let payload = Bytes::from(payload.to_string());
+        let headers = HashMap::from([(
+            HeaderKey::new(MESSAGE_KEY_HEADER).unwrap(),
+            HeaderValue::from_raw(b"a").unwrap(),
+        )]);
+        let message = Message::create(
+            0,
+            MessageState::Available,
+            IggyTimestamp::now().to_micros(),
+            1 + id as u128,
+            payload.clone(),
+            checksum::calculate(&payload),
+            Some(headers),
+        );
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+
+    let partition = RwLock::new(partition);
+    let now = IggyTimestamp::now().to_micros();
+    let offloaded_segments_count = tiering::offload_segments(&partition, now).await.unwrap();
+    assert_eq!(offloaded_segments_count, payloads.len() as u32);
+
+    // The offloaded segments are fetched back by polling, then compacted and offloaded again.
+    for _ in 0..2 {
+        let loaded_messages = tiering::read_partition(&partition, |partition| {
+            Box::pin(partition.get_messages_by_offset(0, 10))
+        })
+        .await
+        .unwrap();
+        let loaded_payloads = loaded_messages
+            .iter()
+            .map(|message| message.payload.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(loaded_payloads, payloads.map(Bytes::from));
+
+        let removed_messages_count = compaction::compact_segments(&partition, 0).await.unwrap();
+        assert_eq!(removed_messages_count, 0);
+        let offloaded_segments_count = tiering::offload_segments(&partition, now).await.unwrap();
+        assert_eq!(offloaded_segments_count, payloads.len() as u32);
+    }
+
+    // The messages count of the offloaded segments is restored from the markers, without fetching their logs.
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        false,
+        setup.config.clone(),
+        storage.clone(),
+        None,
+    );
+    loaded_partition.load().await.unwrap();
+    assert_eq!(loaded_partition.get_messages_count(), payloads.len() as u64);
+}
+
+#[tokio::test]
+async fn should_find_offsets_for_timestamps_across_segments_with_sparse_time_indexes() {
+    let setup = TestSetup::init_with_config(SystemConfig {
+        cache: CacheConfig {
+            enabled: false,
+            ..Default::default()
+        },
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        },
+        segment: SegmentConfig {
+            size: IggyByteSize::from(150),
+            cache_time_indexes: false,
+            index_interval_messages: 2,
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .await;
+    let stream_id = 1;
+    let topic_id = 2;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        1,
+        true,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    partition.persist().await.unwrap();
+    assert_eq!(partition.get_earliest_offset(), 0);
+    assert_eq!(partition.get_offset_for_timestamp(0).await.unwrap(), None);
+
+    let messages = create_messages();
+    let messages_count = messages.len() as u64;
+    for (index, mut message) in messages.into_iter().enumerate() {
+        message.timestamp = (index as u64 + 1) * 10;
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+
+    assert!(partition.get_segments().len() > 1);
+    assert_eq!(partition.get_earliest_offset(), 0);
+    assert_eq!(partition.current_offset, messages_count - 1);
+    assert_eq!(
+        partition.get_offset_for_timestamp(0).await.unwrap(),
+        Some(0)
+    );
+    for offset in 0..messages_count {
+        let timestamp = (offset + 1) * 10;
+        assert_eq!(
+            partition.get_offset_for_timestamp(timestamp).await.unwrap(),
+            Some(offset)
+        );
+        assert_eq!(
+            partition
+                .get_offset_for_timestamp(timestamp - 5)
+                .await
+                .unwrap(),
+            Some(offset)
+        );
+    }
+    assert_eq!(
+        partition
+            .get_offset_for_timestamp(messages_count * 10 + 1)
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn should_poll_next_messages_from_reset_consumer_group_offset() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let consumer_group_id = 3;
+    let consumer = PollingConsumer::ConsumerGroup(consumer_group_id, 1);
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        1,
+        true,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    partition.persist().await.unwrap();
+    let messages = create_messages();
+    let messages_count = messages.len() as u64;
+    partition.append_messages(messages).await.unwrap();
+
+    let offset = partition
+        .reset_consumer_group_offset(consumer_group_id, OffsetsResetStrategy::Offset(3), true)
+        .await
+        .unwrap();
+    assert_eq!(offset.previous_offset, 0);
+    assert_eq!(offset.new_offset, 3);
+    let polled_messages = partition.get_next_messages(consumer, 10).await.unwrap();
+    assert_eq!(polled_messages.len() as u64, messages_count);
+
+    partition
+        .reset_consumer_group_offset(consumer_group_id, OffsetsResetStrategy::Offset(3), false)
+        .await
+        .unwrap();
+    let polled_messages = partition.get_next_messages(consumer, 10).await.unwrap();
+    assert_eq!(polled_messages.len() as u64, messages_count - 3);
+    assert_eq!(polled_messages[0].offset, 3);
+
+    let offset = partition
+        .reset_consumer_group_offset(consumer_group_id, OffsetsResetStrategy::Latest, false)
+        .await
+        .unwrap();
+    assert_eq!(offset.previous_offset, 3);
+    assert_eq!(offset.new_offset, messages_count);
+    let polled_messages = partition.get_next_messages(consumer, 10).await.unwrap();
+    assert!(polled_messages.is_empty());
+
+    let offset = partition
+        .reset_consumer_group_offset(consumer_group_id, OffsetsResetStrategy::Shift(-100), false)
+        .await
+        .unwrap();
+    assert_eq!(offset.previous_offset, messages_count);
+    assert_eq!(offset.new_offset, 0);
+    let polled_messages = partition.get_next_messages(consumer, 10).await.unwrap();
+    assert_eq!(polled_messages.len() as u64, messages_count);
+    assert_eq!(polled_messages[0].offset, 0);
+}
+
 async fn assert_persisted_partition(partition_path: &str, with_segment: bool) {
     assert!(fs::metadata(&partition_path).await.is_ok());
 
@@ -164,3 +524,7 @@ async fn assert_persisted_partition(partition_path: &str, with_segment: bool) {
 fn get_partition_ids() -> Vec<u32> {
     vec![1, 2, 3, 5, 10, 100, 1000, 99999]
 }
+
+fn read_all_messages(partition: &Partition) -> PartitionRead<'_, Vec<Arc<Message>>> {
+    Box::pin(partition.get_messages_by_offset(0, 100))
+}
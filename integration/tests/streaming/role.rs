@@ -0,0 +1,62 @@
+use crate::streaming::common::test_setup::TestSetup;
+use iggy::models::permissions::{GlobalPermissions, Permissions, StreamPermissions};
+use server::streaming::roles::role::Role;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn single_role_should_be_saved_and_loaded() {
+    let setup = TestSetup::init().await;
+    let role = create_role(1, "readers");
+    setup.storage.role.save(&role).await.unwrap();
+
+    let loaded_role = setup.storage.role.load_by_id(role.id).await.unwrap();
+    assert_role(&role, &loaded_role);
+
+    let loaded_role = setup.storage.role.load_by_name(&role.name).await.unwrap();
+    assert_role(&role, &loaded_role);
+}
+
+#[tokio::test]
+async fn role_should_be_deleted() {
+    let setup = TestSetup::init().await;
+    let role1 = create_role(1, "readers");
+    let role2 = create_role(2, "writers");
+    setup.storage.role.save(&role1).await.unwrap();
+    setup.storage.role.save(&role2).await.unwrap();
+
+    let roles = setup.storage.role.load_all().await.unwrap();
+    assert_eq!(roles.len(), 2);
+
+    setup.storage.role.delete(&role1).await.unwrap();
+    let roles = setup.storage.role.load_all().await.unwrap();
+    assert_eq!(roles.len(), 1);
+    assert_role(&role2, roles.first().unwrap());
+    assert!(setup.storage.role.load_by_name(&role1.name).await.is_err());
+}
+
+fn assert_role(role: &Role, loaded_role: &Role) {
+    assert_eq!(loaded_role.id, role.id);
+    assert_eq!(loaded_role.name, role.name);
+    assert_eq!(loaded_role.created_at, role.created_at);
+    assert_eq!(loaded_role.permissions, role.permissions);
+}
+
+fn create_role(id: u32, name: &str) -> Role {
+    Role::new(
+        id,
+        name,
+        Permissions {
+            global: GlobalPermissions {
+                read_streams: true,
+                ..Default::default()
+            },
+            streams: Some(HashMap::from([(
+                1,
+                StreamPermissions {
+                    poll_messages: true,
+                    ..Default::default()
+                },
+            )])),
+        },
+    )
+}
@@ -2,6 +2,8 @@ use crate::streaming::common::test_setup::TestSetup;
 use bytes::Bytes;
 use iggy::models::messages::{Message, MessageState};
 use iggy::utils::{checksum, timestamp::IggyTimestamp};
+use server::configs::system::{SegmentConfig, SystemConfig};
+use server::streaming::segments::integrity::check_segment_files;
 use server::streaming::segments::segment;
 use server::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION};
 use std::sync::Arc;
@@ -149,6 +151,272 @@ async fn should_persist_and_load_segment_with_messages() {
     assert_eq!(messages.len(), messages_count as usize);
 }
 
+#[tokio::test]
+async fn should_map_persisted_messages_with_the_same_bytes_as_loaded() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let messages_count = 10;
+    for i in 0..messages_count {
+        let message = create_message(i, "test", IggyTimestamp::now().to_micros());
+        segment.append_messages(&[Arc::new(message)]).await.unwrap();
+    }
+
+    let unsaved_mapped_messages = segment.get_mapped_messages(0, 5).await.unwrap();
+    assert!(unsaved_mapped_messages.is_none());
+
+    segment.persist_messages().await.unwrap();
+
+    let mapped_messages = segment.get_mapped_messages(2, 5).await.unwrap().unwrap();
+    let messages = segment.get_messages(2, 5).await.unwrap();
+    let mut messages_bytes = Vec::new();
+    for message in &messages {
+        message.extend(&mut messages_bytes);
+    }
+
+    assert_eq!(mapped_messages.count, 5);
+    assert_eq!(mapped_messages.last_offset, 6);
+    assert_eq!(mapped_messages.as_bytes(), messages_bytes.as_slice());
+}
+
+#[tokio::test]
+async fn given_sparse_indexes_segment_should_be_loaded_and_messages_found_by_offset() {
+    for cache_indexes in [true, false] {
+        let mut config = SystemConfig::default();
+        config.segment.index_interval_messages = 4;
+        config.segment.cache_indexes = cache_indexes;
+        config.segment.cache_time_indexes = cache_indexes;
+        let setup = TestSetup::init_with_config(config).await;
+        let stream_id = 1;
+        let topic_id = 2;
+        let partition_id = 3;
+        let start_offset = 0;
+        let messages_count = 10;
+        let segment = create_segment_with_messages(
+            &setup,
+            stream_id,
+            topic_id,
+            partition_id,
+            start_offset,
+            messages_count,
+        )
+        .await;
+
+        // Only every 4th message is indexed, each index consists of relative offset and position.
+        let index_bytes = fs::read(&segment.index_path).await.unwrap();
+        let time_index_bytes = fs::read(&segment.time_index_path).await.unwrap();
+        assert_eq!(index_bytes.len(), 3 * 8);
+        assert_eq!(time_index_bytes.len(), 3 * 12);
+        assert!(segment.check_integrity(false).await.unwrap().is_valid());
+
+        let mut loaded_segment = segment::Segment::create(
+            stream_id,
+            topic_id,
+            partition_id,
+            start_offset,
+            setup.config.clone(),
+            setup.storage.clone(),
+            None,
+        );
+        loaded_segment.load().await.unwrap();
+        assert_eq!(loaded_segment.current_offset, segment.current_offset);
+        assert_eq!(loaded_segment.last_timestamp, segment.last_timestamp);
+
+        for (offset, count) in [(0, 10), (1, 2), (3, 3), (5, 10), (9, 1)] {
+            let messages = loaded_segment.get_messages(offset, count).await.unwrap();
+            let expected_count = count.min((messages_count - offset) as u32);
+            assert_eq!(messages.len() as u32, expected_count);
+            for (i, message) in messages.iter().enumerate() {
+                assert_eq!(message.offset, offset + i as u64);
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn given_changed_index_interval_existing_indexes_should_remain_valid() {
+    let mut config = SystemConfig::default();
+    config.segment.index_interval_messages = 4;
+    let setup = TestSetup::init_with_config(config).await;
+    let start_offset = 0;
+    let segment = create_segment_with_messages(&setup, 1, 2, 3, start_offset, 10).await;
+    let index_bytes = fs::read(&segment.index_path).await.unwrap();
+    let changed_config = SegmentConfig {
+        index_interval_messages: 3,
+        ..Default::default()
+    };
+
+    let report = check_segment_files(
+        &changed_config,
+        start_offset,
+        &segment.log_path,
+        &segment.index_path,
+        &segment.time_index_path,
+        true,
+    )
+    .await
+    .unwrap();
+
+    assert!(report.is_valid());
+    assert!(!report.repaired);
+    assert_eq!(fs::read(&segment.index_path).await.unwrap(), index_bytes);
+
+    // The entry which doesn't point at the message boundary is invalid regardless of the index interval.
+    let mut invalid_index_bytes = index_bytes.clone();
+    invalid_index_bytes[12..16].copy_from_slice(&1u32.to_le_bytes());
+    fs::write(&segment.index_path, &invalid_index_bytes)
+        .await
+        .unwrap();
+    let report = segment.check_integrity(false).await.unwrap();
+    assert!(report.invalid_index);
+    assert!(!report.invalid_time_index);
+}
+
+#[tokio::test]
+async fn given_segment_with_torn_write_it_should_be_repaired_and_loaded() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let messages_count = 10;
+    let segment = create_segment_with_messages(
+        &setup,
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        messages_count,
+    )
+    .await;
+
+    let valid_size_bytes = fs::metadata(&segment.log_path).await.unwrap().len();
+    let mut torn_message = Vec::new();
+    create_message(messages_count, "test", IggyTimestamp::now().to_micros())
+        .extend(&mut torn_message);
+    let mut log_bytes = fs::read(&segment.log_path).await.unwrap();
+    log_bytes.extend_from_slice(&torn_message[..torn_message.len() / 2]);
+    fs::write(&segment.log_path, &log_bytes).await.unwrap();
+    // The index entry of the torn message points past the valid messages.
+    let index_bytes = fs::read(&segment.index_path).await.unwrap();
+    let mut torn_index_bytes = index_bytes.clone();
+    torn_index_bytes.extend_from_slice(&(messages_count as u32).to_le_bytes());
+    torn_index_bytes.extend_from_slice(&(valid_size_bytes as u32).to_le_bytes());
+    fs::write(&segment.index_path, &torn_index_bytes)
+        .await
+        .unwrap();
+
+    let report = segment.check_integrity(false).await.unwrap();
+    assert!(!report.is_valid());
+    assert!(!report.repaired);
+    assert_eq!(report.messages_count, messages_count);
+    assert_eq!(report.valid_size_bytes, valid_size_bytes);
+    assert_eq!(report.corrupted_size_bytes, torn_message.len() as u64 / 2);
+    assert!(report.invalid_index);
+    assert!(!report.invalid_time_index);
+
+    let report = segment.check_integrity(true).await.unwrap();
+    assert!(report.repaired);
+    assert_eq!(
+        fs::metadata(&segment.log_path).await.unwrap().len(),
+        valid_size_bytes
+    );
+    assert_eq!(fs::read(&segment.index_path).await.unwrap(), index_bytes);
+
+    let report = segment.check_integrity(false).await.unwrap();
+    assert!(report.is_valid());
+
+    let mut loaded_segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_segment.load().await.unwrap();
+    let messages = loaded_segment
+        .get_messages(0, messages_count as u32)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), messages_count as usize);
+    assert_eq!(loaded_segment.current_offset, messages_count - 1);
+}
+
+#[tokio::test]
+async fn given_segment_with_invalid_checksum_it_should_be_truncated_from_corrupted_message() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let messages_count = 10;
+    let corrupted_offset = 5;
+    let segment = create_segment_with_messages(
+        &setup,
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        messages_count,
+    )
+    .await;
+
+    let index_bytes = fs::read(&segment.index_path).await.unwrap();
+    // Each index entry consists of the relative offset and the position.
+    let position = corrupted_offset as usize * 8 + 4;
+    let corrupted_position =
+        u32::from_le_bytes(index_bytes[position..position + 4].try_into().unwrap()) as usize;
+    let mut log_bytes = fs::read(&segment.log_path).await.unwrap();
+    // The last byte of the message payload.
+    let next_position =
+        u32::from_le_bytes(index_bytes[position + 8..position + 12].try_into().unwrap()) as usize;
+    log_bytes[next_position - 1] ^= 0xFF;
+    fs::write(&segment.log_path, &log_bytes).await.unwrap();
+
+    let report = segment.check_integrity(true).await.unwrap();
+    assert!(report.repaired);
+    assert_eq!(report.messages_count, corrupted_offset);
+    assert_eq!(report.corrupted_offset, Some(corrupted_offset));
+    assert_eq!(report.valid_size_bytes, corrupted_position as u64);
+    assert!(report.invalid_index);
+    assert!(report.invalid_time_index);
+
+    let mut loaded_segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_segment.load().await.unwrap();
+    let messages = loaded_segment
+        .get_messages(0, messages_count as u32)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), corrupted_offset as usize);
+    assert_eq!(loaded_segment.current_offset, corrupted_offset - 1);
+}
+
 #[tokio::test]
 async fn given_all_expired_messages_segment_should_be_expired() {
     let setup = TestSetup::init().await;
@@ -254,6 +522,36 @@ async fn assert_persisted_segment(partition_path: &str, start_offset: u64) {
     assert!(fs::metadata(&time_index_path).await.is_ok());
 }
 
+async fn create_segment_with_messages(
+    setup: &TestSetup,
+    stream_id: u32,
+    topic_id: u32,
+    partition_id: u32,
+    start_offset: u64,
+    messages_count: u64,
+) -> segment::Segment {
+    let mut segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    for i in 0..messages_count {
+        let message = create_message(start_offset + i, "test", IggyTimestamp::now().to_micros());
+        segment.append_messages(&[Arc::new(message)]).await.unwrap();
+    }
+
+    segment.persist_messages().await.unwrap();
+    segment
+}
+
 fn create_message(offset: u64, payload: &str, timestamp: u64) -> Message {
     let payload = Bytes::from(payload.to_string());
     let checksum = checksum::calculate(payload.as_ref());
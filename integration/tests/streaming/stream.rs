@@ -3,6 +3,7 @@ use crate::streaming::create_messages;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::Partitioning;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::streams::stream::Stream;
 use tokio::fs;
@@ -94,7 +95,18 @@ async fn should_purge_existing_stream_on_disk() {
 
         let topic_id = 1;
         stream
-            .create_topic(topic_id, "test", 1, None, None, 1)
+            .create_topic(
+                topic_id,
+                "test",
+                1,
+                None,
+                None,
+                1,
+                None,
+                CleanupPolicy::Delete,
+                MaxTopicSizePolicy::DeleteOldest,
+                FlushPolicy::default(),
+            )
             .await
             .unwrap();
 
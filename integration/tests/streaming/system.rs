@@ -1,10 +1,31 @@
 use crate::streaming::common::test_setup::TestSetup;
+use bytes::Bytes;
+use iggy::error::Error;
 use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::reject_message::{
+    DEAD_LETTER_ORIGIN_OFFSET_HEADER, DEAD_LETTER_ORIGIN_PARTITION_HEADER,
+    DEAD_LETTER_ORIGIN_STREAM_HEADER, DEAD_LETTER_ORIGIN_TOPIC_HEADER, DEAD_LETTER_REASON_HEADER,
+};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning};
+use iggy::models::consumer_group::PartitionAssignmentStrategy;
+use iggy::models::header::HeaderKey;
+use iggy::models::messages::MessageState;
+use iggy::models::permissions::Permissions;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
+use iggy::models::user_status::UserStatus;
 use server::configs::server::PersonalAccessTokenConfig;
+use server::configs::system::SystemConfig;
+use server::streaming::clients::client_manager::Transport;
+use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::session::Session;
+use server::streaming::systems::messages::PollingArgs;
 use server::streaming::systems::system::System;
+use server::streaming::users::user::User;
 use std::net::{Ipv4Addr, SocketAddr};
+use std::time::Duration;
 use tokio::fs;
+use tokio::time::sleep;
 
 #[tokio::test]
 async fn should_initialize_system_and_base_directories() {
@@ -40,7 +61,12 @@ async fn should_create_and_persist_stream() {
     );
     let stream_id = 1;
     let stream_name = "test";
-    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    let session = Session::new(
+        1,
+        1,
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+        Transport::Tcp,
+    );
     system.init().await.unwrap();
 
     system
@@ -51,6 +77,58 @@ async fn should_create_and_persist_stream() {
     assert_persisted_stream(&setup.config.get_streams_path(), stream_id).await;
 }
 
+#[tokio::test]
+async fn renamed_role_should_be_found_only_by_new_name() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let session = Session::new(
+        1,
+        1,
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+        Transport::Tcp,
+    );
+    system.init().await.unwrap();
+    system
+        .create_role(&session, "readers", Permissions::default())
+        .await
+        .unwrap();
+    let role = system
+        .get_role(&Identifier::named("readers").unwrap())
+        .await
+        .unwrap();
+
+    let renamed_role = system
+        .update_role(
+            &session,
+            &Identifier::numeric(role.id).unwrap(),
+            Some("viewers".to_string()),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(renamed_role.id, role.id);
+    assert_eq!(renamed_role.name, "viewers");
+    let found_role = system
+        .get_role(&Identifier::named("viewers").unwrap())
+        .await
+        .unwrap();
+    assert_eq!(found_role.id, role.id);
+    let found_role = system
+        .get_role(&Identifier::numeric(role.id).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(found_role.name, "viewers");
+    assert!(system
+        .get_role(&Identifier::named("readers").unwrap())
+        .await
+        .is_err());
+}
+
 #[tokio::test]
 async fn should_delete_persisted_stream() {
     let setup = TestSetup::init().await;
@@ -61,7 +139,12 @@ async fn should_delete_persisted_stream() {
     );
     let stream_id = 1;
     let stream_name = "test";
-    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    let session = Session::new(
+        1,
+        1,
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+        Transport::Tcp,
+    );
     system.init().await.unwrap();
     system
         .create_stream(&session, stream_id, stream_name)
@@ -82,6 +165,444 @@ async fn should_delete_persisted_stream() {
     assert!(fs::metadata(stream_path).await.is_err());
 }
 
+#[tokio::test]
+async fn should_mark_rejected_message_as_poisoned_and_copy_it_to_dead_letter_topic() {
+    let mut config = SystemConfig::default();
+    config.dead_letter_queue.enabled = true;
+    config.dead_letter_queue.stream = "dead-letters".to_string();
+    config.dead_letter_queue.topic = "dead-letters".to_string();
+    let setup = TestSetup::init_with_config(config).await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let session = Session::new(
+        1,
+        1,
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+        Transport::Tcp,
+    );
+    let stream_id = Identifier::numeric(1).unwrap();
+    let topic_id = Identifier::numeric(1).unwrap();
+    let dead_letter_stream_id = Identifier::numeric(2).unwrap();
+    let dead_letter_topic_id = Identifier::numeric(1).unwrap();
+    let partition_id = 1;
+    let rejected_offset = 1;
+    let reason = "invalid payload";
+    system.init().await.unwrap();
+    system.create_stream(&session, 1, "test").await.unwrap();
+    system
+        .create_topic(
+            &session,
+            &stream_id,
+            1,
+            "test",
+            1,
+            None,
+            None,
+            1,
+            None,
+            CleanupPolicy::Delete,
+            MaxTopicSizePolicy::DeleteOldest,
+            FlushPolicy::default(),
+        )
+        .await
+        .unwrap();
+    system
+        .create_stream(&session, 2, "dead-letters")
+        .await
+        .unwrap();
+    system
+        .create_topic(
+            &session,
+            &dead_letter_stream_id,
+            1,
+            "dead-letters",
+            1,
+            None,
+            None,
+            1,
+            None,
+            CleanupPolicy::Delete,
+            MaxTopicSizePolicy::DeleteOldest,
+            FlushPolicy::default(),
+        )
+        .await
+        .unwrap();
+    let messages = (1..=3)
+        .map(|id| Message::new(Some(id), Bytes::from(format!("message {id}")), None))
+        .collect();
+    system
+        .append_messages(
+            &session,
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(partition_id),
+            &messages,
+            AckLevel::InMemory,
+        )
+        .await
+        .unwrap();
+
+    // Rejecting the same message again must not copy it to the dead-letter topic twice.
+    for _ in 0..2 {
+        let dead_letter_message = system
+            .get_dead_letter_message(
+                &session,
+                &stream_id,
+                &topic_id,
+                partition_id,
+                rejected_offset,
+                reason,
+            )
+            .await
+            .unwrap();
+        system
+            .reject_message(
+                &session,
+                &stream_id,
+                &topic_id,
+                partition_id,
+                rejected_offset,
+                reason,
+                dead_letter_message,
+            )
+            .await
+            .unwrap();
+    }
+
+    let consumer = PollingConsumer::Consumer(1, partition_id);
+    let polled_messages = system
+        .poll_messages(
+            &session,
+            consumer,
+            &stream_id,
+            &topic_id,
+            PollingArgs::new(PollingStrategy::offset(0), 10, false, false),
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 3);
+    for message in polled_messages.messages {
+        let expected_state = match message.offset {
+            offset if offset == rejected_offset => MessageState::Poisoned,
+            _ => MessageState::Available,
+        };
+        assert_eq!(message.state, expected_state);
+    }
+
+    let polled_messages = system
+        .poll_messages(
+            &session,
+            consumer,
+            &stream_id,
+            &topic_id,
+            PollingArgs::new(PollingStrategy::offset(0), 10, false, true),
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 2);
+    assert!(polled_messages
+        .messages
+        .iter()
+        .all(|message| message.offset != rejected_offset));
+
+    let dead_letters = system
+        .poll_messages(
+            &session,
+            consumer,
+            &dead_letter_stream_id,
+            &dead_letter_topic_id,
+            PollingArgs::new(PollingStrategy::offset(0), 10, false, false),
+        )
+        .await
+        .unwrap();
+    assert_eq!(dead_letters.messages.len(), 1);
+    let dead_letter = &dead_letters.messages[0];
+    assert_eq!(dead_letter.id, 2);
+    assert_eq!(dead_letter.payload, Bytes::from("message 2"));
+    let headers = dead_letter.headers.as_ref().unwrap();
+    let get_header = |key: &str| headers.get(&HeaderKey::new(key).unwrap()).unwrap();
+    assert_eq!(
+        get_header(DEAD_LETTER_ORIGIN_STREAM_HEADER)
+            .as_uint32()
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        get_header(DEAD_LETTER_ORIGIN_TOPIC_HEADER)
+            .as_uint32()
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        get_header(DEAD_LETTER_ORIGIN_PARTITION_HEADER)
+            .as_uint32()
+            .unwrap(),
+        partition_id
+    );
+    assert_eq!(
+        get_header(DEAD_LETTER_ORIGIN_OFFSET_HEADER)
+            .as_uint64()
+            .unwrap(),
+        rejected_offset
+    );
+    assert_eq!(
+        get_header(DEAD_LETTER_REASON_HEADER).as_str().unwrap(),
+        reason
+    );
+
+    let poisoned_messages = setup
+        .storage
+        .partition
+        .load_poisoned_messages(1, 1, partition_id)
+        .await
+        .unwrap();
+    assert_eq!(poisoned_messages.len(), 1);
+    assert_eq!(poisoned_messages[0].offset, rejected_offset);
+    assert_eq!(poisoned_messages[0].reason, reason);
+}
+
+#[tokio::test]
+async fn should_delete_consumer_group_members_with_expired_sessions() {
+    let mut config = SystemConfig::default();
+    config.consumer_group.session_timeout = "100ms".parse().unwrap();
+    let setup = TestSetup::init_with_config(config).await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let address = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234);
+    let stream_id = Identifier::numeric(1).unwrap();
+    let topic_id = Identifier::numeric(1).unwrap();
+    let consumer_group_id = Identifier::numeric(1).unwrap();
+    system.init().await.unwrap();
+    let client_id = system.add_client(&address, Transport::Tcp).await;
+    let session = Session::new(client_id, 1, address, Transport::Tcp);
+    system.create_stream(&session, 1, "test").await.unwrap();
+    system
+        .create_topic(
+            &session,
+            &stream_id,
+            1,
+            "test",
+            3,
+            None,
+            None,
+            1,
+            None,
+            CleanupPolicy::Delete,
+            MaxTopicSizePolicy::DeleteOldest,
+            FlushPolicy::default(),
+        )
+        .await
+        .unwrap();
+    system
+        .create_consumer_group(
+            &session,
+            &stream_id,
+            &topic_id,
+            1,
+            "test",
+            None,
+            PartitionAssignmentStrategy::RoundRobin,
+        )
+        .await
+        .unwrap();
+    system
+        .join_consumer_group(&session, &stream_id, &topic_id, &consumer_group_id)
+        .await
+        .unwrap();
+
+    assert_eq!(system.delete_expired_consumer_group_members().await, 0);
+    sleep(Duration::from_millis(200)).await;
+    system
+        .heartbeat_consumer_group(&session, &stream_id, &topic_id, &consumer_group_id)
+        .await
+        .unwrap();
+    assert_eq!(system.delete_expired_consumer_group_members().await, 0);
+
+    sleep(Duration::from_millis(200)).await;
+    assert_eq!(system.delete_expired_consumer_group_members().await, 1);
+    let consumer_group = system
+        .get_consumer_group(&session, &stream_id, &topic_id, &consumer_group_id)
+        .unwrap()
+        .read()
+        .await;
+    assert!(consumer_group.get_members().is_empty());
+}
+
+#[tokio::test]
+async fn failed_passwords_should_not_lock_out_client_certificate_or_personal_access_token() {
+    let mut config = SystemConfig::default();
+    config.login_attempts.free_attempts = 3;
+    config.login_attempts.user_max_failed_attempts = 3;
+    let setup = TestSetup::init_with_config(config).await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let session = Session::new(
+        1,
+        2,
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+        Transport::Tcp,
+    );
+    system.init().await.unwrap();
+    let user = User::new(2, "user", "secret", UserStatus::Active, None);
+    setup.storage.user.save(&user).await.unwrap();
+    let token = system
+        .create_personal_access_token(&session, "token", None)
+        .await
+        .unwrap();
+    let login_session = Session::from_client_id(0, session.ip_address, Transport::Tcp);
+
+    for _ in 0..3 {
+        let result = system
+            .login_user("user", "invalid", Some(&login_session))
+            .await;
+        assert!(matches!(result, Err(Error::InvalidCredentials)));
+    }
+
+    let result = system
+        .login_user("user", "secret", Some(&login_session))
+        .await;
+    assert!(matches!(result, Err(Error::UserLocked(_))));
+    assert!(system
+        .login_user_with_certificate("user", &login_session)
+        .await
+        .is_ok());
+    assert!(system
+        .login_with_personal_access_token(&token, Some(&login_session))
+        .await
+        .is_ok());
+
+    // Exceeding the free attempts from the same IP address blocks it for every login method.
+    let result = system
+        .login_user("other", "invalid", Some(&login_session))
+        .await;
+    assert!(matches!(result, Err(Error::InvalidCredentials)));
+    let result = system
+        .login_user_with_certificate("user", &login_session)
+        .await;
+    assert!(matches!(result, Err(Error::TooManyLoginAttempts(_))));
+    let result = system
+        .login_with_personal_access_token(&token, Some(&login_session))
+        .await;
+    assert!(matches!(result, Err(Error::TooManyLoginAttempts(_))));
+}
+
+#[tokio::test]
+async fn unknown_user_should_be_locked_the_same_way_as_existing_user() {
+    let mut config = SystemConfig::default();
+    config.login_attempts.free_attempts = 3;
+    config.login_attempts.user_max_failed_attempts = 3;
+    let setup = TestSetup::init_with_config(config).await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    system.init().await.unwrap();
+    let user = User::new(2, "user", "secret", UserStatus::Active, None);
+    setup.storage.user.save(&user).await.unwrap();
+
+    for username in ["user", "unknown"] {
+        for _ in 0..3 {
+            let result = system.login_user(username, "invalid", None).await;
+            assert!(matches!(result, Err(Error::InvalidCredentials)));
+        }
+
+        let result = system.login_user(username, "invalid", None).await;
+        assert!(
+            matches!(result, Err(Error::UserLocked(locked_username)) if locked_username == username)
+        );
+    }
+}
+
+#[tokio::test]
+async fn replica_messages_should_be_fetched_only_by_configured_followers_with_server_permission() {
+    let mut config = SystemConfig::default();
+    config.replication.enabled = true;
+    config.replication.follower_ids = vec![2];
+    let setup = TestSetup::init_with_config(config).await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let address = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234);
+    let root_session = Session::new(1, 1, address, Transport::Tcp);
+    let user_session = Session::new(2, 2, address, Transport::Tcp);
+    let stream_id = Identifier::numeric(1).unwrap();
+    let topic_id = Identifier::numeric(1).unwrap();
+    system.init().await.unwrap();
+    system
+        .create_stream(&root_session, 1, "test")
+        .await
+        .unwrap();
+    system
+        .create_topic(
+            &root_session,
+            &stream_id,
+            1,
+            "test",
+            1,
+            None,
+            None,
+            2,
+            None,
+            CleanupPolicy::Delete,
+            MaxTopicSizePolicy::DeleteOldest,
+            FlushPolicy::default(),
+        )
+        .await
+        .unwrap();
+    let messages = (1..=3)
+        .map(|id| Message::new(Some(id), Bytes::from(format!("message {id}")), None))
+        .collect();
+    system
+        .append_messages(
+            &root_session,
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(1),
+            &messages,
+            AckLevel::InMemory,
+        )
+        .await
+        .unwrap();
+
+    let result = system
+        .fetch_replica_messages(&user_session, &stream_id, &topic_id, 1, 2, 0, 10)
+        .await;
+    assert!(matches!(result, Err(Error::Unauthorized)));
+    let result = system
+        .fetch_replica_messages(&root_session, &stream_id, &topic_id, 1, 3, 0, 10)
+        .await;
+    assert!(matches!(result, Err(Error::UnknownReplica(3))));
+
+    // The offset beyond the leader's log must not acknowledge the messages appended later.
+    system
+        .fetch_replica_messages(&root_session, &stream_id, &topic_id, 1, 2, 1000, 10)
+        .await
+        .unwrap();
+    let partition = system
+        .get_stream(&stream_id)
+        .unwrap()
+        .get_topic(&topic_id)
+        .unwrap()
+        .get_partition(1)
+        .unwrap();
+    let partition = partition.read().await;
+    let next_offset = partition.get_next_offset();
+    assert!(partition.is_replicated_by_in_sync_replicas(next_offset - 1, 2));
+    assert!(!partition.is_replicated_by_in_sync_replicas(next_offset, 2));
+}
+
 async fn assert_persisted_stream(streams_path: &str, stream_id: u32) {
     let streams_metadata = fs::metadata(streams_path).await.unwrap();
     assert!(streams_metadata.is_dir());
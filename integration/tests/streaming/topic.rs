@@ -1,7 +1,10 @@
 use crate::streaming::common::test_setup::TestSetup;
 use crate::streaming::create_messages;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::Partitioning;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
+use server::configs::system::{CompressionConfig, SystemConfig};
 use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::topics::topic::Topic;
 use tokio::fs;
@@ -25,6 +28,10 @@ async fn should_persist_topics_with_partitions_directories_and_info_file() {
             None,
             None,
             1,
+            None,
+            CleanupPolicy::Delete,
+            MaxTopicSizePolicy::DeleteOldest,
+            FlushPolicy::default(),
         )
         .unwrap();
 
@@ -58,6 +65,10 @@ async fn should_load_existing_topic_from_disk() {
             None,
             None,
             1,
+            None,
+            CleanupPolicy::Delete,
+            MaxTopicSizePolicy::DeleteOldest,
+            FlushPolicy::default(),
         )
         .unwrap();
         topic.persist().await.unwrap();
@@ -84,6 +95,53 @@ async fn should_load_existing_topic_from_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_load_existing_topic_with_compression_algorithm_from_disk() {
+    let setup = TestSetup::init_with_config(SystemConfig {
+        compression: CompressionConfig {
+            allow_override: true,
+            default_algorithm: CompressionAlgorithm::None,
+        },
+        ..Default::default()
+    })
+    .await;
+    let stream_id = 1;
+    let topic_id = 1;
+    let partitions_count = 2;
+    setup.create_topics_directory(stream_id).await;
+    let topic = Topic::create(
+        stream_id,
+        topic_id,
+        "test",
+        partitions_count,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+        None,
+        1,
+        Some(CompressionAlgorithm::Zstd),
+        CleanupPolicy::Delete,
+        MaxTopicSizePolicy::DeleteOldest,
+        FlushPolicy::default(),
+    )
+    .unwrap();
+    topic.persist().await.unwrap();
+
+    let mut loaded_topic = Topic::empty(
+        stream_id,
+        topic_id,
+        setup.config.clone(),
+        setup.storage.clone(),
+    );
+    loaded_topic.load().await.unwrap();
+
+    assert_eq!(
+        loaded_topic.compression_algorithm,
+        CompressionAlgorithm::Zstd
+    );
+    assert_eq!(loaded_topic.get_partitions().len() as u32, partitions_count);
+}
+
 #[tokio::test]
 async fn should_delete_existing_topic_from_disk() {
     let setup = TestSetup::init().await;
@@ -103,6 +161,10 @@ async fn should_delete_existing_topic_from_disk() {
             None,
             None,
             1,
+            None,
+            CleanupPolicy::Delete,
+            MaxTopicSizePolicy::DeleteOldest,
+            FlushPolicy::default(),
         )
         .unwrap();
         topic.persist().await.unwrap();
@@ -138,6 +200,10 @@ async fn should_purge_existing_topic_on_disk() {
             None,
             None,
             1,
+            None,
+            CleanupPolicy::Delete,
+            MaxTopicSizePolicy::DeleteOldest,
+            FlushPolicy::default(),
         )
         .unwrap();
         topic.persist().await.unwrap();
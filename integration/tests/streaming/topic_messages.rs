@@ -1,11 +1,15 @@
 use crate::streaming::common::test_setup::TestSetup;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::Error;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages;
 use iggy::messages::send_messages::Partitioning;
 use iggy::models::messages::Message;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::timestamp::IggyTimestamp;
 use server::configs::resource_quota::MemoryResourceQuota;
-use server::configs::system::{CacheConfig, SystemConfig};
+use server::configs::system::{CacheConfig, PartitionConfig, SegmentConfig, SystemConfig};
 use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::topics::topic::Topic;
 use server::streaming::utils::hash;
@@ -178,6 +182,192 @@ async fn given_key_messages_key_messages_should_be_appended_to_the_calculated_pa
     }
 }
 
+#[tokio::test]
+async fn given_topic_exceeding_max_size_oldest_segments_across_partitions_should_be_deleted() {
+    let setup = TestSetup::init_with_config(get_single_message_segments_config()).await;
+    let partitions_count = 2;
+    let messages_count = 8;
+    let mut topic = init_topic(&setup, partitions_count).await;
+    let partitioning = Partitioning::balanced();
+    for i in 1..=messages_count {
+        let mut message = get_message(&get_payload(i));
+        message.timestamp = i as u64;
+        topic
+            .append_messages(&partitioning, vec![message])
+            .await
+            .unwrap();
+    }
+
+    // Each message is stored in its own segment, so the topic should keep only the 5 latest messages.
+    let topic_size = topic.get_size().await.as_bytes_u64();
+    topic.max_topic_size = Some(IggyByteSize::from(topic_size * 5 / messages_count as u64));
+    let (deleted_segments_count, deleted_messages_count) =
+        topic.delete_oldest_segments().await.unwrap();
+
+    assert_eq!(deleted_segments_count, 3);
+    assert_eq!(deleted_messages_count, 3);
+    assert!(topic.get_size().await.as_bytes_u64() <= topic_size * 5 / messages_count as u64);
+    let first_partition = topic.get_partition(1).unwrap();
+    let first_partition = first_partition.read().await;
+    assert_eq!(first_partition.get_segments()[0].start_offset, 2);
+    let second_partition = topic.get_partition(2).unwrap();
+    let second_partition = second_partition.read().await;
+    assert_eq!(second_partition.get_segments()[0].start_offset, 1);
+}
+
+#[tokio::test]
+async fn given_full_topic_with_reject_policy_messages_should_not_be_appended() {
+    let setup = TestSetup::init_with_config(get_single_message_segments_config()).await;
+    let mut topic = init_topic(&setup, 1).await;
+    topic.max_topic_size_policy = MaxTopicSizePolicy::Reject;
+    let partitioning = Partitioning::partition_id(1);
+    topic
+        .append_messages(&partitioning, vec![get_message(&get_payload(1))])
+        .await
+        .unwrap();
+    topic.max_topic_size = Some(topic.get_size().await);
+
+    let result = topic
+        .append_messages(&partitioning, vec![get_message(&get_payload(2))])
+        .await;
+
+    assert!(matches!(result, Err(Error::TopicFull(2, 1))));
+    assert_messages(&topic, 1, 1).await;
+}
+
+#[tokio::test]
+async fn given_topic_with_reject_policy_compressed_messages_should_be_sized_as_stored() {
+    let setup = TestSetup::init_with_config(get_single_message_segments_config()).await;
+    let mut topic = init_topic(&setup, 1).await;
+    topic.max_topic_size_policy = MaxTopicSizePolicy::Reject;
+    topic.compression_algorithm = CompressionAlgorithm::Gzip;
+    let partitioning = Partitioning::partition_id(1);
+    let payload = create_payload(1000);
+    topic
+        .append_messages(&partitioning, vec![get_message(&payload)])
+        .await
+        .unwrap();
+    let stored_size = topic.get_size().await.as_bytes_u64();
+    assert!(stored_size < payload.len() as u64);
+    topic.max_topic_size = Some(IggyByteSize::from(2 * stored_size));
+
+    topic
+        .append_messages(&partitioning, vec![get_message(&payload)])
+        .await
+        .unwrap();
+
+    assert_messages(&topic, 1, 2).await;
+}
+
+#[tokio::test]
+async fn given_flush_policy_messages_count_messages_should_be_saved_once_count_is_reached() {
+    let setup = TestSetup::init_with_config(get_flush_policy_config()).await;
+    let flush_policy = FlushPolicy {
+        messages_count: Some(3),
+        ..Default::default()
+    };
+    let topic = init_topic_with_flush_policy(&setup, 1, flush_policy).await;
+    let partitioning = Partitioning::partition_id(1);
+    for id in 1..=2 {
+        topic
+            .append_messages(&partitioning, vec![get_message(&get_payload(id))])
+            .await
+            .unwrap();
+    }
+    assert_unsaved_messages(&topic, 2).await;
+
+    topic
+        .append_messages(&partitioning, vec![get_message(&get_payload(3))])
+        .await
+        .unwrap();
+
+    assert_unsaved_messages(&topic, 0).await;
+    assert_messages(&topic, 1, 3).await;
+}
+
+#[tokio::test]
+async fn given_flush_policy_size_messages_should_be_saved_once_size_is_reached() {
+    let setup = TestSetup::init_with_config(get_flush_policy_config()).await;
+    let message = get_message(&create_payload(100));
+    let flush_policy = FlushPolicy {
+        size: Some(IggyByteSize::from(2 * message.get_size_bytes() as u64)),
+        ..Default::default()
+    };
+    let topic = init_topic_with_flush_policy(&setup, 1, flush_policy).await;
+    let partitioning = Partitioning::partition_id(1);
+    topic
+        .append_messages(&partitioning, vec![message])
+        .await
+        .unwrap();
+    assert_unsaved_messages(&topic, 1).await;
+
+    topic
+        .append_messages(&partitioning, vec![get_message(&create_payload(100))])
+        .await
+        .unwrap();
+
+    assert_unsaved_messages(&topic, 0).await;
+    assert_messages(&topic, 1, 2).await;
+}
+
+#[tokio::test]
+async fn given_flush_policy_max_latency_messages_exceeding_it_should_be_saved() {
+    let setup = TestSetup::init_with_config(get_flush_policy_config()).await;
+    let flush_policy = FlushPolicy {
+        max_latency: Some(1000),
+        ..Default::default()
+    };
+    let topic = init_topic_with_flush_policy(&setup, 1, flush_policy).await;
+    let partitioning = Partitioning::partition_id(1);
+    topic
+        .append_messages(&partitioning, vec![get_message(&get_payload(1))])
+        .await
+        .unwrap();
+    let now = IggyTimestamp::now().to_micros();
+
+    topic
+        .persist_messages_exceeding_max_latency(now)
+        .await
+        .unwrap();
+    assert_unsaved_messages(&topic, 1).await;
+
+    topic
+        .persist_messages_exceeding_max_latency(now + 1_000_000)
+        .await
+        .unwrap();
+    assert_unsaved_messages(&topic, 0).await;
+}
+
+fn get_flush_policy_config() -> SystemConfig {
+    SystemConfig {
+        partition: PartitionConfig {
+            messages_required_to_save: 1000,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+async fn assert_unsaved_messages(topic: &Topic, expected_messages: u32) {
+    let partition = topic.get_partition(1).unwrap();
+    let partition = partition.read().await;
+    assert_eq!(partition.unsaved_messages_count, expected_messages);
+}
+
+fn get_single_message_segments_config() -> SystemConfig {
+    SystemConfig {
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        },
+        segment: SegmentConfig {
+            size: IggyByteSize::from(1),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
 fn get_payload(id: u32) -> String {
     format!("message-{}", id)
 }
@@ -192,6 +382,14 @@ async fn assert_messages(topic: &Topic, partition_id: u32, expected_messages: u3
 }
 
 async fn init_topic(setup: &TestSetup, partitions_count: u32) -> Topic {
+    init_topic_with_flush_policy(setup, partitions_count, FlushPolicy::default()).await
+}
+
+async fn init_topic_with_flush_policy(
+    setup: &TestSetup,
+    partitions_count: u32,
+    flush_policy: FlushPolicy,
+) -> Topic {
     let stream_id = 1;
     setup.create_topics_directory(stream_id).await;
     let id = 2;
@@ -206,6 +404,10 @@ async fn init_topic(setup: &TestSetup, partitions_count: u32) -> Topic {
         None,
         None,
         1,
+        None,
+        CleanupPolicy::Delete,
+        MaxTopicSizePolicy::DeleteOldest,
+        flush_policy,
     )
     .unwrap();
     topic.persist().await.unwrap();
@@ -68,6 +68,7 @@ fn assert_user(user: &User, loaded_user: &User) {
     assert_eq!(loaded_user.password, user.password);
     assert_eq!(loaded_user.created_at, user.created_at);
     assert_eq!(loaded_user.status, user.status);
+    assert_eq!(loaded_user.roles, user.roles);
     if user.permissions.is_none() {
         assert!(loaded_user.permissions.is_none());
         return;
@@ -202,5 +203,6 @@ fn create_user(id: u32) -> User {
                 map
             }),
         }),
+        roles: vec![1, 2],
     }
 }
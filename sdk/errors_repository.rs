@@ -180,6 +180,22 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             source: "anyhow::Error".to_string(),
             template: "Cannot deserialize resource. Reason: {0:#}".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_open_database".to_string(),
+            code: 26,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Cannot open database at path: {0}".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_migrate_database".to_string(),
+            code: 27,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Cannot migrate database, {0}.".to_string(),
+        },
         ErrorRepositoryEntry {
             snake_case_name: "unauthenticated".to_string(),
             code: 40,
@@ -300,6 +316,46 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             source: "".to_string(),
             template: "Personal access token: {0} for user with ID: {1} has expired.".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_role_name".to_string(),
+            code: 55,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Invalid role name".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "role_already_exists".to_string(),
+            code: 56,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Role: {0} already exists".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "quota_exceeded".to_string(),
+            code: 57,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Quota exceeded: {0}".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "user_locked".to_string(),
+            code: 58,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "User: {0} is temporarily locked due to too many failed login attempts".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "too_many_login_attempts".to_string(),
+            code: 59,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Too many failed login attempts, retry after: {0}".to_string(),
+        },
         ErrorRepositoryEntry {
             snake_case_name: "not_connected".to_string(),
             code: 61,
@@ -820,6 +876,14 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             source: "".to_string(),
             template: "Invalid replication factor".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "topic_full".to_string(),
+            code: 2019,
+            signature: "u32, u32".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Topic with ID: {0} for stream with ID: {1} has reached its maximum size.".to_string(),
+        },
         ErrorRepositoryEntry {
             snake_case_name: "cannot_create_partition".to_string(),
             code: 3000,
@@ -1124,6 +1188,78 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             source: "".to_string(),
             template: "Invalid key value length".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_compress_data".to_string(),
+            code: 4029,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Cannot compress data".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_decompress_data".to_string(),
+            code: 4030,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Cannot decompress data".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_rejection_reason".to_string(),
+            code: 4031,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Invalid rejection reason".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_upload_segment_file".to_string(),
+            code: 4032,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Failed to upload segment file: {0} to the tiered storage.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_download_segment_file".to_string(),
+            code: 4033,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Failed to download segment file: {0} from the tiered storage.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_delete_remote_segment_file".to_string(),
+            code: 4034,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Failed to delete segment file: {0} from the tiered storage.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "tiered_storage_disabled".to_string(),
+            code: 4035,
+            signature: "u64, u32".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Segment with start offset: {0} and partition with ID: {1} is stored in the tiered storage, which is disabled.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "remote_segment_not_fetched".to_string(),
+            code: 4037,
+            signature: "u64, u32".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Log file of segment with start offset: {0} and partition with ID: {1} has to be fetched from the tiered storage.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "reserved_header_key".to_string(),
+            code: 4036,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Header key: {0} is reserved.".to_string(),
+        },
         ErrorRepositoryEntry {
             snake_case_name: "invalid_offset".to_string(),
             code: 4100,
@@ -1212,6 +1348,78 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             source: "".to_string(),
             template: "Failed to delete consumer group info file for ID: {0} for topic with ID: {1} for stream with ID: {2}.".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "consumer_group_member_fenced".to_string(),
+            code: 5009,
+            signature: "u32, u32, u32".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Consumer group member with ID: {0} no longer owns partition with ID: {1} in consumer group with ID: {2}, the generation is stale.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "consumer_group_generation_mismatch".to_string(),
+            code: 5010,
+            signature: "u32, u32, u32".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Consumer group generation: {0} is stale, the current generation of consumer group with ID: {2} is: {1}.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "replication_disabled".to_string(),
+            code: 6000,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Replication is disabled".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_append_messages_to_follower".to_string(),
+            code: 6001,
+            signature: "u32".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Cannot append messages to the follower node with ID: {0}, they must be sent to the leader.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "in_sync_replicas_ack_timeout".to_string(),
+            code: 6002,
+            signature: "u32, u32, u32".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Timed out waiting for the in-sync replicas of partition with ID: {0} for topic with ID: {1} and stream with ID: {2}.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "unknown_replica".to_string(),
+            code: 6003,
+            signature: "u32".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Replica with ID: {0} is not one of the configured followers.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "audit_log_disabled".to_string(),
+            code: 7000,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Audit log is disabled".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_read_audit_log".to_string(),
+            code: 7001,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Cannot read audit log file: {0}".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_audit_log_entries_count".to_string(),
+            code: 7002,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Invalid audit log entries count".to_string(),
+        },
     ];
 
     error_codes
@@ -40,6 +40,14 @@ pub struct Args {
     #[arg(long, default_value = "localhost")]
     pub tcp_tls_domain: String,
 
+    /// The optional path to the PEM client certificate for the mutual TLS authentication over TCP.
+    #[arg(long)]
+    pub tcp_tls_certificate: Option<String>,
+
+    /// The optional path to the PEM private key of the client certificate for TCP.
+    #[arg(long)]
+    pub tcp_tls_key: Option<String>,
+
     /// The optional client address for the QUIC transport.
     #[arg(long, default_value = "127.0.0.1:0")]
     pub quic_client_address: String,
@@ -95,4 +103,12 @@ pub struct Args {
     /// Flag to enable certificate validation for QUIC.
     #[arg(long, default_value = "false")]
     pub quic_validate_certificate: bool,
+
+    /// The optional path to the PEM client certificate for the mutual TLS authentication over QUIC.
+    #[arg(long)]
+    pub quic_client_certificate: Option<String>,
+
+    /// The optional path to the PEM private key of the client certificate for QUIC.
+    #[arg(long)]
+    pub quic_client_key: Option<String>,
 }
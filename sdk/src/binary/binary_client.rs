@@ -22,4 +22,6 @@ pub trait BinaryClient: Client {
     async fn set_state(&self, state: ClientState);
     /// Sends a command and returns the response.
     async fn send_with_response(&self, command: u32, payload: &[u8]) -> Result<Vec<u8>, Error>;
+    /// Sends a command without waiting for the response, the server doesn't respond to such commands.
+    async fn send_without_response(&self, command: u32, payload: &[u8]) -> Result<(), Error>;
 }
@@ -1,16 +1,26 @@
 use crate::bytes_serializable::BytesSerializable;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::Error;
-use crate::models::client_info::{ClientInfo, ClientInfoDetails, ConsumerGroupInfo};
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
+use crate::models::audit_log::{AuditLogEntry, AuditOutcome};
+use crate::models::client_info::{
+    ClientInfo, ClientInfoDetails, ClientQuotasInfo, ConsumerGroupInfo, QuotaUsageInfo,
+    QuotasUsageInfo,
+};
+use crate::models::consumer_group::{
+    ConsumerGroup, ConsumerGroupAssignment, ConsumerGroupDetails, ConsumerGroupMember,
+    ConsumerGroupOffsetReset, ConsumerGroupState, PartitionAssignmentStrategy,
+};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::{Message, MessageState, PolledMessages};
 use crate::models::partition::Partition;
+use crate::models::partition_offsets::{PartitionOffsets, TimestampOffset};
 use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::role_info::{RoleInfo, RoleInfoDetails};
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
-use crate::models::topic::{Topic, TopicDetails};
+use crate::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy, Topic, TopicDetails};
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
 use crate::utils::byte_size::IggyByteSize;
@@ -23,6 +33,7 @@ const EMPTY_TOPICS: Vec<Topic> = vec![];
 const EMPTY_STREAMS: Vec<Stream> = vec![];
 const EMPTY_CLIENTS: Vec<ClientInfo> = vec![];
 const EMPTY_USERS: Vec<UserInfo> = vec![];
+const EMPTY_ROLES: Vec<RoleInfo> = vec![];
 const EMPTY_PERSONAL_ACCESS_TOKENS: Vec<PersonalAccessTokenInfo> = vec![];
 const EMPTY_CONSUMER_GROUPS: Vec<ConsumerGroup> = vec![];
 
@@ -104,28 +115,118 @@ pub fn map_consumer_offset(payload: &[u8]) -> Result<ConsumerOffsetInfo, Error>
     })
 }
 
+pub fn map_partition_offsets(payload: &[u8]) -> Result<Vec<PartitionOffsets>, Error> {
+    let mut offsets = Vec::with_capacity(payload.len() / 20);
+    let mut position = 0;
+    while position < payload.len() {
+        let partition_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let earliest_offset = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+        let latest_offset = u64::from_le_bytes(payload[position + 12..position + 20].try_into()?);
+        offsets.push(PartitionOffsets {
+            partition_id,
+            earliest_offset,
+            latest_offset,
+        });
+        position += 20;
+    }
+    offsets.sort_by_key(|offset| offset.partition_id);
+    Ok(offsets)
+}
+
+pub fn map_timestamp_offsets(payload: &[u8]) -> Result<Vec<TimestampOffset>, Error> {
+    let mut offsets = Vec::with_capacity(payload.len() / 13);
+    let mut position = 0;
+    while position < payload.len() {
+        let partition_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let has_offset = payload[position + 4] == 1;
+        let offset = u64::from_le_bytes(payload[position + 5..position + 13].try_into()?);
+        offsets.push(TimestampOffset {
+            partition_id,
+            offset: if has_offset { Some(offset) } else { None },
+        });
+        position += 13;
+    }
+    offsets.sort_by_key(|offset| offset.partition_id);
+    Ok(offsets)
+}
+
 pub fn map_user(payload: &[u8]) -> Result<UserInfoDetails, Error> {
-    let (user, position) = map_to_user_info(payload, 0)?;
+    let (user, mut position) = map_to_user_info(payload, 0)?;
     let has_permissions = payload[position];
+    position += 1;
     let permissions = if has_permissions == 1 {
         let permissions_length =
-            u32::from_le_bytes(payload[position + 1..position + 5].try_into()?) as usize;
-        let permissions = &payload[position + 5..position + 5 + permissions_length];
+            u32::from_le_bytes(payload[position..position + 4].try_into()?) as usize;
+        let permissions = &payload[position + 4..position + 4 + permissions_length];
+        position += 4 + permissions_length;
         Some(Permissions::from_bytes(permissions)?)
     } else {
         None
     };
 
+    let mut roles = Vec::new();
+    if payload.len() >= position + 4 {
+        let roles_count = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        position += 4;
+        for _ in 0..roles_count {
+            roles.push(u32::from_le_bytes(
+                payload[position..position + 4].try_into()?,
+            ));
+            position += 4;
+        }
+    }
+
+    let mut locked_until = None;
+    if payload.len() >= position + 8 {
+        let timestamp = u64::from_le_bytes(payload[position..position + 8].try_into()?);
+        if timestamp > 0 {
+            locked_until = Some(timestamp);
+        }
+    }
+
     let user = UserInfoDetails {
         id: user.id,
         created_at: user.created_at,
         status: user.status,
         username: user.username,
         permissions,
+        roles,
+        locked_until,
     };
     Ok(user)
 }
 
+pub fn map_role(payload: &[u8]) -> Result<RoleInfoDetails, Error> {
+    let (role, position) = map_to_role_info(payload, 0)?;
+    let permissions_length =
+        u32::from_le_bytes(payload[position..position + 4].try_into()?) as usize;
+    let permissions =
+        Permissions::from_bytes(&payload[position + 4..position + 4 + permissions_length])?;
+    Ok(RoleInfoDetails {
+        id: role.id,
+        created_at: role.created_at,
+        name: role.name,
+        permissions,
+    })
+}
+
+pub fn map_roles(payload: &[u8]) -> Result<Vec<RoleInfo>, Error> {
+    if payload.is_empty() {
+        return Ok(EMPTY_ROLES);
+    }
+
+    let mut roles = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (role, read_bytes) = map_to_role_info(payload, position)?;
+        roles.push(role);
+        position += read_bytes;
+    }
+    roles.sort_by_key(|role| role.id);
+    Ok(roles)
+}
+
 pub fn map_users(payload: &[u8]) -> Result<Vec<UserInfo>, Error> {
     if payload.is_empty() {
         return Ok(EMPTY_USERS);
@@ -177,21 +278,36 @@ pub fn map_raw_pat(payload: &[u8]) -> Result<RawPersonalAccessToken, Error> {
 pub fn map_client(payload: &[u8]) -> Result<ClientInfoDetails, Error> {
     let (client, mut position) = map_to_client_info(payload, 0)?;
     let mut consumer_groups = Vec::new();
-    let length = payload.len();
-    while position < length {
-        for _ in 0..client.consumer_groups_count {
-            let stream_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
-            let topic_id = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
-            let consumer_group_id =
-                u32::from_le_bytes(payload[position + 8..position + 12].try_into()?);
-            let consumer_group = ConsumerGroupInfo {
-                stream_id,
-                topic_id,
-                consumer_group_id,
-            };
-            consumer_groups.push(consumer_group);
-            position += 12;
-        }
+    for _ in 0..client.consumer_groups_count {
+        let stream_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let topic_id = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
+        let consumer_group_id =
+            u32::from_le_bytes(payload[position + 8..position + 12].try_into()?);
+        let consumer_group = ConsumerGroupInfo {
+            stream_id,
+            topic_id,
+            consumer_group_id,
+        };
+        consumer_groups.push(consumer_group);
+        position += 12;
+    }
+
+    let mut quotas = None;
+    if position < payload.len() && payload[position] == 1 {
+        position += 1;
+        let throttled_requests = u64::from_le_bytes(payload[position..position + 8].try_into()?);
+        let rejected_requests =
+            u64::from_le_bytes(payload[position + 8..position + 16].try_into()?);
+        position += 16;
+        let (user, read_bytes) = map_to_quotas_usage(payload, position)?;
+        position += read_bytes;
+        let (client, _) = map_to_quotas_usage(payload, position)?;
+        quotas = Some(ClientQuotasInfo {
+            throttled_requests,
+            rejected_requests,
+            user,
+            client,
+        });
     }
 
     consumer_groups.sort_by(|x, y| x.consumer_group_id.cmp(&y.consumer_group_id));
@@ -202,10 +318,34 @@ pub fn map_client(payload: &[u8]) -> Result<ClientInfoDetails, Error> {
         transport: client.transport,
         consumer_groups_count: client.consumer_groups_count,
         consumer_groups,
+        quotas,
     };
     Ok(client)
 }
 
+fn map_to_quotas_usage(payload: &[u8], position: usize) -> Result<(QuotasUsageInfo, usize), Error> {
+    let mut usages = Vec::with_capacity(5);
+    let mut current_position = position;
+    for _ in 0..5 {
+        let limit = u64::from_le_bytes(payload[current_position..current_position + 8].try_into()?);
+        let used =
+            u64::from_le_bytes(payload[current_position + 8..current_position + 16].try_into()?);
+        usages.push(QuotaUsageInfo { limit, used });
+        current_position += 16;
+    }
+
+    Ok((
+        QuotasUsageInfo {
+            requests: usages[0],
+            sent_messages: usages[1],
+            sent_bytes: usages[2],
+            polled_messages: usages[3],
+            polled_bytes: usages[4],
+        },
+        current_position - position,
+    ))
+}
+
 pub fn map_clients(payload: &[u8]) -> Result<Vec<ClientInfo>, Error> {
     if payload.is_empty() {
         return Ok(EMPTY_CLIENTS);
@@ -223,6 +363,45 @@ pub fn map_clients(payload: &[u8]) -> Result<Vec<ClientInfo>, Error> {
     Ok(clients)
 }
 
+pub fn map_audit_log(payload: &[u8]) -> Result<Vec<AuditLogEntry>, Error> {
+    let mut entries = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let timestamp = u64::from_le_bytes(payload[position..position + 8].try_into()?);
+        let user_id = u32::from_le_bytes(payload[position + 8..position + 12].try_into()?);
+        let outcome = AuditOutcome::from_code(payload[position + 12])?;
+        position += 13;
+        let (address, read_bytes) = map_to_string(payload, position)?;
+        position += read_bytes;
+        let (transport, read_bytes) = map_to_string(payload, position)?;
+        position += read_bytes;
+        let (command, read_bytes) = map_to_string(payload, position)?;
+        position += read_bytes;
+        let (details, read_bytes) = map_to_string(payload, position)?;
+        position += read_bytes;
+        let (error, read_bytes) = map_to_string(payload, position)?;
+        position += read_bytes;
+        entries.push(AuditLogEntry {
+            timestamp,
+            user_id,
+            address,
+            transport,
+            command,
+            details,
+            outcome,
+            error: if error.is_empty() { None } else { Some(error) },
+        });
+    }
+    Ok(entries)
+}
+
+fn map_to_string(payload: &[u8], position: usize) -> Result<(String, usize), Error> {
+    let length = u32::from_le_bytes(payload[position..position + 4].try_into()?) as usize;
+    let value = from_utf8(&payload[position + 4..position + 4 + length])?.to_string();
+    Ok((value, 4 + length))
+}
+
 pub fn map_polled_messages(payload: &[u8]) -> Result<PolledMessages, Error> {
     if payload.is_empty() {
         return Ok(PolledMessages {
@@ -386,6 +565,10 @@ pub fn map_topic(payload: &[u8]) -> Result<TopicDetails, Error> {
         message_expiry: topic.message_expiry,
         max_topic_size: topic.max_topic_size,
         replication_factor: topic.replication_factor,
+        compression_algorithm: topic.compression_algorithm,
+        cleanup_policy: topic.cleanup_policy,
+        max_topic_size_policy: topic.max_topic_size_policy,
+        flush_policy: topic.flush_policy,
         #[allow(clippy::cast_possible_truncation)]
         partitions_count: partitions.len() as u32,
         partitions,
@@ -408,14 +591,18 @@ fn map_to_topic(payload: &[u8], position: usize) -> Result<(Topic, usize), Error
         max_topic_size => Some(IggyByteSize::from(max_topic_size)),
     };
     let replication_factor = payload[position + 28];
+    let compression_algorithm = CompressionAlgorithm::from_code(payload[position + 29])?;
+    let cleanup_policy = CleanupPolicy::from_code(payload[position + 30])?;
+    let max_topic_size_policy = MaxTopicSizePolicy::from_code(payload[position + 31])?;
+    let flush_policy = FlushPolicy::from_bytes(&payload[position + 32..])?;
     let size_bytes = IggyByteSize::from(u64::from_le_bytes(
-        payload[position + 29..position + 37].try_into()?,
+        payload[position + 48..position + 56].try_into()?,
     ));
-    let messages_count = u64::from_le_bytes(payload[position + 37..position + 45].try_into()?);
-    let name_length = payload[position + 45];
+    let messages_count = u64::from_le_bytes(payload[position + 56..position + 64].try_into()?);
+    let name_length = payload[position + 64];
     let name =
-        from_utf8(&payload[position + 46..position + 46 + name_length as usize])?.to_string();
-    let read_bytes = 4 + 8 + 4 + 4 + 8 + 8 + 8 + 1 + 1 + name_length as usize;
+        from_utf8(&payload[position + 65..position + 65 + name_length as usize])?.to_string();
+    let read_bytes = 4 + 8 + 4 + 4 + 8 + 1 + 1 + 1 + 1 + 16 + 8 + 8 + 1 + name_length as usize;
     Ok((
         Topic {
             id,
@@ -427,6 +614,10 @@ fn map_to_topic(payload: &[u8], position: usize) -> Result<(Topic, usize), Error
             message_expiry,
             max_topic_size,
             replication_factor,
+            compression_algorithm,
+            cleanup_policy,
+            max_topic_size_policy,
+            flush_policy,
         },
         read_bytes,
     ))
@@ -439,7 +630,8 @@ fn map_to_partition(payload: &[u8], position: usize) -> Result<(Partition, usize
     let current_offset = u64::from_le_bytes(payload[position + 16..position + 24].try_into()?);
     let size_bytes = u64::from_le_bytes(payload[position + 24..position + 32].try_into()?);
     let messages_count = u64::from_le_bytes(payload[position + 32..position + 40].try_into()?);
-    let read_bytes = 4 + 8 + 4 + 8 + 8 + 8;
+    let (in_sync_replicas, end_position) = map_to_partition_ids(payload, position + 40)?;
+    let read_bytes = end_position - position;
     Ok((
         Partition {
             id,
@@ -448,6 +640,7 @@ fn map_to_partition(payload: &[u8], position: usize) -> Result<(Partition, usize
             current_offset,
             size_bytes,
             messages_count,
+            in_sync_replicas,
         },
         read_bytes,
     ))
@@ -472,6 +665,11 @@ pub fn map_consumer_groups(payload: &[u8]) -> Result<Vec<ConsumerGroup>, Error>
 
 pub fn map_consumer_group(payload: &[u8]) -> Result<ConsumerGroupDetails, Error> {
     let (consumer_group, mut position) = map_to_consumer_group(payload, 0)?;
+    let generation = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    let state = ConsumerGroupState::from_code(payload[position + 4])?;
+    let session_timeout = u32::from_le_bytes(payload[position + 5..position + 9].try_into()?);
+    let assignment_strategy = PartitionAssignmentStrategy::from_code(payload[position + 9])?;
+    position += 10;
     let mut members = Vec::new();
     let length = payload.len();
     while position < length {
@@ -485,11 +683,63 @@ pub fn map_consumer_group(payload: &[u8]) -> Result<ConsumerGroupDetails, Error>
         name: consumer_group.name,
         partitions_count: consumer_group.partitions_count,
         members_count: consumer_group.members_count,
+        generation,
+        state,
+        session_timeout,
+        assignment_strategy,
         members,
     };
     Ok(consumer_group_details)
 }
 
+pub fn map_consumer_group_assignment(payload: &[u8]) -> Result<ConsumerGroupAssignment, Error> {
+    let consumer_group_id = u32::from_le_bytes(payload[..4].try_into()?);
+    let generation = u32::from_le_bytes(payload[4..8].try_into()?);
+    let state = ConsumerGroupState::from_code(payload[8])?;
+    let (partitions, position) = map_to_partition_ids(payload, 9)?;
+    let (pending_partitions, _) = map_to_partition_ids(payload, position)?;
+    Ok(ConsumerGroupAssignment {
+        consumer_group_id,
+        generation,
+        state,
+        partitions,
+        pending_partitions,
+    })
+}
+
+pub fn map_consumer_group_offset_resets(
+    payload: &[u8],
+) -> Result<Vec<ConsumerGroupOffsetReset>, Error> {
+    let mut offsets = Vec::with_capacity(payload.len() / 20);
+    let mut position = 0;
+    while position < payload.len() {
+        let partition_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let previous_offset = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+        let new_offset = u64::from_le_bytes(payload[position + 12..position + 20].try_into()?);
+        offsets.push(ConsumerGroupOffsetReset {
+            partition_id,
+            previous_offset,
+            new_offset,
+        });
+        position += 20;
+    }
+    offsets.sort_by_key(|offset| offset.partition_id);
+    Ok(offsets)
+}
+
+fn map_to_partition_ids(payload: &[u8], position: usize) -> Result<(Vec<u32>, usize), Error> {
+    let count = u32::from_le_bytes(payload[position..position + 4].try_into()?) as usize;
+    let mut partition_ids = Vec::with_capacity(count);
+    let mut position = position + 4;
+    for _ in 0..count {
+        partition_ids.push(u32::from_le_bytes(
+            payload[position..position + 4].try_into()?,
+        ));
+        position += 4;
+    }
+    Ok((partition_ids, position))
+}
+
 fn map_to_consumer_group(payload: &[u8], position: usize) -> Result<(ConsumerGroup, usize), Error> {
     let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
     let partitions_count = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
@@ -548,6 +798,7 @@ fn map_to_client_info(payload: &[u8], mut position: usize) -> Result<(ClientInfo
     let transport = match transport {
         1 => "TCP",
         2 => "QUIC",
+        3 => "HTTP",
         _ => "Unknown",
     }
     .to_string();
@@ -592,6 +843,24 @@ fn map_to_user_info(payload: &[u8], position: usize) -> Result<(UserInfo, usize)
     ))
 }
 
+fn map_to_role_info(payload: &[u8], position: usize) -> Result<(RoleInfo, usize), Error> {
+    let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    let created_at = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+    let name_length = payload[position + 12];
+    let name =
+        from_utf8(&payload[position + 13..position + 13 + name_length as usize])?.to_string();
+    let read_bytes = 4 + 8 + 1 + name_length as usize;
+
+    Ok((
+        RoleInfo {
+            id,
+            created_at,
+            name,
+        },
+        read_bytes,
+    ))
+}
+
 fn map_to_pat_info(
     payload: &[u8],
     position: usize,
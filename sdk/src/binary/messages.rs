@@ -1,10 +1,14 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
-use crate::command::{POLL_MESSAGES_CODE, SEND_MESSAGES_CODE};
+use crate::command::{
+    FETCH_REPLICA_MESSAGES_CODE, POLL_MESSAGES_CODE, REJECT_MESSAGE_CODE, SEND_MESSAGES_CODE,
+};
 use crate::error::Error;
+use crate::messages::fetch_replica_messages::FetchReplicaMessages;
 use crate::messages::poll_messages::PollMessages;
-use crate::messages::send_messages::SendMessages;
+use crate::messages::reject_message::RejectMessage;
+use crate::messages::send_messages::{AckLevel, SendMessages};
 use crate::models::messages::PolledMessages;
 
 pub async fn poll_messages(
@@ -20,8 +24,36 @@ pub async fn poll_messages(
 
 pub async fn send_messages(client: &dyn BinaryClient, command: &SendMessages) -> Result<(), Error> {
     fail_if_not_authenticated(client).await?;
+    if command.ack == AckLevel::None {
+        return client
+            .send_without_response(SEND_MESSAGES_CODE, &command.as_bytes())
+            .await;
+    }
+
     client
         .send_with_response(SEND_MESSAGES_CODE, &command.as_bytes())
         .await?;
     Ok(())
 }
+
+pub async fn reject_message(
+    client: &dyn BinaryClient,
+    command: &RejectMessage,
+) -> Result<(), Error> {
+    fail_if_not_authenticated(client).await?;
+    client
+        .send_with_response(REJECT_MESSAGE_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn fetch_replica_messages(
+    client: &dyn BinaryClient,
+    command: &FetchReplicaMessages,
+) -> Result<PolledMessages, Error> {
+    fail_if_not_authenticated(client).await?;
+    let response = client
+        .send_with_response(FETCH_REPLICA_MESSAGES_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_polled_messages(&response)
+}
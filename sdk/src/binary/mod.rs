@@ -8,6 +8,7 @@ mod mapper;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod roles;
 pub mod streams;
 pub mod system;
 pub mod topics;
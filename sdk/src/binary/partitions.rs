@@ -1,10 +1,16 @@
 use crate::binary::binary_client::BinaryClient;
-use crate::binary::fail_if_not_authenticated;
+use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
-use crate::command::{CREATE_PARTITIONS_CODE, DELETE_PARTITIONS_CODE};
+use crate::command::{
+    CREATE_PARTITIONS_CODE, DELETE_PARTITIONS_CODE, GET_OFFSETS_FOR_TIMESTAMP_CODE,
+    GET_PARTITION_OFFSETS_CODE,
+};
 use crate::error::Error;
+use crate::models::partition_offsets::{PartitionOffsets, TimestampOffset};
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use crate::partitions::get_partition_offsets::GetPartitionOffsets;
 
 pub async fn create_partitions(
     client: &dyn BinaryClient,
@@ -27,3 +33,25 @@ pub async fn delete_partitions(
         .await?;
     Ok(())
 }
+
+pub async fn get_partition_offsets(
+    client: &dyn BinaryClient,
+    command: &GetPartitionOffsets,
+) -> Result<Vec<PartitionOffsets>, Error> {
+    fail_if_not_authenticated(client).await?;
+    let response = client
+        .send_with_response(GET_PARTITION_OFFSETS_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_partition_offsets(&response)
+}
+
+pub async fn get_offsets_for_timestamp(
+    client: &dyn BinaryClient,
+    command: &GetOffsetsForTimestamp,
+) -> Result<Vec<TimestampOffset>, Error> {
+    fail_if_not_authenticated(client).await?;
+    let response = client
+        .send_with_response(GET_OFFSETS_FOR_TIMESTAMP_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_timestamp_offsets(&response)
+}
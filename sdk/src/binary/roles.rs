@@ -0,0 +1,57 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::*;
+use crate::error::Error;
+use crate::models::role_info::{RoleInfo, RoleInfoDetails};
+use crate::roles::create_role::CreateRole;
+use crate::roles::delete_role::DeleteRole;
+use crate::roles::get_role::GetRole;
+use crate::roles::get_roles::GetRoles;
+use crate::roles::update_role::UpdateRole;
+
+pub async fn get_role(
+    client: &dyn BinaryClient,
+    command: &GetRole,
+) -> Result<RoleInfoDetails, Error> {
+    fail_if_not_authenticated(client).await?;
+    let response = client
+        .send_with_response(GET_ROLE_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_role(&response)
+}
+
+pub async fn get_roles(
+    client: &dyn BinaryClient,
+    command: &GetRoles,
+) -> Result<Vec<RoleInfo>, Error> {
+    fail_if_not_authenticated(client).await?;
+    let response = client
+        .send_with_response(GET_ROLES_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_roles(&response)
+}
+
+pub async fn create_role(client: &dyn BinaryClient, command: &CreateRole) -> Result<(), Error> {
+    fail_if_not_authenticated(client).await?;
+    client
+        .send_with_response(CREATE_ROLE_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn delete_role(client: &dyn BinaryClient, command: &DeleteRole) -> Result<(), Error> {
+    fail_if_not_authenticated(client).await?;
+    client
+        .send_with_response(DELETE_ROLE_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn update_role(client: &dyn BinaryClient, command: &UpdateRole) -> Result<(), Error> {
+    fail_if_not_authenticated(client).await?;
+    client
+        .send_with_response(UPDATE_ROLE_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
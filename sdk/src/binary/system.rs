@@ -1,10 +1,14 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
-use crate::command::{GET_CLIENTS_CODE, GET_CLIENT_CODE, GET_ME_CODE, GET_STATS_CODE, PING_CODE};
+use crate::command::{
+    GET_AUDIT_LOG_CODE, GET_CLIENTS_CODE, GET_CLIENT_CODE, GET_ME_CODE, GET_STATS_CODE, PING_CODE,
+};
 use crate::error::Error;
+use crate::models::audit_log::AuditLogEntry;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::stats::Stats;
+use crate::system::get_audit_log::GetAuditLog;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
@@ -52,6 +56,17 @@ pub async fn get_clients(
     mapper::map_clients(&response)
 }
 
+pub async fn get_audit_log(
+    client: &dyn BinaryClient,
+    command: &GetAuditLog,
+) -> Result<Vec<AuditLogEntry>, Error> {
+    fail_if_not_authenticated(client).await?;
+    let response = client
+        .send_with_response(GET_AUDIT_LOG_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_audit_log(&response)
+}
+
 pub async fn ping(client: &dyn BinaryClient, command: &Ping) -> Result<(), Error> {
     client
         .send_with_response(PING_CODE, &command.as_bytes())
@@ -5,6 +5,7 @@ use crate::command::*;
 use crate::error::Error;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
+use crate::users::assign_roles::AssignRoles;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
@@ -12,6 +13,7 @@ use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
 use crate::users::update_user::UpdateUser;
 
@@ -72,6 +74,22 @@ pub async fn update_permissions(
     Ok(())
 }
 
+pub async fn assign_roles(client: &dyn BinaryClient, command: &AssignRoles) -> Result<(), Error> {
+    fail_if_not_authenticated(client).await?;
+    client
+        .send_with_response(ASSIGN_ROLES_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn unlock_user(client: &dyn BinaryClient, command: &UnlockUser) -> Result<(), Error> {
+    fail_if_not_authenticated(client).await?;
+    client
+        .send_with_response(UNLOCK_USER_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
 pub async fn change_password(
     client: &dyn BinaryClient,
     command: &ChangePassword,
@@ -1,5 +1,6 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
+use crate::models::client_info::QuotaUsageInfo;
 use crate::system::get_client::GetClient;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -71,8 +72,65 @@ impl CliCommand for GetClientCmd {
             ]);
         }
 
+        if let Some(quotas) = client_details.quotas {
+            table.add_row(vec![
+                "Throttled Requests",
+                format!("{}", quotas.throttled_requests).as_str(),
+            ]);
+            table.add_row(vec![
+                "Rejected Requests",
+                format!("{}", quotas.rejected_requests).as_str(),
+            ]);
+
+            let mut usage = Table::new();
+            usage.load_preset(ASCII_NO_BORDERS);
+            usage.set_header(vec![
+                "Quota (per second)",
+                "User Used / Limit",
+                "Client Used / Limit",
+            ]);
+            for (name, user, client) in [
+                ("Requests", quotas.user.requests, quotas.client.requests),
+                (
+                    "Sent Messages",
+                    quotas.user.sent_messages,
+                    quotas.client.sent_messages,
+                ),
+                (
+                    "Sent Bytes",
+                    quotas.user.sent_bytes,
+                    quotas.client.sent_bytes,
+                ),
+                (
+                    "Polled Messages",
+                    quotas.user.polled_messages,
+                    quotas.client.polled_messages,
+                ),
+                (
+                    "Polled Bytes",
+                    quotas.user.polled_bytes,
+                    quotas.client.polled_bytes,
+                ),
+            ] {
+                usage.add_row(vec![
+                    name,
+                    format_quota_usage(&user).as_str(),
+                    format_quota_usage(&client).as_str(),
+                ]);
+            }
+
+            table.add_row(vec!["Quotas Usage", usage.to_string().as_str()]);
+        }
+
         event!(target: PRINT_TARGET, Level::INFO, "{table}");
 
         Ok(())
     }
 }
+
+fn format_quota_usage(quota: &QuotaUsageInfo) -> String {
+    match quota.limit {
+        0 => String::from("unlimited"),
+        limit => format!("{} / {}", quota.used, limit),
+    }
+}
@@ -2,6 +2,7 @@ use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::identifier::Identifier;
+use crate::models::consumer_group::PartitionAssignmentStrategy;
 use anyhow::Context;
 use async_trait::async_trait;
 use tracing::{event, Level};
@@ -16,6 +17,8 @@ impl CreateConsumerGroupCmd {
         topic_id: Identifier,
         consumer_group_id: u32,
         name: String,
+        session_timeout: Option<u32>,
+        assignment_strategy: PartitionAssignmentStrategy,
     ) -> Self {
         Self {
             create_consumer_group: CreateConsumerGroup {
@@ -23,6 +26,8 @@ impl CreateConsumerGroupCmd {
                 topic_id,
                 consumer_group_id,
                 name,
+                session_timeout,
+                assignment_strategy,
             },
         }
     }
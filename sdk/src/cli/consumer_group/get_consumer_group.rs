@@ -5,6 +5,8 @@ use crate::identifier::Identifier;
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::{presets::ASCII_NO_BORDERS, Table};
+use humantime::format_duration;
+use std::time::Duration;
 use tracing::{event, Level};
 
 pub struct GetConsumerGroupCmd {
@@ -61,6 +63,25 @@ impl CliCommand for GetConsumerGroupCmd {
             "Members count",
             format!("{}", consumer_group.members_count).as_str(),
         ]);
+        table.add_row(vec![
+            "Generation",
+            format!("{}", consumer_group.generation).as_str(),
+        ]);
+        table.add_row(vec!["State", format!("{}", consumer_group.state).as_str()]);
+        table.add_row(vec![
+            "Session timeout",
+            match consumer_group.session_timeout {
+                0 => "default".to_string(),
+                session_timeout => {
+                    format_duration(Duration::from_secs(session_timeout as u64)).to_string()
+                }
+            }
+            .as_str(),
+        ]);
+        table.add_row(vec![
+            "Assignment strategy",
+            format!("{}", consumer_group.assignment_strategy).as_str(),
+        ]);
 
         if consumer_group.members_count > 0 {
             let mut members_table = Table::new();
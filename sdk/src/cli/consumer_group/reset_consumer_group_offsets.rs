@@ -0,0 +1,109 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer_groups::reset_consumer_group_offsets::{
+    OffsetsResetStrategy, ResetConsumerGroupOffsets,
+};
+use crate::identifier::Identifier;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct ResetConsumerGroupOffsetsCmd {
+    reset_consumer_group_offsets: ResetConsumerGroupOffsets,
+}
+
+impl ResetConsumerGroupOffsetsCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        consumer_group_id: Identifier,
+        partition_ids: Vec<u32>,
+        strategy: OffsetsResetStrategy,
+        dry_run: bool,
+    ) -> Self {
+        Self {
+            reset_consumer_group_offsets: ResetConsumerGroupOffsets {
+                stream_id,
+                topic_id,
+                consumer_group_id,
+                partition_ids,
+                strategy,
+                dry_run,
+            },
+        }
+    }
+
+    fn partitions(&self) -> String {
+        match self.reset_consumer_group_offsets.partition_ids.is_empty() {
+            true => String::from("all partitions"),
+            false => format!(
+                "partitions: {}",
+                self.reset_consumer_group_offsets
+                    .partition_ids
+                    .iter()
+                    .map(|partition_id| partition_id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for ResetConsumerGroupOffsetsCmd {
+    fn explain(&self) -> String {
+        format!(
+            "{}reset offsets ({}) of consumer group with ID: {} for {} of topic with ID: {} and stream with ID: {}",
+            if self.reset_consumer_group_offsets.dry_run { "dry run of " } else { "" },
+            self.reset_consumer_group_offsets.strategy,
+            self.reset_consumer_group_offsets.consumer_group_id,
+            self.partitions(),
+            self.reset_consumer_group_offsets.topic_id,
+            self.reset_consumer_group_offsets.stream_id,
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let offsets = client
+            .reset_consumer_group_offsets(&self.reset_consumer_group_offsets)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem resetting offsets of consumer group with ID: {} for topic with ID: {} and stream with ID: {}",
+                    self.reset_consumer_group_offsets.consumer_group_id,
+                    self.reset_consumer_group_offsets.topic_id,
+                    self.reset_consumer_group_offsets.stream_id
+                )
+            })?;
+
+        let mut table = Table::new();
+        table.set_header(vec!["Partition ID", "Previous Offset", "New Offset"]);
+        offsets.iter().for_each(|offset| {
+            table.add_row(vec![
+                format!("{}", offset.partition_id),
+                format!("{}", offset.previous_offset),
+                format!("{}", offset.new_offset),
+            ]);
+        });
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+        if self.reset_consumer_group_offsets.dry_run {
+            event!(target: PRINT_TARGET, Level::INFO,
+                "Dry run, offsets of consumer group with ID: {} for topic with ID: {} and stream with ID: {} were not changed",
+                self.reset_consumer_group_offsets.consumer_group_id,
+                self.reset_consumer_group_offsets.topic_id,
+                self.reset_consumer_group_offsets.stream_id,
+            );
+        } else {
+            event!(target: PRINT_TARGET, Level::INFO,
+                "Offsets of consumer group with ID: {} reset for topic with ID: {} and stream with ID: {}",
+                self.reset_consumer_group_offsets.consumer_group_id,
+                self.reset_consumer_group_offsets.topic_id,
+                self.reset_consumer_group_offsets.stream_id,
+            );
+        }
+
+        Ok(())
+    }
+}
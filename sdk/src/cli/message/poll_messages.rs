@@ -44,6 +44,8 @@ impl PollMessagesCmd {
                 strategy,
                 count: message_count,
                 auto_commit,
+                skip_poisoned: false,
+                generation: None,
             },
         }
     }
@@ -1,7 +1,7 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
-use crate::messages::send_messages::{Message, Partitioning, SendMessages};
+use crate::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
 use anyhow::Context;
 use async_trait::async_trait;
 use std::io::{self, Read};
@@ -12,6 +12,7 @@ pub struct SendMessagesCmd {
     stream_id: Identifier,
     topic_id: Identifier,
     partitioning: Partitioning,
+    ack: AckLevel,
     messages: Option<Vec<String>>,
 }
 
@@ -21,6 +22,7 @@ impl SendMessagesCmd {
         topic_id: Identifier,
         partition_id: Option<u32>,
         message_key: Option<String>,
+        ack: AckLevel,
         messages: Option<Vec<String>>,
     ) -> Self {
         let partitioning = match (partition_id, message_key) {
@@ -39,6 +41,7 @@ impl SendMessagesCmd {
             stream_id,
             topic_id,
             partitioning,
+            ack,
             messages,
         }
     }
@@ -82,6 +85,7 @@ impl CliCommand for SendMessagesCmd {
                 stream_id: self.stream_id.clone(),
                 topic_id: self.topic_id.clone(),
                 partitioning: self.partitioning.clone(),
+                ack: self.ack,
                 messages,
             })
             .await
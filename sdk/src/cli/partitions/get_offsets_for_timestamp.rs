@@ -0,0 +1,66 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetOffsetsForTimestampCmd {
+    get_offsets_for_timestamp: GetOffsetsForTimestamp,
+}
+
+impl GetOffsetsForTimestampCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, timestamp: u64) -> Self {
+        Self {
+            get_offsets_for_timestamp: GetOffsetsForTimestamp {
+                stream_id,
+                topic_id,
+                timestamp,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetOffsetsForTimestampCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get offsets for timestamp: {} for topic with ID: {} and stream with ID: {}",
+            self.get_offsets_for_timestamp.timestamp,
+            self.get_offsets_for_timestamp.topic_id,
+            self.get_offsets_for_timestamp.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let offsets = client
+            .get_offsets_for_timestamp(&self.get_offsets_for_timestamp)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting offsets for timestamp: {} for topic with ID: {} and stream with ID: {}",
+                    self.get_offsets_for_timestamp.timestamp,
+                    self.get_offsets_for_timestamp.topic_id,
+                    self.get_offsets_for_timestamp.stream_id
+                )
+            })?;
+
+        let mut table = Table::new();
+        table.set_header(vec!["Partition ID", "Offset"]);
+        offsets.iter().for_each(|offset| {
+            table.add_row(vec![
+                format!("{}", offset.partition_id),
+                match offset.offset {
+                    Some(offset) => format!("{}", offset),
+                    None => String::from("none"),
+                },
+            ]);
+        });
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
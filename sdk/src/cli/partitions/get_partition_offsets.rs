@@ -0,0 +1,59 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::partitions::get_partition_offsets::GetPartitionOffsets;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetPartitionOffsetsCmd {
+    get_partition_offsets: GetPartitionOffsets,
+}
+
+impl GetPartitionOffsetsCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier) -> Self {
+        Self {
+            get_partition_offsets: GetPartitionOffsets {
+                stream_id,
+                topic_id,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetPartitionOffsetsCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get partition offsets for topic with ID: {} and stream with ID: {}",
+            self.get_partition_offsets.topic_id, self.get_partition_offsets.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let offsets = client
+            .get_partition_offsets(&self.get_partition_offsets)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting partition offsets for topic with ID: {} and stream with ID: {}",
+                    self.get_partition_offsets.topic_id, self.get_partition_offsets.stream_id
+                )
+            })?;
+
+        let mut table = Table::new();
+        table.set_header(vec!["Partition ID", "Earliest Offset", "Latest Offset"]);
+        offsets.iter().for_each(|offsets| {
+            table.add_row(vec![
+                format!("{}", offsets.partition_id),
+                format!("{}", offsets.earliest_offset),
+                format!("{}", offsets.latest_offset),
+            ]);
+        });
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -1,2 +1,4 @@
 pub mod create_partitions;
 pub mod delete_partitions;
+pub mod get_offsets_for_timestamp;
+pub mod get_partition_offsets;
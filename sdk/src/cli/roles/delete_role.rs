@@ -0,0 +1,45 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::roles::delete_role::DeleteRole;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct DeleteRoleCmd {
+    delete_role: DeleteRole,
+}
+
+impl DeleteRoleCmd {
+    pub fn new(role_id: Identifier) -> Self {
+        Self {
+            delete_role: DeleteRole { role_id },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for DeleteRoleCmd {
+    fn explain(&self) -> String {
+        format!("delete role with ID: {}", self.delete_role.role_id)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .delete_role(&self.delete_role)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem deleting role with ID: {}",
+                    self.delete_role.role_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Role with ID: {} deleted",
+            self.delete_role.role_id
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::roles::get_role::GetRole;
+use crate::utils::timestamp::IggyTimestamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetRoleCmd {
+    get_role: GetRole,
+}
+
+impl GetRoleCmd {
+    pub fn new(role_id: Identifier) -> Self {
+        Self {
+            get_role: GetRole { role_id },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetRoleCmd {
+    fn explain(&self) -> String {
+        format!("get role with ID: {}", self.get_role.role_id)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let role = client
+            .get_role(&self.get_role)
+            .await
+            .with_context(|| format!("Problem getting role with ID: {}", self.get_role.role_id))?;
+
+        let mut table = Table::new();
+
+        table.set_header(vec!["Property", "Value"]);
+        table.add_row(vec!["Role ID", format!("{}", role.id).as_str()]);
+        table.add_row(vec![
+            "Created",
+            IggyTimestamp::from(role.created_at)
+                .to_local("%Y-%m-%d %H:%M:%S")
+                .as_str(),
+        ]);
+        table.add_row(vec!["Name", role.name.as_str()]);
+
+        let global_permissions: Table = role.permissions.global.into();
+        table.add_row(vec!["Global", format!("{}", global_permissions).as_str()]);
+
+        if let Some(streams) = role.permissions.streams {
+            streams.iter().for_each(|(stream_id, stream_permissions)| {
+                let stream_permissions: Table = stream_permissions.into();
+                table.add_row(vec![
+                    format!("Stream: {}", stream_id).as_str(),
+                    format!("{}", stream_permissions).as_str(),
+                ]);
+            });
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::system::get_audit_log::GetAuditLog;
+use crate::utils::timestamp::IggyTimestamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub enum GetAuditLogOutput {
+    Table,
+    List,
+}
+
+pub struct GetAuditLogCmd {
+    get_audit_log: GetAuditLog,
+    output: GetAuditLogOutput,
+}
+
+impl GetAuditLogCmd {
+    pub fn new(count: u32, user_id: Option<u32>, output: GetAuditLogOutput) -> Self {
+        Self {
+            get_audit_log: GetAuditLog { count, user_id },
+            output,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetAuditLogCmd {
+    fn explain(&self) -> String {
+        let mode = match self.output {
+            GetAuditLogOutput::Table => "table",
+            GetAuditLogOutput::List => "list",
+        };
+        match self.get_audit_log.user_id {
+            Some(user_id) => format!(
+                "get {} latest audit log entries for user with ID: {user_id} in {mode} mode",
+                self.get_audit_log.count
+            ),
+            None => format!(
+                "get {} latest audit log entries in {mode} mode",
+                self.get_audit_log.count
+            ),
+        }
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let entries = client
+            .get_audit_log(&self.get_audit_log)
+            .await
+            .with_context(|| String::from("Problem getting audit log"))?;
+
+        if entries.is_empty() {
+            event!(target: PRINT_TARGET, Level::INFO, "No audit log entries found!");
+            return Ok(());
+        }
+
+        match self.output {
+            GetAuditLogOutput::Table => {
+                let mut table = Table::new();
+
+                table.set_header(vec![
+                    "Time",
+                    "User ID",
+                    "Address",
+                    "Transport",
+                    "Command",
+                    "Details",
+                    "Outcome",
+                    "Error",
+                ]);
+
+                entries.iter().for_each(|entry| {
+                    table.add_row(vec![
+                        IggyTimestamp::from(entry.timestamp).to_local("%Y-%m-%d %H:%M:%S"),
+                        format!("{}", entry.user_id),
+                        entry.address.clone(),
+                        entry.transport.clone(),
+                        entry.command.clone(),
+                        entry.details.clone(),
+                        format!("{}", entry.outcome),
+                        entry.error.clone().unwrap_or_default(),
+                    ]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+            GetAuditLogOutput::List => {
+                entries.iter().for_each(|entry| {
+                    event!(target: PRINT_TARGET, Level::INFO,
+                        "{}|{}|{}|{}|{}|{}|{}|{}",
+                        IggyTimestamp::from(entry.timestamp).to_local("%Y-%m-%d %H:%M:%S"),
+                        entry.user_id,
+                        entry.address,
+                        entry.transport,
+                        entry.command,
+                        entry.details,
+                        entry.outcome,
+                        entry.error.as_deref().unwrap_or_default(),
+                    );
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
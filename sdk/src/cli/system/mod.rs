@@ -1,3 +1,4 @@
+pub mod audit_log;
 pub mod me;
 pub mod ping;
 pub mod stats;
@@ -1,7 +1,9 @@
 use crate::cli::utils::message_expiry::MessageExpiry;
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::identifier::Identifier;
+use crate::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use crate::topics::create_topic::CreateTopic;
 use crate::utils::byte_size::IggyByteSize;
 use anyhow::Context;
@@ -17,6 +19,7 @@ pub struct CreateTopicCmd {
 }
 
 impl CreateTopicCmd {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         stream_id: Identifier,
         topic_id: u32,
@@ -25,6 +28,10 @@ impl CreateTopicCmd {
         message_expiry: MessageExpiry,
         max_topic_size: IggyByteSize,
         replication_factor: u8,
+        compression_algorithm: Option<CompressionAlgorithm>,
+        cleanup_policy: CleanupPolicy,
+        max_topic_size_policy: MaxTopicSizePolicy,
+        flush_policy: FlushPolicy,
     ) -> Self {
         Self {
             create_topic: CreateTopic {
@@ -35,12 +42,23 @@ impl CreateTopicCmd {
                 message_expiry: message_expiry.clone().into(),
                 max_topic_size: Some(max_topic_size),
                 replication_factor,
+                compression_algorithm,
+                cleanup_policy,
+                max_topic_size_policy,
+                flush_policy,
             },
             message_expiry,
             max_topic_size,
             replication_factor,
         }
     }
+
+    fn compression_algorithm(&self) -> String {
+        match self.create_topic.compression_algorithm {
+            Some(compression_algorithm) => compression_algorithm.to_string(),
+            None => "server default".to_string(),
+        }
+    }
 }
 
 #[async_trait]
@@ -61,13 +79,17 @@ impl CliCommand for CreateTopicCmd {
             })?;
 
         event!(target: PRINT_TARGET, Level::INFO,
-            "Topic with ID: {}, name: {}, partitions count: {}, message expiry: {}, max topic size: {}, replication factor: {} created in stream with ID: {}",
+            "Topic with ID: {}, name: {}, partitions count: {}, message expiry: {}, max topic size: {}, replication factor: {}, compression algorithm: {}, cleanup policy: {}, max topic size policy: {}, flush policy: ({}) created in stream with ID: {}",
             self.create_topic.topic_id,
             self.create_topic.name,
             self.create_topic.partitions_count,
             self.message_expiry,
             self.max_topic_size.as_human_string_with_zero_as_unlimited(),
             self.replication_factor,
+            self.compression_algorithm(),
+            self.create_topic.cleanup_policy,
+            self.create_topic.max_topic_size_policy,
+            self.create_topic.flush_policy,
             self.create_topic.stream_id,
         );
 
@@ -82,12 +104,19 @@ impl fmt::Display for CreateTopicCmd {
         let message_expiry = &self.message_expiry;
         let max_topic_size = &self.max_topic_size.as_human_string_with_zero_as_unlimited();
         let replication_factor = self.replication_factor;
+        let compression_algorithm = self.compression_algorithm();
+        let cleanup_policy = self.create_topic.cleanup_policy;
+        let max_topic_size_policy = self.create_topic.max_topic_size_policy;
+        let flush_policy = self.create_topic.flush_policy;
         let stream_id = &self.create_topic.stream_id;
 
         write!(
             f,
             "create topic with ID: {topic_id}, name: {topic_name}, message expiry: {message_expiry}, \
-            max topic size: {max_topic_size}, replication factor: {replication_factor} in stream with ID: {stream_id}",
+            max topic size: {max_topic_size}, replication factor: {replication_factor}, \
+            compression algorithm: {compression_algorithm}, cleanup policy: {cleanup_policy}, \
+            max topic size policy: {max_topic_size_policy}, flush policy: ({flush_policy}) \
+            in stream with ID: {stream_id}",
         )
     }
 }
@@ -68,6 +68,22 @@ impl CliCommand for GetTopicCmd {
             }
             .as_str(),
         ]);
+        table.add_row(vec![
+            "Compression",
+            format!("{}", topic.compression_algorithm).as_str(),
+        ]);
+        table.add_row(vec![
+            "Cleanup policy",
+            format!("{}", topic.cleanup_policy).as_str(),
+        ]);
+        table.add_row(vec![
+            "Max size policy",
+            format!("{}", topic.max_topic_size_policy).as_str(),
+        ]);
+        table.add_row(vec![
+            "Flush policy",
+            format!("{}", topic.flush_policy).as_str(),
+        ]);
         table.add_row(vec![
             "Topic message count",
             format!("{}", topic.messages_count).as_str(),
@@ -1,7 +1,9 @@
 use crate::cli::utils::message_expiry::MessageExpiry;
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::identifier::Identifier;
+use crate::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use crate::topics::update_topic::UpdateTopic;
 use crate::utils::byte_size::IggyByteSize;
 use anyhow::Context;
@@ -17,6 +19,7 @@ pub struct UpdateTopicCmd {
 }
 
 impl UpdateTopicCmd {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         stream_id: Identifier,
         topic_id: Identifier,
@@ -24,6 +27,10 @@ impl UpdateTopicCmd {
         message_expiry: MessageExpiry,
         max_topic_size: IggyByteSize,
         replication_factor: u8,
+        compression_algorithm: Option<CompressionAlgorithm>,
+        cleanup_policy: Option<CleanupPolicy>,
+        max_topic_size_policy: Option<MaxTopicSizePolicy>,
+        flush_policy: Option<FlushPolicy>,
     ) -> Self {
         Self {
             update_topic: UpdateTopic {
@@ -33,12 +40,23 @@ impl UpdateTopicCmd {
                 message_expiry: message_expiry.clone().into(),
                 max_topic_size: Some(max_topic_size),
                 replication_factor,
+                compression_algorithm,
+                cleanup_policy,
+                max_topic_size_policy,
+                flush_policy,
             },
             message_expiry,
             max_topic_size,
             replication_factor,
         }
     }
+
+    fn unchanged_or<T: fmt::Display>(value: &Option<T>) -> String {
+        match value {
+            Some(value) => value.to_string(),
+            None => "unchanged".to_string(),
+        }
+    }
 }
 
 #[async_trait]
@@ -80,13 +98,22 @@ impl fmt::Display for UpdateTopicCmd {
         let message_expiry = &self.message_expiry;
         let max_topic_size = &self.max_topic_size.as_human_string_with_zero_as_unlimited();
         let replication_factor = self.replication_factor;
+        let compression_algorithm = Self::unchanged_or(&self.update_topic.compression_algorithm);
+        let cleanup_policy = Self::unchanged_or(&self.update_topic.cleanup_policy);
+        let max_topic_size_policy = Self::unchanged_or(&self.update_topic.max_topic_size_policy);
+        let flush_policy = match &self.update_topic.flush_policy {
+            Some(flush_policy) => format!("({flush_policy})"),
+            None => "unchanged".to_string(),
+        };
         let stream_id = &self.update_topic.stream_id;
 
         write!(
             f,
             "update topic with ID: {topic_id}, name: {topic_name}, message expiry: \
             {message_expiry}, max topic size: {max_topic_size}, replication \
-            factor: {replication_factor}, in stream with ID: {stream_id}",
+            factor: {replication_factor}, compression algorithm: {compression_algorithm}, \
+            cleanup policy: {cleanup_policy}, max topic size policy: {max_topic_size_policy}, \
+            flush policy: {flush_policy}, in stream with ID: {stream_id}",
         )
     }
 }
@@ -0,0 +1,60 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::users::assign_roles::AssignRoles;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct AssignRolesCmd {
+    assign_roles: AssignRoles,
+}
+
+impl AssignRolesCmd {
+    pub fn new(user_id: Identifier, roles: Vec<Identifier>) -> Self {
+        Self {
+            assign_roles: AssignRoles { user_id, roles },
+        }
+    }
+
+    fn get_roles(&self) -> String {
+        self.assign_roles
+            .roles
+            .iter()
+            .map(|role| role.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[async_trait]
+impl CliCommand for AssignRolesCmd {
+    fn explain(&self) -> String {
+        format!(
+            "assign roles: [{}] to user with ID: {}",
+            self.get_roles(),
+            self.assign_roles.user_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .assign_roles(&self.assign_roles)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem assigning roles: [{}] to user with ID: {}",
+                    self.get_roles(),
+                    self.assign_roles.user_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Roles: [{}] assigned to user with ID: {}",
+            self.get_roles(),
+            self.assign_roles.user_id
+        );
+
+        Ok(())
+    }
+}
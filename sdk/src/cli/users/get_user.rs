@@ -155,6 +155,25 @@ impl CliCommand for GetUserCmd {
         table.add_row(vec!["Status", format!("{}", user.status).as_str()]);
         table.add_row(vec!["Username", user.username.as_str()]);
 
+        if let Some(locked_until) = user.locked_until {
+            table.add_row(vec![
+                "Locked Until",
+                IggyTimestamp::from(locked_until)
+                    .to_local("%Y-%m-%d %H:%M:%S")
+                    .as_str(),
+            ]);
+        }
+
+        if !user.roles.is_empty() {
+            let roles = user
+                .roles
+                .iter()
+                .map(|role_id| role_id.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            table.add_row(vec!["Roles", roles.as_str()]);
+        }
+
         if let Some(permissions) = user.permissions {
             let global_permissions: Table = permissions.global.into();
             table.add_row(vec!["Global", format!("{}", global_permissions).as_str()]);
@@ -1,7 +1,9 @@
+pub mod assign_roles;
 pub mod change_password;
 pub mod create_user;
 pub mod delete_user;
 pub mod get_user;
 pub mod get_users;
+pub mod unlock_user;
 pub mod update_permissions;
 pub mod update_user;
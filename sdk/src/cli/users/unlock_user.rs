@@ -0,0 +1,42 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::users::unlock_user::UnlockUser;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct UnlockUserCmd {
+    unlock_user: UnlockUser,
+}
+
+impl UnlockUserCmd {
+    pub fn new(user_id: Identifier) -> Self {
+        Self {
+            unlock_user: UnlockUser { user_id },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UnlockUserCmd {
+    fn explain(&self) -> String {
+        format!("unlock user with ID: {}", self.unlock_user.user_id)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .unlock_user(&self.unlock_user)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem unlocking user with ID: {}",
+                    self.unlock_user.user_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO, "User with ID: {} unlocked", self.unlock_user.user_id);
+
+        Ok(())
+    }
+}
@@ -1,36 +1,54 @@
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
+use crate::consumer_groups::get_consumer_group_assignment::GetConsumerGroupAssignment;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use crate::consumer_groups::reset_consumer_group_offsets::ResetConsumerGroupOffsets;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
+use crate::messages::fetch_replica_messages::FetchReplicaMessages;
 use crate::messages::poll_messages::PollMessages;
+use crate::messages::reject_message::RejectMessage;
 use crate::messages::send_messages::SendMessages;
+use crate::models::audit_log::AuditLogEntry;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::consumer_group::{
+    ConsumerGroup, ConsumerGroupAssignment, ConsumerGroupDetails, ConsumerGroupOffsetReset,
+};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::PolledMessages;
+use crate::models::partition_offsets::{PartitionOffsets, TimestampOffset};
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::role_info::{RoleInfo, RoleInfoDetails};
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use crate::partitions::get_partition_offsets::GetPartitionOffsets;
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::roles::create_role::CreateRole;
+use crate::roles::delete_role::DeleteRole;
+use crate::roles::get_role::GetRole;
+use crate::roles::get_roles::GetRoles;
+use crate::roles::update_role::UpdateRole;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
 use crate::streams::update_stream::UpdateStream;
+use crate::system::get_audit_log::GetAuditLog;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
@@ -42,6 +60,7 @@ use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
 use crate::topics::update_topic::UpdateTopic;
+use crate::users::assign_roles::AssignRoles;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
@@ -49,6 +68,7 @@ use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
 use crate::users::update_user::UpdateUser;
 use async_trait::async_trait;
@@ -61,6 +81,7 @@ use std::fmt::Debug;
 pub trait Client:
     SystemClient
     + UserClient
+    + RoleClient
     + PersonalAccessTokenClient
     + StreamClient
     + TopicClient
@@ -99,6 +120,10 @@ pub trait SystemClient {
     ///
     /// Authentication is required, and the permission to read the server info.
     async fn get_clients(&self, command: &GetClients) -> Result<Vec<ClientInfo>, Error>;
+    /// Get the latest entries of the audit log, recording the administrative and security-relevant actions such as the changes of streams, users and permissions or the failed logins.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn get_audit_log(&self, command: &GetAuditLog) -> Result<Vec<AuditLogEntry>, Error>;
     /// Ping the server to check if it's alive.
     async fn ping(&self, command: &Ping) -> Result<(), Error>;
 }
@@ -130,6 +155,14 @@ pub trait UserClient {
     ///
     /// Authentication is required, and the permission to manage the users.
     async fn update_permissions(&self, command: &UpdatePermissions) -> Result<(), Error>;
+    /// Assign the roles to a user by unique ID or username, replacing the ones assigned before.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn assign_roles(&self, command: &AssignRoles) -> Result<(), Error>;
+    /// Unlock a user by unique ID or username, temporarily locked due to too many failed login attempts.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn unlock_user(&self, command: &UnlockUser) -> Result<(), Error>;
     /// Change the password of a user by unique ID or username.
     ///
     /// Authentication is required, and the permission to manage the users, unless the provided user ID is the same as the authenticated user.
@@ -140,6 +173,31 @@ pub trait UserClient {
     async fn logout_user(&self, command: &LogoutUser) -> Result<(), Error>;
 }
 
+/// This trait defines the methods to interact with the role module.
+#[async_trait]
+pub trait RoleClient {
+    /// Get the info about a specific role by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to read the users.
+    async fn get_role(&self, command: &GetRole) -> Result<RoleInfoDetails, Error>;
+    /// Get the info about all the roles.
+    ///
+    /// Authentication is required, and the permission to read the users.
+    async fn get_roles(&self, command: &GetRoles) -> Result<Vec<RoleInfo>, Error>;
+    /// Create a new role.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn create_role(&self, command: &CreateRole) -> Result<(), Error>;
+    /// Delete a role by unique ID or name, the role is also removed from all the users having it.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn delete_role(&self, command: &DeleteRole) -> Result<(), Error>;
+    /// Update the name and permissions of a role by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn update_role(&self, command: &UpdateRole) -> Result<(), Error>;
+}
+
 /// This trait defines the methods to interact with the personal access token module.
 #[async_trait]
 pub trait PersonalAccessTokenClient {
@@ -238,6 +296,22 @@ pub trait PartitionClient {
     ///
     /// Authentication is required, and the permission to manage the partitions.
     async fn delete_partitions(&self, command: &DeletePartitions) -> Result<(), Error>;
+    /// Get the earliest and latest offsets of each partition for a topic by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn get_partition_offsets(
+        &self,
+        command: &GetPartitionOffsets,
+    ) -> Result<Vec<PartitionOffsets>, Error>;
+    /// Get the offset of the first message appended at or after the given timestamp in each partition for a topic by unique ID or name.
+    ///
+    /// The offset is resolved using the time indexes, so no messages are returned. The partition without such message has no offset.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn get_offsets_for_timestamp(
+        &self,
+        command: &GetOffsetsForTimestamp,
+    ) -> Result<Vec<TimestampOffset>, Error>;
 }
 
 /// This trait defines the methods to interact with the messaging module.
@@ -251,6 +325,19 @@ pub trait MessageClient {
     ///
     /// Authentication is required, and the permission to send the messages.
     async fn send_messages(&self, command: &mut SendMessages) -> Result<(), Error>;
+    /// Reject the message which cannot be processed, by marking it as poisoned in the given stream and topic by unique IDs or names.
+    /// If the dead-letter queue is enabled on the server, the message is also copied to the configured dead-letter topic.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn reject_message(&self, command: &RejectMessage) -> Result<(), Error>;
+    /// Fetch the messages stored by the leader node from the given partition of the stream and topic by unique IDs or names, used by the follower nodes to replicate the partition.
+    /// The messages are returned as they are stored by the leader, without decryption or decompression.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn fetch_replica_messages(
+        &self,
+        command: &FetchReplicaMessages,
+    ) -> Result<PolledMessages, Error>;
 }
 
 /// This trait defines the methods to interact with the consumer offset module.
@@ -302,4 +389,25 @@ pub trait ConsumerGroupClient {
     ///
     /// Authentication is required, and the permission to read the streams or topics.
     async fn leave_consumer_group(&self, command: &LeaveConsumerGroup) -> Result<(), Error>;
+    /// Get the partitions assigned to the currently authenticated client in the consumer group by unique ID or name for the given stream and topic by unique IDs or names.
+    /// It also acknowledges the current generation of the assignment, which releases the partitions revoked from the client to the other members.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    async fn get_consumer_group_assignment(
+        &self,
+        command: &GetConsumerGroupAssignment,
+    ) -> Result<ConsumerGroupAssignment, Error>;
+    /// Send a heartbeat to the consumer group by unique ID or name for the given stream and topic by unique IDs or names, to keep the membership of the currently authenticated client alive.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    async fn heartbeat_consumer_group(&self, command: &HeartbeatConsumerGroup)
+        -> Result<(), Error>;
+    /// Reset the offsets of the consumer group by unique ID or name for all or selected partitions of the given stream and topic by unique IDs or names.
+    /// Returns the offsets of the next messages to be polled by the consumer group before and after the reset. In the dry run mode, the offsets are not stored.
+    ///
+    /// Authentication is required, and the permission to manage the streams or topics.
+    async fn reset_consumer_group_offsets(
+        &self,
+        command: &ResetConsumerGroupOffsets,
+    ) -> Result<Vec<ConsumerGroupOffsetReset>, Error>;
 }
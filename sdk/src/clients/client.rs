@@ -1,27 +1,41 @@
+use crate::bytes_serializable::BytesSerializable;
 use crate::client::{
     Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient,
-    PersonalAccessTokenClient, StreamClient, SystemClient, TopicClient, UserClient,
+    PersonalAccessTokenClient, RoleClient, StreamClient, SystemClient, TopicClient, UserClient,
 };
-use crate::consumer::Consumer;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::compression::compressor::MAX_DECOMPRESSED_SIZE;
+use crate::consumer::{Consumer, ConsumerKind};
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
+use crate::consumer_groups::get_consumer_group_assignment::GetConsumerGroupAssignment;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use crate::consumer_groups::reset_consumer_group_offsets::ResetConsumerGroupOffsets;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
 use crate::identifier::Identifier;
 use crate::message_handler::MessageHandler;
+use crate::messages::fetch_replica_messages::FetchReplicaMessages;
 use crate::messages::poll_messages::{PollMessages, PollingKind};
-use crate::messages::send_messages::{Partitioning, PartitioningKind, SendMessages};
+use crate::messages::reject_message::RejectMessage;
+use crate::messages::send_messages::{AckLevel, Partitioning, PartitioningKind, SendMessages};
+use crate::models::audit_log::AuditLogEntry;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::consumer_group::{
+    ConsumerGroup, ConsumerGroupAssignment, ConsumerGroupDetails, ConsumerGroupOffsetReset,
+};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::header::{HeaderKey, HeaderValue};
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::{Message, PolledMessages};
+use crate::models::partition_offsets::{PartitionOffsets, TimestampOffset};
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::role_info::{RoleInfo, RoleInfoDetails};
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
@@ -29,16 +43,24 @@ use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::partitioner::Partitioner;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use crate::partitions::get_partition_offsets::GetPartitionOffsets;
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::roles::create_role::CreateRole;
+use crate::roles::delete_role::DeleteRole;
+use crate::roles::get_role::GetRole;
+use crate::roles::get_roles::GetRoles;
+use crate::roles::update_role::UpdateRole;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
 use crate::streams::update_stream::UpdateStream;
+use crate::system::get_audit_log::GetAuditLog;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
@@ -51,6 +73,7 @@ use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
 use crate::topics::update_topic::UpdateTopic;
+use crate::users::assign_roles::AssignRoles;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
@@ -58,36 +81,44 @@ use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
 use crate::users::update_user::UpdateUser;
+use crate::utils::checksum;
 use crate::utils::crypto::Encryptor;
 use async_dropper::AsyncDrop;
 use async_trait::async_trait;
 use bytes::Bytes;
 use flume::{Receiver, Sender};
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Debug;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::{Mutex, RwLock};
 use tokio::task::JoinHandle;
 use tokio::time::sleep;
 use tracing::{error, info, warn};
 
+/// The reserved header storing the code of the algorithm used to compress the batch of messages on the client side.
+/// It's set by the producer on the single message carrying the compressed `SendMessages` batch,
+/// and used by the consumer to restore the original messages, so it's never exposed to the message handlers.
+pub const COMPRESSION_ALGORITHM_HEADER: &str = "iggy-client-compression-algorithm";
+
 /// The main client struct which implements all the `Client` traits and wraps the underlying low-level client for the specific transport.
-/// It also provides additional functionality (outside of the shared trait) like sending messages in background, partitioning, client-side encryption or message handling via channels.
+/// It also provides additional functionality (outside of the shared trait) like sending messages in background, partitioning, client-side compression and encryption or message handling via channels.
 #[derive(Debug)]
 pub struct IggyClient {
     client: Arc<RwLock<Box<dyn Client>>>,
     config: Option<IggyClientConfig>,
     send_messages_batch: Option<Arc<Mutex<SendMessagesBatch>>>,
     partitioner: Option<Box<dyn Partitioner>>,
-    encryptor: Option<Box<dyn Encryptor>>,
+    encryptor: Option<Arc<Box<dyn Encryptor>>>,
+    compression_algorithm: Option<CompressionAlgorithm>,
     message_handler: Option<Arc<Box<dyn MessageHandler>>>,
     message_channel_sender: Option<Arc<Sender<Message>>>,
 }
 
-/// The builder for the `IggyClient` instance, which allows to configure and provide custom implementations for the partitioner, encryptor or message handler.
+/// The builder for the `IggyClient` instance, which allows to configure and provide custom implementations for the partitioner, encryptor, compression algorithm or message handler.
 #[derive(Debug)]
 pub struct IggyClientBuilder {
     client: IggyClient,
@@ -116,7 +147,22 @@ impl IggyClientBuilder {
 
     /// Use the the custom encryptor implementation.
     pub fn with_encryptor(mut self, encryptor: Box<dyn Encryptor>) -> Self {
-        self.client.encryptor = Some(encryptor);
+        self.client.encryptor = Some(Arc::new(encryptor));
+        self
+    }
+
+    /// Use the compression algorithm for the batches of messages sent by the client.
+    /// Each batch is compressed as a whole and stored as a single message, as the batch compresses much better than the separate payloads.
+    /// The trade-off is that the restored messages keep their IDs and headers, but share the offset (and timestamp) of the stored message,
+    /// so the offset can't address a single message of the batch, and their IDs are not deduplicated by the server.
+    /// The batches exceeding the max size which can be decompressed by the consumer are split into several ones.
+    /// The polled messages are decompressed based on the header set by the producer, regardless of this setting,
+    /// thus the consumers must use `IggyClient` too, as the low-level clients return the compressed batch as it is.
+    pub fn with_compression_algorithm(
+        mut self,
+        compression_algorithm: CompressionAlgorithm,
+    ) -> Self {
+        self.client.compression_algorithm = Some(compression_algorithm);
         self
     }
 
@@ -164,6 +210,8 @@ pub struct PollMessagesConfig {
     pub interval: u64,
     /// The offset storing strategy.
     pub store_offset_kind: StoreOffsetKind,
+    /// The interval in milliseconds between sending the heartbeats to the consumer group, used only when polling the messages as a consumer group member. Heartbeats are disabled if set to 0, in which case the member evicted due to the session timeout joins the group again on the next poll.
+    pub heartbeat_interval: u64,
 }
 
 /// The consumer offset storing strategy on the server.
@@ -194,6 +242,7 @@ impl Default for PollMessagesConfig {
         PollMessagesConfig {
             interval: 100,
             store_offset_kind: StoreOffsetKind::WhenMessagesAreProcessed,
+            heartbeat_interval: 3000,
         }
     }
 }
@@ -218,6 +267,7 @@ impl IggyClient {
             send_messages_batch: None,
             partitioner: None,
             encryptor: None,
+            compression_algorithm: None,
             message_handler: None,
             message_channel_sender: None,
         }
@@ -260,7 +310,8 @@ impl IggyClient {
             message_handler: message_handler.map(Arc::new),
             message_channel_sender: None,
             partitioner,
-            encryptor,
+            encryptor: encryptor.map(Arc::new),
+            compression_algorithm: None,
         }
     }
 
@@ -285,8 +336,11 @@ impl IggyClient {
         let mut interval = Duration::from_millis(100);
         let message_handler = self.message_handler.clone();
         let message_channel_sender = self.message_channel_sender.clone();
+        let encryptor = self.encryptor.clone();
         let mut store_offset_after_processing_each_message = false;
         let mut store_offset_when_messages_are_processed = false;
+        let mut heartbeat_interval =
+            Duration::from_millis(PollMessagesConfig::default().heartbeat_interval);
 
         let config = match config_override {
             Some(config) => Some(config),
@@ -296,6 +350,7 @@ impl IggyClient {
             if config.interval > 0 {
                 interval = Duration::from_millis(config.interval);
             }
+            heartbeat_interval = Duration::from_millis(config.heartbeat_interval);
             match config.store_offset_kind {
                 StoreOffsetKind::Never => {
                     poll_messages.auto_commit = false;
@@ -314,47 +369,90 @@ impl IggyClient {
             }
         }
 
+        if poll_messages.consumer.kind != ConsumerKind::ConsumerGroup {
+            heartbeat_interval = Duration::ZERO;
+        }
+
         tokio::spawn(async move {
+            let mut last_heartbeat_at = Instant::now();
             loop {
                 sleep(interval).await;
                 let client = client.read().await;
+                if !heartbeat_interval.is_zero()
+                    && last_heartbeat_at.elapsed() >= heartbeat_interval
+                {
+                    Self::send_heartbeat(client.as_ref(), &poll_messages).await;
+                    last_heartbeat_at = Instant::now();
+                }
+
+                // The consumer group member polls and stores the offsets using the generation of its current assignment,
+                // so that it's fenced by the server once the group is rebalanced, until it learns the new assignment.
+                if poll_messages.consumer.kind == ConsumerKind::ConsumerGroup
+                    && poll_messages.generation.is_none()
+                {
+                    poll_messages.generation =
+                        Self::get_generation(client.as_ref(), &poll_messages).await;
+                    if poll_messages.generation.is_none() {
+                        continue;
+                    }
+                }
+
                 let polled_messages = client.poll_messages(&poll_messages).await;
                 if let Err(error) = polled_messages {
+                    if matches!(error, Error::InvalidResponse(code) if code == Error::ConsumerGroupGenerationMismatch(0, 0, 0).as_code())
+                    {
+                        info!(
+                            "Consumer group with ID: {} has been rebalanced, fetching the new assignment...",
+                            poll_messages.consumer.id
+                        );
+                        poll_messages.generation = None;
+                        continue;
+                    }
+
                     error!("There was an error while polling messages: {:?}", error);
                     continue;
                 }
 
-                let messages = polled_messages.unwrap().messages;
+                let polled_messages = polled_messages.unwrap();
+                let messages = polled_messages.messages;
                 if messages.is_empty() {
                     continue;
                 }
 
-                let mut current_offset = 0;
+                let mut processed_offset = None;
                 for message in messages {
-                    current_offset = message.offset;
-                    // Send a message to the subscribed channel (if created), otherwise to the provided closure or message handler.
-                    if let Some(sender) = &message_channel_sender {
-                        if sender.send_async(message).await.is_err() {
-                            error!("Error when sending a message to the channel.");
+                    let offset = message.offset;
+                    for message in Self::decode_polled_message_or_skip(message, encryptor.as_ref())
+                    {
+                        // Send a message to the subscribed channel (if created), otherwise to the provided closure or message handler.
+                        if let Some(sender) = &message_channel_sender {
+                            if sender.send_async(message).await.is_err() {
+                                error!("Error when sending a message to the channel.");
+                            }
+                        } else if let Some(on_message) = &on_message {
+                            on_message(message);
+                        } else if let Some(message_handler) = &message_handler {
+                            message_handler.handle(message);
+                        } else {
+                            warn!("Received a message with ID: {} at offset: {} which won't be processed. Consider providing the custom `MessageHandler` trait implementation or `on_message` closure.", message.id, message.offset);
                         }
-                    } else if let Some(on_message) = &on_message {
-                        on_message(message);
-                    } else if let Some(message_handler) = &message_handler {
-                        message_handler.handle(message);
-                    } else {
-                        warn!("Received a message with ID: {} at offset: {} which won't be processed. Consider providing the custom `MessageHandler` trait implementation or `on_message` closure.", message.id, message.offset);
                     }
+                    processed_offset = Some(offset);
                     if store_offset_after_processing_each_message {
-                        Self::store_offset(client.as_ref(), &poll_messages, current_offset).await;
+                        Self::store_offset(client.as_ref(), &poll_messages, offset).await;
                     }
                 }
 
+                let Some(processed_offset) = processed_offset else {
+                    continue;
+                };
+
                 if store_offset_when_messages_are_processed {
-                    Self::store_offset(client.as_ref(), &poll_messages, current_offset).await;
+                    Self::store_offset(client.as_ref(), &poll_messages, processed_offset).await;
                 }
 
                 if poll_messages.strategy.kind == PollingKind::Offset {
-                    poll_messages.strategy.value = current_offset + 1;
+                    poll_messages.strategy.value = processed_offset + 1;
                 }
             }
         })
@@ -376,6 +474,182 @@ impl IggyClient {
         self.send_messages(command).await
     }
 
+    fn encode_messages(
+        messages: &mut Vec<crate::messages::send_messages::Message>,
+        compression_algorithm: Option<CompressionAlgorithm>,
+        encryptor: Option<&Arc<Box<dyn Encryptor>>>,
+    ) -> Result<(), Error> {
+        if let Some(compression_algorithm) = compression_algorithm {
+            Self::compress_messages(messages, compression_algorithm)?;
+        }
+        if let Some(encryptor) = encryptor {
+            for message in messages {
+                message.payload = Bytes::from(encryptor.encrypt(&message.payload)?);
+                message.length = message.payload.len() as u32;
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces the batch of messages with the single message, whose payload is the compressed batch of all the serialized messages.
+    /// The batch is split, so that none of the serialized batches exceeds the max size which can be decompressed by the consumer.
+    fn compress_messages(
+        messages: &mut Vec<crate::messages::send_messages::Message>,
+        compression_algorithm: CompressionAlgorithm,
+    ) -> Result<(), Error> {
+        if compression_algorithm == CompressionAlgorithm::None || messages.is_empty() {
+            return Ok(());
+        }
+
+        let mut batches: Vec<Vec<crate::messages::send_messages::Message>> = Vec::new();
+        let mut batch_size = 0;
+        for message in messages.drain(..) {
+            let size = message.get_size_bytes() as usize;
+            match batches.last_mut() {
+                Some(batch) if batch_size + size <= MAX_DECOMPRESSED_SIZE => {
+                    batch_size += size;
+                    batch.push(message);
+                }
+                _ => {
+                    batch_size = size;
+                    batches.push(vec![message]);
+                }
+            }
+        }
+
+        let header_key = HeaderKey::new(COMPRESSION_ALGORITHM_HEADER)?;
+        let header_value = HeaderValue::from_uint8(compression_algorithm.as_code())?;
+        for batch in batches {
+            let size = batch
+                .iter()
+                .map(|message| message.get_size_bytes())
+                .sum::<u32>();
+            if size as usize > MAX_DECOMPRESSED_SIZE {
+                messages.extend(batch);
+                continue;
+            }
+
+            let mut bytes = Vec::with_capacity(size as usize);
+            for message in &batch {
+                bytes.extend(message.as_bytes());
+            }
+
+            let payload = Bytes::from(compression_algorithm.compress(&bytes)?);
+            let message = crate::messages::send_messages::Message {
+                id: 0,
+                length: payload.len() as u32,
+                payload,
+                headers: Some(HashMap::from([(header_key.clone(), header_value.clone())])),
+            };
+            // Keep the original messages if compression doesn't pay off, e.g. for already compressed data.
+            if message.get_size_bytes() >= size {
+                messages.extend(batch);
+                continue;
+            }
+
+            messages.push(message);
+        }
+        Ok(())
+    }
+
+    // The decoding might fail only for this consumer (e.g. due to the different encryption key),
+    // so the message is skipped rather than rejected, which would affect all the other consumers.
+    fn decode_polled_message_or_skip(
+        message: Message,
+        encryptor: Option<&Arc<Box<dyn Encryptor>>>,
+    ) -> Vec<Message> {
+        let offset = message.offset;
+        let id = message.id;
+        match Self::decode_polled_message(message, encryptor) {
+            Ok(messages) => messages,
+            Err(error) => {
+                error!("There was an error while decoding a message with ID: {id} at offset: {offset}, it will be skipped: {error:?}");
+                Vec::new()
+            }
+        }
+    }
+
+    fn decode_polled_message(
+        mut message: Message,
+        encryptor: Option<&Arc<Box<dyn Encryptor>>>,
+    ) -> Result<Vec<Message>, Error> {
+        if let Some(encryptor) = encryptor {
+            message.payload = Bytes::from(encryptor.decrypt(&message.payload)?);
+            message.length = message.payload.len() as u32;
+        }
+        Self::decompress_messages(message)
+    }
+
+    /// Restores the batch of messages compressed by the producer, all of them share the offset of the compressed message.
+    fn decompress_messages(message: Message) -> Result<Vec<Message>, Error> {
+        let header_key = HeaderKey::new(COMPRESSION_ALGORITHM_HEADER)?;
+        let Some(value) = message
+            .headers
+            .as_ref()
+            .and_then(|headers| headers.get(&header_key))
+        else {
+            return Ok(vec![message]);
+        };
+
+        let compression_algorithm = CompressionAlgorithm::from_code(value.as_uint8()?)?;
+        let bytes = compression_algorithm.decompress(&message.payload)?;
+        let mut messages = Vec::new();
+        let mut position = 0;
+        while position < bytes.len() {
+            let (batched_message, size) = Self::read_batched_message(&bytes[position..])?;
+            position += size;
+            messages.push(Message {
+                offset: message.offset,
+                state: message.state,
+                timestamp: message.timestamp,
+                id: batched_message.id,
+                checksum: checksum::calculate(&batched_message.payload),
+                headers: batched_message.headers,
+                length: batched_message.length,
+                payload: batched_message.payload,
+            });
+        }
+        Ok(messages)
+    }
+
+    // The batch is decompressed from the untrusted payload, so the bounds are checked before reading each field.
+    fn read_batched_message(
+        bytes: &[u8],
+    ) -> Result<(crate::messages::send_messages::Message, usize), Error> {
+        let read_u32 = |position: usize| {
+            bytes
+                .get(position..position + 4)
+                .map(|value| u32::from_le_bytes([value[0], value[1], value[2], value[3]]) as usize)
+                .ok_or(Error::InvalidMessagePayloadLength)
+        };
+        let id = bytes
+            .get(..16)
+            .and_then(|id| id.try_into().ok())
+            .map(u128::from_le_bytes)
+            .ok_or(Error::InvalidMessagePayloadLength)?;
+        let headers_length = read_u32(16)?;
+        let headers = match headers_length {
+            0 => None,
+            _ => Some(HashMap::from_bytes(
+                bytes
+                    .get(20..20 + headers_length)
+                    .ok_or(Error::InvalidMessagePayloadLength)?,
+            )?),
+        };
+        let position = 20 + headers_length;
+        let length = read_u32(position)?;
+        let payload = bytes
+            .get(position + 4..position + 4 + length)
+            .ok_or(Error::InvalidMessagePayloadLength)?;
+        let message = crate::messages::send_messages::Message {
+            id,
+            length: length as u32,
+            payload: Bytes::copy_from_slice(payload),
+            headers,
+        };
+        Ok((message, position + 4 + length))
+    }
+
     async fn store_offset(client: &dyn Client, poll_messages: &PollMessages, offset: u64) {
         let result = client
             .store_consumer_offset(&StoreConsumerOffset {
@@ -384,6 +658,7 @@ impl IggyClient {
                 topic_id: Identifier::from_identifier(&poll_messages.topic_id),
                 partition_id: poll_messages.partition_id,
                 offset,
+                generation: poll_messages.generation,
             })
             .await;
         if let Err(error) = result {
@@ -391,6 +666,82 @@ impl IggyClient {
         }
     }
 
+    /// Returns the generation of the current assignment of the member.
+    /// The member evicted from the consumer group (e.g. not sending the heartbeats) joins it again, so it's not stalled.
+    async fn get_generation(client: &dyn Client, poll_messages: &PollMessages) -> Option<u32> {
+        let get_assignment = GetConsumerGroupAssignment {
+            stream_id: Identifier::from_identifier(&poll_messages.stream_id),
+            topic_id: Identifier::from_identifier(&poll_messages.topic_id),
+            consumer_group_id: Identifier::from_identifier(&poll_messages.consumer.id),
+        };
+        let mut result = client.get_consumer_group_assignment(&get_assignment).await;
+        if let Err(error) = &result {
+            if Self::join_consumer_group_if_evicted(client, poll_messages, error).await {
+                result = client.get_consumer_group_assignment(&get_assignment).await;
+            }
+        }
+
+        match result {
+            Ok(assignment) => Some(assignment.generation),
+            Err(error) => {
+                error!(
+                    "There was an error while getting consumer group assignment: {:?}",
+                    error
+                );
+                None
+            }
+        }
+    }
+
+    async fn send_heartbeat(client: &dyn Client, poll_messages: &PollMessages) {
+        let result = client
+            .heartbeat_consumer_group(&HeartbeatConsumerGroup {
+                stream_id: Identifier::from_identifier(&poll_messages.stream_id),
+                topic_id: Identifier::from_identifier(&poll_messages.topic_id),
+                consumer_group_id: Identifier::from_identifier(&poll_messages.consumer.id),
+            })
+            .await;
+        let error = match result {
+            Ok(_) => return,
+            Err(error) => error,
+        };
+
+        if !Self::join_consumer_group_if_evicted(client, poll_messages, &error).await {
+            error!("There was an error while sending heartbeat: {:?}", error);
+        }
+    }
+
+    /// Joins the consumer group again if the member was evicted from it due to the session timeout, returns true if so.
+    async fn join_consumer_group_if_evicted(
+        client: &dyn Client,
+        poll_messages: &PollMessages,
+        error: &Error,
+    ) -> bool {
+        if !matches!(error, Error::InvalidResponse(code) if *code == Error::ConsumerGroupMemberNotFound(0, 0, 0).as_code())
+        {
+            return false;
+        }
+
+        warn!(
+            "Consumer group member has been removed from the group with ID: {}, joining it again...",
+            poll_messages.consumer.id
+        );
+        let result = client
+            .join_consumer_group(&JoinConsumerGroup {
+                stream_id: Identifier::from_identifier(&poll_messages.stream_id),
+                topic_id: Identifier::from_identifier(&poll_messages.topic_id),
+                consumer_group_id: Identifier::from_identifier(&poll_messages.consumer.id),
+            })
+            .await;
+        if let Err(error) = result {
+            error!(
+                "There was an error while joining consumer group: {:?}",
+                error
+            );
+        }
+        true
+    }
+
     fn send_messages_in_background(
         interval: u64,
         max_messages: u32,
@@ -411,6 +762,7 @@ impl IggyClient {
                 let mut stream_id = Identifier::numeric(1).unwrap();
                 let mut topic_id = Identifier::numeric(1).unwrap();
                 let mut key = Partitioning::partition_id(1);
+                let mut ack = AckLevel::default();
                 let mut batch_messages = true;
 
                 for send_messages in &send_messages_batch.commands {
@@ -423,14 +775,16 @@ impl IggyClient {
                         stream_id = Identifier::from_identifier(&send_messages.stream_id);
                         topic_id = Identifier::from_identifier(&send_messages.topic_id);
                         key.value = send_messages.partitioning.value.clone();
+                        ack = send_messages.ack;
                         initialized = true;
                     }
 
-                    // Batching the messages is only possible for the same stream, topic and partition.
+                    // Batching the messages is only possible for the same stream, topic, partition and ack level.
                     if send_messages.stream_id != stream_id
                         || send_messages.topic_id != topic_id
                         || send_messages.partitioning.kind != PartitioningKind::PartitionId
                         || send_messages.partitioning.value != key.value
+                        || send_messages.ack != ack
                     {
                         batch_messages = false;
                         break;
@@ -470,6 +824,7 @@ impl IggyClient {
                             length: 4,
                             value: key.value.clone(),
                         },
+                        ack,
                         messages,
                     };
 
@@ -518,6 +873,14 @@ impl UserClient for IggyClient {
         self.client.read().await.update_permissions(command).await
     }
 
+    async fn assign_roles(&self, command: &AssignRoles) -> Result<(), Error> {
+        self.client.read().await.assign_roles(command).await
+    }
+
+    async fn unlock_user(&self, command: &UnlockUser) -> Result<(), Error> {
+        self.client.read().await.unlock_user(command).await
+    }
+
     async fn change_password(&self, command: &ChangePassword) -> Result<(), Error> {
         self.client.read().await.change_password(command).await
     }
@@ -531,6 +894,29 @@ impl UserClient for IggyClient {
     }
 }
 
+#[async_trait]
+impl RoleClient for IggyClient {
+    async fn get_role(&self, command: &GetRole) -> Result<RoleInfoDetails, Error> {
+        self.client.read().await.get_role(command).await
+    }
+
+    async fn get_roles(&self, command: &GetRoles) -> Result<Vec<RoleInfo>, Error> {
+        self.client.read().await.get_roles(command).await
+    }
+
+    async fn create_role(&self, command: &CreateRole) -> Result<(), Error> {
+        self.client.read().await.create_role(command).await
+    }
+
+    async fn delete_role(&self, command: &DeleteRole) -> Result<(), Error> {
+        self.client.read().await.delete_role(command).await
+    }
+
+    async fn update_role(&self, command: &UpdateRole) -> Result<(), Error> {
+        self.client.read().await.update_role(command).await
+    }
+}
+
 #[async_trait]
 impl PersonalAccessTokenClient for IggyClient {
     async fn get_personal_access_tokens(
@@ -607,6 +993,10 @@ impl SystemClient for IggyClient {
         self.client.read().await.get_clients(command).await
     }
 
+    async fn get_audit_log(&self, command: &GetAuditLog) -> Result<Vec<AuditLogEntry>, Error> {
+        self.client.read().await.get_audit_log(command).await
+    }
+
     async fn ping(&self, command: &Ping) -> Result<(), Error> {
         self.client.read().await.ping(command).await
     }
@@ -675,18 +1065,42 @@ impl PartitionClient for IggyClient {
     async fn delete_partitions(&self, command: &DeletePartitions) -> Result<(), Error> {
         self.client.read().await.delete_partitions(command).await
     }
+
+    async fn get_partition_offsets(
+        &self,
+        command: &GetPartitionOffsets,
+    ) -> Result<Vec<PartitionOffsets>, Error> {
+        self.client
+            .read()
+            .await
+            .get_partition_offsets(command)
+            .await
+    }
+
+    async fn get_offsets_for_timestamp(
+        &self,
+        command: &GetOffsetsForTimestamp,
+    ) -> Result<Vec<TimestampOffset>, Error> {
+        self.client
+            .read()
+            .await
+            .get_offsets_for_timestamp(command)
+            .await
+    }
 }
 
 #[async_trait]
 impl MessageClient for IggyClient {
     async fn poll_messages(&self, command: &PollMessages) -> Result<PolledMessages, Error> {
         let mut polled_messages = self.client.read().await.poll_messages(command).await?;
-        if let Some(ref encryptor) = self.encryptor {
-            for message in &mut polled_messages.messages {
-                let payload = encryptor.decrypt(&message.payload)?;
-                message.payload = Bytes::from(payload);
-            }
+        let mut messages = Vec::with_capacity(polled_messages.messages.len());
+        for message in polled_messages.messages {
+            messages.extend(Self::decode_polled_message_or_skip(
+                message,
+                self.encryptor.as_ref(),
+            ));
         }
+        polled_messages.messages = messages;
         Ok(polled_messages)
     }
 
@@ -705,12 +1119,11 @@ impl MessageClient for IggyClient {
             command.partitioning = Partitioning::partition_id(partition_id);
         }
 
-        if let Some(encryptor) = &self.encryptor {
-            for message in &mut command.messages {
-                message.payload = Bytes::from(encryptor.encrypt(&message.payload)?);
-                message.length = message.payload.len() as u32;
-            }
-        }
+        Self::encode_messages(
+            &mut command.messages,
+            self.compression_algorithm,
+            self.encryptor.as_ref(),
+        )?;
 
         let send_messages_now = self.send_messages_batch.is_none()
             || match &self.config {
@@ -736,6 +1149,7 @@ impl MessageClient for IggyClient {
             stream_id: Identifier::from_identifier(&command.stream_id),
             topic_id: Identifier::from_identifier(&command.topic_id),
             partitioning: Partitioning::from_partitioning(&command.partitioning),
+            ack: command.ack,
             messages,
         };
 
@@ -743,6 +1157,21 @@ impl MessageClient for IggyClient {
         batch.commands.push_back(send_messages);
         Ok(())
     }
+
+    async fn reject_message(&self, command: &RejectMessage) -> Result<(), Error> {
+        self.client.read().await.reject_message(command).await
+    }
+
+    async fn fetch_replica_messages(
+        &self,
+        command: &FetchReplicaMessages,
+    ) -> Result<PolledMessages, Error> {
+        self.client
+            .read()
+            .await
+            .fetch_replica_messages(command)
+            .await
+    }
 }
 
 #[async_trait]
@@ -802,6 +1231,39 @@ impl ConsumerGroupClient for IggyClient {
     async fn leave_consumer_group(&self, command: &LeaveConsumerGroup) -> Result<(), Error> {
         self.client.read().await.leave_consumer_group(command).await
     }
+
+    async fn get_consumer_group_assignment(
+        &self,
+        command: &GetConsumerGroupAssignment,
+    ) -> Result<ConsumerGroupAssignment, Error> {
+        self.client
+            .read()
+            .await
+            .get_consumer_group_assignment(command)
+            .await
+    }
+
+    async fn heartbeat_consumer_group(
+        &self,
+        command: &HeartbeatConsumerGroup,
+    ) -> Result<(), Error> {
+        self.client
+            .read()
+            .await
+            .heartbeat_consumer_group(command)
+            .await
+    }
+
+    async fn reset_consumer_group_offsets(
+        &self,
+        command: &ResetConsumerGroupOffsets,
+    ) -> Result<Vec<ConsumerGroupOffsetReset>, Error> {
+        self.client
+            .read()
+            .await
+            .reset_consumer_group_offsets(command)
+            .await
+    }
 }
 
 #[async_trait]
@@ -810,3 +1272,182 @@ impl AsyncDrop for IggyClient {
         let _ = self.client.read().await.logout_user(&LogoutUser {}).await;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::send_messages;
+    use crate::models::messages::MessageState;
+    use crate::utils::crypto::Aes256GcmEncryptor;
+    use std::str::FromStr;
+
+    fn payload() -> String {
+        "{\"entity_id\": 1, \"name\": \"iggy\"}".repeat(10)
+    }
+
+    #[test]
+    fn messages_should_be_compressed_and_decompressed_using_each_algorithm() {
+        for compression_algorithm in [
+            CompressionAlgorithm::Gzip,
+            CompressionAlgorithm::Lz4,
+            CompressionAlgorithm::Zstd,
+            CompressionAlgorithm::Snappy,
+        ] {
+            let mut messages = create_messages(10);
+            IggyClient::encode_messages(&mut messages, Some(compression_algorithm), None).unwrap();
+            assert_eq!(messages.len(), 1);
+            let message = messages.remove(0);
+            assert!(message.payload.len() < payload().len() * 10);
+            assert_eq!(message.length, message.payload.len() as u32);
+
+            let polled_messages =
+                IggyClient::decode_polled_message(to_polled_message(message, 5), None).unwrap();
+            assert_eq!(polled_messages.len(), 10);
+            for (index, polled_message) in polled_messages.into_iter().enumerate() {
+                assert_eq!(polled_message.id, index as u128 + 1);
+                assert_eq!(polled_message.offset, 5);
+                assert_eq!(polled_message.payload, Bytes::from(payload()));
+                assert_eq!(polled_message.length, payload().len() as u32);
+                assert!(polled_message.headers.is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn messages_should_not_be_compressed_given_incompressible_payload() {
+        let mut messages = vec![send_messages::Message::from_str("iggy").unwrap()];
+        IggyClient::encode_messages(&mut messages, Some(CompressionAlgorithm::Gzip), None).unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].payload, Bytes::from("iggy"));
+        assert!(messages[0].headers.is_none());
+    }
+
+    #[test]
+    fn messages_should_be_compressed_in_several_batches_given_batch_exceeding_max_decompressed_size(
+    ) {
+        let payload = Bytes::from(vec![1; MAX_DECOMPRESSED_SIZE / 3]);
+        let mut messages = (1..=4)
+            .map(|id| send_messages::Message {
+                id,
+                length: payload.len() as u32,
+                payload: payload.clone(),
+                headers: None,
+            })
+            .collect::<Vec<_>>();
+
+        IggyClient::encode_messages(&mut messages, Some(CompressionAlgorithm::Lz4), None).unwrap();
+        assert_eq!(messages.len(), 2);
+        let polled_messages = messages
+            .into_iter()
+            .flat_map(|message| {
+                IggyClient::decode_polled_message(to_polled_message(message, 1), None).unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(polled_messages.len(), 4);
+        for (index, polled_message) in polled_messages.into_iter().enumerate() {
+            assert_eq!(polled_message.id, index as u128 + 1);
+            assert_eq!(polled_message.payload, payload);
+        }
+    }
+
+    #[test]
+    fn messages_should_be_compressed_before_encryption_and_decompressed_after_decryption() {
+        let encryptor: Arc<Box<dyn Encryptor>> =
+            Arc::new(Box::new(Aes256GcmEncryptor::new(&[1; 32]).unwrap()));
+        let key = HeaderKey::new("key").unwrap();
+        let value = HeaderValue::from_str("value").unwrap();
+        let mut messages = create_messages(2);
+        messages[0].headers = Some(HashMap::from([(key.clone(), value.clone())]));
+
+        IggyClient::encode_messages(
+            &mut messages,
+            Some(CompressionAlgorithm::Zstd),
+            Some(&encryptor),
+        )
+        .unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].headers.as_ref().unwrap().len(), 1);
+
+        let polled_messages = IggyClient::decode_polled_message(
+            to_polled_message(messages.remove(0), 1),
+            Some(&encryptor),
+        )
+        .unwrap();
+        assert_eq!(polled_messages.len(), 2);
+        assert_eq!(polled_messages[0].payload, Bytes::from(payload()));
+        let headers = polled_messages[0].headers.as_ref().unwrap();
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers.get(&key), Some(&value));
+        assert_eq!(polled_messages[1].payload, Bytes::from(payload()));
+        assert!(polled_messages[1].headers.is_none());
+    }
+
+    #[test]
+    fn decoding_corrupted_compressed_message_should_fail() {
+        let mut messages = create_messages(1);
+        IggyClient::encode_messages(&mut messages, Some(CompressionAlgorithm::Lz4), None).unwrap();
+        let mut message = to_polled_message(messages.remove(0), 1);
+        message.payload = message.payload.slice(..message.payload.len() / 2);
+
+        assert!(IggyClient::decode_polled_message(message, None).is_err());
+    }
+
+    #[test]
+    fn corrupted_compressed_message_should_be_skipped_without_affecting_the_other_ones() {
+        let mut messages = create_messages(1);
+        IggyClient::encode_messages(&mut messages, Some(CompressionAlgorithm::Lz4), None).unwrap();
+        let mut corrupted_message = to_polled_message(messages.remove(0), 1);
+        corrupted_message.payload = corrupted_message
+            .payload
+            .slice(..corrupted_message.payload.len() / 2);
+        let message = to_polled_message(create_messages(1).remove(0), 2);
+
+        assert!(IggyClient::decode_polled_message_or_skip(corrupted_message, None).is_empty());
+        let polled_messages = IggyClient::decode_polled_message_or_skip(message, None);
+        assert_eq!(polled_messages.len(), 1);
+        assert_eq!(polled_messages[0].offset, 2);
+        assert_eq!(polled_messages[0].payload, Bytes::from(payload()));
+    }
+
+    #[test]
+    fn decoding_compressed_message_with_malformed_batch_should_fail() {
+        let mut bytes = vec![0; 16];
+        // The headers length exceeds the decompressed batch.
+        bytes.extend(1000u32.to_le_bytes());
+        let payload = Bytes::from(CompressionAlgorithm::Lz4.compress(&bytes).unwrap());
+        let message = send_messages::Message {
+            id: 1,
+            length: payload.len() as u32,
+            payload,
+            headers: Some(HashMap::from([(
+                HeaderKey::new(COMPRESSION_ALGORITHM_HEADER).unwrap(),
+                HeaderValue::from_uint8(CompressionAlgorithm::Lz4.as_code()).unwrap(),
+            )])),
+        };
+
+        assert!(IggyClient::decode_polled_message(to_polled_message(message, 1), None).is_err());
+    }
+
+    fn create_messages(count: u32) -> Vec<send_messages::Message> {
+        (1..=count)
+            .map(|id| {
+                let mut message = send_messages::Message::from_str(&payload()).unwrap();
+                message.id = id as u128;
+                message
+            })
+            .collect()
+    }
+
+    fn to_polled_message(message: send_messages::Message, offset: u64) -> Message {
+        Message {
+            offset,
+            state: MessageState::Available,
+            timestamp: 1,
+            id: message.id,
+            checksum: 1,
+            headers: message.headers,
+            length: message.length,
+            payload: message.payload,
+        }
+    }
+}
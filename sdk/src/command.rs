@@ -2,26 +2,39 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
+use crate::consumer_groups::get_consumer_group_assignment::GetConsumerGroupAssignment;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use crate::consumer_groups::reset_consumer_group_offsets::ResetConsumerGroupOffsets;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
+use crate::messages::fetch_replica_messages::FetchReplicaMessages;
 use crate::messages::poll_messages::PollMessages;
+use crate::messages::reject_message::RejectMessage;
 use crate::messages::send_messages::SendMessages;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use crate::partitions::get_partition_offsets::GetPartitionOffsets;
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::roles::create_role::CreateRole;
+use crate::roles::delete_role::DeleteRole;
+use crate::roles::get_role::GetRole;
+use crate::roles::get_roles::GetRoles;
+use crate::roles::update_role::UpdateRole;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
 use crate::streams::update_stream::UpdateStream;
+use crate::system::get_audit_log::GetAuditLog;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
@@ -33,6 +46,7 @@ use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
 use crate::topics::update_topic::UpdateTopic;
+use crate::users::assign_roles::AssignRoles;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
@@ -40,6 +54,7 @@ use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
 use crate::users::update_user::UpdateUser;
 use bytes::BufMut;
@@ -49,6 +64,8 @@ pub const PING: &str = "ping";
 pub const PING_CODE: u32 = 1;
 pub const GET_STATS: &str = "stats";
 pub const GET_STATS_CODE: u32 = 10;
+pub const GET_AUDIT_LOG: &str = "audit_log";
+pub const GET_AUDIT_LOG_CODE: u32 = 11;
 pub const GET_ME: &str = "me";
 pub const GET_ME_CODE: u32 = 20;
 pub const GET_CLIENT: &str = "client.get";
@@ -73,6 +90,10 @@ pub const LOGIN_USER: &str = "user.login";
 pub const LOGIN_USER_CODE: u32 = 38;
 pub const LOGOUT_USER: &str = "user.logout";
 pub const LOGOUT_USER_CODE: u32 = 39;
+pub const ASSIGN_ROLES: &str = "user.roles";
+pub const ASSIGN_ROLES_CODE: u32 = 40;
+pub const UNLOCK_USER: &str = "user.unlock";
+pub const UNLOCK_USER_CODE: u32 = 45;
 pub const GET_PERSONAL_ACCESS_TOKENS: &str = "personal_access_token.list";
 pub const GET_PERSONAL_ACCESS_TOKENS_CODE: u32 = 41;
 pub const CREATE_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.create";
@@ -81,6 +102,16 @@ pub const DELETE_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.delete";
 pub const DELETE_PERSONAL_ACCESS_TOKEN_CODE: u32 = 43;
 pub const LOGIN_WITH_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.login";
 pub const LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE: u32 = 44;
+pub const GET_ROLE: &str = "role.get";
+pub const GET_ROLE_CODE: u32 = 51;
+pub const GET_ROLES: &str = "role.list";
+pub const GET_ROLES_CODE: u32 = 52;
+pub const CREATE_ROLE: &str = "role.create";
+pub const CREATE_ROLE_CODE: u32 = 53;
+pub const DELETE_ROLE: &str = "role.delete";
+pub const DELETE_ROLE_CODE: u32 = 54;
+pub const UPDATE_ROLE: &str = "role.update";
+pub const UPDATE_ROLE_CODE: u32 = 55;
 pub const POLL_MESSAGES: &str = "message.poll";
 pub const POLL_MESSAGES_CODE: u32 = 100;
 pub const SEND_MESSAGES: &str = "message.send";
@@ -89,6 +120,10 @@ pub const GET_CONSUMER_OFFSET: &str = "consumer_offset.get";
 pub const GET_CONSUMER_OFFSET_CODE: u32 = 120;
 pub const STORE_CONSUMER_OFFSET: &str = "consumer_offset.store";
 pub const STORE_CONSUMER_OFFSET_CODE: u32 = 121;
+pub const REJECT_MESSAGE: &str = "message.reject";
+pub const REJECT_MESSAGE_CODE: u32 = 122;
+pub const FETCH_REPLICA_MESSAGES: &str = "message.fetch_replica";
+pub const FETCH_REPLICA_MESSAGES_CODE: u32 = 123;
 pub const GET_STREAM: &str = "stream.get";
 pub const GET_STREAM_CODE: u32 = 200;
 pub const GET_STREAMS: &str = "stream.list";
@@ -117,6 +152,10 @@ pub const CREATE_PARTITIONS: &str = "partition.create";
 pub const CREATE_PARTITIONS_CODE: u32 = 402;
 pub const DELETE_PARTITIONS: &str = "partition.delete";
 pub const DELETE_PARTITIONS_CODE: u32 = 403;
+pub const GET_PARTITION_OFFSETS: &str = "partition.offsets";
+pub const GET_PARTITION_OFFSETS_CODE: u32 = 404;
+pub const GET_OFFSETS_FOR_TIMESTAMP: &str = "partition.offsets_for_timestamp";
+pub const GET_OFFSETS_FOR_TIMESTAMP_CODE: u32 = 405;
 pub const GET_CONSUMER_GROUP: &str = "consumer_group.get";
 pub const GET_CONSUMER_GROUP_CODE: u32 = 600;
 pub const GET_CONSUMER_GROUPS: &str = "consumer_group.list";
@@ -129,11 +168,18 @@ pub const JOIN_CONSUMER_GROUP: &str = "consumer_group.join";
 pub const JOIN_CONSUMER_GROUP_CODE: u32 = 604;
 pub const LEAVE_CONSUMER_GROUP: &str = "consumer_group.leave";
 pub const LEAVE_CONSUMER_GROUP_CODE: u32 = 605;
+pub const GET_CONSUMER_GROUP_ASSIGNMENT: &str = "consumer_group.assignment";
+pub const GET_CONSUMER_GROUP_ASSIGNMENT_CODE: u32 = 606;
+pub const HEARTBEAT_CONSUMER_GROUP: &str = "consumer_group.heartbeat";
+pub const HEARTBEAT_CONSUMER_GROUP_CODE: u32 = 607;
+pub const RESET_CONSUMER_GROUP_OFFSETS: &str = "consumer_group.reset_offsets";
+pub const RESET_CONSUMER_GROUP_OFFSETS_CODE: u32 = 608;
 
 #[derive(Debug, PartialEq)]
 pub enum Command {
     Ping(Ping),
     GetStats(GetStats),
+    GetAuditLog(GetAuditLog),
     GetMe(GetMe),
     GetClient(GetClient),
     GetClients(GetClients),
@@ -146,14 +192,23 @@ pub enum Command {
     ChangePassword(ChangePassword),
     LoginUser(LoginUser),
     LogoutUser(LogoutUser),
+    AssignRoles(AssignRoles),
+    UnlockUser(UnlockUser),
     GetPersonalAccessTokens(GetPersonalAccessTokens),
     CreatePersonalAccessToken(CreatePersonalAccessToken),
     DeletePersonalAccessToken(DeletePersonalAccessToken),
     LoginWithPersonalAccessToken(LoginWithPersonalAccessToken),
+    GetRole(GetRole),
+    GetRoles(GetRoles),
+    CreateRole(CreateRole),
+    DeleteRole(DeleteRole),
+    UpdateRole(UpdateRole),
     SendMessages(SendMessages),
     PollMessages(PollMessages),
     GetConsumerOffset(GetConsumerOffset),
     StoreConsumerOffset(StoreConsumerOffset),
+    RejectMessage(RejectMessage),
+    FetchReplicaMessages(FetchReplicaMessages),
     GetStream(GetStream),
     GetStreams(GetStreams),
     CreateStream(CreateStream),
@@ -168,12 +223,17 @@ pub enum Command {
     PurgeTopic(PurgeTopic),
     CreatePartitions(CreatePartitions),
     DeletePartitions(DeletePartitions),
+    GetPartitionOffsets(GetPartitionOffsets),
+    GetOffsetsForTimestamp(GetOffsetsForTimestamp),
     GetConsumerGroup(GetConsumerGroup),
     GetConsumerGroups(GetConsumerGroups),
     CreateConsumerGroup(CreateConsumerGroup),
     DeleteConsumerGroup(DeleteConsumerGroup),
     JoinConsumerGroup(JoinConsumerGroup),
     LeaveConsumerGroup(LeaveConsumerGroup),
+    GetConsumerGroupAssignment(GetConsumerGroupAssignment),
+    HeartbeatConsumerGroup(HeartbeatConsumerGroup),
+    ResetConsumerGroupOffsets(ResetConsumerGroupOffsets),
 }
 
 /// A trait for all command payloads.
@@ -184,6 +244,7 @@ impl BytesSerializable for Command {
         match self {
             Command::Ping(payload) => as_bytes(PING_CODE, &payload.as_bytes()),
             Command::GetStats(payload) => as_bytes(GET_STATS_CODE, &payload.as_bytes()),
+            Command::GetAuditLog(payload) => as_bytes(GET_AUDIT_LOG_CODE, &payload.as_bytes()),
             Command::GetMe(payload) => as_bytes(GET_ME_CODE, &payload.as_bytes()),
             Command::GetClient(payload) => as_bytes(GET_CLIENT_CODE, &payload.as_bytes()),
             Command::GetClients(payload) => as_bytes(GET_CLIENTS_CODE, &payload.as_bytes()),
@@ -198,6 +259,8 @@ impl BytesSerializable for Command {
             Command::ChangePassword(payload) => as_bytes(CHANGE_PASSWORD_CODE, &payload.as_bytes()),
             Command::LoginUser(payload) => as_bytes(LOGIN_USER_CODE, &payload.as_bytes()),
             Command::LogoutUser(payload) => as_bytes(LOGOUT_USER_CODE, &payload.as_bytes()),
+            Command::AssignRoles(payload) => as_bytes(ASSIGN_ROLES_CODE, &payload.as_bytes()),
+            Command::UnlockUser(payload) => as_bytes(UNLOCK_USER_CODE, &payload.as_bytes()),
             Command::GetPersonalAccessTokens(payload) => {
                 as_bytes(GET_PERSONAL_ACCESS_TOKENS_CODE, &payload.as_bytes())
             }
@@ -210,11 +273,20 @@ impl BytesSerializable for Command {
             Command::LoginWithPersonalAccessToken(payload) => {
                 as_bytes(LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE, &payload.as_bytes())
             }
+            Command::GetRole(payload) => as_bytes(GET_ROLE_CODE, &payload.as_bytes()),
+            Command::GetRoles(payload) => as_bytes(GET_ROLES_CODE, &payload.as_bytes()),
+            Command::CreateRole(payload) => as_bytes(CREATE_ROLE_CODE, &payload.as_bytes()),
+            Command::DeleteRole(payload) => as_bytes(DELETE_ROLE_CODE, &payload.as_bytes()),
+            Command::UpdateRole(payload) => as_bytes(UPDATE_ROLE_CODE, &payload.as_bytes()),
             Command::SendMessages(payload) => as_bytes(SEND_MESSAGES_CODE, &payload.as_bytes()),
             Command::PollMessages(payload) => as_bytes(POLL_MESSAGES_CODE, &payload.as_bytes()),
             Command::StoreConsumerOffset(payload) => {
                 as_bytes(STORE_CONSUMER_OFFSET_CODE, &payload.as_bytes())
             }
+            Command::RejectMessage(payload) => as_bytes(REJECT_MESSAGE_CODE, &payload.as_bytes()),
+            Command::FetchReplicaMessages(payload) => {
+                as_bytes(FETCH_REPLICA_MESSAGES_CODE, &payload.as_bytes())
+            }
             Command::GetConsumerOffset(payload) => {
                 as_bytes(GET_CONSUMER_OFFSET_CODE, &payload.as_bytes())
             }
@@ -236,6 +308,12 @@ impl BytesSerializable for Command {
             Command::DeletePartitions(payload) => {
                 as_bytes(DELETE_PARTITIONS_CODE, &payload.as_bytes())
             }
+            Command::GetPartitionOffsets(payload) => {
+                as_bytes(GET_PARTITION_OFFSETS_CODE, &payload.as_bytes())
+            }
+            Command::GetOffsetsForTimestamp(payload) => {
+                as_bytes(GET_OFFSETS_FOR_TIMESTAMP_CODE, &payload.as_bytes())
+            }
             Command::GetConsumerGroup(payload) => {
                 as_bytes(GET_CONSUMER_GROUP_CODE, &payload.as_bytes())
             }
@@ -254,6 +332,15 @@ impl BytesSerializable for Command {
             Command::LeaveConsumerGroup(payload) => {
                 as_bytes(LEAVE_CONSUMER_GROUP_CODE, &payload.as_bytes())
             }
+            Command::GetConsumerGroupAssignment(payload) => {
+                as_bytes(GET_CONSUMER_GROUP_ASSIGNMENT_CODE, &payload.as_bytes())
+            }
+            Command::HeartbeatConsumerGroup(payload) => {
+                as_bytes(HEARTBEAT_CONSUMER_GROUP_CODE, &payload.as_bytes())
+            }
+            Command::ResetConsumerGroupOffsets(payload) => {
+                as_bytes(RESET_CONSUMER_GROUP_OFFSETS_CODE, &payload.as_bytes())
+            }
         }
     }
 
@@ -263,6 +350,7 @@ impl BytesSerializable for Command {
         match command {
             PING_CODE => Ok(Command::Ping(Ping::from_bytes(payload)?)),
             GET_STATS_CODE => Ok(Command::GetStats(GetStats::from_bytes(payload)?)),
+            GET_AUDIT_LOG_CODE => Ok(Command::GetAuditLog(GetAuditLog::from_bytes(payload)?)),
             GET_ME_CODE => Ok(Command::GetMe(GetMe::from_bytes(payload)?)),
             GET_CLIENT_CODE => Ok(Command::GetClient(GetClient::from_bytes(payload)?)),
             GET_CLIENTS_CODE => Ok(Command::GetClients(GetClients::from_bytes(payload)?)),
@@ -279,6 +367,8 @@ impl BytesSerializable for Command {
             )?)),
             LOGIN_USER_CODE => Ok(Command::LoginUser(LoginUser::from_bytes(payload)?)),
             LOGOUT_USER_CODE => Ok(Command::LogoutUser(LogoutUser::from_bytes(payload)?)),
+            ASSIGN_ROLES_CODE => Ok(Command::AssignRoles(AssignRoles::from_bytes(payload)?)),
+            UNLOCK_USER_CODE => Ok(Command::UnlockUser(UnlockUser::from_bytes(payload)?)),
             GET_PERSONAL_ACCESS_TOKENS_CODE => Ok(Command::GetPersonalAccessTokens(
                 GetPersonalAccessTokens::from_bytes(payload)?,
             )),
@@ -291,11 +381,20 @@ impl BytesSerializable for Command {
             LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE => Ok(Command::LoginWithPersonalAccessToken(
                 LoginWithPersonalAccessToken::from_bytes(payload)?,
             )),
+            GET_ROLE_CODE => Ok(Command::GetRole(GetRole::from_bytes(payload)?)),
+            GET_ROLES_CODE => Ok(Command::GetRoles(GetRoles::from_bytes(payload)?)),
+            CREATE_ROLE_CODE => Ok(Command::CreateRole(CreateRole::from_bytes(payload)?)),
+            DELETE_ROLE_CODE => Ok(Command::DeleteRole(DeleteRole::from_bytes(payload)?)),
+            UPDATE_ROLE_CODE => Ok(Command::UpdateRole(UpdateRole::from_bytes(payload)?)),
             SEND_MESSAGES_CODE => Ok(Command::SendMessages(SendMessages::from_bytes(payload)?)),
             POLL_MESSAGES_CODE => Ok(Command::PollMessages(PollMessages::from_bytes(payload)?)),
             STORE_CONSUMER_OFFSET_CODE => Ok(Command::StoreConsumerOffset(
                 StoreConsumerOffset::from_bytes(payload)?,
             )),
+            REJECT_MESSAGE_CODE => Ok(Command::RejectMessage(RejectMessage::from_bytes(payload)?)),
+            FETCH_REPLICA_MESSAGES_CODE => Ok(Command::FetchReplicaMessages(
+                FetchReplicaMessages::from_bytes(payload)?,
+            )),
             GET_CONSUMER_OFFSET_CODE => Ok(Command::GetConsumerOffset(
                 GetConsumerOffset::from_bytes(payload)?,
             )),
@@ -317,6 +416,12 @@ impl BytesSerializable for Command {
             DELETE_PARTITIONS_CODE => Ok(Command::DeletePartitions(DeletePartitions::from_bytes(
                 payload,
             )?)),
+            GET_PARTITION_OFFSETS_CODE => Ok(Command::GetPartitionOffsets(
+                GetPartitionOffsets::from_bytes(payload)?,
+            )),
+            GET_OFFSETS_FOR_TIMESTAMP_CODE => Ok(Command::GetOffsetsForTimestamp(
+                GetOffsetsForTimestamp::from_bytes(payload)?,
+            )),
             GET_CONSUMER_GROUP_CODE => Ok(Command::GetConsumerGroup(GetConsumerGroup::from_bytes(
                 payload,
             )?)),
@@ -335,6 +440,15 @@ impl BytesSerializable for Command {
             LEAVE_CONSUMER_GROUP_CODE => Ok(Command::LeaveConsumerGroup(
                 LeaveConsumerGroup::from_bytes(payload)?,
             )),
+            GET_CONSUMER_GROUP_ASSIGNMENT_CODE => Ok(Command::GetConsumerGroupAssignment(
+                GetConsumerGroupAssignment::from_bytes(payload)?,
+            )),
+            HEARTBEAT_CONSUMER_GROUP_CODE => Ok(Command::HeartbeatConsumerGroup(
+                HeartbeatConsumerGroup::from_bytes(payload)?,
+            )),
+            RESET_CONSUMER_GROUP_OFFSETS_CODE => Ok(Command::ResetConsumerGroupOffsets(
+                ResetConsumerGroupOffsets::from_bytes(payload)?,
+            )),
             _ => Err(Error::InvalidCommand),
         }
     }
@@ -352,6 +466,7 @@ impl Display for Command {
         match self {
             Command::Ping(_) => write!(formatter, "{PING}"),
             Command::GetStats(_) => write!(formatter, "{GET_STATS}"),
+            Command::GetAuditLog(payload) => write!(formatter, "{GET_AUDIT_LOG}|{payload}"),
             Command::GetMe(_) => write!(formatter, "{GET_ME}"),
             Command::GetClient(payload) => write!(formatter, "{GET_CLIENT}|{payload}"),
             Command::GetClients(_) => write!(formatter, "{GET_CLIENTS}"),
@@ -368,6 +483,8 @@ impl Display for Command {
             }
             Command::LoginUser(payload) => write!(formatter, "{LOGIN_USER}|{payload}"),
             Command::LogoutUser(_) => write!(formatter, "{LOGOUT_USER}"),
+            Command::AssignRoles(payload) => write!(formatter, "{ASSIGN_ROLES}|{payload}"),
+            Command::UnlockUser(payload) => write!(formatter, "{UNLOCK_USER}|{payload}"),
             Command::GetPersonalAccessTokens(_) => {
                 write!(formatter, "{GET_PERSONAL_ACCESS_TOKENS}")
             }
@@ -380,6 +497,11 @@ impl Display for Command {
             Command::LoginWithPersonalAccessToken(payload) => {
                 write!(formatter, "{LOGIN_WITH_PERSONAL_ACCESS_TOKEN}|{payload}")
             }
+            Command::GetRole(payload) => write!(formatter, "{GET_ROLE}|{payload}"),
+            Command::GetRoles(_) => write!(formatter, "{GET_ROLES}"),
+            Command::CreateRole(payload) => write!(formatter, "{CREATE_ROLE}|{payload}"),
+            Command::DeleteRole(payload) => write!(formatter, "{DELETE_ROLE}|{payload}"),
+            Command::UpdateRole(payload) => write!(formatter, "{UPDATE_ROLE}|{payload}"),
             Command::GetStream(payload) => write!(formatter, "{GET_STREAM}|{payload}"),
             Command::GetStreams(_) => write!(formatter, "{GET_STREAMS}"),
             Command::CreateStream(payload) => write!(formatter, "{CREATE_STREAM}|{payload}"),
@@ -398,11 +520,21 @@ impl Display for Command {
             Command::DeletePartitions(payload) => {
                 write!(formatter, "{DELETE_PARTITIONS}|{payload}")
             }
+            Command::GetPartitionOffsets(payload) => {
+                write!(formatter, "{GET_PARTITION_OFFSETS}|{payload}")
+            }
+            Command::GetOffsetsForTimestamp(payload) => {
+                write!(formatter, "{GET_OFFSETS_FOR_TIMESTAMP}|{payload}")
+            }
             Command::PollMessages(payload) => write!(formatter, "{POLL_MESSAGES}|{payload}"),
             Command::SendMessages(payload) => write!(formatter, "{SEND_MESSAGES}|{payload}"),
             Command::StoreConsumerOffset(payload) => {
                 write!(formatter, "{STORE_CONSUMER_OFFSET}|{payload}")
             }
+            Command::RejectMessage(payload) => write!(formatter, "{REJECT_MESSAGE}|{payload}"),
+            Command::FetchReplicaMessages(payload) => {
+                write!(formatter, "{FETCH_REPLICA_MESSAGES}|{payload}")
+            }
             Command::GetConsumerOffset(payload) => {
                 write!(formatter, "{GET_CONSUMER_OFFSET}|{payload}")
             }
@@ -424,6 +556,15 @@ impl Display for Command {
             Command::LeaveConsumerGroup(payload) => {
                 write!(formatter, "{LEAVE_CONSUMER_GROUP}|{payload}")
             }
+            Command::GetConsumerGroupAssignment(payload) => {
+                write!(formatter, "{GET_CONSUMER_GROUP_ASSIGNMENT}|{payload}")
+            }
+            Command::HeartbeatConsumerGroup(payload) => {
+                write!(formatter, "{HEARTBEAT_CONSUMER_GROUP}|{payload}")
+            }
+            Command::ResetConsumerGroupOffsets(payload) => {
+                write!(formatter, "{RESET_CONSUMER_GROUP_OFFSETS}|{payload}")
+            }
         }
     }
 }
@@ -444,6 +585,11 @@ mod tests {
             GET_STATS_CODE,
             &GetStats::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetAuditLog(GetAuditLog::default()),
+            GET_AUDIT_LOG_CODE,
+            &GetAuditLog::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetMe(GetMe::default()),
             GET_ME_CODE,
@@ -504,6 +650,16 @@ mod tests {
             LOGOUT_USER_CODE,
             &LogoutUser::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::AssignRoles(AssignRoles::default()),
+            ASSIGN_ROLES_CODE,
+            &AssignRoles::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UnlockUser(UnlockUser::default()),
+            UNLOCK_USER_CODE,
+            &UnlockUser::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetPersonalAccessTokens(GetPersonalAccessTokens::default()),
             GET_PERSONAL_ACCESS_TOKENS_CODE,
@@ -524,6 +680,31 @@ mod tests {
             LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE,
             &LoginWithPersonalAccessToken::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetRole(GetRole::default()),
+            GET_ROLE_CODE,
+            &GetRole::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetRoles(GetRoles::default()),
+            GET_ROLES_CODE,
+            &GetRoles::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::CreateRole(CreateRole::default()),
+            CREATE_ROLE_CODE,
+            &CreateRole::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::DeleteRole(DeleteRole::default()),
+            DELETE_ROLE_CODE,
+            &DeleteRole::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UpdateRole(UpdateRole::default()),
+            UPDATE_ROLE_CODE,
+            &UpdateRole::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::SendMessages(SendMessages::default()),
             SEND_MESSAGES_CODE,
@@ -539,6 +720,16 @@ mod tests {
             STORE_CONSUMER_OFFSET_CODE,
             &StoreConsumerOffset::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::RejectMessage(RejectMessage::default()),
+            REJECT_MESSAGE_CODE,
+            &RejectMessage::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::FetchReplicaMessages(FetchReplicaMessages::default()),
+            FETCH_REPLICA_MESSAGES_CODE,
+            &FetchReplicaMessages::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetConsumerOffset(GetConsumerOffset::default()),
             GET_CONSUMER_OFFSET_CODE,
@@ -614,6 +805,16 @@ mod tests {
             DELETE_PARTITIONS_CODE,
             &DeletePartitions::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetPartitionOffsets(GetPartitionOffsets::default()),
+            GET_PARTITION_OFFSETS_CODE,
+            &GetPartitionOffsets::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetOffsetsForTimestamp(GetOffsetsForTimestamp::default()),
+            GET_OFFSETS_FOR_TIMESTAMP_CODE,
+            &GetOffsetsForTimestamp::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetConsumerGroup(GetConsumerGroup::default()),
             GET_CONSUMER_GROUP_CODE,
@@ -644,6 +845,21 @@ mod tests {
             LEAVE_CONSUMER_GROUP_CODE,
             &LeaveConsumerGroup::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetConsumerGroupAssignment(GetConsumerGroupAssignment::default()),
+            GET_CONSUMER_GROUP_ASSIGNMENT_CODE,
+            &GetConsumerGroupAssignment::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::HeartbeatConsumerGroup(HeartbeatConsumerGroup::default()),
+            HEARTBEAT_CONSUMER_GROUP_CODE,
+            &HeartbeatConsumerGroup::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::ResetConsumerGroupOffsets(ResetConsumerGroupOffsets::default()),
+            RESET_CONSUMER_GROUP_OFFSETS_CODE,
+            &ResetConsumerGroupOffsets::default(),
+        );
     }
 
     fn assert_serialized_as_bytes_and_deserialized_from_bytes(
@@ -7,14 +7,20 @@ use std::{
     str::FromStr,
 };
 
+use crate::compression::compressor::{
+    Compressor, GzipCompressor, Lz4Compressor, NoneCompressor, SnappyCompressor, ZstdCompressor,
+};
 use crate::error::Error;
 
-// for now only those, in the future will add snappy, lz4, zstd (same as in confluent kafka) in addition to that
-// we should consider brotli as well.
-#[derive(Debug, PartialEq, Clone)]
+// Same set of algorithms as in confluent kafka, in the future we should consider brotli as well.
+// The codes are part of the binary protocol and are persisted with the data, so they must never change.
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum CompressionAlgorithm {
     None,
     Gzip,
+    Lz4,
+    Zstd,
+    Snappy,
 }
 impl FromStr for CompressionAlgorithm {
     type Err = String;
@@ -22,6 +28,9 @@ impl FromStr for CompressionAlgorithm {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "gzip" => Ok(CompressionAlgorithm::Gzip),
+            "lz4" => Ok(CompressionAlgorithm::Lz4),
+            "zstd" => Ok(CompressionAlgorithm::Zstd),
+            "snappy" => Ok(CompressionAlgorithm::Snappy),
             "none" => Ok(CompressionAlgorithm::None),
             _ => Err(format!("Unknown compression type: {}", s)),
         }
@@ -33,6 +42,9 @@ impl CompressionAlgorithm {
         match self {
             CompressionAlgorithm::None => 1,
             CompressionAlgorithm::Gzip => 2,
+            CompressionAlgorithm::Lz4 => 3,
+            CompressionAlgorithm::Zstd => 4,
+            CompressionAlgorithm::Snappy => 5,
         }
     }
 
@@ -40,9 +52,30 @@ impl CompressionAlgorithm {
         match code {
             1 => Ok(CompressionAlgorithm::None),
             2 => Ok(CompressionAlgorithm::Gzip),
+            3 => Ok(CompressionAlgorithm::Lz4),
+            4 => Ok(CompressionAlgorithm::Zstd),
+            5 => Ok(CompressionAlgorithm::Snappy),
             _ => Err(Error::InvalidCommand),
         }
     }
+
+    pub fn compressor(&self) -> &'static dyn Compressor {
+        match self {
+            CompressionAlgorithm::None => &NoneCompressor,
+            CompressionAlgorithm::Gzip => &GzipCompressor,
+            CompressionAlgorithm::Lz4 => &Lz4Compressor,
+            CompressionAlgorithm::Zstd => &ZstdCompressor,
+            CompressionAlgorithm::Snappy => &SnappyCompressor,
+        }
+    }
+
+    pub fn compress(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        self.compressor().compress(data)
+    }
+
+    pub fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        self.compressor().decompress(data)
+    }
 }
 
 impl Display for CompressionAlgorithm {
@@ -50,6 +83,9 @@ impl Display for CompressionAlgorithm {
         match self {
             CompressionAlgorithm::None => write!(f, "none"),
             CompressionAlgorithm::Gzip => write!(f, "gzip"),
+            CompressionAlgorithm::Lz4 => write!(f, "lz4"),
+            CompressionAlgorithm::Zstd => write!(f, "zstd"),
+            CompressionAlgorithm::Snappy => write!(f, "snappy"),
         }
     }
 }
@@ -62,6 +98,9 @@ impl Serialize for CompressionAlgorithm {
         match self {
             CompressionAlgorithm::None => serializer.serialize_str("none"),
             CompressionAlgorithm::Gzip => serializer.serialize_str("gzip"),
+            CompressionAlgorithm::Lz4 => serializer.serialize_str("lz4"),
+            CompressionAlgorithm::Zstd => serializer.serialize_str("zstd"),
+            CompressionAlgorithm::Snappy => serializer.serialize_str("snappy"),
         }
     }
 }
@@ -71,6 +110,9 @@ impl From<CompressionAlgorithm> for String {
         match value {
             CompressionAlgorithm::None => "none".to_string(),
             CompressionAlgorithm::Gzip => "gzip".to_string(),
+            CompressionAlgorithm::Lz4 => "lz4".to_string(),
+            CompressionAlgorithm::Zstd => "zstd".to_string(),
+            CompressionAlgorithm::Snappy => "snappy".to_string(),
         }
     }
 }
@@ -121,6 +163,18 @@ mod tests {
         let gzip_alg = CompressionAlgorithm::from_str("Gzip");
         assert!(gzip_alg.is_ok());
         assert_eq!(gzip_alg.unwrap(), CompressionAlgorithm::Gzip);
+
+        let lz4_alg = CompressionAlgorithm::from_str("lz4");
+        assert!(lz4_alg.is_ok());
+        assert_eq!(lz4_alg.unwrap(), CompressionAlgorithm::Lz4);
+
+        let zstd_alg = CompressionAlgorithm::from_str("ZSTD");
+        assert!(zstd_alg.is_ok());
+        assert_eq!(zstd_alg.unwrap(), CompressionAlgorithm::Zstd);
+
+        let snappy_alg = CompressionAlgorithm::from_str("Snappy");
+        assert!(snappy_alg.is_ok());
+        assert_eq!(snappy_alg.unwrap(), CompressionAlgorithm::Snappy);
     }
 
     #[test]
@@ -143,6 +197,15 @@ mod tests {
         let gzip_string: String = gzip.into();
 
         assert_eq!(gzip_string, "gzip".to_string());
+
+        let lz4_string: String = CompressionAlgorithm::Lz4.into();
+        assert_eq!(lz4_string, "lz4".to_string());
+
+        let zstd_string: String = CompressionAlgorithm::Zstd.into();
+        assert_eq!(zstd_string, "zstd".to_string());
+
+        let snappy_string: String = CompressionAlgorithm::Snappy.into();
+        assert_eq!(snappy_string, "snappy".to_string());
     }
     #[test]
     fn test_as_code() {
@@ -153,6 +216,10 @@ mod tests {
         let gzip = CompressionAlgorithm::Gzip;
         let gzip_code = gzip.as_code();
         assert_eq!(gzip_code, 2);
+
+        assert_eq!(CompressionAlgorithm::Lz4.as_code(), 3);
+        assert_eq!(CompressionAlgorithm::Zstd.as_code(), 4);
+        assert_eq!(CompressionAlgorithm::Snappy.as_code(), 5);
     }
     #[test]
     fn test_from_code() {
@@ -163,6 +230,56 @@ mod tests {
         let gzip = CompressionAlgorithm::from_code(2);
         assert!(gzip.is_ok());
         assert_eq!(gzip.unwrap(), CompressionAlgorithm::Gzip);
+
+        let lz4 = CompressionAlgorithm::from_code(3);
+        assert!(lz4.is_ok());
+        assert_eq!(lz4.unwrap(), CompressionAlgorithm::Lz4);
+
+        let zstd = CompressionAlgorithm::from_code(4);
+        assert!(zstd.is_ok());
+        assert_eq!(zstd.unwrap(), CompressionAlgorithm::Zstd);
+
+        let snappy = CompressionAlgorithm::from_code(5);
+        assert!(snappy.is_ok());
+        assert_eq!(snappy.unwrap(), CompressionAlgorithm::Snappy);
+    }
+    #[test]
+    fn test_compress_and_decompress() {
+        let data = "iggy ".repeat(100).into_bytes();
+        for algorithm in [
+            CompressionAlgorithm::None,
+            CompressionAlgorithm::Gzip,
+            CompressionAlgorithm::Lz4,
+            CompressionAlgorithm::Zstd,
+            CompressionAlgorithm::Snappy,
+        ] {
+            let compressed = algorithm.compress(&data);
+            assert!(compressed.is_ok());
+            let decompressed = algorithm.decompress(&compressed.unwrap());
+            assert!(decompressed.is_ok());
+            assert_eq!(decompressed.unwrap(), data);
+        }
+    }
+    #[test]
+    fn test_gzip_compress_should_shrink_repetitive_data() {
+        let data = "iggy ".repeat(100).into_bytes();
+        let compressed = CompressionAlgorithm::Gzip.compress(&data).unwrap();
+        assert!(compressed.len() < data.len());
+    }
+    #[test]
+    fn test_gzip_decompress_invalid_input() {
+        let decompressed = CompressionAlgorithm::Gzip.decompress(b"invalid");
+        assert!(decompressed.is_err());
+    }
+    #[test]
+    fn test_serde() {
+        let algorithm = CompressionAlgorithm::Zstd;
+        let json = serde_json::to_string(&algorithm).unwrap();
+        assert_eq!(json, "\"zstd\"");
+
+        let deserialized = serde_json::from_str::<CompressionAlgorithm>("\"lz4\"");
+        assert!(deserialized.is_ok());
+        assert_eq!(deserialized.unwrap(), CompressionAlgorithm::Lz4);
     }
     #[test]
     fn test_from_code_invalid_input() {
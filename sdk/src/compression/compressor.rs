@@ -0,0 +1,189 @@
+use crate::error::Error;
+use crate::messages::MAX_PAYLOAD_SIZE;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fmt::Debug;
+use std::io::{Read, Write};
+
+const ZSTD_COMPRESSION_LEVEL: i32 = 3;
+/// The maximum size of the decompressed data, so the crafted data can't exhaust the memory of the reader.
+pub const MAX_DECOMPRESSED_SIZE: usize = MAX_PAYLOAD_SIZE as usize;
+
+/// The codec used to compress and decompress the binary data, e.g. the message payload.
+/// Implemented for each of the supported compression algorithms, so it can be shared by the SDK and the server.
+pub trait Compressor: Send + Sync + Debug {
+    /// Compresses the provided data.
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>, Error>;
+    /// Decompresses the provided data, failing if it exceeds the `MAX_DECOMPRESSED_SIZE`.
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, Error>;
+}
+
+/// The no-op codec returning the data as it is.
+#[derive(Debug)]
+pub struct NoneCompressor;
+
+/// The codec using the gzip (deflate) algorithm.
+#[derive(Debug)]
+pub struct GzipCompressor;
+
+/// The codec using the LZ4 block format with the uncompressed size prepended.
+#[derive(Debug)]
+pub struct Lz4Compressor;
+
+/// The codec using the Zstandard algorithm.
+#[derive(Debug)]
+pub struct ZstdCompressor;
+
+/// The codec using the Snappy raw format.
+#[derive(Debug)]
+pub struct SnappyCompressor;
+
+impl Compressor for NoneCompressor {
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        Ok(data.to_vec())
+    }
+
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        Ok(data.to_vec())
+    }
+}
+
+impl Compressor for GzipCompressor {
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        if encoder.write_all(data).is_err() {
+            return Err(Error::CannotCompressData);
+        }
+        encoder.finish().map_err(|_| Error::CannotCompressData)
+    }
+
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        read_to_end_bounded(GzDecoder::new(data))
+    }
+}
+
+impl Compressor for Lz4Compressor {
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        Ok(lz4_flex::compress_prepend_size(data))
+    }
+
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        // The decompressed size is prepended, so it's checked before allocating the output.
+        let size = data
+            .get(..4)
+            .map(|size| u32::from_le_bytes([size[0], size[1], size[2], size[3]]) as usize)
+            .ok_or(Error::CannotDecompressData)?;
+        if size > MAX_DECOMPRESSED_SIZE {
+            return Err(Error::CannotDecompressData);
+        }
+        lz4_flex::decompress_size_prepended(data).map_err(|_| Error::CannotDecompressData)
+    }
+}
+
+impl Compressor for ZstdCompressor {
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        zstd::encode_all(data, ZSTD_COMPRESSION_LEVEL).map_err(|_| Error::CannotCompressData)
+    }
+
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        let decoder = zstd::Decoder::new(data).map_err(|_| Error::CannotDecompressData)?;
+        read_to_end_bounded(decoder)
+    }
+}
+
+impl Compressor for SnappyCompressor {
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        snap::raw::Encoder::new()
+            .compress_vec(data)
+            .map_err(|_| Error::CannotCompressData)
+    }
+
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        let size = snap::raw::decompress_len(data).map_err(|_| Error::CannotDecompressData)?;
+        if size > MAX_DECOMPRESSED_SIZE {
+            return Err(Error::CannotDecompressData);
+        }
+        snap::raw::Decoder::new()
+            .decompress_vec(data)
+            .map_err(|_| Error::CannotDecompressData)
+    }
+}
+
+fn read_to_end_bounded(reader: impl Read) -> Result<Vec<u8>, Error> {
+    let mut decompressed = Vec::new();
+    if reader
+        .take(MAX_DECOMPRESSED_SIZE as u64 + 1)
+        .read_to_end(&mut decompressed)
+        .is_err()
+    {
+        return Err(Error::CannotDecompressData);
+    }
+    if decompressed.len() > MAX_DECOMPRESSED_SIZE {
+        return Err(Error::CannotDecompressData);
+    }
+    Ok(decompressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_should_be_compressed_and_decompressed_by_each_compressor() {
+        let data = "{\"entity_id\": 1, \"name\": \"iggy\"}"
+            .repeat(100)
+            .into_bytes();
+        let compressors: [&dyn Compressor; 4] = [
+            &GzipCompressor,
+            &Lz4Compressor,
+            &ZstdCompressor,
+            &SnappyCompressor,
+        ];
+        for compressor in compressors {
+            let compressed = compressor.compress(&data).unwrap();
+            assert!(compressed.len() < data.len(), "{compressor:?}");
+            let decompressed = compressor.decompress(&compressed).unwrap();
+            assert_eq!(decompressed, data, "{compressor:?}");
+        }
+    }
+
+    #[test]
+    fn none_compressor_should_return_the_same_data() {
+        let data = b"iggy".to_vec();
+        assert_eq!(NoneCompressor.compress(&data).unwrap(), data);
+        assert_eq!(NoneCompressor.decompress(&data).unwrap(), data);
+    }
+
+    #[test]
+    fn invalid_data_should_not_be_decompressed() {
+        let data = &[255, 255, 255];
+        let compressors: [&dyn Compressor; 4] = [
+            &GzipCompressor,
+            &Lz4Compressor,
+            &ZstdCompressor,
+            &SnappyCompressor,
+        ];
+        for compressor in compressors {
+            assert!(compressor.decompress(data).is_err(), "{compressor:?}");
+        }
+    }
+
+    #[test]
+    fn data_exceeding_the_max_decompressed_size_should_not_be_decompressed() {
+        let data = vec![0; MAX_DECOMPRESSED_SIZE + 1];
+        let compressors: [&dyn Compressor; 4] = [
+            &GzipCompressor,
+            &Lz4Compressor,
+            &ZstdCompressor,
+            &SnappyCompressor,
+        ];
+        for compressor in compressors {
+            let compressed = compressor.compress(&data).unwrap();
+            assert!(
+                compressor.decompress(&compressed).is_err(),
+                "{compressor:?}"
+            );
+        }
+    }
+}
@@ -1 +1,2 @@
 pub mod compression_algorithm;
+pub mod compressor;
@@ -3,6 +3,7 @@ use crate::command::CommandPayload;
 use crate::consumer_groups::MAX_NAME_LENGTH;
 use crate::error::Error;
 use crate::identifier::Identifier;
+use crate::models::consumer_group::PartitionAssignmentStrategy;
 use crate::utils::text;
 use crate::validatable::Validatable;
 use bytes::BufMut;
@@ -16,6 +17,8 @@ use std::str::from_utf8;
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `consumer_group_id` - unique consumer group ID.
 /// - `name` - unique consumer group name.
+/// - `session_timeout` - optional session timeout of the members in seconds, if `None` then the default one configured on the server is used.
+/// - `assignment_strategy` - strategy used to assign the partitions to the members (round-robin, range or sticky).
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateConsumerGroup {
     /// Unique stream ID (numeric or name).
@@ -28,6 +31,14 @@ pub struct CreateConsumerGroup {
     pub consumer_group_id: u32,
     /// Unique consumer group name.
     pub name: String,
+    /// Optional session timeout of the members in seconds, if `None` then the default one configured on the server is used.
+    /// The member which doesn't send any heartbeat or poll the messages within this time is removed from the group.
+    #[serde(default)]
+    pub session_timeout: Option<u32>,
+    /// Strategy used to assign the partitions to the members (round-robin, range or sticky).
+    /// The sticky one keeps the current assignment of the members as much as possible on membership change.
+    #[serde(default)]
+    pub assignment_strategy: PartitionAssignmentStrategy,
 }
 
 impl CommandPayload for CreateConsumerGroup {}
@@ -39,6 +50,8 @@ impl Default for CreateConsumerGroup {
             topic_id: Identifier::default(),
             consumer_group_id: 1,
             name: "consumer_group_1".to_string(),
+            session_timeout: None,
+            assignment_strategy: PartitionAssignmentStrategy::default(),
         }
     }
 }
@@ -66,10 +79,12 @@ impl BytesSerializable for CreateConsumerGroup {
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
         let mut bytes =
-            Vec::with_capacity(4 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len());
+            Vec::with_capacity(10 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len());
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
         bytes.put_u32_le(self.consumer_group_id);
+        bytes.put_u32_le(self.session_timeout.unwrap_or(0));
+        bytes.put_u8(self.assignment_strategy.as_code());
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.extend(self.name.as_bytes());
@@ -77,7 +92,7 @@ impl BytesSerializable for CreateConsumerGroup {
     }
 
     fn from_bytes(bytes: &[u8]) -> Result<CreateConsumerGroup, Error> {
-        if bytes.len() < 10 {
+        if bytes.len() < 15 {
             return Err(Error::InvalidCommand);
         }
 
@@ -87,14 +102,22 @@ impl BytesSerializable for CreateConsumerGroup {
         let topic_id = Identifier::from_bytes(&bytes[position..])?;
         position += topic_id.get_size_bytes() as usize;
         let consumer_group_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
-        let name_length = bytes[position + 4];
+        let session_timeout =
+            match u32::from_le_bytes(bytes[position + 4..position + 8].try_into()?) {
+                0 => None,
+                session_timeout => Some(session_timeout),
+            };
+        let assignment_strategy = PartitionAssignmentStrategy::from_code(bytes[position + 8])?;
+        let name_length = bytes[position + 9];
         let name =
-            from_utf8(&bytes[position + 5..position + 5 + name_length as usize])?.to_string();
+            from_utf8(&bytes[position + 10..position + 10 + name_length as usize])?.to_string();
         let command = CreateConsumerGroup {
             stream_id,
             topic_id,
             consumer_group_id,
             name,
+            session_timeout,
+            assignment_strategy,
         };
         command.validate()?;
         Ok(command)
@@ -105,8 +128,13 @@ impl Display for CreateConsumerGroup {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}",
-            self.stream_id, self.topic_id, self.consumer_group_id, self.name
+            "{}|{}|{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.consumer_group_id,
+            self.name,
+            self.session_timeout.unwrap_or(0),
+            self.assignment_strategy
         )
     }
 }
@@ -122,6 +150,8 @@ mod tests {
             topic_id: Identifier::numeric(2).unwrap(),
             consumer_group_id: 3,
             name: "test".to_string(),
+            session_timeout: Some(10),
+            assignment_strategy: PartitionAssignmentStrategy::Sticky,
         };
 
         let bytes = command.as_bytes();
@@ -132,13 +162,19 @@ mod tests {
         position += topic_id.get_size_bytes() as usize;
         let consumer_group_id =
             u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
-        let name_length = bytes[position + 4];
-        let name = from_utf8(&bytes[position + 5..position + 5 + name_length as usize]).unwrap();
+        let session_timeout =
+            u32::from_le_bytes(bytes[position + 4..position + 8].try_into().unwrap());
+        let assignment_strategy =
+            PartitionAssignmentStrategy::from_code(bytes[position + 8]).unwrap();
+        let name_length = bytes[position + 9];
+        let name = from_utf8(&bytes[position + 10..position + 10 + name_length as usize]).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(consumer_group_id, command.consumer_group_id);
+        assert_eq!(Some(session_timeout), command.session_timeout);
+        assert_eq!(assignment_strategy, command.assignment_strategy);
         assert_eq!(name, command.name);
     }
 
@@ -147,14 +183,18 @@ mod tests {
         let stream_id = Identifier::numeric(1).unwrap();
         let topic_id = Identifier::numeric(2).unwrap();
         let consumer_group_id = 3u32;
+        let session_timeout = 10u32;
+        let assignment_strategy = PartitionAssignmentStrategy::Range;
         let name = "test".to_string();
         let stream_id_bytes = stream_id.as_bytes();
         let topic_id_bytes = topic_id.as_bytes();
         let mut bytes =
-            Vec::with_capacity(4 + stream_id_bytes.len() + topic_id_bytes.len() + name.len());
+            Vec::with_capacity(10 + stream_id_bytes.len() + topic_id_bytes.len() + name.len());
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
         bytes.put_u32_le(consumer_group_id);
+        bytes.put_u32_le(session_timeout);
+        bytes.put_u8(assignment_strategy.as_code());
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(name.len() as u8);
         bytes.extend(name.as_bytes());
@@ -166,5 +206,7 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.consumer_group_id, consumer_group_id);
         assert_eq!(command.name, name);
+        assert_eq!(command.session_timeout, Some(session_timeout));
+        assert_eq!(command.assignment_strategy, assignment_strategy);
     }
 }
@@ -1,8 +1,11 @@
 pub mod create_consumer_group;
 pub mod delete_consumer_group;
 pub mod get_consumer_group;
+pub mod get_consumer_group_assignment;
 pub mod get_consumer_groups;
+pub mod heartbeat_consumer_group;
 pub mod join_consumer_group;
 pub mod leave_consumer_group;
+pub mod reset_consumer_group_offsets;
 
 const MAX_NAME_LENGTH: usize = 255;
@@ -0,0 +1,295 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `ResetConsumerGroupOffsets` command resets the offsets of the consumer group for all or selected partitions of the topic.
+/// The offsets are expressed as the offset of the next message to be polled by the consumer group,
+/// and are clamped to the range of the offsets available in the partition.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `consumer_group_id` - unique consumer group ID (numeric or name).
+/// - `partition_ids` - partition IDs for which the offsets are reset. If empty, the offsets are reset for all the partitions.
+/// - `strategy` - strategy which specifies to which offset the consumer group is reset.
+/// - `dry_run` - whether to only return the offsets which would be set, without storing them.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct ResetConsumerGroupOffsets {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique consumer group ID (numeric or name).
+    #[serde(skip)]
+    pub consumer_group_id: Identifier,
+    /// Partition IDs for which the offsets are reset. If empty, the offsets are reset for all the partitions.
+    #[serde(default)]
+    pub partition_ids: Vec<u32>,
+    /// Strategy which specifies to which offset the consumer group is reset.
+    pub strategy: OffsetsResetStrategy,
+    /// Whether to only return the offsets which would be set, without storing them.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// `OffsetsResetStrategy` specifies to which offset the consumer group is reset.
+/// It has the following kinds:
+/// - `Earliest` - the first message still stored in the partition.
+/// - `Latest` - the message appended after the last one, so that only the new messages are polled.
+/// - `Offset` - the message with the specified offset.
+/// - `Timestamp` - the first message appended at or after the specified timestamp (in microseconds).
+/// - `Shift` - the current offset of the consumer group moved by the specified number of messages (negative value rewinds it).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum OffsetsResetStrategy {
+    /// The first message still stored in the partition.
+    #[default]
+    Earliest,
+    /// The message appended after the last one, so that only the new messages are polled.
+    Latest,
+    /// The message with the specified offset.
+    Offset(u64),
+    /// The first message appended at or after the specified timestamp (in microseconds).
+    Timestamp(u64),
+    /// The current offset of the consumer group moved by the specified number of messages (negative value rewinds it).
+    Shift(i64),
+}
+
+impl OffsetsResetStrategy {
+    /// Returns the code of the offsets reset strategy.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            OffsetsResetStrategy::Earliest => 1,
+            OffsetsResetStrategy::Latest => 2,
+            OffsetsResetStrategy::Offset(_) => 3,
+            OffsetsResetStrategy::Timestamp(_) => 4,
+            OffsetsResetStrategy::Shift(_) => 5,
+        }
+    }
+
+    /// Returns the offsets reset strategy from the code and the value.
+    pub fn from_code(code: u8, value: u64) -> Result<Self, Error> {
+        match code {
+            1 => Ok(OffsetsResetStrategy::Earliest),
+            2 => Ok(OffsetsResetStrategy::Latest),
+            3 => Ok(OffsetsResetStrategy::Offset(value)),
+            4 => Ok(OffsetsResetStrategy::Timestamp(value)),
+            5 => Ok(OffsetsResetStrategy::Shift(value as i64)),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+
+    /// Returns the value of the offsets reset strategy, or 0 if it has none.
+    pub fn value(&self) -> u64 {
+        match self {
+            OffsetsResetStrategy::Earliest | OffsetsResetStrategy::Latest => 0,
+            OffsetsResetStrategy::Offset(offset) => *offset,
+            OffsetsResetStrategy::Timestamp(timestamp) => *timestamp,
+            OffsetsResetStrategy::Shift(shift) => *shift as u64,
+        }
+    }
+}
+
+impl Display for OffsetsResetStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OffsetsResetStrategy::Earliest => write!(f, "earliest"),
+            OffsetsResetStrategy::Latest => write!(f, "latest"),
+            OffsetsResetStrategy::Offset(offset) => write!(f, "offset: {offset}"),
+            OffsetsResetStrategy::Timestamp(timestamp) => write!(f, "timestamp: {timestamp}"),
+            OffsetsResetStrategy::Shift(shift) => write!(f, "shift: {shift}"),
+        }
+    }
+}
+
+impl CommandPayload for ResetConsumerGroupOffsets {}
+
+impl Validatable<Error> for ResetConsumerGroupOffsets {
+    fn validate(&self) -> Result<(), Error> {
+        if self.partition_ids.contains(&0) {
+            return Err(Error::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for ResetConsumerGroupOffsets {
+    fn as_bytes(&self) -> Vec<u8> {
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let consumer_group_id_bytes = self.consumer_group_id.as_bytes();
+        let mut bytes = Vec::with_capacity(
+            14 + stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + consumer_group_id_bytes.len()
+                + 4 * self.partition_ids.len(),
+        );
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.extend(consumer_group_id_bytes);
+        bytes.put_u8(self.strategy.as_code());
+        bytes.put_u64_le(self.strategy.value());
+        bytes.put_u8(if self.dry_run { 1 } else { 0 });
+        bytes.put_u32_le(self.partition_ids.len() as u32);
+        for partition_id in &self.partition_ids {
+            bytes.put_u32_le(*partition_id);
+        }
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<ResetConsumerGroupOffsets, Error> {
+        if bytes.len() < 23 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes)?;
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes() as usize;
+        let consumer_group_id = Identifier::from_bytes(&bytes[position..])?;
+        position += consumer_group_id.get_size_bytes() as usize;
+        if bytes.len() < position + 14 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let strategy_code = bytes[position];
+        let strategy_value = u64::from_le_bytes(bytes[position + 1..position + 9].try_into()?);
+        let strategy = OffsetsResetStrategy::from_code(strategy_code, strategy_value)?;
+        let dry_run = match bytes[position + 9] {
+            0 => false,
+            1 => true,
+            _ => return Err(Error::InvalidCommand),
+        };
+        let partitions_count =
+            u32::from_le_bytes(bytes[position + 10..position + 14].try_into()?) as usize;
+        position += 14;
+        if bytes.len() != position + 4 * partitions_count {
+            return Err(Error::InvalidCommand);
+        }
+
+        let mut partition_ids = Vec::with_capacity(partitions_count);
+        for _ in 0..partitions_count {
+            partition_ids.push(u32::from_le_bytes(
+                bytes[position..position + 4].try_into()?,
+            ));
+            position += 4;
+        }
+
+        let command = ResetConsumerGroupOffsets {
+            stream_id,
+            topic_id,
+            consumer_group_id,
+            partition_ids,
+            strategy,
+            dry_run,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for ResetConsumerGroupOffsets {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let partition_ids = self
+            .partition_ids
+            .iter()
+            .map(|partition_id| partition_id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.consumer_group_id,
+            partition_ids,
+            self.strategy,
+            self.dry_run
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = ResetConsumerGroupOffsets {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            consumer_group_id: Identifier::numeric(3).unwrap(),
+            partition_ids: vec![1, 3],
+            strategy: OffsetsResetStrategy::Shift(-10),
+            dry_run: true,
+        };
+
+        let bytes = command.as_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += topic_id.get_size_bytes() as usize;
+        let consumer_group_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += consumer_group_id.get_size_bytes() as usize;
+        let strategy = OffsetsResetStrategy::from_code(
+            bytes[position],
+            u64::from_le_bytes(bytes[position + 1..position + 9].try_into().unwrap()),
+        )
+        .unwrap();
+        let dry_run = bytes[position + 9] == 1;
+        let partitions_count =
+            u32::from_le_bytes(bytes[position + 10..position + 14].try_into().unwrap());
+        let first_partition_id =
+            u32::from_le_bytes(bytes[position + 14..position + 18].try_into().unwrap());
+        let second_partition_id =
+            u32::from_le_bytes(bytes[position + 18..position + 22].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(consumer_group_id, command.consumer_group_id);
+        assert_eq!(strategy, command.strategy);
+        assert_eq!(dry_run, command.dry_run);
+        assert_eq!(partitions_count, 2);
+        assert_eq!(first_partition_id, 1);
+        assert_eq!(second_partition_id, 3);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let consumer_group_id = Identifier::named("group").unwrap();
+        let strategy = OffsetsResetStrategy::Timestamp(1_700_000_000_000_000);
+        let mut bytes = [
+            stream_id.as_bytes(),
+            topic_id.as_bytes(),
+            consumer_group_id.as_bytes(),
+        ]
+        .concat();
+        bytes.put_u8(strategy.as_code());
+        bytes.put_u64_le(strategy.value());
+        bytes.put_u8(0);
+        bytes.put_u32_le(1);
+        bytes.put_u32_le(2);
+        let command = ResetConsumerGroupOffsets::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.consumer_group_id, consumer_group_id);
+        assert_eq!(command.partition_ids, vec![2]);
+        assert_eq!(command.strategy, strategy);
+        assert!(!command.dry_run);
+    }
+}
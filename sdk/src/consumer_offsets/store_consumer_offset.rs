@@ -15,6 +15,7 @@ use std::fmt::Display;
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `partition_id` - partition ID on which the offset is stored. Has to be specified for the regular consumer. For consumer group it is ignored (use `None`).
 /// - `offset` - offset to store.
+/// - `generation` - generation of the consumer group assignment known to the member. For the regular consumer it is ignored (use `None`).
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct StoreConsumerOffset {
     /// The consumer that is storing the offset, either the regular consumer or the consumer group.
@@ -30,6 +31,12 @@ pub struct StoreConsumerOffset {
     pub partition_id: Option<u32>,
     /// Offset to store.
     pub offset: u64,
+    /// Generation of the consumer group assignment known to the member, as returned by `GetConsumerGroupAssignment`.
+    /// The offset is rejected if the group has been rebalanced since, so that the stale member is fenced.
+    /// For the regular consumer it is ignored (use `None`). If it's not specified, the member isn't fenced by the generation,
+    /// and only the ownership of the partition is validated.
+    #[serde(default)]
+    pub generation: Option<u32>,
 }
 
 impl Default for StoreConsumerOffset {
@@ -40,6 +47,7 @@ impl Default for StoreConsumerOffset {
             topic_id: Identifier::default(),
             partition_id: Some(1),
             offset: 0,
+            generation: None,
         }
     }
 }
@@ -58,7 +66,7 @@ impl BytesSerializable for StoreConsumerOffset {
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
         let mut bytes = Vec::with_capacity(
-            12 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
+            16 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
         );
         bytes.extend(consumer_bytes);
         bytes.extend(stream_id_bytes);
@@ -69,6 +77,7 @@ impl BytesSerializable for StoreConsumerOffset {
             bytes.put_u32_le(0);
         }
         bytes.put_u64_le(self.offset);
+        bytes.put_u32_le(self.generation.unwrap_or(0));
         bytes
     }
 
@@ -89,6 +98,10 @@ impl BytesSerializable for StoreConsumerOffset {
         position += stream_id.get_size_bytes() as usize;
         let topic_id = Identifier::from_bytes(&bytes[position..])?;
         position += topic_id.get_size_bytes() as usize;
+        if bytes.len() < position + 12 {
+            return Err(Error::InvalidCommand);
+        }
+
         let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
         let partition_id = if partition_id == 0 {
             None
@@ -96,12 +109,23 @@ impl BytesSerializable for StoreConsumerOffset {
             Some(partition_id)
         };
         let offset = u64::from_le_bytes(bytes[position + 4..position + 12].try_into()?);
+        // The generation is optional, so that the clients which don't send it are still supported.
+        let generation = match bytes.get(position + 12..position + 16) {
+            Some(generation) => u32::from_le_bytes(generation.try_into()?),
+            None => 0,
+        };
+        let generation = if generation == 0 {
+            None
+        } else {
+            Some(generation)
+        };
         let command = StoreConsumerOffset {
             consumer,
             stream_id,
             topic_id,
             partition_id,
             offset,
+            generation,
         };
         command.validate()?;
         Ok(command)
@@ -112,12 +136,13 @@ impl Display for StoreConsumerOffset {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}",
             self.consumer,
             self.stream_id,
             self.topic_id,
             self.partition_id.unwrap_or(0),
-            self.offset
+            self.offset,
+            self.generation.unwrap_or(0)
         )
     }
 }
@@ -134,6 +159,7 @@ mod tests {
             topic_id: Identifier::numeric(3).unwrap(),
             partition_id: Some(4),
             offset: 5,
+            generation: Some(6),
         };
 
         let bytes = command.as_bytes();
@@ -151,6 +177,8 @@ mod tests {
         position += topic_id.get_size_bytes() as usize;
         let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
         let offset = u64::from_le_bytes(bytes[position + 4..position + 12].try_into().unwrap());
+        let generation =
+            u32::from_le_bytes(bytes[position + 12..position + 16].try_into().unwrap());
 
         assert!(!bytes.is_empty());
         assert_eq!(consumer, command.consumer);
@@ -158,6 +186,7 @@ mod tests {
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(Some(partition_id), command.partition_id);
         assert_eq!(offset, command.offset);
+        assert_eq!(Some(generation), command.generation);
     }
 
     #[test]
@@ -167,18 +196,20 @@ mod tests {
         let topic_id = Identifier::numeric(3).unwrap();
         let partition_id = 4u32;
         let offset = 5u64;
+        let generation = 6u32;
 
         let consumer_bytes = consumer.as_bytes();
         let stream_id_bytes = stream_id.as_bytes();
         let topic_id_bytes = topic_id.as_bytes();
         let mut bytes = Vec::with_capacity(
-            12 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
+            16 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
         );
         bytes.extend(consumer_bytes);
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
         bytes.put_u32_le(partition_id);
         bytes.put_u64_le(offset);
+        bytes.put_u32_le(generation);
 
         let command = StoreConsumerOffset::from_bytes(&bytes);
         assert!(command.is_ok());
@@ -189,5 +220,25 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partition_id, Some(partition_id));
         assert_eq!(command.offset, offset);
+        assert_eq!(command.generation, Some(generation));
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_generation() {
+        let command = StoreConsumerOffset {
+            consumer: Consumer::new(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::numeric(3).unwrap(),
+            partition_id: Some(4),
+            offset: 5,
+            generation: Some(6),
+        };
+        let bytes = command.as_bytes();
+
+        let command = StoreConsumerOffset::from_bytes(&bytes[..bytes.len() - 4]).unwrap();
+        assert_eq!(command.partition_id, Some(4));
+        assert_eq!(command.offset, 5);
+        assert_eq!(command.generation, None);
+        assert!(StoreConsumerOffset::from_bytes(&bytes[..bytes.len() - 5]).is_err());
     }
 }
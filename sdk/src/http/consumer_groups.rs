@@ -2,12 +2,17 @@ use crate::client::ConsumerGroupClient;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
+use crate::consumer_groups::get_consumer_group_assignment::GetConsumerGroupAssignment;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use crate::consumer_groups::reset_consumer_group_offsets::ResetConsumerGroupOffsets;
 use crate::error::Error;
 use crate::http::client::HttpClient;
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::consumer_group::{
+    ConsumerGroup, ConsumerGroupAssignment, ConsumerGroupDetails, ConsumerGroupOffsetReset,
+};
 use async_trait::async_trait;
 
 #[async_trait]
@@ -76,6 +81,37 @@ impl ConsumerGroupClient for HttpClient {
     async fn leave_consumer_group(&self, _command: &LeaveConsumerGroup) -> Result<(), Error> {
         Err(Error::FeatureUnavailable)
     }
+
+    async fn get_consumer_group_assignment(
+        &self,
+        _command: &GetConsumerGroupAssignment,
+    ) -> Result<ConsumerGroupAssignment, Error> {
+        Err(Error::FeatureUnavailable)
+    }
+
+    async fn heartbeat_consumer_group(
+        &self,
+        _command: &HeartbeatConsumerGroup,
+    ) -> Result<(), Error> {
+        Err(Error::FeatureUnavailable)
+    }
+
+    async fn reset_consumer_group_offsets(
+        &self,
+        command: &ResetConsumerGroupOffsets,
+    ) -> Result<Vec<ConsumerGroupOffsetReset>, Error> {
+        let path = format!(
+            "{}/{}/offsets/reset",
+            get_path(
+                &command.stream_id.as_string(),
+                &command.topic_id.as_string()
+            ),
+            command.consumer_group_id
+        );
+        let response = self.post(&path, &command).await?;
+        let offsets = response.json().await?;
+        Ok(offsets)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
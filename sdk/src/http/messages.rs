@@ -1,7 +1,9 @@
 use crate::client::MessageClient;
 use crate::error::Error;
 use crate::http::client::HttpClient;
+use crate::messages::fetch_replica_messages::FetchReplicaMessages;
 use crate::messages::poll_messages::PollMessages;
+use crate::messages::reject_message::RejectMessage;
 use crate::messages::send_messages::SendMessages;
 use crate::models::messages::PolledMessages;
 use async_trait::async_trait;
@@ -33,8 +35,31 @@ impl MessageClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn reject_message(&self, command: &RejectMessage) -> Result<(), Error> {
+        self.post(
+            &get_reject_path(
+                &command.stream_id.as_string(),
+                &command.topic_id.as_string(),
+            ),
+            &command,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn fetch_replica_messages(
+        &self,
+        _command: &FetchReplicaMessages,
+    ) -> Result<PolledMessages, Error> {
+        Err(Error::FeatureUnavailable)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
     format!("streams/{stream_id}/topics/{topic_id}/messages")
 }
+
+fn get_reject_path(stream_id: &str, topic_id: &str) -> String {
+    format!("{}/reject", get_path(stream_id, topic_id))
+}
@@ -1,8 +1,11 @@
 use crate::client::PartitionClient;
 use crate::error::Error;
 use crate::http::client::HttpClient;
+use crate::models::partition_offsets::{PartitionOffsets, TimestampOffset};
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use crate::partitions::get_partition_offsets::GetPartitionOffsets;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -30,6 +33,43 @@ impl PartitionClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn get_partition_offsets(
+        &self,
+        command: &GetPartitionOffsets,
+    ) -> Result<Vec<PartitionOffsets>, Error> {
+        let response = self
+            .get(&format!(
+                "{}/offsets",
+                get_path(
+                    &command.stream_id.as_string(),
+                    &command.topic_id.as_string()
+                )
+            ))
+            .await?;
+        let offsets = response.json().await?;
+        Ok(offsets)
+    }
+
+    async fn get_offsets_for_timestamp(
+        &self,
+        command: &GetOffsetsForTimestamp,
+    ) -> Result<Vec<TimestampOffset>, Error> {
+        let response = self
+            .get_with_query(
+                &format!(
+                    "{}/offsets-for-timestamp",
+                    get_path(
+                        &command.stream_id.as_string(),
+                        &command.topic_id.as_string()
+                    )
+                ),
+                &command,
+            )
+            .await?;
+        let offsets = response.json().await?;
+        Ok(offsets)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
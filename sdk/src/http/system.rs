@@ -1,8 +1,10 @@
 use crate::client::SystemClient;
 use crate::error::Error;
 use crate::http::client::HttpClient;
+use crate::models::audit_log::AuditLogEntry;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::stats::Stats;
+use crate::system::get_audit_log::GetAuditLog;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
@@ -13,6 +15,7 @@ use async_trait::async_trait;
 const PING: &str = "/ping";
 const CLIENTS: &str = "/clients";
 const STATS: &str = "/stats";
+const AUDIT_LOG: &str = "/audit-log";
 
 #[async_trait]
 impl SystemClient for HttpClient {
@@ -39,6 +42,12 @@ impl SystemClient for HttpClient {
         Ok(clients)
     }
 
+    async fn get_audit_log(&self, command: &GetAuditLog) -> Result<Vec<AuditLogEntry>, Error> {
+        let response = self.get_with_query(AUDIT_LOG, &command).await?;
+        let entries = response.json().await?;
+        Ok(entries)
+    }
+
     async fn ping(&self, _command: &Ping) -> Result<(), Error> {
         self.get(PING).await?;
         Ok(())
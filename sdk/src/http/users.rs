@@ -3,6 +3,7 @@ use crate::error::Error;
 use crate::http::client::HttpClient;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
+use crate::users::assign_roles::AssignRoles;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
@@ -10,6 +11,7 @@ use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
 use crate::users::update_user::UpdateUser;
 use async_trait::async_trait;
@@ -53,6 +55,18 @@ impl UserClient for HttpClient {
         Ok(())
     }
 
+    async fn assign_roles(&self, command: &AssignRoles) -> Result<(), Error> {
+        self.put(&format!("{PATH}/{}/roles", command.user_id), &command)
+            .await?;
+        Ok(())
+    }
+
+    async fn unlock_user(&self, command: &UnlockUser) -> Result<(), Error> {
+        self.post(&format!("{PATH}/{}/unlock", command.user_id), &command)
+            .await?;
+        Ok(())
+    }
+
     async fn change_password(&self, command: &ChangePassword) -> Result<(), Error> {
         self.put(&format!("{PATH}/{}/password", command.user_id), &command)
             .await?;
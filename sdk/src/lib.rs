@@ -23,6 +23,7 @@ pub mod partitioner;
 pub mod partitions;
 pub mod personal_access_tokens;
 pub mod quic;
+pub mod roles;
 pub mod sizeable;
 pub mod streams;
 pub mod system;
@@ -0,0 +1,198 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `FetchReplicaMessages` command is used by the follower node to fetch the messages from the partition stored by the leader node.
+/// The messages are returned exactly as they are stored by the leader, including their offsets, and the fetch request acknowledges
+/// that the follower has already replicated all the messages with the lower offsets, which is used to track the in-sync replica set of the partition.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - partition ID from which the messages should be fetched.
+/// - `replica_id` - unique ID of the follower node fetching the messages.
+/// - `offset` - offset of the first message to fetch, equal to the number of messages already replicated by the follower.
+/// - `count` - maximum number of messages to fetch.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct FetchReplicaMessages {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Partition ID from which the messages should be fetched.
+    pub partition_id: u32,
+    /// Unique ID of the follower node fetching the messages.
+    pub replica_id: u32,
+    /// Offset of the first message to fetch, equal to the number of messages already replicated by the follower.
+    pub offset: u64,
+    /// Maximum number of messages to fetch.
+    pub count: u32,
+}
+
+impl Default for FetchReplicaMessages {
+    fn default() -> Self {
+        FetchReplicaMessages {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            partition_id: 1,
+            replica_id: 1,
+            offset: 0,
+            count: 1000,
+        }
+    }
+}
+
+impl CommandPayload for FetchReplicaMessages {}
+
+impl Validatable<Error> for FetchReplicaMessages {
+    fn validate(&self) -> Result<(), Error> {
+        if self.count == 0 {
+            return Err(Error::InvalidMessagesCount);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for FetchReplicaMessages {
+    fn as_bytes(&self) -> Vec<u8> {
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let mut bytes = Vec::with_capacity(20 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.put_u32_le(self.partition_id);
+        bytes.put_u32_le(self.replica_id);
+        bytes.put_u64_le(self.offset);
+        bytes.put_u32_le(self.count);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<FetchReplicaMessages, Error> {
+        if bytes.len() < 26 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes)?;
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes() as usize;
+        if bytes.len() != position + 20 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let replica_id = u32::from_le_bytes(bytes[position + 4..position + 8].try_into()?);
+        let offset = u64::from_le_bytes(bytes[position + 8..position + 16].try_into()?);
+        let count = u32::from_le_bytes(bytes[position + 16..position + 20].try_into()?);
+        let command = FetchReplicaMessages {
+            stream_id,
+            topic_id,
+            partition_id,
+            replica_id,
+            offset,
+            count,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for FetchReplicaMessages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+            self.replica_id,
+            self.offset,
+            self.count
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = FetchReplicaMessages {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partition_id: 3,
+            replica_id: 4,
+            offset: 5,
+            count: 6,
+        };
+
+        let bytes = command.as_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += topic_id.get_size_bytes() as usize;
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let replica_id = u32::from_le_bytes(bytes[position + 4..position + 8].try_into().unwrap());
+        let offset = u64::from_le_bytes(bytes[position + 8..position + 16].try_into().unwrap());
+        let count = u32::from_le_bytes(bytes[position + 16..position + 20].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(partition_id, command.partition_id);
+        assert_eq!(replica_id, command.replica_id);
+        assert_eq!(offset, command.offset);
+        assert_eq!(count, command.count);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::named("topic").unwrap();
+        let partition_id = 3u32;
+        let replica_id = 4u32;
+        let offset = 5u64;
+        let count = 6u32;
+
+        let stream_id_bytes = stream_id.as_bytes();
+        let topic_id_bytes = topic_id.as_bytes();
+        let mut bytes = Vec::with_capacity(20 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.put_u32_le(partition_id);
+        bytes.put_u32_le(replica_id);
+        bytes.put_u64_le(offset);
+        bytes.put_u32_le(count);
+
+        let command = FetchReplicaMessages::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, partition_id);
+        assert_eq!(command.replica_id, replica_id);
+        assert_eq!(command.offset, offset);
+        assert_eq!(command.count, count);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_zero_count() {
+        let command = FetchReplicaMessages {
+            count: 0,
+            ..FetchReplicaMessages::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
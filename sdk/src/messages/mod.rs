@@ -1,4 +1,6 @@
+pub mod fetch_replica_messages;
 pub mod poll_messages;
+pub mod reject_message;
 pub mod send_messages;
 
 const MAX_HEADERS_SIZE: u32 = 100 * 1000;
@@ -19,6 +19,8 @@ use std::str::FromStr;
 /// - `strategy` - polling strategy which specifies from where to start polling messages.
 /// - `count` - number of messages to poll.
 /// - `auto_commit` - whether to commit offset on the server automatically after polling the messages.
+/// - `skip_poisoned` - whether to skip the messages which were rejected and marked as poisoned.
+/// - `generation` - generation of the consumer group assignment known to the member. For the regular consumer it is ignored (use `None`).
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct PollMessages {
     /// Consumer which will poll messages. Either regular consumer or consumer group.
@@ -42,6 +44,16 @@ pub struct PollMessages {
     #[serde(default)]
     /// Whether to commit offset on the server automatically after polling the messages.
     pub auto_commit: bool,
+    #[serde(default)]
+    /// Whether to skip the messages which were rejected and marked as poisoned.
+    /// The skipped messages are still taken into account when committing the offset automatically.
+    pub skip_poisoned: bool,
+    /// Generation of the consumer group assignment known to the member, as returned by `GetConsumerGroupAssignment`.
+    /// The poll is rejected if the group has been rebalanced since, so that the stale member is fenced.
+    /// For the regular consumer it is ignored (use `None`). If it's not specified, the member isn't fenced by the generation,
+    /// and only the ownership of the partition is validated.
+    #[serde(default)]
+    pub generation: Option<u32>,
 }
 
 /// `PollingStrategy` specifies from where to start polling messages.
@@ -91,6 +103,8 @@ impl Default for PollMessages {
             strategy: default_strategy(),
             count: default_count(),
             auto_commit: false,
+            skip_poisoned: false,
+            generation: None,
         }
     }
 }
@@ -232,7 +246,7 @@ impl BytesSerializable for PollMessages {
         let topic_id_bytes = self.topic_id.as_bytes();
         let strategy_bytes = self.strategy.as_bytes();
         let mut bytes = Vec::with_capacity(
-            9 + consumer_bytes.len()
+            14 + consumer_bytes.len()
                 + stream_id_bytes.len()
                 + topic_id_bytes.len()
                 + strategy_bytes.len(),
@@ -252,6 +266,12 @@ impl BytesSerializable for PollMessages {
         } else {
             bytes.put_u8(0);
         }
+        if self.skip_poisoned {
+            bytes.put_u8(1);
+        } else {
+            bytes.put_u8(0);
+        }
+        bytes.put_u32_le(self.generation.unwrap_or(0));
 
         bytes
     }
@@ -273,6 +293,10 @@ impl BytesSerializable for PollMessages {
         position += stream_id.get_size_bytes() as usize;
         let topic_id = Identifier::from_bytes(&bytes[position..])?;
         position += topic_id.get_size_bytes() as usize;
+        if bytes.len() < position + 18 {
+            return Err(Error::InvalidCommand);
+        }
+
         let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
         let partition_id = match partition_id {
             0 => None,
@@ -288,6 +312,16 @@ impl BytesSerializable for PollMessages {
         let count = u32::from_le_bytes(bytes[position + 8..position + 12].try_into()?);
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        // The trailing fields are optional, so that the clients which don't send them are still supported.
+        let skip_poisoned = matches!(bytes.get(position + 13), Some(1));
+        let generation = match bytes.get(position + 14..position + 18) {
+            Some(generation) => u32::from_le_bytes(generation.try_into()?),
+            None => 0,
+        };
+        let generation = match generation {
+            0 => None,
+            generation => Some(generation),
+        };
         let command = PollMessages {
             consumer,
             stream_id,
@@ -296,6 +330,8 @@ impl BytesSerializable for PollMessages {
             strategy,
             count,
             auto_commit,
+            skip_poisoned,
+            generation,
         };
         command.validate()?;
         Ok(command)
@@ -306,14 +342,16 @@ impl Display for PollMessages {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}",
             self.consumer,
             self.stream_id,
             self.topic_id,
             self.partition_id.unwrap_or(0),
             self.strategy,
             self.count,
-            auto_commit_to_string(self.auto_commit)
+            auto_commit_to_string(self.auto_commit),
+            skip_poisoned_to_string(self.skip_poisoned),
+            self.generation.unwrap_or(0)
         )
     }
 }
@@ -332,6 +370,14 @@ fn auto_commit_to_string(auto_commit: bool) -> &'static str {
     }
 }
 
+fn skip_poisoned_to_string(skip_poisoned: bool) -> &'static str {
+    if skip_poisoned {
+        "s"
+    } else {
+        "n"
+    }
+}
+
 impl BytesSerializable for PollingStrategy {
     fn as_bytes(&self) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(9);
@@ -366,6 +412,8 @@ mod tests {
             strategy: PollingStrategy::offset(2),
             count: 3,
             auto_commit: true,
+            skip_poisoned: true,
+            generation: Some(5),
         };
 
         let bytes = command.as_bytes();
@@ -392,6 +440,10 @@ mod tests {
         let count = u32::from_le_bytes(bytes[position + 8..position + 12].try_into().unwrap());
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        let skip_poisoned = bytes[position + 13];
+        let skip_poisoned = matches!(skip_poisoned, 1);
+        let generation =
+            u32::from_le_bytes(bytes[position + 14..position + 18].try_into().unwrap());
 
         assert!(!bytes.is_empty());
         assert_eq!(consumer, command.consumer);
@@ -401,6 +453,8 @@ mod tests {
         assert_eq!(strategy, command.strategy);
         assert_eq!(count, command.count);
         assert_eq!(auto_commit, command.auto_commit);
+        assert_eq!(skip_poisoned, command.skip_poisoned);
+        assert_eq!(Some(generation), command.generation);
     }
 
     #[test]
@@ -412,13 +466,15 @@ mod tests {
         let strategy = PollingStrategy::offset(2);
         let count = 3u32;
         let auto_commit = 1u8;
+        let skip_poisoned = 1u8;
+        let generation = 5u32;
 
         let consumer_bytes = consumer.as_bytes();
         let stream_id_bytes = stream_id.as_bytes();
         let topic_id_bytes = topic_id.as_bytes();
         let strategy_bytes = strategy.as_bytes();
         let mut bytes = Vec::with_capacity(
-            9 + consumer_bytes.len()
+            14 + consumer_bytes.len()
                 + stream_id_bytes.len()
                 + topic_id_bytes.len()
                 + strategy_bytes.len(),
@@ -430,11 +486,14 @@ mod tests {
         bytes.extend(strategy_bytes);
         bytes.put_u32_le(count);
         bytes.put_u8(auto_commit);
+        bytes.put_u8(skip_poisoned);
+        bytes.put_u32_le(generation);
 
         let command = PollMessages::from_bytes(&bytes);
         assert!(command.is_ok());
 
         let auto_commit = matches!(auto_commit, 1);
+        let skip_poisoned = matches!(skip_poisoned, 1);
 
         let command = command.unwrap();
         assert_eq!(command.consumer, consumer);
@@ -444,5 +503,52 @@ mod tests {
         assert_eq!(command.strategy, strategy);
         assert_eq!(command.count, count);
         assert_eq!(command.auto_commit, auto_commit);
+        assert_eq!(command.skip_poisoned, skip_poisoned);
+        assert_eq!(command.generation, Some(generation));
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_trailing_fields() {
+        let command = PollMessages {
+            consumer: Consumer::new(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::numeric(3).unwrap(),
+            partition_id: Some(4),
+            strategy: PollingStrategy::offset(2),
+            count: 3,
+            auto_commit: true,
+            skip_poisoned: true,
+            generation: Some(5),
+        };
+        let bytes = command.as_bytes();
+
+        let without_generation = PollMessages::from_bytes(&bytes[..bytes.len() - 4]).unwrap();
+        assert!(without_generation.skip_poisoned);
+        assert_eq!(without_generation.generation, None);
+
+        let without_trailing_fields = PollMessages::from_bytes(&bytes[..bytes.len() - 5]).unwrap();
+        assert_eq!(without_trailing_fields.count, command.count);
+        assert!(without_trailing_fields.auto_commit);
+        assert!(!without_trailing_fields.skip_poisoned);
+        assert_eq!(without_trailing_fields.generation, None);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = PollMessages {
+            consumer: Consumer::new(Identifier::named("consumer").unwrap()),
+            ..PollMessages::default()
+        };
+        let bytes = command.as_bytes();
+
+        assert!(PollMessages::from_bytes(&bytes[..bytes.len() - 6]).is_err());
+    }
+
+    #[test]
+    fn should_have_capacity_matching_serialized_size() {
+        let command = PollMessages::default();
+        let bytes = command.as_bytes();
+
+        assert_eq!(bytes.len(), bytes.capacity());
     }
 }
@@ -0,0 +1,207 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+const MAX_REASON_LENGTH: usize = 255;
+
+/// Header storing the ID of the stream from which the message was moved to the dead-letter topic.
+pub const DEAD_LETTER_ORIGIN_STREAM_HEADER: &str = "iggy-dead-letter-origin-stream";
+/// Header storing the ID of the topic from which the message was moved to the dead-letter topic.
+pub const DEAD_LETTER_ORIGIN_TOPIC_HEADER: &str = "iggy-dead-letter-origin-topic";
+/// Header storing the ID of the partition from which the message was moved to the dead-letter topic.
+pub const DEAD_LETTER_ORIGIN_PARTITION_HEADER: &str = "iggy-dead-letter-origin-partition";
+/// Header storing the offset of the message in the partition from which it was moved to the dead-letter topic.
+pub const DEAD_LETTER_ORIGIN_OFFSET_HEADER: &str = "iggy-dead-letter-origin-offset";
+/// Header storing the reason for which the message was rejected.
+pub const DEAD_LETTER_REASON_HEADER: &str = "iggy-dead-letter-reason";
+
+/// `RejectMessage` command is used to reject the message which cannot be processed by the consumer.
+/// The message is marked as poisoned, and if the dead-letter queue is enabled on the server, it's also copied to the configured dead-letter topic.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - partition ID in which the message is stored.
+/// - `offset` - offset of the rejected message.
+/// - `reason` - optional reason of the rejection, max 255 characters.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RejectMessage {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Partition ID in which the message is stored.
+    pub partition_id: u32,
+    /// Offset of the rejected message.
+    pub offset: u64,
+    /// Optional reason of the rejection, max 255 characters.
+    #[serde(default)]
+    pub reason: String,
+}
+
+impl Default for RejectMessage {
+    fn default() -> Self {
+        RejectMessage {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            partition_id: 1,
+            offset: 0,
+            reason: "".to_string(),
+        }
+    }
+}
+
+impl CommandPayload for RejectMessage {}
+
+impl Validatable<Error> for RejectMessage {
+    fn validate(&self) -> Result<(), Error> {
+        if self.reason.len() > MAX_REASON_LENGTH {
+            return Err(Error::InvalidRejectionReason);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for RejectMessage {
+    fn as_bytes(&self) -> Vec<u8> {
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let mut bytes = Vec::with_capacity(
+            13 + stream_id_bytes.len() + topic_id_bytes.len() + self.reason.len(),
+        );
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.put_u32_le(self.partition_id);
+        bytes.put_u64_le(self.offset);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.reason.len() as u8);
+        bytes.extend(self.reason.as_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<RejectMessage, Error> {
+        if bytes.len() < 19 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes)?;
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes() as usize;
+        if bytes.len() < position + 13 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let offset = u64::from_le_bytes(bytes[position + 4..position + 12].try_into()?);
+        let reason_length = bytes[position + 12] as usize;
+        position += 13;
+        if bytes.len() != position + reason_length {
+            return Err(Error::InvalidCommand);
+        }
+
+        let reason = from_utf8(&bytes[position..position + reason_length])?.to_string();
+        let command = RejectMessage {
+            stream_id,
+            topic_id,
+            partition_id,
+            offset,
+            reason,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for RejectMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.partition_id, self.offset, self.reason
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = RejectMessage {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partition_id: 3,
+            offset: 4,
+            reason: "invalid payload".to_string(),
+        };
+
+        let bytes = command.as_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += topic_id.get_size_bytes() as usize;
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let offset = u64::from_le_bytes(bytes[position + 4..position + 12].try_into().unwrap());
+        let reason_length = bytes[position + 12] as usize;
+        let reason = from_utf8(&bytes[position + 13..position + 13 + reason_length]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(partition_id, command.partition_id);
+        assert_eq!(offset, command.offset);
+        assert_eq!(reason, command.reason);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::named("topic").unwrap();
+        let partition_id = 3u32;
+        let offset = 4u64;
+        let reason = "invalid payload";
+
+        let stream_id_bytes = stream_id.as_bytes();
+        let topic_id_bytes = topic_id.as_bytes();
+        let mut bytes =
+            Vec::with_capacity(13 + stream_id_bytes.len() + topic_id_bytes.len() + reason.len());
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.put_u32_le(partition_id);
+        bytes.put_u64_le(offset);
+        bytes.put_u8(reason.len() as u8);
+        bytes.extend(reason.as_bytes());
+
+        let command = RejectMessage::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, partition_id);
+        assert_eq!(command.offset, offset);
+        assert_eq!(command.reason, reason);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_too_long_reason() {
+        let command = RejectMessage {
+            reason: "a".repeat(MAX_REASON_LENGTH + 1),
+            ..RejectMessage::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
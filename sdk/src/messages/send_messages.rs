@@ -16,12 +16,22 @@ use std::str::FromStr;
 
 const EMPTY_KEY_VALUE: Vec<u8> = vec![];
 
+/// Header storing the key of the message, used to keep only its latest value in the compacted topics.
+/// It's set by the server based on the `MessagesKey` partitioning, unless already provided by the producer.
+pub const MESSAGE_KEY_HEADER: &str = "iggy-message-key";
+
 /// `SendMessages` command is used to send messages to a topic in a stream.
 /// It has additional payload:
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `partitioning` - to which partition the messages should be sent - either provided by the client or calculated by the server.
+/// - `ack` - acknowledgement level, when the server should respond to the request (if at all).
 /// - `messages` - collection of messages to be sent.
+///
+/// The binary layout is: `stream_id | topic_id | partitioning | messages | [ack]`.
+/// The acknowledgement level is encoded as the optional trailing byte, only if it's different from the default `InMemory`,
+/// so the commands sent with the default level remain compatible with the servers which don't support it,
+/// while the other levels are rejected by such servers instead of being silently ignored.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct SendMessages {
     /// Unique stream ID (numeric or name).
@@ -32,10 +42,29 @@ pub struct SendMessages {
     pub topic_id: Identifier,
     /// To which partition the messages should be sent - either provided by the client or calculated by the server.
     pub partitioning: Partitioning,
+    /// Acknowledgement level, when the server should respond to the request (if at all).
+    #[serde(default)]
+    pub ack: AckLevel,
     /// Collection of messages to be sent.
     pub messages: Vec<Message>,
 }
 
+/// `AckLevel` specifies when the server acknowledges the sent messages:
+/// - `None` - the server doesn't respond at all (fire-and-forget), thus any errors are not returned to the client.
+/// - `InMemory` - the server responds once the messages are appended to the in-memory buffer of the partition.
+/// - `Persisted` - the server responds once the messages are written to the segment on disk and synced (fsync).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum AckLevel {
+    /// The server doesn't respond at all (fire-and-forget).
+    None,
+    /// The server responds once the messages are appended to the in-memory buffer of the partition.
+    #[default]
+    InMemory,
+    /// The server responds once the messages are written to the segment on disk and synced (fsync).
+    Persisted,
+}
+
 /// `Partitioning` is used to specify to which partition the messages should be sent.
 /// It has the following kinds:
 /// - `Balanced` - the partition ID is calculated by the server using the round-robin algorithm.
@@ -98,6 +127,7 @@ impl Default for SendMessages {
             stream_id: Identifier::default(),
             topic_id: Identifier::default(),
             partitioning: Partitioning::default(),
+            ack: AckLevel::default(),
             messages: vec![Message::default()],
         }
     }
@@ -251,6 +281,39 @@ impl PartitioningKind {
     }
 }
 
+impl AckLevel {
+    /// Get the code of the acknowledgement level.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            AckLevel::None => 1,
+            AckLevel::InMemory => 2,
+            AckLevel::Persisted => 3,
+        }
+    }
+
+    /// Get the acknowledgement level from the provided code.
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            1 => Ok(AckLevel::None),
+            2 => Ok(AckLevel::InMemory),
+            3 => Ok(AckLevel::Persisted),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for AckLevel {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "none" => Ok(AckLevel::None),
+            "in_memory" | "in-memory" => Ok(AckLevel::InMemory),
+            "persisted" => Ok(AckLevel::Persisted),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
 impl Message {
     /// Create a new message with the optional ID, payload and headers.
     pub fn new(
@@ -411,7 +474,10 @@ impl BytesSerializable for SendMessages {
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
         let mut bytes = Vec::with_capacity(
-            stream_id_bytes.len() + topic_id_bytes.len() + key_bytes.len() + messages_size as usize,
+            1 + stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + key_bytes.len()
+                + messages_size as usize,
         );
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
@@ -419,12 +485,15 @@ impl BytesSerializable for SendMessages {
         for message in &self.messages {
             bytes.extend(message.as_bytes());
         }
+        if self.ack != AckLevel::default() {
+            bytes.put_u8(self.ack.as_code());
+        }
 
         bytes
     }
 
     fn from_bytes(bytes: &[u8]) -> Result<SendMessages, Error> {
-        if bytes.len() < 11 {
+        if bytes.len() < 12 {
             return Err(Error::InvalidCommand);
         }
 
@@ -437,8 +506,15 @@ impl BytesSerializable for SendMessages {
         position += key.get_size_bytes() as usize;
         let messages_payloads = &bytes[position..];
         position = 0;
+        let mut ack = AckLevel::default();
         let mut messages = Vec::new();
         while position < messages_payloads.len() {
+            // The single remaining byte can't be a message, thus it's the optional acknowledgement level.
+            if messages_payloads.len() - position == 1 {
+                ack = AckLevel::from_code(messages_payloads[position])?;
+                break;
+            }
+
             let message = Message::from_bytes(&messages_payloads[position..])?;
             position += message.get_size_bytes() as usize;
             messages.push(message);
@@ -448,6 +524,7 @@ impl BytesSerializable for SendMessages {
             stream_id,
             topic_id,
             partitioning: key,
+            ack,
             messages,
         };
         command.validate()?;
@@ -459,10 +536,11 @@ impl Display for SendMessages {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.partitioning,
+            self.ack,
             self.messages
                 .iter()
                 .map(std::string::ToString::to_string)
@@ -489,6 +567,16 @@ impl Display for Partitioning {
     }
 }
 
+impl Display for AckLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AckLevel::None => write!(f, "none"),
+            AckLevel::InMemory => write!(f, "in_memory"),
+            AckLevel::Persisted => write!(f, "persisted"),
+        }
+    }
+}
+
 impl Display for PartitioningKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -513,6 +601,7 @@ mod tests {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
             partitioning: Partitioning::partition_id(4),
+            ack: AckLevel::Persisted,
             messages,
         };
 
@@ -525,7 +614,8 @@ mod tests {
         position += topic_id.get_size_bytes() as usize;
         let key = Partitioning::from_bytes(&bytes[position..]).unwrap();
         position += key.get_size_bytes() as usize;
-        let messages = &bytes[position..];
+        let ack = AckLevel::from_code(bytes[bytes.len() - 1]).unwrap();
+        let messages = &bytes[position..bytes.len() - 1];
         let command_messages = &command
             .messages
             .iter()
@@ -537,6 +627,7 @@ mod tests {
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(key, command.partitioning);
+        assert_eq!(ack, command.ack);
         assert_eq!(messages, command_messages);
     }
 
@@ -545,6 +636,7 @@ mod tests {
         let stream_id = Identifier::numeric(1).unwrap();
         let topic_id = Identifier::numeric(2).unwrap();
         let key = Partitioning::partition_id(4);
+        let ack = AckLevel::None;
 
         let message_1 = Message::from_str("hello 1").unwrap();
         let message_2 = Message::new(Some(2), "hello 2".into(), None);
@@ -560,16 +652,17 @@ mod tests {
         let stream_id_bytes = stream_id.as_bytes();
         let topic_id_bytes = topic_id.as_bytes();
         let current_position = stream_id_bytes.len() + topic_id_bytes.len() + key_bytes.len();
-        let mut bytes = Vec::with_capacity(current_position);
+        let mut bytes = Vec::with_capacity(current_position + messages.len() + 1);
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
         bytes.extend(key_bytes);
         bytes.extend(messages);
+        bytes.put_u8(ack.as_code());
 
         let command = SendMessages::from_bytes(&bytes);
         assert!(command.is_ok());
 
-        let messages_payloads = &bytes[current_position..];
+        let messages_payloads = &bytes[current_position..bytes.len() - 1];
         let mut position = 0;
         let mut messages = Vec::new();
         while position < messages_payloads.len() {
@@ -582,6 +675,7 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partitioning, key);
+        assert_eq!(command.ack, ack);
         for (index, message) in command.messages.iter().enumerate() {
             let command_message = &command.messages[index];
             assert_eq!(command_message.id, message.id);
@@ -590,6 +684,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn default_ack_level_should_not_be_serialized() {
+        let command = SendMessages {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partitioning: Partitioning::partition_id(4),
+            ack: AckLevel::InMemory,
+            messages: vec![Message::from_str("hello").unwrap()],
+        };
+
+        let bytes = command.as_bytes();
+
+        let expected_bytes = [
+            command.stream_id.as_bytes(),
+            command.topic_id.as_bytes(),
+            command.partitioning.as_bytes(),
+            command.messages[0].as_bytes(),
+        ]
+        .concat();
+        assert_eq!(bytes, expected_bytes);
+        assert_eq!(SendMessages::from_bytes(&bytes).unwrap(), command);
+    }
+
     #[test]
     fn key_of_type_balanced_should_have_empty_value() {
         let key = Partitioning::balanced();
@@ -641,4 +758,12 @@ mod tests {
         let key = Partitioning::messages_key_str(&messages_key);
         assert!(key.is_err());
     }
+
+    #[test]
+    fn ack_level_should_be_mapped_from_code() {
+        for ack in [AckLevel::None, AckLevel::InMemory, AckLevel::Persisted] {
+            assert_eq!(AckLevel::from_code(ack.as_code()).unwrap(), ack);
+        }
+        assert!(AckLevel::from_code(0).is_err());
+    }
 }
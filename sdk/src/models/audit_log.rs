@@ -0,0 +1,71 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `AuditLogEntry` represents a single administrative or security-relevant action recorded by the server.
+/// It consists of the following fields:
+/// - `timestamp`: the timestamp of the action in microseconds.
+/// - `user_id`: the unique identifier of the user performing the action, 0 if the client was not authenticated.
+/// - `address`: the remote address of the client.
+/// - `transport`: the transport protocol used by the client.
+/// - `command`: the name of the command, e.g. `stream.create`.
+/// - `details`: the arguments of the command, without any secrets.
+/// - `outcome`: the outcome of the action.
+/// - `error`: the error returned by the server, if the action failed.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct AuditLogEntry {
+    /// The timestamp of the action in microseconds.
+    pub timestamp: u64,
+    /// The unique identifier of the user performing the action, 0 if the client was not authenticated.
+    pub user_id: u32,
+    /// The remote address of the client.
+    pub address: String,
+    /// The transport protocol used by the client.
+    pub transport: String,
+    /// The name of the command, e.g. `stream.create`.
+    pub command: String,
+    /// The arguments of the command, without any secrets.
+    pub details: String,
+    /// The outcome of the action.
+    pub outcome: AuditOutcome,
+    /// The error returned by the server, if the action failed.
+    pub error: Option<String>,
+}
+
+/// `AuditOutcome` represents the outcome of the audited action.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+    /// The action succeeded.
+    Success,
+    /// The action failed.
+    Failure,
+}
+
+impl Display for AuditOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditOutcome::Success => write!(f, "success"),
+            AuditOutcome::Failure => write!(f, "failure"),
+        }
+    }
+}
+
+impl AuditOutcome {
+    /// Returns the code of the audit outcome.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            AuditOutcome::Success => 1,
+            AuditOutcome::Failure => 2,
+        }
+    }
+
+    /// Returns the audit outcome from the code.
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            1 => Ok(AuditOutcome::Success),
+            2 => Ok(AuditOutcome::Failure),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
@@ -29,6 +29,7 @@ pub struct ClientInfo {
 /// - `transport`: the transport protocol used by the client.
 /// - `consumer_groups_count`: the number of consumer groups the client is part of.
 /// - `consumer_groups`: the collection of consumer groups the client is part of.
+/// - `quotas`: the usage of the quotas by the client. This field is optional, as the quotas might be disabled.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ClientInfoDetails {
     /// The unique identifier of the client.
@@ -43,6 +44,8 @@ pub struct ClientInfoDetails {
     pub consumer_groups_count: u32,
     /// The collection of consumer groups the client is part of.
     pub consumer_groups: Vec<ConsumerGroupInfo>,
+    /// The usage of the quotas by the client. This field is optional, as the quotas might be disabled.
+    pub quotas: Option<ClientQuotasInfo>,
 }
 
 /// `ConsumerGroupInfo` represents the information about a consumer group.
@@ -59,3 +62,54 @@ pub struct ConsumerGroupInfo {
     /// The unique identifier (numeric) of the consumer group.
     pub consumer_group_id: u32,
 }
+
+/// `ClientQuotasInfo` represents the usage of the quotas by a client.
+/// It consists of the following fields:
+/// - `throttled_requests`: the number of the client's requests delayed due to the exceeded quotas.
+/// - `rejected_requests`: the number of the client's requests rejected due to the exceeded quotas.
+/// - `user`: the usage of the quotas shared by all the clients of the same user.
+/// - `client`: the usage of the quotas of the client.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct ClientQuotasInfo {
+    /// The number of the client's requests delayed due to the exceeded quotas.
+    pub throttled_requests: u64,
+    /// The number of the client's requests rejected due to the exceeded quotas.
+    pub rejected_requests: u64,
+    /// The usage of the quotas shared by all the clients of the same user.
+    pub user: QuotasUsageInfo,
+    /// The usage of the quotas of the client.
+    pub client: QuotasUsageInfo,
+}
+
+/// `QuotasUsageInfo` represents the usage of the per second quotas.
+/// It consists of the following fields:
+/// - `requests`: the usage of the requests quota.
+/// - `sent_messages`: the usage of the sent messages quota.
+/// - `sent_bytes`: the usage of the sent bytes quota.
+/// - `polled_messages`: the usage of the polled messages quota.
+/// - `polled_bytes`: the usage of the polled bytes quota.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct QuotasUsageInfo {
+    /// The usage of the requests quota.
+    pub requests: QuotaUsageInfo,
+    /// The usage of the sent messages quota.
+    pub sent_messages: QuotaUsageInfo,
+    /// The usage of the sent bytes quota.
+    pub sent_bytes: QuotaUsageInfo,
+    /// The usage of the polled messages quota.
+    pub polled_messages: QuotaUsageInfo,
+    /// The usage of the polled bytes quota.
+    pub polled_bytes: QuotaUsageInfo,
+}
+
+/// `QuotaUsageInfo` represents the usage of a single per second quota.
+/// It consists of the following fields:
+/// - `limit`: the configured limit per second, 0 means unlimited.
+/// - `used`: the amount used within the last second, which might exceed the limit when the requests are throttled.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+pub struct QuotaUsageInfo {
+    /// The configured limit per second, 0 means unlimited.
+    pub limit: u64,
+    /// The amount used within the last second, which might exceed the limit when the requests are throttled.
+    pub used: u64,
+}
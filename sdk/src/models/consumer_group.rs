@@ -1,4 +1,7 @@
+use crate::error::Error;
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
 
 /// `ConsumerGroup` represents the information about a consumer group.
 /// It consists of the following fields:
@@ -24,6 +27,11 @@ pub struct ConsumerGroup {
 /// - `name`: the name of the consumer group.
 /// - `partitions_count`: the number of partitions the consumer group is consuming.
 /// - `members_count`: the number of members in the consumer group.
+/// - `generation`: the generation of the partitions assignment, incremented on each rebalance.
+/// - `state`: the state of the consumer group, either stable or rebalancing.
+/// - `session_timeout`: the session timeout of the members in seconds, 0 if the default one configured on the server is used.
+/// - `assignment_strategy`: the strategy used to assign the partitions to the members.
+/// - `members`: the collection of members in the consumer group.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ConsumerGroupDetails {
     /// The unique identifier (numeric) of the consumer group.
@@ -34,6 +42,14 @@ pub struct ConsumerGroupDetails {
     pub partitions_count: u32,
     /// The number of members in the consumer group.
     pub members_count: u32,
+    /// The generation of the partitions assignment, incremented on each rebalance.
+    pub generation: u32,
+    /// The state of the consumer group, either stable or rebalancing.
+    pub state: ConsumerGroupState,
+    /// The session timeout of the members in seconds, 0 if the default one configured on the server is used.
+    pub session_timeout: u32,
+    /// The strategy used to assign the partitions to the members.
+    pub assignment_strategy: PartitionAssignmentStrategy,
     /// The collection of members in the consumer group.
     pub members: Vec<ConsumerGroupMember>,
 }
@@ -52,3 +68,136 @@ pub struct ConsumerGroupMember {
     /// The collection of partitions the consumer group member is consuming.
     pub partitions: Vec<u32>,
 }
+
+/// `ConsumerGroupAssignment` represents the partitions assigned to the consumer group member in the current generation.
+/// It consists of the following fields:
+/// - `consumer_group_id`: the unique identifier (numeric) of the consumer group.
+/// - `generation`: the generation of the partitions assignment acknowledged by the member.
+/// - `state`: the state of the consumer group, either stable or rebalancing.
+/// - `partitions`: the collection of partitions assigned to the member.
+/// - `pending_partitions`: the collection of assigned partitions which are still owned by their previous members, and cannot be polled yet.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConsumerGroupAssignment {
+    /// The unique identifier (numeric) of the consumer group.
+    pub consumer_group_id: u32,
+    /// The generation of the partitions assignment acknowledged by the member.
+    pub generation: u32,
+    /// The state of the consumer group, either stable or rebalancing.
+    pub state: ConsumerGroupState,
+    /// The collection of partitions assigned to the member.
+    pub partitions: Vec<u32>,
+    /// The collection of assigned partitions which are still owned by their previous members, and cannot be polled yet.
+    pub pending_partitions: Vec<u32>,
+}
+
+/// `ConsumerGroupOffsetReset` represents the offset of the consumer group in a partition before and after the reset.
+/// Both offsets are expressed as the offset of the next message to be polled by the consumer group.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `previous_offset`: the offset of the next message to be polled before the reset.
+/// - `new_offset`: the offset of the next message to be polled after the reset.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ConsumerGroupOffsetReset {
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The offset of the next message to be polled before the reset.
+    pub previous_offset: u64,
+    /// The offset of the next message to be polled after the reset.
+    pub new_offset: u64,
+}
+
+/// `ConsumerGroupState` represents the state of the consumer group.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsumerGroupState {
+    /// All the members have acknowledged the current generation of the partitions assignment.
+    #[default]
+    Stable,
+    /// The partitions are being reassigned, and some members haven't acknowledged the current generation yet.
+    Rebalancing,
+}
+
+impl Display for ConsumerGroupState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConsumerGroupState::Stable => write!(f, "stable"),
+            ConsumerGroupState::Rebalancing => write!(f, "rebalancing"),
+        }
+    }
+}
+
+impl ConsumerGroupState {
+    /// Returns the code of the consumer group state.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            ConsumerGroupState::Stable => 1,
+            ConsumerGroupState::Rebalancing => 2,
+        }
+    }
+
+    /// Returns the consumer group state from the code.
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            1 => Ok(ConsumerGroupState::Stable),
+            2 => Ok(ConsumerGroupState::Rebalancing),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+/// `PartitionAssignmentStrategy` represents the strategy used to assign the partitions to the consumer group members.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitionAssignmentStrategy {
+    /// The partitions are distributed one by one across the members, e.g. 1, 4 to the first member, 2, 5 to the second one and 3 to the third one.
+    #[default]
+    RoundRobin,
+    /// Each member gets the contiguous range of the partitions, e.g. 1, 2 to the first member, 3, 4 to the second one and 5 to the third one.
+    Range,
+    /// The members keep as many of their current partitions as possible, and only the surplus ones are moved on membership change.
+    Sticky,
+}
+
+impl Display for PartitionAssignmentStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartitionAssignmentStrategy::RoundRobin => write!(f, "round_robin"),
+            PartitionAssignmentStrategy::Range => write!(f, "range"),
+            PartitionAssignmentStrategy::Sticky => write!(f, "sticky"),
+        }
+    }
+}
+
+impl FromStr for PartitionAssignmentStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "round_robin" | "round-robin" => Ok(PartitionAssignmentStrategy::RoundRobin),
+            "range" => Ok(PartitionAssignmentStrategy::Range),
+            "sticky" => Ok(PartitionAssignmentStrategy::Sticky),
+            _ => Err(format!("Unknown partition assignment strategy: {}", s)),
+        }
+    }
+}
+
+impl PartitionAssignmentStrategy {
+    /// Returns the code of the partition assignment strategy.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            PartitionAssignmentStrategy::RoundRobin => 1,
+            PartitionAssignmentStrategy::Range => 2,
+            PartitionAssignmentStrategy::Sticky => 3,
+        }
+    }
+
+    /// Returns the partition assignment strategy from the code.
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            1 => Ok(PartitionAssignmentStrategy::RoundRobin),
+            2 => Ok(PartitionAssignmentStrategy::Range),
+            3 => Ok(PartitionAssignmentStrategy::Sticky),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
@@ -562,11 +562,18 @@ impl BytesSerializable for HashMap<HeaderKey, HeaderValue> {
         let mut headers = Self::new();
         let mut position = 0;
         while position < bytes.len() {
+            // The headers might come from the untrusted data, e.g. the batch decompressed by the client.
+            if position + 4 > bytes.len() {
+                return Err(Error::InvalidHeaderKey);
+            }
             let key_length = u32::from_le_bytes(bytes[position..position + 4].try_into()?) as usize;
             if key_length == 0 || key_length > 255 {
                 return Err(Error::InvalidHeaderKey);
             }
             position += 4;
+            if position + key_length + 1 + 4 > bytes.len() {
+                return Err(Error::InvalidHeaderKey);
+            }
             let key = String::from_utf8(bytes[position..position + key_length].to_vec());
             if key.is_err() {
                 return Err(Error::InvalidHeaderKey);
@@ -581,6 +588,9 @@ impl BytesSerializable for HashMap<HeaderKey, HeaderValue> {
                 return Err(Error::InvalidHeaderValue);
             }
             position += 4;
+            if position + value_length > bytes.len() {
+                return Err(Error::InvalidHeaderValue);
+            }
             let value = bytes[position..position + value_length].to_vec();
             position += value_length;
             headers.insert(HeaderKey(key), HeaderValue { kind, value });
@@ -596,13 +606,18 @@ pub fn get_headers_size_bytes(headers: &Option<HashMap<HeaderKey, HeaderValue>>)
     let mut size = 4;
     if let Some(headers) = headers {
         for (key, value) in headers {
-            // Key length + Key + Kind + Value length + Value
-            size += 4 + key.as_str().len() as u32 + 1 + 4 + value.value.len() as u32;
+            size += get_header_size_bytes(key, value);
         }
     }
     size
 }
 
+/// Returns the size in bytes of the single header.
+pub fn get_header_size_bytes(key: &HeaderKey, value: &HeaderValue) -> u32 {
+    // Key length + Key + Kind + Value length + Value
+    4 + key.as_str().len() as u32 + 1 + 4 + value.value.len() as u32
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -62,7 +62,7 @@ pub struct Message {
     pub payload: Bytes,
 }
 
-/// The state of the message, currently only the `Available` state is used.
+/// The state of the message, currently the `Available` and `Poisoned` (rejected by the consumer) states are used.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum MessageState {
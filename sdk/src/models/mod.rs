@@ -1,3 +1,4 @@
+pub mod audit_log;
 pub mod client_info;
 pub mod consumer_group;
 pub mod consumer_offset_info;
@@ -5,8 +6,10 @@ pub mod header;
 pub mod identity_info;
 pub mod messages;
 pub mod partition;
+pub mod partition_offsets;
 pub mod permissions;
 pub mod personal_access_token;
+pub mod role_info;
 pub mod stats;
 pub mod stream;
 pub mod topic;
@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 /// - `current_offset`: the current offset of the partition.
 /// - `size_bytes`: the size of the partition in bytes.
 /// - `messages_count`: the number of messages in the partition.
+/// - `in_sync_replicas`: the IDs of the nodes which are in sync with the leader, empty if the replication is disabled.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Partition {
     /// Unique identifier of the partition.
@@ -22,4 +23,7 @@ pub struct Partition {
     pub size_bytes: u64,
     /// The number of messages in the partition.
     pub messages_count: u64,
+    /// The IDs of the nodes which are in sync with the leader, empty if the replication is disabled.
+    #[serde(default)]
+    pub in_sync_replicas: Vec<u32>,
 }
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+/// `PartitionOffsets` represents the range of the offsets available in a partition.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `earliest_offset`: the offset of the first message still stored in the partition.
+/// - `latest_offset`: the offset of the last message appended to the partition.
+///
+/// For the empty partition both offsets are equal to 0.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PartitionOffsets {
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The offset of the first message still stored in the partition.
+    pub earliest_offset: u64,
+    /// The offset of the last message appended to the partition.
+    pub latest_offset: u64,
+}
+
+/// `TimestampOffset` represents the offset corresponding to the given timestamp in a partition.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `offset`: the offset of the first message with the timestamp greater than or equal to the given one, if any.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct TimestampOffset {
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The offset of the first message with the timestamp greater than or equal to the given one, if any.
+    pub offset: Option<u64>,
+}
@@ -0,0 +1,38 @@
+use crate::models::permissions::Permissions;
+use serde::{Deserialize, Serialize};
+
+/// `RoleId` represents the unique identifier (numeric) of the role.
+pub type RoleId = u32;
+
+/// `RoleInfo` represents the basic information about the role.
+/// It consists of the following fields:
+/// - `id`: the unique identifier (numeric) of the role.
+/// - `created_at`: the timestamp when the role was created.
+/// - `name`: the unique name of the role.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleInfo {
+    /// The unique identifier (numeric) of the role.
+    pub id: RoleId,
+    /// The timestamp when the role was created.
+    pub created_at: u64,
+    /// The unique name of the role.
+    pub name: String,
+}
+
+/// `RoleInfoDetails` represents the detailed information about the role.
+/// It consists of the following fields:
+/// - `id`: the unique identifier (numeric) of the role.
+/// - `created_at`: the timestamp when the role was created.
+/// - `name`: the unique name of the role.
+/// - `permissions`: the permissions granted to all the users having the role.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleInfoDetails {
+    /// The unique identifier (numeric) of the role.
+    pub id: RoleId,
+    /// The timestamp when the role was created.
+    pub created_at: u64,
+    /// The unique name of the role.
+    pub name: String,
+    /// The permissions granted to all the users having the role.
+    pub permissions: Permissions,
+}
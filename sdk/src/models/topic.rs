@@ -1,5 +1,11 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::error::Error;
 use crate::{models::partition::Partition, utils::byte_size::IggyByteSize};
+use bytes::BufMut;
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
 
 /// `Topic` represents the medium level of logical separation of data as it's a part of the stream.
 /// It consists of the following fields:
@@ -10,6 +16,10 @@ use serde::{Deserialize, Serialize};
 /// - `message_expiry`: the optional expiry of the messages in the topic in seconds.
 /// - `max_topic_size`: the optional maximum size of the topic in bytes.
 /// - `replication_factor`: replication factor for the topic.
+/// - `compression_algorithm`: the compression algorithm used by the server to store the messages in the topic.
+/// - `cleanup_policy`: the policy used to clean up the messages in the topic.
+/// - `max_topic_size_policy`: the policy applied once the topic reaches its maximum size.
+/// - `flush_policy`: the policy controlling when the buffered messages of the topic are saved on disk.
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
 #[derive(Debug, Serialize, Deserialize)]
@@ -29,6 +39,17 @@ pub struct Topic {
     pub max_topic_size: Option<IggyByteSize>,
     /// Replication factor for the topic.
     pub replication_factor: u8,
+    /// The compression algorithm used by the server to store the messages in the topic.
+    pub compression_algorithm: CompressionAlgorithm,
+    /// The policy used to clean up the messages in the topic.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    /// The policy applied once the topic reaches its maximum size.
+    #[serde(default)]
+    pub max_topic_size_policy: MaxTopicSizePolicy,
+    /// The policy controlling when the buffered messages of the topic are saved on disk.
+    #[serde(default)]
+    pub flush_policy: FlushPolicy,
     /// The total number of messages in the topic.
     pub messages_count: u64,
     /// The total number of partitions in the topic.
@@ -44,6 +65,10 @@ pub struct Topic {
 /// - `message_expiry`: the optional expiry of the messages in the topic in seconds.
 /// - `max_topic_size`: the optional maximum size of the topic.
 /// - `replication_factor`: replication factor for the topic.
+/// - `compression_algorithm`: the compression algorithm used by the server to store the messages in the topic.
+/// - `cleanup_policy`: the policy used to clean up the messages in the topic.
+/// - `max_topic_size_policy`: the policy applied once the topic reaches its maximum size.
+/// - `flush_policy`: the policy controlling when the buffered messages of the topic are saved on disk.
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
 /// - `partitions`: the collection of partitions in the topic.
@@ -64,6 +89,17 @@ pub struct TopicDetails {
     pub max_topic_size: Option<IggyByteSize>,
     /// Replication factor for the topic.
     pub replication_factor: u8,
+    /// The compression algorithm used by the server to store the messages in the topic.
+    pub compression_algorithm: CompressionAlgorithm,
+    /// The policy used to clean up the messages in the topic.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    /// The policy applied once the topic reaches its maximum size.
+    #[serde(default)]
+    pub max_topic_size_policy: MaxTopicSizePolicy,
+    /// The policy controlling when the buffered messages of the topic are saved on disk.
+    #[serde(default)]
+    pub flush_policy: FlushPolicy,
     /// The total number of messages in the topic.
     pub messages_count: u64,
     /// The total number of partitions in the topic.
@@ -71,3 +107,181 @@ pub struct TopicDetails {
     /// The collection of partitions in the topic.
     pub partitions: Vec<Partition>,
 }
+
+/// `CleanupPolicy` represents the policy used to clean up the messages in the topic.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum CleanupPolicy {
+    /// The messages are deleted once they expire, together with the whole segments.
+    #[default]
+    Delete,
+    /// The closed segments are compacted, keeping only the latest message for each message key.
+    /// The message with an empty payload is a tombstone, which removes all the previous messages for its key.
+    Compact,
+}
+
+impl Display for CleanupPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CleanupPolicy::Delete => write!(f, "delete"),
+            CleanupPolicy::Compact => write!(f, "compact"),
+        }
+    }
+}
+
+impl FromStr for CleanupPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "delete" => Ok(CleanupPolicy::Delete),
+            "compact" => Ok(CleanupPolicy::Compact),
+            _ => Err(format!("Unknown cleanup policy: {}", s)),
+        }
+    }
+}
+
+impl CleanupPolicy {
+    /// Returns the code of the cleanup policy.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            CleanupPolicy::Delete => 1,
+            CleanupPolicy::Compact => 2,
+        }
+    }
+
+    /// Returns the cleanup policy from the code.
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            1 => Ok(CleanupPolicy::Delete),
+            2 => Ok(CleanupPolicy::Compact),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+/// `MaxTopicSizePolicy` represents the policy applied once the topic reaches its maximum size.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum MaxTopicSizePolicy {
+    /// The oldest closed segments across the partitions are deleted until the topic fits its maximum size.
+    #[default]
+    DeleteOldest,
+    /// The topic is bounded, appending the messages is rejected once it's full.
+    Reject,
+}
+
+impl Display for MaxTopicSizePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MaxTopicSizePolicy::DeleteOldest => write!(f, "delete_oldest"),
+            MaxTopicSizePolicy::Reject => write!(f, "reject"),
+        }
+    }
+}
+
+impl FromStr for MaxTopicSizePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "delete_oldest" => Ok(MaxTopicSizePolicy::DeleteOldest),
+            "reject" => Ok(MaxTopicSizePolicy::Reject),
+            _ => Err(format!("Unknown max topic size policy: {}", s)),
+        }
+    }
+}
+
+impl MaxTopicSizePolicy {
+    /// Returns the code of the max topic size policy.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            MaxTopicSizePolicy::DeleteOldest => 1,
+            MaxTopicSizePolicy::Reject => 2,
+        }
+    }
+
+    /// Returns the max topic size policy from the code.
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            1 => Ok(MaxTopicSizePolicy::DeleteOldest),
+            2 => Ok(MaxTopicSizePolicy::Reject),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+/// The size of the serialized `FlushPolicy` in bytes.
+pub const FLUSH_POLICY_SIZE: usize = 16;
+
+/// `FlushPolicy` controls when the buffered messages of each partition in the topic are saved on disk.
+/// The messages are saved once any of the configured triggers is reached, the unset triggers fall back to the server configuration.
+/// It consists of the following fields:
+/// - `messages_count`: the number of the buffered messages triggering the save, if `None` then `partition.messages_required_to_save` is used.
+/// - `size`: the size of the buffered messages triggering the save, if `None` then the size is not taken into account.
+/// - `max_latency`: the maximum time in milliseconds the messages can stay buffered, if `None` then they're saved by the message saver.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+pub struct FlushPolicy {
+    /// The number of the buffered messages triggering the save.
+    pub messages_count: Option<u32>,
+    /// The size of the buffered messages triggering the save.
+    pub size: Option<IggyByteSize>,
+    /// The maximum time in milliseconds the messages can stay buffered.
+    pub max_latency: Option<u32>,
+}
+
+impl Display for FlushPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let messages_count = match self.messages_count {
+            Some(messages_count) => messages_count.to_string(),
+            None => "default".to_string(),
+        };
+        let size = match self.size {
+            Some(size) => size.to_string(),
+            None => "unlimited".to_string(),
+        };
+        let max_latency = match self.max_latency {
+            Some(max_latency) => format!("{} ms", max_latency),
+            None => "default".to_string(),
+        };
+        write!(
+            f,
+            "messages count: {}, size: {}, max latency: {}",
+            messages_count, size, max_latency
+        )
+    }
+}
+
+impl BytesSerializable for FlushPolicy {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FLUSH_POLICY_SIZE);
+        bytes.put_u32_le(self.messages_count.unwrap_or(0));
+        bytes.put_u64_le(self.size.map(|size| size.as_bytes_u64()).unwrap_or(0));
+        bytes.put_u32_le(self.max_latency.unwrap_or(0));
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < FLUSH_POLICY_SIZE {
+            return Err(Error::InvalidCommand);
+        }
+
+        let messages_count = match u32::from_le_bytes(bytes[0..4].try_into()?) {
+            0 => None,
+            messages_count => Some(messages_count),
+        };
+        let size = match u64::from_le_bytes(bytes[4..12].try_into()?) {
+            0 => None,
+            size => Some(IggyByteSize::from(size)),
+        };
+        let max_latency = match u32::from_le_bytes(bytes[12..16].try_into()?) {
+            0 => None,
+            max_latency => Some(max_latency),
+        };
+        Ok(FlushPolicy {
+            messages_count,
+            size,
+            max_latency,
+        })
+    }
+}
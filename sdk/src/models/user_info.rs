@@ -1,4 +1,5 @@
 use crate::models::permissions::Permissions;
+use crate::models::role_info::RoleId;
 use crate::models::user_status::UserStatus;
 use serde::{Deserialize, Serialize};
 use std::sync::atomic::AtomicU32;
@@ -34,6 +35,8 @@ pub struct UserInfo {
 /// - `status`: the status of the user.
 /// - `username`: the username of the user.
 /// - `permissions`: the optional permissions of the user.
+/// - `roles`: the IDs of the roles assigned to the user.
+/// - `locked_until`: the optional timestamp until which the user is locked due to too many failed login attempts.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UserInfoDetails {
     /// The unique identifier (numeric) of the user.
@@ -46,4 +49,9 @@ pub struct UserInfoDetails {
     pub username: String,
     /// The optional permissions of the user.
     pub permissions: Option<Permissions>,
+    /// The IDs of the roles assigned to the user, their permissions are granted in addition to the user's ones.
+    pub roles: Vec<RoleId>,
+    /// The optional timestamp until which the user is locked due to too many failed login attempts.
+    #[serde(default)]
+    pub locked_until: Option<u64>,
 }
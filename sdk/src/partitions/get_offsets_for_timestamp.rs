@@ -0,0 +1,118 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetOffsetsForTimestamp` command is used to find the offset corresponding to the given timestamp in each partition of a topic.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `timestamp` - timestamp (in microseconds) for which the offset of the first message appended at or after it is returned.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct GetOffsetsForTimestamp {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Timestamp (in microseconds) for which the offset of the first message appended at or after it is returned.
+    pub timestamp: u64,
+}
+
+impl CommandPayload for GetOffsetsForTimestamp {}
+
+impl Validatable<Error> for GetOffsetsForTimestamp {
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetOffsetsForTimestamp {
+    fn as_bytes(&self) -> Vec<u8> {
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let mut bytes = Vec::with_capacity(8 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.put_u64_le(self.timestamp);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> std::result::Result<GetOffsetsForTimestamp, Error> {
+        if bytes.len() < 14 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes)?;
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes() as usize;
+        let timestamp = u64::from_le_bytes(bytes[position..position + 8].try_into()?);
+        let command = GetOffsetsForTimestamp {
+            stream_id,
+            topic_id,
+            timestamp,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetOffsetsForTimestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}|{}", self.stream_id, self.topic_id, self.timestamp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetOffsetsForTimestamp {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            timestamp: 1_700_000_000_000_000,
+        };
+
+        let bytes = command.as_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += topic_id.get_size_bytes() as usize;
+        let timestamp = u64::from_le_bytes(bytes[position..position + 8].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(timestamp, command.timestamp);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let timestamp = 1_700_000_000_000_000u64;
+        let stream_id_bytes = stream_id.as_bytes();
+        let topic_id_bytes = topic_id.as_bytes();
+        let mut bytes = Vec::with_capacity(8 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.put_u64_le(timestamp);
+        let command = GetOffsetsForTimestamp::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.timestamp, timestamp);
+    }
+}
@@ -6,7 +6,9 @@ use async_trait::async_trait;
 use bytes::BufMut;
 use quinn::{ClientConfig, Connection, Endpoint, IdleTimeout, RecvStream, VarInt};
 use rustls::client::{ServerCertVerified, ServerCertVerifier};
-use rustls::{Certificate, ServerName};
+use rustls::{Certificate, PrivateKey, RootCertStore, ServerName};
+use std::fs::File;
+use std::io::BufReader;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
@@ -83,7 +85,11 @@ impl Client for QuicClient {
             break;
         }
 
-        self.set_state(ClientState::Connected).await;
+        // The user mapped to the client certificate is logged in by the server during the handshake.
+        match self.config.client_certificate.is_some() {
+            true => self.set_state(ClientState::Authenticated).await,
+            false => self.set_state(ClientState::Connected).await,
+        }
         self.connection.lock().await.replace(connection);
 
         Ok(())
@@ -120,13 +126,7 @@ impl BinaryClient for QuicClient {
 
         let connection = self.connection.lock().await;
         if let Some(connection) = connection.as_ref() {
-            let payload_length = payload.len() + 4;
-            let mut buffer = Vec::with_capacity(REQUEST_INITIAL_BYTES_LENGTH + payload_length);
-            #[allow(clippy::cast_possible_truncation)]
-            buffer.put_u32_le(payload_length as u32);
-            buffer.put_u32_le(command);
-            buffer.extend(payload);
-
+            let buffer = Self::create_request(command, payload);
             let (mut send, mut recv) = connection.open_bi().await?;
             send.write_all(&buffer).await?;
             send.finish().await?;
@@ -136,6 +136,24 @@ impl BinaryClient for QuicClient {
         error!("Cannot send data. Client is not connected.");
         Err(Error::NotConnected)
     }
+
+    async fn send_without_response(&self, command: u32, payload: &[u8]) -> Result<(), Error> {
+        if self.get_state().await == ClientState::Disconnected {
+            return Err(Error::NotConnected);
+        }
+
+        let connection = self.connection.lock().await;
+        if let Some(connection) = connection.as_ref() {
+            let buffer = Self::create_request(command, payload);
+            let (mut send, _) = connection.open_bi().await?;
+            send.write_all(&buffer).await?;
+            send.finish().await?;
+            return Ok(());
+        }
+
+        error!("Cannot send data. Client is not connected.");
+        Err(Error::NotConnected)
+    }
 }
 
 impl QuicClient {
@@ -186,6 +204,16 @@ impl QuicClient {
         })
     }
 
+    fn create_request(command: u32, payload: &[u8]) -> Vec<u8> {
+        let payload_length = payload.len() + 4;
+        let mut buffer = Vec::with_capacity(REQUEST_INITIAL_BYTES_LENGTH + payload_length);
+        #[allow(clippy::cast_possible_truncation)]
+        buffer.put_u32_le(payload_length as u32);
+        buffer.put_u32_le(command);
+        buffer.extend(payload);
+        buffer
+    }
+
     async fn handle_response(&self, recv: &mut RecvStream) -> Result<Vec<u8>, Error> {
         let buffer = recv
             .read_to_end(self.config.response_buffer_size as usize)
@@ -253,19 +281,84 @@ fn configure(config: &QuicClientConfig) -> Result<ClientConfig, Error> {
         transport.max_idle_timeout(Some(max_idle_timeout.unwrap()));
     }
 
-    let mut client_config = match config.validate_certificate {
-        true => ClientConfig::with_native_roots(),
-        false => ClientConfig::new(Arc::new(
+    let client_identity = load_client_identity(config)?;
+    let mut client_config = match (config.validate_certificate, client_identity) {
+        (true, None) => ClientConfig::with_native_roots(),
+        (false, None) => ClientConfig::new(Arc::new(
             rustls::ClientConfig::builder()
                 .with_safe_defaults()
                 .with_custom_certificate_verifier(SkipServerVerification::new())
                 .with_no_client_auth(),
         )),
+        (validate_certificate, Some((certificates, key))) => {
+            let builder = rustls::ClientConfig::builder().with_safe_defaults();
+            let crypto = match validate_certificate {
+                true => builder
+                    .with_root_certificates(load_native_roots()?)
+                    .with_client_auth_cert(certificates, key),
+                false => builder
+                    .with_custom_certificate_verifier(SkipServerVerification::new())
+                    .with_client_auth_cert(certificates, key),
+            };
+            if let Err(error) = crypto {
+                error!("Invalid client certificate or key, error: {error}");
+                return Err(Error::InvalidConfiguration);
+            }
+            ClientConfig::new(Arc::new(crypto.unwrap()))
+        }
     };
     client_config.transport_config(Arc::new(transport));
     Ok(client_config)
 }
 
+fn load_client_identity(
+    config: &QuicClientConfig,
+) -> Result<Option<(Vec<Certificate>, PrivateKey)>, Error> {
+    let (certificate_path, key_path) = match (&config.client_certificate, &config.client_key) {
+        (Some(certificate_path), Some(key_path)) => (certificate_path, key_path),
+        (None, None) => return Ok(None),
+        _ => {
+            error!("Both client certificate and key are required for the client authentication.");
+            return Err(Error::InvalidConfiguration);
+        }
+    };
+
+    let certificates = File::open(certificate_path).map(|file| {
+        rustls_pemfile::certs(&mut BufReader::new(file))
+            .map(|certificate| certificate.map(|certificate| Certificate(certificate.to_vec())))
+            .collect::<Result<Vec<_>, _>>()
+    });
+    let key =
+        File::open(key_path).map(|file| rustls_pemfile::private_key(&mut BufReader::new(file)));
+    match (certificates, key) {
+        (Ok(Ok(certificates)), Ok(Ok(Some(key)))) if !certificates.is_empty() => {
+            Ok(Some((certificates, PrivateKey(key.secret_der().to_vec()))))
+        }
+        _ => {
+            error!("Cannot load client certificate: {certificate_path} or key: {key_path}");
+            Err(Error::InvalidConfiguration)
+        }
+    }
+}
+
+fn load_native_roots() -> Result<RootCertStore, Error> {
+    let certificates = rustls_native_certs::load_native_certs();
+    if let Err(error) = certificates {
+        error!("Cannot load native root certificates, error: {error}");
+        return Err(Error::InvalidConfiguration);
+    }
+
+    let mut roots = RootCertStore::empty();
+    roots.add_parsable_certificates(
+        &certificates
+            .unwrap()
+            .into_iter()
+            .map(|certificate| certificate.0)
+            .collect::<Vec<_>>(),
+    );
+    Ok(roots)
+}
+
 #[derive(Debug)]
 struct SkipServerVerification;
 
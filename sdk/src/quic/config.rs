@@ -29,6 +29,10 @@ pub struct QuicClientConfig {
     pub max_idle_timeout: u64,
     /// Whether to validate the server certificate.
     pub validate_certificate: bool,
+    /// The optional path to the PEM client certificate used for the mutual TLS authentication.
+    pub client_certificate: Option<String>,
+    /// The optional path to the PEM private key of the client certificate.
+    pub client_key: Option<String>,
 }
 
 impl Default for QuicClientConfig {
@@ -48,6 +52,8 @@ impl Default for QuicClientConfig {
             keep_alive_interval: 5000,
             max_idle_timeout: 10000,
             validate_certificate: false,
+            client_certificate: None,
+            client_key: None,
         }
     }
 }
@@ -1,7 +1,9 @@
 use crate::binary;
 use crate::client::MessageClient;
 use crate::error::Error;
+use crate::messages::fetch_replica_messages::FetchReplicaMessages;
 use crate::messages::poll_messages::PollMessages;
+use crate::messages::reject_message::RejectMessage;
 use crate::messages::send_messages::SendMessages;
 use crate::models::messages::PolledMessages;
 use crate::quic::client::QuicClient;
@@ -16,4 +18,15 @@ impl MessageClient for QuicClient {
     async fn send_messages(&self, command: &mut SendMessages) -> Result<(), Error> {
         binary::messages::send_messages(self, command).await
     }
+
+    async fn reject_message(&self, command: &RejectMessage) -> Result<(), Error> {
+        binary::messages::reject_message(self, command).await
+    }
+
+    async fn fetch_replica_messages(
+        &self,
+        command: &FetchReplicaMessages,
+    ) -> Result<PolledMessages, Error> {
+        binary::messages::fetch_replica_messages(self, command).await
+    }
 }
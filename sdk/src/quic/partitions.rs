@@ -1,8 +1,11 @@
 use crate::binary;
 use crate::client::PartitionClient;
 use crate::error::Error;
+use crate::models::partition_offsets::{PartitionOffsets, TimestampOffset};
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use crate::partitions::get_partition_offsets::GetPartitionOffsets;
 use crate::quic::client::QuicClient;
 use async_trait::async_trait;
 
@@ -15,4 +18,18 @@ impl PartitionClient for QuicClient {
     async fn delete_partitions(&self, command: &DeletePartitions) -> Result<(), Error> {
         binary::partitions::delete_partitions(self, command).await
     }
+
+    async fn get_partition_offsets(
+        &self,
+        command: &GetPartitionOffsets,
+    ) -> Result<Vec<PartitionOffsets>, Error> {
+        binary::partitions::get_partition_offsets(self, command).await
+    }
+
+    async fn get_offsets_for_timestamp(
+        &self,
+        command: &GetOffsetsForTimestamp,
+    ) -> Result<Vec<TimestampOffset>, Error> {
+        binary::partitions::get_offsets_for_timestamp(self, command).await
+    }
 }
@@ -1,9 +1,11 @@
 use crate::binary;
 use crate::client::SystemClient;
 use crate::error::Error;
+use crate::models::audit_log::AuditLogEntry;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::stats::Stats;
 use crate::quic::client::QuicClient;
+use crate::system::get_audit_log::GetAuditLog;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
@@ -29,6 +31,10 @@ impl SystemClient for QuicClient {
         binary::system::get_clients(self, command).await
     }
 
+    async fn get_audit_log(&self, command: &GetAuditLog) -> Result<Vec<AuditLogEntry>, Error> {
+        binary::system::get_audit_log(self, command).await
+    }
+
     async fn ping(&self, command: &Ping) -> Result<(), Error> {
         binary::system::ping(self, command).await
     }
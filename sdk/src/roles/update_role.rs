@@ -0,0 +1,197 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::models::permissions::Permissions;
+use crate::roles::create_role::validate_role_name;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `UpdateRole` command is used to update a role's name and permissions.
+/// The updated permissions are applied immediately to all the users having the role.
+/// It has additional payload:
+/// - `role_id` - unique role ID (numeric or name).
+/// - `name` - new name (optional), if provided, must be between 3 and 50 characters long.
+/// - `permissions` - new permissions (optional)
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct UpdateRole {
+    /// Unique role ID (numeric or name).
+    #[serde(skip)]
+    pub role_id: Identifier,
+    /// New name (optional), if provided, must be between 3 and 50 characters long.
+    pub name: Option<String>,
+    /// New permissions (optional), if `None` is provided, then the existing role's permissions will be kept.
+    pub permissions: Option<Permissions>,
+}
+
+impl CommandPayload for UpdateRole {}
+
+impl Validatable<Error> for UpdateRole {
+    fn validate(&self) -> Result<(), Error> {
+        if let Some(name) = &self.name {
+            validate_role_name(name)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateRole {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend(self.role_id.as_bytes());
+        if let Some(name) = &self.name {
+            bytes.put_u8(1);
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u8(name.len() as u8);
+            bytes.extend(name.as_bytes());
+        } else {
+            bytes.put_u8(0);
+        }
+        if let Some(permissions) = &self.permissions {
+            bytes.put_u8(1);
+            let permissions = permissions.as_bytes();
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u32_le(permissions.len() as u32);
+            bytes.extend(permissions);
+        } else {
+            bytes.put_u8(0);
+        }
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<UpdateRole, Error> {
+        if bytes.len() < 5 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let role_id = Identifier::from_bytes(bytes)?;
+        let mut position = role_id.get_size_bytes() as usize;
+        let has_name = bytes[position];
+        if has_name > 1 {
+            return Err(Error::InvalidCommand);
+        }
+
+        position += 1;
+        let name = if has_name == 1 {
+            let name_length = bytes[position] as usize;
+            position += 1;
+            let name = from_utf8(&bytes[position..position + name_length])?.to_string();
+            position += name_length;
+            Some(name)
+        } else {
+            None
+        };
+
+        let has_permissions = bytes[position];
+        if has_permissions > 1 {
+            return Err(Error::InvalidCommand);
+        }
+
+        position += 1;
+        let permissions = if has_permissions == 1 {
+            let permissions_length =
+                u32::from_le_bytes(bytes[position..position + 4].try_into()?) as usize;
+            position += 4;
+            Some(Permissions::from_bytes(
+                &bytes[position..position + permissions_length],
+            )?)
+        } else {
+            None
+        };
+
+        let command = UpdateRole {
+            role_id,
+            name,
+            permissions,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for UpdateRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = self.name.as_deref().unwrap_or("");
+        let permissions = self
+            .permissions
+            .as_ref()
+            .map_or_else(String::new, |p| p.to_string());
+        write!(f, "{}|{name}|{permissions}", self.role_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::permissions::GlobalPermissions;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UpdateRole {
+            role_id: Identifier::numeric(1).unwrap(),
+            name: Some("writers".to_string()),
+            permissions: Some(get_permissions()),
+        };
+
+        let bytes = command.as_bytes();
+        let role_id = Identifier::from_bytes(&bytes).unwrap();
+        let mut position = role_id.get_size_bytes() as usize;
+        let has_name = bytes[position];
+        position += 1;
+        let name_length = bytes[position] as usize;
+        position += 1;
+        let name = from_utf8(&bytes[position..position + name_length]).unwrap();
+        position += name_length;
+        let has_permissions = bytes[position];
+        position += 1;
+        let permissions_length =
+            u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap()) as usize;
+        position += 4;
+        let permissions =
+            Permissions::from_bytes(&bytes[position..position + permissions_length]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(role_id, command.role_id);
+        assert_eq!(has_name, 1);
+        assert_eq!(name, command.name.unwrap());
+        assert_eq!(has_permissions, 1);
+        assert_eq!(permissions, command.permissions.unwrap());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let role_id = Identifier::named("readers").unwrap();
+        let permissions = get_permissions();
+        let mut bytes = Vec::new();
+        bytes.extend(role_id.as_bytes());
+        bytes.put_u8(0);
+        bytes.put_u8(1);
+        let permissions_bytes = permissions.as_bytes();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(permissions_bytes.len() as u32);
+        bytes.extend(permissions_bytes);
+
+        let command = UpdateRole::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.role_id, role_id);
+        assert!(command.name.is_none());
+        assert_eq!(command.permissions.unwrap(), permissions);
+    }
+
+    fn get_permissions() -> Permissions {
+        Permissions {
+            global: GlobalPermissions {
+                send_messages: true,
+                ..Default::default()
+            },
+            streams: None,
+        }
+    }
+}
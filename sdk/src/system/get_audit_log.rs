@@ -0,0 +1,124 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+const DEFAULT_COUNT: u32 = 100;
+
+/// `GetAuditLog` command is used to get the latest entries of the audit log, ordered from the oldest to the newest.
+/// It has additional payload:
+/// - `count` - maximum number of the entries to return.
+/// - `user_id` - optional unique ID (numeric) of the user performing the actions, used to filter the entries.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetAuditLog {
+    /// Maximum number of the entries to return.
+    #[serde(default = "default_count")]
+    pub count: u32,
+    /// Optional unique ID (numeric) of the user performing the actions, used to filter the entries.
+    pub user_id: Option<u32>,
+}
+
+impl CommandPayload for GetAuditLog {}
+
+impl Default for GetAuditLog {
+    fn default() -> Self {
+        GetAuditLog {
+            count: default_count(),
+            user_id: None,
+        }
+    }
+}
+
+fn default_count() -> u32 {
+    DEFAULT_COUNT
+}
+
+impl Validatable<Error> for GetAuditLog {
+    fn validate(&self) -> Result<(), Error> {
+        if self.count == 0 {
+            return Err(Error::InvalidAuditLogEntriesCount);
+        }
+
+        if let Some(user_id) = self.user_id {
+            if user_id == 0 {
+                return Err(Error::InvalidCommand);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetAuditLog {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8);
+        bytes.put_u32_le(self.count);
+        bytes.put_u32_le(self.user_id.unwrap_or(0));
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<GetAuditLog, Error> {
+        if bytes.len() != 8 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let count = u32::from_le_bytes(bytes[..4].try_into()?);
+        let user_id = u32::from_le_bytes(bytes[4..8].try_into()?);
+        let command = GetAuditLog {
+            count,
+            user_id: if user_id > 0 { Some(user_id) } else { None },
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetAuditLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.count, self.user_id.unwrap_or(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetAuditLog {
+            count: 10,
+            user_id: Some(2),
+        };
+
+        let bytes = command.as_bytes();
+        let count = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+        let user_id = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(count, command.count);
+        assert_eq!(user_id, command.user_id.unwrap());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let count = 10u32;
+        let user_id = 0u32;
+        let bytes = [count.to_le_bytes(), user_id.to_le_bytes()].concat();
+        let command = GetAuditLog::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.count, count);
+        assert!(command.user_id.is_none());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_with_zero_count() {
+        let bytes = [0u32.to_le_bytes(), 0u32.to_le_bytes()].concat();
+        let command = GetAuditLog::from_bytes(&bytes);
+        assert!(command.is_err());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod get_audit_log;
 pub mod get_client;
 pub mod get_clients;
 pub mod get_me;
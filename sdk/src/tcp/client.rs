@@ -12,7 +12,7 @@ use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
 use tokio::sync::Mutex;
 use tokio::time::sleep;
-use tokio_native_tls::native_tls::TlsConnector;
+use tokio_native_tls::native_tls::{Identity, TlsConnector};
 use tokio_native_tls::TlsStream;
 use tracing::log::trace;
 use tracing::{error, info};
@@ -152,8 +152,12 @@ impl Client for TcpClient {
                 break;
             }
 
+            let mut connector_builder = TlsConnector::builder();
+            if let Some(identity) = self.load_tls_identity()? {
+                connector_builder.identity(identity);
+            }
             let connector =
-                tokio_native_tls::TlsConnector::from(TlsConnector::builder().build().unwrap());
+                tokio_native_tls::TlsConnector::from(connector_builder.build().unwrap());
             let stream = tokio_native_tls::TlsConnector::connect(
                 &connector,
                 &self.config.tls_domain,
@@ -166,7 +170,11 @@ impl Client for TcpClient {
         }
 
         self.stream.lock().await.replace(connection_stream);
-        self.set_state(ClientState::Connected).await;
+        // The user mapped to the client certificate is logged in by the server during the handshake.
+        match tls_enabled && self.config.tls_certificate.is_some() {
+            true => self.set_state(ClientState::Authenticated).await,
+            false => self.set_state(ClientState::Connected).await,
+        }
 
         info!(
             "{} client has connected to server: {}",
@@ -206,13 +214,7 @@ impl BinaryClient for TcpClient {
 
         let mut stream = self.stream.lock().await;
         if let Some(stream) = stream.as_mut() {
-            let payload_length = payload.len() + 4;
-            let mut buffer = Vec::with_capacity(REQUEST_INITIAL_BYTES_LENGTH + payload_length);
-            #[allow(clippy::cast_possible_truncation)]
-            buffer.put_u32_le(payload_length as u32);
-            buffer.put_u32_le(command);
-            buffer.extend(payload);
-
+            let buffer = Self::create_request(command, payload);
             trace!("Sending a TCP request...");
             stream.write(&buffer).await?;
             trace!("Sent a TCP request, waiting for a response...");
@@ -232,6 +234,24 @@ impl BinaryClient for TcpClient {
         error!("Cannot send data. Client is not connected.");
         Err(Error::NotConnected)
     }
+
+    async fn send_without_response(&self, command: u32, payload: &[u8]) -> Result<(), Error> {
+        if self.get_state().await == ClientState::Disconnected {
+            return Err(Error::NotConnected);
+        }
+
+        let mut stream = self.stream.lock().await;
+        if let Some(stream) = stream.as_mut() {
+            let buffer = Self::create_request(command, payload);
+            trace!("Sending a TCP request without waiting for a response...");
+            stream.write(&buffer).await?;
+            trace!("Sent a TCP request.");
+            return Ok(());
+        }
+
+        error!("Cannot send data. Client is not connected.");
+        Err(Error::NotConnected)
+    }
 }
 
 impl TcpClient {
@@ -265,6 +285,47 @@ impl TcpClient {
         })
     }
 
+    fn load_tls_identity(&self) -> Result<Option<Identity>, Error> {
+        let (certificate_path, key_path) =
+            match (&self.config.tls_certificate, &self.config.tls_key) {
+                (Some(certificate_path), Some(key_path)) => (certificate_path, key_path),
+                (None, None) => return Ok(None),
+                _ => {
+                    error!(
+                        "Both TLS certificate and key are required for the client authentication."
+                    );
+                    return Err(Error::InvalidConfiguration);
+                }
+            };
+
+        let certificate = std::fs::read(certificate_path);
+        let key = std::fs::read(key_path);
+        if certificate.is_err() || key.is_err() {
+            error!("Cannot read TLS certificate: {certificate_path} or key: {key_path}");
+            return Err(Error::InvalidConfiguration);
+        }
+
+        let identity = Identity::from_pkcs8(&certificate.unwrap(), &key.unwrap());
+        if let Err(error) = identity {
+            error!(
+                "Invalid TLS certificate: {certificate_path} or key: {key_path}, error: {error}"
+            );
+            return Err(Error::InvalidConfiguration);
+        }
+
+        Ok(Some(identity.unwrap()))
+    }
+
+    fn create_request(command: u32, payload: &[u8]) -> Vec<u8> {
+        let payload_length = payload.len() + 4;
+        let mut buffer = Vec::with_capacity(REQUEST_INITIAL_BYTES_LENGTH + payload_length);
+        #[allow(clippy::cast_possible_truncation)]
+        buffer.put_u32_le(payload_length as u32);
+        buffer.put_u32_le(command);
+        buffer.extend(payload);
+        buffer
+    }
+
     async fn handle_response(
         &self,
         status: u32,
@@ -11,6 +11,10 @@ pub struct TcpClientConfig {
     pub tls_enabled: bool,
     /// The domain to use for TLS when connecting to the server.
     pub tls_domain: String,
+    /// The optional path to the PEM client certificate used for the mutual TLS authentication.
+    pub tls_certificate: Option<String>,
+    /// The optional path to the PEM (PKCS#8) private key of the client certificate.
+    pub tls_key: Option<String>,
 }
 
 impl Default for TcpClientConfig {
@@ -21,6 +25,8 @@ impl Default for TcpClientConfig {
             reconnection_interval: 1000,
             tls_enabled: false,
             tls_domain: "localhost".to_string(),
+            tls_certificate: None,
+            tls_key: None,
         }
     }
 }
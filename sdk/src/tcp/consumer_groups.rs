@@ -3,11 +3,16 @@ use crate::client::ConsumerGroupClient;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
+use crate::consumer_groups::get_consumer_group_assignment::GetConsumerGroupAssignment;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use crate::consumer_groups::reset_consumer_group_offsets::ResetConsumerGroupOffsets;
 use crate::error::Error;
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::consumer_group::{
+    ConsumerGroup, ConsumerGroupAssignment, ConsumerGroupDetails, ConsumerGroupOffsetReset,
+};
 use crate::tcp::client::TcpClient;
 use async_trait::async_trait;
 
@@ -42,4 +47,25 @@ impl ConsumerGroupClient for TcpClient {
     async fn leave_consumer_group(&self, command: &LeaveConsumerGroup) -> Result<(), Error> {
         binary::consumer_groups::leave_group(self, command).await
     }
+
+    async fn get_consumer_group_assignment(
+        &self,
+        command: &GetConsumerGroupAssignment,
+    ) -> Result<ConsumerGroupAssignment, Error> {
+        binary::consumer_groups::get_group_assignment(self, command).await
+    }
+
+    async fn heartbeat_consumer_group(
+        &self,
+        command: &HeartbeatConsumerGroup,
+    ) -> Result<(), Error> {
+        binary::consumer_groups::heartbeat_group(self, command).await
+    }
+
+    async fn reset_consumer_group_offsets(
+        &self,
+        command: &ResetConsumerGroupOffsets,
+    ) -> Result<Vec<ConsumerGroupOffsetReset>, Error> {
+        binary::consumer_groups::reset_group_offsets(self, command).await
+    }
 }
@@ -5,6 +5,7 @@ pub mod consumer_offsets;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod roles;
 pub mod streams;
 pub mod system;
 pub mod topics;
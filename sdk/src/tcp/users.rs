@@ -4,6 +4,7 @@ use crate::error::Error;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::tcp::client::TcpClient;
+use crate::users::assign_roles::AssignRoles;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
@@ -11,6 +12,7 @@ use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
 use crate::users::update_user::UpdateUser;
 use async_trait::async_trait;
@@ -41,6 +43,14 @@ impl UserClient for TcpClient {
         binary::users::update_permissions(self, command).await
     }
 
+    async fn assign_roles(&self, command: &AssignRoles) -> Result<(), Error> {
+        binary::users::assign_roles(self, command).await
+    }
+
+    async fn unlock_user(&self, command: &UnlockUser) -> Result<(), Error> {
+        binary::users::unlock_user(self, command).await
+    }
+
     async fn change_password(&self, command: &ChangePassword) -> Result<(), Error> {
         binary::users::change_password(self, command).await
     }
@@ -1,7 +1,9 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::Error;
 use crate::identifier::Identifier;
+use crate::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use crate::topics::{MAX_NAME_LENGTH, MAX_PARTITIONS_COUNT};
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::text;
@@ -20,6 +22,10 @@ use std::str::from_utf8;
 /// - `max_topic_size` - optional maximum size of the topic, if `None` then topic size is unlimited.
 ///                      Can't be lower than segment size in the config.
 /// - `replication_factor` - replication factor for the topic.
+/// - `compression_algorithm` - optional compression algorithm for the topic, if `None` then the server default is used.
+/// - `cleanup_policy` - policy used to clean up the messages in the topic, either `delete` (default) or `compact`.
+/// - `max_topic_size_policy` - policy applied once the topic reaches its maximum size, either `delete_oldest` (default) or `reject`.
+/// - `flush_policy` - policy controlling when the buffered messages of the topic are saved on disk, the unset triggers fall back to the server configuration.
 /// - `name` - unique topic name, max length is 255 characters.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateTopic {
@@ -36,6 +42,17 @@ pub struct CreateTopic {
     pub max_topic_size: Option<IggyByteSize>,
     /// Replication factor for the topic.
     pub replication_factor: u8,
+    /// Optional compression algorithm for the topic, if `None` then the server default is used.
+    pub compression_algorithm: Option<CompressionAlgorithm>,
+    /// Policy used to clean up the messages in the topic, either `delete` (default) or `compact`.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    /// Policy applied once the topic reaches its maximum size, either `delete_oldest` (default) or `reject`.
+    #[serde(default)]
+    pub max_topic_size_policy: MaxTopicSizePolicy,
+    /// Policy controlling when the buffered messages of the topic are saved on disk, the unset triggers fall back to the server configuration.
+    #[serde(default)]
+    pub flush_policy: FlushPolicy,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
 }
@@ -51,6 +68,10 @@ impl Default for CreateTopic {
             message_expiry: None,
             max_topic_size: None,
             replication_factor: 1,
+            compression_algorithm: None,
+            cleanup_policy: CleanupPolicy::default(),
+            max_topic_size_policy: MaxTopicSizePolicy::default(),
+            flush_policy: FlushPolicy::default(),
             name: "topic".to_string(),
         }
     }
@@ -85,7 +106,7 @@ impl Validatable<Error> for CreateTopic {
 impl BytesSerializable for CreateTopic {
     fn as_bytes(&self) -> Vec<u8> {
         let stream_id_bytes = self.stream_id.as_bytes();
-        let mut bytes = Vec::with_capacity(22 + stream_id_bytes.len() + self.name.len());
+        let mut bytes = Vec::with_capacity(41 + stream_id_bytes.len() + self.name.len());
         bytes.extend(stream_id_bytes);
         bytes.put_u32_le(self.topic_id);
         bytes.put_u32_le(self.partitions_count);
@@ -101,16 +122,27 @@ impl BytesSerializable for CreateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.extend(self.name.as_bytes());
+        // The optional trailing bytes, so the commands sent by the clients unaware of these fields remain valid.
+        match self.compression_algorithm {
+            Some(compression_algorithm) => bytes.put_u8(compression_algorithm.as_code()),
+            None => bytes.put_u8(0),
+        }
+        bytes.put_u8(self.cleanup_policy.as_code());
+        bytes.put_u8(self.max_topic_size_policy.as_code());
+        bytes.extend(self.flush_policy.as_bytes());
         bytes
     }
 
     fn from_bytes(bytes: &[u8]) -> std::result::Result<CreateTopic, Error> {
-        if bytes.len() < 18 {
+        if bytes.len() < 26 {
             return Err(Error::InvalidCommand);
         }
         let mut position = 0;
         let stream_id = Identifier::from_bytes(bytes)?;
         position += stream_id.get_size_bytes() as usize;
+        if bytes.len() < position + 22 {
+            return Err(Error::InvalidCommand);
+        }
         let topic_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
         let partitions_count = u32::from_le_bytes(bytes[position + 4..position + 8].try_into()?);
         let message_expiry =
@@ -125,11 +157,33 @@ impl BytesSerializable for CreateTopic {
             };
         let replication_factor = bytes[position + 20];
         let name_length = bytes[position + 21];
+        if bytes.len() < position + 22 + name_length as usize {
+            return Err(Error::InvalidCommand);
+        }
         let name =
             from_utf8(&bytes[position + 22..(position + 22 + name_length as usize)])?.to_string();
         if name.len() != name_length as usize {
             return Err(Error::InvalidCommand);
         }
+        position += 22 + name_length as usize;
+        let compression_algorithm = match bytes.get(position) {
+            None | Some(0) => None,
+            Some(code) => Some(CompressionAlgorithm::from_code(*code)?),
+        };
+        let cleanup_policy = match bytes.get(position + 1) {
+            Some(code) => CleanupPolicy::from_code(*code)?,
+            None => CleanupPolicy::default(),
+        };
+        let max_topic_size_policy = match bytes.get(position + 2) {
+            Some(code) => MaxTopicSizePolicy::from_code(*code)?,
+            None => MaxTopicSizePolicy::default(),
+        };
+        // The flush policy must be either complete or absent.
+        let flush_policy = if bytes.len() > position + 3 {
+            FlushPolicy::from_bytes(&bytes[position + 3..])?
+        } else {
+            FlushPolicy::default()
+        };
         let command = CreateTopic {
             stream_id,
             topic_id,
@@ -137,6 +191,10 @@ impl BytesSerializable for CreateTopic {
             message_expiry,
             max_topic_size,
             replication_factor,
+            compression_algorithm,
+            cleanup_policy,
+            max_topic_size_policy,
+            flush_policy,
             name,
         };
         command.validate()?;
@@ -150,15 +208,23 @@ impl Display for CreateTopic {
             Some(max_topic_size) => max_topic_size.to_string(),
             None => "unlimited".to_string(),
         };
+        let compression_algorithm = match self.compression_algorithm {
+            Some(compression_algorithm) => compression_algorithm.to_string(),
+            None => "default".to_string(),
+        };
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.partitions_count,
             self.message_expiry.unwrap_or(0),
             max_topic_size,
             self.replication_factor,
+            compression_algorithm,
+            self.cleanup_policy,
+            self.max_topic_size_policy,
+            self.flush_policy,
             self.name
         )
     }
@@ -167,6 +233,7 @@ impl Display for CreateTopic {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::topic::FLUSH_POLICY_SIZE;
     use bytes::BufMut;
 
     #[test]
@@ -178,6 +245,14 @@ mod tests {
             message_expiry: Some(10),
             max_topic_size: Some(IggyByteSize::from(100)),
             replication_factor: 1,
+            compression_algorithm: Some(CompressionAlgorithm::Zstd),
+            cleanup_policy: CleanupPolicy::Compact,
+            max_topic_size_policy: MaxTopicSizePolicy::Reject,
+            flush_policy: FlushPolicy {
+                messages_count: Some(100),
+                size: Some(IggyByteSize::from(1000)),
+                max_latency: Some(50),
+            },
             name: "test".to_string(),
         };
         let bytes = command.as_bytes();
@@ -202,6 +277,14 @@ mod tests {
         let name = from_utf8(&bytes[position + 22..(position + 22 + name_length as usize)])
             .unwrap()
             .to_string();
+        position += 22 + name_length as usize;
+        let compression_algorithm = match bytes[position] {
+            0 => None,
+            code => Some(CompressionAlgorithm::from_code(code).unwrap()),
+        };
+        let cleanup_policy = CleanupPolicy::from_code(bytes[position + 1]).unwrap();
+        let max_topic_size_policy = MaxTopicSizePolicy::from_code(bytes[position + 2]).unwrap();
+        let flush_policy = FlushPolicy::from_bytes(&bytes[position + 3..]).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
@@ -210,6 +293,10 @@ mod tests {
         assert_eq!(message_expiry, command.message_expiry);
         assert_eq!(max_topic_size, command.max_topic_size);
         assert_eq!(replication_factor, command.replication_factor);
+        assert_eq!(compression_algorithm, command.compression_algorithm);
+        assert_eq!(cleanup_policy, command.cleanup_policy);
+        assert_eq!(max_topic_size_policy, command.max_topic_size_policy);
+        assert_eq!(flush_policy, command.flush_policy);
         assert_eq!(name.len() as u8, command.name.len() as u8);
         assert_eq!(name, command.name);
     }
@@ -223,6 +310,14 @@ mod tests {
         let message_expiry = 10;
         let max_topic_size = IggyByteSize::from(100);
         let replication_factor = 1;
+        let compression_algorithm = CompressionAlgorithm::Lz4;
+        let cleanup_policy = CleanupPolicy::Compact;
+        let max_topic_size_policy = MaxTopicSizePolicy::Reject;
+        let flush_policy = FlushPolicy {
+            messages_count: Some(100),
+            size: None,
+            max_latency: Some(50),
+        };
         let stream_id_bytes = stream_id.as_bytes();
         let mut bytes = Vec::with_capacity(14 + stream_id_bytes.len() + name.len());
         bytes.extend(stream_id_bytes);
@@ -234,6 +329,10 @@ mod tests {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(name.len() as u8);
         bytes.extend(name.as_bytes());
+        bytes.put_u8(compression_algorithm.as_code());
+        bytes.put_u8(cleanup_policy.as_code());
+        bytes.put_u8(max_topic_size_policy.as_code());
+        bytes.extend(flush_policy.as_bytes());
 
         let command = CreateTopic::from_bytes(&bytes);
         assert!(command.is_ok());
@@ -243,7 +342,59 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partitions_count, partitions_count);
         assert_eq!(command.message_expiry, Some(message_expiry));
+        assert_eq!(command.compression_algorithm, Some(compression_algorithm));
+        assert_eq!(command.cleanup_policy, cleanup_policy);
+        assert_eq!(command.max_topic_size_policy, max_topic_size_policy);
+        assert_eq!(command.flush_policy, flush_policy);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partitions_count, partitions_count);
     }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = CreateTopic {
+            stream_id: Identifier::named("stream").unwrap(),
+            name: "test".to_string(),
+            ..CreateTopic::default()
+        };
+        let bytes = command.as_bytes();
+
+        // The trailing compression algorithm, cleanup policy, max topic size policy and flush policy are optional,
+        // but the flush policy must be either complete or absent.
+        let required_bytes_count = bytes.len() - 3 - FLUSH_POLICY_SIZE;
+        for length in 0..required_bytes_count {
+            assert!(CreateTopic::from_bytes(&bytes[..length]).is_err());
+        }
+        for length in required_bytes_count..=required_bytes_count + 3 {
+            assert!(CreateTopic::from_bytes(&bytes[..length]).is_ok());
+        }
+        for length in required_bytes_count + 4..bytes.len() {
+            assert!(CreateTopic::from_bytes(&bytes[..length]).is_err());
+        }
+        assert!(CreateTopic::from_bytes(&bytes).is_ok());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_trailing_fields() {
+        let command = CreateTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            compression_algorithm: Some(CompressionAlgorithm::Gzip),
+            cleanup_policy: CleanupPolicy::Compact,
+            max_topic_size_policy: MaxTopicSizePolicy::Reject,
+            flush_policy: FlushPolicy {
+                messages_count: Some(100),
+                size: None,
+                max_latency: Some(50),
+            },
+            ..CreateTopic::default()
+        };
+        let bytes = command.as_bytes();
+
+        let command =
+            CreateTopic::from_bytes(&bytes[..bytes.len() - 3 - FLUSH_POLICY_SIZE]).unwrap();
+        assert_eq!(command.compression_algorithm, None);
+        assert_eq!(command.cleanup_policy, CleanupPolicy::default());
+        assert_eq!(command.max_topic_size_policy, MaxTopicSizePolicy::default());
+        assert_eq!(command.flush_policy, FlushPolicy::default());
+    }
 }
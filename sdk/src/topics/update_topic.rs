@@ -1,7 +1,9 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::Error;
 use crate::identifier::Identifier;
+use crate::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use crate::topics::MAX_NAME_LENGTH;
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::text;
@@ -19,6 +21,10 @@ use std::str::from_utf8;
 /// - `max_topic_size` - optional maximum size of the topic in bytes, if `None` then topic size is unlimited.
 ///                      Can't be lower than segment size in the config.
 /// - `replication_factor` - replication factor for the topic.
+/// - `compression_algorithm` - optional new compression algorithm for the topic, if `None` then the current one is kept.
+/// - `cleanup_policy` - optional new policy used to clean up the messages in the topic, either `delete` or `compact`, if `None` then the current one is kept.
+/// - `max_topic_size_policy` - optional new policy applied once the topic reaches its maximum size, either `delete_oldest` or `reject`, if `None` then the current one is kept.
+/// - `flush_policy` - optional new policy controlling when the buffered messages of the topic are saved on disk, if `None` then the current one is kept.
 /// - `name` - unique topic name, max length is 255 characters.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct UpdateTopic {
@@ -35,6 +41,18 @@ pub struct UpdateTopic {
     pub max_topic_size: Option<IggyByteSize>,
     /// Replication factor for the topic.
     pub replication_factor: u8,
+    /// Optional new compression algorithm for the topic, if `None` then the current one is kept.
+    #[serde(default)]
+    pub compression_algorithm: Option<CompressionAlgorithm>,
+    /// Optional new policy used to clean up the messages in the topic, if `None` then the current one is kept.
+    #[serde(default)]
+    pub cleanup_policy: Option<CleanupPolicy>,
+    /// Optional new policy applied once the topic reaches its maximum size, if `None` then the current one is kept.
+    #[serde(default)]
+    pub max_topic_size_policy: Option<MaxTopicSizePolicy>,
+    /// Optional new policy controlling when the buffered messages of the topic are saved on disk, if `None` then the current one is kept.
+    #[serde(default)]
+    pub flush_policy: Option<FlushPolicy>,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
 }
@@ -49,6 +67,10 @@ impl Default for UpdateTopic {
             message_expiry: None,
             max_topic_size: None,
             replication_factor: 1,
+            compression_algorithm: None,
+            cleanup_policy: None,
+            max_topic_size_policy: None,
+            flush_policy: None,
             name: "topic".to_string(),
         }
     }
@@ -77,7 +99,7 @@ impl BytesSerializable for UpdateTopic {
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
         let mut bytes =
-            Vec::with_capacity(13 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len());
+            Vec::with_capacity(33 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len());
         bytes.extend(stream_id_bytes.clone());
         bytes.extend(topic_id_bytes.clone());
         match self.message_expiry {
@@ -92,11 +114,28 @@ impl BytesSerializable for UpdateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.extend(self.name.as_bytes());
+        // The optional trailing bytes, so the commands sent by the clients unaware of these fields remain valid.
+        // The code 0 (or the missing bytes) keeps the current value of the topic.
+        match self.compression_algorithm {
+            Some(compression_algorithm) => bytes.put_u8(compression_algorithm.as_code()),
+            None => bytes.put_u8(0),
+        }
+        match self.cleanup_policy {
+            Some(cleanup_policy) => bytes.put_u8(cleanup_policy.as_code()),
+            None => bytes.put_u8(0),
+        }
+        match self.max_topic_size_policy {
+            Some(max_topic_size_policy) => bytes.put_u8(max_topic_size_policy.as_code()),
+            None => bytes.put_u8(0),
+        }
+        if let Some(flush_policy) = self.flush_policy {
+            bytes.extend(flush_policy.as_bytes());
+        }
         bytes
     }
 
     fn from_bytes(bytes: &[u8]) -> Result<UpdateTopic, Error> {
-        if bytes.len() < 12 {
+        if bytes.len() < 21 {
             return Err(Error::InvalidCommand);
         }
         let mut position = 0;
@@ -104,6 +143,9 @@ impl BytesSerializable for UpdateTopic {
         position += stream_id.get_size_bytes() as usize;
         let topic_id = Identifier::from_bytes(&bytes[position..])?;
         position += topic_id.get_size_bytes() as usize;
+        if bytes.len() < position + 14 {
+            return Err(Error::InvalidCommand);
+        }
         let message_expiry = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
         let message_expiry = match message_expiry {
             0 => None,
@@ -116,17 +158,43 @@ impl BytesSerializable for UpdateTopic {
             };
         let replication_factor = bytes[position + 12];
         let name_length = bytes[position + 13];
+        if bytes.len() < position + 14 + name_length as usize {
+            return Err(Error::InvalidCommand);
+        }
         let name =
             from_utf8(&bytes[position + 14..(position + 14 + name_length as usize)])?.to_string();
         if name.len() != name_length as usize {
             return Err(Error::InvalidCommand);
         }
+        position += 14 + name_length as usize;
+        let compression_algorithm = match bytes.get(position) {
+            None | Some(0) => None,
+            Some(code) => Some(CompressionAlgorithm::from_code(*code)?),
+        };
+        let cleanup_policy = match bytes.get(position + 1) {
+            None | Some(0) => None,
+            Some(code) => Some(CleanupPolicy::from_code(*code)?),
+        };
+        let max_topic_size_policy = match bytes.get(position + 2) {
+            None | Some(0) => None,
+            Some(code) => Some(MaxTopicSizePolicy::from_code(*code)?),
+        };
+        // The flush policy must be either complete or absent.
+        let flush_policy = if bytes.len() > position + 3 {
+            Some(FlushPolicy::from_bytes(&bytes[position + 3..])?)
+        } else {
+            None
+        };
         let command = UpdateTopic {
             stream_id,
             topic_id,
             message_expiry,
             max_topic_size,
             replication_factor,
+            compression_algorithm,
+            cleanup_policy,
+            max_topic_size_policy,
+            flush_policy,
             name,
         };
         command.validate()?;
@@ -142,20 +210,32 @@ impl Display for UpdateTopic {
         };
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.message_expiry.unwrap_or(0),
             max_topic_size,
             self.replication_factor,
+            unchanged_or(&self.compression_algorithm),
+            unchanged_or(&self.cleanup_policy),
+            unchanged_or(&self.max_topic_size_policy),
+            unchanged_or(&self.flush_policy),
             self.name,
         )
     }
 }
 
+fn unchanged_or<T: Display>(value: &Option<T>) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => "unchanged".to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::topic::FLUSH_POLICY_SIZE;
     use bytes::BufMut;
 
     #[test]
@@ -166,6 +246,14 @@ mod tests {
             message_expiry: Some(10),
             max_topic_size: Some(IggyByteSize::from(100)),
             replication_factor: 1,
+            compression_algorithm: Some(CompressionAlgorithm::Snappy),
+            cleanup_policy: Some(CleanupPolicy::Compact),
+            max_topic_size_policy: Some(MaxTopicSizePolicy::Reject),
+            flush_policy: Some(FlushPolicy {
+                messages_count: Some(100),
+                size: Some(IggyByteSize::from(1000)),
+                max_latency: Some(50),
+            }),
             name: "test".to_string(),
         };
 
@@ -190,6 +278,15 @@ mod tests {
         let name = from_utf8(&bytes[position + 14..position + 14 + name_length as usize])
             .unwrap()
             .to_string();
+        position += 14 + name_length as usize;
+        let compression_algorithm = match bytes[position] {
+            0 => None,
+            code => Some(CompressionAlgorithm::from_code(code).unwrap()),
+        };
+        let cleanup_policy = Some(CleanupPolicy::from_code(bytes[position + 1]).unwrap());
+        let max_topic_size_policy =
+            Some(MaxTopicSizePolicy::from_code(bytes[position + 2]).unwrap());
+        let flush_policy = Some(FlushPolicy::from_bytes(&bytes[position + 3..]).unwrap());
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
@@ -197,6 +294,10 @@ mod tests {
         assert_eq!(message_expiry, command.message_expiry);
         assert_eq!(max_topic_size, command.max_topic_size);
         assert_eq!(replication_factor, command.replication_factor);
+        assert_eq!(compression_algorithm, command.compression_algorithm);
+        assert_eq!(cleanup_policy, command.cleanup_policy);
+        assert_eq!(max_topic_size_policy, command.max_topic_size_policy);
+        assert_eq!(flush_policy, command.flush_policy);
         assert_eq!(name.len() as u8, command.name.len() as u8);
         assert_eq!(name, command.name);
     }
@@ -209,6 +310,14 @@ mod tests {
         let message_expiry = 10;
         let max_topic_size = IggyByteSize::from(100);
         let replication_factor = 1;
+        let compression_algorithm = CompressionAlgorithm::Gzip;
+        let cleanup_policy = CleanupPolicy::Compact;
+        let max_topic_size_policy = MaxTopicSizePolicy::Reject;
+        let flush_policy = FlushPolicy {
+            messages_count: Some(100),
+            size: None,
+            max_latency: Some(50),
+        };
 
         let stream_id_bytes = stream_id.as_bytes();
         let topic_id_bytes = topic_id.as_bytes();
@@ -223,6 +332,10 @@ mod tests {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(name.len() as u8);
         bytes.extend(name.as_bytes());
+        bytes.put_u8(compression_algorithm.as_code());
+        bytes.put_u8(cleanup_policy.as_code());
+        bytes.put_u8(max_topic_size_policy.as_code());
+        bytes.extend(flush_policy.as_bytes());
 
         let command = UpdateTopic::from_bytes(&bytes);
         assert!(command.is_ok());
@@ -231,7 +344,66 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.message_expiry, Some(message_expiry));
+        assert_eq!(command.compression_algorithm, Some(compression_algorithm));
+        assert_eq!(command.cleanup_policy, Some(cleanup_policy));
+        assert_eq!(command.max_topic_size_policy, Some(max_topic_size_policy));
+        assert_eq!(command.flush_policy, Some(flush_policy));
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
     }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = UpdateTopic {
+            stream_id: Identifier::named("stream").unwrap(),
+            topic_id: Identifier::named("topic").unwrap(),
+            name: "test".to_string(),
+            flush_policy: Some(FlushPolicy::default()),
+            ..UpdateTopic::default()
+        };
+        let bytes = command.as_bytes();
+
+        // The trailing compression algorithm, cleanup policy, max topic size policy and flush policy are optional,
+        // but the flush policy must be either complete or absent.
+        let required_bytes_count = bytes.len() - 3 - FLUSH_POLICY_SIZE;
+        for length in 0..required_bytes_count {
+            assert!(UpdateTopic::from_bytes(&bytes[..length]).is_err());
+        }
+        for length in required_bytes_count..=required_bytes_count + 3 {
+            assert!(UpdateTopic::from_bytes(&bytes[..length]).is_ok());
+        }
+        for length in required_bytes_count + 4..bytes.len() {
+            assert!(UpdateTopic::from_bytes(&bytes[..length]).is_err());
+        }
+        assert!(UpdateTopic::from_bytes(&bytes).is_ok());
+    }
+
+    #[test]
+    fn legacy_payload_without_trailing_fields_should_keep_current_values() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let name = "test".to_string();
+        let mut bytes = Vec::new();
+        bytes.extend(stream_id.as_bytes());
+        bytes.extend(topic_id.as_bytes());
+        bytes.put_u32_le(10);
+        bytes.put_u64_le(0);
+        bytes.put_u8(1);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(name.len() as u8);
+        bytes.extend(name.as_bytes());
+
+        let command = UpdateTopic::from_bytes(&bytes).unwrap();
+        assert_eq!(command.name, name);
+        assert_eq!(command.message_expiry, Some(10));
+        assert_eq!(command.compression_algorithm, None);
+        assert_eq!(command.cleanup_policy, None);
+        assert_eq!(command.max_topic_size_policy, None);
+        assert_eq!(command.flush_policy, None);
+
+        // The command without the new values is sent back in the same legacy layout extended only with the unchanged codes.
+        let round_trip = UpdateTopic::from_bytes(&command.as_bytes()).unwrap();
+        assert_eq!(round_trip, command);
+        assert_eq!(command.as_bytes(), [bytes.as_slice(), &[0, 0, 0]].concat());
+    }
 }
@@ -0,0 +1,155 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use std::fmt::Display;
+
+/// `AssignRoles` command is used to assign the roles to a user, replacing the ones assigned before.
+/// The user's effective permissions consist of its own permissions and the permissions of all its roles.
+/// It has additional payload:
+/// - `user_id` - unique user ID (numeric or name).
+/// - `roles` - unique IDs (numeric or name) of the roles, if empty, then all the user's roles will be removed.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct AssignRoles {
+    /// Unique user ID (numeric or name).
+    #[serde(skip)]
+    pub user_id: Identifier,
+    /// Unique IDs (numeric or name) of the roles, if empty, then all the user's roles will be removed.
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    pub roles: Vec<Identifier>,
+}
+
+impl CommandPayload for AssignRoles {}
+
+impl Validatable<Error> for AssignRoles {
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for AssignRoles {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend(self.user_id.as_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(self.roles.len() as u32);
+        for role in &self.roles {
+            bytes.extend(role.as_bytes());
+        }
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<AssignRoles, Error> {
+        if bytes.len() < 7 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let user_id = Identifier::from_bytes(bytes)?;
+        let mut position = user_id.get_size_bytes() as usize;
+        if bytes.len() < position + 4 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let roles_count = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        position += 4;
+        let mut roles = Vec::new();
+        for _ in 0..roles_count {
+            if position >= bytes.len() {
+                return Err(Error::InvalidCommand);
+            }
+
+            let role = Identifier::from_bytes(&bytes[position..])?;
+            position += role.get_size_bytes() as usize;
+            roles.push(role);
+        }
+
+        if position != bytes.len() {
+            return Err(Error::InvalidCommand);
+        }
+
+        let command = AssignRoles { user_id, roles };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for AssignRoles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let roles = self
+            .roles
+            .iter()
+            .map(|role| role.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(f, "{}|{roles}", self.user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = AssignRoles {
+            user_id: Identifier::numeric(2).unwrap(),
+            roles: vec![
+                Identifier::numeric(1).unwrap(),
+                Identifier::named("writers").unwrap(),
+            ],
+        };
+
+        let bytes = command.as_bytes();
+        let user_id = Identifier::from_bytes(&bytes).unwrap();
+        let mut position = user_id.get_size_bytes() as usize;
+        let roles_count = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        position += 4;
+        let first_role = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += first_role.get_size_bytes() as usize;
+        let second_role = Identifier::from_bytes(&bytes[position..]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(user_id, command.user_id);
+        assert_eq!(roles_count, 2);
+        assert_eq!(first_role, command.roles[0]);
+        assert_eq!(second_role, command.roles[1]);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let user_id = Identifier::named("user").unwrap();
+        let roles = vec![
+            Identifier::named("readers").unwrap(),
+            Identifier::numeric(3).unwrap(),
+        ];
+        let mut bytes = Vec::new();
+        bytes.extend(user_id.as_bytes());
+        bytes.put_u32_le(roles.len() as u32);
+        for role in &roles {
+            bytes.extend(role.as_bytes());
+        }
+
+        let command = AssignRoles::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.user_id, user_id);
+        assert_eq!(command.roles, roles);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_given_missing_roles() {
+        let user_id = Identifier::numeric(2).unwrap();
+        let mut bytes = Vec::new();
+        bytes.extend(user_id.as_bytes());
+        bytes.put_u32_le(1);
+
+        let command = AssignRoles::from_bytes(&bytes);
+        assert!(command.is_err());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod assign_roles;
 pub mod change_password;
 pub mod create_user;
 pub mod defaults;
@@ -6,5 +7,6 @@ pub mod get_user;
 pub mod get_users;
 pub mod login_user;
 pub mod logout_user;
+pub mod unlock_user;
 pub mod update_permissions;
 pub mod update_user;
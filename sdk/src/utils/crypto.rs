@@ -5,6 +5,8 @@ use aes_gcm::aead::{Aead, OsRng};
 use aes_gcm::{AeadCore, Aes256Gcm, KeyInit};
 use std::fmt::Debug;
 
+const NONCE_SIZE: usize = 12;
+
 pub trait Encryptor: Send + Sync + Debug {
     fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, Error>;
     fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, Error>;
@@ -50,8 +52,12 @@ impl Encryptor for Aes256GcmEncryptor {
     }
 
     fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
-        let nonce = GenericArray::from_slice(&data[0..12]);
-        let payload = self.cipher.decrypt(nonce, &data[12..]);
+        if data.len() < NONCE_SIZE {
+            return Err(Error::CannotDecryptData);
+        }
+
+        let nonce = GenericArray::from_slice(&data[0..NONCE_SIZE]);
+        let payload = self.cipher.decrypt(nonce, &data[NONCE_SIZE..]);
         if payload.is_err() {
             return Err(Error::CannotDecryptData);
         }
@@ -63,6 +69,13 @@ impl Encryptor for Aes256GcmEncryptor {
 mod tests {
     use super::*;
 
+    #[test]
+    fn given_data_shorter_than_nonce_decryption_should_fail() {
+        let encryptor = Aes256GcmEncryptor::new(&[1; 32]).unwrap();
+        let decrypted_data = encryptor.decrypt(b"Hello");
+        assert!(matches!(decrypted_data, Err(Error::CannotDecryptData)));
+    }
+
     #[test]
     fn given_the_same_key_data_should_be_encrypted_and_decrypted_correctly() {
         let key = [1; 32];
@@ -1,6 +1,8 @@
 use crate::binary::handlers::consumer_groups::{
-    create_consumer_group_handler, delete_consumer_group_handler, get_consumer_group_handler,
-    get_consumer_groups_handler, join_consumer_group_handler, leave_consumer_group_handler,
+    create_consumer_group_handler, delete_consumer_group_handler,
+    get_consumer_group_assignment_handler, get_consumer_group_handler, get_consumer_groups_handler,
+    heartbeat_consumer_group_handler, join_consumer_group_handler, leave_consumer_group_handler,
+    reset_consumer_group_offsets_handler,
 };
 use crate::binary::handlers::consumer_offsets::*;
 use crate::binary::handlers::messages::*;
@@ -9,19 +11,25 @@ use crate::binary::handlers::personal_access_tokens::{
     create_personal_access_token_handler, delete_personal_access_token_handler,
     get_personal_access_tokens_handler, login_with_personal_access_token_handler,
 };
+use crate::binary::handlers::roles::{
+    create_role_handler, delete_role_handler, get_role_handler, get_roles_handler,
+    update_role_handler,
+};
 use crate::binary::handlers::streams::*;
 use crate::binary::handlers::system::*;
 use crate::binary::handlers::topics::*;
 use crate::binary::handlers::users::{
-    change_password_handler, create_user_handler, delete_user_handler, get_user_handler,
-    get_users_handler, login_user_handler, logout_user_handler, update_permissions_handler,
-    update_user_handler,
+    assign_roles_handler, change_password_handler, create_user_handler, delete_user_handler,
+    get_user_handler, get_users_handler, login_user_handler, logout_user_handler,
+    unlock_user_handler, update_permissions_handler, update_user_handler,
 };
 use crate::binary::sender::Sender;
+use crate::streaming::quotas::quota_manager::QuotaDemand;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use iggy::command::Command;
 use iggy::error::Error;
+use iggy::messages::send_messages::AckLevel;
 use tracing::{debug, error};
 
 pub async fn handle(
@@ -37,11 +45,43 @@ pub async fn handle(
         }
         Err(error) => {
             error!("Command was not handled successfully, session: {session}, error: {error}");
+            if is_sent_without_response(command) {
+                return Ok(());
+            }
+
             sender.send_error_response(error).await
         }
     }
 }
 
+fn is_sent_without_response(command: &Command) -> bool {
+    matches!(command, Command::SendMessages(command) if command.ack == AckLevel::None)
+}
+
+fn get_quota_demand(command: &Command) -> QuotaDemand {
+    match command {
+        Command::SendMessages(command) => QuotaDemand {
+            requests: 1,
+            sent_messages: command.messages.len() as u64,
+            sent_bytes: command
+                .messages
+                .iter()
+                .map(|message| message.get_size_bytes() as u64)
+                .sum(),
+            ..Default::default()
+        },
+        Command::PollMessages(_) => QuotaDemand {
+            requests: 1,
+            poll: true,
+            ..Default::default()
+        },
+        _ => QuotaDemand {
+            requests: 1,
+            ..Default::default()
+        },
+    }
+}
+
 async fn try_handle(
     command: &Command,
     sender: &mut dyn Sender,
@@ -49,6 +89,10 @@ async fn try_handle(
     system: &SharedSystem,
 ) -> Result<(), Error> {
     debug!("Handling command '{command}', session: {session}...");
+    let quota_manager = system.read().quota_manager.clone();
+    quota_manager
+        .acquire(session, get_quota_demand(command))
+        .await?;
     match command {
         Command::Ping(command) => ping_handler::handle(command, sender, session).await,
         Command::GetStats(command) => {
@@ -61,6 +105,9 @@ async fn try_handle(
         Command::GetClients(command) => {
             get_clients_handler::handle(command, sender, session, system).await
         }
+        Command::GetAuditLog(command) => {
+            get_audit_log_handler::handle(command, sender, session, system).await
+        }
         Command::GetUser(command) => {
             get_user_handler::handle(command, sender, session, system).await
         }
@@ -88,6 +135,12 @@ async fn try_handle(
         Command::LogoutUser(command) => {
             logout_user_handler::handle(command, sender, session, system).await
         }
+        Command::AssignRoles(command) => {
+            assign_roles_handler::handle(command, sender, session, system).await
+        }
+        Command::UnlockUser(command) => {
+            unlock_user_handler::handle(command, sender, session, system).await
+        }
         Command::GetPersonalAccessTokens(command) => {
             get_personal_access_tokens_handler::handle(command, sender, session, system).await
         }
@@ -100,18 +153,39 @@ async fn try_handle(
         Command::LoginWithPersonalAccessToken(command) => {
             login_with_personal_access_token_handler::handle(command, sender, session, system).await
         }
+        Command::GetRole(command) => {
+            get_role_handler::handle(command, sender, session, system).await
+        }
+        Command::GetRoles(command) => {
+            get_roles_handler::handle(command, sender, session, system).await
+        }
+        Command::CreateRole(command) => {
+            create_role_handler::handle(command, sender, session, system).await
+        }
+        Command::DeleteRole(command) => {
+            delete_role_handler::handle(command, sender, session, system).await
+        }
+        Command::UpdateRole(command) => {
+            update_role_handler::handle(command, sender, session, system).await
+        }
         Command::SendMessages(command) => {
             send_messages_handler::handle(command, sender, session, system).await
         }
         Command::PollMessages(command) => {
             poll_messages_handler::handle(command, sender, session, system).await
         }
+        Command::FetchReplicaMessages(command) => {
+            fetch_replica_messages_handler::handle(command, sender, session, system).await
+        }
         Command::GetConsumerOffset(command) => {
             get_consumer_offset_handler::handle(command, sender, session, system).await
         }
         Command::StoreConsumerOffset(command) => {
             store_consumer_offset_handler::handle(command, sender, session, system).await
         }
+        Command::RejectMessage(command) => {
+            reject_message_handler::handle(command, sender, session, system).await
+        }
         Command::GetStream(command) => {
             get_stream_handler::handle(command, sender, session, system).await
         }
@@ -154,6 +228,12 @@ async fn try_handle(
         Command::DeletePartitions(command) => {
             delete_partitions_handler::handle(command, sender, session, system).await
         }
+        Command::GetPartitionOffsets(command) => {
+            get_partition_offsets_handler::handle(command, sender, session, system).await
+        }
+        Command::GetOffsetsForTimestamp(command) => {
+            get_offsets_for_timestamp_handler::handle(command, sender, session, system).await
+        }
         Command::GetConsumerGroup(command) => {
             get_consumer_group_handler::handle(command, sender, session, system).await
         }
@@ -172,5 +252,14 @@ async fn try_handle(
         Command::LeaveConsumerGroup(command) => {
             leave_consumer_group_handler::handle(command, sender, session, system).await
         }
+        Command::GetConsumerGroupAssignment(command) => {
+            get_consumer_group_assignment_handler::handle(command, sender, session, system).await
+        }
+        Command::HeartbeatConsumerGroup(command) => {
+            heartbeat_consumer_group_handler::handle(command, sender, session, system).await
+        }
+        Command::ResetConsumerGroupOffsets(command) => {
+            reset_consumer_group_offsets_handler::handle(command, sender, session, system).await
+        }
     }
 }
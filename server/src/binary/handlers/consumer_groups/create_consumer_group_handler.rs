@@ -21,6 +21,8 @@ pub async fn handle(
             &command.topic_id,
             command.consumer_group_id,
             &command.name,
+            command.session_timeout,
+            command.assignment_strategy,
         )
         .await?;
     sender.send_empty_ok_response().await?;
@@ -0,0 +1,29 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::consumer_groups::get_consumer_group_assignment::GetConsumerGroupAssignment;
+use iggy::error::Error;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetConsumerGroupAssignment,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let assignment = system
+        .get_consumer_group_assignment(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            &command.consumer_group_id,
+        )
+        .await?;
+    let assignment = mapper::map_consumer_group_assignment(&assignment);
+    sender.send_ok_response(assignment.as_slice()).await?;
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::consumer_groups::reset_consumer_group_offsets::ResetConsumerGroupOffsets;
+use iggy::error::Error;
+use tracing::debug;
+
+pub async fn handle(
+    command: &ResetConsumerGroupOffsets,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let offsets = system
+        .reset_consumer_group_offsets(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            &command.consumer_group_id,
+            &command.partition_ids,
+            command.strategy,
+            command.dry_run,
+        )
+        .await?;
+    let offsets = mapper::map_consumer_group_offset_resets(&offsets);
+    sender.send_ok_response(&offsets).await?;
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::messages::fetch_replica_messages::FetchReplicaMessages;
+use tracing::debug;
+
+pub async fn handle(
+    command: &FetchReplicaMessages,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let messages = system
+        .fetch_replica_messages(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.replica_id,
+            command.offset,
+            command.count,
+        )
+        .await?;
+    let messages = mapper::map_polled_messages(&messages);
+    sender.send_ok_response(&messages).await?;
+    Ok(())
+}
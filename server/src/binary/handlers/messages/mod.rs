@@ -1,2 +1,4 @@
+pub mod fetch_replica_messages_handler;
 pub mod poll_messages_handler;
+pub mod reject_message_handler;
 pub mod send_messages_handler;
@@ -25,9 +25,29 @@ pub async fn handle(
             consumer,
             &command.stream_id,
             &command.topic_id,
-            PollingArgs::new(command.strategy, command.count, command.auto_commit),
+            PollingArgs::new(
+                command.strategy,
+                command.count,
+                command.auto_commit,
+                command.skip_poisoned,
+            )
+            .with_generation(command.generation)
+            .with_zero_copy(),
         )
         .await?;
+    system.quota_manager.record_polled(
+        session,
+        messages.get_messages_count(),
+        messages.get_size_bytes(),
+    );
+    if let Some(mapped_messages) = &messages.mapped_messages {
+        let header = mapper::map_mapped_polled_messages_header(&messages, mapped_messages);
+        sender
+            .send_ok_response_vectored(&[&header, mapped_messages.as_bytes()])
+            .await?;
+        return Ok(());
+    }
+
     let messages = mapper::map_polled_messages(&messages);
     sender.send_ok_response(&messages).await?;
     Ok(())
@@ -0,0 +1,56 @@
+use crate::binary::sender::Sender;
+use crate::streaming::quotas::quota_manager::QuotaDemand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::messages::reject_message::RejectMessage;
+use tracing::debug;
+
+pub async fn handle(
+    command: &RejectMessage,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let dead_letter_message = system
+        .read()
+        .get_dead_letter_message(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.offset,
+            &command.reason,
+        )
+        .await?;
+    if let Some(dead_letter_message) = &dead_letter_message {
+        let quota_manager = system.read().quota_manager.clone();
+        quota_manager
+            .acquire(
+                session,
+                QuotaDemand {
+                    sent_messages: 1,
+                    sent_bytes: dead_letter_message.get_size_bytes() as u64,
+                    ..Default::default()
+                },
+            )
+            .await?;
+    }
+
+    let system = system.read();
+    system
+        .reject_message(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.offset,
+            &command.reason,
+            dead_letter_message,
+        )
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
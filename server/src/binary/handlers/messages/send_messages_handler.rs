@@ -3,7 +3,7 @@ use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use iggy::error::Error;
-use iggy::messages::send_messages::SendMessages;
+use iggy::messages::send_messages::{AckLevel, SendMessages};
 use tracing::debug;
 
 pub async fn handle(
@@ -13,16 +13,27 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), Error> {
     debug!("session: {session}, command: {command}");
-    let system = system.read();
-    system
-        .append_messages(
-            session,
-            &command.stream_id,
-            &command.topic_id,
-            &command.partitioning,
-            &command.messages,
-        )
-        .await?;
+    let in_sync_replicas_ack = {
+        let system = system.read();
+        system
+            .append_messages(
+                session,
+                &command.stream_id,
+                &command.topic_id,
+                &command.partitioning,
+                &command.messages,
+                command.ack,
+            )
+            .await?
+    };
+    // The client doesn't wait for any response when the messages are sent without acknowledgement.
+    if command.ack == AckLevel::None {
+        return Ok(());
+    }
+
+    if let Some(in_sync_replicas_ack) = in_sync_replicas_ack {
+        in_sync_replicas_ack.wait().await?;
+    }
     sender.send_empty_ok_response().await?;
     Ok(())
 }
@@ -0,0 +1,24 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::partitions::get_partition_offsets::GetPartitionOffsets;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetPartitionOffsets,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let offsets = system
+        .get_partition_offsets(session, &command.stream_id, &command.topic_id)
+        .await?;
+    let offsets = mapper::map_partition_offsets(&offsets);
+    sender.send_ok_response(&offsets).await?;
+    Ok(())
+}
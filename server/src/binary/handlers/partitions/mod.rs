@@ -1,2 +1,4 @@
 pub mod create_partitions_handler;
 pub mod delete_partitions_handler;
+pub mod get_offsets_for_timestamp_handler;
+pub mod get_partition_offsets_handler;
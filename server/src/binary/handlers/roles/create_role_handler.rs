@@ -0,0 +1,22 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::roles::create_role::CreateRole;
+use tracing::debug;
+
+pub async fn handle(
+    command: &CreateRole,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    system
+        .create_role(session, &command.name, command.permissions.clone())
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
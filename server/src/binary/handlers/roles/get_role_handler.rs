@@ -0,0 +1,21 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::Error;
+use iggy::roles::get_role::GetRole;
+use tracing::log::debug;
+
+pub async fn handle(
+    command: &GetRole,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let role = system.find_role(session, &command.role_id).await?;
+    let bytes = mapper::map_role(&role);
+    sender.send_ok_response(bytes.as_slice()).await?;
+    Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod create_role_handler;
+pub mod delete_role_handler;
+pub mod get_role_handler;
+pub mod get_roles_handler;
+pub mod update_role_handler;
@@ -19,7 +19,10 @@ pub async fn handle(
         let client = system.get_client(session, command.client_id).await?;
         {
             let client = client.read().await;
-            bytes = mapper::map_client(&client).await;
+            let quotas = system
+                .quota_manager
+                .get_client_quotas(client.client_id, client.user_id);
+            bytes = mapper::map_client(&client, quotas.as_ref()).await;
         }
     }
     sender.send_ok_response(bytes.as_slice()).await?;
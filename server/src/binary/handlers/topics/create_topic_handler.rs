@@ -24,6 +24,10 @@ pub async fn handle(
             command.message_expiry,
             command.max_topic_size,
             command.replication_factor,
+            command.compression_algorithm,
+            command.cleanup_policy,
+            command.max_topic_size_policy,
+            command.flush_policy,
         )
         .await?;
     sender.send_empty_ok_response().await?;
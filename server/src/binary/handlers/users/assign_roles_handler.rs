@@ -0,0 +1,22 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::users::assign_roles::AssignRoles;
+use tracing::debug;
+
+pub async fn handle(
+    command: &AssignRoles,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    system
+        .assign_roles(session, &command.user_id, &command.roles)
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
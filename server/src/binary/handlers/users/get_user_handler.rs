@@ -15,7 +15,7 @@ pub async fn handle(
     debug!("session: {session}, command: {command}");
     let system = system.read();
     let user = system.find_user(session, &command.user_id).await?;
-    let bytes = mapper::map_user(&user);
+    let bytes = mapper::map_user(&user, system.get_user_locked_until(&user));
     sender.send_ok_response(bytes.as_slice()).await?;
     Ok(())
 }
@@ -1,3 +1,4 @@
+pub mod assign_roles_handler;
 pub mod change_password_handler;
 pub mod create_user_handler;
 pub mod delete_user_handler;
@@ -5,5 +6,6 @@ pub mod get_user_handler;
 pub mod get_users_handler;
 pub mod login_user_handler;
 pub mod logout_user_handler;
+pub mod unlock_user_handler;
 pub mod update_permissions_handler;
 pub mod update_user_handler;
@@ -2,13 +2,19 @@ use crate::streaming::clients::client_manager::{Client, Transport};
 use crate::streaming::models::messages::PolledMessages;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::roles::role::Role;
+use crate::streaming::segments::mapped::MappedMessages;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::users::user::User;
 use bytes::BufMut;
 use iggy::bytes_serializable::BytesSerializable;
+use iggy::models::audit_log::AuditLogEntry;
+use iggy::models::client_info::{ClientQuotasInfo, QuotasUsageInfo};
+use iggy::models::consumer_group::{ConsumerGroupAssignment, ConsumerGroupOffsetReset};
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::partition_offsets::{PartitionOffsets, TimestampOffset};
 use iggy::models::stats::Stats;
 use iggy::models::user_info::UserId;
 use std::sync::Arc;
@@ -52,7 +58,45 @@ pub fn map_consumer_offset(offset: &ConsumerOffsetInfo) -> Vec<u8> {
     bytes
 }
 
-pub async fn map_client(client: &Client) -> Vec<u8> {
+pub fn map_partition_offsets(offsets: &[PartitionOffsets]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(20 * offsets.len());
+    for offset in offsets {
+        bytes.put_u32_le(offset.partition_id);
+        bytes.put_u64_le(offset.earliest_offset);
+        bytes.put_u64_le(offset.latest_offset);
+    }
+    bytes
+}
+
+pub fn map_consumer_group_offset_resets(offsets: &[ConsumerGroupOffsetReset]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(20 * offsets.len());
+    for offset in offsets {
+        bytes.put_u32_le(offset.partition_id);
+        bytes.put_u64_le(offset.previous_offset);
+        bytes.put_u64_le(offset.new_offset);
+    }
+    bytes
+}
+
+pub fn map_timestamp_offsets(offsets: &[TimestampOffset]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(13 * offsets.len());
+    for offset in offsets {
+        bytes.put_u32_le(offset.partition_id);
+        match offset.offset {
+            Some(value) => {
+                bytes.put_u8(1);
+                bytes.put_u64_le(value);
+            }
+            None => {
+                bytes.put_u8(0);
+                bytes.put_u64_le(0);
+            }
+        }
+    }
+    bytes
+}
+
+pub async fn map_client(client: &Client, quotas: Option<&ClientQuotasInfo>) -> Vec<u8> {
     let mut bytes = Vec::new();
     extend_client(client, &mut bytes);
     for consumer_group in &client.consumer_groups {
@@ -60,6 +104,16 @@ pub async fn map_client(client: &Client) -> Vec<u8> {
         bytes.put_u32_le(consumer_group.topic_id);
         bytes.put_u32_le(consumer_group.consumer_group_id);
     }
+    match quotas {
+        Some(quotas) => {
+            bytes.put_u8(1);
+            bytes.put_u64_le(quotas.throttled_requests);
+            bytes.put_u64_le(quotas.rejected_requests);
+            extend_quotas_usage(&quotas.user, &mut bytes);
+            extend_quotas_usage(&quotas.client, &mut bytes);
+        }
+        None => bytes.put_u8(0),
+    }
     bytes
 }
 
@@ -72,7 +126,35 @@ pub async fn map_clients(clients: &[Arc<RwLock<Client>>]) -> Vec<u8> {
     bytes
 }
 
-pub fn map_user(user: &User) -> Vec<u8> {
+fn extend_quotas_usage(usage: &QuotasUsageInfo, bytes: &mut Vec<u8>) {
+    for quota in [
+        &usage.requests,
+        &usage.sent_messages,
+        &usage.sent_bytes,
+        &usage.polled_messages,
+        &usage.polled_bytes,
+    ] {
+        bytes.put_u64_le(quota.limit);
+        bytes.put_u64_le(quota.used);
+    }
+}
+
+pub fn map_audit_log(entries: &[AuditLogEntry]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for entry in entries {
+        bytes.put_u64_le(entry.timestamp);
+        bytes.put_u32_le(entry.user_id);
+        bytes.put_u8(entry.outcome.as_code());
+        extend_string(&entry.address, &mut bytes);
+        extend_string(&entry.transport, &mut bytes);
+        extend_string(&entry.command, &mut bytes);
+        extend_string(&entry.details, &mut bytes);
+        extend_string(entry.error.as_deref().unwrap_or_default(), &mut bytes);
+    }
+    bytes
+}
+
+pub fn map_user(user: &User, locked_until: Option<u64>) -> Vec<u8> {
     let mut bytes = Vec::new();
     extend_user(user, &mut bytes);
     if let Some(permissions) = &user.permissions {
@@ -82,8 +164,14 @@ pub fn map_user(user: &User) -> Vec<u8> {
         bytes.put_u32_le(permissions.len() as u32);
         bytes.extend(permissions);
     } else {
-        bytes.put_u32_le(0);
+        bytes.put_u8(0);
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    bytes.put_u32_le(user.roles.len() as u32);
+    for role_id in &user.roles {
+        bytes.put_u32_le(*role_id);
     }
+    bytes.put_u64_le(locked_until.unwrap_or(0));
     bytes
 }
 
@@ -95,6 +183,24 @@ pub fn map_users(users: &[User]) -> Vec<u8> {
     bytes
 }
 
+pub fn map_role(role: &Role) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    extend_role(role, &mut bytes);
+    let permissions = role.permissions.as_bytes();
+    #[allow(clippy::cast_possible_truncation)]
+    bytes.put_u32_le(permissions.len() as u32);
+    bytes.extend(permissions);
+    bytes
+}
+
+pub fn map_roles(roles: &[Role]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for role in roles {
+        extend_role(role, &mut bytes);
+    }
+    bytes
+}
+
 pub fn map_identity_info(user_id: UserId) -> Vec<u8> {
     let mut bytes = Vec::with_capacity(4);
     bytes.put_u32_le(user_id);
@@ -135,6 +241,17 @@ pub fn map_polled_messages(polled_messages: &PolledMessages) -> Vec<u8> {
     bytes
 }
 
+pub fn map_mapped_polled_messages_header(
+    polled_messages: &PolledMessages,
+    mapped_messages: &MappedMessages,
+) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(16);
+    bytes.put_u32_le(polled_messages.partition_id);
+    bytes.put_u64_le(polled_messages.current_offset);
+    bytes.put_u32_le(mapped_messages.count);
+    bytes
+}
+
 pub async fn map_stream(stream: &Stream) -> Vec<u8> {
     let mut bytes = Vec::new();
     extend_stream(stream, &mut bytes).await;
@@ -173,6 +290,10 @@ pub async fn map_topic(topic: &Topic) -> Vec<u8> {
 pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> Vec<u8> {
     let mut bytes = Vec::new();
     extend_consumer_group(consumer_group, &mut bytes);
+    bytes.put_u32_le(consumer_group.generation);
+    bytes.put_u8(consumer_group.get_state().await.as_code());
+    bytes.put_u32_le(consumer_group.session_timeout.unwrap_or(0));
+    bytes.put_u8(consumer_group.assignment_strategy.as_code());
     let members = consumer_group.get_members();
     for member in members {
         let member = member.read().await;
@@ -186,6 +307,24 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> Vec<u8> {
     bytes
 }
 
+pub fn map_consumer_group_assignment(assignment: &ConsumerGroupAssignment) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(
+        17 + 4 * (assignment.partitions.len() + assignment.pending_partitions.len()),
+    );
+    bytes.put_u32_le(assignment.consumer_group_id);
+    bytes.put_u32_le(assignment.generation);
+    bytes.put_u8(assignment.state.as_code());
+    bytes.put_u32_le(assignment.partitions.len() as u32);
+    for partition_id in &assignment.partitions {
+        bytes.put_u32_le(*partition_id);
+    }
+    bytes.put_u32_le(assignment.pending_partitions.len() as u32);
+    for partition_id in &assignment.pending_partitions {
+        bytes.put_u32_le(*partition_id);
+    }
+    bytes
+}
+
 pub async fn map_consumer_groups(consumer_groups: &[&RwLock<ConsumerGroup>]) -> Vec<u8> {
     let mut bytes = Vec::new();
     for consumer_group in consumer_groups {
@@ -218,6 +357,10 @@ async fn extend_topic(topic: &Topic, bytes: &mut Vec<u8>) {
         None => bytes.put_u64_le(0),
     };
     bytes.put_u8(topic.replication_factor);
+    bytes.put_u8(topic.compression_algorithm.as_code());
+    bytes.put_u8(topic.cleanup_policy.as_code());
+    bytes.put_u8(topic.max_topic_size_policy.as_code());
+    bytes.extend(topic.flush_policy.as_bytes());
     bytes.put_u64_le(topic.get_size().await.as_bytes_u64());
     bytes.put_u64_le(topic.get_messages_count().await);
     bytes.put_u8(topic.name.len() as u8);
@@ -231,6 +374,11 @@ fn extend_partition(partition: &Partition, bytes: &mut Vec<u8>) {
     bytes.put_u64_le(partition.current_offset);
     bytes.put_u64_le(partition.get_size_bytes());
     bytes.put_u64_le(partition.get_messages_count());
+    let in_sync_replicas = partition.get_in_sync_replicas();
+    bytes.put_u32_le(in_sync_replicas.len() as u32);
+    for replica_id in in_sync_replicas {
+        bytes.put_u32_le(replica_id);
+    }
 }
 
 fn extend_consumer_group(consumer_group: &ConsumerGroup, bytes: &mut Vec<u8>) {
@@ -247,6 +395,7 @@ fn extend_client(client: &Client, bytes: &mut Vec<u8>) {
     let transport: u8 = match client.transport {
         Transport::Tcp => 1,
         Transport::Quic => 2,
+        Transport::Http => 3,
     };
     bytes.put_u8(transport);
     let address = client.address.to_string();
@@ -255,6 +404,11 @@ fn extend_client(client: &Client, bytes: &mut Vec<u8>) {
     bytes.put_u32_le(client.consumer_groups.len() as u32);
 }
 
+fn extend_string(value: &str, bytes: &mut Vec<u8>) {
+    bytes.put_u32_le(value.len() as u32);
+    bytes.extend(value.as_bytes());
+}
+
 fn extend_user(user: &User, bytes: &mut Vec<u8>) {
     bytes.put_u32_le(user.id);
     bytes.put_u64_le(user.created_at);
@@ -263,6 +417,13 @@ fn extend_user(user: &User, bytes: &mut Vec<u8>) {
     bytes.extend(user.username.as_bytes());
 }
 
+fn extend_role(role: &Role, bytes: &mut Vec<u8>) {
+    bytes.put_u32_le(role.id);
+    bytes.put_u64_le(role.created_at);
+    bytes.put_u8(role.name.len() as u8);
+    bytes.extend(role.name.as_bytes());
+}
+
 fn extend_pat(personal_access_token: &PersonalAccessToken, bytes: &mut Vec<u8>) {
     bytes.put_u8(personal_access_token.name.len() as u8);
     bytes.extend(personal_access_token.name.as_bytes());
@@ -0,0 +1,106 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::ConsumerGroupSessionCheckerConfig;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{debug, error, info};
+
+pub struct ConsumerGroupSessionChecker {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<CheckConsumerGroupSessionsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CheckConsumerGroupSessionsCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct CheckConsumerGroupSessionsExecutor;
+
+impl ConsumerGroupSessionChecker {
+    pub fn new(
+        config: &ConsumerGroupSessionCheckerConfig,
+        sender: Sender<CheckConsumerGroupSessionsCommand>,
+    ) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: config.interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Consumer group session checker is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Consumer group session checker is enabled, members with expired sessions will be deleted every: {:?}.",
+            interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(CheckConsumerGroupSessionsCommand)
+                    .unwrap_or_else(|error| {
+                        error!(
+                            "Failed to send CheckConsumerGroupSessionsCommand. Error: {}",
+                            error
+                        );
+                    });
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<CheckConsumerGroupSessionsCommand> for CheckConsumerGroupSessionsExecutor {
+    async fn execute(
+        &mut self,
+        system: &SharedSystem,
+        _command: CheckConsumerGroupSessionsCommand,
+    ) {
+        let system = system.read();
+        let deleted_members_count = system.delete_expired_consumer_group_members().await;
+        if deleted_members_count == 0 {
+            debug!("No consumer group members with expired sessions to delete.");
+            return;
+        }
+
+        info!("Deleted {deleted_members_count} consumer group members with expired sessions.");
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<CheckConsumerGroupSessionsCommand>,
+    ) {
+        let consumer_group_session_checker =
+            ConsumerGroupSessionChecker::new(&config.consumer_group_session_checker, sender);
+        consumer_group_session_checker.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<CheckConsumerGroupSessionsCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Consumer group session checker receiver stopped.");
+        });
+    }
+}
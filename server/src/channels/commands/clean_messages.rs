@@ -4,6 +4,7 @@ use crate::{channels::server_command::ServerCommand, configs::server::MessageCle
 use async_trait::async_trait;
 use flume::Sender;
 use iggy::error::Error;
+use iggy::models::topic::MaxTopicSizePolicy;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
 use tokio::time;
@@ -79,15 +80,39 @@ impl ServerCommand<CleanMessagesCommand> for CleanMessagesExecutor {
                         topic.topic_id
                     );
 
-                    system
-                        .write()
+                    system_read
                         .metrics
                         .decrement_segments(deleted_segments.segments_count);
-                    system
-                        .write()
+                    system_read
                         .metrics
                         .decrement_messages(deleted_segments.messages_count);
                 }
+
+                match delete_oldest_segments(topic).await {
+                    Ok(Some(deleted_segments)) => {
+                        info!(
+                            "Deleted {} oldest segments and {} messages exceeding max topic size for stream ID: {}, topic ID: {}",
+                            deleted_segments.segments_count,
+                            deleted_segments.messages_count,
+                            topic.stream_id,
+                            topic.topic_id
+                        );
+
+                        system_read
+                            .metrics
+                            .decrement_segments(deleted_segments.segments_count);
+                        system_read
+                            .metrics
+                            .decrement_messages(deleted_segments.messages_count);
+                    }
+                    Ok(None) => {}
+                    Err(error) => {
+                        error!(
+                            "Failed to delete the oldest segments for stream ID: {}, topic ID: {}. Error: {}",
+                            topic.stream_id, topic.topic_id, error
+                        );
+                    }
+                }
             }
         }
     }
@@ -174,3 +199,19 @@ async fn delete_expired_segments(
         messages_count,
     }))
 }
+
+async fn delete_oldest_segments(topic: &Topic) -> Result<Option<DeletedSegments>, Error> {
+    if topic.max_topic_size_policy != MaxTopicSizePolicy::DeleteOldest {
+        return Ok(None);
+    }
+
+    let (segments_count, messages_count) = topic.delete_oldest_segments().await?;
+    if segments_count == 0 {
+        return Ok(None);
+    }
+
+    Ok(Some(DeletedSegments {
+        segments_count,
+        messages_count,
+    }))
+}
@@ -0,0 +1,144 @@
+use crate::streaming::partitions::compaction;
+use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::topics::topic::Topic;
+use crate::{channels::server_command::ServerCommand, configs::server::MessageCompactorConfig};
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::error::Error;
+use iggy::models::topic::CleanupPolicy;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use tokio::time;
+use tracing::{error, info};
+
+pub struct MessagesCompactor {
+    enabled: bool,
+    interval: IggyDuration,
+    tombstone_retention: IggyDuration,
+    sender: Sender<CompactMessagesCommand>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompactMessagesCommand {
+    tombstone_retention: IggyDuration,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CompactMessagesExecutor;
+
+impl MessagesCompactor {
+    pub fn new(config: &MessageCompactorConfig, sender: Sender<CompactMessagesCommand>) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: config.interval,
+            tombstone_retention: config.tombstone_retention,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Message compactor is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let tombstone_retention = self.tombstone_retention;
+        let sender = self.sender.clone();
+        info!(
+            "Message compactor is enabled, topics with compact cleanup policy will be compacted every: {:?}.",
+            interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(CompactMessagesCommand {
+                        tombstone_retention,
+                    })
+                    .unwrap_or_else(|err| {
+                        error!("Failed to send CompactMessagesCommand. Error: {}", err);
+                    });
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<CompactMessagesCommand> for CompactMessagesExecutor {
+    async fn execute(&mut self, system: &SharedSystem, command: CompactMessagesCommand) {
+        let tombstone_expiry = IggyTimestamp::now()
+            .to_micros()
+            .saturating_sub(command.tombstone_retention.as_micros());
+        let system_read = system.read();
+        let mut removed_messages_count = 0;
+        for stream in system_read.get_streams() {
+            for topic in stream.get_topics() {
+                if topic.cleanup_policy != CleanupPolicy::Compact {
+                    continue;
+                }
+
+                match compact_topic(topic, tombstone_expiry).await {
+                    Ok(removed_count) => {
+                        if removed_count > 0 {
+                            info!(
+                                "Compacted {} messages for stream ID: {}, topic ID: {}",
+                                removed_count, topic.stream_id, topic.topic_id
+                            );
+                        }
+                        removed_messages_count += removed_count;
+                    }
+                    Err(error) => {
+                        error!(
+                            "Failed to compact messages for stream ID: {}, topic ID: {}. Error: {}",
+                            topic.stream_id, topic.topic_id, error
+                        );
+                    }
+                }
+            }
+        }
+
+        if removed_messages_count > 0 {
+            system_read
+                .metrics
+                .decrement_messages(removed_messages_count);
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<CompactMessagesCommand>,
+    ) {
+        let messages_compactor = MessagesCompactor::new(&config.message_compactor, sender);
+        messages_compactor.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<CompactMessagesCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Messages compactor receiver stopped.");
+        });
+    }
+}
+
+async fn compact_topic(topic: &Topic, tombstone_expiry: u64) -> Result<u64, Error> {
+    let mut removed_messages_count = 0;
+    for partition in topic.get_partitions() {
+        removed_messages_count +=
+            compaction::compact_segments(&partition, tombstone_expiry).await?;
+    }
+
+    Ok(removed_messages_count)
+}
@@ -1,3 +1,7 @@
+pub mod check_consumer_group_sessions;
 pub mod clean_messages;
 pub mod clean_personal_access_tokens;
+pub mod compact_messages;
+pub mod offload_segments;
+pub mod replicate_partitions;
 pub mod save_messages;
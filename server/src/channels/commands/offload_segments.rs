@@ -0,0 +1,130 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::system::TieredStorageConfig;
+use crate::streaming::partitions::tiering;
+use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::topics::topic::Topic;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use tokio::time;
+use tracing::{error, info};
+
+pub struct SegmentsOffloader {
+    enabled: bool,
+    interval: IggyDuration,
+    hot_window: IggyDuration,
+    sender: Sender<OffloadSegmentsCommand>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OffloadSegmentsCommand {
+    hot_window: IggyDuration,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct OffloadSegmentsExecutor;
+
+impl SegmentsOffloader {
+    pub fn new(config: &TieredStorageConfig, sender: Sender<OffloadSegmentsCommand>) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: config.interval,
+            hot_window: config.hot_window,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Segments offloader is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let hot_window = self.hot_window;
+        let sender = self.sender.clone();
+        info!(
+            "Segments offloader is enabled, closed segments older than: {} will be offloaded to the tiered storage every: {:?}.",
+            hot_window, interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(OffloadSegmentsCommand { hot_window })
+                    .unwrap_or_else(|err| {
+                        error!("Failed to send OffloadSegmentsCommand. Error: {}", err);
+                    });
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<OffloadSegmentsCommand> for OffloadSegmentsExecutor {
+    async fn execute(&mut self, system: &SharedSystem, command: OffloadSegmentsCommand) {
+        let hot_window_expiry = IggyTimestamp::now()
+            .to_micros()
+            .saturating_sub(command.hot_window.as_micros());
+        let system_read = system.read();
+        for stream in system_read.get_streams() {
+            for topic in stream.get_topics() {
+                let offloaded_segments_count =
+                    offload_topic_segments(topic, hot_window_expiry).await;
+                if offloaded_segments_count > 0 {
+                    info!(
+                        "Offloaded {} segments for stream ID: {}, topic ID: {}",
+                        offloaded_segments_count, topic.stream_id, topic.topic_id
+                    );
+                }
+            }
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<OffloadSegmentsCommand>,
+    ) {
+        let segments_offloader = SegmentsOffloader::new(&config.system.tiered_storage, sender);
+        segments_offloader.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<OffloadSegmentsCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Segments offloader receiver stopped.");
+        });
+    }
+}
+
+async fn offload_topic_segments(topic: &Topic, hot_window_expiry: u64) -> u32 {
+    let mut offloaded_segments_count = 0;
+    for partition in topic.get_partitions() {
+        // A single partition which cannot be offloaded must not stop the offloading of the other ones.
+        match tiering::offload_segments(&partition, hot_window_expiry).await {
+            Ok(count) => offloaded_segments_count += count,
+            Err(error) => {
+                let partition_id = partition.read().await.partition_id;
+                error!(
+                    "Failed to offload segments for partition with ID: {partition_id} for topic with ID: {}, stream with ID: {}. Error: {error}",
+                    topic.topic_id, topic.stream_id
+                );
+            }
+        }
+    }
+
+    offloaded_segments_count
+}
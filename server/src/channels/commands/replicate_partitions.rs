@@ -0,0 +1,285 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::system::{ReplicaRole, ReplicationConfig};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::client::{Client, MessageClient, StreamClient, UserClient};
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::messages::fetch_replica_messages::FetchReplicaMessages;
+use iggy::models::topic::Topic;
+use iggy::streams::get_stream::GetStream;
+use iggy::streams::get_streams::GetStreams;
+use iggy::tcp::client::TcpClient;
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use iggy::users::login_user::LoginUser;
+use iggy::utils::duration::IggyDuration;
+use std::net::{Ipv4Addr, SocketAddr};
+use tokio::time;
+use tracing::{error, info, trace};
+
+pub struct PartitionsReplicator {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<ReplicatePartitionsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ReplicatePartitionsCommand;
+
+#[derive(Debug, Default)]
+pub struct ReplicatePartitionsExecutor {
+    client: Option<TcpClient>,
+}
+
+impl PartitionsReplicator {
+    pub fn new(config: &ReplicationConfig, sender: Sender<ReplicatePartitionsCommand>) -> Self {
+        Self {
+            enabled: config.enabled && config.role == ReplicaRole::Follower,
+            interval: config.fetch_interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Partitions replicator is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Partitions replicator is enabled, messages will be fetched from the leader every: {:?}.",
+            interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(ReplicatePartitionsCommand)
+                    .unwrap_or_else(|error| {
+                        error!(
+                            "Failed to send ReplicatePartitionsCommand. Error: {}",
+                            error
+                        );
+                    });
+            }
+        });
+    }
+}
+
+impl ReplicatePartitionsExecutor {
+    async fn connect(&mut self, config: &ReplicationConfig) -> Result<&TcpClient, Error> {
+        if self.client.is_none() {
+            let client = TcpClient::new(&config.leader_address)?;
+            client.connect().await?;
+            client
+                .login_user(&LoginUser {
+                    username: config.username.clone(),
+                    password: config.password.clone(),
+                })
+                .await?;
+            info!(
+                "Connected to the leader node at: {}.",
+                config.leader_address
+            );
+            self.client = Some(client);
+        }
+
+        Ok(self.client.as_ref().unwrap())
+    }
+
+    async fn replicate(&mut self, system: &SharedSystem) -> Result<(), Error> {
+        let config = system.read().config.clone();
+        let config = &config.replication;
+        let client = self.connect(config).await?;
+        for stream in client.get_streams(&GetStreams {}).await? {
+            let stream = client
+                .get_stream(&GetStream {
+                    stream_id: Identifier::numeric(stream.id)?,
+                })
+                .await?;
+            for topic in stream.topics {
+                if topic.replication_factor <= 1 {
+                    continue;
+                }
+
+                ensure_topic(system, stream.id, &stream.name, &topic).await?;
+                for partition_id in 1..=topic.partitions_count {
+                    // A single partition which cannot be replicated must not stop the replication of the other ones.
+                    if let Err(error) = replicate_partition(
+                        client,
+                        system,
+                        config,
+                        stream.id,
+                        topic.id,
+                        partition_id,
+                    )
+                    .await
+                    {
+                        error!(
+                            "Failed to replicate partition with ID: {partition_id} for topic with ID: {}, stream with ID: {}. Error: {error}",
+                            topic.id, stream.id
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Creates the replicated stream, topic and partitions if they don't exist yet, using the same IDs as the leader.
+async fn ensure_topic(
+    system: &SharedSystem,
+    stream_id: u32,
+    stream_name: &str,
+    topic: &Topic,
+) -> Result<(), Error> {
+    let session = Session::internal(
+        DEFAULT_ROOT_USER_ID,
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+    );
+    let stream_identifier = Identifier::numeric(stream_id)?;
+    let topic_identifier = Identifier::numeric(topic.id)?;
+    // Avoid acquiring the write lock on every replication attempt, once the topic is already in place.
+    if let Ok(stream) = system.read().get_stream(&stream_identifier) {
+        if let Ok(local_topic) = stream.get_topic(&topic_identifier) {
+            if local_topic.get_partitions_count() >= topic.partitions_count {
+                return Ok(());
+            }
+        }
+    }
+
+    let mut system = system.write();
+    if system.get_stream(&stream_identifier).is_err() {
+        info!("Creating the replicated stream with ID: {stream_id}...");
+        system
+            .create_stream(&session, stream_id, stream_name)
+            .await?;
+    }
+
+    let partitions_count = match system
+        .get_stream(&stream_identifier)?
+        .get_topic(&topic_identifier)
+    {
+        Ok(topic) => topic.get_partitions_count(),
+        Err(_) => {
+            info!(
+                "Creating the replicated topic with ID: {} for stream with ID: {stream_id}...",
+                topic.id
+            );
+            system
+                .create_topic(
+                    &session,
+                    &stream_identifier,
+                    topic.id,
+                    &topic.name,
+                    topic.partitions_count,
+                    topic.message_expiry,
+                    topic.max_topic_size,
+                    topic.replication_factor,
+                    Some(topic.compression_algorithm),
+                    topic.cleanup_policy,
+                    topic.max_topic_size_policy,
+                    topic.flush_policy,
+                )
+                .await?;
+            return Ok(());
+        }
+    };
+
+    if partitions_count < topic.partitions_count {
+        system
+            .create_partitions(
+                &session,
+                &stream_identifier,
+                &topic_identifier,
+                topic.partitions_count - partitions_count,
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+async fn replicate_partition(
+    client: &TcpClient,
+    system: &SharedSystem,
+    config: &ReplicationConfig,
+    stream_id: u32,
+    topic_id: u32,
+    partition_id: u32,
+) -> Result<(), Error> {
+    let stream_identifier = Identifier::numeric(stream_id)?;
+    let topic_identifier = Identifier::numeric(topic_id)?;
+    let partition = system
+        .read()
+        .get_stream(&stream_identifier)?
+        .get_topic(&topic_identifier)?
+        .get_partition(partition_id)?;
+    let offset = partition.read().await.get_next_offset();
+    let polled_messages = client
+        .fetch_replica_messages(&FetchReplicaMessages {
+            stream_id: stream_identifier,
+            topic_id: topic_identifier,
+            partition_id,
+            replica_id: config.node_id,
+            offset,
+            count: config.fetch_messages_count,
+        })
+        .await?;
+    if polled_messages.messages.is_empty() {
+        return Ok(());
+    }
+
+    trace!(
+        "Fetched {} messages from offset: {offset} for partition with ID: {partition_id}, topic with ID: {topic_id}, stream with ID: {stream_id}.",
+        polled_messages.messages.len()
+    );
+    let mut partition = partition.write().await;
+    partition
+        .append_replicated_messages(polled_messages.messages)
+        .await
+}
+
+#[async_trait]
+impl ServerCommand<ReplicatePartitionsCommand> for ReplicatePartitionsExecutor {
+    async fn execute(&mut self, system: &SharedSystem, _command: ReplicatePartitionsCommand) {
+        if let Err(error) = self.replicate(system).await {
+            error!("Failed to replicate partitions from the leader node. Error: {error}");
+            // The connection will be established again on the next replication attempt.
+            self.client = None;
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<ReplicatePartitionsCommand>,
+    ) {
+        let partitions_replicator = PartitionsReplicator::new(&config.system.replication, sender);
+        partitions_replicator.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<ReplicatePartitionsCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Partitions replicator receiver stopped.");
+        });
+    }
+}
@@ -9,14 +9,17 @@ use tokio::time;
 use tracing::{error, info, warn};
 
 pub struct MessagesSaver {
+    enabled: bool,
     enforce_fsync: bool,
     interval: IggyDuration,
+    flush_policy_interval: IggyDuration,
     sender: Sender<SaveMessagesCommand>,
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct SaveMessagesCommand {
     pub enforce_fsync: bool,
+    pub max_latency_only: bool,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -25,13 +28,16 @@ pub struct SaveMessagesExecutor;
 impl MessagesSaver {
     pub fn new(config: &MessageSaverConfig, sender: Sender<SaveMessagesCommand>) -> Self {
         Self {
+            enabled: config.enabled,
             enforce_fsync: config.enforce_fsync,
             interval: config.interval,
+            flush_policy_interval: config.flush_policy_interval,
             sender,
         }
     }
 
     pub fn start(&self) {
+        self.start_max_latency_checker();
         if !self.enforce_fsync {
             info!("Message saver is disabled.");
             return;
@@ -49,7 +55,39 @@ impl MessagesSaver {
             let mut interval_timer = time::interval(interval.get_duration());
             loop {
                 interval_timer.tick().await;
-                let command = SaveMessagesCommand { enforce_fsync };
+                let command = SaveMessagesCommand {
+                    enforce_fsync,
+                    max_latency_only: false,
+                };
+                sender.send(command).unwrap_or_else(|error| {
+                    error!("Failed to send SaveMessagesCommand. Error: {}", error);
+                });
+            }
+        });
+    }
+
+    fn start_max_latency_checker(&self) {
+        if !self.enabled || self.flush_policy_interval.is_zero() {
+            info!("Flush policy max latency checker is disabled.");
+            return;
+        }
+
+        let enforce_fsync = self.enforce_fsync;
+        let interval = self.flush_policy_interval;
+        let sender = self.sender.clone();
+        info!(
+            "Flush policy max latency checker is enabled, buffered messages exceeding the max latency of their topic will be saved every: {:?}.",
+            interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                let command = SaveMessagesCommand {
+                    enforce_fsync,
+                    max_latency_only: true,
+                };
                 sender.send(command).unwrap_or_else(|error| {
                     error!("Failed to send SaveMessagesCommand. Error: {}", error);
                 });
@@ -60,7 +98,21 @@ impl MessagesSaver {
 
 #[async_trait]
 impl ServerCommand<SaveMessagesCommand> for SaveMessagesExecutor {
-    async fn execute(&mut self, system: &SharedSystem, _command: SaveMessagesCommand) {
+    async fn execute(&mut self, system: &SharedSystem, command: SaveMessagesCommand) {
+        if command.max_latency_only {
+            system
+                .read()
+                .persist_messages_exceeding_max_latency()
+                .await
+                .unwrap_or_else(|error| {
+                    error!(
+                        "Couldn't save buffered messages exceeding the max latency on disk. Error: {}",
+                        error
+                    );
+                });
+            return;
+        }
+
         system
             .write()
             .persist_messages()
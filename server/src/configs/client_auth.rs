@@ -0,0 +1,144 @@
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct ClientAuthConfig {
+    pub enabled: bool,
+    pub required: bool,
+    pub ca_file: String,
+    pub users: Vec<ClientCertificateUserConfig>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ClientCertificateUserConfig {
+    pub identity: CertificateIdentity,
+    pub username: String,
+}
+
+/// The identity of the client certificate, each kind is matched only against the same field of the certificate,
+/// so e.g. the DNS name can't be mapped to the user by putting it into the common name of the certificate.
+/// It's configured as the string with the kind prefix, e.g. `cn:service-a` or `dns:service-a.iggy.local`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum CertificateIdentity {
+    /// The whole subject, e.g. `subject:CN=service-a, O=iggy`.
+    Subject(String),
+    /// The subject common name, e.g. `cn:service-a`.
+    CommonName(String),
+    /// The DNS subject alternative name, e.g. `dns:service-a.iggy.local`.
+    Dns(String),
+    /// The email subject alternative name, e.g. `email:service-a@iggy.rs`.
+    Email(String),
+    /// The URI subject alternative name, e.g. `uri:spiffe://iggy.rs/service-a`.
+    Uri(String),
+}
+
+impl ClientAuthConfig {
+    /// Returns the username mapped to the first matching identity of the client certificate.
+    pub fn get_username(&self, identities: &[CertificateIdentity]) -> Option<&str> {
+        self.users
+            .iter()
+            .find(|user| identities.contains(&user.identity))
+            .map(|user| user.username.as_str())
+    }
+}
+
+impl FromStr for CertificateIdentity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, value) = s
+            .split_once(':')
+            .ok_or_else(|| format!("Certificate identity: {s} has no kind prefix"))?;
+        if value.is_empty() {
+            return Err(format!("Certificate identity: {s} has no value"));
+        }
+
+        let value = value.to_string();
+        match kind {
+            "subject" => Ok(CertificateIdentity::Subject(value)),
+            "cn" => Ok(CertificateIdentity::CommonName(value)),
+            "dns" => Ok(CertificateIdentity::Dns(value)),
+            "email" => Ok(CertificateIdentity::Email(value)),
+            "uri" => Ok(CertificateIdentity::Uri(value)),
+            _ => Err(format!(
+                "Invalid certificate identity kind: {kind}, expected one of: subject, cn, dns, email, uri"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for CertificateIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CertificateIdentity::Subject(value) => write!(f, "subject:{value}"),
+            CertificateIdentity::CommonName(value) => write!(f, "cn:{value}"),
+            CertificateIdentity::Dns(value) => write!(f, "dns:{value}"),
+            CertificateIdentity::Email(value) => write!(f, "email:{value}"),
+            CertificateIdentity::Uri(value) => write!(f, "uri:{value}"),
+        }
+    }
+}
+
+struct CertificateIdentityVisitor;
+
+impl<'de> Visitor<'de> for CertificateIdentityVisitor {
+    type Value = CertificateIdentity;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a certificate identity with the kind prefix, e.g. cn:service-a")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        CertificateIdentity::from_str(value).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for CertificateIdentity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CertificateIdentity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(CertificateIdentityVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn certificate_identity_should_be_parsed_by_kind_prefix() {
+        let identity: CertificateIdentity = "cn:service-a".parse().unwrap();
+        assert_eq!(
+            identity,
+            CertificateIdentity::CommonName("service-a".to_string())
+        );
+        let identity: CertificateIdentity = "uri:spiffe://iggy.rs/service-a".parse().unwrap();
+        assert_eq!(
+            identity,
+            CertificateIdentity::Uri("spiffe://iggy.rs/service-a".to_string())
+        );
+        assert_eq!(identity.to_string(), "uri:spiffe://iggy.rs/service-a");
+    }
+
+    #[test]
+    fn certificate_identity_without_valid_kind_prefix_should_not_be_parsed() {
+        assert!("service-a".parse::<CertificateIdentity>().is_err());
+        assert!("ip:127.0.0.1".parse::<CertificateIdentity>().is_err());
+        assert!("dns:".parse::<CertificateIdentity>().is_err());
+    }
+}
@@ -73,7 +73,8 @@ impl CustomEnvProvider {
         let mut current_source = source;
         let mut current_target = target;
 
-        for i in 0..keys.len() {
+        let mut i = 0;
+        while i < keys.len() {
             let combined_keys = keys[i..].join("_");
 
             if current_source.contains_key(&combined_keys) {
@@ -81,24 +82,33 @@ impl CustomEnvProvider {
                 return;
             }
 
-            let key = &keys[i];
-            match current_source.get(key) {
-                Some(FigmentValue::Dict(_, inner_source_dict)) => {
-                    if !current_target.contains_key(key) {
-                        current_target
-                            .insert(key.clone(), FigmentValue::Dict(Tag::Default, Dict::new()));
-                    }
-
-                    if let Some(FigmentValue::Dict(_, ref mut actual_inner_target_dict)) =
-                        current_target.get_mut(key)
-                    {
-                        current_source = inner_source_dict;
-                        current_target = actual_inner_target_dict;
-                    } else {
-                        return;
+            // The name of the nested table might contain underscores as well, e.g. `client_auth`.
+            let nested_table = (i + 1..keys.len()).find_map(|j| {
+                let key = keys[i..j].join("_");
+                match current_source.get(&key) {
+                    Some(FigmentValue::Dict(_, inner_source_dict)) => {
+                        Some((key, inner_source_dict, j))
                     }
+                    _ => None,
                 }
-                _ => return,
+            });
+            let (key, inner_source_dict, next_key_index) = match nested_table {
+                Some(nested_table) => nested_table,
+                None => return,
+            };
+
+            if !current_target.contains_key(&key) {
+                current_target.insert(key.clone(), FigmentValue::Dict(Tag::Default, Dict::new()));
+            }
+
+            if let Some(FigmentValue::Dict(_, ref mut actual_inner_target_dict)) =
+                current_target.get_mut(&key)
+            {
+                current_source = inner_source_dict;
+                current_target = actual_inner_target_dict;
+                i = next_key_index;
+            } else {
+                return;
             }
         }
     }
@@ -137,6 +147,14 @@ impl CustomEnvProvider {
         if let Ok(float_val) = value.parse::<f64>() {
             return FigmentValue::from(float_val);
         }
+        // The arrays are expected in the TOML format, e.g. `[2, 3]`.
+        if value.starts_with('[') && value.ends_with(']') {
+            if let Ok(TomlValue::Table(table)) = toml::from_str(&format!("value = {value}")) {
+                if let Some(array) = table.get("value") {
+                    return Self::toml_to_figment_value(array);
+                }
+            }
+        }
         FigmentValue::from(value)
     }
 }
@@ -1,25 +1,34 @@
+use crate::configs::client_auth::ClientAuthConfig;
 use crate::configs::http::{
     HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig,
 };
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
-    MessageCleanerConfig, MessageSaverConfig, PersonalAccessTokenCleanerConfig,
-    PersonalAccessTokenConfig, ServerConfig,
+    ConsumerGroupSessionCheckerConfig, MessageCleanerConfig, MessageCompactorConfig,
+    MessageSaverConfig, PersonalAccessTokenCleanerConfig, PersonalAccessTokenConfig, ServerConfig,
 };
 use crate::configs::system::{
-    CacheConfig, CompressionConfig, DatabaseConfig, EncryptionConfig, LoggingConfig,
-    MessageDeduplicationConfig, PartitionConfig, RetentionPolicyConfig, RuntimeConfig,
+    AuditLogConfig, CacheConfig, CompressionConfig, ConsumerGroupConfig, DatabaseBackend,
+    DatabaseConfig, DeadLetterQueueConfig, EncryptionConfig, LoggingConfig, LoginAttemptsConfig,
+    MessageDeduplicationConfig, PartitionConfig, QuotaLimitsConfig, QuotaMode, QuotasConfig,
+    ReplicaRole, ReplicationAck, ReplicationConfig, RetentionPolicyConfig, RuntimeConfig,
     SegmentConfig, StreamConfig, SystemConfig, TopicConfig,
 };
+use crate::configs::system::{
+    LocalTieredStorageConfig, S3TieredStorageConfig, TieredStorageBackend, TieredStorageConfig,
+};
 use crate::configs::tcp::{TcpConfig, TcpTlsConfig};
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
 use std::sync::Arc;
 
 impl Default for ServerConfig {
     fn default() -> ServerConfig {
         ServerConfig {
             message_cleaner: MessageCleanerConfig::default(),
+            message_compactor: MessageCompactorConfig::default(),
             message_saver: MessageSaverConfig::default(),
             personal_access_token: PersonalAccessTokenConfig::default(),
+            consumer_group_session_checker: ConsumerGroupSessionCheckerConfig::default(),
             system: Arc::new(SystemConfig::default()),
             quic: QuicConfig::default(),
             tcp: TcpConfig::default(),
@@ -41,6 +50,7 @@ impl Default for QuicConfig {
             keep_alive_interval: "5s".parse().unwrap(),
             max_idle_timeout: "10s".parse().unwrap(),
             certificate: QuicCertificateConfig::default(),
+            client_auth: ClientAuthConfig::default(),
         }
     }
 }
@@ -106,12 +116,23 @@ impl Default for MessageCleanerConfig {
     }
 }
 
+impl Default for MessageCompactorConfig {
+    fn default() -> MessageCompactorConfig {
+        MessageCompactorConfig {
+            enabled: true,
+            interval: "1m".parse().unwrap(),
+            tombstone_retention: "24h".parse().unwrap(),
+        }
+    }
+}
+
 impl Default for MessageSaverConfig {
     fn default() -> MessageSaverConfig {
         MessageSaverConfig {
             enabled: true,
             enforce_fsync: true,
             interval: "30s".parse().unwrap(),
+            flush_policy_interval: "100ms".parse().unwrap(),
         }
     }
 }
@@ -134,6 +155,15 @@ impl Default for PersonalAccessTokenCleanerConfig {
     }
 }
 
+impl Default for ConsumerGroupSessionCheckerConfig {
+    fn default() -> ConsumerGroupSessionCheckerConfig {
+        ConsumerGroupSessionCheckerConfig {
+            enabled: true,
+            interval: "1s".parse().unwrap(),
+        }
+    }
+}
+
 impl Default for SystemConfig {
     fn default() -> SystemConfig {
         SystemConfig {
@@ -150,6 +180,13 @@ impl Default for SystemConfig {
             segment: SegmentConfig::default(),
             compression: CompressionConfig::default(),
             message_deduplication: MessageDeduplicationConfig::default(),
+            dead_letter_queue: DeadLetterQueueConfig::default(),
+            audit_log: AuditLogConfig::default(),
+            quotas: QuotasConfig::default(),
+            login_attempts: LoginAttemptsConfig::default(),
+            consumer_group: ConsumerGroupConfig::default(),
+            replication: ReplicationConfig::default(),
+            tiered_storage: TieredStorageConfig::default(),
         }
     }
 }
@@ -158,6 +195,7 @@ impl Default for DatabaseConfig {
     fn default() -> DatabaseConfig {
         DatabaseConfig {
             path: "database".to_string(),
+            backend: DatabaseBackend::Sled,
         }
     }
 }
@@ -231,6 +269,7 @@ impl Default for PartitionConfig {
             messages_required_to_save: 1000,
             enforce_fsync: false,
             validate_checksum: false,
+            recover_segments: false,
         }
     }
 }
@@ -241,6 +280,8 @@ impl Default for SegmentConfig {
             size: "1 GB".parse().unwrap(),
             cache_indexes: true,
             cache_time_indexes: true,
+            index_interval_messages: 1,
+            index_interval_bytes: "0".parse().unwrap(),
         }
     }
 }
@@ -254,3 +295,112 @@ impl Default for MessageDeduplicationConfig {
         }
     }
 }
+
+impl Default for ConsumerGroupConfig {
+    fn default() -> ConsumerGroupConfig {
+        ConsumerGroupConfig {
+            session_timeout: "30s".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for LoginAttemptsConfig {
+    fn default() -> LoginAttemptsConfig {
+        LoginAttemptsConfig {
+            enabled: true,
+            free_attempts: 3,
+            initial_backoff: "1s".parse().unwrap(),
+            max_backoff: "30s".parse().unwrap(),
+            user_max_failed_attempts: 10,
+            ip_max_failed_attempts: 50,
+            lockout_duration: "15m".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for QuotasConfig {
+    fn default() -> QuotasConfig {
+        QuotasConfig {
+            enabled: false,
+            mode: QuotaMode::Throttle,
+            max_throttle_delay: "1s".parse().unwrap(),
+            user: QuotaLimitsConfig::default(),
+            client: QuotaLimitsConfig::default(),
+            user_overrides: Vec::new(),
+        }
+    }
+}
+
+impl Default for ReplicationConfig {
+    fn default() -> ReplicationConfig {
+        ReplicationConfig {
+            enabled: false,
+            node_id: 1,
+            role: ReplicaRole::Leader,
+            leader_address: "127.0.0.1:8090".to_string(),
+            follower_ids: Vec::new(),
+            username: DEFAULT_ROOT_USERNAME.to_string(),
+            password: DEFAULT_ROOT_PASSWORD.to_string(),
+            fetch_interval: "100ms".parse().unwrap(),
+            fetch_messages_count: 1000,
+            replica_lag_timeout: "10s".parse().unwrap(),
+            ack: ReplicationAck::Leader,
+            ack_timeout: "5s".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for TieredStorageConfig {
+    fn default() -> TieredStorageConfig {
+        TieredStorageConfig {
+            enabled: false,
+            interval: "1m".parse().unwrap(),
+            hot_window: "7 days".parse().unwrap(),
+            backend: TieredStorageBackend::Local,
+            local: LocalTieredStorageConfig::default(),
+            s3: S3TieredStorageConfig::default(),
+        }
+    }
+}
+
+impl Default for LocalTieredStorageConfig {
+    fn default() -> LocalTieredStorageConfig {
+        LocalTieredStorageConfig {
+            path: "local_data_tiered".to_string(),
+        }
+    }
+}
+
+impl Default for S3TieredStorageConfig {
+    fn default() -> S3TieredStorageConfig {
+        S3TieredStorageConfig {
+            endpoint: "http://127.0.0.1:9000".to_string(),
+            region: "us-east-1".to_string(),
+            bucket: "iggy".to_string(),
+            access_key_id: "".to_string(),
+            secret_access_key: "".to_string(),
+        }
+    }
+}
+
+impl Default for DeadLetterQueueConfig {
+    fn default() -> DeadLetterQueueConfig {
+        DeadLetterQueueConfig {
+            enabled: false,
+            stream: "dead-letters".to_string(),
+            topic: "dead-letters".to_string(),
+        }
+    }
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> AuditLogConfig {
+        AuditLogConfig {
+            enabled: true,
+            path: "audit.log".to_string(),
+            mirror: false,
+            stream: "audit".to_string(),
+            topic: "audit".to_string(),
+        }
+    }
+}
@@ -1,13 +1,20 @@
+use crate::configs::client_auth::ClientAuthConfig;
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::system::MessageDeduplicationConfig;
 use crate::configs::{
     http::{HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig},
     resource_quota::MemoryResourceQuota,
-    server::{MessageCleanerConfig, MessageSaverConfig, ServerConfig},
+    server::{
+        ConsumerGroupSessionCheckerConfig, MessageCleanerConfig, MessageCompactorConfig,
+        MessageSaverConfig, ServerConfig,
+    },
     system::{
-        CacheConfig, CompressionConfig, DatabaseConfig, EncryptionConfig, LoggingConfig,
-        PartitionConfig, RetentionPolicyConfig, SegmentConfig, StreamConfig, SystemConfig,
-        TopicConfig,
+        AuditLogConfig, CacheConfig, CompressionConfig, ConsumerGroupConfig, DatabaseBackend,
+        DatabaseConfig, DeadLetterQueueConfig, EncryptionConfig, LoggingConfig,
+        LoginAttemptsConfig, PartitionConfig, QuotaLimitsConfig, QuotaMode, QuotasConfig,
+        ReplicaRole, ReplicationAck, ReplicationConfig, RetentionPolicyConfig, SegmentConfig,
+        StreamConfig, SystemConfig, TieredStorageBackend, TieredStorageConfig, TopicConfig,
+        UserQuotaLimitsConfig,
     },
     tcp::{TcpConfig, TcpTlsConfig},
 };
@@ -67,7 +74,7 @@ impl Display for QuicConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ enabled: {}, address: {}, max_concurrent_bidi_streams: {}, datagram_send_buffer_size: {}, initial_mtu: {}, send_window: {}, receive_window: {}, keep_alive_interval: {}, max_idle_timeout: {}, certificate: {} }}",
+          "{{ enabled: {}, address: {}, max_concurrent_bidi_streams: {}, datagram_send_buffer_size: {}, initial_mtu: {}, send_window: {}, receive_window: {}, keep_alive_interval: {}, max_idle_timeout: {}, certificate: {}, client_auth: {} }}",
           self.enabled,
           self.address,
           self.max_concurrent_bidi_streams,
@@ -77,7 +84,8 @@ impl Display for QuicConfig {
           self.receive_window,
           self.keep_alive_interval,
           self.max_idle_timeout,
-          self.certificate
+          self.certificate,
+          self.client_auth
       )
     }
 }
@@ -115,8 +123,14 @@ impl Display for ServerConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ message_cleaner: {}, message_saver: {}, system: {}, quic: {}, tcp: {}, http: {} }}",
-            self.message_cleaner, self.message_saver, self.system, self.quic, self.tcp, self.http
+            "{{ message_cleaner: {}, message_compactor: {}, message_saver: {}, system: {}, quic: {}, tcp: {}, http: {} }}",
+            self.message_cleaner,
+            self.message_compactor,
+            self.message_saver,
+            self.system,
+            self.quic,
+            self.tcp,
+            self.http
         )
     }
 }
@@ -131,19 +145,48 @@ impl Display for MessageCleanerConfig {
     }
 }
 
+impl Display for MessageCompactorConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {}, tombstone_retention: {} }}",
+            self.enabled, self.interval, self.tombstone_retention
+        )
+    }
+}
+
+impl Display for ConsumerGroupSessionCheckerConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {} }}",
+            self.enabled, self.interval
+        )
+    }
+}
+
 impl Display for MessageSaverConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, enforce_fsync: {}, interval: {} }}",
-            self.enabled, self.enforce_fsync, self.interval
+            "{{ enabled: {}, enforce_fsync: {}, interval: {}, flush_policy_interval: {} }}",
+            self.enabled, self.enforce_fsync, self.interval, self.flush_policy_interval
         )
     }
 }
 
 impl Display for DatabaseConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{ path: {} }}", self.path)
+        write!(f, "{{ path: {}, backend: {} }}", self.path, self.backend)
+    }
+}
+
+impl Display for DatabaseBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DatabaseBackend::Sled => write!(f, "sled"),
+            DatabaseBackend::Redb => write!(f, "redb"),
+        }
     }
 }
 
@@ -186,11 +229,12 @@ impl Display for PartitionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {} }}",
+          "{{ path: {}, messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {}, recover_segments: {} }}",
           self.path,
           self.messages_required_to_save,
           self.enforce_fsync,
-          self.validate_checksum
+          self.validate_checksum,
+          self.recover_segments
       )
     }
 }
@@ -205,12 +249,174 @@ impl Display for MessageDeduplicationConfig {
     }
 }
 
+impl Display for ConsumerGroupConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ session_timeout: {} }}", self.session_timeout)
+    }
+}
+
+impl Display for DeadLetterQueueConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, stream: {}, topic: {} }}",
+            self.enabled, self.stream, self.topic
+        )
+    }
+}
+
+impl Display for AuditLogConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, path: {}, mirror: {}, stream: {}, topic: {} }}",
+            self.enabled, self.path, self.mirror, self.stream, self.topic
+        )
+    }
+}
+
+impl Display for LoginAttemptsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, free_attempts: {}, initial_backoff: {}, max_backoff: {}, user_max_failed_attempts: {}, ip_max_failed_attempts: {}, lockout_duration: {} }}",
+            self.enabled,
+            self.free_attempts,
+            self.initial_backoff,
+            self.max_backoff,
+            self.user_max_failed_attempts,
+            self.ip_max_failed_attempts,
+            self.lockout_duration
+        )
+    }
+}
+
+impl Display for QuotasConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, mode: {}, max_throttle_delay: {}, user: {}, client: {}, user_overrides: [{}] }}",
+            self.enabled,
+            self.mode,
+            self.max_throttle_delay,
+            self.user,
+            self.client,
+            self.user_overrides
+                .iter()
+                .map(|user| user.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+impl Display for UserQuotaLimitsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ user_id: {}, limits: {} }}",
+            self.user_id, self.limits
+        )
+    }
+}
+
+impl Display for QuotaMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuotaMode::Throttle => write!(f, "throttle"),
+            QuotaMode::Reject => write!(f, "reject"),
+        }
+    }
+}
+
+impl Display for QuotaLimitsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ requests_per_second: {}, send_messages_per_second: {}, send_bytes_per_second: {}, poll_messages_per_second: {}, poll_bytes_per_second: {} }}",
+            self.requests_per_second,
+            self.send_messages_per_second,
+            self.send_bytes_per_second,
+            self.poll_messages_per_second,
+            self.poll_bytes_per_second
+        )
+    }
+}
+
+impl Display for ReplicationConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, node_id: {}, role: {}, leader_address: {}, follower_ids: {:?}, username: {}, fetch_interval: {}, fetch_messages_count: {}, replica_lag_timeout: {}, ack: {}, ack_timeout: {} }}",
+            self.enabled,
+            self.node_id,
+            self.role,
+            self.leader_address,
+            self.follower_ids,
+            self.username,
+            self.fetch_interval,
+            self.fetch_messages_count,
+            self.replica_lag_timeout,
+            self.ack,
+            self.ack_timeout
+        )
+    }
+}
+
+impl Display for TieredStorageConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {}, hot_window: {}, backend: {}, local_path: {}, s3_endpoint: {}, s3_region: {}, s3_bucket: {} }}",
+            self.enabled,
+            self.interval,
+            self.hot_window,
+            self.backend,
+            self.local.path,
+            self.s3.endpoint,
+            self.s3.region,
+            self.s3.bucket
+        )
+    }
+}
+
+impl Display for TieredStorageBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TieredStorageBackend::Local => write!(f, "local"),
+            TieredStorageBackend::S3 => write!(f, "s3"),
+        }
+    }
+}
+
+impl Display for ReplicaRole {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplicaRole::Leader => write!(f, "leader"),
+            ReplicaRole::Follower => write!(f, "follower"),
+        }
+    }
+}
+
+impl Display for ReplicationAck {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplicationAck::Leader => write!(f, "leader"),
+            ReplicationAck::InSyncReplicas => write!(f, "in_sync_replicas"),
+        }
+    }
+}
+
 impl Display for SegmentConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ size_bytes: {}, cache_indexes: {}, cache_time_indexes: {} }}",
-            self.size, self.cache_indexes, self.cache_time_indexes
+            "{{ size_bytes: {}, cache_indexes: {}, cache_time_indexes: {}, index_interval_messages: {}, index_interval_bytes: {} }}",
+            self.size,
+            self.cache_indexes,
+            self.cache_time_indexes,
+            self.index_interval_messages,
+            self.index_interval_bytes
         )
     }
 }
@@ -242,8 +448,25 @@ impl Display for TcpTlsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, certificate: {} }}",
-            self.enabled, self.certificate
+            "{{ enabled: {}, certificate: {}, client_auth: {} }}",
+            self.enabled, self.certificate, self.client_auth
+        )
+    }
+}
+
+impl Display for ClientAuthConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, required: {}, ca_file: {}, users: {} }}",
+            self.enabled,
+            self.required,
+            self.ca_file,
+            self.users
+                .iter()
+                .map(|user| format!("{} -> {}", user.identity, user.username))
+                .collect::<Vec<_>>()
+                .join(", ")
         )
     }
 }
@@ -5,6 +5,7 @@ pub mod http;
 pub mod quic;
 pub mod tcp;
 
+pub mod client_auth;
 pub mod config_provider;
 pub mod defaults;
 pub mod displays;
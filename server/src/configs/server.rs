@@ -14,8 +14,10 @@ use std::sync::Arc;
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ServerConfig {
     pub message_cleaner: MessageCleanerConfig,
+    pub message_compactor: MessageCompactorConfig,
     pub message_saver: MessageSaverConfig,
     pub personal_access_token: PersonalAccessTokenConfig,
+    pub consumer_group_session_checker: ConsumerGroupSessionCheckerConfig,
     pub system: Arc<SystemConfig>,
     pub quic: QuicConfig,
     pub tcp: TcpConfig,
@@ -30,6 +32,16 @@ pub struct MessageCleanerConfig {
     pub interval: IggyDuration,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct MessageCompactorConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub tombstone_retention: IggyDuration,
+}
+
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct MessageSaverConfig {
@@ -37,6 +49,8 @@ pub struct MessageSaverConfig {
     pub enforce_fsync: bool,
     #[serde_as(as = "DisplayFromStr")]
     pub interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub flush_policy_interval: IggyDuration,
 }
 
 #[derive(Debug, Deserialize, Serialize, Copy, Clone)]
@@ -53,6 +67,14 @@ pub struct PersonalAccessTokenCleanerConfig {
     pub interval: IggyDuration,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+pub struct ConsumerGroupSessionCheckerConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+}
+
 impl ServerConfig {
     pub async fn load(config_provider: &dyn ConfigProvider) -> Result<ServerConfig, ServerError> {
         let server_config = config_provider.load_config().await?;
@@ -6,6 +6,7 @@ use iggy::{
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use serde_with::DisplayFromStr;
+use std::path::Path;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SystemConfig {
@@ -22,11 +23,26 @@ pub struct SystemConfig {
     pub encryption: EncryptionConfig,
     pub compression: CompressionConfig,
     pub message_deduplication: MessageDeduplicationConfig,
+    pub dead_letter_queue: DeadLetterQueueConfig,
+    pub audit_log: AuditLogConfig,
+    pub quotas: QuotasConfig,
+    pub login_attempts: LoginAttemptsConfig,
+    pub consumer_group: ConsumerGroupConfig,
+    pub replication: ReplicationConfig,
+    pub tiered_storage: TieredStorageConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DatabaseConfig {
     pub path: String,
+    pub backend: DatabaseBackend,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum DatabaseBackend {
+    Sled,
+    Redb,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -86,6 +102,7 @@ pub struct PartitionConfig {
     pub messages_required_to_save: u32,
     pub enforce_fsync: bool,
     pub validate_checksum: bool,
+    pub recover_segments: bool,
 }
 
 #[serde_as]
@@ -97,11 +114,164 @@ pub struct MessageDeduplicationConfig {
     pub expiry: IggyDuration,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DeadLetterQueueConfig {
+    pub enabled: bool,
+    pub stream: String,
+    pub topic: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AuditLogConfig {
+    pub enabled: bool,
+    pub path: String,
+    pub mirror: bool,
+    pub stream: String,
+    pub topic: String,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct QuotasConfig {
+    pub enabled: bool,
+    pub mode: QuotaMode,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_throttle_delay: IggyDuration,
+    pub user: QuotaLimitsConfig,
+    pub client: QuotaLimitsConfig,
+    pub user_overrides: Vec<UserQuotaLimitsConfig>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotaMode {
+    Throttle,
+    Reject,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone, Copy)]
+pub struct QuotaLimitsConfig {
+    pub requests_per_second: u64,
+    pub send_messages_per_second: u64,
+    pub send_bytes_per_second: IggyByteSize,
+    pub poll_messages_per_second: u64,
+    pub poll_bytes_per_second: IggyByteSize,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct UserQuotaLimitsConfig {
+    pub user_id: u32,
+    #[serde(flatten)]
+    pub limits: QuotaLimitsConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct LoginAttemptsConfig {
+    pub enabled: bool,
+    pub free_attempts: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub initial_backoff: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_backoff: IggyDuration,
+    pub user_max_failed_attempts: u32,
+    pub ip_max_failed_attempts: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub lockout_duration: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ConsumerGroupConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub session_timeout: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ReplicationConfig {
+    pub enabled: bool,
+    pub node_id: u32,
+    pub role: ReplicaRole,
+    pub leader_address: String,
+    pub follower_ids: Vec<u32>,
+    pub username: String,
+    pub password: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub fetch_interval: IggyDuration,
+    pub fetch_messages_count: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub replica_lag_timeout: IggyDuration,
+    pub ack: ReplicationAck,
+    #[serde_as(as = "DisplayFromStr")]
+    pub ack_timeout: IggyDuration,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplicaRole {
+    Leader,
+    Follower,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplicationAck {
+    Leader,
+    InSyncReplicas,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TieredStorageConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub hot_window: IggyDuration,
+    pub backend: TieredStorageBackend,
+    pub local: LocalTieredStorageConfig,
+    pub s3: S3TieredStorageConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TieredStorageBackend {
+    Local,
+    S3,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LocalTieredStorageConfig {
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct S3TieredStorageConfig {
+    pub endpoint: String,
+    pub region: String,
+    pub bucket: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SegmentConfig {
     pub size: IggyByteSize,
     pub cache_indexes: bool,
     pub cache_time_indexes: bool,
+    pub index_interval_messages: u32,
+    pub index_interval_bytes: IggyByteSize,
+}
+
+impl QuotaLimitsConfig {
+    pub fn is_unlimited(&self) -> bool {
+        self.requests_per_second == 0
+            && self.send_messages_per_second == 0
+            && self.send_bytes_per_second.as_bytes_u64() == 0
+            && self.poll_messages_per_second == 0
+            && self.poll_bytes_per_second.as_bytes_u64() == 0
+    }
 }
 
 impl SystemConfig {
@@ -109,8 +279,20 @@ impl SystemConfig {
         self.path.to_string()
     }
 
+    /// The redb database is a single file, so it gets the `.redb` extension (unless the path already has one),
+    /// to not collide with the sled database directory using the same configured path.
     pub fn get_database_path(&self) -> String {
-        format!("{}/{}", self.get_system_path(), self.database.path)
+        let path = format!("{}/{}", self.get_system_path(), self.database.path);
+        match self.database.backend {
+            DatabaseBackend::Redb if Path::new(&path).extension().is_none() => {
+                format!("{path}.redb")
+            }
+            _ => path,
+        }
+    }
+
+    pub fn get_audit_log_path(&self) -> String {
+        format!("{}/{}", self.get_system_path(), self.audit_log.path)
     }
 
     pub fn get_runtime_path(&self) -> String {
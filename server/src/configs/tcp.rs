@@ -1,3 +1,4 @@
+use crate::configs::client_auth::ClientAuthConfig;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -12,4 +13,5 @@ pub struct TcpTlsConfig {
     pub enabled: bool,
     pub certificate: String,
     pub password: String,
+    pub client_auth: ClientAuthConfig,
 }
@@ -1,14 +1,23 @@
 extern crate sysinfo;
 
-use super::server::{MessageCleanerConfig, MessageSaverConfig};
-use super::system::CompressionConfig;
-use crate::configs::server::{PersonalAccessTokenConfig, ServerConfig};
+use super::server::{MessageCleanerConfig, MessageCompactorConfig, MessageSaverConfig};
+use super::system::{
+    AuditLogConfig, CompressionConfig, ConsumerGroupConfig, DeadLetterQueueConfig,
+    LoginAttemptsConfig, QuotaMode, QuotasConfig, ReplicaRole, ReplicationConfig,
+    TieredStorageBackend, TieredStorageConfig,
+};
+use crate::configs::server::{
+    ConsumerGroupSessionCheckerConfig, PersonalAccessTokenConfig, ServerConfig,
+};
 use crate::configs::system::{CacheConfig, RetentionPolicyConfig, SegmentConfig};
 use crate::server_error::ServerError;
 use crate::streaming::segments::segment;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::identifier::Identifier;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::validatable::Validatable;
+use std::collections::HashSet;
+use std::net::SocketAddr;
 use sysinfo::System;
 use tracing::{error, info, warn};
 
@@ -18,7 +27,16 @@ impl Validatable<ServerError> for ServerConfig {
         self.system.cache.validate()?;
         self.system.retention_policy.validate()?;
         self.system.compression.validate()?;
+        self.system.dead_letter_queue.validate()?;
+        self.system.audit_log.validate()?;
+        self.system.quotas.validate()?;
+        self.system.login_attempts.validate()?;
+        self.system.consumer_group.validate()?;
+        self.system.replication.validate()?;
+        self.system.tiered_storage.validate()?;
         self.personal_access_token.validate()?;
+        self.consumer_group_session_checker.validate()?;
+        self.message_compactor.validate()?;
 
         Ok(())
     }
@@ -28,9 +46,8 @@ impl Validatable<ServerError> for CompressionConfig {
     fn validate(&self) -> Result<(), ServerError> {
         let compression_alg = &self.default_algorithm;
         if *compression_alg != CompressionAlgorithm::None {
-            // TODO(numinex): Change this message once server side compression is fully developed.
-            warn!(
-                "Server started with server-side compression enabled, using algorithm: {}, this feature is not implemented yet!",
+            info!(
+                "Server started with server-side compression enabled, using algorithm: {}.",
                 compression_alg
             );
         }
@@ -39,6 +56,137 @@ impl Validatable<ServerError> for CompressionConfig {
     }
 }
 
+impl Validatable<ServerError> for DeadLetterQueueConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if Identifier::from_str_value(&self.stream).is_err()
+            || Identifier::from_str_value(&self.topic).is_err()
+        {
+            error!(
+                "Dead-letter queue configuration -> invalid stream: {} or topic: {}.",
+                self.stream, self.topic
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        info!(
+            "Dead-letter queue enabled, rejected messages will be copied to stream: {}, topic: {}.",
+            self.stream, self.topic
+        );
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for LoginAttemptsConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            warn!("Login attempts tracking is disabled, the failed logins will not be throttled.");
+            return Ok(());
+        }
+
+        if self.user_max_failed_attempts == 0 || self.ip_max_failed_attempts == 0 {
+            error!("Login attempts configuration -> max failed attempts cannot be zero.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.lockout_duration.is_zero() {
+            error!("Login attempts configuration -> lockout duration cannot be zero.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.initial_backoff.get_duration() > self.max_backoff.get_duration() {
+            error!(
+                "Login attempts configuration -> initial backoff: {} cannot be greater than max backoff: {}.",
+                self.initial_backoff, self.max_backoff
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for QuotasConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let mut user_ids = HashSet::new();
+        for user in &self.user_overrides {
+            if !user_ids.insert(user.user_id) {
+                error!(
+                    "Quotas configuration -> user with ID: {} has more than one override.",
+                    user.user_id
+                );
+                return Err(ServerError::InvalidConfiguration);
+            }
+        }
+
+        if self.user.is_unlimited()
+            && self.client.is_unlimited()
+            && self
+                .user_overrides
+                .iter()
+                .all(|user| user.limits.is_unlimited())
+        {
+            warn!("Quotas are enabled, but no limits are configured for the users or clients.");
+            return Ok(());
+        }
+
+        if self.mode == QuotaMode::Throttle && self.max_throttle_delay.is_zero() {
+            error!("Quotas configuration -> max throttle delay cannot be zero in throttle mode.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        info!(
+            "Quotas enabled in {} mode, user limits: {}, client limits: {}, overridden user limits: {}.",
+            self.mode,
+            self.user,
+            self.client,
+            self.user_overrides.len()
+        );
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for AuditLogConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            warn!("Audit log is disabled, the administrative and security-relevant actions will not be recorded.");
+            return Ok(());
+        }
+
+        if self.path.is_empty() {
+            error!("Audit log configuration -> path cannot be empty.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if !self.mirror {
+            return Ok(());
+        }
+
+        if Identifier::from_str_value(&self.stream).is_err()
+            || Identifier::from_str_value(&self.topic).is_err()
+        {
+            error!(
+                "Audit log configuration -> invalid stream: {} or topic: {}.",
+                self.stream, self.topic
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        info!(
+            "Audit log mirroring enabled, the entries will be also appended to stream: {}, topic: {}.",
+            self.stream, self.topic
+        );
+        Ok(())
+    }
+}
+
 impl Validatable<ServerError> for CacheConfig {
     fn validate(&self) -> Result<(), ServerError> {
         let limit_bytes = self.size.clone().into();
@@ -78,11 +226,6 @@ impl Validatable<ServerError> for CacheConfig {
 
 impl Validatable<ServerError> for RetentionPolicyConfig {
     fn validate(&self) -> Result<(), ServerError> {
-        // TODO(hubcio): Change this message once topic size based retention policy is fully developed.
-        if self.max_topic_size.as_bytes_u64() > 0 {
-            warn!("Retention policy max_topic_size is not implemented yet!");
-        }
-
         Ok(())
     }
 }
@@ -97,6 +240,13 @@ impl Validatable<ServerError> for SegmentConfig {
             return Err(ServerError::InvalidConfiguration);
         }
 
+        if self.index_interval_messages == 0 && self.index_interval_bytes.as_bytes_u64() == 0 {
+            error!(
+                "Segment configuration -> index interval messages and bytes cannot be both zero."
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
         Ok(())
     }
 }
@@ -123,6 +273,136 @@ impl Validatable<ServerError> for MessageCleanerConfig {
     }
 }
 
+impl Validatable<ServerError> for ConsumerGroupConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.session_timeout.is_zero() {
+            error!("Consumer group session timeout cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for ReplicationConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.node_id == 0 {
+            error!("Replication node ID cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        // The fetch interval is usually shorter than a second, thus it's checked with the full precision.
+        if self.fetch_interval.get_duration().is_zero() || self.fetch_messages_count == 0 {
+            error!(
+                "Replication fetch interval: {} and messages count: {} must be greater than 0.",
+                self.fetch_interval, self.fetch_messages_count
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.replica_lag_timeout.is_zero() || self.ack_timeout.is_zero() {
+            error!(
+                "Replication replica lag timeout: {} and ack timeout: {} must be greater than 0.",
+                self.replica_lag_timeout, self.ack_timeout
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.follower_ids.contains(&self.node_id) {
+            error!(
+                "Replication follower IDs: {:?} cannot contain the node ID: {}.",
+                self.follower_ids, self.node_id
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.role == ReplicaRole::Follower && self.leader_address.parse::<SocketAddr>().is_err()
+        {
+            error!(
+                "Replication leader address: {} is invalid, it must be a valid TCP address.",
+                self.leader_address
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        info!(
+            "Replication is enabled, node ID: {}, role: {}.",
+            self.node_id, self.role
+        );
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for TieredStorageConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.interval.get_duration().is_zero() {
+            error!("Tiered storage interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        match self.backend {
+            TieredStorageBackend::Local => {
+                if self.local.path.is_empty() {
+                    error!("Tiered storage local path cannot be empty.");
+                    return Err(ServerError::InvalidConfiguration);
+                }
+            }
+            TieredStorageBackend::S3 => {
+                if reqwest::Url::parse(&self.s3.endpoint).is_err() {
+                    error!(
+                        "Tiered storage S3 endpoint: {} is invalid, it must be a valid URL.",
+                        self.s3.endpoint
+                    );
+                    return Err(ServerError::InvalidConfiguration);
+                }
+
+                if self.s3.region.is_empty() || self.s3.bucket.is_empty() {
+                    error!("Tiered storage S3 region and bucket cannot be empty.");
+                    return Err(ServerError::InvalidConfiguration);
+                }
+            }
+        }
+
+        info!(
+            "Tiered storage is enabled, backend: {}, closed segments older than: {} will be offloaded.",
+            self.backend, self.hot_window
+        );
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for MessageCompactorConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.enabled && self.interval.get_duration().is_zero() {
+            error!("Message compactor interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for ConsumerGroupSessionCheckerConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.enabled && self.interval.is_zero() {
+            error!(
+                "Consumer group session checker interval cannot be zero, it must be greater than 0."
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ServerError> for PersonalAccessTokenConfig {
     fn validate(&self) -> Result<(), ServerError> {
         if self.max_tokens_per_user == 0 {
@@ -5,11 +5,12 @@ use crate::http::shared::AppState;
 use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::routing::get;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::reset_consumer_group_offsets::ResetConsumerGroupOffsets;
 use iggy::identifier::Identifier;
-use iggy::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use iggy::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupOffsetReset};
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 
@@ -23,6 +24,10 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/:stream_id/topics/:topic_id/consumer-groups/:consumer_group_id",
             get(get_consumer_group).delete(delete_consumer_group),
         )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/consumer-groups/:consumer_group_id/offsets/reset",
+            post(reset_consumer_group_offsets),
+        )
         .with_state(state)
 }
 
@@ -80,6 +85,8 @@ async fn create_consumer_group(
             &command.topic_id,
             command.consumer_group_id,
             &command.name,
+            command.session_timeout,
+            command.assignment_strategy,
         )
         .await?;
     Ok(StatusCode::CREATED)
@@ -104,3 +111,28 @@ async fn delete_consumer_group(
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+async fn reset_consumer_group_offsets(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, consumer_group_id)): Path<(String, String, String)>,
+    Json(mut command): Json<ResetConsumerGroupOffsets>,
+) -> Result<Json<Vec<ConsumerGroupOffsetReset>>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.consumer_group_id = Identifier::from_str_value(&consumer_group_id)?;
+    command.validate()?;
+    let system = state.system.read();
+    let offsets = system
+        .reset_consumer_group_offsets(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            &command.topic_id,
+            &command.consumer_group_id,
+            &command.partition_ids,
+            command.strategy,
+            command.dry_run,
+        )
+        .await?;
+    Ok(Json(offsets))
+}
@@ -65,6 +65,7 @@ async fn store_consumer_offset(
             &command.stream_id,
             &command.topic_id,
             command.offset,
+            command.generation,
         )
         .await?;
     Ok(StatusCode::NO_CONTENT)
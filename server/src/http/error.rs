@@ -34,6 +34,8 @@ impl IntoResponse for CustomError {
                     Error::ConsumerGroupIdNotFound(_, _) => StatusCode::NOT_FOUND,
                     Error::ConsumerGroupNameNotFound(_, _) => StatusCode::NOT_FOUND,
                     Error::ConsumerGroupMemberNotFound(_, _, _) => StatusCode::NOT_FOUND,
+                    Error::ConsumerGroupMemberFenced(_, _, _) => StatusCode::CONFLICT,
+                    Error::ConsumerGroupGenerationMismatch(_, _, _) => StatusCode::CONFLICT,
                     Error::CannotLoadResource(_) => StatusCode::NOT_FOUND,
                     Error::ResourceNotFound(_) => StatusCode::NOT_FOUND,
                     Error::IoError(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -43,6 +45,9 @@ impl IntoResponse for CustomError {
                     Error::CannotParseUtf8(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     Error::Unauthenticated => StatusCode::UNAUTHORIZED,
                     Error::Unauthorized => StatusCode::FORBIDDEN,
+                    Error::QuotaExceeded(_) => StatusCode::TOO_MANY_REQUESTS,
+                    Error::UserLocked(_) => StatusCode::FORBIDDEN,
+                    Error::TooManyLoginAttempts(_) => StatusCode::TOO_MANY_REQUESTS,
                     _ => StatusCode::BAD_REQUEST,
                 };
                 (status_code, Json(ErrorResponse::from_error(error)))
@@ -77,6 +82,8 @@ impl ErrorResponse {
                 Error::ConsumerGroupIdAlreadyExists(_, _) => Some("consumer_group_id".to_string()),
                 Error::ConsumerGroupNameAlreadyExists(_, _) => Some("name".to_string()),
                 Error::UserAlreadyExists => Some("username".to_string()),
+                Error::InvalidRoleName => Some("name".to_string()),
+                Error::RoleAlreadyExists(_) => Some("name".to_string()),
                 Error::PersonalAccessTokenAlreadyExists(_, _) => Some("name".to_string()),
                 _ => None,
             },
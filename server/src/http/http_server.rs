@@ -4,6 +4,7 @@ use crate::http::jwt::cleaner::start_expired_tokens_cleaner;
 use crate::http::jwt::jwt_manager::JwtManager;
 use crate::http::jwt::middleware::jwt_auth;
 use crate::http::metrics::metrics;
+use crate::http::quotas::quotas;
 use crate::http::shared::AppState;
 use crate::http::*;
 use crate::streaming::systems::system::SharedSystem;
@@ -30,12 +31,14 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         .merge(system::router(app_state.clone(), &config.metrics))
         .merge(personal_access_tokens::router(app_state.clone()))
         .merge(users::router(app_state.clone()))
+        .merge(roles::router(app_state.clone()))
         .merge(streams::router(app_state.clone()))
         .merge(topics::router(app_state.clone()))
         .merge(consumer_groups::router(app_state.clone()))
         .merge(consumer_offsets::router(app_state.clone()))
         .merge(partitions::router(app_state.clone()))
         .merge(messages::router(app_state.clone()))
+        .layer(middleware::from_fn_with_state(app_state.clone(), quotas))
         .layer(middleware::from_fn_with_state(app_state.clone(), jwt_auth));
 
     if config.cors.enabled {
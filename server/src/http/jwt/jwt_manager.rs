@@ -2,12 +2,12 @@ use crate::configs::http::HttpJwtConfig;
 use crate::http::jwt::json_web_token::{GeneratedTokens, JwtClaims, RevokedAccessToken};
 use crate::http::jwt::refresh_token::RefreshToken;
 use crate::http::jwt::storage::TokenStorage;
+use crate::streaming::metadata::database::Database;
 use iggy::error::Error;
 use iggy::models::user_info::UserId;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
 use jsonwebtoken::{encode, Algorithm, DecodingKey, EncodingKey, Header, TokenData, Validation};
-use sled::Db;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -42,7 +42,7 @@ impl JwtManager {
     pub fn new(
         issuer: IssuerOptions,
         validator: ValidatorOptions,
-        db: Arc<Db>,
+        db: Arc<dyn Database>,
     ) -> Result<Self, Error> {
         let validation = JwtManager::create_validation(
             issuer.algorithm,
@@ -60,7 +60,7 @@ impl JwtManager {
         })
     }
 
-    pub fn from_config(config: &HttpJwtConfig, db: Arc<Db>) -> Result<Self, Error> {
+    pub fn from_config(config: &HttpJwtConfig, db: Arc<dyn Database>) -> Result<Self, Error> {
         let algorithm = config.get_algorithm()?;
         let issuer = IssuerOptions {
             issuer: config.issuer.clone(),
@@ -1,9 +1,8 @@
 use crate::http::jwt::json_web_token::RevokedAccessToken;
 use crate::http::jwt::refresh_token::RefreshToken;
+use crate::streaming::metadata::database::Database;
 use anyhow::Context;
 use iggy::error::Error;
-use sled::Db;
-use std::str::from_utf8;
 use std::sync::Arc;
 use tracing::{error, info};
 
@@ -12,11 +11,11 @@ const REFRESH_TOKENS_KEY_PREFIX: &str = "refresh_token";
 
 #[derive(Debug)]
 pub struct TokenStorage {
-    db: Arc<Db>,
+    db: Arc<dyn Database>,
 }
 
 impl TokenStorage {
-    pub fn new(db: Arc<Db>) -> Self {
+    pub fn new(db: Arc<dyn Database>) -> Self {
         Self { db }
     }
 
@@ -71,10 +70,7 @@ impl TokenStorage {
                     })
                     .map_err(Error::CannotDeserializeResource)?;
 
-                token.token_hash = from_utf8(&hash)
-                    .with_context(|| "Failed to convert hash to UTF-8 string")
-                    .map_err(Error::CannotDeserializeResource)?
-                    .to_string();
+                token.token_hash = hash;
                 Ok(token)
             })
             .collect();
@@ -124,7 +120,7 @@ impl TokenStorage {
             Ok(data) => {
                 if let Err(err) = self
                     .db
-                    .insert(&key, data)
+                    .insert(&key, &data)
                     .with_context(|| "Failed to save revoked access token")
                 {
                     return Err(Error::CannotSaveResource(err));
@@ -145,7 +141,7 @@ impl TokenStorage {
             Ok(data) => {
                 if let Err(err) = self
                     .db
-                    .insert(&key, data)
+                    .insert(&key, &data)
                     .with_context(|| format!("Failed to save refresh token, key: {}", key))
                 {
                     return Err(Error::CannotSaveResource(err));
@@ -1,14 +1,16 @@
 use crate::http::jwt::json_web_token::GeneratedTokens;
 use crate::streaming::clients::client_manager::Client;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::roles::role::Role;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::users::user::User;
-use iggy::models::client_info::ConsumerGroupInfo;
+use iggy::models::client_info::{ClientQuotasInfo, ConsumerGroupInfo};
 use iggy::models::consumer_group::{ConsumerGroupDetails, ConsumerGroupMember};
 use iggy::models::identity_info::{IdentityInfo, IdentityTokens, TokenInfo};
 use iggy::models::personal_access_token::PersonalAccessTokenInfo;
+use iggy::models::role_info::{RoleInfo, RoleInfoDetails};
 use iggy::models::stream::StreamDetails;
 use iggy::models::topic::TopicDetails;
 use iggy::models::user_info::{UserInfo, UserInfoDetails};
@@ -61,6 +63,10 @@ pub async fn map_topics(topics: &[&Topic]) -> Vec<iggy::models::topic::Topic> {
             message_expiry: topic.message_expiry,
             max_topic_size: topic.max_topic_size,
             replication_factor: topic.replication_factor,
+            compression_algorithm: topic.compression_algorithm,
+            cleanup_policy: topic.cleanup_policy,
+            max_topic_size_policy: topic.max_topic_size_policy,
+            flush_policy: topic.flush_policy,
         };
         topics_data.push(topic);
     }
@@ -80,6 +86,10 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
         message_expiry: topic.message_expiry,
         max_topic_size: topic.max_topic_size,
         replication_factor: topic.replication_factor,
+        compression_algorithm: topic.compression_algorithm,
+        cleanup_policy: topic.cleanup_policy,
+        max_topic_size_policy: topic.max_topic_size_policy,
+        flush_policy: topic.flush_policy,
     };
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
@@ -92,19 +102,22 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
                 current_offset: partition.current_offset,
                 size_bytes: partition.get_size_bytes(),
                 messages_count: partition.get_messages_count(),
+                in_sync_replicas: partition.get_in_sync_replicas(),
             });
     }
     topic_details.partitions.sort_by(|a, b| a.id.cmp(&b.id));
     topic_details
 }
 
-pub fn map_user(user: &User) -> UserInfoDetails {
+pub fn map_user(user: &User, locked_until: Option<u64>) -> UserInfoDetails {
     UserInfoDetails {
         id: user.id,
         username: user.username.clone(),
         created_at: user.created_at,
         status: user.status,
         permissions: user.permissions.clone(),
+        roles: user.roles.clone(),
+        locked_until,
     }
 }
 
@@ -123,6 +136,29 @@ pub fn map_users(users: &[User]) -> Vec<UserInfo> {
     users_data
 }
 
+pub fn map_role(role: &Role) -> RoleInfoDetails {
+    RoleInfoDetails {
+        id: role.id,
+        name: role.name.clone(),
+        created_at: role.created_at,
+        permissions: role.permissions.clone(),
+    }
+}
+
+pub fn map_roles(roles: &[Role]) -> Vec<RoleInfo> {
+    let mut roles_data = Vec::with_capacity(roles.len());
+    for role in roles {
+        let role = RoleInfo {
+            id: role.id,
+            name: role.name.clone(),
+            created_at: role.created_at,
+        };
+        roles_data.push(role);
+    }
+    roles_data.sort_by_key(|role| role.id);
+    roles_data
+}
+
 pub fn map_personal_access_tokens(
     personal_access_tokens: &[PersonalAccessToken],
 ) -> Vec<PersonalAccessTokenInfo> {
@@ -138,7 +174,10 @@ pub fn map_personal_access_tokens(
     personal_access_tokens_data
 }
 
-pub async fn map_client(client: &Client) -> iggy::models::client_info::ClientInfoDetails {
+pub async fn map_client(
+    client: &Client,
+    quotas: Option<ClientQuotasInfo>,
+) -> iggy::models::client_info::ClientInfoDetails {
     let client = iggy::models::client_info::ClientInfoDetails {
         client_id: client.client_id,
         user_id: client.user_id,
@@ -154,6 +193,7 @@ pub async fn map_client(client: &Client) -> iggy::models::client_info::ClientInf
                 consumer_group_id: consumer_group.consumer_group_id,
             })
             .collect(),
+        quotas,
     };
     client
 }
@@ -202,6 +242,10 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> ConsumerGroup
         name: consumer_group.name.clone(),
         partitions_count: consumer_group.partitions_count,
         members_count: consumer_group.get_members().len() as u32,
+        generation: consumer_group.generation,
+        state: consumer_group.get_state().await,
+        session_timeout: consumer_group.session_timeout.unwrap_or(0),
+        assignment_strategy: consumer_group.assignment_strategy,
         members: Vec::new(),
     };
     let members = consumer_group.get_members();
@@ -3,14 +3,16 @@ use crate::http::jwt::json_web_token::Identity;
 use crate::http::shared::AppState;
 use crate::streaming;
 use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::quotas::quota_manager::QuotaDemand;
 use crate::streaming::session::Session;
 use crate::streaming::systems::messages::PollingArgs;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::get;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollMessages;
+use iggy::messages::reject_message::RejectMessage;
 use iggy::messages::send_messages::SendMessages;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
@@ -21,6 +23,10 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/:stream_id/topics/:topic_id/messages",
             get(poll_messages).post(send_messages),
         )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/messages/reject",
+            post(reject_message),
+        )
         .with_state(state)
 }
 
@@ -37,16 +43,38 @@ async fn poll_messages(
     let partition_id = query.partition_id.unwrap_or(0);
     let consumer_id = PollingConsumer::resolve_consumer_id(&query.consumer.id);
     let consumer = PollingConsumer::Consumer(consumer_id, partition_id);
+    let session = Session::stateless(identity.user_id, identity.ip_address);
+    let quota_manager = state.system.read().quota_manager.clone();
+    quota_manager
+        .acquire(
+            &session,
+            QuotaDemand {
+                poll: true,
+                ..Default::default()
+            },
+        )
+        .await?;
+
     let system = state.system.read();
     let polled_messages = system
         .poll_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &session,
             consumer,
             &query.stream_id,
             &query.topic_id,
-            PollingArgs::new(query.strategy, query.count, query.auto_commit),
+            PollingArgs::new(
+                query.strategy,
+                query.count,
+                query.auto_commit,
+                query.skip_poisoned,
+            ),
         )
         .await?;
+    system.quota_manager.record_polled(
+        &session,
+        polled_messages.get_messages_count(),
+        polled_messages.get_size_bytes(),
+    );
     Ok(Json(polled_messages))
 }
 
@@ -61,15 +89,90 @@ async fn send_messages(
     command.partitioning.length = command.partitioning.value.len() as u8;
     command.validate()?;
 
+    let session = Session::stateless(identity.user_id, identity.ip_address);
+    let quota_manager = state.system.read().quota_manager.clone();
+    quota_manager
+        .acquire(
+            &session,
+            QuotaDemand {
+                sent_messages: command.messages.len() as u64,
+                sent_bytes: command
+                    .messages
+                    .iter()
+                    .map(|message| message.get_size_bytes() as u64)
+                    .sum(),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    let in_sync_replicas_ack = {
+        let system = state.system.read();
+        system
+            .append_messages(
+                &session,
+                &command.stream_id,
+                &command.topic_id,
+                &command.partitioning,
+                &command.messages,
+                command.ack,
+            )
+            .await?
+    };
+    if let Some(in_sync_replicas_ack) = in_sync_replicas_ack {
+        in_sync_replicas_ack.wait().await?;
+    }
+    Ok(StatusCode::CREATED)
+}
+
+async fn reject_message(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<RejectMessage>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+
+    let session = Session::stateless(identity.user_id, identity.ip_address);
+    let dead_letter_message = state
+        .system
+        .read()
+        .get_dead_letter_message(
+            &session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.offset,
+            &command.reason,
+        )
+        .await?;
+    if let Some(dead_letter_message) = &dead_letter_message {
+        let quota_manager = state.system.read().quota_manager.clone();
+        quota_manager
+            .acquire(
+                &session,
+                QuotaDemand {
+                    sent_messages: 1,
+                    sent_bytes: dead_letter_message.get_size_bytes() as u64,
+                    ..Default::default()
+                },
+            )
+            .await?;
+    }
+
     let system = state.system.read();
     system
-        .append_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
+        .reject_message(
+            &session,
             &command.stream_id,
             &command.topic_id,
-            &command.partitioning,
-            &command.messages,
+            command.partition_id,
+            command.offset,
+            &command.reason,
+            dead_letter_message,
         )
         .await?;
-    Ok(StatusCode::CREATED)
+    Ok(StatusCode::NO_CONTENT)
 }
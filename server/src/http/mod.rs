@@ -9,6 +9,8 @@ pub mod messages;
 pub mod metrics;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod quotas;
+pub mod roles;
 mod shared;
 pub mod streams;
 pub mod system;
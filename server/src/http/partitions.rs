@@ -4,11 +4,13 @@ use crate::http::shared::AppState;
 use crate::streaming::session::Session;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::post;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
 use iggy::identifier::Identifier;
+use iggy::models::partition_offsets::{PartitionOffsets, TimestampOffset};
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 
@@ -18,6 +20,14 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/:stream_id/topics/:topic_id/partitions",
             post(create_partitions).delete(delete_partitions),
         )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/partitions/offsets",
+            get(get_partition_offsets),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/partitions/offsets-for-timestamp",
+            get(get_offsets_for_timestamp),
+        )
         .with_state(state)
 }
 
@@ -62,3 +72,42 @@ async fn delete_partitions(
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+async fn get_partition_offsets(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<Json<Vec<PartitionOffsets>>, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let system = state.system.read();
+    let offsets = system
+        .get_partition_offsets(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+            &topic_id,
+        )
+        .await?;
+    Ok(Json(offsets))
+}
+
+async fn get_offsets_for_timestamp(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut query: Query<GetOffsetsForTimestamp>,
+) -> Result<Json<Vec<TimestampOffset>>, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.validate()?;
+    let system = state.system.read();
+    let offsets = system
+        .get_offsets_for_timestamp(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &query.stream_id,
+            &query.topic_id,
+            query.timestamp,
+        )
+        .await?;
+    Ok(Json(offsets))
+}
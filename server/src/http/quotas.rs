@@ -0,0 +1,31 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::streaming::quotas::quota_manager::QuotaDemand;
+use crate::streaming::session::Session;
+use axum::body::Body;
+use axum::{extract::State, http::Request, middleware::Next, response::Response};
+use std::sync::Arc;
+
+/// Applies the requests quota of the authenticated user, the HTTP API is stateless, so there are no per client quotas.
+pub async fn quotas(
+    State(state): State<Arc<AppState>>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, CustomError> {
+    if let Some(identity) = request.extensions().get::<Identity>() {
+        let session = Session::stateless(identity.user_id, identity.ip_address);
+        let quota_manager = state.system.read().quota_manager.clone();
+        quota_manager
+            .acquire(
+                &session,
+                QuotaDemand {
+                    requests: 1,
+                    ..Default::default()
+                },
+            )
+            .await?;
+    }
+
+    Ok(next.run(request).await)
+}
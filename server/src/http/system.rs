@@ -4,11 +4,14 @@ use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::shared::AppState;
 use crate::streaming::session::Session;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::routing::get;
 use axum::{Extension, Json, Router};
+use iggy::models::audit_log::AuditLogEntry;
 use iggy::models::client_info::{ClientInfo, ClientInfoDetails};
 use iggy::models::stats::Stats;
+use iggy::system::get_audit_log::GetAuditLog;
+use iggy::validatable::Validatable;
 use std::sync::Arc;
 
 const NAME: &str = "Iggy HTTP";
@@ -20,7 +23,8 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
         .route("/ping", get(|| async { PONG }))
         .route("/stats", get(get_stats))
         .route("/clients", get(get_clients))
-        .route("/clients/:client_id", get(get_client));
+        .route("/clients/:client_id", get(get_client))
+        .route("/audit-log", get(get_audit_log));
     if metrics_config.enabled {
         router = router.route(&metrics_config.endpoint, get(get_metrics));
     }
@@ -57,7 +61,10 @@ async fn get_client(
         )
         .await?;
     let client = client.read().await;
-    let client = mapper::map_client(&client).await;
+    let quotas = system
+        .quota_manager
+        .get_client_quotas(client.client_id, client.user_id);
+    let client = mapper::map_client(&client, quotas).await;
     Ok(Json(client))
 }
 
@@ -72,3 +79,20 @@ async fn get_clients(
     let clients = mapper::map_clients(&clients).await;
     Ok(Json(clients))
 }
+
+async fn get_audit_log(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    query: Query<GetAuditLog>,
+) -> Result<Json<Vec<AuditLogEntry>>, CustomError> {
+    query.validate()?;
+    let system = state.system.read();
+    let entries = system
+        .get_audit_log(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            query.count,
+            query.user_id,
+        )
+        .await?;
+    Ok(Json(entries))
+}
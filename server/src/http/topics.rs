@@ -82,6 +82,10 @@ async fn create_topic(
             command.message_expiry,
             command.max_topic_size,
             command.replication_factor,
+            command.compression_algorithm,
+            command.cleanup_policy,
+            command.max_topic_size_policy,
+            command.flush_policy,
         )
         .await?;
     Ok(StatusCode::CREATED)
@@ -106,6 +110,10 @@ async fn update_topic(
             command.message_expiry,
             command.max_topic_size,
             command.replication_factor,
+            command.compression_algorithm,
+            command.cleanup_policy,
+            command.max_topic_size_policy,
+            command.flush_policy,
         )
         .await?;
     Ok(StatusCode::NO_CONTENT)
@@ -2,7 +2,7 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::mapper::map_generated_tokens_to_identity_info;
-use crate::http::shared::AppState;
+use crate::http::shared::{AppState, RequestDetails};
 use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
@@ -11,10 +11,12 @@ use axum::{Extension, Json, Router};
 use iggy::identifier::Identifier;
 use iggy::models::identity_info::IdentityInfo;
 use iggy::models::user_info::{UserInfo, UserInfoDetails};
+use iggy::users::assign_roles::AssignRoles;
 use iggy::users::change_password::ChangePassword;
 use iggy::users::create_user::CreateUser;
 use iggy::users::login_user::LoginUser;
 use iggy::users::logout_user::LogoutUser;
+use iggy::users::unlock_user::UnlockUser;
 use iggy::users::update_permissions::UpdatePermissions;
 use iggy::users::update_user::UpdateUser;
 use iggy::validatable::Validatable;
@@ -30,6 +32,8 @@ pub fn router(state: Arc<AppState>) -> Router {
         )
         .route("/users/:user_id/permissions", put(update_permissions))
         .route("/users/:user_id/password", put(change_password))
+        .route("/users/:user_id/roles", put(assign_roles))
+        .route("/users/:user_id/unlock", post(unlock_user))
         .route("/users/login", post(login_user))
         .route("/users/logout", post(logout_user))
         .route("/users/refresh-token", post(refresh_token))
@@ -49,7 +53,7 @@ async fn get_user(
             &user_id,
         )
         .await?;
-    let user = mapper::map_user(&user);
+    let user = mapper::map_user(&user, system.get_user_locked_until(&user));
     Ok(Json(user))
 }
 
@@ -123,6 +127,43 @@ async fn update_permissions(
     Ok(StatusCode::NO_CONTENT)
 }
 
+async fn assign_roles(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(user_id): Path<String>,
+    Json(mut command): Json<AssignRoles>,
+) -> Result<StatusCode, CustomError> {
+    command.user_id = Identifier::from_str_value(&user_id)?;
+    command.validate()?;
+    let mut system = state.system.write();
+    system
+        .assign_roles(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.user_id,
+            &command.roles,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn unlock_user(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(user_id): Path<String>,
+    Json(mut command): Json<UnlockUser>,
+) -> Result<StatusCode, CustomError> {
+    command.user_id = Identifier::from_str_value(&user_id)?;
+    command.validate()?;
+    let system = state.system.read();
+    system
+        .unlock_user(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.user_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 async fn change_password(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -161,12 +202,17 @@ async fn delete_user(
 
 async fn login_user(
     State(state): State<Arc<AppState>>,
+    Extension(request_details): Extension<RequestDetails>,
     Json(command): Json<LoginUser>,
 ) -> Result<Json<IdentityInfo>, CustomError> {
     command.validate()?;
     let system = state.system.read();
     let user = system
-        .login_user(&command.username, &command.password, None)
+        .login_user(
+            &command.username,
+            &command.password,
+            Some(&Session::stateless(0, request_details.ip_address)),
+        )
         .await?;
     let tokens = state.jwt_manager.generate(user.id)?;
     Ok(Json(map_generated_tokens_to_identity_info(tokens)))
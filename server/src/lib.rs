@@ -18,3 +18,4 @@ pub mod quic;
 pub mod server_error;
 pub mod streaming;
 pub mod tcp;
+pub mod tls;
@@ -2,8 +2,12 @@ use anyhow::Result;
 use clap::Parser;
 use figlet_rs::FIGfont;
 use server::args::Args;
+use server::channels::commands::check_consumer_group_sessions::CheckConsumerGroupSessionsExecutor;
 use server::channels::commands::clean_messages::CleanMessagesExecutor;
 use server::channels::commands::clean_personal_access_tokens::CleanPersonalAccessTokensExecutor;
+use server::channels::commands::compact_messages::CompactMessagesExecutor;
+use server::channels::commands::offload_segments::OffloadSegmentsExecutor;
+use server::channels::commands::replicate_partitions::ReplicatePartitionsExecutor;
 use server::channels::commands::save_messages::SaveMessagesExecutor;
 use server::channels::handler::ServerCommandHandler;
 use server::configs::config_provider;
@@ -44,7 +48,11 @@ async fn main() -> Result<(), ServerError> {
     let _command_handler = ServerCommandHandler::new(system.clone(), &config)
         .install_handler(SaveMessagesExecutor)
         .install_handler(CleanMessagesExecutor)
-        .install_handler(CleanPersonalAccessTokensExecutor);
+        .install_handler(CompactMessagesExecutor)
+        .install_handler(CleanPersonalAccessTokensExecutor)
+        .install_handler(CheckConsumerGroupSessionsExecutor)
+        .install_handler(OffloadSegmentsExecutor)
+        .install_handler(ReplicatePartitionsExecutor::default());
 
     #[cfg(unix)]
     let (mut ctrl_c, mut sigterm) = {
@@ -2,24 +2,27 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 
 use crate::binary::command;
+use crate::configs::client_auth::ClientAuthConfig;
 use crate::quic::quic_sender::QuicSender;
 use crate::server_error::ServerError;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
+use crate::tls;
 use anyhow::{anyhow, Context};
 use iggy::command::Command;
 use iggy::{bytes_serializable::BytesSerializable, messages::MAX_PAYLOAD_SIZE};
 use quinn::{Connection, Endpoint, RecvStream, SendStream};
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 const LISTENERS_COUNT: u32 = 10;
 const INITIAL_BYTES_LENGTH: usize = 4;
 
-pub fn start(endpoint: Endpoint, system: SharedSystem) {
+pub fn start(endpoint: Endpoint, system: SharedSystem, client_auth: Arc<ClientAuthConfig>) {
     for _ in 0..LISTENERS_COUNT {
         let endpoint = endpoint.clone();
         let system = system.clone();
+        let client_auth = client_auth.clone();
         tokio::spawn(async move {
             while let Some(incoming_connection) = endpoint.accept().await {
                 info!(
@@ -27,8 +30,11 @@ pub fn start(endpoint: Endpoint, system: SharedSystem) {
                     incoming_connection.remote_address()
                 );
                 let system = system.clone();
+                let client_auth = client_auth.clone();
                 tokio::spawn(async move {
-                    if let Err(error) = handle_connection(incoming_connection, system).await {
+                    if let Err(error) =
+                        handle_connection(incoming_connection, system, &client_auth).await
+                    {
                         error!("Connection has failed: {error}");
                     }
                 });
@@ -40,12 +46,33 @@ pub fn start(endpoint: Endpoint, system: SharedSystem) {
 async fn handle_connection(
     incoming_connection: quinn::Connecting,
     system: SharedSystem,
+    client_auth: &ClientAuthConfig,
 ) -> Result<(), ServerError> {
     let connection = incoming_connection.await?;
     let address = connection.remote_address();
     info!("Client has connected: {address}");
     let client_id = system.read().add_client(&address, Transport::Quic).await;
-    let session = Arc::new(Session::from_client_id(client_id, address));
+    let session = Arc::new(Session::from_client_id(client_id, address, Transport::Quic));
+    if client_auth.enabled {
+        let certificates = connection
+            .peer_identity()
+            .and_then(|identity| identity.downcast::<Vec<rustls::Certificate>>().ok());
+        let username = tls::get_client_certificate_username(
+            client_auth,
+            certificates
+                .as_deref()
+                .map(|certificates| certificates.as_slice()),
+        );
+        if let Some(username) = username {
+            if let Err(error) = system
+                .read()
+                .login_user_with_certificate(&username, &session)
+                .await
+            {
+                warn!("Cannot login user: {username} with the client certificate, error: {error}");
+            }
+        }
+    }
 
     while let Some(stream) = accept_stream(&connection, &system, &address).await? {
         let system = system.clone();
@@ -34,6 +34,10 @@ impl Sender for QuicSender {
         self.send_response(STATUS_OK, payload).await
     }
 
+    async fn send_ok_response_vectored(&mut self, payload: &[&[u8]]) -> Result<(), Error> {
+        self.send_response(STATUS_OK, &payload.concat()).await
+    }
+
     async fn send_error_response(&mut self, error: Error) -> Result<(), Error> {
         self.send_response(&error.as_code().to_le_bytes(), &[])
             .await
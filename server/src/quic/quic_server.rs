@@ -1,11 +1,10 @@
 use crate::configs::quic::QuicConfig;
 use crate::quic::listener;
 use crate::streaming::systems::system::SharedSystem;
+use crate::tls;
 use anyhow::Result;
 use quinn::{Endpoint, IdleTimeout, VarInt};
 use std::error::Error;
-use std::fs::File;
-use std::io::BufReader;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tracing::info;
@@ -21,7 +20,7 @@ pub fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
 
     let endpoint = Endpoint::server(quic_config.unwrap(), config.address.parse().unwrap()).unwrap();
     let addr = endpoint.local_addr().unwrap();
-    listener::start(endpoint, system);
+    listener::start(endpoint, system, Arc::new(config.client_auth));
     info!("Iggy QUIC server has started on: {:?}", addr);
     addr
 }
@@ -29,10 +28,25 @@ pub fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
 fn configure_quic(config: &QuicConfig) -> Result<quinn::ServerConfig, Box<dyn Error>> {
     let (certificate, key) = match config.certificate.self_signed {
         true => generate_self_signed_cert()?,
-        false => load_certificates(&config.certificate.cert_file, &config.certificate.key_file)?,
+        false => {
+            tls::load_certificates(&config.certificate.cert_file, &config.certificate.key_file)?
+        }
     };
 
-    let mut server_config = quinn::ServerConfig::with_single_cert(certificate, key)?;
+    let mut server_config = match config.client_auth.enabled {
+        true => {
+            let crypto = rustls::ServerConfig::builder()
+                .with_safe_default_cipher_suites()
+                .with_safe_default_kx_groups()
+                .with_protocol_versions(&[&rustls::version::TLS13])?
+                .with_client_cert_verifier(tls::create_client_certificate_verifier(
+                    &config.client_auth,
+                )?)
+                .with_single_cert(certificate, key)?;
+            quinn::ServerConfig::with_crypto(Arc::new(crypto))
+        }
+        false => quinn::ServerConfig::with_single_cert(certificate, key)?,
+    };
     let mut transport = quinn::TransportConfig::default();
     transport.initial_mtu(config.initial_mtu.as_bytes_u64() as u16);
     transport.send_window(config.send_window.as_bytes_u64());
@@ -60,19 +74,3 @@ fn generate_self_signed_cert(
     let cert_chain = vec![rustls::Certificate(certificate_der)];
     Ok((cert_chain, private_key))
 }
-
-fn load_certificates(
-    cert_file: &str,
-    key_file: &str,
-) -> Result<(Vec<rustls::Certificate>, rustls::PrivateKey), Box<dyn Error>> {
-    let mut cert_chain_reader = BufReader::new(File::open(cert_file)?);
-    let certs = rustls_pemfile::certs(&mut cert_chain_reader)
-        .map(|x| rustls::Certificate(x.unwrap().to_vec()))
-        .collect();
-    let mut key_reader = BufReader::new(File::open(key_file)?);
-    let mut keys = rustls_pemfile::rsa_private_keys(&mut key_reader)
-        .map(|x| rustls::PrivateKey(x.unwrap().secret_pkcs1_der().to_vec()))
-        .collect::<Vec<_>>();
-    let key = rustls::PrivateKey(keys.remove(0).0);
-    Ok((certs, key))
-}
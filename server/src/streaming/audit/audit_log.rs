@@ -0,0 +1,193 @@
+use anyhow::Context;
+use iggy::error::Error;
+use iggy::models::audit_log::AuditLogEntry;
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+/// Append-only audit log, storing each entry as a single JSON line.
+#[derive(Debug)]
+pub struct AuditLog {
+    enabled: bool,
+    path: String,
+    initialized: AtomicBool,
+    file: Mutex<Option<File>>,
+}
+
+impl AuditLog {
+    pub fn new(enabled: bool, path: &str) -> Self {
+        Self {
+            enabled,
+            path: path.to_string(),
+            initialized: AtomicBool::new(false),
+            file: Mutex::new(None),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enables appending the entries, the ones appended before are skipped.
+    /// The file is created once the first entry is appended.
+    pub fn init(&self) {
+        if !self.enabled {
+            return;
+        }
+
+        self.initialized.store(true, Ordering::SeqCst);
+        info!("Audit log will be stored at: {}", self.path);
+    }
+
+    pub async fn append(&self, entry: &AuditLogEntry) -> Result<(), Error> {
+        if !self.initialized.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let mut file = self.file.lock().await;
+        if file.is_none() {
+            let opened_file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)
+                .await?;
+            file.replace(opened_file);
+        }
+
+        let file = file.as_mut().unwrap();
+
+        let mut line = serde_json::to_vec(entry)
+            .with_context(|| "Failed to serialize audit log entry")
+            .map_err(Error::CannotSerializeResource)?;
+        line.push(b'\n');
+        file.write_all(&line).await?;
+        file.flush().await?;
+        Ok(())
+    }
+
+    /// Loads the latest `count` entries, optionally only the ones of the given user, ordered from the oldest to the newest.
+    /// The file is read line by line, so only the latest `count` matching entries are kept in memory.
+    pub async fn load(
+        &self,
+        count: u32,
+        user_id: Option<u32>,
+    ) -> Result<Vec<AuditLogEntry>, Error> {
+        if !Path::new(&self.path).exists() {
+            return Ok(Vec::new());
+        }
+
+        let file = File::open(&self.path)
+            .await
+            .map_err(|_| Error::CannotReadAuditLog(self.path.clone()))?;
+        let mut lines = BufReader::new(file).lines();
+        let count = count as usize;
+        let mut entries = VecDeque::with_capacity(count);
+        while let Some(line) = lines
+            .next_line()
+            .await
+            .map_err(|_| Error::CannotReadAuditLog(self.path.clone()))?
+        {
+            if line.is_empty() {
+                continue;
+            }
+
+            let entry = match serde_json::from_str::<AuditLogEntry>(&line) {
+                Ok(entry) => entry,
+                Err(error) => {
+                    warn!("Skipping invalid audit log entry: {line}, error: {error}");
+                    continue;
+                }
+            };
+
+            if user_id.is_some_and(|user_id| user_id != entry.user_id) {
+                continue;
+            }
+
+            if entries.len() == count {
+                entries.pop_front();
+            }
+            entries.push_back(entry);
+        }
+
+        Ok(entries.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::audit_log::AuditOutcome;
+
+    #[tokio::test]
+    async fn should_load_latest_entries_appended_after_init() {
+        let path = format!(
+            "{}/iggy_audit_{}.log",
+            std::env::temp_dir().display(),
+            uuid::Uuid::new_v4()
+        );
+        let audit_log = AuditLog::new(true, &path);
+        audit_log.append(&get_entry(1, "skipped")).await.unwrap();
+        audit_log.init();
+        for (user_id, command) in [
+            (1, "stream.create"),
+            (2, "user.login"),
+            (1, "stream.delete"),
+        ] {
+            audit_log
+                .append(&get_entry(user_id, command))
+                .await
+                .unwrap();
+        }
+
+        let entries = audit_log.load(10, None).await.unwrap();
+        let commands = entries
+            .iter()
+            .map(|entry| entry.command.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            commands,
+            vec!["stream.create", "user.login", "stream.delete"]
+        );
+
+        let entries = audit_log.load(1, Some(1)).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0], get_entry(1, "stream.delete"));
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_not_append_entries_when_disabled() {
+        let path = format!(
+            "{}/iggy_audit_{}.log",
+            std::env::temp_dir().display(),
+            uuid::Uuid::new_v4()
+        );
+        let audit_log = AuditLog::new(false, &path);
+        audit_log.init();
+        audit_log
+            .append(&get_entry(1, "stream.create"))
+            .await
+            .unwrap();
+
+        assert!(!Path::new(&path).exists());
+        assert!(audit_log.load(10, None).await.unwrap().is_empty());
+    }
+
+    fn get_entry(user_id: u32, command: &str) -> AuditLogEntry {
+        AuditLogEntry {
+            timestamp: 1,
+            user_id,
+            address: "127.0.0.1:1234".to_string(),
+            transport: "TCP".to_string(),
+            command: command.to_string(),
+            details: "".to_string(),
+            outcome: AuditOutcome::Success,
+            error: None,
+        }
+    }
+}
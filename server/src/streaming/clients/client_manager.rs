@@ -32,6 +32,7 @@ pub struct ConsumerGroup {
 pub enum Transport {
     Tcp,
     Quic,
+    Http,
 }
 
 impl Display for Transport {
@@ -39,6 +40,7 @@ impl Display for Transport {
         match self {
             Transport::Tcp => write!(f, "TCP"),
             Transport::Quic => write!(f, "QUIC"),
+            Transport::Http => write!(f, "HTTP"),
         }
     }
 }
@@ -0,0 +1,64 @@
+use crate::configs::system::DatabaseBackend;
+use crate::streaming::metadata::redb::RedbDatabase;
+use crate::streaming::metadata::sled::SledDatabase;
+use iggy::error::Error;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+pub type DatabaseEntry = (String, Vec<u8>);
+
+/// The key-value store holding the metadata, such as users, streams, topics and consumer offsets.
+/// The keys are the strings prefixed with the resource kind, e.g. `users:1`, so they can be scanned by prefix.
+pub trait Database: Sync + Send {
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, anyhow::Error>;
+    fn insert(&self, key: &str, value: &[u8]) -> Result<(), anyhow::Error>;
+    fn remove(&self, key: &str) -> Result<(), anyhow::Error>;
+    fn scan_prefix(
+        &self,
+        prefix: &str,
+    ) -> Box<dyn Iterator<Item = Result<DatabaseEntry, anyhow::Error>> + Send + '_>;
+    fn flush(&self) -> Result<(), anyhow::Error>;
+}
+
+impl Debug for dyn Database {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Database")
+    }
+}
+
+pub fn open_database(backend: DatabaseBackend, path: &str) -> Result<Arc<dyn Database>, Error> {
+    let database: Arc<dyn Database> = match backend {
+        DatabaseBackend::Sled => Arc::new(SledDatabase::open(path)?),
+        DatabaseBackend::Redb => Arc::new(RedbDatabase::open(path)?),
+    };
+    Ok(database)
+}
+
+/// Copies all the entries from the source to the empty target database, returns the number of the migrated entries.
+pub fn migrate_database(source: &dyn Database, target: &dyn Database) -> Result<u64, Error> {
+    if target.scan_prefix("").next().is_some() {
+        return Err(Error::CannotMigrateDatabase(
+            "target database is not empty".to_string(),
+        ));
+    }
+
+    let mut migrated_entries_count = 0;
+    for entry in source.scan_prefix("") {
+        let (key, value) = entry.map_err(Error::CannotLoadResource)?;
+        target
+            .insert(&key, &value)
+            .map_err(Error::CannotSaveResource)?;
+        migrated_entries_count += 1;
+    }
+    target.flush().map_err(Error::CannotSaveResource)?;
+
+    let target_entries_count = target.scan_prefix("").count() as u64;
+    if target_entries_count != migrated_entries_count {
+        return Err(Error::CannotMigrateDatabase(format!(
+            "migrated {} entries, but the target database contains {} entries",
+            migrated_entries_count, target_entries_count
+        )));
+    }
+
+    Ok(migrated_entries_count)
+}
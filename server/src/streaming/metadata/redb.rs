@@ -0,0 +1,108 @@
+use crate::streaming::metadata::database::{Database, DatabaseEntry};
+use iggy::error::Error;
+use redb::{Durability, TableDefinition};
+use std::path::Path;
+use tracing::error;
+
+const METADATA_TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("metadata");
+
+/// Stores all the metadata in a single table of the redb file, each write is committed in its own transaction.
+/// The consumer offsets are stored on every poll with the auto commit, so they are committed with the eventual
+/// durability and persisted along with the next durable commit or flush, similar to sled.
+#[derive(Debug)]
+pub struct RedbDatabase {
+    db: redb::Database,
+}
+
+impl RedbDatabase {
+    pub fn open(path: &str) -> Result<Self, Error> {
+        let db = Self::create(path).map_err(|err| {
+            error!("Cannot open redb database at: {}. Error: {}", path, err);
+            Error::CannotOpenDatabase(path.to_string())
+        })?;
+        Ok(Self { db })
+    }
+
+    fn create(path: &str) -> Result<redb::Database, anyhow::Error> {
+        if let Some(directory) = Path::new(path).parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+
+        let db = redb::Database::create(path)?;
+        // The table is created upfront, so the read transactions never have to deal with its absence.
+        let transaction = db.begin_write()?;
+        transaction.open_table(METADATA_TABLE)?;
+        transaction.commit()?;
+        Ok(db)
+    }
+}
+
+impl Database for RedbDatabase {
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, anyhow::Error> {
+        let transaction = self.db.begin_read()?;
+        let table = transaction.open_table(METADATA_TABLE)?;
+        Ok(table.get(key)?.map(|value| value.value().to_vec()))
+    }
+
+    fn insert(&self, key: &str, value: &[u8]) -> Result<(), anyhow::Error> {
+        let mut transaction = self.db.begin_write()?;
+        transaction.set_durability(get_durability(key));
+        {
+            let mut table = transaction.open_table(METADATA_TABLE)?;
+            table.insert(key, value)?;
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), anyhow::Error> {
+        let mut transaction = self.db.begin_write()?;
+        transaction.set_durability(get_durability(key));
+        {
+            let mut table = transaction.open_table(METADATA_TABLE)?;
+            table.remove(key)?;
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    fn scan_prefix(
+        &self,
+        prefix: &str,
+    ) -> Box<dyn Iterator<Item = Result<DatabaseEntry, anyhow::Error>> + Send + '_> {
+        // The entries are collected, as the iterator can't outlive the read transaction.
+        let entries = (|| -> Result<Vec<DatabaseEntry>, anyhow::Error> {
+            let transaction = self.db.begin_read()?;
+            let table = transaction.open_table(METADATA_TABLE)?;
+            let mut entries = Vec::new();
+            for entry in table.range(prefix..)? {
+                let (key, value) = entry?;
+                if !key.value().starts_with(prefix) {
+                    break;
+                }
+                entries.push((key.value().to_string(), value.value().to_vec()));
+            }
+            Ok(entries)
+        })();
+
+        match entries {
+            Ok(entries) => Box::new(entries.into_iter().map(Ok)),
+            Err(err) => Box::new(std::iter::once(Err(err))),
+        }
+    }
+
+    fn flush(&self) -> Result<(), anyhow::Error> {
+        // An empty durable commit persists all the preceding commits with the eventual durability.
+        self.db.begin_write()?.commit()?;
+        Ok(())
+    }
+}
+
+fn get_durability(key: &str) -> Durability {
+    // Matches both the `consumer_offsets` and `consumer_group_offsets` keys.
+    if key.starts_with("consumer") && key.contains("_offsets:") {
+        Durability::Eventual
+    } else {
+        Durability::Immediate
+    }
+}
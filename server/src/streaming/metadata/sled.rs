@@ -0,0 +1,52 @@
+use crate::streaming::metadata::database::{Database, DatabaseEntry};
+use iggy::error::Error;
+use sled::Db;
+use tracing::error;
+
+#[derive(Debug)]
+pub struct SledDatabase {
+    db: Db,
+}
+
+impl SledDatabase {
+    pub fn open(path: &str) -> Result<Self, Error> {
+        match sled::open(path) {
+            Ok(db) => Ok(Self { db }),
+            Err(err) => {
+                error!("Cannot open sled database at: {}. Error: {}", path, err);
+                Err(Error::CannotOpenDatabase(path.to_string()))
+            }
+        }
+    }
+}
+
+impl Database for SledDatabase {
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, anyhow::Error> {
+        Ok(self.db.get(key)?.map(|value| value.to_vec()))
+    }
+
+    fn insert(&self, key: &str, value: &[u8]) -> Result<(), anyhow::Error> {
+        self.db.insert(key, value)?;
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), anyhow::Error> {
+        self.db.remove(key)?;
+        Ok(())
+    }
+
+    fn scan_prefix(
+        &self,
+        prefix: &str,
+    ) -> Box<dyn Iterator<Item = Result<DatabaseEntry, anyhow::Error>> + Send + '_> {
+        Box::new(self.db.scan_prefix(prefix).map(|entry| {
+            let (key, value) = entry?;
+            Ok((String::from_utf8(key.to_vec())?, value.to_vec()))
+        }))
+    }
+
+    fn flush(&self) -> Result<(), anyhow::Error> {
+        self.db.flush()?;
+        Ok(())
+    }
+}
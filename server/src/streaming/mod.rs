@@ -1,17 +1,22 @@
+pub mod audit;
 pub mod cache;
 pub mod clients;
 mod deduplication;
 pub mod diagnostics;
+pub mod metadata;
 pub mod models;
 pub mod partitions;
 pub mod persistence;
 pub mod personal_access_tokens;
 pub mod polling_consumer;
+pub mod quotas;
+pub mod roles;
 pub mod segments;
 pub mod session;
 pub mod storage;
 pub mod streams;
 pub mod systems;
+pub mod tiering;
 pub mod topics;
 pub mod users;
 pub mod utils;
@@ -1,11 +1,37 @@
+use crate::streaming::segments::mapped::MappedMessages;
 use iggy::models::messages::Message;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 // It's the same as PolledMessages from Iggy models, but with the Arc<Message> instead of Message.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct PolledMessages {
     pub partition_id: u32,
     pub current_offset: u64,
     pub messages: Vec<Arc<Message>>,
+    /// The persisted messages mapped from the segment log file, sent as they are instead of the `messages`.
+    #[serde(skip)]
+    pub mapped_messages: Option<MappedMessages>,
+}
+
+impl PolledMessages {
+    /// Returns the number of the polled messages, either mapped or loaded ones.
+    pub fn get_messages_count(&self) -> u64 {
+        match &self.mapped_messages {
+            Some(mapped_messages) => mapped_messages.count as u64,
+            None => self.messages.len() as u64,
+        }
+    }
+
+    /// Returns the size in bytes of the polled messages, either mapped or loaded ones.
+    pub fn get_size_bytes(&self) -> u64 {
+        match &self.mapped_messages {
+            Some(mapped_messages) => mapped_messages.as_bytes().len() as u64,
+            None => self
+                .messages
+                .iter()
+                .map(|message| message.get_size_bytes() as u64)
+                .sum(),
+        }
+    }
 }
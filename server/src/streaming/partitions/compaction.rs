@@ -0,0 +1,181 @@
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::segments::segment::Segment;
+use iggy::error::Error;
+use iggy::messages::send_messages::MESSAGE_KEY_HEADER;
+use iggy::models::header::HeaderKey;
+use iggy::models::messages::Message;
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+use tracing::{info, trace};
+
+/// Rewrites the closed segments keeping only the latest message for each message key.
+/// Tombstones (messages with an empty payload) are removed once they are older than the given timestamp.
+/// Messages without the message key are always retained. Returns the number of removed messages.
+/// The segments offloaded to the tiered storage are never compacted, as their remote files couldn't be updated.
+///
+/// The segments are streamed from disk one at a time, and the partition lock is acquired separately for each segment,
+/// so the partition remains available for appending and polling the messages during the compaction.
+pub async fn compact_segments(
+    partition: &RwLock<Partition>,
+    tombstone_expiry: u64,
+) -> Result<u64, Error> {
+    let (partition_id, topic_id, stream_id, segments) = {
+        let partition = partition.read().await;
+        let segments = partition
+            .segments
+            .iter()
+            .filter(|segment| !segment.is_remote)
+            .map(|segment| (segment.start_offset, segment.is_closed))
+            .collect::<Vec<_>>();
+        (
+            partition.partition_id,
+            partition.topic_id,
+            partition.stream_id,
+            segments,
+        )
+    };
+    if !segments.iter().any(|(_, is_closed)| *is_closed) {
+        return Ok(0);
+    }
+
+    // Only the keys and their offsets are kept in memory. The messages appended in the meantime can only make
+    // the other messages obsolete, which will be removed by the next compaction. The remote segments precede
+    // the local ones, so they are skipped without fetching them back.
+    let header_key = HeaderKey::new(MESSAGE_KEY_HEADER)?;
+    let mut latest_offsets = HashMap::new();
+    for (start_offset, _) in &segments {
+        let partition = partition.read().await;
+        let Some(segment) = partition
+            .get_segment(*start_offset)
+            .filter(|segment| !segment.is_remote)
+        else {
+            continue;
+        };
+
+        latest_offsets.extend(segment.storage.segment.load_message_keys(segment).await?);
+        if let Some(unsaved_messages) = &segment.unsaved_messages {
+            for message in unsaved_messages {
+                if let Some(key) = get_message_key(message, &header_key) {
+                    latest_offsets.insert(key.to_vec(), message.offset);
+                }
+            }
+        }
+    }
+
+    let retain = |message: &Message| match get_message_key(message, &header_key) {
+        Some(key) => {
+            latest_offsets.get(key) == Some(&message.offset)
+                && !(message.payload.is_empty() && message.timestamp < tombstone_expiry)
+        }
+        None => true,
+    };
+    let mut removed_messages_count = 0;
+    for (start_offset, is_closed) in segments {
+        if !is_closed {
+            continue;
+        }
+
+        let removed_offsets = {
+            let partition = partition.read().await;
+            let Some(segment) = partition
+                .get_segment(start_offset)
+                .filter(|segment| !segment.is_remote)
+            else {
+                continue;
+            };
+
+            segment
+                .storage
+                .segment
+                .save_compacted_messages(segment, &retain)
+                .await?
+        };
+        if removed_offsets.is_empty() {
+            continue;
+        }
+
+        let mut partition = partition.write().await;
+        removed_messages_count += partition
+            .replace_compacted_segment(start_offset, &removed_offsets)
+            .await?;
+    }
+
+    if removed_messages_count > 0 {
+        info!(
+            "Compacted {} messages for partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+            removed_messages_count, partition_id, topic_id, stream_id
+        );
+    }
+    Ok(removed_messages_count)
+}
+
+impl Partition {
+    pub(crate) fn get_segment(&self, start_offset: u64) -> Option<&Segment> {
+        self.segments
+            .iter()
+            .find(|segment| segment.start_offset == start_offset)
+    }
+
+    // Swaps the segment files with the compacted ones, the segment might have been deleted or offloaded in the meantime.
+    async fn replace_compacted_segment(
+        &mut self,
+        start_offset: u64,
+        removed_offsets: &[u64],
+    ) -> Result<u64, Error> {
+        let Some(segment) = self
+            .segments
+            .iter_mut()
+            .find(|segment| segment.start_offset == start_offset)
+        else {
+            return Ok(0);
+        };
+
+        let storage = segment.storage.segment.clone();
+        if segment.is_remote {
+            storage.delete_compacted_messages(segment).await?;
+            return Ok(0);
+        }
+
+        segment.current_size_bytes = storage.replace_compacted_messages(segment).await?;
+        segment.messages_count = segment
+            .messages_count
+            .saturating_sub(removed_offsets.len() as u64);
+        if segment.indexes.is_some() {
+            segment.indexes = Some(storage.load_all_indexes(segment).await?);
+        }
+        if segment.time_indexes.is_some() {
+            segment.time_indexes = Some(storage.load_all_time_indexes(segment).await?);
+        }
+
+        for offset in removed_offsets {
+            if self.is_message_poisoned(*offset) {
+                self.delete_poisoned_messages(*offset, *offset).await?;
+            }
+        }
+
+        // The cache lookups rely on the contiguous offsets, so the compacted messages are served from disk.
+        if let Some(cache) = &mut self.cache {
+            cache.purge();
+        }
+
+        let removed_count = removed_offsets.len() as u64;
+        trace!(
+            "Compacted segment with start offset: {} for partition with ID: {}, removed {} messages.",
+            start_offset,
+            self.partition_id,
+            removed_count
+        );
+        Ok(removed_count)
+    }
+}
+
+pub(crate) fn get_message_key<'a>(
+    message: &'a Message,
+    header_key: &HeaderKey,
+) -> Option<&'a [u8]> {
+    message
+        .headers
+        .as_ref()
+        .and_then(|headers| headers.get(header_key))
+        .map(|value| value.value.as_slice())
+}
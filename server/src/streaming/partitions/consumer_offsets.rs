@@ -2,7 +2,9 @@ use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
 use crate::streaming::polling_consumer::PollingConsumer;
 use dashmap::DashMap;
 use iggy::consumer::ConsumerKind;
+use iggy::consumer_groups::reset_consumer_group_offsets::OffsetsResetStrategy;
 use iggy::error::Error;
+use iggy::models::consumer_group::ConsumerGroupOffsetReset;
 use tracing::trace;
 
 impl Partition {
@@ -62,6 +64,71 @@ impl Partition {
         Ok(())
     }
 
+    /// Resets the offset of the consumer group, so that the next polled message is the one resolved from the given strategy,
+    /// clamped to the range of the offsets available in the partition.
+    /// Both returned offsets are the offsets of the next message to be polled, and nothing is stored in the dry run mode.
+    pub async fn reset_consumer_group_offset(
+        &self,
+        consumer_group_id: u32,
+        strategy: OffsetsResetStrategy,
+        dry_run: bool,
+    ) -> Result<ConsumerGroupOffsetReset, Error> {
+        let earliest_offset = self.get_earliest_offset();
+        let end_offset = if self.should_increment_offset {
+            self.current_offset + 1
+        } else {
+            0
+        };
+        let previous_offset = match self.consumer_group_offsets.get(&consumer_group_id) {
+            Some(consumer_offset) => consumer_offset.offset + 1,
+            None => earliest_offset,
+        };
+        let new_offset = match strategy {
+            OffsetsResetStrategy::Earliest => earliest_offset,
+            OffsetsResetStrategy::Latest => end_offset,
+            OffsetsResetStrategy::Offset(offset) => offset,
+            OffsetsResetStrategy::Timestamp(timestamp) => self
+                .get_offset_for_timestamp(timestamp)
+                .await?
+                .unwrap_or(end_offset),
+            OffsetsResetStrategy::Shift(shift) => previous_offset.saturating_add_signed(shift),
+        }
+        .clamp(earliest_offset, end_offset);
+
+        trace!(
+            "Resetting offset for consumer group with ID: {}, partition: {} from: {} to: {}, dry run: {}...",
+            consumer_group_id,
+            self.partition_id,
+            previous_offset,
+            new_offset,
+            dry_run
+        );
+        if !dry_run {
+            if new_offset > 0 {
+                self.store_offset(
+                    ConsumerKind::ConsumerGroup,
+                    consumer_group_id,
+                    new_offset - 1,
+                )
+                .await?;
+            } else if let Some((_, consumer_offset)) =
+                self.consumer_group_offsets.remove(&consumer_group_id)
+            {
+                // There's no offset preceding the first message, so the group starts from the first messages again.
+                self.storage
+                    .partition
+                    .delete_consumer_offset(&consumer_offset)
+                    .await?;
+            }
+        }
+
+        Ok(ConsumerGroupOffsetReset {
+            partition_id: self.partition_id,
+            previous_offset,
+            new_offset,
+        })
+    }
+
     async fn store_offset(
         &self,
         kind: ConsumerKind,
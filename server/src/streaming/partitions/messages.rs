@@ -1,9 +1,13 @@
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::segments::mapped::MappedMessages;
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::utils::random_id;
 use iggy::error::Error;
+use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
+use iggy::messages::send_messages::AckLevel;
 use iggy::models::messages::Message;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::Arc;
 use tracing::{trace, warn};
 
@@ -11,18 +15,78 @@ const EMPTY_MESSAGES: Vec<Arc<Message>> = vec![];
 
 impl Partition {
     pub fn get_messages_count(&self) -> u64 {
-        let first_segment = self.segments.first();
-        if first_segment.is_none() {
-            return 0;
+        self.segments
+            .iter()
+            .map(|segment| segment.get_messages_count())
+            .sum()
+    }
+
+    pub fn get_earliest_offset(&self) -> u64 {
+        match self.segments.first() {
+            Some(segment) if segment.current_size_bytes > 0 => segment.start_offset,
+            _ => 0,
         }
+    }
+
+    /// Returns the offset of the first message with the timestamp greater than or equal to the given one,
+    /// resolved from the time indexes without fetching the messages, unless the indexes are sparse.
+    pub async fn get_offset_for_timestamp(&self, timestamp: u64) -> Result<Option<u64>, Error> {
+        trace!(
+            "Getting offset for timestamp: {} for partition: {}...",
+            timestamp,
+            self.partition_id
+        );
+        for segment in self.segments.iter() {
+            let Some(last_timestamp) = segment.last_timestamp else {
+                continue;
+            };
+            if timestamp > last_timestamp {
+                continue;
+            }
 
-        let first_segment = first_segment.unwrap();
-        if first_segment.current_size_bytes == 0 {
-            return 0;
+            let loaded_time_indexes;
+            let time_indexes = match &segment.time_indexes {
+                Some(time_indexes) => time_indexes,
+                None => {
+                    loaded_time_indexes =
+                        self.storage.segment.load_all_time_indexes(segment).await?;
+                    &loaded_time_indexes
+                }
+            };
+            if time_indexes.is_empty() {
+                continue;
+            }
+
+            let following_index =
+                time_indexes.partition_point(|time_index| time_index.timestamp < timestamp);
+            let following_offset = time_indexes
+                .get(following_index)
+                .map(|time_index| segment.start_offset + time_index.relative_offset as u64);
+            if following_index == 0 {
+                return Ok(following_offset);
+            }
+
+            // The time indexes might be sparse, so the matching message might be located before the following time index.
+            let start_offset =
+                segment.start_offset + time_indexes[following_index - 1].relative_offset as u64;
+            let end_offset = following_offset.unwrap_or(segment.current_offset + 1);
+            let messages = self
+                .get_messages_by_offset(start_offset, (end_offset - start_offset) as u32)
+                .await?;
+            if let Some(message) = messages
+                .iter()
+                .find(|message| message.timestamp >= timestamp)
+            {
+                return Ok(Some(message.offset));
+            }
+
+            if following_offset.is_some() {
+                return Ok(following_offset);
+            }
         }
 
-        let last_segment = self.segments.last().unwrap();
-        last_segment.current_offset - first_segment.start_offset + 1
+        trace!("Offset for timestamp: {} was not found.", timestamp);
+        Ok(None)
     }
 
     pub async fn get_messages_by_timestamp(
@@ -39,7 +103,7 @@ impl Partition {
             return Ok(EMPTY_MESSAGES);
         }
 
-        let mut maybe_start_offset = None;
+        let mut maybe_offsets_range = None;
         for segment in self.segments.iter() {
             if segment.time_indexes.is_none() {
                 continue;
@@ -51,19 +115,24 @@ impl Partition {
             }
 
             let first_timestamp = time_indexes.first().unwrap().timestamp;
-            let last_timestamp = time_indexes.last().unwrap().timestamp;
+            let Some(last_timestamp) = segment.last_timestamp else {
+                continue;
+            };
             if timestamp < first_timestamp || timestamp > last_timestamp {
                 continue;
             }
 
-            let relative_start_offset = time_indexes
-                .iter()
-                .find(|time_index| time_index.timestamp >= timestamp)
-                .map(|time_index| time_index.relative_offset)
-                .unwrap_or(0);
-
-            let start_offset = segment.start_offset + relative_start_offset as u64;
-            maybe_start_offset = Some(start_offset);
+            // The time indexes might be sparse, so the first message with the matching timestamp
+            // is somewhere between the preceding and the following time index.
+            let following_index =
+                time_indexes.partition_point(|time_index| time_index.timestamp < timestamp);
+            let start_offset = segment.start_offset
+                + time_indexes[following_index.saturating_sub(1)].relative_offset as u64;
+            let end_offset = match time_indexes.get(following_index) {
+                Some(time_index) => segment.start_offset + time_index.relative_offset as u64,
+                None => segment.current_offset + 1,
+            };
+            maybe_offsets_range = Some((start_offset, end_offset));
             trace!(
                 "Found start offset: {} for timestamp: {}.",
                 start_offset,
@@ -73,13 +142,20 @@ impl Partition {
             break;
         }
 
-        if maybe_start_offset.is_none() {
+        let Some((start_offset, end_offset)) = maybe_offsets_range else {
             trace!("Start offset for timestamp: {} was not found.", timestamp);
             return Ok(EMPTY_MESSAGES);
-        }
+        };
 
-        self.get_messages_by_offset(maybe_start_offset.unwrap(), count)
-            .await
+        let scanned_count = count + (end_offset - start_offset) as u32;
+        let messages = self
+            .get_messages_by_offset(start_offset, scanned_count)
+            .await?;
+        Ok(messages
+            .into_iter()
+            .filter(|message| message.timestamp >= timestamp)
+            .take(count as usize)
+            .collect())
     }
 
     pub async fn get_messages_by_offset(
@@ -106,12 +182,7 @@ impl Partition {
             return Ok(messages);
         }
 
-        let segments = self.filter_segments_by_offsets(start_offset, end_offset);
-        match segments.len() {
-            0 => Ok(EMPTY_MESSAGES),
-            1 => segments[0].get_messages(start_offset, count).await,
-            _ => Self::get_messages_from_segments(segments, start_offset, count).await,
-        }
+        self.get_messages_from_segments(start_offset, count).await
     }
 
     pub async fn get_first_messages(&self, count: u32) -> Result<Vec<Arc<Message>>, Error> {
@@ -173,6 +244,62 @@ impl Partition {
         self.get_messages_by_offset(offset, count).await
     }
 
+    /// Maps the persisted messages for the given strategy, so they can be sent to the client without re-encoding.
+    /// Returns `None` if the messages should be polled as usual, e.g. when they're cached, poisoned,
+    /// not saved yet or span across the multiple segments.
+    pub async fn get_mapped_messages(
+        &self,
+        consumer: PollingConsumer,
+        strategy: PollingStrategy,
+        count: u32,
+    ) -> Result<Option<MappedMessages>, Error> {
+        let start_offset = match strategy.kind {
+            PollingKind::Offset => strategy.value,
+            PollingKind::First => 0,
+            PollingKind::Next => {
+                let (consumer_offsets, consumer_id) = match consumer {
+                    PollingConsumer::Consumer(consumer_id, _) => {
+                        (&self.consumer_offsets, consumer_id)
+                    }
+                    PollingConsumer::ConsumerGroup(consumer_group_id, _) => {
+                        (&self.consumer_group_offsets, consumer_group_id)
+                    }
+                };
+                match consumer_offsets.get(&consumer_id) {
+                    Some(consumer_offset) => consumer_offset.offset + 1,
+                    None => 0,
+                }
+            }
+            PollingKind::Timestamp | PollingKind::Last => return Ok(None),
+        };
+
+        if count == 0
+            || self.segments.is_empty()
+            || start_offset > self.current_offset
+            || !self.poisoned_messages.is_empty()
+        {
+            return Ok(None);
+        }
+
+        let end_offset = self.get_end_offset(start_offset, count);
+        if self.is_cached(start_offset, end_offset) {
+            return Ok(None);
+        }
+
+        // The compacted segment might not have enough messages within the range, so they're read from the following ones.
+        let segments = self.filter_segments_by_offsets(start_offset, end_offset);
+        if segments.len() != 1 || segments[0].is_compacted() {
+            return Ok(None);
+        }
+
+        trace!(
+            "Mapping messages for start offset: {} for partition: {}...",
+            start_offset,
+            self.partition_id
+        );
+        segments[0].get_mapped_messages(start_offset, count).await
+    }
+
     fn get_end_offset(&self, offset: u64, count: u32) -> u64 {
         let mut end_offset = offset + (count - 1) as u64;
         let segment = self.segments.last().unwrap();
@@ -195,16 +322,23 @@ impl Partition {
             .collect::<Vec<&Segment>>()
     }
 
+    // The offsets removed by the compaction or the retention are skipped, so the requested count of the messages
+    // is read from the following segments, otherwise the consumer would get stuck on the gap.
     async fn get_messages_from_segments(
-        segments: Vec<&Segment>,
+        &self,
         offset: u64,
         count: u32,
     ) -> Result<Vec<Arc<Message>>, Error> {
-        let mut messages = Vec::with_capacity(segments.len());
-        for segment in segments {
-            let segment_messages = segment.get_messages(offset, count).await?;
-            for message in segment_messages {
-                messages.push(message);
+        let mut messages = Vec::new();
+        for segment in self
+            .segments
+            .iter()
+            .filter(|segment| segment.current_offset >= offset)
+        {
+            let remaining_count = count - messages.len() as u32;
+            messages.extend(segment.get_messages(offset, remaining_count).await?);
+            if messages.len() >= count as usize {
+                break;
             }
         }
 
@@ -235,6 +369,18 @@ impl Partition {
         None
     }
 
+    fn is_cached(&self, start_offset: u64, end_offset: u64) -> bool {
+        match &self.cache {
+            Some(cache) => {
+                !cache.is_empty()
+                    && start_offset <= end_offset
+                    && end_offset <= self.current_offset
+                    && start_offset >= cache[0].offset
+            }
+            None => false,
+        }
+    }
+
     pub async fn get_newest_messages_by_size(
         &self,
         size_bytes: u32,
@@ -252,6 +398,12 @@ impl Partition {
         let mut remaining_size = size_bytes as u64;
         let mut messages = Vec::new();
         for segment in self.segments.iter().rev() {
+            // The offloaded segments precede the local ones, and they aren't fetched back only to fill the cache.
+            // The cache lookups rely on the contiguous offsets, so the compacted segments aren't cached either.
+            if segment.is_offloaded() || segment.is_compacted() {
+                break;
+            }
+
             let segment_size_bytes = segment.current_size_bytes as u64;
             if segment_size_bytes > remaining_size {
                 // Last segment is bigger than the remaining size, so we need to get the newest messages from it.
@@ -318,18 +470,16 @@ impl Partition {
     }
 
     pub async fn append_messages(&mut self, messages: Vec<Message>) -> Result<(), Error> {
-        {
-            let last_segment = self.segments.last_mut().ok_or(Error::SegmentNotFound)?;
-            if last_segment.is_closed {
-                let start_offset = last_segment.end_offset + 1;
-                trace!(
-                    "Current segment is closed, creating new segment with start offset: {} for partition with ID: {}...",
-                    start_offset, self.partition_id
-                );
-                self.add_persisted_segment(start_offset).await?;
-            }
-        }
+        self.append_messages_with_ack(messages, AckLevel::InMemory)
+            .await
+    }
 
+    pub async fn append_messages_with_ack(
+        &mut self,
+        messages: Vec<Message>,
+        ack: AckLevel,
+    ) -> Result<(), Error> {
+        self.ensure_open_segment().await?;
         let mut appendable_messages = Vec::with_capacity(messages.len());
         if let Some(message_deduplicator) = &mut self.message_deduplicator {
             for mut message in messages {
@@ -371,34 +521,111 @@ impl Partition {
             }
         }
 
+        self.store_messages(appendable_messages, ack).await
+    }
+
+    pub(crate) async fn ensure_open_segment(&mut self) -> Result<(), Error> {
+        let last_segment = self.segments.last_mut().ok_or(Error::SegmentNotFound)?;
+        if last_segment.is_closed {
+            let start_offset = last_segment.end_offset + 1;
+            trace!(
+                "Current segment is closed, creating new segment with start offset: {} for partition with ID: {}...",
+                start_offset, self.partition_id
+            );
+            self.add_persisted_segment(start_offset).await?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) async fn store_messages(
+        &mut self,
+        appendable_messages: Vec<Arc<Message>>,
+        ack: AckLevel,
+    ) -> Result<(), Error> {
         {
             let last_segment = self.segments.last_mut().ok_or(Error::SegmentNotFound)?;
             last_segment.append_messages(&appendable_messages).await?;
         }
 
         let messages_count = appendable_messages.len() as u32;
+        let messages_size = appendable_messages
+            .iter()
+            .map(|message| message.get_size_bytes() as u64)
+            .sum::<u64>();
         if let Some(cache) = &mut self.cache {
             cache.extend(appendable_messages);
         }
 
         self.unsaved_messages_count += messages_count;
+        self.unsaved_messages_size += messages_size;
+        let now = IggyTimestamp::now().to_micros();
+        if self.oldest_unsaved_message_at.is_none() {
+            self.oldest_unsaved_message_at = Some(now);
+        }
+
+        let flush_required = self.is_flush_required(now);
         {
             let last_segment = self.segments.last_mut().ok_or(Error::SegmentNotFound)?;
-            if self.unsaved_messages_count >= self.config.partition.messages_required_to_save
-                || last_segment.is_full().await
-            {
+            if ack == AckLevel::Persisted {
+                trace!(
+                    "Segment with start offset: {} for partition with ID: {} will be persisted and synced on disk...",
+                    last_segment.start_offset,
+                    self.partition_id
+                );
+                last_segment.persist_messages_with_sync().await?;
+            } else if flush_required || last_segment.is_full().await {
                 trace!(
                     "Segment with start offset: {} for partition with ID: {} will be persisted on disk...",
                     last_segment.start_offset,
                     self.partition_id
                 );
                 last_segment.persist_messages().await?;
-                self.unsaved_messages_count = 0;
+            } else {
+                return Ok(());
             }
         }
 
+        self.reset_unsaved_messages();
         Ok(())
     }
+
+    // The flush policy of the topic overrides the messages count required to save from the config.
+    fn is_flush_required(&self, now: u64) -> bool {
+        let messages_required_to_save = self
+            .flush_policy
+            .messages_count
+            .unwrap_or(self.config.partition.messages_required_to_save);
+        if self.unsaved_messages_count >= messages_required_to_save {
+            return true;
+        }
+
+        if let Some(size) = self.flush_policy.size {
+            if self.unsaved_messages_size >= size.as_bytes_u64() {
+                return true;
+            }
+        }
+
+        self.is_max_latency_exceeded(now)
+    }
+
+    pub fn is_max_latency_exceeded(&self, now: u64) -> bool {
+        match (
+            self.flush_policy.max_latency,
+            self.oldest_unsaved_message_at,
+        ) {
+            (Some(max_latency), Some(oldest_unsaved_message_at)) => {
+                now.saturating_sub(oldest_unsaved_message_at) >= max_latency as u64 * 1000
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn reset_unsaved_messages(&mut self) {
+        self.unsaved_messages_count = 0;
+        self.unsaved_messages_size = 0;
+        self.oldest_unsaved_message_at = None;
+    }
 }
 
 #[cfg(test)]
@@ -2,12 +2,16 @@ use bytes::Bytes;
 use iggy::models::messages::{Message, MessageState};
 use iggy::utils::checksum;
 
+pub mod compaction;
 pub mod consumer_offsets;
 pub mod messages;
 pub mod partition;
 pub mod persistence;
+pub mod poisoned_messages;
+pub mod replicas;
 pub mod segments;
 pub mod storage;
+pub mod tiering;
 
 #[allow(dead_code)]
 fn create_messages() -> Vec<Message> {
@@ -7,8 +7,10 @@ use crate::streaming::storage::SystemStorage;
 use dashmap::DashMap;
 use iggy::consumer::ConsumerKind;
 use iggy::models::messages::Message;
+use iggy::models::topic::FlushPolicy;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::Arc;
+use tokio::sync::Notify;
 
 #[derive(Debug)]
 pub struct Partition {
@@ -21,11 +23,17 @@ pub struct Partition {
     pub cached_memory_tracker: Option<Arc<CacheMemoryTracker>>,
     pub message_deduplicator: Option<MessageDeduplicator>,
     pub unsaved_messages_count: u32,
+    pub unsaved_messages_size: u64,
     pub should_increment_offset: bool,
     pub created_at: u64,
     pub(crate) message_expiry: Option<u32>,
+    pub(crate) flush_policy: FlushPolicy,
+    pub(crate) oldest_unsaved_message_at: Option<u64>,
     pub(crate) consumer_offsets: DashMap<u32, ConsumerOffset>,
     pub(crate) consumer_group_offsets: DashMap<u32, ConsumerOffset>,
+    pub(crate) poisoned_messages: DashMap<u64, PoisonedMessage>,
+    pub(crate) replicas: DashMap<u32, ReplicaState>,
+    pub(crate) replicas_notify: Arc<Notify>,
     pub(crate) segments: Vec<Segment>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) storage: Arc<SystemStorage>,
@@ -69,6 +77,48 @@ impl ConsumerOffset {
     }
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub struct PoisonedMessage {
+    pub offset: u64,
+    pub reason: String,
+    pub key: String,
+}
+
+impl PoisonedMessage {
+    pub fn new(
+        offset: u64,
+        reason: &str,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> PoisonedMessage {
+        PoisonedMessage {
+            key: format!(
+                "{}:{offset}",
+                Self::get_key_prefix(stream_id, topic_id, partition_id)
+            ),
+            offset,
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn get_key_prefix(stream_id: u32, topic_id: u32, partition_id: u32) -> String {
+        format!("poisoned_messages:{stream_id}:{topic_id}:{partition_id}")
+    }
+}
+
+// The replication progress of the follower node, tracked by the leader based on the fetch requests.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ReplicaState {
+    // Offset of the next message to be fetched, all the messages below it are already replicated.
+    pub offset: u64,
+    // Next offset of the leader at the time of the last fetch.
+    pub leader_offset: u64,
+    pub fetched_at: u64,
+    // The last time when the replica had all the messages stored by the leader.
+    pub caught_up_at: u64,
+}
+
 impl Partition {
     pub fn create(
         stream_id: u32,
@@ -94,6 +144,8 @@ impl Partition {
             partition_id,
             path,
             message_expiry,
+            flush_policy: FlushPolicy::default(),
+            oldest_unsaved_message_at: None,
             cache: messages,
             cached_memory_tracker,
             message_deduplicator: match config.message_deduplication.enabled {
@@ -116,9 +168,13 @@ impl Partition {
             segments: vec![],
             current_offset: 0,
             unsaved_messages_count: 0,
+            unsaved_messages_size: 0,
             should_increment_offset: false,
             consumer_offsets: DashMap::new(),
             consumer_group_offsets: DashMap::new(),
+            poisoned_messages: DashMap::new(),
+            replicas: DashMap::new(),
+            replicas_notify: Arc::new(Notify::new()),
             config,
             storage,
             created_at: IggyTimestamp::now().to_micros(),
@@ -188,6 +244,7 @@ mod tests {
         let consumer_offsets = partition.consumer_offsets;
         assert_eq!(partition.message_expiry, message_expiry);
         assert!(consumer_offsets.is_empty());
+        assert!(partition.poisoned_messages.is_empty());
     }
 
     #[test]
@@ -16,9 +16,17 @@ impl Partition {
         self.storage.partition.delete(self).await
     }
 
+    pub async fn persist_messages(&mut self) -> Result<(), Error> {
+        for segment in self.get_segments_mut() {
+            segment.persist_messages().await?;
+        }
+        self.reset_unsaved_messages();
+        Ok(())
+    }
+
     pub async fn purge(&mut self) -> Result<(), Error> {
         self.current_offset = 0;
-        self.unsaved_messages_count = 0;
+        self.reset_unsaved_messages();
         self.should_increment_offset = false;
         if let Some(cache) = self.cache.as_mut() {
             cache.purge();
@@ -45,6 +53,11 @@ impl Partition {
                 self.partition_id,
             )
             .await?;
+        self.poisoned_messages.clear();
+        self.storage
+            .partition
+            .delete_poisoned_messages(self.stream_id, self.topic_id, self.partition_id)
+            .await?;
         self.add_persisted_segment(0).await?;
         Ok(())
     }
@@ -0,0 +1,188 @@
+use crate::streaming::partitions::partition::{Partition, PoisonedMessage};
+use iggy::error::Error;
+use iggy::models::messages::{Message, MessageState};
+use std::sync::Arc;
+use tracing::trace;
+
+impl Partition {
+    pub fn is_message_poisoned(&self, offset: u64) -> bool {
+        self.poisoned_messages.contains_key(&offset)
+    }
+
+    pub async fn poison_message(&self, offset: u64, reason: &str) -> Result<(), Error> {
+        trace!(
+            "Marking message with offset: {} as poisoned for partition: {}, current: {}...",
+            offset,
+            self.partition_id,
+            self.current_offset
+        );
+        if offset > self.current_offset {
+            return Err(Error::InvalidOffset(offset));
+        }
+
+        let poisoned_message = PoisonedMessage::new(
+            offset,
+            reason,
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+        );
+        self.storage
+            .partition
+            .save_poisoned_message(&poisoned_message)
+            .await?;
+        self.poisoned_messages.insert(offset, poisoned_message);
+        Ok(())
+    }
+
+    /// Deletes the poisoned state of the messages within the given offsets, once they are removed from the log.
+    pub async fn delete_poisoned_messages(
+        &self,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Result<(), Error> {
+        if self.poisoned_messages.is_empty() {
+            return Ok(());
+        }
+
+        let offsets = self
+            .poisoned_messages
+            .iter()
+            .map(|entry| *entry.key())
+            .filter(|offset| *offset >= start_offset && *offset <= end_offset)
+            .collect::<Vec<_>>();
+        for offset in offsets {
+            if let Some((_, poisoned_message)) = self.poisoned_messages.remove(&offset) {
+                self.storage
+                    .partition
+                    .delete_poisoned_message(&poisoned_message)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    // The log is append-only, so the state of the already stored messages is never modified,
+    // instead it's overridden when the messages are being polled.
+    pub fn mark_poisoned_messages(&self, messages: Vec<Arc<Message>>) -> Vec<Arc<Message>> {
+        if self.poisoned_messages.is_empty() {
+            return messages;
+        }
+
+        messages
+            .into_iter()
+            .map(|message| {
+                if !self.is_message_poisoned(message.offset) {
+                    return message;
+                }
+
+                Arc::new(Message::create(
+                    message.offset,
+                    MessageState::Poisoned,
+                    message.timestamp,
+                    message.id,
+                    message.payload.clone(),
+                    message.checksum,
+                    message.headers.clone(),
+                ))
+            })
+            .collect()
+    }
+
+    pub async fn load_poisoned_messages(&mut self) -> Result<(), Error> {
+        trace!(
+            "Loading poisoned messages for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
+            self.partition_id,
+            self.topic_id,
+            self.stream_id
+        );
+        let poisoned_messages = self
+            .storage
+            .partition
+            .load_poisoned_messages(self.stream_id, self.topic_id, self.partition_id)
+            .await?;
+        for poisoned_message in poisoned_messages {
+            self.poisoned_messages
+                .insert(poisoned_message.offset, poisoned_message);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use bytes::Bytes;
+
+    #[tokio::test]
+    async fn poisoned_messages_should_be_marked_when_polled() {
+        let mut partition = create_partition();
+        partition.current_offset = 2;
+        partition
+            .poison_message(1, "invalid payload")
+            .await
+            .unwrap();
+
+        let messages = (0..3)
+            .map(|offset| {
+                Arc::new(Message::create(
+                    offset,
+                    MessageState::Available,
+                    1,
+                    offset as u128 + 1,
+                    Bytes::from("message"),
+                    1,
+                    None,
+                ))
+            })
+            .collect();
+        let messages = partition.mark_poisoned_messages(messages);
+
+        assert!(partition.is_message_poisoned(1));
+        assert_eq!(messages[0].state, MessageState::Available);
+        assert_eq!(messages[1].state, MessageState::Poisoned);
+        assert_eq!(messages[2].state, MessageState::Available);
+    }
+
+    #[tokio::test]
+    async fn message_should_not_be_poisoned_given_offset_greater_than_current_one() {
+        let partition = create_partition();
+
+        let result = partition.poison_message(1, "invalid payload").await;
+
+        assert!(matches!(result, Err(Error::InvalidOffset(1))));
+        assert!(!partition.is_message_poisoned(1));
+    }
+
+    #[tokio::test]
+    async fn poisoned_messages_should_be_deleted_within_given_offsets() {
+        let mut partition = create_partition();
+        partition.current_offset = 10;
+        for offset in [1, 5, 9] {
+            partition
+                .poison_message(offset, "invalid payload")
+                .await
+                .unwrap();
+        }
+
+        partition.delete_poisoned_messages(0, 5).await.unwrap();
+
+        assert!(!partition.is_message_poisoned(1));
+        assert!(!partition.is_message_poisoned(5));
+        assert!(partition.is_message_poisoned(9));
+    }
+
+    fn create_partition() -> Partition {
+        Partition::create(
+            1,
+            1,
+            1,
+            false,
+            Arc::new(SystemConfig::default()),
+            Arc::new(get_test_system_storage()),
+            None,
+        )
+    }
+}
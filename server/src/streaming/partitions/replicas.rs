@@ -0,0 +1,295 @@
+use crate::configs::system::ReplicaRole;
+use crate::streaming::partitions::partition::{Partition, ReplicaState};
+use iggy::error::Error;
+use iggy::messages::send_messages::AckLevel;
+use iggy::models::messages::Message;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tokio::time::{timeout_at, Instant};
+use tracing::{trace, warn};
+
+// Awaited by the leader before acknowledging the appended messages, once the lock of the system is released,
+// as the followers need to access the system to fetch the messages.
+#[derive(Debug)]
+pub struct InSyncReplicasAck {
+    partition: Arc<RwLock<Partition>>,
+    offset: u64,
+    replicas_count: u32,
+    timeout: IggyDuration,
+}
+
+impl InSyncReplicasAck {
+    pub fn new(
+        partition: Arc<RwLock<Partition>>,
+        offset: u64,
+        replicas_count: u32,
+        timeout: IggyDuration,
+    ) -> Self {
+        Self {
+            partition,
+            offset,
+            replicas_count,
+            timeout,
+        }
+    }
+
+    pub async fn wait(self) -> Result<(), Error> {
+        let replicas_notify = self.partition.read().await.replicas_notify.clone();
+        let deadline = Instant::now() + self.timeout.get_duration();
+        loop {
+            let notified = replicas_notify.notified();
+            let partition = self.partition.read().await;
+            if partition.is_replicated_by_in_sync_replicas(self.offset, self.replicas_count) {
+                return Ok(());
+            }
+
+            drop(partition);
+            if timeout_at(deadline, notified).await.is_err() {
+                let partition = self.partition.read().await;
+                warn!(
+                    "Messages up to offset: {} were not acknowledged by the in-sync replicas in time for partition with ID: {}, topic with ID: {}, stream with ID: {}.",
+                    self.offset, partition.partition_id, partition.topic_id, partition.stream_id
+                );
+                return Err(Error::InSyncReplicasAckTimeout(
+                    partition.partition_id,
+                    partition.topic_id,
+                    partition.stream_id,
+                ));
+            }
+        }
+    }
+}
+
+impl Partition {
+    pub fn get_next_offset(&self) -> u64 {
+        if self.should_increment_offset {
+            self.current_offset + 1
+        } else {
+            self.current_offset
+        }
+    }
+
+    pub fn record_replica_fetch(&self, replica_id: u32, offset: u64) {
+        let now = IggyTimestamp::now().to_micros();
+        let next_offset = self.get_next_offset();
+        let previous_state = self.replicas.get(&replica_id).map(|state| *state);
+        let caught_up_at = if offset >= next_offset {
+            now
+        } else {
+            match previous_state {
+                // The replica has fetched everything that was available during its previous fetch.
+                Some(state) if offset >= state.leader_offset => state.fetched_at,
+                Some(state) => state.caught_up_at,
+                None => 0,
+            }
+        };
+
+        trace!(
+            "Replica with ID: {} fetched messages from offset: {} for partition with ID: {}, next offset: {}.",
+            replica_id,
+            offset,
+            self.partition_id,
+            next_offset
+        );
+        self.replicas.insert(
+            replica_id,
+            ReplicaState {
+                offset,
+                leader_offset: next_offset,
+                fetched_at: now,
+                caught_up_at,
+            },
+        );
+        self.replicas_notify.notify_waiters();
+    }
+
+    pub fn get_in_sync_replicas(&self) -> Vec<u32> {
+        let config = &self.config.replication;
+        if !config.enabled || config.role != ReplicaRole::Leader {
+            return Vec::new();
+        }
+
+        let now = IggyTimestamp::now().to_micros();
+        let lag_timeout = config.replica_lag_timeout.as_micros();
+        let mut replicas = vec![config.node_id];
+        for replica in self.replicas.iter() {
+            if replica.caught_up_at + lag_timeout >= now {
+                replicas.push(*replica.key());
+            }
+        }
+
+        replicas.sort();
+        replicas
+    }
+
+    pub fn is_replicated_by_in_sync_replicas(&self, offset: u64, replicas_count: u32) -> bool {
+        let config = &self.config.replication;
+        let now = IggyTimestamp::now().to_micros();
+        let lag_timeout = config.replica_lag_timeout.as_micros();
+        let replicated_count = self
+            .replicas
+            .iter()
+            .filter(|replica| replica.offset > offset && replica.caught_up_at + lag_timeout >= now)
+            .count() as u32;
+
+        // The leader node is always one of the replicas.
+        replicated_count + 1 >= replicas_count
+    }
+
+    pub async fn append_replicated_messages(
+        &mut self,
+        messages: Vec<Message>,
+    ) -> Result<(), Error> {
+        let mut next_offset = self.get_next_offset();
+        let mut appendable_messages = Vec::with_capacity(messages.len());
+        for message in messages {
+            if message.offset < next_offset {
+                continue;
+            }
+
+            // The leader's log might have gaps, e.g. after compaction or deleting the expired segments,
+            // so the follower keeps the same offsets and continues from the first available one.
+            if message.offset > next_offset {
+                trace!(
+                    "Skipping offsets: {next_offset}..{} not available on the leader for partition with ID: {}.",
+                    message.offset,
+                    self.partition_id
+                );
+            }
+
+            next_offset = message.offset + 1;
+            appendable_messages.push(Arc::new(message));
+        }
+
+        if appendable_messages.is_empty() {
+            return Ok(());
+        }
+
+        self.ensure_open_segment().await?;
+        self.current_offset = next_offset - 1;
+        self.should_increment_offset = true;
+        self.store_messages(appendable_messages, AckLevel::InMemory)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::{ReplicationConfig, SystemConfig};
+    use crate::streaming::partitions::create_messages;
+    use crate::streaming::storage::tests::get_test_system_storage;
+
+    #[tokio::test]
+    async fn replicated_messages_should_be_appended_with_the_same_offsets() {
+        let mut leader = create_partition(ReplicaRole::Leader);
+        let mut follower = create_partition(ReplicaRole::Follower);
+        leader.append_messages(create_messages()).await.unwrap();
+        let messages_count = leader.get_next_offset() as u32;
+
+        let messages = leader
+            .get_messages_by_offset(0, messages_count)
+            .await
+            .unwrap();
+        follower
+            .append_replicated_messages(copy_messages(&messages))
+            .await
+            .unwrap();
+        // Already replicated messages should be ignored.
+        follower
+            .append_replicated_messages(copy_messages(&messages))
+            .await
+            .unwrap();
+
+        assert_eq!(follower.get_next_offset(), leader.get_next_offset());
+        let replicated_messages = follower
+            .get_messages_by_offset(0, messages_count)
+            .await
+            .unwrap();
+        assert_eq!(replicated_messages.len(), messages.len());
+        for (replicated_message, message) in replicated_messages.iter().zip(messages.iter()) {
+            assert_eq!(replicated_message.offset, message.offset);
+            assert_eq!(replicated_message.id, message.id);
+            assert_eq!(replicated_message.payload, message.payload);
+        }
+    }
+
+    #[tokio::test]
+    async fn replicated_messages_with_offset_gap_should_be_appended_with_the_same_offsets() {
+        let mut follower = create_partition(ReplicaRole::Follower);
+        let mut messages = create_messages();
+        messages.truncate(2);
+        messages[0].offset = 5;
+        messages[1].offset = 8;
+
+        follower.append_replicated_messages(messages).await.unwrap();
+
+        assert_eq!(follower.get_next_offset(), 9);
+        let replicated_messages = follower.get_messages_by_offset(0, 10).await.unwrap();
+        let offsets = replicated_messages
+            .iter()
+            .map(|message| message.offset)
+            .collect::<Vec<_>>();
+        assert_eq!(offsets, vec![5, 8]);
+    }
+
+    #[tokio::test]
+    async fn replica_should_be_in_sync_only_after_fetching_all_messages() {
+        let mut leader = create_partition(ReplicaRole::Leader);
+        leader.append_messages(create_messages()).await.unwrap();
+        let next_offset = leader.get_next_offset();
+        assert_eq!(leader.get_in_sync_replicas(), vec![1]);
+
+        leader.record_replica_fetch(2, 0);
+        assert_eq!(leader.get_in_sync_replicas(), vec![1]);
+        assert!(!leader.is_replicated_by_in_sync_replicas(0, 2));
+
+        leader.record_replica_fetch(2, next_offset);
+        assert_eq!(leader.get_in_sync_replicas(), vec![1, 2]);
+        assert!(leader.is_replicated_by_in_sync_replicas(next_offset - 1, 2));
+        assert!(!leader.is_replicated_by_in_sync_replicas(next_offset, 2));
+    }
+
+    fn copy_messages(messages: &[Arc<Message>]) -> Vec<Message> {
+        messages
+            .iter()
+            .map(|message| {
+                Message::create(
+                    message.offset,
+                    message.state,
+                    message.timestamp,
+                    message.id,
+                    message.payload.clone(),
+                    message.checksum,
+                    message.headers.clone(),
+                )
+            })
+            .collect()
+    }
+
+    fn create_partition(role: ReplicaRole) -> Partition {
+        let storage = Arc::new(get_test_system_storage());
+        let stream_id = 1;
+        let topic_id = 2;
+        let partition_id = 3;
+        let config = Arc::new(SystemConfig {
+            replication: ReplicationConfig {
+                enabled: true,
+                role,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        Partition::create(
+            stream_id,
+            topic_id,
+            partition_id,
+            true,
+            config,
+            storage,
+            None,
+        )
+    }
+}
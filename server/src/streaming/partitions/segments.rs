@@ -72,6 +72,8 @@ impl Partition {
         }
 
         self.segments.retain(|s| s.start_offset != start_offset);
+        self.delete_poisoned_messages(start_offset, deleted_segment.end_offset)
+            .await?;
         Ok(deleted_segment)
     }
 }
@@ -1,12 +1,12 @@
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
-use crate::streaming::segments::segment::{Segment, LOG_EXTENSION};
+use crate::streaming::metadata::database::Database;
+use crate::streaming::partitions::partition::{ConsumerOffset, Partition, PoisonedMessage};
+use crate::streaming::segments::segment::{Segment, LOG_EXTENSION, REMOTE_EXTENSION};
 use crate::streaming::storage::{PartitionStorage, Storage};
 use anyhow::Context;
 use async_trait::async_trait;
 use iggy::consumer::ConsumerKind;
 use iggy::error::Error;
 use serde::{Deserialize, Serialize};
-use sled::Db;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::fs;
@@ -15,11 +15,11 @@ use tracing::{error, info, trace, warn};
 
 #[derive(Debug)]
 pub struct FilePartitionStorage {
-    db: Arc<Db>,
+    db: Arc<dyn Database>,
 }
 
 impl FilePartitionStorage {
-    pub fn new(db: Arc<Db>) -> Self {
+    pub fn new(db: Arc<dyn Database>) -> Self {
         Self { db }
     }
 }
@@ -75,8 +75,7 @@ impl PartitionStorage for FilePartitionStorage {
                 )
             }) {
                 Ok((key, value)) => {
-                    let key = String::from_utf8(key.to_vec()).unwrap();
-                    let offset = u64::from_be_bytes(value.as_ref().try_into().unwrap());
+                    let offset = u64::from_be_bytes(value.as_slice().try_into().unwrap());
                     let consumer_id = key.split(':').last().unwrap().parse::<u32>().unwrap();
                     ConsumerOffset {
                         key,
@@ -96,6 +95,23 @@ impl PartitionStorage for FilePartitionStorage {
         Ok(consumer_offsets)
     }
 
+    async fn delete_consumer_offset(&self, offset: &ConsumerOffset) -> Result<(), Error> {
+        if let Err(err) = self
+            .db
+            .remove(&offset.key)
+            .with_context(|| format!("Failed to delete consumer offset, key: {}", offset.key))
+        {
+            return Err(Error::CannotDeleteResource(err));
+        }
+
+        trace!(
+            "Deleted consumer offset for {} with ID: {}",
+            offset.kind,
+            offset.consumer_id
+        );
+        Ok(())
+    }
+
     async fn delete_consumer_offsets(
         &self,
         kind: ConsumerKind,
@@ -130,6 +146,129 @@ impl PartitionStorage for FilePartitionStorage {
 
         Ok(())
     }
+
+    async fn save_poisoned_message(&self, message: &PoisonedMessage) -> Result<(), Error> {
+        // The offset is already a part of the key, so the stored value is just the rejection reason.
+        if let Err(err) = self
+            .db
+            .insert(&message.key, message.reason.as_bytes())
+            .with_context(|| {
+                format!(
+                    "Failed to save poisoned message with offset: {}, key: {}",
+                    message.offset, message.key
+                )
+            })
+        {
+            return Err(Error::CannotSaveResource(err));
+        }
+
+        trace!(
+            "Stored poisoned message with offset: {}, reason: {}",
+            message.offset,
+            message.reason
+        );
+        Ok(())
+    }
+
+    async fn delete_poisoned_message(&self, message: &PoisonedMessage) -> Result<(), Error> {
+        if let Err(err) = self.db.remove(&message.key).with_context(|| {
+            format!(
+                "Failed to delete poisoned message with offset: {}, key: {}",
+                message.offset, message.key
+            )
+        }) {
+            return Err(Error::CannotDeleteResource(err));
+        }
+
+        trace!("Deleted poisoned message with offset: {}", message.offset);
+        Ok(())
+    }
+
+    async fn load_poisoned_messages(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<Vec<PoisonedMessage>, Error> {
+        let mut poisoned_messages = Vec::new();
+        let key_prefix = format!(
+            "{}:",
+            PoisonedMessage::get_key_prefix(stream_id, topic_id, partition_id)
+        );
+        for data in self.db.scan_prefix(&key_prefix) {
+            let poisoned_message = match data.with_context(|| {
+                format!(
+                    "Failed to load poisoned message, when searching by key: {}",
+                    key_prefix
+                )
+            }) {
+                Ok((key, value)) => {
+                    let reason = String::from_utf8(value.to_vec()).with_context(|| {
+                        format!("Invalid reason of poisoned message, key: {}", key)
+                    });
+                    let offset = key
+                        .rsplit(':')
+                        .next()
+                        .unwrap_or_default()
+                        .parse::<u64>()
+                        .with_context(|| {
+                            format!("Invalid offset of poisoned message, key: {}", key)
+                        });
+                    match (reason, offset) {
+                        (Ok(reason), Ok(offset)) => PoisonedMessage {
+                            key,
+                            offset,
+                            reason,
+                        },
+                        (Err(err), _) | (_, Err(err)) => {
+                            return Err(Error::CannotLoadResource(err));
+                        }
+                    }
+                }
+                Err(err) => {
+                    return Err(Error::CannotLoadResource(err));
+                }
+            };
+            poisoned_messages.push(poisoned_message);
+        }
+
+        poisoned_messages.sort_by_key(|message| message.offset);
+        Ok(poisoned_messages)
+    }
+
+    async fn delete_poisoned_messages(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<(), Error> {
+        let key_prefix = format!(
+            "{}:",
+            PoisonedMessage::get_key_prefix(stream_id, topic_id, partition_id)
+        );
+
+        for data in self.db.scan_prefix(&key_prefix) {
+            match data.with_context(|| {
+                format!(
+                    "Failed to delete poisoned message, when searching by key: {}",
+                    key_prefix
+                )
+            }) {
+                Ok((key, _)) => {
+                    if let Err(err) = self.db.remove(&key).with_context(|| {
+                        format!("Failed to delete poisoned message, key: {:?}", key)
+                    }) {
+                        return Err(Error::CannotLoadResource(err));
+                    }
+                }
+                Err(err) => {
+                    return Err(Error::CannotLoadResource(err));
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -193,17 +332,24 @@ impl Storage<Partition> for FilePartitionStorage {
 
             let path = dir_entry.path();
             let extension = path.extension();
-            if extension.is_none() || extension.unwrap() != LOG_EXTENSION {
+            if extension.is_none()
+                || (extension.unwrap() != LOG_EXTENSION && extension.unwrap() != REMOTE_EXTENSION)
+            {
                 continue;
             }
 
-            let log_file_name = dir_entry
-                .file_name()
-                .into_string()
-                .unwrap()
-                .replace(&format!(".{}", LOG_EXTENSION), "");
+            // The remote segment is loaded using its marker only if the log file was freed.
+            if extension.unwrap() == REMOTE_EXTENSION && path.with_extension(LOG_EXTENSION).exists()
+            {
+                continue;
+            }
 
-            let start_offset = log_file_name.parse::<u64>().unwrap();
+            let start_offset = path
+                .file_stem()
+                .unwrap()
+                .to_string_lossy()
+                .parse::<u64>()
+                .unwrap();
             let mut segment = Segment::create(
                 partition.stream_id,
                 partition.topic_id,
@@ -213,6 +359,15 @@ impl Storage<Partition> for FilePartitionStorage {
                 partition.storage.clone(),
                 partition.message_expiry,
             );
+            // The offloaded segments are skipped, as their log files are stored in the tiered storage.
+            if partition.config.partition.recover_segments && Path::new(&segment.log_path).exists()
+            {
+                let report = segment.check_integrity(true).await?;
+                if report.repaired {
+                    warn!("Recovered segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}, {}", segment.start_offset, partition.partition_id, partition.topic_id, partition.stream_id, report);
+                }
+            }
+
             segment.load().await?;
             if !segment.is_closed {
                 segment.unsaved_messages = Some(Vec::new())
@@ -223,7 +378,8 @@ impl Storage<Partition> for FilePartitionStorage {
                 partition.should_increment_offset = segment.current_size_bytes > 0;
             }
 
-            if partition.config.partition.validate_checksum {
+            // The messages of the offloaded segments are not fetched from the tiered storage on startup.
+            if partition.config.partition.validate_checksum && !segment.is_offloaded() {
                 info!("Validating messages checksum for partition with ID: {} and segment with start offset: {}...", partition.partition_id, segment.start_offset);
                 segment.storage.segment.load_checksums(&segment).await?;
                 info!("Validated messages checksum for partition with ID: {} and segment with start offset: {}.", partition.partition_id, segment.start_offset);
@@ -231,7 +387,11 @@ impl Storage<Partition> for FilePartitionStorage {
 
             // Load the unique message IDs for the partition if the deduplication feature is enabled.
             let mut unique_message_ids_count = 0;
-            if let Some(message_deduplicator) = &partition.message_deduplicator {
+            if let Some(message_deduplicator) = partition
+                .message_deduplicator
+                .as_ref()
+                .filter(|_| !segment.is_offloaded())
+            {
                 info!("Loading unique message IDs for partition with ID: {} and segment with start offset: {}...", partition.partition_id, segment.start_offset);
                 let message_ids = segment.storage.segment.load_message_ids(&segment).await?;
                 for message_id in message_ids {
@@ -265,6 +425,9 @@ impl Storage<Partition> for FilePartitionStorage {
             }
 
             segment.end_offset = end_offsets[end_offset_index];
+            // Compacted segments might not be full anymore, but only the last segment can be appended to.
+            segment.is_closed = true;
+            segment.unsaved_messages = None;
         }
 
         if !partition.segments.is_empty() {
@@ -277,6 +440,7 @@ impl Storage<Partition> for FilePartitionStorage {
         }
 
         partition.load_consumer_offsets().await?;
+        partition.load_poisoned_messages().await?;
         info!(
             "Loaded partition with ID: {} for stream with ID: {} and topic with ID: {}, current offset: {}.",
             partition.partition_id, partition.stream_id, partition.topic_id, partition.current_offset
@@ -311,7 +475,7 @@ impl Storage<Partition> for FilePartitionStorage {
             Ok(data) => {
                 if let Err(err) = self
                     .db
-                    .insert(&key, data)
+                    .insert(&key, &data)
                     .with_context(|| format!("Failed to insert partition with key: {}", key))
                 {
                     return Err(Error::CannotSaveResource(err));
@@ -326,7 +490,7 @@ impl Storage<Partition> for FilePartitionStorage {
             .db
             .insert(
                 &key,
-                rmp_serde::to_vec(&PartitionData {
+                &rmp_serde::to_vec(&PartitionData {
                     created_at: partition.created_at,
                 })
                 .unwrap(),
@@ -352,7 +516,7 @@ impl Storage<Partition> for FilePartitionStorage {
         );
         if self
             .db
-            .remove(get_partition_key(
+            .remove(&get_partition_key(
                 partition.stream_id,
                 partition.topic_id,
                 partition.partition_id,
@@ -400,6 +564,37 @@ impl Storage<Partition> for FilePartitionStorage {
             ));
         }
 
+        if let Err(err) = self
+            .delete_poisoned_messages(
+                partition.stream_id,
+                partition.topic_id,
+                partition.partition_id,
+            )
+            .await
+        {
+            error!("Cannot delete poisoned messages for partition with ID: {} for topic with ID: {} for stream with ID: {}. Error: {}", partition.partition_id, partition.topic_id, partition.stream_id, err);
+            return Err(Error::CannotDeletePartition(
+                partition.partition_id,
+                partition.topic_id,
+                partition.stream_id,
+            ));
+        }
+
+        for segment in partition
+            .segments
+            .iter()
+            .filter(|segment| segment.is_remote)
+        {
+            if let Err(err) = segment.storage.segment.delete(segment).await {
+                error!("Cannot delete remote segment with start offset: {} for partition with ID: {} for topic with ID: {} for stream with ID: {}. Error: {}", segment.start_offset, partition.partition_id, partition.topic_id, partition.stream_id, err);
+                return Err(Error::CannotDeletePartition(
+                    partition.partition_id,
+                    partition.topic_id,
+                    partition.stream_id,
+                ));
+            }
+        }
+
         if fs::remove_dir_all(&partition.path).await.is_err() {
             error!("Cannot delete partition directory: {} for partition with ID: {} for topic with ID: {} for stream with ID: {}.", partition.path, partition.partition_id, partition.topic_id, partition.stream_id);
             return Err(Error::CannotDeletePartitionDirectory(
@@ -0,0 +1,108 @@
+use crate::streaming::partitions::partition::Partition;
+use iggy::error::Error;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use tokio::sync::RwLock;
+use tracing::info;
+
+const MAX_REMOTE_LOG_FETCH_ATTEMPTS: u32 = 3;
+
+pub type PartitionRead<'a, T> = Pin<Box<dyn Future<Output = Result<T, Error>> + Send + 'a>>;
+
+/// Reads from the partition, fetching the freed log files of the offloaded segments on demand.
+/// The log file is downloaded without holding the partition lock, so the appends and other reads aren't blocked,
+/// and the read is retried afterwards, as the partition might have changed in the meantime.
+/// The read spanning many offloaded segments fetches them one by one, each at most a few times.
+pub async fn read_partition<T>(
+    partition: &RwLock<Partition>,
+    read: impl for<'a> Fn(&'a Partition) -> PartitionRead<'a, T>,
+) -> Result<T, Error> {
+    let mut attempts = HashMap::new();
+    loop {
+        let fetch = {
+            let partition = partition.read().await;
+            let start_offset = match read(&partition).await {
+                Err(Error::RemoteSegmentNotFetched(start_offset, _))
+                    if attempts.get(&start_offset).copied().unwrap_or_default()
+                        < MAX_REMOTE_LOG_FETCH_ATTEMPTS =>
+                {
+                    start_offset
+                }
+                result => return result,
+            };
+            *attempts.entry(start_offset).or_insert(0) += 1;
+            partition
+                .get_segment(start_offset)
+                .map(|segment| segment.get_remote_log_fetch())
+                .transpose()?
+        };
+
+        if let Some(fetch) = fetch {
+            fetch.fetch().await?;
+        }
+    }
+}
+
+/// Offloads the closed segments with the last message older than the given timestamp to the tiered storage.
+/// The segments fetched back from the tiered storage are freed again. Returns the number of offloaded segments.
+///
+/// The segment files are uploaded without holding the partition lock, which is acquired only to free the local log.
+pub async fn offload_segments(
+    partition: &RwLock<Partition>,
+    hot_window_expiry: u64,
+) -> Result<u32, Error> {
+    let (partition_id, topic_id, stream_id, uploads) = {
+        let partition = partition.read().await;
+        let mut uploads = Vec::new();
+        for segment in &partition.segments {
+            if !segment.is_closed || segment.is_offloaded() {
+                continue;
+            }
+
+            let Some(last_timestamp) = segment.last_timestamp else {
+                continue;
+            };
+
+            // The segments are ordered, so the following ones are within the hot window as well.
+            if last_timestamp > hot_window_expiry {
+                break;
+            }
+
+            uploads.push(segment.get_upload()?);
+        }
+        (
+            partition.partition_id,
+            partition.topic_id,
+            partition.stream_id,
+            uploads,
+        )
+    };
+
+    let mut offloaded_segments_count = 0;
+    for upload in uploads {
+        upload.upload().await?;
+        let mut partition = partition.write().await;
+        // The segment might have been deleted in the meantime.
+        let Some(segment) = partition
+            .segments
+            .iter_mut()
+            .find(|segment| segment.start_offset == upload.start_offset)
+        else {
+            continue;
+        };
+
+        if segment.offload(&upload).await? {
+            offloaded_segments_count += 1;
+        }
+    }
+
+    if offloaded_segments_count > 0 {
+        info!(
+            "Offloaded {} segments for partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+            offloaded_segments_count, partition_id, topic_id, stream_id
+        );
+    }
+
+    Ok(offloaded_segments_count)
+}
@@ -1,10 +1,10 @@
+use crate::streaming::metadata::database::Database;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::storage::{PersonalAccessTokenStorage, Storage};
 use anyhow::Context;
 use async_trait::async_trait;
 use iggy::error::Error;
 use iggy::models::user_info::UserId;
-use sled::Db;
 use std::str::from_utf8;
 use std::sync::Arc;
 use tracing::info;
@@ -13,11 +13,11 @@ const KEY_PREFIX: &str = "personal_access_token";
 
 #[derive(Debug)]
 pub struct FilePersonalAccessTokenStorage {
-    db: Arc<Db>,
+    db: Arc<dyn Database>,
 }
 
 impl FilePersonalAccessTokenStorage {
-    pub fn new(db: Arc<Db>) -> Self {
+    pub fn new(db: Arc<dyn Database>) -> Self {
         Self { db }
     }
 }
@@ -29,7 +29,7 @@ unsafe impl Sync for FilePersonalAccessTokenStorage {}
 impl PersonalAccessTokenStorage for FilePersonalAccessTokenStorage {
     async fn load_all(&self) -> Result<Vec<PersonalAccessToken>, Error> {
         let mut personal_access_tokens = Vec::new();
-        for data in self.db.scan_prefix(format!("{}:token:", KEY_PREFIX)) {
+        for data in self.db.scan_prefix(&format!("{}:token:", KEY_PREFIX)) {
             let personal_access_token = match data
                 .with_context(|| format!("Failed to load personal access token, when searching by key: {}", KEY_PREFIX)){
                 Ok((_, value)) => match rmp_serde::from_slice::<PersonalAccessToken>(&value)
@@ -133,7 +133,7 @@ impl PersonalAccessTokenStorage for FilePersonalAccessTokenStorage {
         let key = get_name_key(user_id, name);
         if let Err(err) = self
             .db
-            .remove(key)
+            .remove(&key)
             .with_context(|| "Failed to delete personal access token")
         {
             return Err(Error::CannotDeleteResource(err));
@@ -141,7 +141,7 @@ impl PersonalAccessTokenStorage for FilePersonalAccessTokenStorage {
         let key = get_key(&personal_access_token.token);
         if let Err(err) = self
             .db
-            .remove(key)
+            .remove(&key)
             .with_context(|| "Failed to delete personal access token")
         {
             return Err(Error::CannotDeleteResource(err));
@@ -167,7 +167,7 @@ impl Storage<PersonalAccessToken> for FilePersonalAccessTokenStorage {
             Ok(data) => {
                 if let Err(err) = self
                     .db
-                    .insert(key, data)
+                    .insert(&key, &data)
                     .with_context(|| "Failed to save personal access token")
                 {
                     return Err(Error::CannotSaveResource(err));
@@ -175,7 +175,7 @@ impl Storage<PersonalAccessToken> for FilePersonalAccessTokenStorage {
                 if let Err(err) = self
                     .db
                     .insert(
-                        get_name_key(personal_access_token.user_id, &personal_access_token.name),
+                        &get_name_key(personal_access_token.user_id, &personal_access_token.name),
                         personal_access_token.token.as_bytes(),
                     )
                     .with_context(|| "Failed to save personal access token")
@@ -0,0 +1,566 @@
+use crate::configs::system::{QuotaLimitsConfig, QuotaMode, QuotasConfig};
+use crate::streaming::session::Session;
+use iggy::error::Error;
+use iggy::models::client_info::{ClientQuotasInfo, QuotaUsageInfo, QuotasUsageInfo};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+use tracing::trace;
+
+/// The resources required to handle the request, checked against the quotas before handling it.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct QuotaDemand {
+    pub requests: u64,
+    pub sent_messages: u64,
+    pub sent_bytes: u64,
+    /// The polled messages and bytes are known only once the request is handled,
+    /// thus the poll is allowed only if the previously polled ones fit within the quotas.
+    pub poll: bool,
+}
+
+/// Enforces the per second quotas of the users (shared by all their clients) and of the individual clients.
+/// The user quotas can be overridden for the specific users, replacing the default ones.
+/// Each quota is a token bucket refilled continuously at the configured rate, with the capacity equal to the rate.
+/// Depending on the mode, the request exceeding the quota is either delayed until the tokens are available
+/// (unless it would take longer than the max throttle delay) or rejected with `QuotaExceeded` error.
+#[derive(Debug)]
+pub struct QuotaManager {
+    enabled: bool,
+    mode: QuotaMode,
+    max_throttle_delay: Duration,
+    user_limits: QuotaLimitsConfig,
+    user_overrides: HashMap<u32, QuotaLimitsConfig>,
+    client_limits: QuotaLimitsConfig,
+    usage: Mutex<QuotasUsage>,
+}
+
+#[derive(Debug, Default)]
+struct QuotasUsage {
+    users: HashMap<u32, Buckets>,
+    clients: HashMap<u32, ClientUsage>,
+}
+
+#[derive(Debug)]
+struct ClientUsage {
+    buckets: Buckets,
+    throttled_requests: u64,
+    rejected_requests: u64,
+}
+
+#[derive(Debug)]
+struct Buckets {
+    requests: TokenBucket,
+    sent_messages: TokenBucket,
+    sent_bytes: TokenBucket,
+    polled_messages: TokenBucket,
+    polled_bytes: TokenBucket,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    limit: u64,
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl QuotaManager {
+    pub fn new(config: &QuotasConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            mode: config.mode,
+            max_throttle_delay: config.max_throttle_delay.get_duration(),
+            user_limits: config.user,
+            user_overrides: config
+                .user_overrides
+                .iter()
+                .map(|user| (user.user_id, user.limits))
+                .collect(),
+            client_limits: config.client,
+            usage: Mutex::new(QuotasUsage::default()),
+        }
+    }
+
+    /// Checks the demand against the quotas of the session's user and client.
+    /// In throttle mode, the resources are reserved and the call is delayed until they become available.
+    pub async fn acquire(&self, session: &Session, demand: QuotaDemand) -> Result<(), Error> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let delay = self.reserve(
+            session.get_user_id(),
+            session.client_id,
+            demand,
+            Instant::now(),
+        )?;
+        if !delay.is_zero() {
+            trace!(
+                "Throttling request for {delay:?} due to the exceeded quotas, session: {session}."
+            );
+            sleep(delay).await;
+        }
+
+        Ok(())
+    }
+
+    /// Records the messages polled by the session, which will be taken into account by the next polls.
+    pub fn record_polled(&self, session: &Session, messages: u64, bytes: u64) {
+        if !self.enabled || messages == 0 {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut usage = self.usage.lock().unwrap();
+        let user_id = session.get_user_id();
+        if user_id > 0 {
+            usage
+                .users
+                .entry(user_id)
+                .or_insert_with(|| Buckets::new(self.get_user_limits(user_id), now))
+                .record_polled(messages, bytes, now);
+        }
+        if session.client_id > 0 {
+            usage
+                .clients
+                .entry(session.client_id)
+                .or_insert_with(|| ClientUsage::new(&self.client_limits, now))
+                .buckets
+                .record_polled(messages, bytes, now);
+        }
+    }
+
+    /// Returns the quotas usage of the client, or `None` if the quotas are disabled.
+    pub fn get_client_quotas(
+        &self,
+        client_id: u32,
+        user_id: Option<u32>,
+    ) -> Option<ClientQuotasInfo> {
+        if !self.enabled {
+            return None;
+        }
+
+        let now = Instant::now();
+        let mut usage = self.usage.lock().unwrap();
+        let user = match user_id {
+            Some(user_id) => match usage.users.get_mut(&user_id) {
+                Some(buckets) => buckets.get_usage(now),
+                None => Buckets::new(self.get_user_limits(user_id), now).get_usage(now),
+            },
+            None => Buckets::new(&self.user_limits, now).get_usage(now),
+        };
+        let client = match usage.clients.get_mut(&client_id) {
+            Some(client) => ClientQuotasInfo {
+                throttled_requests: client.throttled_requests,
+                rejected_requests: client.rejected_requests,
+                user,
+                client: client.buckets.get_usage(now),
+            },
+            None => ClientQuotasInfo {
+                user,
+                client: Buckets::new(&self.client_limits, now).get_usage(now),
+                ..Default::default()
+            },
+        };
+        Some(client)
+    }
+
+    pub fn delete_client(&self, client_id: u32) {
+        if !self.enabled {
+            return;
+        }
+
+        self.usage.lock().unwrap().clients.remove(&client_id);
+    }
+
+    fn get_user_limits(&self, user_id: u32) -> &QuotaLimitsConfig {
+        self.user_overrides
+            .get(&user_id)
+            .unwrap_or(&self.user_limits)
+    }
+
+    fn reserve(
+        &self,
+        user_id: u32,
+        client_id: u32,
+        demand: QuotaDemand,
+        now: Instant,
+    ) -> Result<Duration, Error> {
+        let mut usage = self.usage.lock().unwrap();
+        let QuotasUsage { users, clients } = &mut *usage;
+        let mut user = if user_id > 0 {
+            Some(
+                users
+                    .entry(user_id)
+                    .or_insert_with(|| Buckets::new(self.get_user_limits(user_id), now)),
+            )
+        } else {
+            None
+        };
+        let mut client = if client_id > 0 {
+            Some(
+                clients
+                    .entry(client_id)
+                    .or_insert_with(|| ClientUsage::new(&self.client_limits, now)),
+            )
+        } else {
+            None
+        };
+
+        let mut delay = Duration::ZERO;
+        let mut exceeded_quota = None;
+        if let Some(user) = user.as_mut() {
+            if let Some((wait, quota)) = user.get_wait(&demand, now) {
+                delay = wait;
+                exceeded_quota = Some(format!("{quota} for user with ID: {user_id}"));
+            }
+        }
+        if let Some(client) = client.as_mut() {
+            if let Some((wait, quota)) = client.buckets.get_wait(&demand, now) {
+                if wait > delay {
+                    delay = wait;
+                    exceeded_quota = Some(format!("{quota} for client with ID: {client_id}"));
+                }
+            }
+        }
+
+        if let Some(exceeded_quota) = exceeded_quota {
+            if self.mode == QuotaMode::Reject || delay > self.max_throttle_delay {
+                if let Some(client) = client {
+                    client.rejected_requests += 1;
+                }
+                return Err(Error::QuotaExceeded(exceeded_quota));
+            }
+
+            if let Some(client) = client.as_mut() {
+                client.throttled_requests += 1;
+            }
+        }
+
+        if let Some(user) = user {
+            user.consume(&demand);
+        }
+        if let Some(client) = client {
+            client.buckets.consume(&demand);
+        }
+        Ok(delay)
+    }
+}
+
+impl ClientUsage {
+    fn new(limits: &QuotaLimitsConfig, now: Instant) -> Self {
+        Self {
+            buckets: Buckets::new(limits, now),
+            throttled_requests: 0,
+            rejected_requests: 0,
+        }
+    }
+}
+
+impl Buckets {
+    fn new(limits: &QuotaLimitsConfig, now: Instant) -> Self {
+        Self {
+            requests: TokenBucket::new(limits.requests_per_second, now),
+            sent_messages: TokenBucket::new(limits.send_messages_per_second, now),
+            sent_bytes: TokenBucket::new(limits.send_bytes_per_second.as_bytes_u64(), now),
+            polled_messages: TokenBucket::new(limits.poll_messages_per_second, now),
+            polled_bytes: TokenBucket::new(limits.poll_bytes_per_second.as_bytes_u64(), now),
+        }
+    }
+
+    /// Returns the longest wait required by the demand along with the description of the exceeded quota, if any.
+    fn get_wait(&mut self, demand: &QuotaDemand, now: Instant) -> Option<(Duration, String)> {
+        let mut checks = Vec::with_capacity(5);
+        if demand.requests > 0 {
+            checks.push(("requests", &mut self.requests, demand.requests));
+        }
+        if demand.sent_messages > 0 {
+            checks.push((
+                "sent messages",
+                &mut self.sent_messages,
+                demand.sent_messages,
+            ));
+        }
+        if demand.sent_bytes > 0 {
+            checks.push(("sent bytes", &mut self.sent_bytes, demand.sent_bytes));
+        }
+        if demand.poll {
+            checks.push(("polled messages", &mut self.polled_messages, 0));
+            checks.push(("polled bytes", &mut self.polled_bytes, 0));
+        }
+
+        let mut result: Option<(Duration, String)> = None;
+        for (name, bucket, amount) in checks {
+            let wait = bucket.get_wait(amount, now);
+            if wait.is_zero() || result.as_ref().is_some_and(|(max, _)| *max >= wait) {
+                continue;
+            }
+
+            result = Some((wait, format!("{name} per second limit: {}", bucket.limit)));
+        }
+        result
+    }
+
+    fn consume(&mut self, demand: &QuotaDemand) {
+        self.requests.consume(demand.requests);
+        self.sent_messages.consume(demand.sent_messages);
+        self.sent_bytes.consume(demand.sent_bytes);
+    }
+
+    fn record_polled(&mut self, messages: u64, bytes: u64, now: Instant) {
+        self.polled_messages.refill(now);
+        self.polled_messages.consume(messages);
+        self.polled_bytes.refill(now);
+        self.polled_bytes.consume(bytes);
+    }
+
+    fn get_usage(&mut self, now: Instant) -> QuotasUsageInfo {
+        QuotasUsageInfo {
+            requests: self.requests.get_usage(now),
+            sent_messages: self.sent_messages.get_usage(now),
+            sent_bytes: self.sent_bytes.get_usage(now),
+            polled_messages: self.polled_messages.get_usage(now),
+            polled_bytes: self.polled_bytes.get_usage(now),
+        }
+    }
+}
+
+impl TokenBucket {
+    fn new(limit: u64, now: Instant) -> Self {
+        Self {
+            limit,
+            tokens: limit as f64,
+            updated_at: now,
+        }
+    }
+
+    fn is_unlimited(&self) -> bool {
+        self.limit == 0
+    }
+
+    fn refill(&mut self, now: Instant) {
+        if self.is_unlimited() || now <= self.updated_at {
+            return;
+        }
+
+        let elapsed = now.duration_since(self.updated_at).as_secs_f64();
+        let limit = self.limit as f64;
+        self.tokens = (self.tokens + elapsed * limit).min(limit);
+        self.updated_at = now;
+    }
+
+    /// Returns how long it takes until the bucket has enough tokens for the given amount.
+    /// The amount is capped at the bucket capacity, so the larger ones are allowed once the bucket is full,
+    /// and the resulting debt delays the subsequent requests instead.
+    fn get_wait(&mut self, amount: u64, now: Instant) -> Duration {
+        if self.is_unlimited() {
+            return Duration::ZERO;
+        }
+
+        self.refill(now);
+        let required = amount.min(self.limit) as f64;
+        if self.tokens >= required {
+            return Duration::ZERO;
+        }
+
+        Duration::from_secs_f64((required - self.tokens) / self.limit as f64)
+    }
+
+    fn consume(&mut self, amount: u64) {
+        if self.is_unlimited() {
+            return;
+        }
+
+        self.tokens -= amount as f64;
+    }
+
+    fn get_usage(&mut self, now: Instant) -> QuotaUsageInfo {
+        if self.is_unlimited() {
+            return QuotaUsageInfo::default();
+        }
+
+        self.refill(now);
+        QuotaUsageInfo {
+            limit: self.limit,
+            used: (self.limit as f64 - self.tokens).max(0.0).round() as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::UserQuotaLimitsConfig;
+    use crate::streaming::clients::client_manager::Transport;
+
+    const USER_ID: u32 = 1;
+    const CLIENT_ID: u32 = 2;
+
+    #[test]
+    fn requests_exceeding_quota_should_be_rejected_in_reject_mode() {
+        let quota_manager = QuotaManager::new(&get_config(QuotaMode::Reject));
+        let now = Instant::now();
+        for _ in 0..2 {
+            let delay = quota_manager
+                .reserve(USER_ID, CLIENT_ID, request(), now)
+                .unwrap();
+            assert!(delay.is_zero());
+        }
+
+        let result = quota_manager.reserve(USER_ID, CLIENT_ID, request(), now);
+        assert!(matches!(result, Err(Error::QuotaExceeded(_))));
+
+        let delay = quota_manager
+            .reserve(USER_ID, CLIENT_ID, request(), now + Duration::from_secs(1))
+            .unwrap();
+        assert!(delay.is_zero());
+
+        let quotas = quota_manager
+            .get_client_quotas(CLIENT_ID, Some(USER_ID))
+            .unwrap();
+        assert_eq!(quotas.rejected_requests, 1);
+        assert_eq!(quotas.throttled_requests, 0);
+        assert_eq!(quotas.client.requests.limit, 2);
+    }
+
+    #[test]
+    fn requests_exceeding_quota_should_be_delayed_in_throttle_mode() {
+        let quota_manager = QuotaManager::new(&get_config(QuotaMode::Throttle));
+        let now = Instant::now();
+        for _ in 0..2 {
+            let delay = quota_manager
+                .reserve(USER_ID, CLIENT_ID, request(), now)
+                .unwrap();
+            assert!(delay.is_zero());
+        }
+
+        let delay = quota_manager
+            .reserve(USER_ID, CLIENT_ID, request(), now)
+            .unwrap();
+        assert_eq!(delay, Duration::from_millis(500));
+        let delay = quota_manager
+            .reserve(USER_ID, CLIENT_ID, request(), now)
+            .unwrap();
+        assert_eq!(delay, Duration::from_secs(1));
+
+        let result = quota_manager.reserve(USER_ID, CLIENT_ID, request(), now);
+        assert!(matches!(result, Err(Error::QuotaExceeded(_))));
+
+        let quotas = quota_manager
+            .get_client_quotas(CLIENT_ID, Some(USER_ID))
+            .unwrap();
+        assert_eq!(quotas.throttled_requests, 2);
+        assert_eq!(quotas.rejected_requests, 1);
+    }
+
+    #[test]
+    fn user_quota_should_be_shared_by_all_its_clients() {
+        let quota_manager = QuotaManager::new(&get_config(QuotaMode::Reject));
+        let now = Instant::now();
+        let demand = QuotaDemand {
+            sent_messages: 50,
+            ..Default::default()
+        };
+        assert!(quota_manager.reserve(USER_ID, 10, demand, now).is_ok());
+        assert!(quota_manager.reserve(USER_ID, 11, demand, now).is_ok());
+        let result = quota_manager.reserve(USER_ID, 12, demand, now);
+        assert!(matches!(result, Err(Error::QuotaExceeded(_))));
+        assert!(quota_manager.reserve(USER_ID + 1, 12, demand, now).is_ok());
+    }
+
+    #[test]
+    fn user_with_overridden_quota_should_get_its_own_limits() {
+        let mut config = get_config(QuotaMode::Reject);
+        config.user_overrides = vec![UserQuotaLimitsConfig {
+            user_id: USER_ID + 1,
+            limits: QuotaLimitsConfig {
+                send_messages_per_second: 200,
+                ..Default::default()
+            },
+        }];
+        let quota_manager = QuotaManager::new(&config);
+        let now = Instant::now();
+        let demand = QuotaDemand {
+            sent_messages: 150,
+            ..Default::default()
+        };
+        let result = quota_manager.reserve(USER_ID, 10, demand, now);
+        assert!(result.is_ok());
+        let result = quota_manager.reserve(USER_ID, 10, demand, now);
+        assert!(matches!(result, Err(Error::QuotaExceeded(_))));
+        assert!(quota_manager.reserve(USER_ID + 1, 11, demand, now).is_ok());
+
+        let quotas = quota_manager.get_client_quotas(10, Some(USER_ID)).unwrap();
+        assert_eq!(quotas.user.sent_messages.limit, 100);
+        let quotas = quota_manager
+            .get_client_quotas(11, Some(USER_ID + 1))
+            .unwrap();
+        assert_eq!(quotas.user.sent_messages.limit, 200);
+        let quotas = quota_manager
+            .get_client_quotas(12, Some(USER_ID + 1))
+            .unwrap();
+        assert_eq!(quotas.user.sent_messages.limit, 200);
+    }
+
+    #[test]
+    fn poll_should_be_rejected_until_polled_messages_fit_within_quota() {
+        let quota_manager = QuotaManager::new(&get_config(QuotaMode::Reject));
+        let session = Session::new(
+            CLIENT_ID,
+            USER_ID,
+            "127.0.0.1:1234".parse().unwrap(),
+            Transport::Tcp,
+        );
+        let now = Instant::now();
+        let demand = QuotaDemand {
+            poll: true,
+            ..Default::default()
+        };
+        assert!(quota_manager
+            .reserve(USER_ID, CLIENT_ID, demand, now)
+            .is_ok());
+
+        quota_manager.record_polled(&session, 20, 2000);
+        let result = quota_manager.reserve(USER_ID, CLIENT_ID, demand, now);
+        assert!(matches!(result, Err(Error::QuotaExceeded(_))));
+
+        let quotas = quota_manager
+            .get_client_quotas(CLIENT_ID, Some(USER_ID))
+            .unwrap();
+        assert_eq!(quotas.client.polled_messages.limit, 10);
+        assert!(quotas.client.polled_messages.used >= 19);
+    }
+
+    #[test]
+    fn client_quotas_should_not_be_returned_given_disabled_quotas() {
+        let mut config = get_config(QuotaMode::Reject);
+        config.enabled = false;
+        let quota_manager = QuotaManager::new(&config);
+        assert!(quota_manager.get_client_quotas(CLIENT_ID, None).is_none());
+    }
+
+    fn request() -> QuotaDemand {
+        QuotaDemand {
+            requests: 1,
+            ..Default::default()
+        }
+    }
+
+    fn get_config(mode: QuotaMode) -> QuotasConfig {
+        QuotasConfig {
+            enabled: true,
+            mode,
+            max_throttle_delay: "1s".parse().unwrap(),
+            user: QuotaLimitsConfig {
+                send_messages_per_second: 100,
+                ..Default::default()
+            },
+            client: QuotaLimitsConfig {
+                requests_per_second: 2,
+                poll_messages_per_second: 10,
+                ..Default::default()
+            },
+            user_overrides: Vec::new(),
+        }
+    }
+}
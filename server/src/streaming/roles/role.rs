@@ -0,0 +1,41 @@
+use iggy::models::permissions::Permissions;
+use iggy::models::role_info::RoleId;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Role {
+    pub id: RoleId,
+    pub name: String,
+    pub created_at: u64,
+    pub permissions: Permissions,
+}
+
+impl Default for Role {
+    fn default() -> Self {
+        Self {
+            id: 1,
+            name: "role".to_string(),
+            created_at: IggyTimestamp::now().to_micros(),
+            permissions: Permissions::default(),
+        }
+    }
+}
+
+impl Role {
+    pub fn empty(id: RoleId) -> Self {
+        Self {
+            id,
+            ..Default::default()
+        }
+    }
+
+    pub fn new(id: RoleId, name: &str, permissions: Permissions) -> Self {
+        Self {
+            id,
+            name: name.to_string(),
+            created_at: IggyTimestamp::now().to_micros(),
+            permissions,
+        }
+    }
+}
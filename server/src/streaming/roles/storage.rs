@@ -0,0 +1,203 @@
+use crate::streaming::metadata::database::Database;
+use crate::streaming::roles::role::Role;
+use crate::streaming::storage::{RoleStorage, Storage};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy::error::Error;
+use iggy::models::role_info::RoleId;
+use std::sync::Arc;
+use tracing::info;
+
+const KEY_PREFIX: &str = "roles";
+
+#[derive(Debug)]
+pub struct FileRoleStorage {
+    db: Arc<dyn Database>,
+}
+
+impl FileRoleStorage {
+    pub fn new(db: Arc<dyn Database>) -> Self {
+        Self { db }
+    }
+}
+
+unsafe impl Send for FileRoleStorage {}
+unsafe impl Sync for FileRoleStorage {}
+
+#[async_trait]
+impl RoleStorage for FileRoleStorage {
+    async fn load_by_id(&self, id: RoleId) -> Result<Role, Error> {
+        let mut role = Role::empty(id);
+        self.load(&mut role).await?;
+        Ok(role)
+    }
+
+    async fn load_by_name(&self, name: &str) -> Result<Role, Error> {
+        let role_id_key = get_id_key(name);
+        let role_id = self.db.get(&role_id_key).with_context(|| {
+            format!(
+                "Failed to load role with key: {}, name: {}",
+                role_id_key, name
+            )
+        });
+        match role_id {
+            Ok(role_id) => {
+                if let Some(role_id) = role_id {
+                    let role_id = u32::from_le_bytes(role_id.as_slice().try_into()?);
+                    let mut role = Role::empty(role_id);
+                    self.load(&mut role).await?;
+                    Ok(role)
+                } else {
+                    Err(Error::ResourceNotFound(role_id_key))
+                }
+            }
+            Err(err) => Err(Error::CannotLoadResource(err)),
+        }
+    }
+
+    async fn load_all(&self) -> Result<Vec<Role>, Error> {
+        let mut roles = Vec::new();
+        for data in self.db.scan_prefix(&format!("{}:", KEY_PREFIX)) {
+            let role = match data.with_context(|| {
+                format!(
+                    "Failed to load role, when searching for key: {}",
+                    KEY_PREFIX
+                )
+            }) {
+                Ok((_, value)) => match rmp_serde::from_slice::<Role>(&value).with_context(|| {
+                    format!(
+                        "Failed to deserialize role, when searching for key: {}",
+                        KEY_PREFIX
+                    )
+                }) {
+                    Ok(role) => role,
+                    Err(err) => {
+                        return Err(Error::CannotDeserializeResource(err));
+                    }
+                },
+                Err(err) => {
+                    return Err(Error::CannotLoadResource(err));
+                }
+            };
+            roles.push(role);
+        }
+
+        Ok(roles)
+    }
+
+    /// Deletes only the name of the role, so the role can be renamed without losing its record.
+    async fn delete_name(&self, name: &str) -> Result<(), Error> {
+        let key = get_id_key(name);
+        if let Err(err) = self
+            .db
+            .remove(&key)
+            .with_context(|| format!("Failed to delete role name: {}, key: {}", name, key))
+        {
+            return Err(Error::CannotDeleteResource(err));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Storage<Role> for FileRoleStorage {
+    async fn load(&self, role: &mut Role) -> Result<(), Error> {
+        let key = get_key(role.id);
+        let role_data =
+            match self.db.get(&key).with_context(|| {
+                format!("Failed to load role with key: {}, name: {}", key, role.name)
+            }) {
+                Ok(data) => {
+                    if let Some(role_data) = data {
+                        role_data
+                    } else {
+                        return Err(Error::ResourceNotFound(key));
+                    }
+                }
+                Err(err) => {
+                    return Err(Error::CannotLoadResource(err));
+                }
+            };
+
+        let role_data = rmp_serde::from_slice::<Role>(&role_data)
+            .with_context(|| format!("Failed to deserialize role with key: {}", key));
+        match role_data {
+            Ok(role_data) => {
+                role.name = role_data.name;
+                role.created_at = role_data.created_at;
+                role.permissions = role_data.permissions;
+                Ok(())
+            }
+            Err(err) => {
+                return Err(Error::CannotDeserializeResource(err));
+            }
+        }
+    }
+
+    async fn save(&self, role: &Role) -> Result<(), Error> {
+        let key = get_key(role.id);
+        match rmp_serde::to_vec(&role)
+            .with_context(|| format!("Failed to serialize role with key: {}", key))
+        {
+            Ok(data) => {
+                if let Err(err) = self
+                    .db
+                    .insert(&key, &data)
+                    .with_context(|| format!("Failed to insert role with key: {}", key))
+                {
+                    return Err(Error::CannotSaveResource(err));
+                }
+                if let Err(err) = self
+                    .db
+                    .insert(&get_id_key(&role.name), &role.id.to_le_bytes())
+                    .with_context(|| {
+                        format!(
+                            "Failed to insert role with ID: {} key: {}",
+                            &role.id,
+                            get_id_key(&role.name)
+                        )
+                    })
+                {
+                    return Err(Error::CannotSaveResource(err));
+                }
+            }
+            Err(err) => {
+                return Err(Error::CannotSerializeResource(err));
+            }
+        }
+
+        info!("Saved role with ID: {}.", role.id);
+        Ok(())
+    }
+
+    async fn delete(&self, role: &Role) -> Result<(), Error> {
+        info!("Deleting role with ID: {}...", role.id);
+        let key = get_key(role.id);
+        if let Err(err) = self
+            .db
+            .remove(&key)
+            .with_context(|| format!("Failed to delete role with ID: {}, key: {}", role.id, key))
+        {
+            return Err(Error::CannotDeleteResource(err));
+        } else {
+            let key = get_id_key(&role.name);
+            if let Err(err) = self.db.remove(&key).with_context(|| {
+                format!("Failed to delete role with ID: {}, key : {}", role.id, key)
+            }) {
+                return Err(Error::CannotDeleteResource(err));
+            } else {
+                info!("Deleted role with ID: {}.", role.id);
+                Ok(())
+            }
+        }
+    }
+}
+
+fn get_key(role_id: RoleId) -> String {
+    format!("{}:{}", KEY_PREFIX, role_id)
+}
+
+fn get_id_key(name: &str) -> String {
+    format!("{}_id:{}", KEY_PREFIX, name)
+}
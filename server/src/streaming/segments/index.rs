@@ -1,9 +1,12 @@
+use crate::configs::system::SegmentConfig;
+
 #[derive(Debug)]
 pub struct Index {
     pub relative_offset: u32,
     pub position: u32,
 }
 
+/// The range of messages to load, which are scanned from the start position, as the indexes might be sparse.
 #[derive(Debug)]
 pub struct IndexRange {
     pub start: Index,
@@ -24,3 +27,129 @@ impl IndexRange {
         }
     }
 }
+
+/// Determines whether the message gets the index and time index entries. The message is indexed if its relative offset
+/// is a multiple of the messages interval, or if it starts at or crosses the multiple of the bytes interval in the log.
+/// The first message of the segment is always indexed, even if the preceding offsets were removed by the compaction,
+/// so the lookups by offset or timestamp never skip the leading messages of the segment.
+pub fn is_indexed(
+    config: &SegmentConfig,
+    relative_offset: u32,
+    position: u32,
+    size_bytes: u32,
+) -> bool {
+    if position == 0 {
+        return true;
+    }
+
+    let interval_messages = config.index_interval_messages;
+    if interval_messages > 0 && relative_offset % interval_messages == 0 {
+        return true;
+    }
+
+    let interval_bytes = config.index_interval_bytes.as_bytes_u64();
+    if interval_bytes == 0 {
+        return false;
+    }
+
+    let start_position = position as u64;
+    let end_position = start_position + size_bytes.max(1) as u64 - 1;
+    start_position % interval_bytes == 0
+        || start_position / interval_bytes != end_position / interval_bytes
+}
+
+/// Returns the nearest index at or preceding the relative offset, the indexes must be sorted by the relative offset.
+pub fn find_preceding_index(indexes: &[Index], relative_offset: u32) -> Option<&Index> {
+    let count = indexes.partition_point(|index| index.relative_offset <= relative_offset);
+    match count {
+        0 => None,
+        _ => indexes.get(count - 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_message_should_be_indexed_given_default_config() {
+        let config = SegmentConfig::default();
+        for relative_offset in 0..10 {
+            assert!(is_indexed(
+                &config,
+                relative_offset,
+                relative_offset * 100,
+                100
+            ));
+        }
+    }
+
+    #[test]
+    fn every_nth_message_should_be_indexed_given_messages_interval() {
+        let config = SegmentConfig {
+            index_interval_messages: 4,
+            ..SegmentConfig::default()
+        };
+        let indexed_offsets = (0..10)
+            .filter(|relative_offset| {
+                is_indexed(&config, *relative_offset, relative_offset * 100, 100)
+            })
+            .collect::<Vec<u32>>();
+        assert_eq!(indexed_offsets, vec![0, 4, 8]);
+    }
+
+    #[test]
+    fn first_message_should_be_indexed_given_compacted_segment() {
+        let config = SegmentConfig {
+            index_interval_messages: 4,
+            ..SegmentConfig::default()
+        };
+        // The compaction removed the messages preceding the relative offset 3, which is now the first one in the log.
+        let indexed_offsets = (3..10)
+            .filter(|relative_offset| {
+                is_indexed(&config, *relative_offset, (relative_offset - 3) * 100, 100)
+            })
+            .collect::<Vec<u32>>();
+        assert_eq!(indexed_offsets, vec![3, 4, 8]);
+    }
+
+    #[test]
+    fn single_message_per_bytes_interval_should_be_indexed_given_bytes_interval() {
+        let config = SegmentConfig {
+            index_interval_messages: 0,
+            index_interval_bytes: "250 B".parse().unwrap(),
+            ..SegmentConfig::default()
+        };
+        // The messages of 100 bytes start at 0, 100, 200, ..., the multiples of 250 are at 0, 250, 500 and 750.
+        let indexed_offsets = (0..10)
+            .filter(|relative_offset| {
+                is_indexed(&config, *relative_offset, relative_offset * 100, 100)
+            })
+            .collect::<Vec<u32>>();
+        assert_eq!(indexed_offsets, vec![0, 2, 5, 7]);
+    }
+
+    #[test]
+    fn preceding_index_should_be_found() {
+        let indexes = vec![
+            Index {
+                relative_offset: 0,
+                position: 0,
+            },
+            Index {
+                relative_offset: 4,
+                position: 400,
+            },
+            Index {
+                relative_offset: 8,
+                position: 800,
+            },
+        ];
+
+        assert_eq!(find_preceding_index(&indexes, 0).unwrap().position, 0);
+        assert_eq!(find_preceding_index(&indexes, 3).unwrap().position, 0);
+        assert_eq!(find_preceding_index(&indexes, 4).unwrap().position, 400);
+        assert_eq!(find_preceding_index(&indexes, 100).unwrap().position, 800);
+        assert!(find_preceding_index(&indexes[1..], 3).is_none());
+    }
+}
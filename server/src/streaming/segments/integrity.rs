@@ -0,0 +1,281 @@
+use crate::configs::system::SegmentConfig;
+use crate::streaming::segments::index;
+use crate::streaming::segments::segment::Segment;
+use crate::streaming::utils::compression;
+use bytes::{BufMut, Bytes};
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::Error;
+use iggy::models::messages::{Message, MessageState};
+use iggy::utils::checksum;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt, BufReader};
+use tracing::trace;
+
+const BUF_READER_CAPACITY_BYTES: usize = 512 * 1000;
+// offset (8) + state (1) + timestamp (8) + id (16) + checksum (4) + headers length (4)
+const MESSAGE_PREFIX_SIZE: u64 = 41;
+
+/// The result of checking the segment files, the inconsistencies are reported even if they were repaired.
+#[derive(Debug, Default, PartialEq)]
+pub struct SegmentIntegrityReport {
+    pub messages_count: u64,
+    pub valid_size_bytes: u64,
+    pub corrupted_size_bytes: u64,
+    pub corrupted_offset: Option<u64>,
+    pub invalid_index: bool,
+    pub invalid_time_index: bool,
+    pub repaired: bool,
+}
+
+impl SegmentIntegrityReport {
+    pub fn is_valid(&self) -> bool {
+        self.corrupted_size_bytes == 0 && !self.invalid_index && !self.invalid_time_index
+    }
+}
+
+impl Display for SegmentIntegrityReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ messages_count: {}, valid_size_bytes: {}, corrupted_size_bytes: {}, corrupted_offset: {}, invalid_index: {}, invalid_time_index: {}, repaired: {} }}",
+            self.messages_count,
+            self.valid_size_bytes,
+            self.corrupted_size_bytes,
+            self.corrupted_offset
+                .map(|offset| offset.to_string())
+                .unwrap_or("none".to_string()),
+            self.invalid_index,
+            self.invalid_time_index,
+            self.repaired
+        )
+    }
+}
+
+impl Segment {
+    pub async fn check_integrity(&self, repair: bool) -> Result<SegmentIntegrityReport, Error> {
+        check_segment_files(
+            &self.config.segment,
+            self.start_offset,
+            &self.log_path,
+            &self.index_path,
+            &self.time_index_path,
+            repair,
+        )
+        .await
+    }
+}
+
+/// Scans the log file verifying the checksum of each message, until the first corrupted or partially written one.
+/// Each entry of the index and time index files must point at the valid message, i.e. match its relative offset
+/// and position (or timestamp). The entries are not required for every message, as the index interval might have changed.
+/// If `repair` is set, the corrupted tail of the log file is truncated and the inconsistent index files are rebuilt.
+pub async fn check_segment_files(
+    config: &SegmentConfig,
+    start_offset: u64,
+    log_path: &str,
+    index_path: &str,
+    time_index_path: &str,
+    repair: bool,
+) -> Result<SegmentIntegrityReport, Error> {
+    let file = File::open(log_path).await?;
+    let file_size = file.metadata().await?.len();
+    let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
+    let mut report = SegmentIntegrityReport::default();
+    let index_bytes = read_file(index_path).await?;
+    let time_index_bytes = read_file(time_index_path).await?;
+    let mut index_entries = IndexEntries::parse(&index_bytes, 8, |bytes| {
+        u32::from_le_bytes(bytes.try_into().unwrap()) as u64
+    });
+    let mut time_index_entries = IndexEntries::parse(&time_index_bytes, 12, |bytes| {
+        u64::from_le_bytes(bytes.try_into().unwrap())
+    });
+    let mut expected_index_bytes = Vec::new();
+    let mut expected_time_index_bytes = Vec::new();
+    let mut last_relative_offset = None;
+    let mut position = 0;
+    while position < file_size {
+        let message = match read_message(&mut reader, file_size - position).await {
+            Ok(message) => message,
+            Err(error) => {
+                trace!(
+                    "Cannot read message at position: {} from segment log file: {}. Error: {}",
+                    position,
+                    log_path,
+                    error
+                );
+                break;
+            }
+        };
+
+        let is_offset_valid = message.offset >= start_offset
+            && message.offset - start_offset <= u32::MAX as u64
+            && last_relative_offset
+                .is_none_or(|relative_offset| message.offset - start_offset > relative_offset);
+        if !is_offset_valid || !is_checksum_valid(&message) {
+            report.corrupted_offset = Some(message.offset);
+            break;
+        }
+
+        let relative_offset = (message.offset - start_offset) as u32;
+        let size_bytes = message.get_size_bytes();
+        index_entries.check(relative_offset, position);
+        time_index_entries.check(relative_offset, message.timestamp);
+        if index::is_indexed(config, relative_offset, position as u32, size_bytes) {
+            expected_index_bytes.put_u32_le(relative_offset);
+            expected_index_bytes.put_u32_le(position as u32);
+            expected_time_index_bytes.put_u32_le(relative_offset);
+            expected_time_index_bytes.put_u64_le(message.timestamp);
+        }
+
+        last_relative_offset = Some(relative_offset as u64);
+        report.messages_count += 1;
+        position += size_bytes as u64;
+    }
+
+    report.valid_size_bytes = position;
+    report.corrupted_size_bytes = file_size - position;
+
+    report.invalid_index = !index_entries.is_valid();
+    report.invalid_time_index = !time_index_entries.is_valid();
+    if !repair || report.is_valid() {
+        return Ok(report);
+    }
+
+    if report.corrupted_size_bytes > 0 {
+        let file = OpenOptions::new().write(true).open(log_path).await?;
+        file.set_len(report.valid_size_bytes).await?;
+        file.sync_all().await?;
+    }
+
+    if report.invalid_index {
+        overwrite(index_path, &expected_index_bytes).await?;
+    }
+
+    if report.invalid_time_index {
+        overwrite(time_index_path, &expected_time_index_bytes).await?;
+    }
+
+    report.repaired = true;
+    Ok(report)
+}
+
+// The entries of the index or time index file, consisting of the relative offset and the position or timestamp.
+struct IndexEntries {
+    entries: Vec<(u32, u64)>,
+    next_entry: usize,
+    is_valid: bool,
+}
+
+impl IndexEntries {
+    fn parse(bytes: &[u8], entry_size: usize, parse_value: impl Fn(&[u8]) -> u64) -> Self {
+        let chunks = bytes.chunks_exact(entry_size);
+        let is_valid = chunks.remainder().is_empty();
+        let entries = chunks
+            .map(|entry| {
+                let relative_offset = u32::from_le_bytes(entry[..4].try_into().unwrap());
+                (relative_offset, parse_value(&entry[4..]))
+            })
+            .collect();
+        Self {
+            entries,
+            next_entry: 0,
+            is_valid,
+        }
+    }
+
+    // The messages are checked in order, so any skipped entry doesn't point at the message boundary.
+    fn check(&mut self, relative_offset: u32, value: u64) {
+        while let Some((entry_relative_offset, entry_value)) = self.entries.get(self.next_entry) {
+            if *entry_relative_offset > relative_offset {
+                break;
+            }
+
+            if *entry_relative_offset < relative_offset || *entry_value != value {
+                self.is_valid = false;
+            }
+            self.next_entry += 1;
+        }
+    }
+
+    // The remaining entries point past the last valid message.
+    fn is_valid(&self) -> bool {
+        self.is_valid && self.next_entry == self.entries.len()
+    }
+}
+
+async fn read_message<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    remaining_size: u64,
+) -> Result<Message, Error> {
+    // The lengths are checked against the remaining size, so the torn write can't cause reading past the file.
+    if remaining_size < MESSAGE_PREFIX_SIZE {
+        return Err(Error::CannotReadMessage);
+    }
+
+    let offset = reader.read_u64_le().await?;
+    let state = MessageState::from_code(reader.read_u8().await?)?;
+    let timestamp = reader.read_u64_le().await?;
+    let id = reader.read_u128_le().await?;
+    let checksum = reader.read_u32_le().await?;
+    let headers_length = reader.read_u32_le().await? as u64;
+    if remaining_size < MESSAGE_PREFIX_SIZE + headers_length + 4 {
+        return Err(Error::CannotReadHeadersPayload);
+    }
+
+    let headers = match headers_length {
+        0 => None,
+        _ => {
+            let mut headers_payload = vec![0; headers_length as usize];
+            reader.read_exact(&mut headers_payload).await?;
+            Some(HashMap::from_bytes(&headers_payload)?)
+        }
+    };
+
+    let payload_length = reader.read_u32_le().await? as u64;
+    if remaining_size < MESSAGE_PREFIX_SIZE + headers_length + 4 + payload_length {
+        return Err(Error::CannotReadMessagePayload);
+    }
+
+    let mut payload = vec![0; payload_length as usize];
+    reader.read_exact(&mut payload).await?;
+    Ok(Message::create(
+        offset,
+        state,
+        timestamp,
+        id,
+        Bytes::from(payload),
+        checksum,
+        headers,
+    ))
+}
+
+// Checksum is always calculated for the original, uncompressed payload.
+fn is_checksum_valid(message: &Message) -> bool {
+    let calculated_checksum = match compression::get_compression_algorithm(message) {
+        Ok(Some(algorithm)) => match algorithm.decompress(&message.payload) {
+            Ok(payload) => checksum::calculate(&payload),
+            Err(_) => return false,
+        },
+        Ok(None) => checksum::calculate(&message.payload),
+        Err(_) => return false,
+    };
+    calculated_checksum == message.checksum
+}
+
+async fn read_file(path: &str) -> Result<Vec<u8>, Error> {
+    if !Path::new(path).exists() {
+        return Ok(Vec::new());
+    }
+
+    Ok(tokio::fs::read(path).await?)
+}
+
+async fn overwrite(path: &str, bytes: &[u8]) -> Result<(), Error> {
+    let mut file = File::create(path).await?;
+    file.write_all(bytes).await?;
+    file.sync_all().await?;
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use crate::streaming::utils::compression::COMPRESSION_ALGORITHM_HEADER;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::Error;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::MessageState;
+use memmap2::{Mmap, MmapOptions};
+use std::collections::HashMap;
+use std::fs::File;
+
+// offset (8) + state (1) + timestamp (8) + id (16) + checksum (4) + headers length (4)
+const MESSAGE_HEADERS_LENGTH_POSITION: usize = 37;
+const MESSAGE_PREFIX_SIZE: usize = MESSAGE_HEADERS_LENGTH_POSITION + 4;
+
+/// The range of the persisted messages memory-mapped from the segment log file.
+/// The on-disk format of the messages is the same as the wire format,
+/// thus the mapped bytes can be sent to the client as they are, without loading and re-encoding the messages.
+#[derive(Debug)]
+pub struct MappedMessages {
+    mmap: Mmap,
+    start_position: usize,
+    end_position: usize,
+    pub count: u32,
+    pub last_offset: u64,
+}
+
+impl MappedMessages {
+    /// Maps the messages scanned from the given position, starting at the start offset, up to the given count and the end offset.
+    /// Returns `None` if any of the messages can't be sent as stored, i.e. it's not available or compressed,
+    /// in which case the messages must be loaded and processed as usual.
+    pub fn map(
+        file: &File,
+        start_position: u64,
+        start_offset: u64,
+        end_offset: u64,
+        count: u32,
+    ) -> Result<Option<Self>, Error> {
+        let file_size = file.metadata()?.len();
+        if count == 0 || start_position >= file_size {
+            return Ok(None);
+        }
+
+        // The mapping is created lazily by the OS, so only the pages that are actually sent will be read.
+        let mmap = unsafe {
+            MmapOptions::new()
+                .offset(start_position)
+                .len((file_size - start_position) as usize)
+                .map(file)?
+        };
+
+        let compression_header = HeaderKey::new(COMPRESSION_ALGORITHM_HEADER)?;
+        let mut position = 0;
+        let mut first_position = 0;
+        let mut mapped_count = 0;
+        let mut last_offset = 0;
+        while mapped_count < count && position + MESSAGE_PREFIX_SIZE <= mmap.len() {
+            let offset = u64::from_le_bytes(mmap[position..position + 8].try_into()?);
+            // Compacted segments might have gaps in the offsets, so the range is bounded by the end offset as well.
+            if offset > end_offset {
+                break;
+            }
+
+            let headers_position = position + MESSAGE_PREFIX_SIZE;
+            let headers_length = u32::from_le_bytes(
+                mmap[position + MESSAGE_HEADERS_LENGTH_POSITION..headers_position].try_into()?,
+            ) as usize;
+            let payload_length_position = headers_position + headers_length;
+            if payload_length_position + 4 > mmap.len() {
+                return Ok(None);
+            }
+
+            let payload_length = u32::from_le_bytes(
+                mmap[payload_length_position..payload_length_position + 4].try_into()?,
+            ) as usize;
+            let next_position = payload_length_position + 4 + payload_length;
+            if next_position > mmap.len() {
+                return Ok(None);
+            }
+
+            // The indexes might be sparse, so the messages preceding the start offset are skipped.
+            if offset < start_offset {
+                position = next_position;
+                first_position = next_position;
+                continue;
+            }
+
+            if MessageState::from_code(mmap[position + 8])? != MessageState::Available {
+                return Ok(None);
+            }
+
+            if headers_length > 0 {
+                let headers = HashMap::<HeaderKey, HeaderValue>::from_bytes(
+                    &mmap[headers_position..payload_length_position],
+                )?;
+                if headers.contains_key(&compression_header) {
+                    return Ok(None);
+                }
+            }
+
+            position = next_position;
+            mapped_count += 1;
+            last_offset = offset;
+        }
+
+        if mapped_count == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            mmap,
+            start_position: first_position,
+            end_position: position,
+            count: mapped_count,
+            last_offset,
+        }))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.mmap[self.start_position..self.end_position]
+    }
+}
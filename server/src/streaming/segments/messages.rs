@@ -1,6 +1,8 @@
-use crate::streaming::segments::index::{Index, IndexRange};
+use crate::streaming::segments::index::{self, Index, IndexRange};
+use crate::streaming::segments::mapped::MappedMessages;
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::segments::time_index::TimeIndex;
+use crate::streaming::storage::SegmentStorage;
 use iggy::error::Error;
 use iggy::models::messages::Message;
 use std::sync::Arc;
@@ -10,13 +12,17 @@ const EMPTY_MESSAGES: Vec<Arc<Message>> = vec![];
 
 impl Segment {
     pub fn get_messages_count(&self) -> u64 {
-        if self.current_size_bytes == 0 {
-            return 0;
-        }
+        self.messages_count
+    }
 
-        self.current_offset - self.start_offset + 1
+    /// Returns true if the compaction removed some of the messages, leaving the gaps in the offsets.
+    pub fn is_compacted(&self) -> bool {
+        self.current_size_bytes > 0
+            && self.messages_count < self.current_offset - self.start_offset + 1
     }
 
+    /// Returns up to the given count of the messages starting from the given offset.
+    /// The offsets removed by the compaction are skipped, so the range is scanned further until enough messages are found.
     pub async fn get_messages(
         &self,
         mut offset: u64,
@@ -30,11 +36,37 @@ impl Segment {
             offset = self.start_offset;
         }
 
-        let mut end_offset = offset + (count - 1) as u64;
-        if end_offset > self.current_offset {
-            end_offset = self.current_offset;
+        let mut messages = Vec::new();
+        let mut scanned_count = count as u64;
+        while offset <= self.current_offset {
+            let end_offset = u64::min(
+                offset.saturating_add(scanned_count - 1),
+                self.current_offset,
+            );
+            messages.extend(self.get_messages_by_range(offset, end_offset).await?);
+            if messages.len() >= count as usize {
+                messages.truncate(count as usize);
+                break;
+            }
+
+            // The scanned range is doubled, so the large gaps are skipped without reading the log many times.
+            offset = end_offset + 1;
+            scanned_count = scanned_count.saturating_mul(2);
         }
 
+        Ok(messages)
+    }
+
+    pub async fn get_all_messages(&self) -> Result<Vec<Arc<Message>>, Error> {
+        self.get_messages_by_range(self.start_offset, self.current_offset)
+            .await
+    }
+
+    async fn get_messages_by_range(
+        &self,
+        offset: u64,
+        end_offset: u64,
+    ) -> Result<Vec<Arc<Message>>, Error> {
         // In case that the partition messages buffer is disabled, we need to check the unsaved messages buffer
         if self.unsaved_messages.is_none() {
             return self.load_messages_from_disk(offset, end_offset).await;
@@ -62,11 +94,6 @@ impl Segment {
         Ok(messages)
     }
 
-    pub async fn get_all_messages(&self) -> Result<Vec<Arc<Message>>, Error> {
-        self.get_messages(self.start_offset, self.get_messages_count() as u32)
-            .await
-    }
-
     pub async fn get_newest_messages_by_size(
         &self,
         size_bytes: u64,
@@ -111,38 +138,7 @@ impl Segment {
             return Ok(EMPTY_MESSAGES);
         }
 
-        if let Some(indexes) = &self.indexes {
-            let relative_start_offset = start_offset - self.start_offset;
-            let relative_end_offset = end_offset - self.start_offset;
-            let start_index = indexes.get(relative_start_offset as usize);
-            let end_index = indexes.get(1 + relative_end_offset as usize);
-            if let Some(start_index) = start_index {
-                let start_position = start_index.position;
-                let end_position = match end_index {
-                    Some(index) => index.position,
-                    None => self.current_size_bytes,
-                };
-
-                let index_range = IndexRange {
-                    start: Index {
-                        relative_offset: relative_start_offset as u32,
-                        position: start_position,
-                    },
-                    end: Index {
-                        relative_offset: relative_end_offset as u32,
-                        position: end_position,
-                    },
-                };
-
-                return self.load_messages_from_segment_file(&index_range).await;
-            }
-        }
-
-        let index_range = self
-            .storage
-            .segment
-            .load_index_range(self, self.start_offset, start_offset, end_offset)
-            .await?;
+        let index_range = self.get_index_range(start_offset, end_offset).await?;
         if index_range.is_none() {
             trace!(
                 "Cannot load messages from disk, index range not found: {} - {}.",
@@ -157,6 +153,76 @@ impl Segment {
             .await
     }
 
+    /// Maps the persisted messages from the segment log file, so they can be sent without loading them into memory.
+    /// Returns `None` if the range includes the unsaved messages or any of the messages can't be sent as stored.
+    pub async fn get_mapped_messages(
+        &self,
+        mut start_offset: u64,
+        count: u32,
+    ) -> Result<Option<MappedMessages>, Error> {
+        if count == 0 {
+            return Ok(None);
+        }
+
+        if start_offset < self.start_offset {
+            start_offset = self.start_offset;
+        }
+
+        let mut end_offset = start_offset + (count - 1) as u64;
+        if end_offset > self.current_offset {
+            end_offset = self.current_offset;
+        }
+
+        if start_offset > end_offset {
+            return Ok(None);
+        }
+
+        if let Some(unsaved_messages) = &self.unsaved_messages {
+            if unsaved_messages
+                .first()
+                .is_some_and(|message| message.offset <= end_offset)
+            {
+                return Ok(None);
+            }
+        }
+
+        let Some(index_range) = self.get_index_range(start_offset, end_offset).await? else {
+            return Ok(None);
+        };
+
+        self.storage.segment.map_messages(self, &index_range).await
+    }
+
+    async fn get_index_range(
+        &self,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Result<Option<IndexRange>, Error> {
+        if let Some(indexes) = &self.indexes {
+            let relative_start_offset = (start_offset - self.start_offset) as u32;
+            let relative_end_offset = (end_offset - self.start_offset) as u32;
+            // The messages are scanned from the preceding index, as the indexes might be sparse.
+            let start_position = index::find_preceding_index(indexes, relative_start_offset)
+                .map(|index| index.position)
+                .unwrap_or(0);
+            return Ok(Some(IndexRange {
+                start: Index {
+                    relative_offset: relative_start_offset,
+                    position: start_position,
+                },
+                end: Index {
+                    relative_offset: relative_end_offset,
+                    position: self.current_size_bytes,
+                },
+            }));
+        }
+
+        self.storage
+            .segment
+            .load_index_range(self, self.start_offset, start_offset, end_offset)
+            .await
+    }
+
     async fn load_messages_from_segment_file(
         &self,
         index_range: &IndexRange,
@@ -186,73 +252,55 @@ impl Segment {
         let unsaved_messages = self.unsaved_messages.get_or_insert_with(Vec::new);
         unsaved_messages.reserve(len);
 
-        if let Some(indexes) = &mut self.indexes {
-            indexes.reserve(len);
-        }
-
-        if let Some(time_indexes) = &mut self.time_indexes {
-            time_indexes.reserve(len);
-        }
-
-        // Not the prettiest code. It's done this way to avoid repeatably
-        // checking if indexes and time_indexes are Some or None.
-        if self.indexes.is_some() && self.time_indexes.is_some() {
-            for message in messages {
-                let relative_offset = (message.offset - self.start_offset) as u32;
-
-                self.indexes.as_mut().unwrap().push(Index {
-                    relative_offset,
-                    position: self.current_size_bytes,
-                });
-
-                self.time_indexes.as_mut().unwrap().push(TimeIndex {
-                    relative_offset,
-                    timestamp: message.timestamp,
-                });
-
-                self.current_size_bytes += message.get_size_bytes();
-                self.current_offset = message.offset;
-                unsaved_messages.push(message.clone());
+        for message in messages {
+            let relative_offset = (message.offset - self.start_offset) as u32;
+            let size_bytes = message.get_size_bytes();
+            // Only the messages matching the configured interval are indexed, so the indexes might be sparse.
+            if index::is_indexed(
+                &self.config.segment,
+                relative_offset,
+                self.current_size_bytes,
+                size_bytes,
+            ) {
+                if let Some(indexes) = &mut self.indexes {
+                    indexes.push(Index {
+                        relative_offset,
+                        position: self.current_size_bytes,
+                    });
+                }
+
+                if let Some(time_indexes) = &mut self.time_indexes {
+                    time_indexes.push(TimeIndex {
+                        relative_offset,
+                        timestamp: message.timestamp,
+                    });
+                }
             }
-        } else if self.indexes.is_some() {
-            for message in messages {
-                let relative_offset = (message.offset - self.start_offset) as u32;
-
-                self.indexes.as_mut().unwrap().push(Index {
-                    relative_offset,
-                    position: self.current_size_bytes,
-                });
 
-                self.current_size_bytes += message.get_size_bytes();
-                self.current_offset = message.offset;
-                unsaved_messages.push(message.clone());
-            }
-        } else if self.time_indexes.is_some() {
-            for message in messages {
-                let relative_offset = (message.offset - self.start_offset) as u32;
-
-                self.time_indexes.as_mut().unwrap().push(TimeIndex {
-                    relative_offset,
-                    timestamp: message.timestamp,
-                });
-
-                self.current_size_bytes += message.get_size_bytes();
-                self.current_offset = message.offset;
-                unsaved_messages.push(message.clone());
-            }
-        } else {
-            for message in messages {
-                self.current_size_bytes += message.get_size_bytes();
-                self.current_offset = message.offset;
-                unsaved_messages.push(message.clone());
-            }
+            self.current_size_bytes += size_bytes;
+            self.messages_count += 1;
+            self.current_offset = message.offset;
+            self.last_timestamp = Some(message.timestamp);
+            unsaved_messages.push(message.clone());
         }
 
         Ok(())
     }
 
     pub async fn persist_messages(&mut self) -> Result<(), Error> {
-        let storage = self.storage.segment.clone();
+        self.persist_messages_using(self.storage.segment.clone())
+            .await
+    }
+
+    pub async fn persist_messages_with_sync(&mut self) -> Result<(), Error> {
+        self.persist_messages_using(self.storage.synced_segment.clone())
+            .await
+    }
+
+    async fn persist_messages_using(
+        &mut self,
+        storage: Arc<dyn SegmentStorage>,
+    ) -> Result<(), Error> {
         if self.unsaved_messages.is_none() {
             return Ok(());
         }
@@ -274,7 +322,9 @@ impl Segment {
         storage
             .save_index(self, current_position, unsaved_messages)
             .await?;
-        storage.save_time_index(self, unsaved_messages).await?;
+        storage
+            .save_time_index(self, current_position, unsaved_messages)
+            .await?;
 
         trace!(
             "Saved {} messages on disk in segment with start offset: {} for partition with ID: {}, total bytes written: {}.",
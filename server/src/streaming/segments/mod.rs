@@ -1,6 +1,9 @@
 pub mod index;
+pub mod integrity;
+pub mod mapped;
 pub mod messages;
 pub mod persistence;
 pub mod segment;
 pub mod storage;
+pub mod tiering;
 pub mod time_index;
@@ -5,10 +5,12 @@ use crate::streaming::storage::SystemStorage;
 use iggy::models::messages::Message;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::Arc;
+use tokio::sync::Mutex;
 
 pub const LOG_EXTENSION: &str = "log";
 pub const INDEX_EXTENSION: &str = "index";
 pub const TIME_INDEX_EXTENSION: &str = "timeindex";
+pub const REMOTE_EXTENSION: &str = "remote";
 pub const MAX_SIZE_BYTES: u32 = 1000 * 1000 * 1000;
 
 #[derive(Debug)]
@@ -22,14 +24,23 @@ pub struct Segment {
     pub index_path: String,
     pub log_path: String,
     pub time_index_path: String,
+    pub remote_path: String,
     pub current_size_bytes: u32,
+    // The compacted segments have gaps in the offsets, so the messages are counted rather than derived from the offsets.
+    pub messages_count: u64,
     pub is_closed: bool,
+    // The indexes might be sparse, so the timestamp of the last message is tracked separately.
+    pub last_timestamp: Option<u64>,
+    // The segment files were uploaded to the tiered storage, the local log file might have been already freed.
+    pub is_remote: bool,
     pub(crate) message_expiry: Option<u32>,
     pub(crate) unsaved_messages: Option<Vec<Arc<Message>>>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) indexes: Option<Vec<Index>>,
     pub(crate) time_indexes: Option<Vec<TimeIndex>>,
     pub(crate) storage: Arc<SystemStorage>,
+    // Held while fetching the freed log file from the tiered storage, so it's downloaded only once by the concurrent readers.
+    pub(crate) remote_log_lock: Arc<Mutex<()>>,
 }
 
 impl Segment {
@@ -54,7 +65,10 @@ impl Segment {
             log_path: Self::get_log_path(&path),
             index_path: Self::get_index_path(&path),
             time_index_path: Self::get_time_index_path(&path),
+            remote_path: Self::get_remote_path(&path),
             current_size_bytes: 0,
+            messages_count: 0,
+            last_timestamp: None,
             message_expiry,
             indexes: match config.segment.cache_indexes {
                 true => Some(Vec::new()),
@@ -66,8 +80,10 @@ impl Segment {
             },
             unsaved_messages: None,
             is_closed: false,
+            is_remote: false,
             config,
             storage,
+            remote_log_lock: Arc::new(Mutex::new(())),
         }
     }
 
@@ -84,19 +100,26 @@ impl Segment {
             return false;
         }
 
-        let last_messages = self.get_messages(self.end_offset, 1).await;
-        if last_messages.is_err() {
-            return false;
-        }
+        // The tracked timestamp avoids fetching the offloaded segment from the tiered storage.
+        let last_timestamp = match self.last_timestamp {
+            Some(last_timestamp) => last_timestamp,
+            None => {
+                let last_messages = self.get_messages(self.end_offset, 1).await;
+                if last_messages.is_err() {
+                    return false;
+                }
 
-        let last_messages = last_messages.unwrap();
-        if last_messages.is_empty() {
-            return false;
-        }
+                let last_messages = last_messages.unwrap();
+                if last_messages.is_empty() {
+                    return false;
+                }
+
+                last_messages[0].timestamp
+            }
+        };
 
-        let last_message = last_messages[0].as_ref();
         let message_expiry = (self.message_expiry.unwrap() * 1000) as u64;
-        (last_message.timestamp + message_expiry) <= now
+        (last_timestamp + message_expiry) <= now
     }
 
     fn get_log_path(path: &str) -> String {
@@ -110,6 +133,10 @@ impl Segment {
     fn get_time_index_path(path: &str) -> String {
         format!("{}.{}", path, TIME_INDEX_EXTENSION)
     }
+
+    fn get_remote_path(path: &str) -> String {
+        format!("{}.{}", path, REMOTE_EXTENSION)
+    }
 }
 
 #[cfg(test)]
@@ -130,6 +157,7 @@ mod tests {
         let log_path = Segment::get_log_path(&path);
         let index_path = Segment::get_index_path(&path);
         let time_index_path = Segment::get_time_index_path(&path);
+        let remote_path = Segment::get_remote_path(&path);
         let message_expiry = Some(10);
 
         let segment = Segment::create(
@@ -152,11 +180,13 @@ mod tests {
         assert_eq!(segment.log_path, log_path);
         assert_eq!(segment.index_path, index_path);
         assert_eq!(segment.time_index_path, time_index_path);
+        assert_eq!(segment.remote_path, remote_path);
         assert_eq!(segment.message_expiry, message_expiry);
         assert!(segment.unsaved_messages.is_none());
         assert!(segment.indexes.is_some());
         assert!(segment.time_indexes.is_some());
         assert!(!segment.is_closed);
+        assert!(!segment.is_remote);
         assert!(!segment.is_full().await);
     }
 
@@ -1,28 +1,36 @@
+use crate::streaming::partitions::compaction;
 use crate::streaming::persistence::persister::Persister;
+use crate::streaming::segments::index;
 use crate::streaming::segments::index::{Index, IndexRange};
+use crate::streaming::segments::mapped::MappedMessages;
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::segments::time_index::TimeIndex;
 use crate::streaming::storage::{SegmentStorage, Storage};
-use crate::streaming::utils::file;
+use crate::streaming::utils::{compression, file};
 use anyhow::Context;
 use async_trait::async_trait;
 use bytes::{BufMut, Bytes};
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::error::Error;
+use iggy::messages::send_messages::MESSAGE_KEY_HEADER;
+use iggy::models::header::HeaderKey;
 use iggy::models::messages::{Message, MessageState};
 use iggy::utils::checksum;
 use std::collections::HashMap;
 use std::io::SeekFrom;
 use std::path::Path;
 use std::sync::Arc;
+use tokio::fs::File;
 use tokio::io::{AsyncReadExt, AsyncSeekExt, BufReader};
 use tracing::log::{trace, warn};
 use tracing::{error, info};
 
 const EMPTY_INDEXES: Vec<Index> = vec![];
 const EMPTY_TIME_INDEXES: Vec<TimeIndex> = vec![];
-const INDEX_SIZE: u32 = 4;
+const INDEX_SIZE: u32 = 8;
+const TIME_INDEX_SIZE: u32 = 12;
 const BUF_READER_CAPACITY_BYTES: usize = 512 * 1000;
+const COMPACTED_FILE_SUFFIX: &str = ".compacted";
 
 #[derive(Debug)]
 pub struct FileSegmentStorage {
@@ -46,14 +54,17 @@ impl Storage<Segment> for FileSegmentStorage {
             "Loading segment from disk for start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {} ...",
             segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
         );
-        let log_file = file::open(&segment.log_path).await?;
-        let file_size = log_file.metadata().await.unwrap().len() as u32;
-        segment.current_size_bytes = file_size;
-
-        info!(
-            "Segment log file for start offset {}, current offset: {}, and partition with ID: {} for topic with ID: {} and stream with ID: {} has {} bytes of size.",
-            segment.start_offset, segment.current_offset, segment.partition_id, segment.topic_id, segment.stream_id, segment.current_size_bytes
-        );
+        self.recover_compacted_messages(segment).await?;
+        segment.is_remote = Path::new(&segment.remote_path).exists();
+        let is_offloaded = segment.is_remote && !Path::new(&segment.log_path).exists();
+        if is_offloaded {
+            self.load_remote_marker(segment).await?;
+        } else {
+            let log_file = file::open(&segment.log_path).await?;
+            let file_size = log_file.metadata().await.unwrap().len() as u32;
+            segment.current_size_bytes = file_size;
+            segment.messages_count = count_messages(log_file).await?;
+        }
 
         if segment.config.segment.cache_indexes {
             segment.indexes = Some(segment.storage.segment.load_all_indexes(segment).await?);
@@ -68,13 +79,7 @@ impl Storage<Segment> for FileSegmentStorage {
         }
 
         if segment.config.segment.cache_time_indexes {
-            let time_indexes = self.load_all_time_indexes(segment).await?;
-            if !time_indexes.is_empty() {
-                let last_index = time_indexes.last().unwrap();
-                segment.current_offset = segment.start_offset + last_index.relative_offset as u64;
-                segment.time_indexes = Some(time_indexes);
-            }
-
+            segment.time_indexes = Some(self.load_all_time_indexes(segment).await?);
             info!(
                 "Loaded {} time indexes for segment with start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {}.",
                 segment.time_indexes.as_ref().unwrap().len(),
@@ -83,21 +88,23 @@ impl Storage<Segment> for FileSegmentStorage {
                 segment.topic_id,
                 segment.stream_id
             );
-        } else {
-            let last_time_index = self.load_last_time_index(segment).await?;
-            if let Some(last_index) = last_time_index {
-                segment.current_offset = segment.start_offset + last_index.relative_offset as u64;
-                info!(
-                "Loaded last time index for segment with start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {}.",
-                segment.start_offset,
-                segment.partition_id,
-                segment.topic_id,
-                segment.stream_id
-            );
+        }
+
+        // The indexes might be sparse, so the last message is found by scanning the log from the last index.
+        if !is_offloaded {
+            if let Some(last_message) = self.load_last_message(segment).await? {
+                segment.current_offset = last_message.offset;
+                segment.last_timestamp = Some(last_message.timestamp);
             }
         }
 
-        if segment.is_full().await {
+        info!(
+            "Segment log file for start offset {}, current offset: {}, and partition with ID: {} for topic with ID: {} and stream with ID: {} has {} bytes of size.",
+            segment.start_offset, segment.current_offset, segment.partition_id, segment.topic_id, segment.stream_id, segment.current_size_bytes
+        );
+
+        // Only the closed segments are offloaded, checking whether the remote one is full would fetch its messages.
+        if segment.is_remote || segment.is_full().await {
             segment.is_closed = true;
         }
 
@@ -152,9 +159,31 @@ impl Storage<Segment> for FileSegmentStorage {
             "Deleting segment with start offset: {} for partition with ID: {} for stream with ID: {} and topic with ID: {}...",
             segment.start_offset, segment.partition_id, segment.stream_id, segment.topic_id,
         );
-        self.persister.delete(&segment.log_path).await?;
+        if segment.is_remote {
+            match &segment.storage.tiered {
+                Some(tiered_storage) => {
+                    for path in [
+                        &segment.log_path,
+                        &segment.index_path,
+                        &segment.time_index_path,
+                    ] {
+                        tiered_storage.delete(&get_remote_key(segment, path)).await?;
+                    }
+                }
+                None => warn!(
+                    "Tiered storage is disabled, the remote files of segment with start offset: {} for partition with ID: {} will not be deleted.",
+                    segment.start_offset, segment.partition_id
+                ),
+            }
+            self.persister.delete(&segment.remote_path).await?;
+        }
+
+        if !segment.is_remote || Path::new(&segment.log_path).exists() {
+            self.persister.delete(&segment.log_path).await?;
+        }
         self.persister.delete(&segment.index_path).await?;
         self.persister.delete(&segment.time_index_path).await?;
+        self.delete_compacted_messages(segment).await?;
         info!(
             "Deleted segment with start offset: {} for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
             segment.start_offset, segment.partition_id, segment.stream_id, segment.topic_id,
@@ -163,6 +192,154 @@ impl Storage<Segment> for FileSegmentStorage {
     }
 }
 
+impl FileSegmentStorage {
+    // The marker holds the log size, the current offset, the last timestamp and the messages count of the offloaded segment.
+    async fn load_remote_marker(&self, segment: &mut Segment) -> Result<(), Error> {
+        let mut file = file::open(&segment.remote_path).await?;
+        segment.current_size_bytes = file.read_u32_le().await?;
+        segment.current_offset = file.read_u64_le().await?;
+        segment.last_timestamp = match file.read_u64_le().await? {
+            0 => None,
+            timestamp => Some(timestamp),
+        };
+        // The markers saved without the messages count are derived from the offsets, as the segment wasn't compacted.
+        segment.messages_count = match file.read_u64_le().await {
+            Ok(messages_count) => messages_count,
+            Err(_) if segment.current_size_bytes == 0 => 0,
+            Err(_) => segment.current_offset - segment.start_offset + 1,
+        };
+        Ok(())
+    }
+
+    async fn append_compacted_messages(
+        &self,
+        segment: &Segment,
+        log_bytes: &mut Vec<u8>,
+        index_bytes: &mut Vec<u8>,
+        time_index_bytes: &mut Vec<u8>,
+    ) -> Result<(), Error> {
+        let compacted_log_path = format!("{}{}", segment.log_path, COMPACTED_FILE_SUFFIX);
+        if let Err(err) = self
+            .persister
+            .append(&compacted_log_path, log_bytes)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to save compacted messages to segment: {}",
+                    segment.log_path
+                )
+            })
+        {
+            return Err(Error::CannotSaveMessagesToSegment(err));
+        }
+
+        let compacted_index_path = format!("{}{}", segment.index_path, COMPACTED_FILE_SUFFIX);
+        if let Err(err) = self
+            .persister
+            .append(&compacted_index_path, index_bytes)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to save compacted index to segment: {}",
+                    segment.index_path
+                )
+            })
+        {
+            return Err(Error::CannotSaveIndexToSegment(err));
+        }
+
+        let compacted_time_index_path =
+            format!("{}{}", segment.time_index_path, COMPACTED_FILE_SUFFIX);
+        if let Err(err) = self
+            .persister
+            .append(&compacted_time_index_path, time_index_bytes)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to save compacted TimeIndex to segment: {}",
+                    segment.time_index_path
+                )
+            })
+        {
+            return Err(Error::CannotSaveTimeIndexToSegment(err));
+        }
+
+        log_bytes.clear();
+        index_bytes.clear();
+        time_index_bytes.clear();
+        Ok(())
+    }
+
+    // The log is renamed first, so the leftover compacted indexes without the compacted log belong to the current log.
+    async fn recover_compacted_messages(&self, segment: &Segment) -> Result<(), Error> {
+        let compacted_log_path = format!("{}{}", segment.log_path, COMPACTED_FILE_SUFFIX);
+        if Path::new(&compacted_log_path).exists() {
+            warn!(
+                "Discarding the unfinished compaction of segment with start offset: {} for partition with ID: {}.",
+                segment.start_offset, segment.partition_id
+            );
+            return self.delete_compacted_messages(segment).await;
+        }
+
+        for path in [&segment.index_path, &segment.time_index_path] {
+            let compacted_path = format!("{}{}", path, COMPACTED_FILE_SUFFIX);
+            if Path::new(&compacted_path).exists() {
+                warn!(
+                    "Completing the compaction of segment with start offset: {} for partition with ID: {}.",
+                    segment.start_offset, segment.partition_id
+                );
+                tokio::fs::rename(&compacted_path, path).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn load_last_message(&self, segment: &Segment) -> Result<Option<Message>, Error> {
+        let last_index = match &segment.indexes {
+            Some(indexes) => indexes.last().map(|index| Index {
+                relative_offset: index.relative_offset,
+                position: index.position,
+            }),
+            None => self.load_last_index(segment).await?,
+        };
+        let index_range = IndexRange {
+            start: last_index.unwrap_or(Index {
+                relative_offset: 0,
+                position: 0,
+            }),
+            end: IndexRange::max_range().end,
+        };
+
+        let mut last_message = None;
+        load_messages_by_range(segment, &index_range, |message: Message| {
+            last_message = Some(message);
+            Ok(())
+        })
+        .await?;
+        Ok(last_message)
+    }
+
+    async fn load_last_index(&self, segment: &Segment) -> Result<Option<Index>, Error> {
+        let mut file = file::open(&segment.index_path).await?;
+        let file_size = file.metadata().await?.len() as u32;
+        if file_size < INDEX_SIZE {
+            trace!("Index file is empty.");
+            return Ok(None);
+        }
+
+        file.seek(SeekFrom::Start(
+            (file_size - file_size % INDEX_SIZE - INDEX_SIZE) as u64,
+        ))
+        .await?;
+        let relative_offset = file.read_u32_le().await?;
+        let position = file.read_u32_le().await?;
+        Ok(Some(Index {
+            relative_offset,
+            position,
+        }))
+    }
+}
+
 #[async_trait]
 impl SegmentStorage for FileSegmentStorage {
     async fn load_messages(
@@ -182,6 +359,37 @@ impl SegmentStorage for FileSegmentStorage {
         Ok(messages)
     }
 
+    async fn map_messages(
+        &self,
+        segment: &Segment,
+        index_range: &IndexRange,
+    ) -> Result<Option<MappedMessages>, Error> {
+        if index_range.end.position == 0 {
+            return Ok(None);
+        }
+
+        segment.ensure_local_log()?;
+        let file = file::open(&segment.log_path).await?.into_std().await;
+        let count = 1 + index_range.end.relative_offset - index_range.start.relative_offset;
+        let start_offset = segment.start_offset + index_range.start.relative_offset as u64;
+        let end_offset = segment.start_offset + index_range.end.relative_offset as u64;
+        let mapped_messages = MappedMessages::map(
+            &file,
+            index_range.start.position as u64,
+            start_offset,
+            end_offset,
+            count,
+        )?;
+        if let Some(mapped_messages) = &mapped_messages {
+            trace!(
+                "Mapped {} messages ({} bytes) from disk.",
+                mapped_messages.count,
+                mapped_messages.as_bytes().len()
+            );
+        }
+        Ok(mapped_messages)
+    }
+
     async fn load_newest_messages_by_size(
         &self,
         segment: &Segment,
@@ -230,6 +438,130 @@ impl SegmentStorage for FileSegmentStorage {
         Ok(messages_size)
     }
 
+    async fn save_compacted_messages(
+        &self,
+        segment: &Segment,
+        retain: &(dyn for<'a> Fn(&'a Message) -> bool + Sync),
+    ) -> Result<Vec<u64>, Error> {
+        let compacted_log_path = format!("{}{}", segment.log_path, COMPACTED_FILE_SUFFIX);
+        let compacted_index_path = format!("{}{}", segment.index_path, COMPACTED_FILE_SUFFIX);
+        let compacted_time_index_path =
+            format!("{}{}", segment.time_index_path, COMPACTED_FILE_SUFFIX);
+        for path in [
+            &compacted_log_path,
+            &compacted_index_path,
+            &compacted_time_index_path,
+        ] {
+            self.persister.overwrite(path, &[]).await?;
+        }
+
+        // The messages are streamed from the log, so only a single buffer of the compacted segment is kept in memory.
+        let file = file::open(&segment.log_path).await?;
+        let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
+        let mut removed_offsets = Vec::new();
+        let mut position = 0;
+        let mut log_bytes = Vec::with_capacity(BUF_READER_CAPACITY_BYTES);
+        let mut index_bytes = Vec::new();
+        let mut time_index_bytes = Vec::new();
+        while let Some(message) = read_message(&mut reader).await? {
+            if !retain(&message) {
+                removed_offsets.push(message.offset);
+                continue;
+            }
+
+            let relative_offset = (message.offset - segment.start_offset) as u32;
+            if index::is_indexed(
+                &segment.config.segment,
+                relative_offset,
+                position,
+                message.get_size_bytes(),
+            ) {
+                index_bytes.put_u32_le(relative_offset);
+                index_bytes.put_u32_le(position);
+                time_index_bytes.put_u32_le(relative_offset);
+                time_index_bytes.put_u64_le(message.timestamp);
+            }
+            position += message.get_size_bytes();
+            message.extend(&mut log_bytes);
+            if log_bytes.len() >= BUF_READER_CAPACITY_BYTES {
+                self.append_compacted_messages(
+                    segment,
+                    &mut log_bytes,
+                    &mut index_bytes,
+                    &mut time_index_bytes,
+                )
+                .await?;
+            }
+        }
+
+        if removed_offsets.is_empty() {
+            self.delete_compacted_messages(segment).await?;
+            return Ok(removed_offsets);
+        }
+
+        self.append_compacted_messages(
+            segment,
+            &mut log_bytes,
+            &mut index_bytes,
+            &mut time_index_bytes,
+        )
+        .await?;
+        Ok(removed_offsets)
+    }
+
+    async fn replace_compacted_messages(&self, segment: &Segment) -> Result<u32, Error> {
+        let paths = [
+            &segment.log_path,
+            &segment.index_path,
+            &segment.time_index_path,
+        ];
+        // The compacted files and their directory entries are synced first, so the crash can't leave the truncated segment.
+        for path in paths {
+            file::open(&format!("{}{}", path, COMPACTED_FILE_SUFFIX))
+                .await?
+                .sync_all()
+                .await?;
+        }
+        sync_directory(&segment.log_path).await?;
+
+        // The log is renamed first, as the startup recovery completes the renaming of the indexes only for the compacted log.
+        for path in paths {
+            tokio::fs::rename(format!("{}{}", path, COMPACTED_FILE_SUFFIX), path).await?;
+        }
+        sync_directory(&segment.log_path).await?;
+
+        let log_file = file::open(&segment.log_path).await?;
+        Ok(log_file.metadata().await?.len() as u32)
+    }
+
+    async fn delete_compacted_messages(&self, segment: &Segment) -> Result<(), Error> {
+        for path in [
+            &segment.log_path,
+            &segment.index_path,
+            &segment.time_index_path,
+        ] {
+            let compacted_path = format!("{}{}", path, COMPACTED_FILE_SUFFIX);
+            if Path::new(&compacted_path).exists() {
+                self.persister.delete(&compacted_path).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn load_message_keys(&self, segment: &Segment) -> Result<HashMap<Vec<u8>, u64>, Error> {
+        let header_key = HeaderKey::new(MESSAGE_KEY_HEADER)?;
+        let mut latest_offsets = HashMap::new();
+        load_messages_by_range(segment, &IndexRange::max_range(), |message: Message| {
+            if let Some(key) = compaction::get_message_key(&message, &header_key) {
+                latest_offsets.insert(key.to_vec(), message.offset);
+            }
+            Ok(())
+        })
+        .await?;
+        trace!("Loaded {} message keys from disk.", latest_offsets.len());
+        Ok(latest_offsets)
+    }
+
     async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, Error> {
         let mut message_ids = Vec::new();
         load_messages_by_range(segment, &IndexRange::max_range(), |message: Message| {
@@ -243,7 +575,11 @@ impl SegmentStorage for FileSegmentStorage {
 
     async fn load_checksums(&self, segment: &Segment) -> Result<(), Error> {
         load_messages_by_range(segment, &IndexRange::max_range(), |message: Message| {
-            let calculated_checksum = checksum::calculate(&message.payload);
+            // Checksum is always calculated for the original, uncompressed payload.
+            let calculated_checksum = match compression::get_compression_algorithm(&message)? {
+                Some(algorithm) => checksum::calculate(&algorithm.decompress(&message.payload)?),
+                None => checksum::calculate(&message.payload),
+            };
             trace!(
                 "Loaded message for offset: {}, checksum: {}, expected: {}",
                 message.offset,
@@ -263,6 +599,25 @@ impl SegmentStorage for FileSegmentStorage {
         Ok(())
     }
 
+    async fn offload(&self, segment: &Segment) -> Result<(), Error> {
+        // The marker with the log size is saved once all the files are uploaded, and only then the log can be freed.
+        if !segment.is_remote {
+            self.persister
+                .overwrite(&segment.remote_path, &get_remote_marker(segment))
+                .await?;
+        }
+
+        if Path::new(&segment.log_path).exists() {
+            self.persister.delete(&segment.log_path).await?;
+        }
+
+        info!(
+            "Offloaded segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {} to the tiered storage.",
+            segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
+        );
+        Ok(())
+    }
+
     async fn load_all_indexes(&self, segment: &Segment) -> Result<Vec<Index>, Error> {
         trace!("Loading indexes from file...");
         let file = file::open(&segment.index_path).await?;
@@ -272,21 +627,23 @@ impl SegmentStorage for FileSegmentStorage {
             return Ok(EMPTY_INDEXES);
         }
 
-        let indexes_count = file_size / 4;
+        let indexes_count = file_size / INDEX_SIZE as usize;
         let mut indexes = Vec::with_capacity(indexes_count);
         let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
-        for offset in 0..indexes_count {
-            match reader.read_u32_le().await {
-                Ok(position) => {
+        for index in 0..indexes_count {
+            let relative_offset = reader.read_u32_le().await;
+            let position = reader.read_u32_le().await;
+            match (relative_offset, position) {
+                (Ok(relative_offset), Ok(position)) => {
                     indexes.push(Index {
-                        relative_offset: offset as u32,
+                        relative_offset,
                         position,
                     });
                 }
-                Err(error) => {
+                (Err(error), _) | (_, Err(error)) => {
                     error!(
-                        "Cannot read position from index file for offset: {}. Error: {}",
-                        offset, error
+                        "Cannot read index: {} from index file. Error: {}",
+                        index, error
                     );
                     break;
                 }
@@ -328,57 +685,39 @@ impl SegmentStorage for FileSegmentStorage {
             return Ok(None);
         }
 
-        let mut file = file::open(&segment.index_path).await?;
-        let file_length = file.metadata().await?.len() as u32;
-        if file_length == 0 {
-            trace!("Index file is empty.");
-            return Ok(None);
-        }
-
-        trace!("Index file length: {}.", file_length);
         if index_start_offset < segment_start_offset {
-            index_start_offset = segment_start_offset - 1;
+            index_start_offset = segment_start_offset;
         }
 
+        let mut file = file::open(&segment.index_path).await?;
+        let file_length = file.metadata().await?.len() as u32;
+        let indexes_count = file_length / INDEX_SIZE;
         let relative_start_offset = (index_start_offset - segment_start_offset) as u32;
         let relative_end_offset = (index_end_offset - segment_start_offset) as u32;
-        let start_seek_position = relative_start_offset * INDEX_SIZE;
-        let mut end_seek_position = relative_end_offset * INDEX_SIZE;
-        if end_seek_position >= file_length {
-            end_seek_position = file_length - INDEX_SIZE;
-        }
-
-        if start_seek_position >= end_seek_position {
-            trace!(
-                "Start seek position: {} is greater than or equal to end seek position: {}.",
-                start_seek_position,
-                end_seek_position
-            );
-            return Ok(None);
-        }
 
-        trace!(
-            "Seeking to index range: {}...{}, position range: {}...{}",
-            relative_start_offset,
-            relative_end_offset,
-            start_seek_position,
-            end_seek_position
-        );
-        file.seek(SeekFrom::Start(start_seek_position as u64))
-            .await?;
-        let start_position = file.read_u32_le().await?;
-        file.seek(SeekFrom::Start(end_seek_position as u64)).await?;
-        let mut end_position = file.read_u32_le().await?;
-        if end_position == 0 {
-            end_position = file_length;
+        // The indexes might be sparse, so the messages are scanned from the nearest preceding index,
+        // or from the beginning of the log if there's none.
+        let mut start_position = 0;
+        let mut low = 0;
+        let mut high = indexes_count;
+        while low < high {
+            let middle = low + (high - low) / 2;
+            file.seek(SeekFrom::Start((middle * INDEX_SIZE) as u64))
+                .await?;
+            let relative_offset = file.read_u32_le().await?;
+            if relative_offset <= relative_start_offset {
+                start_position = file.read_u32_le().await?;
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
         }
 
         trace!(
-            "Loaded index range: {}...{}, position range: {}...{}",
+            "Loaded index range: {}...{}, start position: {}",
             relative_start_offset,
             relative_end_offset,
-            start_position,
-            end_position
+            start_position
         );
 
         Ok(Some(IndexRange {
@@ -388,7 +727,7 @@ impl SegmentStorage for FileSegmentStorage {
             },
             end: Index {
                 relative_offset: relative_end_offset,
-                position: end_position,
+                position: segment.current_size_bytes,
             },
         }))
     }
@@ -399,11 +738,25 @@ impl SegmentStorage for FileSegmentStorage {
         mut current_position: u32,
         messages: &[Arc<Message>],
     ) -> Result<(), Error> {
-        let mut bytes = Vec::with_capacity(messages.len() * 4);
+        let mut bytes = Vec::with_capacity(messages.len() * INDEX_SIZE as usize);
         for message in messages {
-            trace!("Persisting index for position: {}", current_position);
-            bytes.put_u32_le(current_position);
-            current_position += message.get_size_bytes();
+            let relative_offset = (message.offset - segment.start_offset) as u32;
+            let size_bytes = message.get_size_bytes();
+            if index::is_indexed(
+                &segment.config.segment,
+                relative_offset,
+                current_position,
+                size_bytes,
+            ) {
+                trace!("Persisting index for position: {}", current_position);
+                bytes.put_u32_le(relative_offset);
+                bytes.put_u32_le(current_position);
+            }
+            current_position += size_bytes;
+        }
+
+        if bytes.is_empty() {
+            return Ok(());
         }
 
         if let Err(err) = self
@@ -427,21 +780,23 @@ impl SegmentStorage for FileSegmentStorage {
             return Ok(EMPTY_TIME_INDEXES);
         }
 
-        let indexes_count = file_size / 8;
+        let indexes_count = file_size / TIME_INDEX_SIZE as usize;
         let mut indexes = Vec::with_capacity(indexes_count);
         let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
-        for offset in 0..indexes_count {
-            match reader.read_u64_le().await {
-                Ok(timestamp) => {
+        for index in 0..indexes_count {
+            let relative_offset = reader.read_u32_le().await;
+            let timestamp = reader.read_u64_le().await;
+            match (relative_offset, timestamp) {
+                (Ok(relative_offset), Ok(timestamp)) => {
                     indexes.push(TimeIndex {
-                        relative_offset: offset as u32,
+                        relative_offset,
                         timestamp,
                     });
                 }
-                Err(error) => {
+                (Err(error), _) | (_, Err(error)) => {
                     error!(
-                        "Cannot read timestamp from time index file for offset: {}. Error: {}",
-                        offset, error
+                        "Cannot read time index: {} from time index file. Error: {}",
+                        index, error
                     );
                     break;
                 }
@@ -461,37 +816,30 @@ impl SegmentStorage for FileSegmentStorage {
         Ok(indexes)
     }
 
-    async fn load_last_time_index(&self, segment: &Segment) -> Result<Option<TimeIndex>, Error> {
-        trace!("Loading last time index from file...");
-        let mut file = file::open(&segment.time_index_path).await?;
-        let file_size = file.metadata().await?.len() as usize;
-        if file_size == 0 {
-            trace!("Time index file is empty.");
-            return Ok(None);
-        }
-
-        let indexes_count = file_size / 8;
-        let last_index_position = file_size - 8;
-        file.seek(SeekFrom::Start(last_index_position as u64))
-            .await?;
-        let timestamp = file.read_u64_le().await?;
-        let index = TimeIndex {
-            relative_offset: indexes_count as u32 - 1,
-            timestamp,
-        };
-
-        trace!("Loaded last time index from file: {:?}", index);
-        Ok(Some(index))
-    }
-
     async fn save_time_index(
         &self,
         segment: &Segment,
+        mut current_position: u32,
         messages: &[Arc<Message>],
     ) -> Result<(), Error> {
-        let mut bytes = Vec::with_capacity(messages.len() * 8);
+        let mut bytes = Vec::with_capacity(messages.len() * TIME_INDEX_SIZE as usize);
         for message in messages {
-            bytes.put_u64_le(message.timestamp);
+            let relative_offset = (message.offset - segment.start_offset) as u32;
+            let size_bytes = message.get_size_bytes();
+            if index::is_indexed(
+                &segment.config.segment,
+                relative_offset,
+                current_position,
+                size_bytes,
+            ) {
+                bytes.put_u32_le(relative_offset);
+                bytes.put_u64_le(message.timestamp);
+            }
+            current_position += size_bytes;
+        }
+
+        if bytes.is_empty() {
+            return Ok(());
         }
 
         if let Err(err) = self
@@ -512,11 +860,42 @@ impl SegmentStorage for FileSegmentStorage {
     }
 }
 
+fn get_remote_marker(segment: &Segment) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(28);
+    bytes.put_u32_le(segment.current_size_bytes);
+    bytes.put_u64_le(segment.current_offset);
+    bytes.put_u64_le(segment.last_timestamp.unwrap_or_default());
+    bytes.put_u64_le(segment.messages_count);
+    bytes
+}
+
+pub(crate) fn get_remote_key(segment: &Segment, path: &str) -> String {
+    let file_name = Path::new(path)
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy())
+        .unwrap_or_default();
+    format!(
+        "{}/{}/{}/{}",
+        segment.stream_id, segment.topic_id, segment.partition_id, file_name
+    )
+}
+
+// Syncs the directory containing the given file, so the created and renamed entries are durable.
+async fn sync_directory(path: &str) -> Result<(), Error> {
+    let Some(directory_path) = Path::new(path).parent() else {
+        return Ok(());
+    };
+
+    File::open(directory_path).await?.sync_all().await?;
+    Ok(())
+}
+
 async fn load_messages_by_range(
     segment: &Segment,
     index_range: &IndexRange,
     mut on_message: impl FnMut(Message) -> Result<(), Error>,
 ) -> Result<(), Error> {
+    segment.ensure_local_log()?;
     let file = file::open(&segment.log_path).await?;
     let file_size = file.metadata().await?.len();
     if file_size == 0 {
@@ -535,81 +914,127 @@ async fn load_messages_by_range(
     let mut read_messages = 0;
     let messages_count =
         (1 + index_range.end.relative_offset - index_range.start.relative_offset) as usize;
+    let start_offset = segment.start_offset + index_range.start.relative_offset as u64;
+    let end_offset = segment.start_offset + index_range.end.relative_offset as u64;
 
     while read_messages < messages_count {
-        let offset = reader.read_u64_le().await;
-        if offset.is_err() {
+        let Some(message) = read_message(&mut reader).await? else {
             break;
-        }
+        };
 
-        let state = reader.read_u8().await;
-        if state.is_err() {
-            return Err(Error::CannotReadMessageState);
+        // Compacted segments might have gaps in the offsets, so the range is bounded by the end offset as well.
+        if message.offset > end_offset {
+            break;
         }
 
-        let state = MessageState::from_code(state.unwrap())?;
-        let timestamp = reader.read_u64_le().await;
-        if timestamp.is_err() {
-            return Err(Error::CannotReadMessageTimestamp);
+        // The indexes might be sparse, so the messages preceding the start offset are skipped.
+        if message.offset < start_offset {
+            continue;
         }
 
-        let id = reader.read_u128_le().await;
-        if id.is_err() {
-            return Err(Error::CannotReadMessageId);
-        }
+        read_messages += 1;
+        on_message(message)?;
+    }
+    Ok(())
+}
 
-        let checksum = reader.read_u32_le().await;
-        if checksum.is_err() {
-            return Err(Error::CannotReadMessageChecksum);
-        }
+// Reads the next message from the log, returns none once the end of the log is reached.
+// Only the headers are read, the message payloads are skipped without being allocated.
+async fn count_messages(file: File) -> Result<u64, Error> {
+    let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
+    let mut messages_count = 0;
+    // The offset, state, timestamp, ID and checksum precede the headers length.
+    while reader.read_u64_le().await.is_ok() {
+        skip_bytes(&mut reader, 1 + 8 + 16 + 4).await?;
+        let headers_length = reader
+            .read_u32_le()
+            .await
+            .map_err(|_| Error::CannotReadHeadersLength)?;
+        skip_bytes(&mut reader, headers_length as u64).await?;
+        let payload_length = reader
+            .read_u32_le()
+            .await
+            .map_err(|_| Error::CannotReadMessageLength)?;
+        skip_bytes(&mut reader, payload_length as u64).await?;
+        messages_count += 1;
+    }
+    Ok(messages_count)
+}
 
-        let headers_length = reader.read_u32_le().await;
-        if headers_length.is_err() {
-            return Err(Error::CannotReadHeadersLength);
-        }
+async fn skip_bytes(reader: &mut BufReader<File>, length: u64) -> Result<(), Error> {
+    let skipped = tokio::io::copy(&mut reader.take(length), &mut tokio::io::sink()).await?;
+    if skipped < length {
+        return Err(Error::CannotReadMessagePayload);
+    }
+    Ok(())
+}
 
-        let headers_length = headers_length.unwrap();
-        let headers = match headers_length {
-            0 => None,
-            _ => {
-                let mut headers_payload = vec![0; headers_length as usize];
-                if reader.read_exact(&mut headers_payload).await.is_err() {
-                    return Err(Error::CannotReadHeadersPayload);
-                }
+async fn read_message(reader: &mut BufReader<File>) -> Result<Option<Message>, Error> {
+    let offset = reader.read_u64_le().await;
+    if offset.is_err() {
+        return Ok(None);
+    }
 
-                let headers = HashMap::from_bytes(&headers_payload)?;
-                Some(headers)
-            }
-        };
+    let offset = offset.unwrap();
+    let state = reader.read_u8().await;
+    if state.is_err() {
+        return Err(Error::CannotReadMessageState);
+    }
 
-        let payload_length = reader.read_u32_le().await;
-        if payload_length.is_err() {
-            return Err(Error::CannotReadMessageLength);
-        }
+    let state = MessageState::from_code(state.unwrap())?;
+    let timestamp = reader.read_u64_le().await;
+    if timestamp.is_err() {
+        return Err(Error::CannotReadMessageTimestamp);
+    }
 
-        let mut payload = vec![0; payload_length.unwrap() as usize];
-        if reader.read_exact(&mut payload).await.is_err() {
-            return Err(Error::CannotReadMessagePayload);
+    let id = reader.read_u128_le().await;
+    if id.is_err() {
+        return Err(Error::CannotReadMessageId);
+    }
+
+    let checksum = reader.read_u32_le().await;
+    if checksum.is_err() {
+        return Err(Error::CannotReadMessageChecksum);
+    }
+
+    let headers_length = reader.read_u32_le().await;
+    if headers_length.is_err() {
+        return Err(Error::CannotReadHeadersLength);
+    }
+
+    let headers_length = headers_length.unwrap();
+    let headers = match headers_length {
+        0 => None,
+        _ => {
+            let mut headers_payload = vec![0; headers_length as usize];
+            if reader.read_exact(&mut headers_payload).await.is_err() {
+                return Err(Error::CannotReadHeadersPayload);
+            }
+
+            let headers = HashMap::from_bytes(&headers_payload)?;
+            Some(headers)
         }
+    };
 
-        let offset = offset.unwrap();
-        let timestamp = timestamp.unwrap();
-        let id = id.unwrap();
-        let checksum = checksum.unwrap();
+    let payload_length = reader.read_u32_le().await;
+    if payload_length.is_err() {
+        return Err(Error::CannotReadMessageLength);
+    }
 
-        let message = Message::create(
-            offset,
-            state,
-            timestamp,
-            id,
-            Bytes::from(payload),
-            checksum,
-            headers,
-        );
-        read_messages += 1;
-        on_message(message)?;
+    let mut payload = vec![0; payload_length.unwrap() as usize];
+    if reader.read_exact(&mut payload).await.is_err() {
+        return Err(Error::CannotReadMessagePayload);
     }
-    Ok(())
+
+    Ok(Some(Message::create(
+        offset,
+        state,
+        timestamp.unwrap(),
+        id.unwrap(),
+        Bytes::from(payload),
+        checksum.unwrap(),
+        headers,
+    )))
 }
 
 async fn load_messages_by_size(
@@ -617,6 +1042,7 @@ async fn load_messages_by_size(
     size_bytes: u64,
     mut on_message: impl FnMut(Message) -> Result<(), Error>,
 ) -> Result<(), Error> {
+    segment.ensure_local_log()?;
     let file = file::open(&segment.log_path).await?;
     let file_size = file.metadata().await?.len();
     if file_size == 0 {
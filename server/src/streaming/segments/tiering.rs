@@ -0,0 +1,137 @@
+use crate::streaming::segments::segment::Segment;
+use crate::streaming::segments::storage::get_remote_key;
+use crate::streaming::tiering::storage::TieredStorage;
+use iggy::error::Error;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::info;
+
+const DOWNLOAD_FILE_SUFFIX: &str = ".download";
+
+/// The snapshot of the closed segment files, which are uploaded to the tiered storage without holding the partition lock.
+#[derive(Debug)]
+pub struct SegmentUpload {
+    pub start_offset: u64,
+    size_bytes: u32,
+    files: Vec<(String, String)>,
+    tiered_storage: Arc<dyn TieredStorage>,
+}
+
+impl SegmentUpload {
+    /// Uploads the segment files to the tiered storage, the segment itself is offloaded afterwards.
+    pub async fn upload(&self) -> Result<(), Error> {
+        for (key, path) in &self.files {
+            self.tiered_storage.upload(key, path).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The freed log file of the offloaded segment, which is fetched from the tiered storage without holding the partition lock.
+#[derive(Debug)]
+pub struct RemoteLogFetch {
+    start_offset: u64,
+    partition_id: u32,
+    key: String,
+    log_path: String,
+    tiered_storage: Arc<dyn TieredStorage>,
+    lock: Arc<Mutex<()>>,
+}
+
+impl RemoteLogFetch {
+    /// Downloads the log file, unless it was already fetched by the concurrent reader of the same segment.
+    /// It will be freed again by the next offloading.
+    pub async fn fetch(&self) -> Result<(), Error> {
+        let _lock = self.lock.lock().await;
+        if Path::new(&self.log_path).exists() {
+            return Ok(());
+        }
+
+        info!(
+            "Fetching log file of segment with start offset: {} for partition with ID: {} from the tiered storage...",
+            self.start_offset, self.partition_id
+        );
+        // The file is downloaded under the temporary name first, so the readers never see the partial log.
+        let download_path = format!("{}{}", self.log_path, DOWNLOAD_FILE_SUFFIX);
+        self.tiered_storage
+            .download(&self.key, &download_path)
+            .await?;
+        tokio::fs::rename(&download_path, &self.log_path).await?;
+        Ok(())
+    }
+}
+
+impl Segment {
+    /// Returns true if the segment was uploaded to the tiered storage and its local log file was freed.
+    pub fn is_offloaded(&self) -> bool {
+        self.is_remote && !Path::new(&self.log_path).exists()
+    }
+
+    /// Returns the files to be uploaded to the tiered storage, there are none if the segment was already uploaded.
+    pub fn get_upload(&self) -> Result<SegmentUpload, Error> {
+        let Some(tiered_storage) = &self.storage.tiered else {
+            return Err(Error::TieredStorageDisabled(
+                self.start_offset,
+                self.partition_id,
+            ));
+        };
+
+        let files = match self.is_remote {
+            true => Vec::new(),
+            false => [&self.log_path, &self.index_path, &self.time_index_path]
+                .into_iter()
+                .map(|path| (get_remote_key(self, path), path.to_string()))
+                .collect(),
+        };
+        Ok(SegmentUpload {
+            start_offset: self.start_offset,
+            size_bytes: self.current_size_bytes,
+            files,
+            tiered_storage: tiered_storage.clone(),
+        })
+    }
+
+    /// Returns an error if the log file of the offloaded segment has to be fetched from the tiered storage before reading it.
+    pub fn ensure_local_log(&self) -> Result<(), Error> {
+        match self.is_offloaded() {
+            true => Err(Error::RemoteSegmentNotFetched(
+                self.start_offset,
+                self.partition_id,
+            )),
+            false => Ok(()),
+        }
+    }
+
+    pub fn get_remote_log_fetch(&self) -> Result<RemoteLogFetch, Error> {
+        let Some(tiered_storage) = &self.storage.tiered else {
+            return Err(Error::TieredStorageDisabled(
+                self.start_offset,
+                self.partition_id,
+            ));
+        };
+
+        Ok(RemoteLogFetch {
+            start_offset: self.start_offset,
+            partition_id: self.partition_id,
+            key: get_remote_key(self, &self.log_path),
+            log_path: self.log_path.clone(),
+            tiered_storage: tiered_storage.clone(),
+            lock: self.remote_log_lock.clone(),
+        })
+    }
+
+    /// Frees the local log file, once the segment files were uploaded to the tiered storage.
+    /// The indexes are kept on the local disk, so the messages can be still looked up by offset or timestamp.
+    /// Returns false if the segment was compacted after taking the snapshot, as the uploaded files are stale then.
+    pub async fn offload(&mut self, upload: &SegmentUpload) -> Result<bool, Error> {
+        if self.current_size_bytes != upload.size_bytes {
+            return Ok(false);
+        }
+
+        self.storage.segment.offload(self).await?;
+        self.is_remote = true;
+        Ok(true)
+    }
+}
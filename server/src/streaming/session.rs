@@ -1,3 +1,4 @@
+use crate::streaming::clients::client_manager::Transport;
 use iggy::models::user_info::{AtomicUserId, UserId};
 use std::fmt::Display;
 use std::net::SocketAddr;
@@ -9,23 +10,38 @@ pub struct Session {
     user_id: AtomicUserId,
     pub client_id: u32,
     pub ip_address: SocketAddr,
+    /// The transport used by the client, `None` for the sessions created internally by the server.
+    pub transport: Option<Transport>,
 }
 
 impl Session {
-    pub fn new(client_id: u32, user_id: UserId, ip_address: SocketAddr) -> Self {
+    pub fn new(
+        client_id: u32,
+        user_id: UserId,
+        ip_address: SocketAddr,
+        transport: Transport,
+    ) -> Self {
         Self {
             client_id,
             user_id: AtomicUserId::new(user_id),
             ip_address,
+            transport: Some(transport),
         }
     }
 
     pub fn stateless(user_id: UserId, ip_address: SocketAddr) -> Self {
-        Self::new(0, user_id, ip_address)
+        Self::new(0, user_id, ip_address, Transport::Http)
     }
 
-    pub fn from_client_id(client_id: u32, ip_address: SocketAddr) -> Self {
-        Self::new(client_id, 0, ip_address)
+    pub fn internal(user_id: UserId, ip_address: SocketAddr) -> Self {
+        Self {
+            transport: None,
+            ..Self::new(0, user_id, ip_address, Transport::Http)
+        }
+    }
+
+    pub fn from_client_id(client_id: u32, ip_address: SocketAddr, transport: Transport) -> Self {
+        Self::new(client_id, 0, ip_address, transport)
     }
 
     pub fn get_user_id(&self) -> UserId {
@@ -1,9 +1,13 @@
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::metadata::database::Database;
+use crate::streaming::partitions::partition::{ConsumerOffset, Partition, PoisonedMessage};
 use crate::streaming::partitions::storage::FilePartitionStorage;
-use crate::streaming::persistence::persister::Persister;
+use crate::streaming::persistence::persister::{FileWithSyncPersister, Persister};
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::personal_access_tokens::storage::FilePersonalAccessTokenStorage;
+use crate::streaming::roles::role::Role;
+use crate::streaming::roles::storage::FileRoleStorage;
 use crate::streaming::segments::index::{Index, IndexRange};
+use crate::streaming::segments::mapped::MappedMessages;
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::segments::storage::FileSegmentStorage;
 use crate::streaming::segments::time_index::TimeIndex;
@@ -11,6 +15,7 @@ use crate::streaming::streams::storage::FileStreamStorage;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::info::SystemInfo;
 use crate::streaming::systems::storage::FileSystemInfoStorage;
+use crate::streaming::tiering::storage::TieredStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::storage::FileTopicStorage;
 use crate::streaming::topics::topic::Topic;
@@ -20,8 +25,9 @@ use async_trait::async_trait;
 use iggy::consumer::ConsumerKind;
 use iggy::error::Error;
 use iggy::models::messages::Message;
+use iggy::models::role_info::RoleId;
 use iggy::models::user_info::UserId;
-use sled::Db;
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
@@ -42,6 +48,14 @@ pub trait UserStorage: Storage<User> {
     async fn load_all(&self) -> Result<Vec<User>, Error>;
 }
 
+#[async_trait]
+pub trait RoleStorage: Storage<Role> {
+    async fn load_by_id(&self, id: RoleId) -> Result<Role, Error>;
+    async fn load_by_name(&self, name: &str) -> Result<Role, Error>;
+    async fn load_all(&self) -> Result<Vec<Role>, Error>;
+    async fn delete_name(&self, name: &str) -> Result<(), Error>;
+}
+
 #[async_trait]
 pub trait PersonalAccessTokenStorage: Storage<PersonalAccessToken> {
     async fn load_all(&self) -> Result<Vec<PersonalAccessToken>, Error>;
@@ -80,6 +94,7 @@ pub trait PartitionStorage: Storage<Partition> {
         topic_id: u32,
         partition_id: u32,
     ) -> Result<Vec<ConsumerOffset>, Error>;
+    async fn delete_consumer_offset(&self, offset: &ConsumerOffset) -> Result<(), Error>;
     async fn delete_consumer_offsets(
         &self,
         kind: ConsumerKind,
@@ -87,6 +102,20 @@ pub trait PartitionStorage: Storage<Partition> {
         topic_id: u32,
         partition_id: u32,
     ) -> Result<(), Error>;
+    async fn save_poisoned_message(&self, message: &PoisonedMessage) -> Result<(), Error>;
+    async fn delete_poisoned_message(&self, message: &PoisonedMessage) -> Result<(), Error>;
+    async fn load_poisoned_messages(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<Vec<PoisonedMessage>, Error>;
+    async fn delete_poisoned_messages(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<(), Error>;
 }
 
 #[async_trait]
@@ -96,6 +125,11 @@ pub trait SegmentStorage: Storage<Segment> {
         segment: &Segment,
         index_range: &IndexRange,
     ) -> Result<Vec<Arc<Message>>, Error>;
+    async fn map_messages(
+        &self,
+        segment: &Segment,
+        index_range: &IndexRange,
+    ) -> Result<Option<MappedMessages>, Error>;
     async fn load_newest_messages_by_size(
         &self,
         segment: &Segment,
@@ -106,8 +140,17 @@ pub trait SegmentStorage: Storage<Segment> {
         segment: &Segment,
         messages: &[Arc<Message>],
     ) -> Result<u32, Error>;
+    async fn save_compacted_messages(
+        &self,
+        segment: &Segment,
+        retain: &(dyn for<'a> Fn(&'a Message) -> bool + Sync),
+    ) -> Result<Vec<u64>, Error>;
+    async fn replace_compacted_messages(&self, segment: &Segment) -> Result<u32, Error>;
+    async fn delete_compacted_messages(&self, segment: &Segment) -> Result<(), Error>;
+    async fn load_message_keys(&self, segment: &Segment) -> Result<HashMap<Vec<u8>, u64>, Error>;
     async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, Error>;
     async fn load_checksums(&self, segment: &Segment) -> Result<(), Error>;
+    async fn offload(&self, segment: &Segment) -> Result<(), Error>;
     async fn load_all_indexes(&self, segment: &Segment) -> Result<Vec<Index>, Error>;
     async fn load_index_range(
         &self,
@@ -123,10 +166,10 @@ pub trait SegmentStorage: Storage<Segment> {
         messages: &[Arc<Message>],
     ) -> Result<(), Error>;
     async fn load_all_time_indexes(&self, segment: &Segment) -> Result<Vec<TimeIndex>, Error>;
-    async fn load_last_time_index(&self, segment: &Segment) -> Result<Option<TimeIndex>, Error>;
     async fn save_time_index(
         &self,
         segment: &Segment,
+        current_position: u32,
         messages: &[Arc<Message>],
     ) -> Result<(), Error>;
 }
@@ -135,23 +178,31 @@ pub trait SegmentStorage: Storage<Segment> {
 pub struct SystemStorage {
     pub info: Arc<dyn SystemInfoStorage>,
     pub user: Arc<dyn UserStorage>,
+    pub role: Arc<dyn RoleStorage>,
     pub personal_access_token: Arc<dyn PersonalAccessTokenStorage>,
     pub stream: Arc<dyn StreamStorage>,
     pub topic: Arc<dyn TopicStorage>,
     pub partition: Arc<dyn PartitionStorage>,
     pub segment: Arc<dyn SegmentStorage>,
+    // Always syncs the data on disk, used when the messages are sent with the persisted acknowledgement level.
+    pub synced_segment: Arc<dyn SegmentStorage>,
+    // Set only if the tiered storage is enabled, the closed segments are offloaded to it.
+    pub tiered: Option<Arc<dyn TieredStorage>>,
 }
 
 impl SystemStorage {
-    pub fn new(db: Arc<Db>, persister: Arc<dyn Persister>) -> Self {
+    pub fn new(db: Arc<dyn Database>, persister: Arc<dyn Persister>) -> Self {
         Self {
             info: Arc::new(FileSystemInfoStorage::new(db.clone())),
             user: Arc::new(FileUserStorage::new(db.clone())),
+            role: Arc::new(FileRoleStorage::new(db.clone())),
             personal_access_token: Arc::new(FilePersonalAccessTokenStorage::new(db.clone())),
             stream: Arc::new(FileStreamStorage::new(db.clone())),
             topic: Arc::new(FileTopicStorage::new(db.clone())),
             partition: Arc::new(FilePartitionStorage::new(db.clone())),
             segment: Arc::new(FileSegmentStorage::new(persister.clone())),
+            synced_segment: Arc::new(FileSegmentStorage::new(Arc::new(FileWithSyncPersister {}))),
+            tiered: None,
         }
     }
 }
@@ -168,6 +219,12 @@ impl Debug for dyn UserStorage {
     }
 }
 
+impl Debug for dyn RoleStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RoleStorage")
+    }
+}
+
 impl Debug for dyn PersonalAccessTokenStorage {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "PersonalAccessTokenStorage")
@@ -213,6 +270,7 @@ pub(crate) mod tests {
 
     struct TestSystemInfoStorage {}
     struct TestUserStorage {}
+    struct TestRoleStorage {}
     struct TestPersonalAccessTokenStorage {}
     struct TestStreamStorage {}
     struct TestTopicStorage {}
@@ -267,6 +325,40 @@ pub(crate) mod tests {
         }
     }
 
+    #[async_trait]
+    impl Storage<Role> for TestRoleStorage {
+        async fn load(&self, _role: &mut Role) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn save(&self, _role: &Role) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn delete(&self, _role: &Role) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl RoleStorage for TestRoleStorage {
+        async fn load_by_id(&self, _id: RoleId) -> Result<Role, Error> {
+            Ok(Role::default())
+        }
+
+        async fn load_by_name(&self, _name: &str) -> Result<Role, Error> {
+            Ok(Role::default())
+        }
+
+        async fn load_all(&self) -> Result<Vec<Role>, Error> {
+            Ok(vec![])
+        }
+
+        async fn delete_name(&self, _name: &str) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
     #[async_trait]
     impl Storage<PersonalAccessToken> for TestPersonalAccessTokenStorage {
         async fn load(
@@ -398,6 +490,10 @@ pub(crate) mod tests {
             Ok(vec![])
         }
 
+        async fn delete_consumer_offset(&self, _offset: &ConsumerOffset) -> Result<(), Error> {
+            Ok(())
+        }
+
         async fn delete_consumer_offsets(
             &self,
             _kind: ConsumerKind,
@@ -407,6 +503,32 @@ pub(crate) mod tests {
         ) -> Result<(), Error> {
             Ok(())
         }
+
+        async fn save_poisoned_message(&self, _message: &PoisonedMessage) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn delete_poisoned_message(&self, _message: &PoisonedMessage) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn load_poisoned_messages(
+            &self,
+            _stream_id: u32,
+            _topic_id: u32,
+            _partition_id: u32,
+        ) -> Result<Vec<PoisonedMessage>, Error> {
+            Ok(vec![])
+        }
+
+        async fn delete_poisoned_messages(
+            &self,
+            _stream_id: u32,
+            _topic_id: u32,
+            _partition_id: u32,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
     }
 
     #[async_trait]
@@ -434,6 +556,14 @@ pub(crate) mod tests {
             Ok(vec![])
         }
 
+        async fn map_messages(
+            &self,
+            _segment: &Segment,
+            _index_range: &IndexRange,
+        ) -> Result<Option<MappedMessages>, Error> {
+            Ok(None)
+        }
+
         async fn load_newest_messages_by_size(
             &self,
             _segment: &Segment,
@@ -450,6 +580,29 @@ pub(crate) mod tests {
             Ok(0)
         }
 
+        async fn save_compacted_messages(
+            &self,
+            _segment: &Segment,
+            _retain: &(dyn for<'a> Fn(&'a Message) -> bool + Sync),
+        ) -> Result<Vec<u64>, Error> {
+            Ok(vec![])
+        }
+
+        async fn replace_compacted_messages(&self, _segment: &Segment) -> Result<u32, Error> {
+            Ok(0)
+        }
+
+        async fn delete_compacted_messages(&self, _segment: &Segment) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn load_message_keys(
+            &self,
+            _segment: &Segment,
+        ) -> Result<HashMap<Vec<u8>, u64>, Error> {
+            Ok(HashMap::new())
+        }
+
         async fn load_message_ids(&self, _segment: &Segment) -> Result<Vec<u128>, Error> {
             Ok(vec![])
         }
@@ -458,6 +611,10 @@ pub(crate) mod tests {
             Ok(())
         }
 
+        async fn offload(&self, _segment: &Segment) -> Result<(), Error> {
+            Ok(())
+        }
+
         async fn load_all_indexes(&self, _segment: &Segment) -> Result<Vec<Index>, Error> {
             Ok(vec![])
         }
@@ -485,16 +642,10 @@ pub(crate) mod tests {
             Ok(vec![])
         }
 
-        async fn load_last_time_index(
-            &self,
-            _segment: &Segment,
-        ) -> Result<Option<TimeIndex>, Error> {
-            Ok(None)
-        }
-
         async fn save_time_index(
             &self,
             _segment: &Segment,
+            _current_position: u32,
             _messages: &[Arc<Message>],
         ) -> Result<(), Error> {
             Ok(())
@@ -505,11 +656,14 @@ pub(crate) mod tests {
         SystemStorage {
             info: Arc::new(TestSystemInfoStorage {}),
             user: Arc::new(TestUserStorage {}),
+            role: Arc::new(TestRoleStorage {}),
             personal_access_token: Arc::new(TestPersonalAccessTokenStorage {}),
             stream: Arc::new(TestStreamStorage {}),
             topic: Arc::new(TestTopicStorage {}),
             partition: Arc::new(TestPartitionStorage {}),
             segment: Arc::new(TestSegmentStorage {}),
+            synced_segment: Arc::new(TestSegmentStorage {}),
+            tiered: None,
         }
     }
 }
@@ -27,6 +27,14 @@ impl Stream {
         Ok(())
     }
 
+    pub async fn persist_messages_exceeding_max_latency(&self, now: u64) -> Result<(), Error> {
+        for topic in self.get_topics() {
+            topic.persist_messages_exceeding_max_latency(now).await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn purge(&self) -> Result<(), Error> {
         for topic in self.get_topics() {
             topic.purge().await?;
@@ -1,3 +1,4 @@
+use crate::streaming::metadata::database::Database;
 use crate::streaming::storage::{Storage, StreamStorage};
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::topic::Topic;
@@ -6,7 +7,6 @@ use async_trait::async_trait;
 use futures::future::join_all;
 use iggy::error::Error;
 use serde::{Deserialize, Serialize};
-use sled::Db;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::fs;
@@ -16,11 +16,11 @@ use tracing::{error, info};
 
 #[derive(Debug)]
 pub struct FileStreamStorage {
-    db: Arc<Db>,
+    db: Arc<dyn Database>,
 }
 
 impl FileStreamStorage {
-    pub fn new(db: Arc<Db>) -> Self {
+    pub fn new(db: Arc<dyn Database>) -> Self {
         Self { db }
     }
 }
@@ -175,7 +175,7 @@ impl Storage<Stream> for FileStreamStorage {
             Ok(data) => {
                 if let Err(err) = self
                     .db
-                    .insert(&key, data)
+                    .insert(&key, &data)
                     .with_context(|| format!("Failed to insert stream with key: {}", key))
                 {
                     return Err(Error::CannotSaveResource(err));
@@ -1,16 +1,19 @@
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::topic::Topic;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::Error;
 use iggy::identifier::{IdKind, Identifier};
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::text;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 impl Stream {
     pub fn get_topics_count(&self) -> u32 {
         self.topics.len() as u32
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_topic(
         &mut self,
         id: u32,
@@ -19,6 +22,10 @@ impl Stream {
         message_expiry: Option<u32>,
         max_topic_size: Option<IggyByteSize>,
         replication_factor: u8,
+        compression_algorithm: Option<CompressionAlgorithm>,
+        cleanup_policy: CleanupPolicy,
+        max_topic_size_policy: MaxTopicSizePolicy,
+        flush_policy: FlushPolicy,
     ) -> Result<(), Error> {
         if self.topics.contains_key(&id) {
             return Err(Error::TopicIdAlreadyExists(id, self.stream_id));
@@ -31,6 +38,8 @@ impl Stream {
 
         // TODO: check if max_topic_size is not lower than system.segment.size
 
+        self.warn_if_compression_override_disabled(&name, compression_algorithm);
+
         let topic = Topic::create(
             self.stream_id,
             id,
@@ -41,6 +50,10 @@ impl Stream {
             message_expiry,
             max_topic_size,
             replication_factor,
+            compression_algorithm,
+            cleanup_policy,
+            max_topic_size_policy,
+            flush_policy,
         )?;
         topic.persist().await?;
         info!("Created topic {}", topic);
@@ -50,6 +63,7 @@ impl Stream {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_topic(
         &mut self,
         id: &Identifier,
@@ -57,6 +71,10 @@ impl Stream {
         message_expiry: Option<u32>,
         max_topic_size: Option<IggyByteSize>,
         replication_factor: u8,
+        compression_algorithm: Option<CompressionAlgorithm>,
+        cleanup_policy: Option<CleanupPolicy>,
+        max_topic_size_policy: Option<MaxTopicSizePolicy>,
+        flush_policy: Option<FlushPolicy>,
     ) -> Result<(), Error> {
         let topic_id;
         {
@@ -65,6 +83,7 @@ impl Stream {
         }
 
         let updated_name = text::to_lowercase_non_whitespace(name);
+        self.warn_if_compression_override_disabled(&updated_name, compression_algorithm);
 
         {
             if let Some(topic_id_by_name) = self.topics_ids.get(&updated_name) {
@@ -88,9 +107,25 @@ impl Stream {
             let topic = self.get_topic_mut(id)?;
             topic.name = updated_name;
             topic.message_expiry = message_expiry;
+            // The policies which are not set keep their current values, e.g. when sent by the older clients.
+            if compression_algorithm.is_some() {
+                topic.compression_algorithm =
+                    Topic::get_compression_algorithm(&topic.config, compression_algorithm);
+            }
+            if let Some(cleanup_policy) = cleanup_policy {
+                topic.cleanup_policy = cleanup_policy;
+            }
+            if let Some(max_topic_size_policy) = max_topic_size_policy {
+                topic.max_topic_size_policy = max_topic_size_policy;
+            }
+            if let Some(flush_policy) = flush_policy {
+                topic.flush_policy = flush_policy;
+            }
+            let flush_policy = topic.flush_policy;
             for partition in topic.partitions.values_mut() {
                 let mut partition = partition.write().await;
                 partition.message_expiry = message_expiry;
+                partition.flush_policy = flush_policy;
                 for segment in partition.segments.iter_mut() {
                     segment.message_expiry = message_expiry;
                 }
@@ -105,6 +140,19 @@ impl Stream {
         Ok(())
     }
 
+    fn warn_if_compression_override_disabled(
+        &self,
+        topic_name: &str,
+        compression_algorithm: Option<CompressionAlgorithm>,
+    ) {
+        if compression_algorithm.is_some() && !self.config.compression.allow_override {
+            warn!(
+                "Compression algorithm override is disabled, topic: {} will use the default algorithm: {}.",
+                topic_name, self.config.compression.default_algorithm
+            );
+        }
+    }
+
     pub fn remove_topic(&mut self, identifier: &Identifier) -> Result<Topic, Error> {
         match identifier.kind {
             IdKind::Numeric => self.remove_topic_by_id(identifier.get_u32_value()?),
@@ -208,7 +256,18 @@ mod tests {
         let storage = Arc::new(get_test_system_storage());
         let mut stream = Stream::create(stream_id, stream_name, config, storage);
         stream
-            .create_topic(topic_id, topic_name, 1, message_expiry, max_topic_size, 1)
+            .create_topic(
+                topic_id,
+                topic_name,
+                1,
+                message_expiry,
+                max_topic_size,
+                1,
+                None,
+                CleanupPolicy::Delete,
+                MaxTopicSizePolicy::DeleteOldest,
+                FlushPolicy::default(),
+            )
             .await
             .unwrap();
 
@@ -0,0 +1,107 @@
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use anyhow::Context;
+use bytes::Bytes;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages;
+use iggy::messages::send_messages::Partitioning;
+use iggy::models::audit_log::{AuditLogEntry, AuditOutcome};
+use iggy::models::messages::Message;
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::{error, warn};
+
+const INTERNAL_TRANSPORT: &str = "Internal";
+const UNKNOWN_TRANSPORT: &str = "Unknown";
+
+impl System {
+    pub async fn get_audit_log(
+        &self,
+        session: &Session,
+        count: u32,
+        user_id: Option<u32>,
+    ) -> Result<Vec<AuditLogEntry>, Error> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.get_audit_log(session.get_user_id())?;
+        if !self.audit_log.is_enabled() {
+            return Err(Error::AuditLogDisabled);
+        }
+
+        self.audit_log.load(count, user_id).await
+    }
+
+    /// Records the outcome of the administrative or security-relevant action and returns its result.
+    /// Failing to record the entry doesn't affect the result, as the action has been already performed.
+    pub(crate) async fn audit<T>(
+        &self,
+        session: Option<&Session>,
+        command: &str,
+        details: String,
+        result: Result<T, Error>,
+    ) -> Result<T, Error> {
+        if !self.audit_log.is_enabled() {
+            return result;
+        }
+
+        let (user_id, address, transport) = match session {
+            Some(session) => (
+                session.get_user_id(),
+                session.ip_address.to_string(),
+                session
+                    .transport
+                    .map_or(INTERNAL_TRANSPORT.to_string(), |transport| {
+                        transport.to_string()
+                    }),
+            ),
+            None => (0, "".to_string(), UNKNOWN_TRANSPORT.to_string()),
+        };
+        let (outcome, error) = match &result {
+            Ok(_) => (AuditOutcome::Success, None),
+            Err(error) => (AuditOutcome::Failure, Some(error.to_string())),
+        };
+        let entry = AuditLogEntry {
+            timestamp: IggyTimestamp::now().to_micros(),
+            user_id,
+            address,
+            transport,
+            command: command.to_string(),
+            details,
+            outcome,
+            error,
+        };
+
+        if let Err(error) = self.audit_log.append(&entry).await {
+            error!("Cannot append entry: {command} to the audit log. Error: {error}");
+        }
+
+        if self.config.audit_log.mirror {
+            if let Err(error) = self.mirror_audit_log_entry(&entry).await {
+                warn!("Cannot mirror entry: {command} of the audit log to stream: {}, topic: {}. Error: {error}", self.config.audit_log.stream, self.config.audit_log.topic);
+            }
+        }
+
+        result
+    }
+
+    async fn mirror_audit_log_entry(&self, entry: &AuditLogEntry) -> Result<(), Error> {
+        let stream =
+            self.get_stream(&Identifier::from_str_value(&self.config.audit_log.stream)?)?;
+        let topic = stream.get_topic(&Identifier::from_str_value(&self.config.audit_log.topic)?)?;
+        let mut payload = serde_json::to_vec(entry)
+            .with_context(|| "Failed to serialize audit log entry")
+            .map_err(Error::CannotSerializeResource)?;
+        if let Some(ref encryptor) = self.encryptor {
+            payload = encryptor.encrypt(&payload)?;
+        }
+
+        let message = send_messages::Message::new(None, Bytes::from(payload), None);
+        topic
+            .append_messages(
+                &Partitioning::balanced(),
+                vec![Message::from_message(&message)],
+            )
+            .await?;
+        self.metrics.increment_messages(1);
+        Ok(())
+    }
+}
@@ -64,6 +64,7 @@ impl System {
             }
 
             self.metrics.decrement_clients(1);
+            self.quota_manager.delete_client(client_id);
             let client = client.unwrap();
             let client = client.read().await;
 
@@ -1,9 +1,16 @@
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
+use iggy::command::{CREATE_CONSUMER_GROUP, DELETE_CONSUMER_GROUP, RESET_CONSUMER_GROUP_OFFSETS};
+use iggy::consumer_groups::reset_consumer_group_offsets::OffsetsResetStrategy;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
+use iggy::models::consumer_group::{
+    ConsumerGroupAssignment, ConsumerGroupOffsetReset, PartitionAssignmentStrategy,
+};
+use iggy::utils::timestamp::IggyTimestamp;
 use tokio::sync::RwLock;
+use tracing::{error, warn};
 
 impl System {
     pub fn get_consumer_group(
@@ -43,6 +50,7 @@ impl System {
         Ok(topic.get_consumer_groups())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_consumer_group(
         &mut self,
         session: &Session,
@@ -50,6 +58,39 @@ impl System {
         topic_id: &Identifier,
         consumer_group_id: u32,
         name: &str,
+        session_timeout: Option<u32>,
+        assignment_strategy: PartitionAssignmentStrategy,
+    ) -> Result<(), Error> {
+        let result = self
+            .create_consumer_group_internal(
+                session,
+                stream_id,
+                topic_id,
+                consumer_group_id,
+                name,
+                session_timeout,
+                assignment_strategy,
+            )
+            .await;
+        self.audit(
+            Some(session),
+            CREATE_CONSUMER_GROUP,
+            format!("stream ID: {stream_id}, topic ID: {topic_id}, consumer group ID: {consumer_group_id}, name: {name}"),
+            result,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn create_consumer_group_internal(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer_group_id: u32,
+        name: &str,
+        session_timeout: Option<u32>,
+        assignment_strategy: PartitionAssignmentStrategy,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         {
@@ -63,7 +104,14 @@ impl System {
         }
 
         let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
-        topic.create_consumer_group(consumer_group_id, name).await?;
+        topic
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                session_timeout,
+                assignment_strategy,
+            )
+            .await?;
         Ok(())
     }
 
@@ -73,6 +121,25 @@ impl System {
         stream_id: &Identifier,
         topic_id: &Identifier,
         consumer_group_id: &Identifier,
+    ) -> Result<(), Error> {
+        let result = self
+            .delete_consumer_group_internal(session, stream_id, topic_id, consumer_group_id)
+            .await;
+        self.audit(
+            Some(session),
+            DELETE_CONSUMER_GROUP,
+            format!("stream ID: {stream_id}, topic ID: {topic_id}, consumer group ID: {consumer_group_id}"),
+            result,
+        )
+        .await
+    }
+
+    async fn delete_consumer_group_internal(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer_group_id: &Identifier,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         let stream_id_value;
@@ -158,6 +225,186 @@ impl System {
         Ok(())
     }
 
+    pub async fn get_consumer_group_assignment(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer_group_id: &Identifier,
+    ) -> Result<ConsumerGroupAssignment, Error> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner.join_consumer_group(
+            session.get_user_id(),
+            stream.stream_id,
+            topic.topic_id,
+        )?;
+
+        let consumer_group = topic.get_consumer_group(consumer_group_id)?;
+        let mut consumer_group = consumer_group.write().await;
+        consumer_group.get_assignment(session.client_id).await
+    }
+
+    pub async fn heartbeat_consumer_group(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer_group_id: &Identifier,
+    ) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner.join_consumer_group(
+            session.get_user_id(),
+            stream.stream_id,
+            topic.topic_id,
+        )?;
+
+        let consumer_group = topic.get_consumer_group(consumer_group_id)?;
+        let consumer_group = consumer_group.read().await;
+        consumer_group.heartbeat(session.client_id).await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn reset_consumer_group_offsets(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer_group_id: &Identifier,
+        partition_ids: &[u32],
+        strategy: OffsetsResetStrategy,
+        dry_run: bool,
+    ) -> Result<Vec<ConsumerGroupOffsetReset>, Error> {
+        let details = format!("stream ID: {stream_id}, topic ID: {topic_id}, consumer group ID: {consumer_group_id}, partitions: {partition_ids:?}, strategy: {strategy}, dry run: {dry_run}");
+        let result = self
+            .reset_consumer_group_offsets_internal(
+                session,
+                stream_id,
+                topic_id,
+                consumer_group_id,
+                partition_ids,
+                strategy,
+                dry_run,
+            )
+            .await;
+        self.audit(Some(session), RESET_CONSUMER_GROUP_OFFSETS, details, result)
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn reset_consumer_group_offsets_internal(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer_group_id: &Identifier,
+        partition_ids: &[u32],
+        strategy: OffsetsResetStrategy,
+        dry_run: bool,
+    ) -> Result<Vec<ConsumerGroupOffsetReset>, Error> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner.reset_consumer_group_offsets(
+            session.get_user_id(),
+            stream.stream_id,
+            topic.topic_id,
+        )?;
+
+        topic
+            .reset_consumer_group_offsets(consumer_group_id, partition_ids, strategy, dry_run)
+            .await
+    }
+
+    pub async fn delete_expired_consumer_group_members(&self) -> u32 {
+        let now = IggyTimestamp::now().to_micros();
+        let default_session_timeout = self.config.consumer_group.session_timeout;
+        let mut expired_members = Vec::new();
+        for stream in self.streams.values() {
+            for topic in stream.get_topics() {
+                for consumer_group in topic.get_consumer_groups() {
+                    let consumer_group = consumer_group.read().await;
+                    for member_id in consumer_group
+                        .get_expired_members(now, default_session_timeout)
+                        .await
+                    {
+                        expired_members.push((
+                            stream.stream_id,
+                            topic.topic_id,
+                            consumer_group.consumer_group_id,
+                            member_id,
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut deleted_members_count = 0;
+        for (stream_id, topic_id, consumer_group_id, member_id) in expired_members {
+            let result = self
+                .delete_expired_consumer_group_member(
+                    stream_id,
+                    topic_id,
+                    consumer_group_id,
+                    member_id,
+                    now,
+                )
+                .await;
+            match result {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(error) => {
+                    error!(
+                        "Failed to delete expired member with ID: {} from consumer group with ID: {} for topic with ID: {} and stream with ID: {}. Error: {}",
+                        member_id, consumer_group_id, topic_id, stream_id, error
+                    );
+                    continue;
+                }
+            }
+
+            deleted_members_count += 1;
+            warn!(
+                "Deleted member with ID: {} from consumer group with ID: {} for topic with ID: {} and stream with ID: {}, as its session has expired.",
+                member_id, consumer_group_id, topic_id, stream_id
+            );
+        }
+        deleted_members_count
+    }
+
+    /// Deletes the member if its session is still expired under the consumer group write lock,
+    /// so the heartbeat received since the expired members were found keeps it in the group.
+    async fn delete_expired_consumer_group_member(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        consumer_group_id: u32,
+        member_id: u32,
+        now: u64,
+    ) -> Result<bool, Error> {
+        let stream = self.get_stream(&Identifier::numeric(stream_id)?)?;
+        let topic = stream.get_topic(&Identifier::numeric(topic_id)?)?;
+        let deleted = topic
+            .delete_expired_consumer_group_member(
+                &Identifier::numeric(consumer_group_id)?,
+                member_id,
+                now,
+                self.config.consumer_group.session_timeout,
+            )
+            .await?;
+        if !deleted {
+            return Ok(false);
+        }
+
+        let client_manager = self.client_manager.read().await;
+        client_manager
+            .leave_consumer_group(member_id, stream_id, topic_id, consumer_group_id)
+            .await?;
+        Ok(true)
+    }
+
     pub async fn leave_consumer_group(
         &self,
         session: &Session,
@@ -13,6 +13,7 @@ impl System {
         stream_id: &Identifier,
         topic_id: &Identifier,
         offset: u64,
+        generation: Option<u32>,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         let stream = self.get_stream(stream_id)?;
@@ -23,7 +24,9 @@ impl System {
             topic.topic_id,
         )?;
 
-        topic.store_consumer_offset(consumer, offset).await
+        topic
+            .store_consumer_offset(consumer, offset, generation)
+            .await
     }
 
     pub async fn get_consumer_offset(
@@ -1,17 +1,29 @@
+use crate::configs::system::ReplicaRole;
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::models::messages::PolledMessages;
+use crate::streaming::partitions::replicas::InSyncReplicasAck;
+use crate::streaming::partitions::tiering;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
+use crate::streaming::topics::topic::Topic;
 use bytes::Bytes;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::reject_message::{
+    DEAD_LETTER_ORIGIN_OFFSET_HEADER, DEAD_LETTER_ORIGIN_PARTITION_HEADER,
+    DEAD_LETTER_ORIGIN_STREAM_HEADER, DEAD_LETTER_ORIGIN_TOPIC_HEADER, DEAD_LETTER_REASON_HEADER,
+};
 use iggy::messages::send_messages;
-use iggy::messages::send_messages::Partitioning;
-use iggy::models::messages::Message;
+use iggy::messages::send_messages::{AckLevel, Partitioning};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::{Message, MessageState};
+use iggy::utils::timestamp::IggyTimestamp;
+use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
-use tracing::{error, trace};
+use tracing::{error, info, trace};
 
 impl System {
     pub async fn poll_messages(
@@ -39,14 +51,43 @@ impl System {
         let partition_id = match consumer {
             PollingConsumer::Consumer(_, partition_id) => partition_id,
             PollingConsumer::ConsumerGroup(consumer_group_id, member_id) => {
-                let consumer_group = topic
+                let mut consumer_group = topic
                     .get_consumer_group_by_id(consumer_group_id)?
-                    .read()
+                    .write()
                     .await;
-                consumer_group.calculate_partition_id(member_id).await?
+                match consumer_group
+                    .calculate_partition_id(member_id, args.generation)
+                    .await?
+                {
+                    Some(partition_id) => partition_id,
+                    // All the partitions assigned to the member are still owned by the other members.
+                    None => return Ok(PolledMessages::default()),
+                }
             }
         };
 
+        // The stored messages can be sent as they are, unless they have to be decrypted.
+        if args.zero_copy && self.encryptor.is_none() {
+            if let Some(polled_messages) = topic
+                .get_mapped_messages(consumer, partition_id, args.strategy, args.count)
+                .await?
+            {
+                let offset = polled_messages
+                    .mapped_messages
+                    .as_ref()
+                    .unwrap()
+                    .last_offset;
+                if args.auto_commit {
+                    trace!("Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}", offset, consumer, stream_id, topic_id, partition_id);
+                    topic
+                        .store_consumer_offset(consumer, offset, args.generation)
+                        .await?;
+                }
+
+                return Ok(polled_messages);
+            }
+        }
+
         let mut polled_messages = topic
             .get_messages(consumer, partition_id, args.strategy, args.count)
             .await?;
@@ -58,7 +99,15 @@ impl System {
         let offset = polled_messages.messages.last().unwrap().offset;
         if args.auto_commit {
             trace!("Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}", offset, consumer, stream_id, topic_id, partition_id);
-            topic.store_consumer_offset(consumer, offset).await?;
+            topic
+                .store_consumer_offset(consumer, offset, args.generation)
+                .await?;
+        }
+
+        if args.skip_poisoned {
+            polled_messages
+                .messages
+                .retain(|message| message.state != MessageState::Poisoned);
         }
 
         if self.encryptor.is_none() {
@@ -94,6 +143,58 @@ impl System {
         Ok(polled_messages)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub async fn fetch_replica_messages(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        replica_id: u32,
+        offset: u64,
+        count: u32,
+    ) -> Result<PolledMessages, Error> {
+        self.ensure_authenticated(session)?;
+        if !self.config.replication.enabled {
+            return Err(Error::ReplicationDisabled);
+        }
+
+        if count == 0 {
+            return Err(Error::InvalidMessagesCount);
+        }
+
+        // Only the server-level users can fetch as the replicas, as the fetches count towards the acknowledgements.
+        self.permissioner
+            .fetch_replica_messages(session.get_user_id())?;
+        if !self.config.replication.follower_ids.contains(&replica_id) {
+            return Err(Error::UnknownReplica(replica_id));
+        }
+
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        let partition = topic.get_partition(partition_id)?;
+        tiering::read_partition(&partition, |partition| {
+            Box::pin(async move {
+                let next_offset = partition.get_next_offset();
+                // The messages are returned as stored, without decryption and decompression.
+                // The ranges removed by the retention or compaction are skipped, so that the follower doesn't get stuck on them.
+                let fetch_offset = offset.max(partition.get_earliest_offset());
+                let messages = partition
+                    .get_messages_by_offset(fetch_offset, count)
+                    .await?;
+                // The replica can't have more messages than the leader, regardless of the requested offset.
+                partition.record_replica_fetch(replica_id, offset.min(next_offset));
+                Ok(PolledMessages {
+                    partition_id,
+                    current_offset: partition.current_offset,
+                    messages,
+                    mapped_messages: None,
+                })
+            })
+        })
+        .await
+    }
+
     pub async fn append_messages(
         &self,
         session: &Session,
@@ -101,15 +202,12 @@ impl System {
         topic_id: &Identifier,
         partitioning: &Partitioning,
         messages: &Vec<send_messages::Message>,
-    ) -> Result<(), Error> {
+        ack: AckLevel,
+    ) -> Result<Option<InSyncReplicasAck>, Error> {
         self.ensure_authenticated(session)?;
         let stream = self.get_stream(stream_id)?;
         let topic = stream.get_topic(topic_id)?;
-        self.permissioner.append_messages(
-            session.get_user_id(),
-            stream.stream_id,
-            topic.topic_id,
-        )?;
+        self.ensure_can_append_messages(session, topic)?;
 
         let mut received_messages = Vec::with_capacity(messages.len());
         let mut batch_size_bytes = 0u64;
@@ -134,6 +232,42 @@ impl System {
             received_messages.push(Message::from_message(message));
         }
 
+        self.append_received_messages(
+            topic,
+            partitioning,
+            received_messages,
+            batch_size_bytes,
+            ack,
+        )
+        .await
+    }
+
+    fn ensure_can_append_messages(&self, session: &Session, topic: &Topic) -> Result<(), Error> {
+        self.permissioner.append_messages(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        )?;
+
+        let replication = &self.config.replication;
+        if replication.enabled
+            && replication.role == ReplicaRole::Follower
+            && topic.replication_factor > 1
+        {
+            return Err(Error::CannotAppendMessagesToFollower(replication.node_id));
+        }
+
+        Ok(())
+    }
+
+    async fn append_received_messages(
+        &self,
+        topic: &Topic,
+        partitioning: &Partitioning,
+        messages: Vec<Message>,
+        batch_size_bytes: u64,
+        ack: AckLevel,
+    ) -> Result<Option<InSyncReplicasAck>, Error> {
         // If there's enough space in cache, do nothing.
         // Otherwise, clean the cache.
         if let Some(memory_tracker) = CacheMemoryTracker::get_instance() {
@@ -141,11 +275,147 @@ impl System {
                 self.clean_cache(batch_size_bytes).await;
             }
         }
-        topic
-            .append_messages(partitioning, received_messages)
+        let messages_count = messages.len() as u64;
+        let in_sync_replicas_ack = topic
+            .append_messages_with_ack(partitioning, messages, ack)
             .await?;
-        self.metrics.increment_messages(messages.len() as u64);
-        Ok(())
+        self.metrics.increment_messages(messages_count);
+        Ok(in_sync_replicas_ack)
+    }
+
+    /// Returns the copy of the message to be sent to the dead-letter topic once it's rejected, if it's enabled.
+    /// The copy is returned before rejecting the message, so that its quota can be acquired without holding the system lock.
+    pub async fn get_dead_letter_message(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+        reason: &str,
+    ) -> Result<Option<Message>, Error> {
+        if !self.config.dead_letter_queue.enabled {
+            return Ok(None);
+        }
+
+        let topic = self.get_topic_to_reject_message(session, stream_id, topic_id)?;
+        if topic.is_message_poisoned(partition_id, offset).await? {
+            return Ok(None);
+        }
+
+        let message = topic.get_message(partition_id, offset).await?;
+        let mut headers = message.headers.clone().unwrap_or_default();
+        headers.extend(Self::get_dead_letter_headers(
+            topic.stream_id,
+            topic.topic_id,
+            partition_id,
+            offset,
+            reason,
+        )?);
+        // The payload is copied as it's stored, so it remains encrypted if the encryption is enabled.
+        Ok(Some(Message::empty(
+            IggyTimestamp::now().to_micros(),
+            MessageState::Available,
+            message.id,
+            message.payload.clone(),
+            message.checksum,
+            Some(headers),
+        )))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn reject_message(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+        reason: &str,
+        dead_letter_message: Option<Message>,
+    ) -> Result<(), Error> {
+        let topic = self.get_topic_to_reject_message(session, stream_id, topic_id)?;
+        // Rejecting the already poisoned message again (e.g. on retry) must not copy it to the dead-letter topic twice.
+        if topic.is_message_poisoned(partition_id, offset).await? {
+            return Ok(());
+        }
+
+        if let Some(dead_letter_message) = dead_letter_message {
+            let dead_letter_stream = self.get_stream(&Identifier::from_str_value(
+                &self.config.dead_letter_queue.stream,
+            )?)?;
+            let dead_letter_topic = dead_letter_stream.get_topic(&Identifier::from_str_value(
+                &self.config.dead_letter_queue.topic,
+            )?)?;
+            // The copy is sent on behalf of the rejecting user, so it's subject to the same checks as any other message.
+            self.ensure_can_append_messages(session, dead_letter_topic)?;
+            let size_bytes = dead_letter_message.get_size_bytes() as u64;
+            self.append_received_messages(
+                dead_letter_topic,
+                &Partitioning::balanced(),
+                vec![dead_letter_message],
+                size_bytes,
+                AckLevel::InMemory,
+            )
+            .await?;
+            info!("Copied rejected message with offset: {offset} from stream: {}, topic: {}, partition: {partition_id} to dead-letter stream: {}, topic: {}.", topic.stream_id, topic.topic_id, dead_letter_topic.stream_id, dead_letter_topic.topic_id);
+        } else {
+            // The message has been already read to prepare its dead-letter copy, otherwise it must be ensured to exist.
+            topic.get_message(partition_id, offset).await?;
+        }
+
+        topic.poison_message(partition_id, offset, reason).await
+    }
+
+    fn get_topic_to_reject_message(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<&Topic, Error> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner.reject_message(
+            session.get_user_id(),
+            stream.stream_id,
+            topic.topic_id,
+        )?;
+        Ok(topic)
+    }
+
+    fn get_dead_letter_headers(
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        offset: u64,
+        reason: &str,
+    ) -> Result<HashMap<HeaderKey, HeaderValue>, Error> {
+        let mut headers = HashMap::from([
+            (
+                HeaderKey::new(DEAD_LETTER_ORIGIN_STREAM_HEADER)?,
+                HeaderValue::from_uint32(stream_id)?,
+            ),
+            (
+                HeaderKey::new(DEAD_LETTER_ORIGIN_TOPIC_HEADER)?,
+                HeaderValue::from_uint32(topic_id)?,
+            ),
+            (
+                HeaderKey::new(DEAD_LETTER_ORIGIN_PARTITION_HEADER)?,
+                HeaderValue::from_uint32(partition_id)?,
+            ),
+            (
+                HeaderKey::new(DEAD_LETTER_ORIGIN_OFFSET_HEADER)?,
+                HeaderValue::from_uint64(offset)?,
+            ),
+        ]);
+        if !reason.is_empty() {
+            headers.insert(
+                HeaderKey::new(DEAD_LETTER_REASON_HEADER)?,
+                HeaderValue::from_str(reason)?,
+            );
+        }
+        Ok(headers)
     }
 }
 
@@ -154,14 +424,37 @@ pub struct PollingArgs {
     pub strategy: PollingStrategy,
     pub count: u32,
     pub auto_commit: bool,
+    pub skip_poisoned: bool,
+    pub generation: Option<u32>,
+    pub zero_copy: bool,
 }
 
 impl PollingArgs {
-    pub fn new(strategy: PollingStrategy, count: u32, auto_commit: bool) -> Self {
+    pub fn new(
+        strategy: PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        skip_poisoned: bool,
+    ) -> Self {
         Self {
             strategy,
             count,
             auto_commit,
+            skip_poisoned,
+            generation: None,
+            zero_copy: false,
         }
     }
+
+    /// Fences the consumer group member, unless it's aware of the current generation of the group.
+    pub fn with_generation(mut self, generation: Option<u32>) -> Self {
+        self.generation = generation;
+        self
+    }
+
+    /// Allows sending the persisted messages directly from the segment log files, without re-encoding them.
+    pub fn with_zero_copy(mut self) -> Self {
+        self.zero_copy = true;
+        self
+    }
 }
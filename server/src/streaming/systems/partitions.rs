@@ -1,7 +1,9 @@
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
+use iggy::command::{CREATE_PARTITIONS, DELETE_PARTITIONS};
 use iggy::error::Error;
 use iggy::identifier::Identifier;
+use iggy::models::partition_offsets::{PartitionOffsets, TimestampOffset};
 
 impl System {
     pub async fn create_partitions(
@@ -10,6 +12,25 @@ impl System {
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitions_count: u32,
+    ) -> Result<(), Error> {
+        let result = self
+            .create_partitions_internal(session, stream_id, topic_id, partitions_count)
+            .await;
+        self.audit(
+            Some(session),
+            CREATE_PARTITIONS,
+            format!("stream ID: {stream_id}, topic ID: {topic_id}, partitions count: {partitions_count}"),
+            result,
+        )
+        .await
+    }
+
+    async fn create_partitions_internal(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         {
@@ -36,6 +57,25 @@ impl System {
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitions_count: u32,
+    ) -> Result<(), Error> {
+        let result = self
+            .delete_partitions_internal(session, stream_id, topic_id, partitions_count)
+            .await;
+        self.audit(
+            Some(session),
+            DELETE_PARTITIONS,
+            format!("stream ID: {stream_id}, topic ID: {topic_id}, partitions count: {partitions_count}"),
+            result,
+        )
+        .await
+    }
+
+    async fn delete_partitions_internal(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         {
@@ -58,4 +98,41 @@ impl System {
         }
         Ok(())
     }
+
+    pub async fn get_partition_offsets(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<PartitionOffsets>, Error> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner.get_partition_offsets(
+            session.get_user_id(),
+            stream.stream_id,
+            topic.topic_id,
+        )?;
+
+        Ok(topic.get_partition_offsets().await)
+    }
+
+    pub async fn get_offsets_for_timestamp(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        timestamp: u64,
+    ) -> Result<Vec<TimestampOffset>, Error> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner.get_partition_offsets(
+            session.get_user_id(),
+            stream.stream_id,
+            topic.topic_id,
+        )?;
+
+        topic.get_offsets_for_timestamp(timestamp).await
+    }
 }
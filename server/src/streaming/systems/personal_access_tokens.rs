@@ -2,6 +2,9 @@ use crate::streaming::personal_access_tokens::personal_access_token::PersonalAcc
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use crate::streaming::users::user::User;
+use iggy::command::{
+    CREATE_PERSONAL_ACCESS_TOKEN, DELETE_PERSONAL_ACCESS_TOKEN, LOGIN_WITH_PERSONAL_ACCESS_TOKEN,
+};
 use iggy::error::Error;
 use iggy::utils::text;
 use iggy::utils::timestamp::IggyTimestamp;
@@ -32,6 +35,24 @@ impl System {
         session: &Session,
         name: &str,
         expiry: Option<u32>,
+    ) -> Result<String, Error> {
+        let result = self
+            .create_personal_access_token_internal(session, name, expiry)
+            .await;
+        self.audit(
+            Some(session),
+            CREATE_PERSONAL_ACCESS_TOKEN,
+            format!("name: {name}, expiry: {expiry:?}"),
+            result,
+        )
+        .await
+    }
+
+    async fn create_personal_access_token_internal(
+        &self,
+        session: &Session,
+        name: &str,
+        expiry: Option<u32>,
     ) -> Result<String, Error> {
         self.ensure_authenticated(session)?;
         let user_id = session.get_user_id();
@@ -76,6 +97,23 @@ impl System {
         &self,
         session: &Session,
         name: &str,
+    ) -> Result<(), Error> {
+        let result = self
+            .delete_personal_access_token_internal(session, name)
+            .await;
+        self.audit(
+            Some(session),
+            DELETE_PERSONAL_ACCESS_TOKEN,
+            format!("name: {name}"),
+            result,
+        )
+        .await
+    }
+
+    async fn delete_personal_access_token_internal(
+        &self,
+        session: &Session,
+        name: &str,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         let user_id = session.get_user_id();
@@ -94,6 +132,24 @@ impl System {
         token: &str,
         session: Option<&Session>,
     ) -> Result<User, Error> {
+        let result = self
+            .login_with_personal_access_token_internal(token, session)
+            .await;
+        let details = match &result {
+            Ok(user) => format!("username: {}", user.username),
+            Err(_) => "".to_string(),
+        };
+        self.audit(session, LOGIN_WITH_PERSONAL_ACCESS_TOKEN, details, result)
+            .await
+    }
+
+    async fn login_with_personal_access_token_internal(
+        &self,
+        token: &str,
+        session: Option<&Session>,
+    ) -> Result<User, Error> {
+        self.login_attempts
+            .ensure_ip_address_allowed(session.map(|session| session.ip_address.ip()))?;
         let token_hash = PersonalAccessToken::hash_token(token);
         let personal_access_token = self
             .storage
@@ -0,0 +1,191 @@
+use crate::streaming::roles::role::Role;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::command::{CREATE_ROLE, DELETE_ROLE, UPDATE_ROLE};
+use iggy::error::Error;
+use iggy::identifier::{IdKind, Identifier};
+use iggy::models::permissions::Permissions;
+use iggy::utils::text;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tracing::error;
+use tracing::info;
+
+static ROLE_ID: AtomicU32 = AtomicU32::new(1);
+
+impl System {
+    pub(crate) async fn load_roles(&self) -> Result<Vec<Role>, Error> {
+        info!("Loading roles...");
+        let roles = self.storage.role.load_all().await?;
+        let current_role_id = roles.iter().map(|role| role.id).max().unwrap_or(0);
+        ROLE_ID.store(current_role_id + 1, Ordering::SeqCst);
+        info!("Loaded {} role(s).", roles.len());
+        Ok(roles)
+    }
+
+    pub async fn find_role(&self, session: &Session, role_id: &Identifier) -> Result<Role, Error> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.get_role(session.get_user_id())?;
+        self.get_role(role_id).await
+    }
+
+    pub async fn get_role(&self, role_id: &Identifier) -> Result<Role, Error> {
+        Ok(match role_id.kind {
+            IdKind::Numeric => {
+                self.storage
+                    .role
+                    .load_by_id(role_id.get_u32_value()?)
+                    .await?
+            }
+            IdKind::String => {
+                self.storage
+                    .role
+                    .load_by_name(&role_id.get_string_value()?)
+                    .await?
+            }
+        })
+    }
+
+    pub async fn get_roles(&self, session: &Session) -> Result<Vec<Role>, Error> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.get_roles(session.get_user_id())?;
+        self.storage.role.load_all().await
+    }
+
+    pub async fn create_role(
+        &mut self,
+        session: &Session,
+        name: &str,
+        permissions: Permissions,
+    ) -> Result<(), Error> {
+        let result = self.create_role_internal(session, name, permissions).await;
+        self.audit(Some(session), CREATE_ROLE, format!("name: {name}"), result)
+            .await
+    }
+
+    async fn create_role_internal(
+        &mut self,
+        session: &Session,
+        name: &str,
+        permissions: Permissions,
+    ) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.create_role(session.get_user_id())?;
+        let name = text::to_lowercase_non_whitespace(name);
+        if self.storage.role.load_by_name(&name).await.is_ok() {
+            error!("Role: {name} already exists.");
+            return Err(Error::RoleAlreadyExists(name));
+        }
+
+        let role_id = ROLE_ID.fetch_add(1, Ordering::SeqCst);
+        info!("Creating role: {name} with ID: {role_id}...");
+        let role = Role::new(role_id, &name, permissions);
+        self.storage.role.save(&role).await?;
+        self.permissioner
+            .update_permissions_for_role(role.id, role.permissions);
+        info!("Created role: {name} with ID: {role_id}.");
+        Ok(())
+    }
+
+    pub async fn update_role(
+        &mut self,
+        session: &Session,
+        role_id: &Identifier,
+        name: Option<String>,
+        permissions: Option<Permissions>,
+    ) -> Result<Role, Error> {
+        let mut details = format!("role ID: {role_id}");
+        if let Some(name) = &name {
+            details.push_str(&format!(", name: {name}"));
+        }
+        if let Some(permissions) = &permissions {
+            details.push_str(&format!(", permissions: {permissions:?}"));
+        }
+        let result = self
+            .update_role_internal(session, role_id, name, permissions)
+            .await;
+        self.audit(Some(session), UPDATE_ROLE, details, result)
+            .await
+    }
+
+    async fn update_role_internal(
+        &mut self,
+        session: &Session,
+        role_id: &Identifier,
+        name: Option<String>,
+        permissions: Option<Permissions>,
+    ) -> Result<Role, Error> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.update_role(session.get_user_id())?;
+        let mut role = self.get_role(role_id).await?;
+        let mut previous_name = None;
+        if let Some(name) = name {
+            let name = text::to_lowercase_non_whitespace(&name);
+            let existing_role = self.storage.role.load_by_name(&name).await;
+            if existing_role.is_ok() && existing_role.unwrap().id != role.id {
+                error!("Role: {name} already exists.");
+                return Err(Error::RoleAlreadyExists(name));
+            }
+            if role.name != name {
+                previous_name = Some(std::mem::replace(&mut role.name, name));
+            }
+        }
+
+        if let Some(permissions) = permissions {
+            role.permissions = permissions;
+        }
+
+        info!("Updating role: {} with ID: {}...", role.name, role.id);
+        // The role is saved under the new name first, so the failure can't leave it without any name.
+        self.storage.role.save(&role).await?;
+        if let Some(previous_name) = previous_name {
+            self.storage.role.delete_name(&previous_name).await?;
+        }
+        self.permissioner
+            .update_permissions_for_role(role.id, role.permissions.clone());
+        info!("Updated role: {} with ID: {}.", role.name, role.id);
+        Ok(role)
+    }
+
+    pub async fn delete_role(
+        &mut self,
+        session: &Session,
+        role_id: &Identifier,
+    ) -> Result<Role, Error> {
+        let result = self.delete_role_internal(session, role_id).await;
+        self.audit(
+            Some(session),
+            DELETE_ROLE,
+            format!("role ID: {role_id}"),
+            result,
+        )
+        .await
+    }
+
+    async fn delete_role_internal(
+        &mut self,
+        session: &Session,
+        role_id: &Identifier,
+    ) -> Result<Role, Error> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.delete_role(session.get_user_id())?;
+        let role = self.get_role(role_id).await?;
+        info!("Deleting role: {} with ID: {}...", role.name, role.id);
+        for mut user in self.storage.user.load_all().await? {
+            if !user.roles.contains(&role.id) {
+                continue;
+            }
+
+            user.roles.retain(|id| *id != role.id);
+            self.storage.user.save(&user).await?;
+            info!(
+                "Unassigned role: {} from user: {} with ID: {}.",
+                role.name, user.username, user.id
+            );
+        }
+
+        self.storage.role.delete(&role).await?;
+        self.permissioner.delete_permissions_for_role(role.id);
+        info!("Deleted role: {} with ID: {}.", role.name, role.id);
+        Ok(role)
+    }
+}
@@ -1,9 +1,9 @@
+use crate::streaming::metadata::database::Database;
 use crate::streaming::storage::{Storage, SystemInfoStorage};
 use crate::streaming::systems::info::SystemInfo;
 use anyhow::Context;
 use async_trait::async_trait;
 use iggy::error::Error;
-use sled::Db;
 use std::sync::Arc;
 use tracing::info;
 
@@ -11,11 +11,11 @@ const KEY: &str = "system";
 
 #[derive(Debug)]
 pub struct FileSystemInfoStorage {
-    db: Arc<Db>,
+    db: Arc<dyn Database>,
 }
 
 impl FileSystemInfoStorage {
-    pub fn new(db: Arc<Db>) -> Self {
+    pub fn new(db: Arc<dyn Database>) -> Self {
         Self { db }
     }
 }
@@ -61,7 +61,7 @@ impl Storage<SystemInfo> for FileSystemInfoStorage {
             Ok(data) => {
                 if let Err(err) = self
                     .db
-                    .insert(KEY, data)
+                    .insert(KEY, &data)
                     .with_context(|| "Failed to save system info")
                 {
                     return Err(Error::CannotSaveResource(err));
@@ -2,6 +2,7 @@ use crate::streaming::session::Session;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::system::System;
 use futures::future::try_join_all;
+use iggy::command::{CREATE_STREAM, DELETE_STREAM, PURGE_STREAM, UPDATE_STREAM};
 use iggy::error::Error;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::utils::text;
@@ -155,6 +156,22 @@ impl System {
         session: &Session,
         stream_id: u32,
         name: &str,
+    ) -> Result<(), Error> {
+        let result = self.create_stream_internal(session, stream_id, name).await;
+        self.audit(
+            Some(session),
+            CREATE_STREAM,
+            format!("stream ID: {stream_id}, name: {name}"),
+            result,
+        )
+        .await
+    }
+
+    async fn create_stream_internal(
+        &mut self,
+        session: &Session,
+        stream_id: u32,
+        name: &str,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         self.permissioner.create_stream(session.get_user_id())?;
@@ -181,6 +198,22 @@ impl System {
         session: &Session,
         id: &Identifier,
         name: &str,
+    ) -> Result<(), Error> {
+        let result = self.update_stream_internal(session, id, name).await;
+        self.audit(
+            Some(session),
+            UPDATE_STREAM,
+            format!("stream ID: {id}, name: {name}"),
+            result,
+        )
+        .await
+    }
+
+    async fn update_stream_internal(
+        &mut self,
+        session: &Session,
+        id: &Identifier,
+        name: &str,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         let stream_id;
@@ -225,6 +258,21 @@ impl System {
         &mut self,
         session: &Session,
         id: &Identifier,
+    ) -> Result<u32, Error> {
+        let result = self.delete_stream_internal(session, id).await;
+        self.audit(
+            Some(session),
+            DELETE_STREAM,
+            format!("stream ID: {id}"),
+            result,
+        )
+        .await
+    }
+
+    async fn delete_stream_internal(
+        &mut self,
+        session: &Session,
+        id: &Identifier,
     ) -> Result<u32, Error> {
         self.ensure_authenticated(session)?;
         let stream = self.get_stream(id)?;
@@ -258,6 +306,21 @@ impl System {
         &self,
         session: &Session,
         stream_id: &Identifier,
+    ) -> Result<(), Error> {
+        let result = self.purge_stream_internal(session, stream_id).await;
+        self.audit(
+            Some(session),
+            PURGE_STREAM,
+            format!("stream ID: {stream_id}"),
+            result,
+        )
+        .await
+    }
+
+    async fn purge_stream_internal(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
     ) -> Result<(), Error> {
         let stream = self.get_stream(stream_id)?;
         self.permissioner
@@ -271,6 +334,7 @@ mod tests {
     use super::*;
     use crate::configs::server::PersonalAccessTokenConfig;
     use crate::configs::system::SystemConfig;
+    use crate::streaming::clients::client_manager::Transport;
     use crate::streaming::storage::tests::get_test_system_storage;
     use crate::streaming::users::user::User;
     use std::{
@@ -291,6 +355,7 @@ mod tests {
             1,
             root.id,
             SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+            Transport::Tcp,
         );
         system.permissioner.init_permissions_for_user(root);
         system
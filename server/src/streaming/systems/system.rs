@@ -1,16 +1,21 @@
 use crate::configs::server::PersonalAccessTokenConfig;
 use crate::configs::system::SystemConfig;
+use crate::streaming::audit::audit_log::AuditLog;
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::clients::client_manager::ClientManager;
 use crate::streaming::diagnostics::metrics::Metrics;
+use crate::streaming::metadata::database::{open_database, Database};
 use crate::streaming::persistence::persister::*;
+use crate::streaming::quotas::quota_manager::QuotaManager;
 use crate::streaming::session::Session;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::streams::stream::Stream;
+use crate::streaming::tiering::storage::create_tiered_storage;
+use crate::streaming::users::login_attempts::LoginAttempts;
 use crate::streaming::users::permissioner::Permissioner;
 use iggy::error::Error;
 use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
-use sled::Db;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
@@ -60,7 +65,10 @@ pub struct System {
     pub(crate) client_manager: Arc<RwLock<ClientManager>>,
     pub(crate) encryptor: Option<Box<dyn Encryptor>>,
     pub(crate) metrics: Metrics,
-    pub(crate) db: Option<Arc<Db>>,
+    pub(crate) db: Option<Arc<dyn Database>>,
+    pub(crate) audit_log: AuditLog,
+    pub(crate) quota_manager: Arc<QuotaManager>,
+    pub(crate) login_attempts: LoginAttempts,
     pub personal_access_token: PersonalAccessTokenConfig,
 }
 
@@ -71,35 +79,32 @@ const CACHE_OVER_EVICTION_FACTOR: u64 = 5;
 impl System {
     pub fn new(
         config: Arc<SystemConfig>,
-        db: Option<Arc<Db>>,
+        db: Option<Arc<dyn Database>>,
         pat_config: PersonalAccessTokenConfig,
     ) -> System {
         let db = match db {
             Some(db) => db,
             None => {
-                let db = sled::open(config.get_database_path());
+                let db = open_database(config.database.backend, &config.get_database_path());
                 if db.is_err() {
                     panic!("Cannot open database at: {}", config.get_database_path());
                 }
-                Arc::new(db.unwrap())
+                db.unwrap()
             }
         };
         let persister: Arc<dyn Persister> = match config.partition.enforce_fsync {
             true => Arc::new(FileWithSyncPersister {}),
             false => Arc::new(FilePersister {}),
         };
-        Self::create(
-            config,
-            SystemStorage::new(db.clone(), persister),
-            Some(db),
-            pat_config,
-        )
+        let mut storage = SystemStorage::new(db.clone(), persister);
+        storage.tiered = create_tiered_storage(&config.tiered_storage);
+        Self::create(config, storage, Some(db), pat_config)
     }
 
     pub fn create(
         config: Arc<SystemConfig>,
         storage: SystemStorage,
-        db: Option<Arc<Db>>,
+        db: Option<Arc<dyn Database>>,
         pat_config: PersonalAccessTokenConfig,
     ) -> System {
         info!(
@@ -113,6 +118,9 @@ impl System {
                 )),
                 false => None,
             },
+            audit_log: AuditLog::new(config.audit_log.enabled, &config.get_audit_log_path()),
+            quota_manager: Arc::new(QuotaManager::new(&config.quotas)),
+            login_attempts: LoginAttempts::new(&config.login_attempts),
             config,
             streams: HashMap::new(),
             streams_ids: HashMap::new(),
@@ -151,6 +159,7 @@ impl System {
             self.config.get_system_path()
         );
         let now = Instant::now();
+        self.audit_log.init();
         self.load_version().await?;
         self.load_users().await?;
         self.load_streams().await?;
@@ -160,6 +169,9 @@ impl System {
 
     pub async fn shutdown(&mut self) -> Result<(), Error> {
         self.persist_messages().await?;
+        if let Some(db) = &self.db {
+            db.flush().map_err(Error::CannotSaveResource)?;
+        }
         Ok(())
     }
 
@@ -172,6 +184,15 @@ impl System {
         Ok(())
     }
 
+    pub async fn persist_messages_exceeding_max_latency(&self) -> Result<(), Error> {
+        let now = IggyTimestamp::now().to_micros();
+        for stream in self.streams.values() {
+            stream.persist_messages_exceeding_max_latency(now).await?;
+        }
+
+        Ok(())
+    }
+
     pub fn ensure_authenticated(&self, session: &Session) -> Result<(), Error> {
         match session.is_authenticated() {
             true => Ok(()),
@@ -1,8 +1,11 @@
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use crate::streaming::topics::topic::Topic;
+use iggy::command::{CREATE_TOPIC, DELETE_TOPIC, PURGE_TOPIC, UPDATE_TOPIC};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::utils::byte_size::IggyByteSize;
 
 impl System {
@@ -43,6 +46,51 @@ impl System {
         message_expiry: Option<u32>,
         max_topic_size: Option<IggyByteSize>,
         replication_factor: u8,
+        compression_algorithm: Option<CompressionAlgorithm>,
+        cleanup_policy: CleanupPolicy,
+        max_topic_size_policy: MaxTopicSizePolicy,
+        flush_policy: FlushPolicy,
+    ) -> Result<(), Error> {
+        let result = self
+            .create_topic_internal(
+                session,
+                stream_id,
+                topic_id,
+                name,
+                partitions_count,
+                message_expiry,
+                max_topic_size,
+                replication_factor,
+                compression_algorithm,
+                cleanup_policy,
+                max_topic_size_policy,
+                flush_policy,
+            )
+            .await;
+        self.audit(
+            Some(session),
+            CREATE_TOPIC,
+            format!("stream ID: {stream_id}, topic ID: {topic_id}, name: {name}, partitions count: {partitions_count}"),
+            result,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn create_topic_internal(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: u32,
+        name: &str,
+        partitions_count: u32,
+        message_expiry: Option<u32>,
+        max_topic_size: Option<IggyByteSize>,
+        replication_factor: u8,
+        compression_algorithm: Option<CompressionAlgorithm>,
+        cleanup_policy: CleanupPolicy,
+        max_topic_size_policy: MaxTopicSizePolicy,
+        flush_policy: FlushPolicy,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         {
@@ -59,6 +107,10 @@ impl System {
                 message_expiry,
                 max_topic_size,
                 replication_factor,
+                compression_algorithm,
+                cleanup_policy,
+                max_topic_size_policy,
+                flush_policy,
             )
             .await?;
         self.metrics.increment_topics(1);
@@ -77,6 +129,49 @@ impl System {
         message_expiry: Option<u32>,
         max_topic_size: Option<IggyByteSize>,
         replication_factor: u8,
+        compression_algorithm: Option<CompressionAlgorithm>,
+        cleanup_policy: Option<CleanupPolicy>,
+        max_topic_size_policy: Option<MaxTopicSizePolicy>,
+        flush_policy: Option<FlushPolicy>,
+    ) -> Result<(), Error> {
+        let result = self
+            .update_topic_internal(
+                session,
+                stream_id,
+                topic_id,
+                name,
+                message_expiry,
+                max_topic_size,
+                replication_factor,
+                compression_algorithm,
+                cleanup_policy,
+                max_topic_size_policy,
+                flush_policy,
+            )
+            .await;
+        self.audit(
+            Some(session),
+            UPDATE_TOPIC,
+            format!("stream ID: {stream_id}, topic ID: {topic_id}, name: {name}"),
+            result,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn update_topic_internal(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        message_expiry: Option<u32>,
+        max_topic_size: Option<IggyByteSize>,
+        replication_factor: u8,
+        compression_algorithm: Option<CompressionAlgorithm>,
+        cleanup_policy: Option<CleanupPolicy>,
+        max_topic_size_policy: Option<MaxTopicSizePolicy>,
+        flush_policy: Option<FlushPolicy>,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         {
@@ -96,6 +191,10 @@ impl System {
                 message_expiry,
                 max_topic_size,
                 replication_factor,
+                compression_algorithm,
+                cleanup_policy,
+                max_topic_size_policy,
+                flush_policy,
             )
             .await?;
 
@@ -110,6 +209,24 @@ impl System {
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
+    ) -> Result<(), Error> {
+        let result = self
+            .delete_topic_internal(session, stream_id, topic_id)
+            .await;
+        self.audit(
+            Some(session),
+            DELETE_TOPIC,
+            format!("stream ID: {stream_id}, topic ID: {topic_id}"),
+            result,
+        )
+        .await
+    }
+
+    async fn delete_topic_internal(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         let stream_id_value;
@@ -147,6 +264,24 @@ impl System {
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
+    ) -> Result<(), Error> {
+        let result = self
+            .purge_topic_internal(session, stream_id, topic_id)
+            .await;
+        self.audit(
+            Some(session),
+            PURGE_TOPIC,
+            format!("stream ID: {stream_id}, topic ID: {topic_id}"),
+            result,
+        )
+        .await
+    }
+
+    async fn purge_topic_internal(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
     ) -> Result<(), Error> {
         let stream = self.get_stream(stream_id)?;
         let topic = stream.get_topic(topic_id)?;
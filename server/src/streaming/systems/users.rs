@@ -2,6 +2,10 @@ use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use crate::streaming::users::user::User;
 use crate::streaming::utils::crypto;
+use iggy::command::{
+    ASSIGN_ROLES, CHANGE_PASSWORD, CREATE_USER, DELETE_USER, LOGIN_USER, LOGOUT_USER, UNLOCK_USER,
+    UPDATE_PERMISSIONS, UPDATE_USER,
+};
 use iggy::error::Error;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::models::permissions::Permissions;
@@ -15,6 +19,7 @@ static USER_ID: AtomicU32 = AtomicU32::new(1);
 
 impl System {
     pub(crate) async fn load_users(&mut self) -> Result<(), Error> {
+        let roles = self.load_roles().await?;
         info!("Loading users...");
         let mut users = self.storage.user.load_all().await?;
         if users.is_empty() {
@@ -28,7 +33,7 @@ impl System {
         let users_count = users.len();
         let current_user_id = users.iter().map(|user| user.id).max().unwrap_or(1);
         USER_ID.store(current_user_id + 1, Ordering::SeqCst);
-        self.permissioner.init(users);
+        self.permissioner.init(users, roles);
         info!("Initialized {} user(s).", users_count);
         Ok(())
     }
@@ -74,6 +79,26 @@ impl System {
         password: &str,
         status: UserStatus,
         permissions: Option<Permissions>,
+    ) -> Result<(), Error> {
+        let result = self
+            .create_user_internal(session, username, password, status, permissions)
+            .await;
+        self.audit(
+            Some(session),
+            CREATE_USER,
+            format!("username: {username}, status: {status}"),
+            result,
+        )
+        .await
+    }
+
+    async fn create_user_internal(
+        &mut self,
+        session: &Session,
+        username: &str,
+        password: &str,
+        status: UserStatus,
+        permissions: Option<Permissions>,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         self.permissioner.create_user(session.get_user_id())?;
@@ -96,6 +121,21 @@ impl System {
         &mut self,
         session: &Session,
         user_id: &Identifier,
+    ) -> Result<User, Error> {
+        let result = self.delete_user_internal(session, user_id).await;
+        self.audit(
+            Some(session),
+            DELETE_USER,
+            format!("user ID: {user_id}"),
+            result,
+        )
+        .await
+    }
+
+    async fn delete_user_internal(
+        &mut self,
+        session: &Session,
+        user_id: &Identifier,
     ) -> Result<User, Error> {
         self.ensure_authenticated(session)?;
         self.permissioner.delete_user(session.get_user_id())?;
@@ -121,6 +161,27 @@ impl System {
         user_id: &Identifier,
         username: Option<String>,
         status: Option<UserStatus>,
+    ) -> Result<User, Error> {
+        let mut details = format!("user ID: {user_id}");
+        if let Some(username) = &username {
+            details.push_str(&format!(", username: {username}"));
+        }
+        if let Some(status) = status {
+            details.push_str(&format!(", status: {status}"));
+        }
+        let result = self
+            .update_user_internal(session, user_id, username, status)
+            .await;
+        self.audit(Some(session), UPDATE_USER, details, result)
+            .await
+    }
+
+    async fn update_user_internal(
+        &self,
+        session: &Session,
+        user_id: &Identifier,
+        username: Option<String>,
+        status: Option<UserStatus>,
     ) -> Result<User, Error> {
         self.ensure_authenticated(session)?;
         self.permissioner.update_user(session.get_user_id())?;
@@ -151,6 +212,20 @@ impl System {
         session: &Session,
         user_id: &Identifier,
         permissions: Option<Permissions>,
+    ) -> Result<(), Error> {
+        let details = format!("user ID: {user_id}, permissions: {permissions:?}");
+        let result = self
+            .update_permissions_internal(session, user_id, permissions)
+            .await;
+        self.audit(Some(session), UPDATE_PERMISSIONS, details, result)
+            .await
+    }
+
+    async fn update_permissions_internal(
+        &mut self,
+        session: &Session,
+        user_id: &Identifier,
+        permissions: Option<Permissions>,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         self.permissioner
@@ -176,12 +251,114 @@ impl System {
         Ok(())
     }
 
+    pub async fn assign_roles(
+        &mut self,
+        session: &Session,
+        user_id: &Identifier,
+        roles: &[Identifier],
+    ) -> Result<(), Error> {
+        let roles_list = roles
+            .iter()
+            .map(|role| role.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let details = format!("user ID: {user_id}, roles: [{roles_list}]");
+        let result = self.assign_roles_internal(session, user_id, roles).await;
+        self.audit(Some(session), ASSIGN_ROLES, details, result)
+            .await
+    }
+
+    pub async fn unlock_user(&self, session: &Session, user_id: &Identifier) -> Result<(), Error> {
+        let result = self.unlock_user_internal(session, user_id).await;
+        self.audit(
+            Some(session),
+            UNLOCK_USER,
+            format!("user ID: {user_id}"),
+            result,
+        )
+        .await
+    }
+
+    async fn unlock_user_internal(
+        &self,
+        session: &Session,
+        user_id: &Identifier,
+    ) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.unlock_user(session.get_user_id())?;
+        let user = self.get_user(user_id).await?;
+        if self.login_attempts.unlock(&user.username) {
+            info!("Unlocked user: {} with ID: {}.", user.username, user.id);
+        } else {
+            info!(
+                "Reset failed login attempts for user: {} with ID: {}.",
+                user.username, user.id
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the timestamp until which the user is locked due to too many failed login attempts, if any.
+    pub fn get_user_locked_until(&self, user: &User) -> Option<u64> {
+        self.login_attempts.get_locked_until(&user.username)
+    }
+
+    async fn assign_roles_internal(
+        &mut self,
+        session: &Session,
+        user_id: &Identifier,
+        roles: &[Identifier],
+    ) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.assign_roles(session.get_user_id())?;
+        let mut user = self.get_user(user_id).await?;
+        if user.is_root() {
+            error!("Cannot assign roles to the root user.");
+            return Err(Error::CannotChangePermissions(user.id));
+        }
+
+        let mut role_ids = Vec::with_capacity(roles.len());
+        for role_id in roles {
+            let role = self.get_role(role_id).await?;
+            if !role_ids.contains(&role.id) {
+                role_ids.push(role.id);
+            }
+        }
+
+        user.roles = role_ids;
+        let username = user.username.clone();
+        info!("Assigning roles for user: {username} with ID: {user_id}...");
+        self.storage.user.save(&user).await?;
+        self.permissioner.update_permissions_for_user(user);
+        info!("Assigned roles for user: {username} with ID: {user_id}.");
+        Ok(())
+    }
+
     pub async fn change_password(
         &self,
         session: &Session,
         user_id: &Identifier,
         current_password: &str,
         new_password: &str,
+    ) -> Result<(), Error> {
+        let result = self
+            .change_password_internal(session, user_id, current_password, new_password)
+            .await;
+        self.audit(
+            Some(session),
+            CHANGE_PASSWORD,
+            format!("user ID: {user_id}"),
+            result,
+        )
+        .await
+    }
+
+    async fn change_password_internal(
+        &self,
+        session: &Session,
+        user_id: &Identifier,
+        current_password: &str,
+        new_password: &str,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         let mut user = self.get_user(user_id).await?;
@@ -217,10 +394,60 @@ impl System {
         password: &str,
         session: Option<&Session>,
     ) -> Result<User, Error> {
-        self.login_user_with_credentials(username, Some(password), session)
+        let result = self
+            .login_user_with_password(username, password, session)
+            .await;
+        self.audit(session, LOGIN_USER, format!("username: {username}"), result)
             .await
     }
 
+    async fn login_user_with_password(
+        &self,
+        username: &str,
+        password: &str,
+        session: Option<&Session>,
+    ) -> Result<User, Error> {
+        let ip_address = session.map(|session| session.ip_address.ip());
+        self.login_attempts.ensure_allowed(username, ip_address)?;
+        let result = self
+            .login_user_with_credentials(username, Some(password), session)
+            .await;
+        match result {
+            Ok(_) => self.login_attempts.record_success(username),
+            Err(Error::InvalidCredentials) => {
+                self.login_attempts.record_failure(username, ip_address)
+            }
+            Err(_) => {}
+        }
+        result
+    }
+
+    pub async fn login_user_with_certificate(
+        &self,
+        username: &str,
+        session: &Session,
+    ) -> Result<User, Error> {
+        info!("Logging in user: {username} with the client certificate...");
+        // The certificate is already verified, so only the blocked IP address is rejected, the username lockout applies to the passwords.
+        let result = match self
+            .login_attempts
+            .ensure_ip_address_allowed(Some(session.ip_address.ip()))
+        {
+            Ok(()) => {
+                self.login_user_with_credentials(username, None, Some(session))
+                    .await
+            }
+            Err(error) => Err(error),
+        };
+        self.audit(
+            Some(session),
+            LOGIN_USER,
+            format!("username: {username}, client certificate"),
+            result,
+        )
+        .await
+    }
+
     pub async fn login_user_with_credentials(
         &self,
         username: &str,
@@ -267,14 +494,22 @@ impl System {
         }
 
         session.set_user_id(user.id);
-        let mut client_manager = self.client_manager.write().await;
-        client_manager
-            .set_user_id(session.client_id, user.id)
-            .await?;
+        if session.client_id > 0 {
+            let mut client_manager = self.client_manager.write().await;
+            client_manager
+                .set_user_id(session.client_id, user.id)
+                .await?;
+        }
         Ok(user)
     }
 
     pub async fn logout_user(&self, session: &Session) -> Result<(), Error> {
+        let result = self.logout_user_internal(session).await;
+        self.audit(Some(session), LOGOUT_USER, "".to_string(), result)
+            .await
+    }
+
+    async fn logout_user_internal(&self, session: &Session) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         let user = self
             .get_user(&Identifier::numeric(session.get_user_id())?)
@@ -0,0 +1,76 @@
+use crate::streaming::tiering::storage::TieredStorage;
+use async_trait::async_trait;
+use iggy::error::Error;
+use std::io::ErrorKind;
+use std::path::Path;
+use tokio::fs;
+use tracing::error;
+
+#[derive(Debug)]
+pub struct LocalTieredStorage {
+    path: String,
+}
+
+impl LocalTieredStorage {
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+        }
+    }
+
+    fn get_path(&self, key: &str) -> String {
+        format!("{}/{}", self.path, key)
+    }
+}
+
+#[async_trait]
+impl TieredStorage for LocalTieredStorage {
+    async fn upload(&self, key: &str, path: &str) -> Result<(), Error> {
+        let remote_path = self.get_path(key);
+        if let Some(directory) = Path::new(&remote_path).parent() {
+            if let Err(err) = fs::create_dir_all(directory).await {
+                error!(
+                    "Cannot create tiered storage directory for key: {}. Error: {}",
+                    key, err
+                );
+                return Err(Error::CannotUploadSegmentFile(path.to_string()));
+            }
+        }
+
+        if let Err(err) = fs::copy(path, &remote_path).await {
+            error!(
+                "Cannot upload segment file: {} with key: {}. Error: {}",
+                path, key, err
+            );
+            return Err(Error::CannotUploadSegmentFile(path.to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn download(&self, key: &str, path: &str) -> Result<(), Error> {
+        if let Err(err) = fs::copy(self.get_path(key), path).await {
+            error!(
+                "Cannot download segment file: {} with key: {}. Error: {}",
+                path, key, err
+            );
+            return Err(Error::CannotDownloadSegmentFile(path.to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), Error> {
+        match fs::remove_file(self.get_path(key)).await {
+            Ok(_) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => {
+                error!(
+                    "Cannot delete segment file with key: {}. Error: {}",
+                    key, err
+                );
+                Err(Error::CannotDeleteRemoteSegmentFile(key.to_string()))
+            }
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use crate::configs::system::S3TieredStorageConfig;
+use crate::streaming::tiering::storage::TieredStorage;
+use async_trait::async_trait;
+use chrono::Utc;
+use futures::StreamExt;
+use iggy::error::Error;
+use reqwest::header::CONTENT_LENGTH;
+use reqwest::{Body, Client, Method, StatusCode, Url};
+use ring::{digest, hmac};
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio_util::io::ReaderStream;
+use tracing::error;
+
+const ALGORITHM: &str = "AWS4-HMAC-SHA256";
+const SERVICE: &str = "s3";
+const EMPTY_PAYLOAD_HASH: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+/// The S3-compatible object store (e.g. AWS S3 or MinIO) addressed with the path-style URLs,
+/// the requests are signed using the AWS Signature Version 4.
+#[derive(Debug)]
+pub struct S3TieredStorage {
+    client: Client,
+    endpoint: String,
+    region: String,
+    bucket: String,
+    access_key_id: String,
+    secret_access_key: String,
+}
+
+impl S3TieredStorage {
+    pub fn new(config: &S3TieredStorageConfig) -> Self {
+        Self {
+            client: Client::new(),
+            endpoint: config.endpoint.trim_end_matches('/').to_string(),
+            region: config.region.to_string(),
+            bucket: config.bucket.to_string(),
+            access_key_id: config.access_key_id.to_string(),
+            secret_access_key: config.secret_access_key.to_string(),
+        }
+    }
+
+    fn get_url(&self, key: &str) -> Result<Url, Error> {
+        Url::parse(&format!("{}/{}/{}", self.endpoint, self.bucket, key)).map_err(|err| {
+            error!("Invalid S3 object URL for key: {}. Error: {}", key, err);
+            Error::CannotParseUrl
+        })
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        url: Url,
+        payload_hash: &str,
+        content_length: u64,
+        body: Body,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let host = match url.port() {
+            Some(port) => format!("{}:{}", url.host_str().unwrap_or_default(), port),
+            None => url.host_str().unwrap_or_default().to_string(),
+        };
+        let now = Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let headers = [
+            ("host", host.as_str()),
+            ("x-amz-content-sha256", payload_hash),
+            ("x-amz-date", amz_date.as_str()),
+        ];
+        let signature = sign(
+            &self.secret_access_key,
+            &self.region,
+            &amz_date,
+            method.as_str(),
+            url.path(),
+            &headers,
+            payload_hash,
+        );
+        let authorization = format!(
+            "{} Credential={}/{}/{}/{}/aws4_request, SignedHeaders={}, Signature={}",
+            ALGORITHM,
+            self.access_key_id,
+            &amz_date[..8],
+            self.region,
+            SERVICE,
+            get_signed_headers(&headers),
+            signature
+        );
+
+        self.client
+            .request(method, url)
+            .header("x-amz-content-sha256", payload_hash)
+            .header("x-amz-date", amz_date)
+            .header("authorization", authorization)
+            .header(CONTENT_LENGTH, content_length)
+            .body(body)
+            .send()
+            .await
+    }
+}
+
+#[async_trait]
+impl TieredStorage for S3TieredStorage {
+    async fn upload(&self, key: &str, path: &str) -> Result<(), Error> {
+        let url = self.get_url(key)?;
+        // The segment file is hashed and then sent in chunks, so it's never loaded into memory as a whole.
+        let payload_hash = hash_file(path).await?;
+        let file = File::open(path).await?;
+        let content_length = file.metadata().await?.len();
+        let body = Body::wrap_stream(ReaderStream::new(file));
+        match self
+            .send(Method::PUT, url, &payload_hash, content_length, body)
+            .await
+        {
+            Ok(response) if response.status().is_success() => Ok(()),
+            Ok(response) => {
+                error!(
+                    "Cannot upload segment file: {} with key: {}, S3 responded with status: {}.",
+                    path,
+                    key,
+                    response.status()
+                );
+                Err(Error::CannotUploadSegmentFile(path.to_string()))
+            }
+            Err(err) => {
+                error!(
+                    "Cannot upload segment file: {} with key: {}. Error: {}",
+                    path, key, err
+                );
+                Err(Error::CannotUploadSegmentFile(path.to_string()))
+            }
+        }
+    }
+
+    async fn download(&self, key: &str, path: &str) -> Result<(), Error> {
+        let url = self.get_url(key)?;
+        let response = match self
+            .send(Method::GET, url, EMPTY_PAYLOAD_HASH, 0, Body::from(""))
+            .await
+        {
+            Ok(response) if response.status().is_success() => response,
+            Ok(response) => {
+                error!(
+                    "Cannot download segment file: {} with key: {}, S3 responded with status: {}.",
+                    path,
+                    key,
+                    response.status()
+                );
+                return Err(Error::CannotDownloadSegmentFile(path.to_string()));
+            }
+            Err(err) => {
+                error!(
+                    "Cannot download segment file: {} with key: {}. Error: {}",
+                    path, key, err
+                );
+                return Err(Error::CannotDownloadSegmentFile(path.to_string()));
+            }
+        };
+
+        let mut file = File::create(path).await?;
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(err) => {
+                    error!(
+                        "Cannot read segment file: {} with key: {}. Error: {}",
+                        path, key, err
+                    );
+                    return Err(Error::CannotDownloadSegmentFile(path.to_string()));
+                }
+            };
+            file.write_all(&chunk).await?;
+        }
+
+        file.flush().await?;
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), Error> {
+        let url = self.get_url(key)?;
+        match self
+            .send(Method::DELETE, url, EMPTY_PAYLOAD_HASH, 0, Body::from(""))
+            .await
+        {
+            Ok(response)
+                if response.status().is_success() || response.status() == StatusCode::NOT_FOUND =>
+            {
+                Ok(())
+            }
+            Ok(response) => {
+                error!(
+                    "Cannot delete segment file with key: {}, S3 responded with status: {}.",
+                    key,
+                    response.status()
+                );
+                Err(Error::CannotDeleteRemoteSegmentFile(key.to_string()))
+            }
+            Err(err) => {
+                error!(
+                    "Cannot delete segment file with key: {}. Error: {}",
+                    key, err
+                );
+                Err(Error::CannotDeleteRemoteSegmentFile(key.to_string()))
+            }
+        }
+    }
+}
+
+async fn hash_file(path: &str) -> Result<String, Error> {
+    let file = File::open(path).await?;
+    let mut reader = BufReader::new(file);
+    let mut context = digest::Context::new(&digest::SHA256);
+    loop {
+        let buffer = reader.fill_buf().await?;
+        if buffer.is_empty() {
+            break;
+        }
+
+        context.update(buffer);
+        let length = buffer.len();
+        reader.consume(length);
+    }
+
+    Ok(to_hex(context.finish().as_ref()))
+}
+
+// The headers must be lowercase and sorted by name, the request has no query string.
+fn sign(
+    secret_access_key: &str,
+    region: &str,
+    amz_date: &str,
+    method: &str,
+    path: &str,
+    headers: &[(&str, &str)],
+    payload_hash: &str,
+) -> String {
+    let date = &amz_date[..8];
+    let canonical_headers = headers
+        .iter()
+        .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+        .collect::<String>();
+    let canonical_request = format!(
+        "{}\n{}\n\n{}\n{}\n{}",
+        method,
+        path,
+        canonical_headers,
+        get_signed_headers(headers),
+        payload_hash
+    );
+    let scope = format!("{}/{}/{}/aws4_request", date, region, SERVICE);
+    let string_to_sign = format!(
+        "{}\n{}\n{}\n{}",
+        ALGORITHM,
+        amz_date,
+        scope,
+        to_hex(digest::digest(&digest::SHA256, canonical_request.as_bytes()).as_ref())
+    );
+
+    let signing_key = get_signing_key(secret_access_key, date, region, SERVICE);
+    let signature = hmac::sign(&signing_key, string_to_sign.as_bytes());
+    to_hex(signature.as_ref())
+}
+
+fn get_signed_headers(headers: &[(&str, &str)]) -> String {
+    headers
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<&str>>()
+        .join(";")
+}
+
+fn get_signing_key(secret_access_key: &str, date: &str, region: &str, service: &str) -> hmac::Key {
+    let mut key = format!("AWS4{}", secret_access_key).into_bytes();
+    for value in [date, region, service, "aws4_request"] {
+        let tag = hmac::sign(&hmac::Key::new(hmac::HMAC_SHA256, &key), value.as_bytes());
+        key = tag.as_ref().to_vec();
+    }
+
+    hmac::Key::new(hmac::HMAC_SHA256, &key)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example of signing the GET Object request from the AWS Signature Version 4 documentation.
+    #[test]
+    fn should_sign_request_using_signature_version_4() {
+        let empty_payload_hash = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+        let headers = [
+            ("host", "examplebucket.s3.amazonaws.com"),
+            ("range", "bytes=0-9"),
+            ("x-amz-content-sha256", empty_payload_hash),
+            ("x-amz-date", "20130524T000000Z"),
+        ];
+
+        let signature = sign(
+            "wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY",
+            "us-east-1",
+            "20130524T000000Z",
+            "GET",
+            "/test.txt",
+            &headers,
+            empty_payload_hash,
+        );
+
+        assert_eq!(
+            signature,
+            "f0e8bdb87c964420e857bd35b5d6ed310bd44f0170aba48dd91039c6036bdb41"
+        );
+    }
+
+    #[test]
+    fn invalid_endpoint_should_fail_instead_of_panicking() {
+        let storage = S3TieredStorage::new(&S3TieredStorageConfig {
+            endpoint: "invalid endpoint".to_string(),
+            region: "us-east-1".to_string(),
+            bucket: "iggy".to_string(),
+            access_key_id: "key".to_string(),
+            secret_access_key: "secret".to_string(),
+        });
+
+        assert!(matches!(
+            storage.get_url("1/1/1/00000000000000000000.log"),
+            Err(Error::CannotParseUrl)
+        ));
+    }
+}
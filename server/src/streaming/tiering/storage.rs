@@ -0,0 +1,33 @@
+use crate::configs::system::{TieredStorageBackend, TieredStorageConfig};
+use crate::streaming::tiering::local::LocalTieredStorage;
+use crate::streaming::tiering::s3::S3TieredStorage;
+use async_trait::async_trait;
+use iggy::error::Error;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// The remote backend to which the closed segment files are offloaded, each file is stored under its own key.
+#[async_trait]
+pub trait TieredStorage: Sync + Send {
+    async fn upload(&self, key: &str, path: &str) -> Result<(), Error>;
+    async fn download(&self, key: &str, path: &str) -> Result<(), Error>;
+    async fn delete(&self, key: &str) -> Result<(), Error>;
+}
+
+impl Debug for dyn TieredStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TieredStorage")
+    }
+}
+
+pub fn create_tiered_storage(config: &TieredStorageConfig) -> Option<Arc<dyn TieredStorage>> {
+    if !config.enabled {
+        return None;
+    }
+
+    let tiered_storage: Arc<dyn TieredStorage> = match config.backend {
+        TieredStorageBackend::Local => Arc::new(LocalTieredStorage::new(&config.local.path)),
+        TieredStorageBackend::S3 => Arc::new(S3TieredStorage::new(&config.s3)),
+    };
+    Some(tiered_storage)
+}
@@ -1,5 +1,10 @@
 use iggy::error::Error;
-use std::collections::HashMap;
+use iggy::models::consumer_group::{
+    ConsumerGroupAssignment, ConsumerGroupState, PartitionAssignmentStrategy,
+};
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::collections::{HashMap, HashSet};
 use tokio::sync::RwLock;
 use tracing::trace;
 
@@ -9,12 +14,21 @@ pub struct ConsumerGroup {
     pub consumer_group_id: u32,
     pub name: String,
     pub partitions_count: u32,
+    pub session_timeout: Option<u32>,
+    pub assignment_strategy: PartitionAssignmentStrategy,
+    pub generation: u32,
     members: HashMap<u32, RwLock<ConsumerGroupMember>>,
+    // Partition ID -> ID of the member which currently owns the partition and is allowed to poll it.
+    // The ownership is handed over only once the previous owner acknowledges the new generation,
+    // so that the same partition is never consumed by two members at the same time.
+    owners: HashMap<u32, u32>,
 }
 
 #[derive(Debug)]
 pub struct ConsumerGroupMember {
     pub id: u32,
+    pub generation: u32,
+    pub last_heartbeat_at: u64,
     partitions: HashMap<u32, u32>,
     current_partition_index: u32,
     current_partition_id: u32,
@@ -26,13 +40,19 @@ impl ConsumerGroup {
         consumer_group_id: u32,
         name: &str,
         partitions_count: u32,
+        session_timeout: Option<u32>,
+        assignment_strategy: PartitionAssignmentStrategy,
     ) -> ConsumerGroup {
         ConsumerGroup {
             topic_id,
             consumer_group_id,
             name: name.to_string(),
             partitions_count,
+            session_timeout,
+            assignment_strategy,
+            generation: 0,
             members: HashMap::new(),
+            owners: HashMap::new(),
         }
     }
 
@@ -40,15 +60,45 @@ impl ConsumerGroup {
         self.members.values().collect()
     }
 
+    pub async fn get_state(&self) -> ConsumerGroupState {
+        for member in self.members.values() {
+            if member.read().await.generation != self.generation {
+                return ConsumerGroupState::Rebalancing;
+            }
+        }
+        ConsumerGroupState::Stable
+    }
+
     pub async fn reassign_partitions(&mut self, partitions_count: u32) {
         self.partitions_count = partitions_count;
+        self.owners
+            .retain(|partition_id, _| *partition_id <= partitions_count);
         self.assign_partitions().await;
     }
 
-    pub async fn calculate_partition_id(&self, member_id: u32) -> Result<u32, Error> {
+    /// Calculates the next partition to be polled by the member, the provided generation (if any) must be the current one.
+    pub async fn calculate_partition_id(
+        &mut self,
+        member_id: u32,
+        generation: Option<u32>,
+    ) -> Result<Option<u32>, Error> {
+        self.ensure_generation(generation)?;
+        self.sync_member(member_id).await?;
+        let member = self.members.get(&member_id).unwrap();
+        let mut member = member.write().await;
+        for _ in 0..member.partitions.len() {
+            let partition_id = member.calculate_partition_id();
+            if self.owners.get(&partition_id) == Some(&member_id) {
+                return Ok(Some(partition_id));
+            }
+        }
+        Ok(None)
+    }
+
+    pub async fn get_current_partition_id(&self, member_id: u32) -> Result<u32, Error> {
         let member = self.members.get(&member_id);
         if let Some(member) = member {
-            return Ok(member.write().await.calculate_partition_id());
+            return Ok(member.read().await.current_partition_id);
         }
         Err(Error::ConsumerGroupMemberNotFound(
             member_id,
@@ -57,10 +107,68 @@ impl ConsumerGroup {
         ))
     }
 
-    pub async fn get_current_partition_id(&self, member_id: u32) -> Result<u32, Error> {
+    pub async fn get_assignment(
+        &mut self,
+        member_id: u32,
+    ) -> Result<ConsumerGroupAssignment, Error> {
+        self.sync_member(member_id).await?;
+        let state = self.get_state().await;
+        let member = self.members.get(&member_id).unwrap();
+        let member = member.read().await;
+        let mut partitions = member.get_partitions();
+        partitions.sort();
+        let pending_partitions = partitions
+            .iter()
+            .filter(|partition_id| self.owners.get(partition_id) != Some(&member_id))
+            .copied()
+            .collect();
+        Ok(ConsumerGroupAssignment {
+            consumer_group_id: self.consumer_group_id,
+            generation: member.generation,
+            state,
+            partitions,
+            pending_partitions,
+        })
+    }
+
+    pub fn ensure_partition_owned(
+        &self,
+        member_id: u32,
+        partition_id: u32,
+        generation: Option<u32>,
+    ) -> Result<(), Error> {
+        self.ensure_generation(generation)?;
+        if self.owners.get(&partition_id) == Some(&member_id) {
+            return Ok(());
+        }
+
+        Err(Error::ConsumerGroupMemberFenced(
+            member_id,
+            partition_id,
+            self.consumer_group_id,
+        ))
+    }
+
+    // The member using the stale generation is fenced, as it might still process the partitions already revoked from it.
+    // The member which doesn't specify the generation doesn't request the fencing, and only the ownership of the partition is validated.
+    fn ensure_generation(&self, generation: Option<u32>) -> Result<(), Error> {
+        match generation {
+            Some(generation) if generation != self.generation => {
+                Err(Error::ConsumerGroupGenerationMismatch(
+                    generation,
+                    self.generation,
+                    self.consumer_group_id,
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub async fn heartbeat(&self, member_id: u32) -> Result<(), Error> {
         let member = self.members.get(&member_id);
         if let Some(member) = member {
-            return Ok(member.read().await.current_partition_id);
+            member.write().await.last_heartbeat_at = IggyTimestamp::now().to_micros();
+            return Ok(());
         }
         Err(Error::ConsumerGroupMemberNotFound(
             member_id,
@@ -69,11 +177,55 @@ impl ConsumerGroup {
         ))
     }
 
+    pub async fn get_expired_members(
+        &self,
+        now: u64,
+        default_session_timeout: IggyDuration,
+    ) -> Vec<u32> {
+        let session_timeout = self.get_session_timeout(default_session_timeout);
+        let mut expired_members = Vec::new();
+        for member in self.members.values() {
+            let member = member.read().await;
+            if member.last_heartbeat_at + session_timeout <= now {
+                expired_members.push(member.id);
+            }
+        }
+        expired_members
+    }
+
+    /// Deletes the member only if its session is still expired, as it might have sent the heartbeat since it was found expired.
+    pub async fn delete_expired_member(
+        &mut self,
+        member_id: u32,
+        now: u64,
+        default_session_timeout: IggyDuration,
+    ) -> bool {
+        let session_timeout = self.get_session_timeout(default_session_timeout);
+        let Some(member) = self.members.get(&member_id) else {
+            return false;
+        };
+        if member.read().await.last_heartbeat_at + session_timeout > now {
+            return false;
+        }
+
+        self.delete_member(member_id).await;
+        true
+    }
+
+    fn get_session_timeout(&self, default_session_timeout: IggyDuration) -> u64 {
+        match self.session_timeout {
+            Some(session_timeout) => session_timeout as u64 * 1_000_000,
+            None => default_session_timeout.as_micros(),
+        }
+    }
+
     pub async fn add_member(&mut self, member_id: u32) {
         self.members.insert(
             member_id,
             RwLock::new(ConsumerGroupMember {
                 id: member_id,
+                generation: 0,
+                last_heartbeat_at: IggyTimestamp::now().to_micros(),
                 partitions: HashMap::new(),
                 current_partition_index: 0,
                 current_partition_id: 0,
@@ -96,37 +248,178 @@ impl ConsumerGroup {
                 self.consumer_group_id,
                 self.topic_id
             );
+            self.owners.retain(|_, owner_id| *owner_id != member_id);
             self.assign_partitions().await;
         }
     }
 
+    // Acknowledges the current generation by the member, which releases the partitions
+    // no longer assigned to it, and takes over the assigned ones already released by their previous owners.
+    async fn sync_member(&mut self, member_id: u32) -> Result<(), Error> {
+        let member = match self.members.get(&member_id) {
+            Some(member) => member,
+            None => {
+                return Err(Error::ConsumerGroupMemberNotFound(
+                    member_id,
+                    self.consumer_group_id,
+                    self.topic_id,
+                ))
+            }
+        };
+
+        let mut member = member.write().await;
+        member.last_heartbeat_at = IggyTimestamp::now().to_micros();
+        if member.generation != self.generation {
+            trace!(
+                "Member with ID: {} acknowledged generation: {} of consumer group: {} for topic with ID: {}",
+                member_id,
+                self.generation,
+                self.consumer_group_id,
+                self.topic_id
+            );
+            member.generation = self.generation;
+        }
+
+        let partitions = member.get_partitions();
+        self.owners.retain(|partition_id, owner_id| {
+            *owner_id != member_id || partitions.contains(partition_id)
+        });
+        for partition_id in partitions {
+            self.owners.entry(partition_id).or_insert(member_id);
+        }
+        Ok(())
+    }
+
     async fn assign_partitions(&mut self) {
-        let mut members = self.members.values_mut().collect::<Vec<_>>();
-        if members.is_empty() {
+        self.generation += 1;
+        if self.members.is_empty() {
             return;
         }
 
-        let members_count = members.len() as u32;
-        for member in members.iter_mut() {
+        let mut member_ids = self.members.keys().copied().collect::<Vec<_>>();
+        member_ids.sort();
+        let assignment = match self.assignment_strategy {
+            PartitionAssignmentStrategy::RoundRobin => {
+                assign_round_robin(self.partitions_count, &member_ids)
+            }
+            PartitionAssignmentStrategy::Range => assign_range(self.partitions_count, &member_ids),
+            PartitionAssignmentStrategy::Sticky => {
+                let mut current_assignment = Vec::with_capacity(member_ids.len());
+                for member_id in &member_ids {
+                    let member = self.members.get(member_id).unwrap().read().await;
+                    let mut partitions = member.get_partitions();
+                    partitions.sort();
+                    current_assignment.push((*member_id, partitions));
+                }
+                assign_sticky(self.partitions_count, current_assignment)
+            }
+        };
+
+        for (member_id, partitions) in assignment {
+            let member = self.members.get(&member_id).unwrap();
             let mut member = member.write().await;
             member.current_partition_index = 0;
             member.current_partition_id = 0;
             member.partitions.clear();
+            for partition_id in partitions {
+                let member_partition_index = member.partitions.len() as u32;
+                member
+                    .partitions
+                    .insert(member_partition_index, partition_id);
+                trace!("Assigned partition ID: {} to member with ID: {} for topic with ID: {} in consumer group: {}, generation: {}, strategy: {}",
+                    partition_id, member.id, self.topic_id, self.consumer_group_id, self.generation, self.assignment_strategy)
+            }
         }
+    }
+}
 
-        for partition_index in 0..self.partitions_count {
-            let partition_id = partition_index + 1;
-            let member_index = partition_index % members_count;
-            let member = members.get(member_index as usize).unwrap();
-            let mut member = member.write().await;
-            let member_partition_index = member.partitions.len() as u32;
-            member
-                .partitions
-                .insert(member_partition_index, partition_id);
-            trace!("Assigned partition ID: {} to member with ID: {} for topic with ID: {} in consumer group: {}",
-                partition_id, member.id, self.topic_id, self.consumer_group_id)
+// Distributes the partitions one by one across the members.
+fn assign_round_robin(partitions_count: u32, member_ids: &[u32]) -> Vec<(u32, Vec<u32>)> {
+    let mut assignment = member_ids
+        .iter()
+        .map(|member_id| (*member_id, Vec::new()))
+        .collect::<Vec<_>>();
+    for partition_index in 0..partitions_count {
+        let member_index = partition_index as usize % member_ids.len();
+        assignment[member_index].1.push(partition_index + 1);
+    }
+    assignment
+}
+
+// Assigns the contiguous range of the partitions to each member, the first members get one more partition if they can't be split evenly.
+fn assign_range(partitions_count: u32, member_ids: &[u32]) -> Vec<(u32, Vec<u32>)> {
+    let members_count = member_ids.len() as u32;
+    let min_partitions_count = partitions_count / members_count;
+    let extra_partitions_count = partitions_count % members_count;
+    let mut next_partition_id = 1;
+    let mut assignment = Vec::with_capacity(member_ids.len());
+    for (member_index, member_id) in member_ids.iter().enumerate() {
+        let mut member_partitions_count = min_partitions_count;
+        if (member_index as u32) < extra_partitions_count {
+            member_partitions_count += 1;
+        }
+        let partitions = (next_partition_id..next_partition_id + member_partitions_count).collect();
+        next_partition_id += member_partitions_count;
+        assignment.push((*member_id, partitions));
+    }
+    assignment
+}
+
+// Keeps the current partitions of the members up to their balanced quota, and moves only the surplus
+// and the orphaned ones (e.g. of the members which left the group) to the members having the fewest partitions.
+fn assign_sticky(
+    partitions_count: u32,
+    mut current_assignment: Vec<(u32, Vec<u32>)>,
+) -> Vec<(u32, Vec<u32>)> {
+    let members_count = current_assignment.len() as u32;
+    let min_partitions_count = (partitions_count / members_count) as usize;
+    let mut extra_partitions_count = partitions_count % members_count;
+    // The members owning the most partitions are allowed to keep the extra ones first, which minimises the movement.
+    current_assignment.sort_by(|(id_a, partitions_a), (id_b, partitions_b)| {
+        partitions_b
+            .len()
+            .cmp(&partitions_a.len())
+            .then(id_a.cmp(id_b))
+    });
+
+    let mut assigned_partitions = HashSet::new();
+    let mut assignment = Vec::with_capacity(current_assignment.len());
+    for (member_id, partitions) in current_assignment {
+        let mut quota = min_partitions_count;
+        if extra_partitions_count > 0 {
+            quota += 1;
+        }
+        let kept_partitions = partitions
+            .into_iter()
+            .filter(|partition_id| {
+                *partition_id <= partitions_count && !assigned_partitions.contains(partition_id)
+            })
+            .take(quota)
+            .collect::<Vec<_>>();
+        if kept_partitions.len() > min_partitions_count {
+            extra_partitions_count -= 1;
         }
+        assigned_partitions.extend(kept_partitions.iter().copied());
+        assignment.push((member_id, kept_partitions));
     }
+
+    assignment.sort_by_key(|(member_id, _)| *member_id);
+    for partition_id in 1..=partitions_count {
+        if assigned_partitions.contains(&partition_id) {
+            continue;
+        }
+
+        let (_, partitions) = assignment
+            .iter_mut()
+            .min_by_key(|(_, partitions)| partitions.len())
+            .unwrap();
+        partitions.push(partition_id);
+    }
+
+    for (_, partitions) in assignment.iter_mut() {
+        partitions.sort();
+    }
+    assignment
 }
 
 impl ConsumerGroupMember {
@@ -156,6 +449,53 @@ impl ConsumerGroupMember {
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn expired_member_should_not_be_deleted_after_heartbeat() {
+        let member_id = 123;
+        let mut consumer_group = ConsumerGroup {
+            topic_id: 1,
+            consumer_group_id: 1,
+            name: "test".to_string(),
+            partitions_count: 3,
+            session_timeout: Some(1),
+            assignment_strategy: PartitionAssignmentStrategy::RoundRobin,
+            generation: 0,
+            members: HashMap::new(),
+            owners: HashMap::new(),
+        };
+        let default_session_timeout = "1s".parse().unwrap();
+
+        consumer_group.add_member(member_id).await;
+        let now = IggyTimestamp::now().to_micros() + 1_000_000;
+        assert_eq!(
+            consumer_group
+                .get_expired_members(now, default_session_timeout)
+                .await,
+            vec![member_id]
+        );
+
+        consumer_group
+            .members
+            .get(&member_id)
+            .unwrap()
+            .write()
+            .await
+            .last_heartbeat_at = now;
+        assert!(
+            !consumer_group
+                .delete_expired_member(member_id, now, default_session_timeout)
+                .await
+        );
+        assert!(consumer_group.members.contains_key(&member_id));
+
+        assert!(
+            consumer_group
+                .delete_expired_member(member_id, now + 1_000_000, default_session_timeout)
+                .await
+        );
+        assert!(consumer_group.members.is_empty());
+    }
+
     #[tokio::test]
     async fn should_calculate_partition_id_using_round_robin() {
         let member_id = 123;
@@ -164,14 +504,19 @@ mod tests {
             consumer_group_id: 1,
             name: "test".to_string(),
             partitions_count: 3,
+            session_timeout: None,
+            assignment_strategy: PartitionAssignmentStrategy::RoundRobin,
+            generation: 0,
             members: HashMap::new(),
+            owners: HashMap::new(),
         };
 
         consumer_group.add_member(member_id).await;
         for i in 0..1000 {
             let partition_id = consumer_group
-                .calculate_partition_id(member_id)
+                .calculate_partition_id(member_id, None)
                 .await
+                .unwrap()
                 .unwrap();
             assert_eq!(partition_id, (i % consumer_group.partitions_count) + 1);
         }
@@ -185,7 +530,11 @@ mod tests {
             consumer_group_id: 1,
             name: "test".to_string(),
             partitions_count: 3,
+            session_timeout: None,
+            assignment_strategy: PartitionAssignmentStrategy::RoundRobin,
+            generation: 0,
             members: HashMap::new(),
+            owners: HashMap::new(),
         };
 
         consumer_group.add_member(member_id).await;
@@ -210,7 +559,11 @@ mod tests {
             consumer_group_id: 1,
             name: "test".to_string(),
             partitions_count: 3,
+            session_timeout: None,
+            assignment_strategy: PartitionAssignmentStrategy::RoundRobin,
+            generation: 0,
             members: HashMap::new(),
+            owners: HashMap::new(),
         };
 
         consumer_group.add_member(member1_id).await;
@@ -247,7 +600,11 @@ mod tests {
             consumer_group_id: 1,
             name: "test".to_string(),
             partitions_count: 1,
+            session_timeout: None,
+            assignment_strategy: PartitionAssignmentStrategy::RoundRobin,
+            generation: 0,
             members: HashMap::new(),
+            owners: HashMap::new(),
         };
 
         consumer_group.add_member(member1_id).await;
@@ -263,4 +620,315 @@ mod tests {
             assert_eq!(member2.partitions.len(), 1);
         }
     }
+
+    #[tokio::test]
+    async fn should_hand_over_partition_only_after_previous_owner_acknowledges_generation() {
+        let member1_id = 123;
+        let member2_id = 456;
+        let mut consumer_group = ConsumerGroup::new(
+            1,
+            1,
+            "test",
+            2,
+            None,
+            PartitionAssignmentStrategy::RoundRobin,
+        );
+        consumer_group.add_member(member1_id).await;
+        consumer_group
+            .calculate_partition_id(member1_id, None)
+            .await
+            .unwrap();
+        assert_eq!(consumer_group.get_state().await, ConsumerGroupState::Stable);
+
+        consumer_group.add_member(member2_id).await;
+        assert_eq!(consumer_group.generation, 2);
+        assert_eq!(
+            consumer_group.get_state().await,
+            ConsumerGroupState::Rebalancing
+        );
+        let assignment = consumer_group.get_assignment(member2_id).await.unwrap();
+        assert_eq!(assignment.generation, 2);
+        assert_eq!(assignment.partitions.len(), 1);
+        assert_eq!(assignment.pending_partitions, assignment.partitions);
+        assert!(consumer_group
+            .calculate_partition_id(member2_id, Some(assignment.generation))
+            .await
+            .unwrap()
+            .is_none());
+
+        let member1_assignment = consumer_group.get_assignment(member1_id).await.unwrap();
+        assert!(member1_assignment.pending_partitions.is_empty());
+        assert_eq!(consumer_group.get_state().await, ConsumerGroupState::Stable);
+        let partition_id = consumer_group
+            .calculate_partition_id(member2_id, Some(assignment.generation))
+            .await
+            .unwrap();
+        assert_eq!(partition_id, Some(assignment.partitions[0]));
+    }
+
+    #[tokio::test]
+    async fn should_fence_member_which_no_longer_owns_partition() {
+        let member1_id = 123;
+        let member2_id = 456;
+        let mut consumer_group = ConsumerGroup::new(
+            1,
+            1,
+            "test",
+            2,
+            None,
+            PartitionAssignmentStrategy::RoundRobin,
+        );
+        consumer_group.add_member(member1_id).await;
+        consumer_group.get_assignment(member1_id).await.unwrap();
+        assert!(consumer_group
+            .ensure_partition_owned(member1_id, 1, None)
+            .is_ok());
+        assert!(consumer_group
+            .ensure_partition_owned(member1_id, 2, None)
+            .is_ok());
+
+        consumer_group.add_member(member2_id).await;
+        consumer_group.get_assignment(member1_id).await.unwrap();
+        let assignment = consumer_group.get_assignment(member2_id).await.unwrap();
+        let revoked_partition_id = assignment.partitions[0];
+        assert!(consumer_group
+            .ensure_partition_owned(
+                member1_id,
+                revoked_partition_id,
+                Some(assignment.generation)
+            )
+            .is_err());
+        assert!(consumer_group
+            .ensure_partition_owned(
+                member2_id,
+                revoked_partition_id,
+                Some(assignment.generation)
+            )
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_fence_member_using_stale_generation() {
+        let member1_id = 123;
+        let member2_id = 456;
+        let mut consumer_group = ConsumerGroup::new(
+            1,
+            1,
+            "test",
+            2,
+            None,
+            PartitionAssignmentStrategy::RoundRobin,
+        );
+        consumer_group.add_member(member1_id).await;
+        let generation = consumer_group
+            .get_assignment(member1_id)
+            .await
+            .unwrap()
+            .generation;
+        assert!(consumer_group
+            .calculate_partition_id(member1_id, Some(generation))
+            .await
+            .is_ok());
+
+        consumer_group.add_member(member2_id).await;
+        let result = consumer_group
+            .calculate_partition_id(member1_id, Some(generation))
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::ConsumerGroupGenerationMismatch(_, _, _))
+        ));
+        assert_eq!(
+            consumer_group.get_state().await,
+            ConsumerGroupState::Rebalancing
+        );
+        assert!(matches!(
+            consumer_group.ensure_partition_owned(member1_id, 1, Some(generation)),
+            Err(Error::ConsumerGroupGenerationMismatch(_, _, _))
+        ));
+
+        let generation = consumer_group
+            .get_assignment(member1_id)
+            .await
+            .unwrap()
+            .generation;
+        assert!(consumer_group
+            .calculate_partition_id(member1_id, Some(generation))
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_validate_only_partition_ownership_of_member_without_generation() {
+        let member1_id = 123;
+        let member2_id = 456;
+        let mut consumer_group = ConsumerGroup::new(
+            1,
+            1,
+            "test",
+            2,
+            None,
+            PartitionAssignmentStrategy::RoundRobin,
+        );
+        consumer_group.add_member(member1_id).await;
+        consumer_group.add_member(member2_id).await;
+        consumer_group.get_assignment(member1_id).await.unwrap();
+        consumer_group.get_assignment(member2_id).await.unwrap();
+        assert_eq!(consumer_group.generation, 2);
+
+        let partition_id = consumer_group
+            .calculate_partition_id(member1_id, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(consumer_group
+            .ensure_partition_owned(member1_id, partition_id, None)
+            .is_ok());
+
+        let revoked_partition_id = if partition_id == 1 { 2 } else { 1 };
+        assert!(matches!(
+            consumer_group.ensure_partition_owned(member1_id, revoked_partition_id, None),
+            Err(Error::ConsumerGroupMemberFenced(_, _, _))
+        ));
+        assert!(matches!(
+            consumer_group.ensure_partition_owned(member1_id, partition_id, Some(1)),
+            Err(Error::ConsumerGroupGenerationMismatch(1, 2, _))
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_release_partitions_of_leaving_member_immediately() {
+        let member1_id = 123;
+        let member2_id = 456;
+        let mut consumer_group = ConsumerGroup::new(
+            1,
+            1,
+            "test",
+            2,
+            None,
+            PartitionAssignmentStrategy::RoundRobin,
+        );
+        consumer_group.add_member(member1_id).await;
+        consumer_group.add_member(member2_id).await;
+        consumer_group.get_assignment(member1_id).await.unwrap();
+        consumer_group.get_assignment(member2_id).await.unwrap();
+
+        consumer_group.delete_member(member1_id).await;
+        let assignment = consumer_group.get_assignment(member2_id).await.unwrap();
+        assert_eq!(assignment.partitions, vec![1, 2]);
+        assert!(assignment.pending_partitions.is_empty());
+        assert_eq!(assignment.state, ConsumerGroupState::Stable);
+    }
+
+    #[tokio::test]
+    async fn should_return_members_without_heartbeat_within_session_timeout() {
+        let member1_id = 123;
+        let member2_id = 456;
+        let mut consumer_group = ConsumerGroup::new(
+            1,
+            1,
+            "test",
+            2,
+            Some(10),
+            PartitionAssignmentStrategy::RoundRobin,
+        );
+        consumer_group.add_member(member1_id).await;
+        consumer_group.add_member(member2_id).await;
+        let default_session_timeout = IggyDuration::new(std::time::Duration::from_secs(30));
+        let now = IggyTimestamp::now().to_micros();
+        assert!(consumer_group
+            .get_expired_members(now, default_session_timeout)
+            .await
+            .is_empty());
+
+        let now = now + 11_000_000;
+        consumer_group
+            .members
+            .get(&member2_id)
+            .unwrap()
+            .write()
+            .await
+            .last_heartbeat_at = now;
+        let expired_members = consumer_group
+            .get_expired_members(now, default_session_timeout)
+            .await;
+        assert_eq!(expired_members, vec![member1_id]);
+    }
+
+    #[tokio::test]
+    async fn should_assign_contiguous_partitions_using_range_strategy() {
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 5, None, PartitionAssignmentStrategy::Range);
+        consumer_group.add_member(3).await;
+        consumer_group.add_member(1).await;
+        consumer_group.add_member(2).await;
+
+        assert_eq!(get_partitions(&consumer_group, 1).await, vec![1, 2]);
+        assert_eq!(get_partitions(&consumer_group, 2).await, vec![3, 4]);
+        assert_eq!(get_partitions(&consumer_group, 3).await, vec![5]);
+    }
+
+    #[tokio::test]
+    async fn should_move_only_surplus_partitions_using_sticky_strategy() {
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 6, None, PartitionAssignmentStrategy::Sticky);
+        consumer_group.add_member(1).await;
+        consumer_group.add_member(2).await;
+        let member1_partitions = get_partitions(&consumer_group, 1).await;
+        let member2_partitions = get_partitions(&consumer_group, 2).await;
+        assert_eq!(member1_partitions.len(), 3);
+        assert_eq!(member2_partitions.len(), 3);
+
+        consumer_group.add_member(3).await;
+        let member3_partitions = get_partitions(&consumer_group, 3).await;
+        assert_eq!(member3_partitions.len(), 2);
+        for (member_id, previous_partitions) in [(1, &member1_partitions), (2, &member2_partitions)]
+        {
+            let partitions = get_partitions(&consumer_group, member_id).await;
+            assert_eq!(partitions.len(), 2);
+            assert!(partitions
+                .iter()
+                .all(|partition_id| previous_partitions.contains(partition_id)));
+        }
+
+        let member1_partitions = get_partitions(&consumer_group, 1).await;
+        consumer_group.delete_member(2).await;
+        let partitions = get_partitions(&consumer_group, 1).await;
+        assert_eq!(partitions.len(), 3);
+        assert!(member1_partitions
+            .iter()
+            .all(|partition_id| partitions.contains(partition_id)));
+        let partitions = get_partitions(&consumer_group, 3).await;
+        assert_eq!(partitions.len(), 3);
+        assert!(member3_partitions
+            .iter()
+            .all(|partition_id| partitions.contains(partition_id)));
+    }
+
+    #[tokio::test]
+    async fn should_keep_sticky_assignment_balanced_when_partitions_are_added() {
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 2, None, PartitionAssignmentStrategy::Sticky);
+        consumer_group.add_member(1).await;
+        consumer_group.add_member(2).await;
+        let member1_partitions = get_partitions(&consumer_group, 1).await;
+
+        consumer_group.reassign_partitions(5).await;
+        let partitions = get_partitions(&consumer_group, 1).await;
+        assert!(partitions.len() >= 2);
+        assert!(member1_partitions
+            .iter()
+            .all(|partition_id| partitions.contains(partition_id)));
+        let mut all_partitions = partitions;
+        all_partitions.extend(get_partitions(&consumer_group, 2).await);
+        all_partitions.sort();
+        assert_eq!(all_partitions, vec![1, 2, 3, 4, 5]);
+    }
+
+    async fn get_partitions(consumer_group: &ConsumerGroup, member_id: u32) -> Vec<u32> {
+        let member = consumer_group.members.get(&member_id).unwrap();
+        let mut partitions = member.read().await.get_partitions();
+        partitions.sort();
+        partitions
+    }
 }
@@ -2,6 +2,8 @@ use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use iggy::error::Error;
 use iggy::identifier::{IdKind, Identifier};
+use iggy::models::consumer_group::PartitionAssignmentStrategy;
+use iggy::utils::duration::IggyDuration;
 use iggy::utils::text;
 use tokio::sync::RwLock;
 use tracing::info;
@@ -60,7 +62,13 @@ impl Topic {
         Ok(consumer_group.unwrap())
     }
 
-    pub async fn create_consumer_group(&mut self, id: u32, name: &str) -> Result<(), Error> {
+    pub async fn create_consumer_group(
+        &mut self,
+        id: u32,
+        name: &str,
+        session_timeout: Option<u32>,
+        assignment_strategy: PartitionAssignmentStrategy,
+    ) -> Result<(), Error> {
         if self.consumer_groups.contains_key(&id) {
             return Err(Error::ConsumerGroupIdAlreadyExists(id, self.topic_id));
         }
@@ -70,8 +78,14 @@ impl Topic {
             return Err(Error::ConsumerGroupNameAlreadyExists(name, self.topic_id));
         }
 
-        let consumer_group =
-            ConsumerGroup::new(self.topic_id, id, &name, self.partitions.len() as u32);
+        let consumer_group = ConsumerGroup::new(
+            self.topic_id,
+            id,
+            &name,
+            self.partitions.len() as u32,
+            session_timeout,
+            assignment_strategy,
+        );
         self.consumer_groups.insert(id, RwLock::new(consumer_group));
         self.consumer_groups_ids.insert(name, id);
         let consumer_group = self.get_consumer_group_by_id(id)?;
@@ -150,6 +164,21 @@ impl Topic {
         );
         Ok(())
     }
+
+    /// Deletes the consumer group member if its session is still expired, returns true if it was deleted.
+    pub async fn delete_expired_consumer_group_member(
+        &self,
+        consumer_group_id: &Identifier,
+        member_id: u32,
+        now: u64,
+        default_session_timeout: IggyDuration,
+    ) -> Result<bool, Error> {
+        let consumer_group = self.get_consumer_group(consumer_group_id)?;
+        let mut consumer_group = consumer_group.write().await;
+        Ok(consumer_group
+            .delete_expired_member(member_id, now, default_session_timeout)
+            .await)
+    }
 }
 
 #[cfg(test)]
@@ -157,6 +186,7 @@ mod tests {
     use super::*;
     use crate::configs::system::SystemConfig;
     use crate::streaming::storage::tests::get_test_system_storage;
+    use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
     use std::sync::Arc;
 
     #[tokio::test]
@@ -164,7 +194,14 @@ mod tests {
         let consumer_group_id = 1;
         let name = "test";
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(consumer_group_id, name).await;
+        let result = topic
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                None,
+                PartitionAssignmentStrategy::RoundRobin,
+            )
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let consumer_group = topic
@@ -185,10 +222,24 @@ mod tests {
         let consumer_group_id = 1;
         let name = "test";
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(consumer_group_id, name).await;
+        let result = topic
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                None,
+                PartitionAssignmentStrategy::RoundRobin,
+            )
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
-        let result = topic.create_consumer_group(consumer_group_id, name).await;
+        let result = topic
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                None,
+                PartitionAssignmentStrategy::RoundRobin,
+            )
+            .await;
         assert!(result.is_err());
         assert_eq!(topic.consumer_groups.len(), 1);
         let err = result.unwrap_err();
@@ -200,11 +251,25 @@ mod tests {
         let consumer_group_id = 1;
         let name = "test";
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(consumer_group_id, name).await;
+        let result = topic
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                None,
+                PartitionAssignmentStrategy::RoundRobin,
+            )
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let consumer_group_id = consumer_group_id + 1;
-        let result = topic.create_consumer_group(consumer_group_id, name).await;
+        let result = topic
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                None,
+                PartitionAssignmentStrategy::RoundRobin,
+            )
+            .await;
         assert!(result.is_err());
         assert_eq!(topic.consumer_groups.len(), 1);
         let err = result.unwrap_err();
@@ -216,7 +281,14 @@ mod tests {
         let consumer_group_id = 1;
         let name = "test";
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(consumer_group_id, name).await;
+        let result = topic
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                None,
+                PartitionAssignmentStrategy::RoundRobin,
+            )
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let result = topic
@@ -231,7 +303,14 @@ mod tests {
         let consumer_group_id = 1;
         let name = "test";
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(consumer_group_id, name).await;
+        let result = topic
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                None,
+                PartitionAssignmentStrategy::RoundRobin,
+            )
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let consumer_group_id = consumer_group_id + 1;
@@ -249,7 +328,12 @@ mod tests {
         let member_id = 1;
         let mut topic = get_topic();
         topic
-            .create_consumer_group(consumer_group_id, name)
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                None,
+                PartitionAssignmentStrategy::RoundRobin,
+            )
             .await
             .unwrap();
         let result = topic
@@ -272,7 +356,12 @@ mod tests {
         let member_id = 1;
         let mut topic = get_topic();
         topic
-            .create_consumer_group(consumer_group_id, name)
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                None,
+                PartitionAssignmentStrategy::RoundRobin,
+            )
             .await
             .unwrap();
         topic
@@ -310,6 +399,10 @@ mod tests {
             None,
             None,
             1,
+            None,
+            CleanupPolicy::Delete,
+            MaxTopicSizePolicy::DeleteOldest,
+            FlushPolicy::default(),
         )
         .unwrap()
     }
@@ -1,7 +1,11 @@
 use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::tiering;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::topics::topic::Topic;
+use iggy::consumer_groups::reset_consumer_group_offsets::OffsetsResetStrategy;
 use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::models::consumer_group::ConsumerGroupOffsetReset;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
 use tokio::sync::RwLock;
 
@@ -10,9 +14,17 @@ impl Topic {
         &self,
         consumer: PollingConsumer,
         offset: u64,
+        generation: Option<u32>,
     ) -> Result<(), Error> {
         let partition = self.resolve_partition(consumer).await?;
         let partition = partition.read().await;
+        if let PollingConsumer::ConsumerGroup(consumer_group_id, member_id) = consumer {
+            let consumer_group = self
+                .get_consumer_group_by_id(consumer_group_id)?
+                .read()
+                .await;
+            consumer_group.ensure_partition_owned(member_id, partition.partition_id, generation)?;
+        }
         partition.store_consumer_offset(consumer, offset).await
     }
 
@@ -30,6 +42,46 @@ impl Topic {
         })
     }
 
+    pub async fn reset_consumer_group_offsets(
+        &self,
+        consumer_group_id: &Identifier,
+        partition_ids: &[u32],
+        strategy: OffsetsResetStrategy,
+        dry_run: bool,
+    ) -> Result<Vec<ConsumerGroupOffsetReset>, Error> {
+        let consumer_group_id = self
+            .get_consumer_group(consumer_group_id)?
+            .read()
+            .await
+            .consumer_group_id;
+        let mut partition_ids = if partition_ids.is_empty() {
+            self.partitions.keys().copied().collect::<Vec<_>>()
+        } else {
+            partition_ids.to_vec()
+        };
+        partition_ids.sort();
+        partition_ids.dedup();
+
+        // All the partitions are validated upfront, so the offsets are never reset only partially.
+        let partitions = partition_ids
+            .iter()
+            .map(|partition_id| self.get_partition(*partition_id))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let mut offsets = Vec::with_capacity(partitions.len());
+        for partition in partitions {
+            let offset = tiering::read_partition(&partition, |partition| {
+                Box::pin(partition.reset_consumer_group_offset(
+                    consumer_group_id,
+                    strategy,
+                    dry_run,
+                ))
+            })
+            .await?;
+            offsets.push(offset);
+        }
+        Ok(offsets)
+    }
+
     async fn resolve_partition(
         &self,
         consumer: PollingConsumer,
@@ -1,12 +1,18 @@
+use crate::configs::system::{ReplicaRole, ReplicationAck};
 use crate::streaming::models::messages::PolledMessages;
+use crate::streaming::partitions::replicas::InSyncReplicasAck;
+use crate::streaming::partitions::tiering;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::topics::topic::Topic;
+use crate::streaming::utils::compression;
 use crate::streaming::utils::file::folder_size;
 use crate::streaming::utils::hash;
 use iggy::error::Error;
 use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
-use iggy::messages::send_messages::{Partitioning, PartitioningKind};
+use iggy::messages::send_messages::{AckLevel, Partitioning, PartitioningKind, MESSAGE_KEY_HEADER};
+use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::models::messages::Message;
+use iggy::models::topic::{CleanupPolicy, MaxTopicSizePolicy};
 use std::collections::HashMap;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -42,37 +48,117 @@ impl Topic {
             ));
         }
 
-        let partition = partition.unwrap();
-        let partition = partition.read().await;
-        let value = strategy.value;
-        let messages = match strategy.kind {
-            PollingKind::Offset => partition.get_messages_by_offset(value, count).await,
-            PollingKind::Timestamp => partition.get_messages_by_timestamp(value, count).await,
-            PollingKind::First => partition.get_first_messages(count).await,
-            PollingKind::Last => partition.get_last_messages(count).await,
-            PollingKind::Next => partition.get_next_messages(consumer, count).await,
-        }?;
-
-        Ok(PolledMessages {
-            messages,
-            partition_id,
-            current_offset: partition.current_offset,
+        tiering::read_partition(partition.unwrap(), |partition| {
+            Box::pin(async move {
+                let value = strategy.value;
+                let messages = match strategy.kind {
+                    PollingKind::Offset => partition.get_messages_by_offset(value, count).await,
+                    PollingKind::Timestamp => {
+                        partition.get_messages_by_timestamp(value, count).await
+                    }
+                    PollingKind::First => partition.get_first_messages(count).await,
+                    PollingKind::Last => partition.get_last_messages(count).await,
+                    PollingKind::Next => partition.get_next_messages(consumer, count).await,
+                }?;
+
+                // Messages are decompressed based on their own header rather than the current topic setting,
+                // as the partition might contain the messages compressed with the different algorithms.
+                let messages = messages
+                    .iter()
+                    .map(compression::decompress_or_keep_stored)
+                    .collect::<Vec<_>>();
+                let messages = partition.mark_poisoned_messages(messages);
+
+                Ok(PolledMessages {
+                    messages,
+                    partition_id,
+                    current_offset: partition.current_offset,
+                    mapped_messages: None,
+                })
+            })
+        })
+        .await
+    }
+
+    pub async fn get_mapped_messages(
+        &self,
+        consumer: PollingConsumer,
+        partition_id: u32,
+        strategy: PollingStrategy,
+        count: u32,
+    ) -> Result<Option<PolledMessages>, Error> {
+        let partition = self.get_partition(partition_id)?;
+        tiering::read_partition(&partition, |partition| {
+            Box::pin(async move {
+                let mapped_messages = partition
+                    .get_mapped_messages(consumer, strategy, count)
+                    .await?;
+                Ok(mapped_messages.map(|mapped_messages| PolledMessages {
+                    partition_id,
+                    current_offset: partition.current_offset,
+                    messages: Vec::new(),
+                    mapped_messages: Some(mapped_messages),
+                }))
+            })
         })
+        .await
+    }
+
+    pub async fn get_message(&self, partition_id: u32, offset: u64) -> Result<Arc<Message>, Error> {
+        let partition = self.get_partition(partition_id)?;
+        let messages = tiering::read_partition(&partition, |partition| {
+            Box::pin(partition.get_messages_by_offset(offset, 1))
+        })
+        .await?;
+        match messages.first() {
+            Some(message) if message.offset == offset => {
+                Ok(compression::decompress_or_keep_stored(message))
+            }
+            _ => Err(Error::InvalidOffset(offset)),
+        }
+    }
+
+    pub async fn is_message_poisoned(&self, partition_id: u32, offset: u64) -> Result<bool, Error> {
+        let partition = self.get_partition(partition_id)?;
+        let partition = partition.read().await;
+        Ok(partition.is_message_poisoned(offset))
+    }
+
+    pub async fn poison_message(
+        &self,
+        partition_id: u32,
+        offset: u64,
+        reason: &str,
+    ) -> Result<(), Error> {
+        let partition = self.get_partition(partition_id)?;
+        let partition = partition.read().await;
+        partition.poison_message(offset, reason).await
     }
 
     pub async fn append_messages(
         &self,
         partitioning: &Partitioning,
         messages: Vec<Message>,
-    ) -> Result<(), Error> {
+    ) -> Result<Option<InSyncReplicasAck>, Error> {
+        self.append_messages_with_ack(partitioning, messages, AckLevel::InMemory)
+            .await
+    }
+
+    pub async fn append_messages_with_ack(
+        &self,
+        partitioning: &Partitioning,
+        messages: Vec<Message>,
+        ack: AckLevel,
+    ) -> Result<Option<InSyncReplicasAck>, Error> {
         if !self.has_partitions() {
             return Err(Error::NoPartitions(self.topic_id, self.stream_id));
         }
 
         if messages.is_empty() {
-            return Ok(());
+            return Ok(None);
         }
 
+        compression::ensure_no_reserved_header(&messages)?;
         let partition_id = match partitioning.kind {
             PartitioningKind::Balanced => self.get_next_partition_id(),
             PartitioningKind::PartitionId => {
@@ -83,7 +169,20 @@ impl Topic {
             }
         };
 
-        self.append_messages_to_partition(partition_id, messages)
+        let messages = self.set_messages_key(partitioning, messages)?;
+        // The messages are compressed before checking the topic size, so they are sized as they are stored.
+        let messages = messages
+            .into_iter()
+            .map(|message| compression::compress(message, self.compression_algorithm))
+            .collect::<Result<Vec<_>, _>>()?;
+        // The size is checked and the messages are appended under the same lock, otherwise the concurrent senders
+        // (possibly to the different partitions) could all pass the check and exceed the max topic size together.
+        let _size_check_guard = match self.max_topic_size_policy {
+            MaxTopicSizePolicy::Reject => Some(self.size_check_lock.lock().await),
+            MaxTopicSizePolicy::DeleteOldest => None,
+        };
+        self.ensure_not_full(&messages).await?;
+        self.append_messages_to_partition(partition_id, messages, ack)
             .await
     }
 
@@ -91,7 +190,8 @@ impl Topic {
         &self,
         partition_id: u32,
         messages: Vec<Message>,
-    ) -> Result<(), Error> {
+        ack: AckLevel,
+    ) -> Result<Option<InSyncReplicasAck>, Error> {
         let partition = self.partitions.get(&partition_id);
         if partition.is_none() {
             return Err(Error::PartitionNotFound(
@@ -102,11 +202,80 @@ impl Topic {
         }
 
         let partition = partition.unwrap();
-        let mut partition = partition.write().await;
-        partition.append_messages(messages).await?;
+        let next_offset = {
+            let mut partition = partition.write().await;
+            partition.append_messages_with_ack(messages, ack).await?;
+            partition.get_next_offset()
+        };
+
+        // Nobody awaits the acknowledgement of the messages sent without any.
+        if next_offset == 0 || ack == AckLevel::None || !self.should_wait_for_in_sync_replicas() {
+            return Ok(None);
+        }
+
+        Ok(Some(InSyncReplicasAck::new(
+            partition.clone(),
+            next_offset - 1,
+            self.replication_factor as u32,
+            self.config.replication.ack_timeout,
+        )))
+    }
+
+    /// Rejects the messages which would exceed the max topic size, if the topic is configured with the reject policy.
+    async fn ensure_not_full(&self, messages: &[Message]) -> Result<(), Error> {
+        if self.max_topic_size_policy != MaxTopicSizePolicy::Reject {
+            return Ok(());
+        }
+
+        let max_topic_size = match self.get_max_topic_size() {
+            Some(max_topic_size) => max_topic_size.as_bytes_u64(),
+            None => return Ok(()),
+        };
+
+        let messages_size = messages
+            .iter()
+            .map(|message| message.get_size_bytes() as u64)
+            .sum::<u64>();
+        if self.get_size().await.as_bytes_u64() + messages_size > max_topic_size {
+            return Err(Error::TopicFull(self.topic_id, self.stream_id));
+        }
+
         Ok(())
     }
 
+    // The compacted topic keeps the latest message per key, so the partitioning key is stored with each message.
+    fn set_messages_key(
+        &self,
+        partitioning: &Partitioning,
+        mut messages: Vec<Message>,
+    ) -> Result<Vec<Message>, Error> {
+        if self.cleanup_policy != CleanupPolicy::Compact
+            || partitioning.kind != PartitioningKind::MessagesKey
+        {
+            return Ok(messages);
+        }
+
+        let header_key = HeaderKey::new(MESSAGE_KEY_HEADER)?;
+        let header_value = HeaderValue::from_raw(&partitioning.value)?;
+        for message in messages.iter_mut() {
+            message
+                .headers
+                .get_or_insert_with(HashMap::new)
+                .entry(header_key.clone())
+                .or_insert_with(|| header_value.clone());
+        }
+
+        Ok(messages)
+    }
+
+    fn should_wait_for_in_sync_replicas(&self) -> bool {
+        let config = &self.config.replication;
+        config.enabled
+            && config.role == ReplicaRole::Leader
+            && config.ack == ReplicationAck::InSyncReplicas
+            && self.replication_factor > 1
+    }
+
     fn get_next_partition_id(&self) -> u32 {
         let mut partition_id = self.current_partition_id.fetch_add(1, Ordering::SeqCst);
         let partitions_count = self.partitions.len() as u32;
@@ -261,7 +430,11 @@ mod tests {
     use crate::configs::system::SystemConfig;
     use crate::streaming::storage::tests::get_test_system_storage;
     use bytes::Bytes;
+    use futures::future::join_all;
+    use iggy::compression::compression_algorithm::CompressionAlgorithm;
     use iggy::models::messages::MessageState;
+    use iggy::models::topic::FlushPolicy;
+    use iggy::utils::byte_size::IggyByteSize;
     use std::sync::Arc;
 
     #[tokio::test]
@@ -337,6 +510,76 @@ mod tests {
         assert_eq!(read_messages_count, messages_count as usize);
     }
 
+    #[tokio::test]
+    async fn given_compression_algorithm_messages_should_be_stored_compressed_and_polled_decompressed(
+    ) {
+        let partition_id = 1;
+        let partitioning = Partitioning::partition_id(partition_id);
+        let mut topic = init_topic(1);
+        topic.compression_algorithm = CompressionAlgorithm::Gzip;
+
+        let payload = Bytes::from("{\"entity_id\": 1, \"name\": \"iggy\"}".repeat(10));
+        let messages = vec![Message::empty(
+            1,
+            MessageState::Available,
+            1,
+            payload.clone(),
+            1,
+            None,
+        )];
+        topic
+            .append_messages(&partitioning, messages)
+            .await
+            .unwrap();
+
+        let partition = topic.get_partition(partition_id).unwrap();
+        let stored_messages = partition.read().await.cache.as_ref().unwrap().to_vec();
+        assert_eq!(stored_messages.len(), 1);
+        assert!(stored_messages[0].payload.len() < payload.len());
+
+        let polled_messages = topic
+            .get_messages(
+                PollingConsumer::Consumer(1, partition_id),
+                partition_id,
+                PollingStrategy::offset(0),
+                1,
+            )
+            .await
+            .unwrap();
+        assert_eq!(polled_messages.messages.len(), 1);
+        assert_eq!(polled_messages.messages[0].payload, payload);
+        assert!(polled_messages.messages[0].headers.is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn given_reject_policy_concurrently_appended_messages_should_not_exceed_max_topic_size() {
+        let mut topic = init_topic(3);
+        let message = Message::empty(1, MessageState::Available, 1, Bytes::from("test"), 1, None);
+        let message_size = message.get_size_bytes() as u64;
+        topic.max_topic_size = Some(IggyByteSize::from(message_size * 500));
+        topic.max_topic_size_policy = MaxTopicSizePolicy::Reject;
+        let topic = Arc::new(topic);
+
+        let tasks = (1..=2000u128).map(|id| {
+            let topic = topic.clone();
+            tokio::spawn(async move {
+                let message =
+                    Message::empty(1, MessageState::Available, id, Bytes::from("test"), 1, None);
+                topic
+                    .append_messages(&Partitioning::balanced(), vec![message])
+                    .await
+            })
+        });
+        let results = join_all(tasks).await;
+
+        let appended = results
+            .into_iter()
+            .filter(|result| result.as_ref().unwrap().is_ok())
+            .count();
+        assert_eq!(appended, 500);
+        assert_eq!(topic.get_size().await.as_bytes_u64(), message_size * 500);
+    }
+
     #[test]
     fn given_multiple_partitions_calculate_next_partition_id_should_return_next_partition_id_using_round_robin(
     ) {
@@ -392,6 +635,10 @@ mod tests {
             None,
             None,
             1,
+            None,
+            CleanupPolicy::Delete,
+            MaxTopicSizePolicy::DeleteOldest,
+            FlushPolicy::default(),
         )
         .unwrap()
     }
@@ -1,6 +1,8 @@
 use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::tiering;
 use crate::streaming::topics::topic::Topic;
 use iggy::error::Error;
+use iggy::models::partition_offsets::{PartitionOffsets, TimestampOffset};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -27,7 +29,7 @@ impl Topic {
 
         let mut partition_ids = Vec::with_capacity(count as usize);
         for partition_id in current_partitions_count + 1..=current_partitions_count + count {
-            let partition = Partition::create(
+            let mut partition = Partition::create(
                 self.stream_id,
                 self.topic_id,
                 partition_id,
@@ -36,6 +38,7 @@ impl Topic {
                 self.storage.clone(),
                 self.message_expiry,
             );
+            partition.flush_policy = self.flush_policy;
             self.partitions
                 .insert(partition_id, Arc::new(RwLock::new(partition)));
             partition_ids.push(partition_id)
@@ -81,6 +84,41 @@ impl Topic {
             messages_count,
         }))
     }
+
+    pub async fn get_partition_offsets(&self) -> Vec<PartitionOffsets> {
+        let mut offsets = Vec::with_capacity(self.partitions.len());
+        for partition in self.get_partitions() {
+            let partition = partition.read().await;
+            offsets.push(PartitionOffsets {
+                partition_id: partition.partition_id,
+                earliest_offset: partition.get_earliest_offset(),
+                latest_offset: partition.current_offset,
+            });
+        }
+        offsets.sort_by_key(|offset| offset.partition_id);
+        offsets
+    }
+
+    pub async fn get_offsets_for_timestamp(
+        &self,
+        timestamp: u64,
+    ) -> Result<Vec<TimestampOffset>, Error> {
+        let mut offsets = Vec::with_capacity(self.partitions.len());
+        for partition in self.get_partitions() {
+            let offset = tiering::read_partition(&partition, |partition| {
+                Box::pin(async move {
+                    Ok(TimestampOffset {
+                        partition_id: partition.partition_id,
+                        offset: partition.get_offset_for_timestamp(timestamp).await?,
+                    })
+                })
+            })
+            .await?;
+            offsets.push(offset);
+        }
+        offsets.sort_by_key(|offset| offset.partition_id);
+        Ok(offsets)
+    }
 }
 
 pub struct DeletedPartitions {
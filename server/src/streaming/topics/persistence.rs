@@ -20,6 +20,8 @@ impl Topic {
                     consumer_group.consumer_group_id,
                     &consumer_group.name,
                     self.get_partitions_count(),
+                    consumer_group.session_timeout,
+                    consumer_group.assignment_strategy,
                 )),
             );
         }
@@ -40,10 +42,22 @@ impl Topic {
     }
 
     pub async fn persist_messages(&self) -> Result<(), Error> {
+        for partition in self.get_partitions() {
+            partition.write().await.persist_messages().await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn persist_messages_exceeding_max_latency(&self, now: u64) -> Result<(), Error> {
+        if self.flush_policy.max_latency.is_none() {
+            return Ok(());
+        }
+
         for partition in self.get_partitions() {
             let mut partition = partition.write().await;
-            for segment in partition.get_segments_mut() {
-                segment.persist_messages().await?;
+            if partition.is_max_latency_exceeded(now) {
+                partition.persist_messages().await?;
             }
         }
 
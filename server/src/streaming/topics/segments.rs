@@ -1,4 +1,6 @@
 use crate::streaming::topics::topic::Topic;
+use iggy::error::Error;
+use tracing::{info, warn};
 
 impl Topic {
     pub async fn get_segments_count(&self) -> u32 {
@@ -9,4 +11,72 @@ impl Topic {
 
         segments_count
     }
+
+    /// Deletes the oldest closed segments across all the partitions until the topic size no longer exceeds the max topic size.
+    /// The open segments are never deleted. Returns the number of deleted segments and messages.
+    pub async fn delete_oldest_segments(&self) -> Result<(u32, u64), Error> {
+        let max_topic_size = match self.get_max_topic_size() {
+            Some(max_topic_size) => max_topic_size.as_bytes_u64(),
+            None => return Ok((0, 0)),
+        };
+
+        let mut topic_size = self.get_size().await.as_bytes_u64();
+        let mut segments_count = 0;
+        let mut messages_count = 0;
+        while topic_size > max_topic_size {
+            // The oldest segment is the one containing the oldest last message, as it's the first to be fully outdated.
+            let mut oldest_segment = None;
+            for partition in self.partitions.values() {
+                let partition_read = partition.read().await;
+                let Some(segment) = partition_read
+                    .get_segments()
+                    .iter()
+                    .find(|segment| segment.is_closed)
+                else {
+                    continue;
+                };
+
+                let Some(timestamp) = segment.last_timestamp else {
+                    continue;
+                };
+
+                if oldest_segment
+                    .as_ref()
+                    .is_none_or(|(_, _, oldest_timestamp)| timestamp < *oldest_timestamp)
+                {
+                    oldest_segment = Some((partition.clone(), segment.start_offset, timestamp));
+                }
+            }
+
+            let Some((partition, start_offset, _)) = oldest_segment else {
+                warn!(
+                    "Topic with ID: {} for stream with ID: {} exceeds max topic size: {} bytes, but there are no closed segments to delete.",
+                    self.topic_id, self.stream_id, max_topic_size
+                );
+                break;
+            };
+
+            let mut partition = partition.write().await;
+            let size_before = partition.get_size_bytes();
+            let deleted_segment = partition.delete_segment(start_offset).await?;
+            if partition.get_segments().is_empty() {
+                partition
+                    .add_persisted_segment(deleted_segment.end_offset + 1)
+                    .await?;
+            }
+
+            topic_size = topic_size.saturating_sub(size_before - partition.get_size_bytes());
+            segments_count += 1;
+            messages_count += deleted_segment.messages_count;
+        }
+
+        if segments_count > 0 {
+            info!(
+                "Deleted {} oldest segments with {} messages for topic with ID: {} for stream with ID: {}, topic size: {} bytes, max topic size: {} bytes.",
+                segments_count, messages_count, self.topic_id, self.stream_id, topic_size, max_topic_size
+            );
+        }
+
+        Ok((segments_count, messages_count))
+    }
 }
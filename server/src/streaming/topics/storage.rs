@@ -1,3 +1,4 @@
+use crate::streaming::metadata::database::Database;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::storage::{Storage, TopicStorage};
 use crate::streaming::topics::consumer_group::ConsumerGroup;
@@ -5,10 +6,12 @@ use crate::streaming::topics::topic::Topic;
 use anyhow::Context;
 use async_trait::async_trait;
 use futures::future::join_all;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::Error;
+use iggy::models::consumer_group::PartitionAssignmentStrategy;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::utils::byte_size::IggyByteSize;
 use serde::{Deserialize, Serialize};
-use sled::Db;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::fs;
@@ -18,11 +21,11 @@ use tracing::{error, info};
 
 #[derive(Debug)]
 pub struct FileTopicStorage {
-    db: Arc<Db>,
+    db: Arc<dyn Database>,
 }
 
 impl FileTopicStorage {
-    pub fn new(db: Arc<Db>) -> Self {
+    pub fn new(db: Arc<dyn Database>) -> Self {
         Self { db }
     }
 }
@@ -34,6 +37,10 @@ unsafe impl Sync for FileTopicStorage {}
 struct ConsumerGroupData {
     id: u32,
     name: String,
+    #[serde(default)]
+    session_timeout: Option<u32>,
+    #[serde(default)]
+    assignment_strategy: PartitionAssignmentStrategy,
 }
 
 #[async_trait]
@@ -51,13 +58,15 @@ impl TopicStorage for FileTopicStorage {
         match rmp_serde::to_vec(&ConsumerGroupData {
             id: consumer_group.consumer_group_id,
             name: consumer_group.name.clone(),
+            session_timeout: consumer_group.session_timeout,
+            assignment_strategy: consumer_group.assignment_strategy,
         })
         .with_context(|| format!("Failed to serialize consumer group with key: {}", key))
         {
             Ok(data) => {
                 if let Err(err) = self
                     .db
-                    .insert(&key, data)
+                    .insert(&key, &data)
                     .with_context(|| format!("Failed to insert consumer group with key: {}", key))
                 {
                     return Err(Error::CannotSaveResource(err));
@@ -76,7 +85,7 @@ impl TopicStorage for FileTopicStorage {
 
         let key_prefix = get_consumer_groups_key_prefix(topic.stream_id, topic.topic_id);
         let mut consumer_groups = Vec::new();
-        for data in self.db.scan_prefix(format!("{}:", key_prefix)) {
+        for data in self.db.scan_prefix(&format!("{}:", key_prefix)) {
             let consumer_group = match data.with_context(|| {
                 format!(
                     "Failed to load consumer group when searching for key: {}",
@@ -104,6 +113,8 @@ impl TopicStorage for FileTopicStorage {
                 consumer_group.id,
                 &consumer_group.name,
                 topic.get_partitions_count(),
+                consumer_group.session_timeout,
+                consumer_group.assignment_strategy,
             );
             consumer_groups.push(consumer_group);
         }
@@ -147,6 +158,14 @@ struct TopicData {
     message_expiry: Option<u32>,
     max_topic_size: Option<IggyByteSize>,
     replication_factor: u8,
+    #[serde(default)]
+    compression_algorithm: Option<CompressionAlgorithm>,
+    #[serde(default)]
+    cleanup_policy: CleanupPolicy,
+    #[serde(default)]
+    max_topic_size_policy: MaxTopicSizePolicy,
+    #[serde(default)]
+    flush_policy: FlushPolicy,
 }
 
 #[async_trait]
@@ -186,6 +205,11 @@ impl Storage<Topic> for FileTopicStorage {
         topic.message_expiry = topic_data.message_expiry;
         topic.max_topic_size = topic_data.max_topic_size;
         topic.replication_factor = topic_data.replication_factor;
+        topic.compression_algorithm =
+            Topic::get_compression_algorithm(&topic.config, topic_data.compression_algorithm);
+        topic.cleanup_policy = topic_data.cleanup_policy;
+        topic.max_topic_size_policy = topic_data.max_topic_size_policy;
+        topic.flush_policy = topic_data.flush_policy;
 
         let dir_entries = fs::read_dir(&topic.partitions_path).await
             .with_context(|| format!("Failed to read partition with ID: {} for stream with ID: {} for topic with ID: {} and path: {}",
@@ -210,7 +234,7 @@ impl Storage<Topic> for FileTopicStorage {
             }
 
             let partition_id = partition_id.unwrap();
-            let partition = Partition::create(
+            let mut partition = Partition::create(
                 topic.stream_id,
                 topic.topic_id,
                 partition_id,
@@ -219,6 +243,7 @@ impl Storage<Topic> for FileTopicStorage {
                 topic.storage.clone(),
                 topic.message_expiry,
             );
+            partition.flush_policy = topic.flush_policy;
             unloaded_partitions.push(partition);
         }
 
@@ -283,13 +308,17 @@ impl Storage<Topic> for FileTopicStorage {
             message_expiry: topic.message_expiry,
             max_topic_size: topic.max_topic_size,
             replication_factor: topic.replication_factor,
+            compression_algorithm: Some(topic.compression_algorithm),
+            cleanup_policy: topic.cleanup_policy,
+            max_topic_size_policy: topic.max_topic_size_policy,
+            flush_policy: topic.flush_policy,
         })
         .with_context(|| format!("Failed to serialize topic with key: {key}"))
         {
             Ok(data) => {
                 if let Err(err) = self
                     .db
-                    .insert(&key, data)
+                    .insert(&key, &data)
                     .with_context(|| format!("Failed to insert topic with key: {key}"))
                 {
                     return Err(Error::CannotSaveResource(err));
@@ -3,13 +3,15 @@ use crate::streaming::partitions::partition::Partition;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use core::fmt;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::Error;
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicU32;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 
 #[derive(Debug)]
 pub struct Topic {
@@ -27,7 +29,13 @@ pub struct Topic {
     pub message_expiry: Option<u32>,
     pub max_topic_size: Option<IggyByteSize>,
     pub replication_factor: u8,
+    pub compression_algorithm: CompressionAlgorithm,
+    pub cleanup_policy: CleanupPolicy,
+    pub max_topic_size_policy: MaxTopicSizePolicy,
+    pub flush_policy: FlushPolicy,
     pub created_at: u64,
+    // Serializes checking the topic size and appending the messages, when the topic rejects the messages once full.
+    pub(crate) size_check_lock: Mutex<()>,
 }
 
 impl Topic {
@@ -37,7 +45,22 @@ impl Topic {
         config: Arc<SystemConfig>,
         storage: Arc<SystemStorage>,
     ) -> Topic {
-        Topic::create(stream_id, topic_id, "", 0, config, storage, None, None, 1).unwrap()
+        Topic::create(
+            stream_id,
+            topic_id,
+            "",
+            0,
+            config,
+            storage,
+            None,
+            None,
+            1,
+            None,
+            CleanupPolicy::default(),
+            MaxTopicSizePolicy::default(),
+            FlushPolicy::default(),
+        )
+        .unwrap()
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -51,6 +74,10 @@ impl Topic {
         message_expiry: Option<u32>,
         max_topic_size: Option<IggyByteSize>,
         replication_factor: u8,
+        compression_algorithm: Option<CompressionAlgorithm>,
+        cleanup_policy: CleanupPolicy,
+        max_topic_size_policy: MaxTopicSizePolicy,
+        flush_policy: FlushPolicy,
     ) -> Result<Topic, Error> {
         let path = config.get_topic_path(stream_id, topic_id);
         let partitions_path = config.get_partitions_path(stream_id, topic_id);
@@ -77,14 +104,30 @@ impl Topic {
             },
             max_topic_size,
             replication_factor,
+            compression_algorithm: Topic::get_compression_algorithm(&config, compression_algorithm),
+            cleanup_policy,
+            max_topic_size_policy,
+            flush_policy,
             config,
             created_at: IggyTimestamp::now().to_micros(),
+            size_check_lock: Mutex::new(()),
         };
 
         topic.add_partitions(partitions_count)?;
         Ok(topic)
     }
 
+    // The requested algorithm is honoured only if overriding the server default is allowed.
+    pub fn get_compression_algorithm(
+        config: &SystemConfig,
+        compression_algorithm: Option<CompressionAlgorithm>,
+    ) -> CompressionAlgorithm {
+        match compression_algorithm {
+            Some(algorithm) if config.compression.allow_override => algorithm,
+            _ => config.compression.default_algorithm,
+        }
+    }
+
     pub async fn get_size(&self) -> IggyByteSize {
         let mut size_bytes = 0;
         for partition in self.get_partitions() {
@@ -94,6 +137,17 @@ impl Topic {
         IggyByteSize::from(size_bytes)
     }
 
+    // The topic without its own limit is bounded by the max topic size from the retention policy, if set.
+    pub fn get_max_topic_size(&self) -> Option<IggyByteSize> {
+        let max_topic_size = self
+            .max_topic_size
+            .unwrap_or(self.config.retention_policy.max_topic_size);
+        match max_topic_size.as_bytes_u64() {
+            0 => None,
+            _ => Some(max_topic_size),
+        }
+    }
+
     pub fn get_partitions(&self) -> Vec<Arc<RwLock<Partition>>> {
         self.partitions.values().map(Arc::clone).collect()
     }
@@ -123,7 +177,8 @@ impl fmt::Display for Topic {
         write!(f, "partitions count: {:?}, ", self.partitions.len())?;
         write!(f, "message expiry (s): {:?}, ", self.message_expiry)?;
         write!(f, "max topic size (B): {:?}, ", max_topic_size)?;
-        write!(f, "replication factor: {}, ", self.replication_factor)
+        write!(f, "replication factor: {}, ", self.replication_factor)?;
+        write!(f, "compression algorithm: {}, ", self.compression_algorithm)
     }
 }
 
@@ -132,6 +187,7 @@ mod tests {
     use std::str::FromStr;
 
     use super::*;
+    use crate::configs::system::CompressionConfig;
     use crate::streaming::storage::tests::get_test_system_storage;
 
     #[test]
@@ -157,6 +213,10 @@ mod tests {
             Some(message_expiry),
             Some(max_topic_size),
             replication_factor,
+            None,
+            CleanupPolicy::Delete,
+            MaxTopicSizePolicy::DeleteOldest,
+            FlushPolicy::default(),
         )
         .unwrap();
 
@@ -175,4 +235,40 @@ mod tests {
             assert_eq!(partition.segments.len(), 1);
         }
     }
+
+    #[test]
+    fn compression_algorithm_should_be_overridden_only_when_allowed() {
+        let storage = Arc::new(get_test_system_storage());
+        for (allow_override, expected_algorithm) in [
+            (false, CompressionAlgorithm::None),
+            (true, CompressionAlgorithm::Lz4),
+        ] {
+            let config = Arc::new(SystemConfig {
+                compression: CompressionConfig {
+                    allow_override,
+                    default_algorithm: CompressionAlgorithm::None,
+                },
+                ..Default::default()
+            });
+
+            let topic = Topic::create(
+                1,
+                2,
+                "test",
+                1,
+                config,
+                storage.clone(),
+                None,
+                None,
+                1,
+                Some(CompressionAlgorithm::Lz4),
+                CleanupPolicy::Delete,
+                MaxTopicSizePolicy::DeleteOldest,
+                FlushPolicy::default(),
+            )
+            .unwrap();
+
+            assert_eq!(topic.compression_algorithm, expected_algorithm);
+        }
+    }
 }
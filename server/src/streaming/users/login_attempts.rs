@@ -0,0 +1,450 @@
+use crate::configs::system::LoginAttemptsConfig;
+use iggy::error::Error;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::warn;
+
+const MAX_TRACKED_FAILURES: usize = 10_000;
+
+/// Tracks the consecutive failed login attempts per username and per source IP address.
+/// Once the free attempts are used, each next failure blocks the further attempts for an exponentially growing backoff,
+/// and reaching the max failed attempts locks the username (or IP address) for the lockout duration.
+/// The failures are forgotten after the lockout duration passes since the last one, or on the successful login.
+/// The unknown usernames are tracked the same way as the existing ones, so the lockout doesn't reveal whether the user exists.
+/// To keep the memory bounded, the least recently failed username (or IP address) is evicted once the limit is reached.
+#[derive(Debug)]
+pub struct LoginAttempts {
+    config: LoginAttemptsConfig,
+    failures: Mutex<Failures>,
+}
+
+#[derive(Debug, Default)]
+struct Failures {
+    usernames: HashMap<String, FailedAttempts>,
+    ip_addresses: HashMap<IpAddr, FailedAttempts>,
+    pruned_at: u64,
+}
+
+#[derive(Debug, Default)]
+struct FailedAttempts {
+    count: u32,
+    last_failure_at: u64,
+    blocked_until: u64,
+    locked: bool,
+}
+
+impl LoginAttempts {
+    pub fn new(config: &LoginAttemptsConfig) -> Self {
+        Self {
+            config: *config,
+            failures: Mutex::new(Failures::default()),
+        }
+    }
+
+    /// Returns an error if the login attempts for the username or from the IP address are currently blocked.
+    pub fn ensure_allowed(&self, username: &str, ip_address: Option<IpAddr>) -> Result<(), Error> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        self.ensure_allowed_at(username, ip_address, IggyTimestamp::now().to_micros())
+    }
+
+    /// Returns an error if the login attempts from the IP address are currently blocked.
+    /// Unlike the password, the other login methods can't be guessed, so they're only throttled per IP address,
+    /// and the failed password guesses can't lock the user out of them.
+    pub fn ensure_ip_address_allowed(&self, ip_address: Option<IpAddr>) -> Result<(), Error> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        let now = IggyTimestamp::now().to_micros();
+        let mut failures = self.failures.lock().unwrap();
+        self.ensure_ip_address_allowed_at(&mut failures, ip_address, now)
+    }
+
+    /// Records the failed login attempt, regardless of whether the user exists.
+    pub fn record_failure(&self, username: &str, ip_address: Option<IpAddr>) {
+        if !self.config.enabled {
+            return;
+        }
+
+        self.record_failure_at(username, ip_address, IggyTimestamp::now().to_micros());
+    }
+
+    pub fn record_success(&self, username: &str) {
+        if !self.config.enabled {
+            return;
+        }
+
+        self.failures.lock().unwrap().usernames.remove(username);
+    }
+
+    /// Resets the failed login attempts of the username, returns true if it was locked.
+    pub fn unlock(&self, username: &str) -> bool {
+        self.failures
+            .lock()
+            .unwrap()
+            .usernames
+            .remove(username)
+            .is_some_and(|attempts| attempts.locked)
+    }
+
+    /// Returns the timestamp until which the username is locked, if any.
+    pub fn get_locked_until(&self, username: &str) -> Option<u64> {
+        let now = IggyTimestamp::now().to_micros();
+        let failures = self.failures.lock().unwrap();
+        failures
+            .usernames
+            .get(username)
+            .filter(|attempts| attempts.locked && attempts.blocked_until > now)
+            .map(|attempts| attempts.blocked_until)
+    }
+
+    fn ensure_allowed_at(
+        &self,
+        username: &str,
+        ip_address: Option<IpAddr>,
+        now: u64,
+    ) -> Result<(), Error> {
+        let lockout_duration = self.config.lockout_duration.as_micros();
+        let mut failures = self.failures.lock().unwrap();
+        self.ensure_ip_address_allowed_at(&mut failures, ip_address, now)?;
+        remove_expired(&mut failures.usernames, username, now, lockout_duration);
+        if let Some(attempts) = failures.usernames.get(username) {
+            if attempts.locked {
+                return Err(Error::UserLocked(username.to_string()));
+            }
+
+            if attempts.blocked_until > now {
+                return Err(Error::TooManyLoginAttempts(format_retry_after(
+                    attempts.blocked_until - now,
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn ensure_ip_address_allowed_at(
+        &self,
+        failures: &mut Failures,
+        ip_address: Option<IpAddr>,
+        now: u64,
+    ) -> Result<(), Error> {
+        let Some(ip_address) = ip_address else {
+            return Ok(());
+        };
+
+        remove_expired(
+            &mut failures.ip_addresses,
+            &ip_address,
+            now,
+            self.config.lockout_duration.as_micros(),
+        );
+        if let Some(attempts) = failures.ip_addresses.get(&ip_address) {
+            if attempts.blocked_until > now {
+                return Err(Error::TooManyLoginAttempts(format_retry_after(
+                    attempts.blocked_until - now,
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn record_failure_at(&self, username: &str, ip_address: Option<IpAddr>, now: u64) {
+        let lockout_duration = self.config.lockout_duration.as_micros();
+        let mut failures = self.failures.lock().unwrap();
+        failures.prune(now, lockout_duration);
+        remove_expired(&mut failures.usernames, username, now, lockout_duration);
+        if !failures.usernames.contains_key(username) {
+            evict_least_recent(&mut failures.usernames);
+        }
+        let attempts = failures.usernames.entry(username.to_string()).or_default();
+        if attempts.fail(now, self.config.user_max_failed_attempts, &self.config) {
+            warn!(
+                "Username: {username} has been locked after {} failed login attempts.",
+                attempts.count
+            );
+        }
+
+        if let Some(ip_address) = ip_address {
+            remove_expired(
+                &mut failures.ip_addresses,
+                &ip_address,
+                now,
+                lockout_duration,
+            );
+            if !failures.ip_addresses.contains_key(&ip_address) {
+                evict_least_recent(&mut failures.ip_addresses);
+            }
+            let attempts = failures.ip_addresses.entry(ip_address).or_default();
+            if attempts.fail(now, self.config.ip_max_failed_attempts, &self.config) {
+                warn!(
+                    "IP address: {ip_address} has been locked after {} failed login attempts.",
+                    attempts.count
+                );
+            }
+        }
+    }
+}
+
+impl Failures {
+    /// Removes all the expired failures, at most once per the lockout duration, as it has to scan all the entries.
+    fn prune(&mut self, now: u64, lockout_duration: u64) {
+        if self.pruned_at + lockout_duration > now {
+            return;
+        }
+
+        self.pruned_at = now;
+        self.usernames
+            .retain(|_, attempts| !attempts.is_expired(now, lockout_duration));
+        self.ip_addresses
+            .retain(|_, attempts| !attempts.is_expired(now, lockout_duration));
+    }
+}
+
+impl FailedAttempts {
+    fn is_expired(&self, now: u64, lockout_duration: u64) -> bool {
+        self.blocked_until <= now && self.last_failure_at + lockout_duration <= now
+    }
+
+    /// Records the failure, returns true if it results in the lockout.
+    fn fail(&mut self, now: u64, max_failed_attempts: u32, config: &LoginAttemptsConfig) -> bool {
+        self.count += 1;
+        self.last_failure_at = now;
+        if self.locked {
+            return false;
+        }
+
+        if self.count >= max_failed_attempts {
+            self.locked = true;
+            self.blocked_until = now + config.lockout_duration.as_micros();
+            return true;
+        }
+
+        if self.count > config.free_attempts {
+            let exponent = (self.count - config.free_attempts - 1).min(31);
+            let backoff = config
+                .initial_backoff
+                .as_micros()
+                .saturating_mul(1 << exponent)
+                .min(config.max_backoff.as_micros());
+            self.blocked_until = now + backoff;
+        }
+        false
+    }
+}
+
+fn remove_expired<K, Q>(
+    failures: &mut HashMap<K, FailedAttempts>,
+    key: &Q,
+    now: u64,
+    lockout_duration: u64,
+) where
+    K: Borrow<Q> + Hash + Eq,
+    Q: Hash + Eq + ?Sized,
+{
+    if failures
+        .get(key)
+        .is_some_and(|attempts| attempts.is_expired(now, lockout_duration))
+    {
+        failures.remove(key);
+    }
+}
+
+/// Makes room for the new entry by removing the least recently failed one, if the limit is reached.
+/// The locked entries are evicted last, so the lockout can't be easily lifted by failing for many other usernames.
+fn evict_least_recent<K>(failures: &mut HashMap<K, FailedAttempts>)
+where
+    K: Clone + Hash + Eq,
+{
+    if failures.len() < MAX_TRACKED_FAILURES {
+        return;
+    }
+
+    let least_recent = failures
+        .iter()
+        .min_by_key(|(_, attempts)| (attempts.locked, attempts.last_failure_at))
+        .map(|(key, _)| key.clone());
+    if let Some(key) = least_recent {
+        failures.remove(&key);
+    }
+}
+
+fn format_retry_after(micros: u64) -> String {
+    IggyDuration::new(Duration::from_secs(micros.div_ceil(1_000_000))).as_human_time_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USERNAME: &str = "user";
+    const SECOND: u64 = 1_000_000;
+
+    #[test]
+    fn failed_attempts_should_be_blocked_with_exponential_backoff() {
+        let login_attempts = LoginAttempts::new(&get_config());
+        let ip_address = Some("127.0.0.1".parse().unwrap());
+        let now = 1000 * SECOND;
+        for _ in 0..3 {
+            assert!(login_attempts
+                .ensure_allowed_at(USERNAME, ip_address, now)
+                .is_ok());
+            login_attempts.record_failure_at(USERNAME, ip_address, now);
+        }
+        assert!(login_attempts
+            .ensure_allowed_at(USERNAME, ip_address, now)
+            .is_ok());
+
+        login_attempts.record_failure_at(USERNAME, ip_address, now);
+        let result = login_attempts.ensure_allowed_at(USERNAME, ip_address, now);
+        assert!(matches!(result, Err(Error::TooManyLoginAttempts(_))));
+        assert!(login_attempts
+            .ensure_allowed_at(USERNAME, ip_address, now + SECOND)
+            .is_ok());
+
+        login_attempts.record_failure_at(USERNAME, ip_address, now + SECOND);
+        assert!(login_attempts
+            .ensure_allowed_at(USERNAME, ip_address, now + 2 * SECOND)
+            .is_err());
+        assert!(login_attempts
+            .ensure_allowed_at(USERNAME, ip_address, now + 3 * SECOND)
+            .is_ok());
+        assert!(login_attempts
+            .ensure_allowed_at("other", None, now + SECOND)
+            .is_ok());
+    }
+
+    #[test]
+    fn username_should_be_locked_after_max_failed_attempts_until_unlocked() {
+        let login_attempts = LoginAttempts::new(&get_config());
+        let now = 1000 * SECOND;
+        for _ in 0..6 {
+            login_attempts.record_failure_at(USERNAME, None, now);
+        }
+
+        let result = login_attempts.ensure_allowed_at(USERNAME, None, now + 100 * SECOND);
+        assert!(matches!(result, Err(Error::UserLocked(_))));
+        assert!(login_attempts
+            .ensure_allowed_at(USERNAME, None, now + 600 * SECOND)
+            .is_ok());
+
+        for _ in 0..6 {
+            login_attempts.record_failure_at(USERNAME, None, now + 600 * SECOND);
+        }
+        assert!(login_attempts
+            .ensure_allowed_at(USERNAME, None, now + 700 * SECOND)
+            .is_err());
+        assert!(login_attempts.unlock(USERNAME));
+        assert!(login_attempts
+            .ensure_allowed_at(USERNAME, None, now + 700 * SECOND)
+            .is_ok());
+    }
+
+    #[test]
+    fn ip_address_should_be_locked_after_max_failed_attempts_for_any_username() {
+        let login_attempts = LoginAttempts::new(&get_config());
+        let ip_address = Some("127.0.0.1".parse().unwrap());
+        let now = 1000 * SECOND;
+        for i in 0..10 {
+            login_attempts.record_failure_at(&format!("user{i}"), ip_address, now);
+        }
+
+        let result = login_attempts.ensure_allowed_at(USERNAME, ip_address, now + SECOND);
+        assert!(matches!(result, Err(Error::TooManyLoginAttempts(_))));
+        assert!(login_attempts
+            .ensure_allowed_at(USERNAME, None, now + SECOND)
+            .is_ok());
+    }
+
+    #[test]
+    fn locked_username_should_not_block_ip_address_check() {
+        let login_attempts = LoginAttempts::new(&get_config());
+        let ip_address = Some("127.0.0.1".parse().unwrap());
+        let now = 1000 * SECOND;
+        for _ in 0..6 {
+            login_attempts.record_failure_at(USERNAME, None, now);
+        }
+
+        let mut failures = login_attempts.failures.lock().unwrap();
+        assert!(login_attempts
+            .ensure_ip_address_allowed_at(&mut failures, ip_address, now)
+            .is_ok());
+        drop(failures);
+
+        for _ in 0..4 {
+            login_attempts.record_failure_at("other", ip_address, now);
+        }
+        let mut failures = login_attempts.failures.lock().unwrap();
+        let result = login_attempts.ensure_ip_address_allowed_at(&mut failures, ip_address, now);
+        assert!(matches!(result, Err(Error::TooManyLoginAttempts(_))));
+    }
+
+    #[test]
+    fn successful_login_should_reset_failed_attempts() {
+        let login_attempts = LoginAttempts::new(&get_config());
+        let now = 1000 * SECOND;
+        for _ in 0..4 {
+            login_attempts.record_failure_at(USERNAME, None, now);
+        }
+
+        login_attempts.record_success(USERNAME);
+        assert!(login_attempts
+            .ensure_allowed_at(USERNAME, None, now)
+            .is_ok());
+    }
+
+    #[test]
+    fn tracked_usernames_should_be_limited_by_evicting_least_recent_unlocked_failure() {
+        let login_attempts = LoginAttempts::new(&get_config());
+        let now = 1000 * SECOND;
+        for _ in 0..6 {
+            login_attempts.record_failure_at(USERNAME, None, now);
+        }
+        for i in 0..MAX_TRACKED_FAILURES - 1 {
+            login_attempts.record_failure_at(&format!("user{i}"), None, now + SECOND);
+        }
+
+        login_attempts.record_failure_at("other", None, now + 2 * SECOND);
+        let result = login_attempts.ensure_allowed_at(USERNAME, None, now + 2 * SECOND);
+        assert!(matches!(result, Err(Error::UserLocked(_))));
+        let failures = login_attempts.failures.lock().unwrap();
+        assert_eq!(failures.usernames.len(), MAX_TRACKED_FAILURES);
+        assert!(failures.usernames.contains_key("other"));
+    }
+
+    #[test]
+    fn expired_failures_should_be_pruned() {
+        let login_attempts = LoginAttempts::new(&get_config());
+        let now = 1000 * SECOND;
+        for i in 0..10 {
+            login_attempts.record_failure_at(&format!("user{i}"), None, now);
+        }
+
+        login_attempts.record_failure_at(USERNAME, None, now + 600 * SECOND);
+        let failures = login_attempts.failures.lock().unwrap();
+        assert_eq!(failures.usernames.len(), 1);
+        assert!(failures.usernames.contains_key(USERNAME));
+    }
+
+    fn get_config() -> LoginAttemptsConfig {
+        LoginAttemptsConfig {
+            enabled: true,
+            free_attempts: 3,
+            initial_backoff: "1s".parse().unwrap(),
+            max_backoff: "10s".parse().unwrap(),
+            user_max_failed_attempts: 6,
+            ip_max_failed_attempts: 10,
+            lockout_duration: "5m".parse().unwrap(),
+        }
+    }
+}
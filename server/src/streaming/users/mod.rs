@@ -1,3 +1,4 @@
+pub mod login_attempts;
 pub mod permissioner;
 pub mod permissioner_rules;
 pub mod storage;
@@ -1,5 +1,9 @@
+use crate::streaming::roles::role::Role;
 use crate::streaming::users::user::User;
-use iggy::models::permissions::{GlobalPermissions, StreamPermissions};
+use iggy::models::permissions::{
+    GlobalPermissions, Permissions, StreamPermissions, TopicPermissions,
+};
+use iggy::models::role_info::RoleId;
 use iggy::models::user_info::UserId;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -12,32 +16,94 @@ pub struct Permissioner {
     pub(super) users_that_can_send_messages_to_all_streams: HashSet<UserId>,
     pub(super) users_that_can_poll_messages_from_specific_streams: HashSet<(UserId, u32)>,
     pub(super) users_that_can_send_messages_to_specific_streams: HashSet<(UserId, u32)>,
+    users_own_permissions: HashMap<UserId, Permissions>,
+    users_roles: HashMap<UserId, Vec<RoleId>>,
+    roles_permissions: HashMap<RoleId, Permissions>,
 }
 
 impl Permissioner {
-    pub fn init(&mut self, users: Vec<User>) {
+    pub fn init(&mut self, users: Vec<User>, roles: Vec<Role>) {
+        for role in roles {
+            self.roles_permissions.insert(role.id, role.permissions);
+        }
+
         for user in users {
             self.init_permissions_for_user(user);
         }
     }
 
     pub fn init_permissions_for_user(&mut self, user: User) {
-        if user.permissions.is_none() {
-            return;
+        if let Some(permissions) = user.permissions {
+            self.users_own_permissions.insert(user.id, permissions);
+        }
+
+        if !user.roles.is_empty() {
+            self.users_roles.insert(user.id, user.roles);
+        }
+
+        self.init_effective_permissions_for_user(user.id);
+    }
+
+    pub fn update_permissions_for_user(&mut self, user: User) {
+        self.delete_permissions_for_user(user.id);
+        self.init_permissions_for_user(user);
+    }
+
+    pub fn delete_permissions_for_user(&mut self, user_id: UserId) {
+        self.users_own_permissions.remove(&user_id);
+        self.users_roles.remove(&user_id);
+        self.delete_effective_permissions_for_user(user_id);
+    }
+
+    pub fn update_permissions_for_role(&mut self, role_id: RoleId, permissions: Permissions) {
+        self.roles_permissions.insert(role_id, permissions);
+        for user_id in self.get_users_with_role(role_id) {
+            self.refresh_effective_permissions_for_user(user_id);
+        }
+    }
+
+    pub fn delete_permissions_for_role(&mut self, role_id: RoleId) {
+        self.roles_permissions.remove(&role_id);
+        for user_id in self.get_users_with_role(role_id) {
+            if let Some(roles) = self.users_roles.get_mut(&user_id) {
+                roles.retain(|id| *id != role_id);
+                if roles.is_empty() {
+                    self.users_roles.remove(&user_id);
+                }
+            }
+            self.refresh_effective_permissions_for_user(user_id);
         }
+    }
+
+    fn get_users_with_role(&self, role_id: RoleId) -> Vec<UserId> {
+        self.users_roles
+            .iter()
+            .filter(|(_, roles)| roles.contains(&role_id))
+            .map(|(user_id, _)| *user_id)
+            .collect()
+    }
+
+    fn refresh_effective_permissions_for_user(&mut self, user_id: UserId) {
+        self.delete_effective_permissions_for_user(user_id);
+        self.init_effective_permissions_for_user(user_id);
+    }
+
+    fn init_effective_permissions_for_user(&mut self, user_id: UserId) {
+        let Some(permissions) = self.get_effective_permissions(user_id) else {
+            return;
+        };
 
-        let permissions = user.permissions.unwrap();
         if permissions.global.poll_messages {
             self.users_that_can_poll_messages_from_all_streams
-                .insert(user.id);
+                .insert(user_id);
         }
 
         if permissions.global.send_messages {
             self.users_that_can_send_messages_to_all_streams
-                .insert(user.id);
+                .insert(user_id);
         }
 
-        self.users_permissions.insert(user.id, permissions.global);
+        self.users_permissions.insert(user_id, permissions.global);
         if permissions.streams.is_none() {
             return;
         }
@@ -46,25 +112,41 @@ impl Permissioner {
         for (stream_id, stream) in streams {
             if stream.poll_messages {
                 self.users_that_can_poll_messages_from_specific_streams
-                    .insert((user.id, stream_id));
+                    .insert((user_id, stream_id));
             }
 
             if stream.send_messages {
                 self.users_that_can_send_messages_to_specific_streams
-                    .insert((user.id, stream_id));
+                    .insert((user_id, stream_id));
             }
 
             self.users_streams_permissions
-                .insert((user.id, stream_id), stream);
+                .insert((user_id, stream_id), stream);
         }
     }
 
-    pub fn update_permissions_for_user(&mut self, user: User) {
-        self.delete_permissions_for_user(user.id);
-        self.init_permissions_for_user(user);
+    /// Returns the union of the user's own permissions and the permissions of all its roles.
+    fn get_effective_permissions(&self, user_id: UserId) -> Option<Permissions> {
+        let mut effective_permissions = self.users_own_permissions.get(&user_id).cloned();
+        let Some(roles) = self.users_roles.get(&user_id) else {
+            return effective_permissions;
+        };
+
+        for role_id in roles {
+            let Some(role_permissions) = self.roles_permissions.get(role_id) else {
+                continue;
+            };
+
+            effective_permissions = Some(match effective_permissions {
+                Some(permissions) => merge_permissions(permissions, role_permissions),
+                None => role_permissions.clone(),
+            });
+        }
+
+        effective_permissions
     }
 
-    pub fn delete_permissions_for_user(&mut self, user_id: UserId) {
+    fn delete_effective_permissions_for_user(&mut self, user_id: UserId) {
         self.users_permissions.remove(&user_id);
         self.users_that_can_poll_messages_from_all_streams
             .remove(&user_id);
@@ -78,3 +160,128 @@ impl Permissioner {
             .retain(|(id, _)| *id != user_id);
     }
 }
+
+fn merge_permissions(mut permissions: Permissions, other: &Permissions) -> Permissions {
+    let global = &mut permissions.global;
+    global.manage_servers |= other.global.manage_servers;
+    global.read_servers |= other.global.read_servers;
+    global.manage_users |= other.global.manage_users;
+    global.read_users |= other.global.read_users;
+    global.manage_streams |= other.global.manage_streams;
+    global.read_streams |= other.global.read_streams;
+    global.manage_topics |= other.global.manage_topics;
+    global.read_topics |= other.global.read_topics;
+    global.poll_messages |= other.global.poll_messages;
+    global.send_messages |= other.global.send_messages;
+
+    let Some(other_streams) = &other.streams else {
+        return permissions;
+    };
+
+    let streams = permissions.streams.get_or_insert_with(HashMap::new);
+    for (stream_id, other_stream) in other_streams {
+        let Some(stream) = streams.get_mut(stream_id) else {
+            streams.insert(*stream_id, other_stream.clone());
+            continue;
+        };
+
+        stream.manage_stream |= other_stream.manage_stream;
+        stream.read_stream |= other_stream.read_stream;
+        stream.manage_topics |= other_stream.manage_topics;
+        stream.read_topics |= other_stream.read_topics;
+        stream.poll_messages |= other_stream.poll_messages;
+        stream.send_messages |= other_stream.send_messages;
+
+        let Some(other_topics) = &other_stream.topics else {
+            continue;
+        };
+
+        let topics = stream.topics.get_or_insert_with(HashMap::new);
+        for (topic_id, other_topic) in other_topics {
+            let topic = topics
+                .entry(*topic_id)
+                .or_insert_with(TopicPermissions::default);
+            topic.manage_topic |= other_topic.manage_topic;
+            topic.read_topic |= other_topic.read_topic;
+            topic.poll_messages |= other_topic.poll_messages;
+            topic.send_messages |= other_topic.send_messages;
+        }
+    }
+
+    permissions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_permissions_should_be_extended_with_role_permissions() {
+        let mut permissioner = Permissioner::default();
+        let mut user = User::empty(2);
+        user.permissions = Some(Permissions {
+            global: GlobalPermissions {
+                read_streams: true,
+                ..Default::default()
+            },
+            streams: None,
+        });
+        user.roles = vec![1];
+        let role = Role::new(
+            1,
+            "readers",
+            Permissions {
+                global: GlobalPermissions {
+                    read_users: true,
+                    ..Default::default()
+                },
+                streams: Some(HashMap::from([(
+                    3,
+                    StreamPermissions {
+                        poll_messages: true,
+                        ..Default::default()
+                    },
+                )])),
+            },
+        );
+
+        permissioner.init(vec![user], vec![role]);
+
+        let global_permissions = permissioner.users_permissions.get(&2).unwrap();
+        assert!(global_permissions.read_streams);
+        assert!(global_permissions.read_users);
+        assert!(!global_permissions.manage_users);
+        assert!(permissioner
+            .users_that_can_poll_messages_from_specific_streams
+            .contains(&(2, 3)));
+    }
+
+    #[test]
+    fn role_permissions_should_be_removed_from_user_when_role_is_deleted() {
+        let mut permissioner = Permissioner::default();
+        let mut user = User::empty(2);
+        user.roles = vec![1];
+        let role = Role::new(
+            1,
+            "senders",
+            Permissions {
+                global: GlobalPermissions {
+                    send_messages: true,
+                    ..Default::default()
+                },
+                streams: None,
+            },
+        );
+
+        permissioner.init(vec![user], vec![role]);
+        assert!(permissioner
+            .users_that_can_send_messages_to_all_streams
+            .contains(&2));
+
+        permissioner.delete_permissions_for_role(1);
+        assert!(!permissioner
+            .users_that_can_send_messages_to_all_streams
+            .contains(&2));
+        assert!(!permissioner.users_permissions.contains_key(&2));
+    }
+}
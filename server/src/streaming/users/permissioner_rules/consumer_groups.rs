@@ -20,6 +20,15 @@ impl Permissioner {
         self.update_topic(user_id, stream_id, topic_id)
     }
 
+    pub fn reset_consumer_group_offsets(
+        &self,
+        user_id: u32,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), Error> {
+        self.update_topic(user_id, stream_id, topic_id)
+    }
+
     pub fn get_consumer_group(
         &self,
         user_id: u32,
@@ -86,4 +86,8 @@ impl Permissioner {
 
         Err(Error::Unauthorized)
     }
+
+    pub fn reject_message(&self, user_id: u32, stream_id: u32, topic_id: u32) -> Result<(), Error> {
+        self.poll_messages(user_id, stream_id, topic_id)
+    }
 }
@@ -14,6 +14,26 @@ impl Permissioner {
         self.get_server_info(user_id)
     }
 
+    pub fn get_audit_log(&self, user_id: u32) -> Result<(), Error> {
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(Error::Unauthorized)
+    }
+
+    pub fn fetch_replica_messages(&self, user_id: u32) -> Result<(), Error> {
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(Error::Unauthorized)
+    }
+
     fn get_server_info(&self, user_id: u32) -> Result<(), Error> {
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_servers || global_permissions.read_servers {
@@ -30,6 +30,34 @@ impl Permissioner {
         self.manager_users(user_id)
     }
 
+    pub fn unlock_user(&self, user_id: u32) -> Result<(), Error> {
+        self.manager_users(user_id)
+    }
+
+    pub fn get_role(&self, user_id: u32) -> Result<(), Error> {
+        self.read_users(user_id)
+    }
+
+    pub fn get_roles(&self, user_id: u32) -> Result<(), Error> {
+        self.read_users(user_id)
+    }
+
+    pub fn create_role(&self, user_id: u32) -> Result<(), Error> {
+        self.manager_users(user_id)
+    }
+
+    pub fn delete_role(&self, user_id: u32) -> Result<(), Error> {
+        self.manager_users(user_id)
+    }
+
+    pub fn update_role(&self, user_id: u32) -> Result<(), Error> {
+        self.manager_users(user_id)
+    }
+
+    pub fn assign_roles(&self, user_id: u32) -> Result<(), Error> {
+        self.manager_users(user_id)
+    }
+
     fn manager_users(&self, user_id: u32) -> Result<(), Error> {
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_users {
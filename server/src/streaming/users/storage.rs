@@ -1,10 +1,10 @@
+use crate::streaming::metadata::database::Database;
 use crate::streaming::storage::{Storage, UserStorage};
 use crate::streaming::users::user::User;
 use anyhow::Context;
 use async_trait::async_trait;
 use iggy::error::Error;
 use iggy::models::user_info::UserId;
-use sled::Db;
 use std::sync::Arc;
 use tracing::info;
 
@@ -12,11 +12,11 @@ const KEY_PREFIX: &str = "users";
 
 #[derive(Debug)]
 pub struct FileUserStorage {
-    db: Arc<Db>,
+    db: Arc<dyn Database>,
 }
 
 impl FileUserStorage {
-    pub fn new(db: Arc<Db>) -> Self {
+    pub fn new(db: Arc<dyn Database>) -> Self {
         Self { db }
     }
 }
@@ -43,7 +43,7 @@ impl UserStorage for FileUserStorage {
         match user_id {
             Ok(user_id) => {
                 if let Some(user_id) = user_id {
-                    let user_id = u32::from_le_bytes(user_id.as_ref().try_into()?);
+                    let user_id = u32::from_le_bytes(user_id.as_slice().try_into()?);
                     let mut user = User::empty(user_id);
                     self.load(&mut user).await?;
                     Ok(user)
@@ -57,7 +57,7 @@ impl UserStorage for FileUserStorage {
 
     async fn load_all(&self) -> Result<Vec<User>, Error> {
         let mut users = Vec::new();
-        for data in self.db.scan_prefix(format!("{}:", KEY_PREFIX)) {
+        for data in self.db.scan_prefix(&format!("{}:", KEY_PREFIX)) {
             let user = match data.with_context(|| {
                 format!(
                     "Failed to load user, when searching for key: {}",
@@ -117,6 +117,7 @@ impl Storage<User> for FileUserStorage {
                 user.password = user_data.password;
                 user.created_at = user_data.created_at;
                 user.permissions = user_data.permissions;
+                user.roles = user_data.roles;
                 Ok(())
             }
             Err(err) => {
@@ -133,14 +134,14 @@ impl Storage<User> for FileUserStorage {
             Ok(data) => {
                 if let Err(err) = self
                     .db
-                    .insert(&key, data)
+                    .insert(&key, &data)
                     .with_context(|| format!("Failed to insert user with key: {}", key))
                 {
                     return Err(Error::CannotSaveResource(err));
                 }
                 if let Err(err) = self
                     .db
-                    .insert(get_id_key(&user.username), &user.id.to_le_bytes())
+                    .insert(&get_id_key(&user.username), &user.id.to_le_bytes())
                     .with_context(|| {
                         format!(
                             "Failed to insert user with ID: {} key: {}",
@@ -1,4 +1,5 @@
 use crate::streaming::utils::crypto;
+use iggy::models::role_info::RoleId;
 use iggy::models::user_status::UserStatus;
 use iggy::models::{permissions::Permissions, user_info::UserId};
 use iggy::users::defaults::*;
@@ -13,6 +14,8 @@ pub struct User {
     pub password: String,
     pub created_at: u64,
     pub permissions: Option<Permissions>,
+    #[serde(default)]
+    pub roles: Vec<RoleId>,
 }
 
 impl Default for User {
@@ -24,6 +27,7 @@ impl Default for User {
             password: "secret".to_string(),
             created_at: IggyTimestamp::now().to_micros(),
             permissions: None,
+            roles: Vec::new(),
         }
     }
 }
@@ -50,6 +54,7 @@ impl User {
             created_at: IggyTimestamp::now().to_micros(),
             status,
             permissions,
+            roles: Vec::new(),
         }
     }
 
@@ -0,0 +1,215 @@
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::Error;
+use iggy::models::header::{self, HeaderKey, HeaderValue};
+use iggy::models::messages::Message;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::error;
+
+// Reserved header storing the code of the algorithm used to compress the payload on the server side.
+// It's persisted together with the message, so each message can be decompressed on its own,
+// regardless of the algorithm configured for the topic at the time of reading.
+// The payloads are compressed per message rather than per batch, as the messages of the batch are stored,
+// indexed, polled by offset, deduplicated and compacted one by one, so the batch would have to be
+// decompressed as a whole to read any of its messages and the segment format would have to change.
+// The smaller ratio for the tiny payloads is the price, which is why they're kept uncompressed if it doesn't pay off.
+pub const COMPRESSION_ALGORITHM_HEADER: &str = "iggy-compression-algorithm";
+
+/// Rejects the messages sent with the reserved compression header, instead of silently overwriting or dropping it.
+pub fn ensure_no_reserved_header(messages: &[Message]) -> Result<(), Error> {
+    let header_key = HeaderKey::new(COMPRESSION_ALGORITHM_HEADER)?;
+    for message in messages {
+        if let Some(headers) = &message.headers {
+            if headers.contains_key(&header_key) {
+                return Err(Error::ReservedHeaderKey(
+                    COMPRESSION_ALGORITHM_HEADER.to_string(),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn compress(mut message: Message, algorithm: CompressionAlgorithm) -> Result<Message, Error> {
+    if algorithm == CompressionAlgorithm::None || message.payload.is_empty() {
+        return Ok(message);
+    }
+
+    let payload = algorithm.compress(&message.payload)?;
+    let header_key = HeaderKey::new(COMPRESSION_ALGORITHM_HEADER)?;
+    let header_value = HeaderValue::from_uint8(algorithm.as_code())?;
+    // Keep the original payload if compression doesn't pay off (e.g. already compressed or encrypted data).
+    let header_size = header::get_header_size_bytes(&header_key, &header_value) as usize;
+    if payload.len() + header_size >= message.payload.len() {
+        return Ok(message);
+    }
+
+    message
+        .headers
+        .get_or_insert_with(HashMap::new)
+        .insert(header_key, header_value);
+    message.length = payload.len() as u32;
+    message.payload = payload.into();
+    Ok(message)
+}
+
+pub fn decompress(message: &Arc<Message>) -> Result<Arc<Message>, Error> {
+    let algorithm = match get_compression_algorithm(message)? {
+        Some(algorithm) => algorithm,
+        None => return Ok(message.clone()),
+    };
+
+    let payload = algorithm.decompress(&message.payload)?;
+    let headers = message.headers.as_ref().and_then(|headers| {
+        let headers = headers
+            .iter()
+            .filter(|(key, _)| key.as_str() != COMPRESSION_ALGORITHM_HEADER)
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect::<HashMap<_, _>>();
+        match headers.is_empty() {
+            true => None,
+            false => Some(headers),
+        }
+    });
+
+    Ok(Arc::new(Message::create(
+        message.offset,
+        message.state,
+        message.timestamp,
+        message.id,
+        payload.into(),
+        message.checksum,
+        headers,
+    )))
+}
+
+/// Decompresses the message, or returns it as stored if it can't be decompressed (e.g. due to the corrupted payload),
+/// so that a single message doesn't fail the whole poll.
+pub fn decompress_or_keep_stored(message: &Arc<Message>) -> Arc<Message> {
+    match decompress(message) {
+        Ok(message) => message,
+        Err(error) => {
+            error!(
+                "Failed to decompress the message with ID: {} at offset: {}, it will be returned as stored: {error}",
+                message.id, message.offset
+            );
+            message.clone()
+        }
+    }
+}
+
+pub fn get_compression_algorithm(message: &Message) -> Result<Option<CompressionAlgorithm>, Error> {
+    let headers = match &message.headers {
+        Some(headers) => headers,
+        None => return Ok(None),
+    };
+
+    match headers.get(&HeaderKey::new(COMPRESSION_ALGORITHM_HEADER)?) {
+        Some(value) => Ok(Some(CompressionAlgorithm::from_code(value.as_uint8()?)?)),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use iggy::models::messages::MessageState;
+    use std::str::FromStr;
+
+    #[test]
+    fn compressed_message_should_be_decompressed_to_the_original_one() {
+        let payload = Bytes::from("{\"entity_id\": 1}".repeat(100));
+        let message = create_message(payload.clone(), None);
+
+        let compressed = compress(message, CompressionAlgorithm::Gzip).unwrap();
+        assert!(compressed.payload.len() < payload.len());
+        assert_eq!(compressed.length, compressed.payload.len() as u32);
+        assert_eq!(
+            get_compression_algorithm(&compressed).unwrap(),
+            Some(CompressionAlgorithm::Gzip)
+        );
+
+        let decompressed = decompress(&Arc::new(compressed)).unwrap();
+        assert_eq!(decompressed.payload, payload);
+        assert_eq!(decompressed.length, payload.len() as u32);
+        assert!(decompressed.headers.is_none());
+    }
+
+    #[test]
+    fn message_which_cannot_be_decompressed_should_be_kept_as_stored() {
+        let payload = Bytes::from("{\"entity_id\": 1}".repeat(100));
+        let mut compressed =
+            compress(create_message(payload, None), CompressionAlgorithm::Gzip).unwrap();
+        compressed.payload = compressed.payload.slice(..compressed.payload.len() / 2);
+        let compressed = Arc::new(compressed);
+
+        assert!(decompress(&compressed).is_err());
+        let message = decompress_or_keep_stored(&compressed);
+        assert_eq!(message.payload, compressed.payload);
+        assert_eq!(
+            get_compression_algorithm(&message).unwrap(),
+            Some(CompressionAlgorithm::Gzip)
+        );
+    }
+
+    #[test]
+    fn message_should_not_be_compressed_given_none_algorithm() {
+        let payload = Bytes::from("{\"entity_id\": 1}".repeat(100));
+        let message = create_message(payload.clone(), None);
+
+        let compressed = compress(message, CompressionAlgorithm::None).unwrap();
+        assert_eq!(compressed.payload, payload);
+        assert!(get_compression_algorithm(&compressed).unwrap().is_none());
+    }
+
+    #[test]
+    fn message_should_not_be_compressed_given_incompressible_payload() {
+        let payload = Bytes::from("iggy");
+        let message = create_message(payload.clone(), None);
+
+        let compressed = compress(message, CompressionAlgorithm::Gzip).unwrap();
+        assert_eq!(compressed.payload, payload);
+        assert!(compressed.headers.is_none());
+    }
+
+    #[test]
+    fn user_headers_should_be_preserved() {
+        let payload = Bytes::from("{\"entity_id\": 1}".repeat(100));
+        let key = HeaderKey::new("key").unwrap();
+        let value = HeaderValue::from_str("value").unwrap();
+        let headers = HashMap::from([(key.clone(), value.clone())]);
+        let message = create_message(payload.clone(), Some(headers));
+
+        let compressed = compress(message, CompressionAlgorithm::Gzip).unwrap();
+        assert_eq!(compressed.headers.as_ref().unwrap().len(), 2);
+
+        let decompressed = decompress(&Arc::new(compressed)).unwrap();
+        let headers = decompressed.headers.as_ref().unwrap();
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers.get(&key), Some(&value));
+    }
+
+    #[test]
+    fn messages_with_reserved_header_should_be_rejected() {
+        let payload = Bytes::from("iggy");
+        let key = HeaderKey::new(COMPRESSION_ALGORITHM_HEADER).unwrap();
+        let value = HeaderValue::from_uint8(1).unwrap();
+        let headers = HashMap::from([(key, value)]);
+        let messages = vec![
+            create_message(payload.clone(), None),
+            create_message(payload, Some(headers)),
+        ];
+
+        assert!(matches!(
+            ensure_no_reserved_header(&messages),
+            Err(Error::ReservedHeaderKey(_))
+        ));
+        assert!(ensure_no_reserved_header(&messages[..1]).is_ok());
+    }
+
+    fn create_message(payload: Bytes, headers: Option<HashMap<HeaderKey, HeaderValue>>) -> Message {
+        Message::create(1, MessageState::Available, 1, 1, payload, 1, headers)
+    }
+}
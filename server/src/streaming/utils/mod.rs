@@ -1,3 +1,4 @@
+pub mod compression;
 pub mod crypto;
 pub mod file;
 pub mod hash;
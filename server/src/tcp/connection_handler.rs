@@ -8,7 +8,7 @@ use iggy::bytes_serializable::BytesSerializable;
 use iggy::command::Command;
 use std::io::ErrorKind;
 use std::net::SocketAddr;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 const INITIAL_BYTES_LENGTH: usize = 4;
 
@@ -16,10 +16,21 @@ pub(crate) async fn handle_connection(
     address: SocketAddr,
     sender: &mut dyn Sender,
     system: SharedSystem,
+    certificate_username: Option<String>,
 ) -> Result<(), ServerError> {
     let client_id = system.read().add_client(&address, Transport::Tcp).await;
 
-    let session = Session::from_client_id(client_id, address);
+    let session = Session::from_client_id(client_id, address, Transport::Tcp);
+    if let Some(username) = certificate_username {
+        if let Err(error) = system
+            .read()
+            .login_user_with_certificate(&username, &session)
+            .await
+        {
+            warn!("Cannot login user: {username} with the client certificate, error: {error}");
+        }
+    }
+
     let mut initial_buffer = [0u8; INITIAL_BYTES_LENGTH];
     loop {
         let read_length = sender.read(&mut initial_buffer).await?;
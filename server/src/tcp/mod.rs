@@ -1,6 +1,8 @@
 pub mod connection_handler;
 pub mod sender;
 pub mod tcp_listener;
+pub mod tcp_mtls_listener;
+pub mod tcp_mtls_sender;
 mod tcp_sender;
 pub mod tcp_server;
 pub mod tcp_tls_listener;
@@ -1,4 +1,5 @@
 use iggy::error::Error;
+use std::io::{ErrorKind, IoSlice};
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use tracing::debug;
 
@@ -30,6 +31,33 @@ where
     send_response(stream, STATUS_OK, payload).await
 }
 
+pub(crate) async fn send_ok_response_vectored<T>(
+    stream: &mut T,
+    payload: &[&[u8]],
+) -> Result<(), Error>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    debug!("Sending vectored response with status: {:?}...", STATUS_OK);
+    let length = (payload.iter().map(|part| part.len()).sum::<usize>() as u32).to_le_bytes();
+    let mut slices = Vec::with_capacity(2 + payload.len());
+    slices.push(IoSlice::new(STATUS_OK));
+    slices.push(IoSlice::new(&length));
+    slices.extend(payload.iter().map(|part| IoSlice::new(part)));
+
+    // The parts are written directly, e.g. from the mapped segment file, without concatenating them first.
+    let mut slices = slices.as_mut_slice();
+    while !slices.is_empty() {
+        let written = stream.write_vectored(slices).await?;
+        if written == 0 {
+            return Err(Error::from(std::io::Error::from(ErrorKind::WriteZero)));
+        }
+        IoSlice::advance_slices(&mut slices, written);
+    }
+    debug!("Sent vectored response with status: {:?}", STATUS_OK);
+    Ok(())
+}
+
 pub(crate) async fn send_error_response<T>(stream: &mut T, error: Error) -> Result<(), Error>
 where
     T: AsyncRead + AsyncWrite + Unpin,
@@ -33,7 +33,7 @@ pub async fn start(address: &str, system: SharedSystem) -> SocketAddr {
                     let mut sender = TcpSender { stream };
                     tokio::spawn(async move {
                         if let Err(error) =
-                            handle_connection(address, &mut sender, system.clone()).await
+                            handle_connection(address, &mut sender, system.clone(), None).await
                         {
                             handle_error(error);
                             system.read().delete_client(&address).await;
@@ -0,0 +1,89 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use crate::configs::tcp::TcpTlsConfig;
+use crate::streaming::systems::system::SharedSystem;
+use crate::tcp::connection_handler::{handle_connection, handle_error};
+use crate::tcp::tcp_mtls_sender::TcpMtlsSender;
+use crate::tls;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tokio_rustls::TlsAcceptor;
+use tracing::{error, info};
+
+pub(crate) async fn start(address: &str, config: TcpTlsConfig, system: SharedSystem) -> SocketAddr {
+    let address = address.to_string();
+    let (tx, rx) = oneshot::channel();
+    tokio::spawn(async move {
+        let certificates = tls::load_pkcs12_certificates(&config.certificate, &config.password);
+        if let Err(error) = certificates {
+            panic!("Unable to load certificate file, error: {error}");
+        }
+
+        let verifier = tls::create_client_certificate_verifier(&config.client_auth);
+        if let Err(error) = verifier {
+            panic!("Unable to load client CA certificates, error: {error}");
+        }
+
+        let (certificate, key) = certificates.unwrap();
+        let server_config = rustls::ServerConfig::builder()
+            .with_safe_defaults()
+            .with_client_cert_verifier(verifier.unwrap())
+            .with_single_cert(certificate, key)
+            .expect("Unable to create TLS server configuration.");
+        let acceptor = TlsAcceptor::from(Arc::new(server_config));
+        let client_auth = Arc::new(config.client_auth);
+
+        let listener = TcpListener::bind(&address)
+            .await
+            .expect("Unable to start TCP mTLS server.");
+
+        let local_addr = listener
+            .local_addr()
+            .expect("Failed to get local address for TCP mTLS listener");
+
+        tx.send(local_addr).unwrap_or_else(|_| {
+            panic!(
+                "Failed to send the local address {:?} for TCP mTLS listener",
+                local_addr
+            )
+        });
+
+        loop {
+            match listener.accept().await {
+                Ok((stream, address)) => {
+                    info!("Accepted new TCP mTLS connection: {}", address);
+                    let acceptor = acceptor.clone();
+                    let client_auth = client_auth.clone();
+                    let system = system.clone();
+                    tokio::spawn(async move {
+                        let stream = match acceptor.accept(stream).await {
+                            Ok(stream) => stream,
+                            Err(error) => {
+                                error!("TLS handshake with: {address} has failed: {error}");
+                                return;
+                            }
+                        };
+
+                        let username = tls::get_client_certificate_username(
+                            &client_auth,
+                            stream.get_ref().1.peer_certificates(),
+                        );
+                        let mut sender = TcpMtlsSender { stream };
+                        if let Err(error) =
+                            handle_connection(address, &mut sender, system.clone(), username).await
+                        {
+                            handle_error(error);
+                            system.read().delete_client(&address).await;
+                        }
+                    });
+                }
+                Err(error) => error!("Unable to accept TCP mTLS socket, error: {}", error),
+            }
+        }
+    });
+    match rx.await {
+        Ok(addr) => addr,
+        Err(_) => panic!("Failed to get the local address for TCP mTLS listener"),
+    }
+}
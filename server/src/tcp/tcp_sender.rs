@@ -26,6 +26,10 @@ impl Sender for TcpSender {
         sender::send_ok_response(&mut self.stream, payload).await
     }
 
+    async fn send_ok_response_vectored(&mut self, payload: &[&[u8]]) -> Result<(), Error> {
+        sender::send_ok_response_vectored(&mut self.stream, payload).await
+    }
+
     async fn send_error_response(&mut self, error: Error) -> Result<(), Error> {
         sender::send_error_response(&mut self.stream, error).await
     }
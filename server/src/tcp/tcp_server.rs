@@ -1,21 +1,22 @@
 use crate::configs::tcp::TcpConfig;
 use crate::streaming::systems::system::SharedSystem;
-use crate::tcp::{tcp_listener, tcp_tls_listener};
+use crate::tcp::{tcp_listener, tcp_mtls_listener, tcp_tls_listener};
 use std::net::SocketAddr;
 use tracing::info;
 
 /// Starts the TCP server.
 /// Returns the address the server is listening on.
 pub async fn start(config: TcpConfig, system: SharedSystem) -> SocketAddr {
-    let server_name = if config.tls.enabled {
-        "Iggy TCP TLS"
-    } else {
-        "Iggy TCP"
+    let server_name = match (config.tls.enabled, config.tls.client_auth.enabled) {
+        (true, true) => "Iggy TCP mTLS",
+        (true, false) => "Iggy TCP TLS",
+        (false, _) => "Iggy TCP",
     };
     info!("Initializing {server_name} server...");
-    let addr = match config.tls.enabled {
-        true => tcp_tls_listener::start(&config.address, config.tls, system).await,
-        false => tcp_listener::start(&config.address, system).await,
+    let addr = match (config.tls.enabled, config.tls.client_auth.enabled) {
+        (true, true) => tcp_mtls_listener::start(&config.address, config.tls, system).await,
+        (true, false) => tcp_tls_listener::start(&config.address, config.tls, system).await,
+        (false, _) => tcp_listener::start(&config.address, system).await,
     };
     info!("{server_name} server has started on: {:?}", addr);
     addr
@@ -55,7 +55,7 @@ pub(crate) async fn start(address: &str, config: TcpTlsConfig, system: SharedSys
                     let mut sender = TcpTlsSender { stream };
                     tokio::spawn(async move {
                         if let Err(error) =
-                            handle_connection(address, &mut sender, system.clone()).await
+                            handle_connection(address, &mut sender, system.clone(), None).await
                         {
                             handle_error(error);
                             system.read().delete_client(&address).await;
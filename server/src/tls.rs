@@ -0,0 +1,367 @@
+use crate::configs::client_auth::{CertificateIdentity, ClientAuthConfig};
+use openssl::pkcs12::Pkcs12;
+use rustls::server::{
+    AllowAnyAnonymousOrAuthenticatedClient, AllowAnyAuthenticatedClient, ClientCertVerifier,
+};
+use rustls::{Certificate, PrivateKey, RootCertStore};
+use std::error::Error;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+use tracing::{info, warn};
+use x509_parser::certificate::X509Certificate;
+use x509_parser::extensions::GeneralName;
+use x509_parser::prelude::FromDer;
+
+/// Loads the PEM certificate chain and the PEM private key (PKCS#1, PKCS#8 or SEC1).
+pub fn load_certificates(
+    cert_file: &str,
+    key_file: &str,
+) -> Result<(Vec<Certificate>, PrivateKey), Box<dyn Error>> {
+    let mut cert_chain_reader = BufReader::new(File::open(cert_file)?);
+    let certs = rustls_pemfile::certs(&mut cert_chain_reader)
+        .map(|certificate| certificate.map(|certificate| Certificate(certificate.to_vec())))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut key_reader = BufReader::new(File::open(key_file)?);
+    let key = rustls_pemfile::private_key(&mut key_reader)?
+        .ok_or_else(|| format!("Private key was not found in file: {key_file}"))?;
+    Ok((certs, PrivateKey(key.secret_der().to_vec())))
+}
+
+/// Loads the certificate chain and the private key from the PKCS#12 file, e.g. the one used by the TCP TLS server.
+pub fn load_pkcs12_certificates(
+    certificate_file: &str,
+    password: &str,
+) -> Result<(Vec<Certificate>, PrivateKey), Box<dyn Error>> {
+    let pkcs12 = Pkcs12::from_der(&std::fs::read(certificate_file)?)?.parse2(password)?;
+    let certificate = pkcs12
+        .cert
+        .ok_or_else(|| format!("Certificate was not found in file: {certificate_file}"))?;
+    let key = pkcs12
+        .pkey
+        .ok_or_else(|| format!("Private key was not found in file: {certificate_file}"))?;
+    let mut certs = vec![Certificate(certificate.to_der()?)];
+    if let Some(chain) = pkcs12.ca {
+        for certificate in &chain {
+            certs.push(Certificate(certificate.to_der()?));
+        }
+    }
+
+    Ok((certs, PrivateKey(key.private_key_to_pkcs8()?)))
+}
+
+/// Creates the verifier accepting the client certificates signed by the certificate authorities from the configured CA file.
+/// If the client certificate is not required, the connections without it are accepted as well.
+pub fn create_client_certificate_verifier(
+    config: &ClientAuthConfig,
+) -> Result<Arc<dyn ClientCertVerifier>, Box<dyn Error>> {
+    let mut roots = RootCertStore::empty();
+    let mut ca_reader = BufReader::new(File::open(&config.ca_file)?);
+    for certificate in rustls_pemfile::certs(&mut ca_reader) {
+        roots.add(&Certificate(certificate?.to_vec()))?;
+    }
+
+    if roots.is_empty() {
+        return Err(format!("CA certificates were not found in file: {}", config.ca_file).into());
+    }
+
+    Ok(match config.required {
+        true => AllowAnyAuthenticatedClient::new(roots).boxed(),
+        false => AllowAnyAnonymousOrAuthenticatedClient::new(roots).boxed(),
+    })
+}
+
+/// Returns the identities of the certificate: its subject, subject common names
+/// and DNS, email and URI subject alternative names.
+pub fn get_certificate_identities(certificate: &Certificate) -> Vec<CertificateIdentity> {
+    let certificate = match X509Certificate::from_der(&certificate.0) {
+        Ok((_, certificate)) => certificate,
+        Err(error) => {
+            warn!("Cannot parse the client certificate: {error}");
+            return Vec::new();
+        }
+    };
+
+    let subject = certificate.subject();
+    let mut identities = vec![CertificateIdentity::Subject(subject.to_string())];
+    identities.extend(
+        subject
+            .iter_common_name()
+            .filter_map(|name| name.as_str().ok())
+            .map(|name| CertificateIdentity::CommonName(name.to_string())),
+    );
+
+    if let Ok(Some(alternative_names)) = certificate.subject_alternative_name() {
+        for name in &alternative_names.value.general_names {
+            match name {
+                GeneralName::DNSName(name) => {
+                    identities.push(CertificateIdentity::Dns(name.to_string()))
+                }
+                GeneralName::RFC822Name(name) => {
+                    identities.push(CertificateIdentity::Email(name.to_string()))
+                }
+                GeneralName::URI(name) => {
+                    identities.push(CertificateIdentity::Uri(name.to_string()))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    identities
+}
+
+/// Returns the username mapped to the verified client certificate, if any.
+pub fn get_client_certificate_username(
+    config: &ClientAuthConfig,
+    certificates: Option<&[Certificate]>,
+) -> Option<String> {
+    let certificate = certificates?.first()?;
+    let identities = get_certificate_identities(certificate);
+    match config.get_username(&identities) {
+        Some(username) => {
+            info!(
+                "Client certificate: {} is mapped to user: {username}.",
+                identities
+                    .first()
+                    .map(|subject| subject.to_string())
+                    .unwrap_or_default()
+            );
+            Some(username.to_string())
+        }
+        None => {
+            warn!(
+                "User is not mapped to any of the client certificate identities: {}.",
+                identities
+                    .iter()
+                    .map(|identity| identity.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::client_auth::ClientCertificateUserConfig;
+    use rcgen::{BasicConstraints, CertificateParams, DnType, IsCa, SanType};
+    use std::path::PathBuf;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio_rustls::{TlsAcceptor, TlsConnector};
+
+    #[test]
+    fn should_return_subject_common_name_and_alternative_names_as_identities() {
+        let ca = create_ca();
+        let client = create_client_certificate(&ca);
+
+        let identities = get_certificate_identities(&client.0[0]);
+
+        assert!(identities.contains(&CertificateIdentity::Subject(
+            "CN=service-a, O=iggy".to_string()
+        )));
+        assert!(identities.contains(&CertificateIdentity::CommonName("service-a".to_string())));
+        assert!(identities.contains(&CertificateIdentity::Dns(
+            "service-a.iggy.local".to_string()
+        )));
+        assert!(identities.contains(&CertificateIdentity::Email("service-a@iggy.rs".to_string())));
+        assert!(identities.contains(&CertificateIdentity::Uri(
+            "spiffe://iggy.rs/service-a".to_string()
+        )));
+    }
+
+    #[test]
+    fn should_return_username_mapped_to_client_certificate() {
+        let ca = create_ca();
+        let (client_certificates, _) = create_client_certificate(&ca);
+        let mut config = create_config(&PathBuf::new(), true);
+
+        let username = get_client_certificate_username(&config, Some(&client_certificates));
+        assert_eq!(username, Some("service_a".to_string()));
+        assert_eq!(get_client_certificate_username(&config, None), None);
+
+        config.users[0].identity = CertificateIdentity::CommonName("service-b".to_string());
+        let username = get_client_certificate_username(&config, Some(&client_certificates));
+        assert_eq!(username, None);
+
+        // The identity is matched only against the same field, so the DNS name doesn't match the common name.
+        config.users[0].identity = CertificateIdentity::Dns("service-a".to_string());
+        let username = get_client_certificate_username(&config, Some(&client_certificates));
+        assert_eq!(username, None);
+    }
+
+    #[test]
+    fn should_load_certificate_and_private_key_from_pkcs12_file() {
+        let ca = create_ca();
+        let (certificates, key) = create_client_certificate(&ca);
+        let pkcs12 = Pkcs12::builder()
+            .name("iggy")
+            .pkey(&openssl::pkey::PKey::private_key_from_der(&key.0).unwrap())
+            .cert(&openssl::x509::X509::from_der(&certificates[0].0).unwrap())
+            .build2("secret")
+            .unwrap();
+        let path = std::env::temp_dir().join(format!("iggy_{}.pfx", uuid::Uuid::new_v4()));
+        std::fs::write(&path, pkcs12.to_der().unwrap()).unwrap();
+        let path = path.to_string_lossy().to_string();
+
+        let loaded = load_pkcs12_certificates(&path, "secret");
+        let invalid_password = load_pkcs12_certificates(&path, "invalid");
+        std::fs::remove_file(&path).unwrap();
+
+        let (loaded_certificates, loaded_key) = loaded.unwrap();
+        assert_eq!(loaded_certificates, certificates);
+        assert!(!loaded_key.0.is_empty());
+        assert!(invalid_password.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_accept_client_certificate_signed_by_configured_ca() {
+        let ca = create_ca();
+        let ca_file = write_ca_file(&ca);
+        let config = create_config(&ca_file, true);
+        let client_certificate = create_client_certificate(&ca);
+
+        let peer_certificates = handshake(&config, Some(client_certificate)).await;
+        std::fs::remove_file(&ca_file).unwrap();
+
+        assert!(peer_certificates.is_ok());
+        let peer_certificates = peer_certificates.unwrap();
+        let username = get_client_certificate_username(&config, peer_certificates.as_deref());
+        assert_eq!(username, Some("service_a".to_string()));
+    }
+
+    #[tokio::test]
+    async fn should_reject_client_without_certificate_if_it_is_required() {
+        let ca = create_ca();
+        let ca_file = write_ca_file(&ca);
+        let required_config = create_config(&ca_file, true);
+        let optional_config = create_config(&ca_file, false);
+
+        let required_result = handshake(&required_config, None).await;
+        let optional_result = handshake(&optional_config, None).await;
+        std::fs::remove_file(&ca_file).unwrap();
+
+        assert!(required_result.is_err());
+        assert!(optional_result.is_ok());
+        assert!(optional_result.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn should_reject_client_certificate_signed_by_unknown_ca() {
+        let ca = create_ca();
+        let ca_file = write_ca_file(&ca);
+        let config = create_config(&ca_file, false);
+        let client_certificate = create_client_certificate(&create_ca());
+
+        let result = handshake(&config, Some(client_certificate)).await;
+        std::fs::remove_file(&ca_file).unwrap();
+
+        assert!(result.is_err());
+    }
+
+    async fn handshake(
+        config: &ClientAuthConfig,
+        client_certificate: Option<(Vec<Certificate>, PrivateKey)>,
+    ) -> Result<Option<Vec<Certificate>>, std::io::Error> {
+        let server_certificate =
+            rcgen::generate_simple_self_signed(vec!["localhost".into()]).unwrap();
+        let server_certificate_der = Certificate(server_certificate.serialize_der().unwrap());
+        let server_config = rustls::ServerConfig::builder()
+            .with_safe_defaults()
+            .with_client_cert_verifier(create_client_certificate_verifier(config).unwrap())
+            .with_single_cert(
+                vec![server_certificate_der.clone()],
+                PrivateKey(server_certificate.serialize_private_key_der()),
+            )
+            .unwrap();
+        let acceptor = TlsAcceptor::from(Arc::new(server_config));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let mut roots = RootCertStore::empty();
+        roots.add(&server_certificate_der).unwrap();
+        let client_config = rustls::ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots);
+        let client_config = match client_certificate {
+            Some((certificates, key)) => client_config
+                .with_client_auth_cert(certificates, key)
+                .unwrap(),
+            None => client_config.with_no_client_auth(),
+        };
+        let connector = TlsConnector::from(Arc::new(client_config));
+
+        let client = tokio::spawn(async move {
+            let stream = TcpStream::connect(address).await?;
+            let mut stream = connector
+                .connect("localhost".try_into().unwrap(), stream)
+                .await?;
+            stream.write_all(b"ping").await?;
+            stream.flush().await
+        });
+
+        let (stream, _) = listener.accept().await?;
+        let result = acceptor.accept(stream).await;
+        let _ = client.await;
+        let mut stream = result?;
+        let mut buffer = [0u8; 4];
+        stream.read_exact(&mut buffer).await?;
+        Ok(stream
+            .get_ref()
+            .1
+            .peer_certificates()
+            .map(|certificates| certificates.to_vec()))
+    }
+
+    fn create_ca() -> rcgen::Certificate {
+        let mut params = CertificateParams::new(Vec::new());
+        params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        params
+            .distinguished_name
+            .push(DnType::CommonName, "Iggy Test CA");
+        rcgen::Certificate::from_params(params).unwrap()
+    }
+
+    fn create_client_certificate(ca: &rcgen::Certificate) -> (Vec<Certificate>, PrivateKey) {
+        let mut params = CertificateParams::new(vec!["service-a.iggy.local".to_string()]);
+        params.subject_alt_names.extend([
+            SanType::Rfc822Name("service-a@iggy.rs".to_string()),
+            SanType::URI("spiffe://iggy.rs/service-a".to_string()),
+        ]);
+        params.distinguished_name = rcgen::DistinguishedName::new();
+        params
+            .distinguished_name
+            .push(DnType::CommonName, "service-a");
+        params
+            .distinguished_name
+            .push(DnType::OrganizationName, "iggy");
+        let certificate = rcgen::Certificate::from_params(params).unwrap();
+        (
+            vec![Certificate(
+                certificate.serialize_der_with_signer(ca).unwrap(),
+            )],
+            PrivateKey(certificate.serialize_private_key_der()),
+        )
+    }
+
+    fn write_ca_file(ca: &rcgen::Certificate) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("iggy_ca_{}.pem", uuid::Uuid::new_v4()));
+        std::fs::write(&path, ca.serialize_pem().unwrap()).unwrap();
+        path
+    }
+
+    fn create_config(ca_file: &std::path::Path, required: bool) -> ClientAuthConfig {
+        ClientAuthConfig {
+            enabled: true,
+            required,
+            ca_file: ca_file.to_string_lossy().to_string(),
+            users: vec![ClientCertificateUserConfig {
+                identity: CertificateIdentity::Dns("service-a.iggy.local".to_string()),
+                username: "service_a".to_string(),
+            }],
+        }
+    }
+}
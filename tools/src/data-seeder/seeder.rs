@@ -2,8 +2,9 @@ use iggy::client::{MessageClient, StreamClient, TopicClient};
 use iggy::clients::client::IggyClient;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
 use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::topic::{CleanupPolicy, FlushPolicy, MaxTopicSizePolicy};
 use iggy::streams::create_stream::CreateStream;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::get_topics::GetTopics;
@@ -56,6 +57,10 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                compression_algorithm: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+                flush_policy: FlushPolicy::default(),
             })
             .await?;
 
@@ -68,6 +73,10 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                compression_algorithm: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+                flush_policy: FlushPolicy::default(),
             })
             .await?;
 
@@ -80,6 +89,10 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                compression_algorithm: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+                flush_policy: FlushPolicy::default(),
             })
             .await?;
 
@@ -92,6 +105,10 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                compression_algorithm: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+                flush_policy: FlushPolicy::default(),
             })
             .await?;
 
@@ -104,6 +121,10 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                compression_algorithm: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                max_topic_size_policy: MaxTopicSizePolicy::DeleteOldest,
+                flush_policy: FlushPolicy::default(),
             })
             .await?;
     }
@@ -152,6 +173,7 @@ async fn send_messages(client: &IggyClient) -> Result<(), Error> {
                         stream_id: Identifier::numeric(stream_id)?,
                         topic_id: Identifier::numeric(topic.id)?,
                         partitioning: Partitioning::balanced(),
+                        ack: AckLevel::InMemory,
                         messages,
                     })
                     .await?;
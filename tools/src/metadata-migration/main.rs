@@ -0,0 +1,54 @@
+use anyhow::{bail, Result};
+use clap::Parser;
+use server::configs::system::DatabaseBackend;
+use server::streaming::metadata::database::{migrate_database, open_database};
+use tracing::info;
+
+#[derive(Parser, Debug, Clone)]
+#[command(author, version, about, long_about = None)]
+pub struct MetadataMigrationArgs {
+    /// Path to the source database, e.g. `local_data/database`.
+    #[arg(long)]
+    pub source_path: String,
+
+    /// Backend of the source database, `sled` or `redb`.
+    #[arg(long, default_value = "sled", value_parser = parse_backend)]
+    pub source_backend: DatabaseBackend,
+
+    /// Path to the target database, it must not contain any metadata yet.
+    #[arg(long)]
+    pub target_path: String,
+
+    /// Backend of the target database, `sled` or `redb`.
+    #[arg(long, default_value = "redb", value_parser = parse_backend)]
+    pub target_backend: DatabaseBackend,
+}
+
+fn main() -> Result<()> {
+    let args = MetadataMigrationArgs::parse();
+    tracing_subscriber::fmt::init();
+    if args.source_path == args.target_path {
+        bail!("Source and target database paths must be different.");
+    }
+
+    info!(
+        "Migrating metadata from {} database: {} to {} database: {}...",
+        args.source_backend, args.source_path, args.target_backend, args.target_path
+    );
+    let source = open_database(args.source_backend, &args.source_path)?;
+    let target = open_database(args.target_backend, &args.target_path)?;
+    let migrated_entries_count = migrate_database(source.as_ref(), target.as_ref())?;
+    info!(
+        "Migrated {} metadata entries, set `system.database.backend` to: {} and point `system.database.path` at: {} to use the migrated database.",
+        migrated_entries_count, args.target_backend, args.target_path
+    );
+    Ok(())
+}
+
+fn parse_backend(backend: &str) -> Result<DatabaseBackend, String> {
+    match backend {
+        "sled" => Ok(DatabaseBackend::Sled),
+        "redb" => Ok(DatabaseBackend::Redb),
+        _ => Err(format!("Unsupported database backend: {}", backend)),
+    }
+}
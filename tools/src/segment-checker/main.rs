@@ -0,0 +1,118 @@
+use anyhow::Result;
+use clap::Parser;
+use iggy::utils::byte_size::IggyByteSize;
+use server::configs::system::SegmentConfig;
+use server::streaming::segments::integrity;
+use server::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION};
+use std::path::{Path, PathBuf};
+use tracing::{error, info, warn};
+
+#[derive(Parser, Debug, Clone)]
+#[command(author, version, about, long_about = None)]
+pub struct SegmentCheckerArgs {
+    /// Path to the server data directory, the segments are searched recursively.
+    #[arg(long, default_value = "local_data")]
+    pub path: String,
+
+    /// Truncates the corrupted tail of the log files and rebuilds the inconsistent index files.
+    /// The server must not be running while the segments are repaired.
+    #[arg(long, default_value_t = false)]
+    pub repair: bool,
+
+    /// Index interval in messages, it must match the `system.segment.index_interval_messages` server setting.
+    #[arg(long, default_value_t = 1)]
+    pub index_interval_messages: u32,
+
+    /// Index interval in bytes, it must match the `system.segment.index_interval_bytes` server setting.
+    #[arg(long, default_value = "0")]
+    pub index_interval_bytes: IggyByteSize,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = SegmentCheckerArgs::parse();
+    tracing_subscriber::fmt::init();
+    info!("Segment checker has started, path: {}...", args.path);
+    let mut log_paths = Vec::new();
+    find_log_files(Path::new(&args.path), &mut log_paths)?;
+    let config = SegmentConfig {
+        index_interval_messages: args.index_interval_messages,
+        index_interval_bytes: args.index_interval_bytes,
+        ..Default::default()
+    };
+    log_paths.sort();
+
+    let mut invalid_segments_count = 0;
+    for log_path in &log_paths {
+        let Some(start_offset) = log_path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<u64>().ok())
+        else {
+            continue;
+        };
+
+        let report = match integrity::check_segment_files(
+            &config,
+            start_offset,
+            &log_path.to_string_lossy(),
+            &log_path.with_extension(INDEX_EXTENSION).to_string_lossy(),
+            &log_path
+                .with_extension(TIME_INDEX_EXTENSION)
+                .to_string_lossy(),
+            args.repair,
+        )
+        .await
+        {
+            Ok(report) => report,
+            Err(error) => {
+                error!(
+                    "Cannot check segment: {}. Error: {}",
+                    log_path.display(),
+                    error
+                );
+                invalid_segments_count += 1;
+                continue;
+            }
+        };
+
+        if report.is_valid() {
+            info!("Segment: {} is valid, {}", log_path.display(), report);
+            continue;
+        }
+
+        invalid_segments_count += 1;
+        if report.repaired {
+            warn!("Segment: {} was repaired, {}", log_path.display(), report);
+        } else {
+            warn!("Segment: {} is invalid, {}", log_path.display(), report);
+        }
+    }
+
+    info!(
+        "Segment checker has finished, checked {} segments, found {} invalid segments.",
+        log_paths.len(),
+        invalid_segments_count
+    );
+    if invalid_segments_count > 0 && !args.repair {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+fn find_log_files(path: &Path, log_paths: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(path)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            find_log_files(&path, log_paths)?;
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == LOG_EXTENSION)
+        {
+            log_paths.push(path);
+        }
+    }
+
+    Ok(())
+}